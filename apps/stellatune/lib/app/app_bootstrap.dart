@@ -39,6 +39,13 @@ class _BootstrapPaths {
   final String lyricsDbPath;
 }
 
+/// Version plugin manifests' `requires.host` is checked against. Keep the default in
+/// sync with `version` in pubspec.yaml.
+const _appVersion = String.fromEnvironment(
+  'STELLATUNE_APP_VERSION',
+  defaultValue: '1.0.0',
+);
+
 bool _isExitInProgress = false;
 
 Future<void> initializeDesktopWindowIfNeeded() async {
@@ -82,6 +89,11 @@ Future<AppBootstrapResult> bootstrapApp() async {
   await initRustRuntime();
 
   final bridge = await PlayerBridge.create();
+  try {
+    await bridge.pluginsSetHostVersion(_appVersion);
+  } catch (e, s) {
+    logger.w('failed to set plugin host version', error: e, stackTrace: s);
+  }
   await SettingsStore.initHive();
   final settings = SettingsStore();
   final paths = await _resolvePaths();
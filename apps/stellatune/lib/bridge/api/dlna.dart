@@ -143,3 +143,21 @@ Future<String> dlnaPlayLocalTrack({
   album: album,
   coverPath: coverPath,
 );
+
+/// Streams the engine output to LAN listeners through the DLNA HTTP server, next to
+/// the current output device.
+Future<DlnaHttpStreamInfo> dlnaHttpStreamStart({
+  required DlnaStreamFormat format,
+  int? clientBufferMs,
+  required bool disconnectSlowClients,
+}) => StellatuneApi.instance.api.crateApiDlnaDlnaHttpStreamStart(
+  format: format,
+  clientBufferMs: clientBufferMs,
+  disconnectSlowClients: disconnectSlowClients,
+);
+
+Future<void> dlnaHttpStreamStop() =>
+    StellatuneApi.instance.api.crateApiDlnaDlnaHttpStreamStop();
+
+Future<List<DlnaHttpStreamClient>> dlnaHttpStreamClients() =>
+    StellatuneApi.instance.api.crateApiDlnaDlnaHttpStreamClients();
@@ -26,6 +26,59 @@ class DlnaHttpServerInfo {
           baseUrl == other.baseUrl;
 }
 
+class DlnaHttpStreamClient {
+  final String remoteAddr;
+  final BigInt sentBytes;
+  final BigInt droppedBytes;
+
+  const DlnaHttpStreamClient({
+    required this.remoteAddr,
+    required this.sentBytes,
+    required this.droppedBytes,
+  });
+
+  @override
+  int get hashCode =>
+      remoteAddr.hashCode ^ sentBytes.hashCode ^ droppedBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DlnaHttpStreamClient &&
+          runtimeType == other.runtimeType &&
+          remoteAddr == other.remoteAddr &&
+          sentBytes == other.sentBytes &&
+          droppedBytes == other.droppedBytes;
+}
+
+class DlnaHttpStreamInfo {
+  /// Live stream URL; players may send `Icy-MetaData: 1` for now-playing titles.
+  final String streamUrl;
+
+  /// `.m3u` playlist pointing at `stream_url`, for players that only open playlists.
+  final String playlistUrl;
+  final String contentType;
+
+  const DlnaHttpStreamInfo({
+    required this.streamUrl,
+    required this.playlistUrl,
+    required this.contentType,
+  });
+
+  @override
+  int get hashCode =>
+      streamUrl.hashCode ^ playlistUrl.hashCode ^ contentType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DlnaHttpStreamInfo &&
+          runtimeType == other.runtimeType &&
+          streamUrl == other.streamUrl &&
+          playlistUrl == other.playlistUrl &&
+          contentType == other.contentType;
+}
+
 class DlnaPositionInfo {
   final BigInt relTimeMs;
   final BigInt? trackDurationMs;
@@ -115,6 +168,8 @@ class DlnaSsdpDevice {
           server == other.server;
 }
 
+enum DlnaStreamFormat { wav16, flac16, flac24 }
+
 class DlnaTransportInfo {
   final String currentTransportState;
   final String? currentTransportStatus;
@@ -166,3 +166,31 @@ Future<String> libraryPluginApplyStateStatusJson() => StellatuneApi.instance.api
 
 Future<List<String>> libraryListDisabledPluginIds() =>
     StellatuneApi.instance.api.crateApiLibraryLibraryListDisabledPluginIds();
+
+Future<String> libraryPluginConfigLoadJson({
+  required String pluginId,
+  required String typeId,
+}) => StellatuneApi.instance.api.crateApiLibraryLibraryPluginConfigLoadJson(
+  pluginId: pluginId,
+  typeId: typeId,
+);
+
+Future<String> libraryPluginConfigValidateJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+}) => StellatuneApi.instance.api.crateApiLibraryLibraryPluginConfigValidateJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+);
+
+Future<String> libraryPluginConfigSaveJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+}) => StellatuneApi.instance.api.crateApiLibraryLibraryPluginConfigSaveJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+);
@@ -43,6 +43,7 @@ Future<void> stop() => StellatuneApi.instance.api.crateApiPlayerStop();
 
 Stream<Event> events() => StellatuneApi.instance.api.crateApiPlayerEvents();
 
+/// Host-side plugin runtime events: dev plugin reloads and sidecar state changes.
 Stream<PluginRuntimeEvent> pluginRuntimeEventsGlobal() =>
     StellatuneApi.instance.api.crateApiPlayerPluginRuntimeEventsGlobal();
 
@@ -222,3 +223,177 @@ Future<void> preloadTrackRef({
 
 Future<List<String>> decoderSupportedExtensions() =>
     StellatuneApi.instance.api.crateApiPlayerDecoderSupportedExtensions();
+
+/// Sets the app version checked against plugin manifests' `requires.host`. Call before
+/// the first plugin state sync.
+Future<void> pluginsSetHostVersion({required String version}) =>
+    StellatuneApi.instance.api.crateApiPlayerPluginsSetHostVersion(
+      version: version,
+    );
+
+Future<void> lyricsSetProviderPolicyJson({required String policyJson}) =>
+    StellatuneApi.instance.api.crateApiPlayerLyricsSetProviderPolicyJson(
+      policyJson: policyJson,
+    );
+
+Future<String> lyricsListProvidersJson() =>
+    StellatuneApi.instance.api.crateApiPlayerLyricsListProvidersJson();
+
+Future<String> lyricsTimingJson({required String trackKey}) =>
+    StellatuneApi.instance.api.crateApiPlayerLyricsTimingJson(
+      trackKey: trackKey,
+    );
+
+Future<String> lyricsEditTimingJson({
+  required String trackKey,
+  required String editJson,
+}) => StellatuneApi.instance.api.crateApiPlayerLyricsEditTimingJson(
+  trackKey: trackKey,
+  editJson: editJson,
+);
+
+Future<String> lyricsExportLrc({
+  required String trackKey,
+  required bool overwrite,
+}) => StellatuneApi.instance.api.crateApiPlayerLyricsExportLrc(
+  trackKey: trackKey,
+  overwrite: overwrite,
+);
+
+Future<String> sourceCapabilitiesJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+}) => StellatuneApi.instance.api.crateApiPlayerSourceCapabilitiesJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+);
+
+Future<String> sourceBrowseJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+  String? parentId,
+  required String pageJson,
+}) => StellatuneApi.instance.api.crateApiPlayerSourceBrowseJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+  parentId: parentId,
+  pageJson: pageJson,
+);
+
+Future<String> sourceSearchJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+  required String requestJson,
+}) => StellatuneApi.instance.api.crateApiPlayerSourceSearchJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+  requestJson: requestJson,
+);
+
+Future<String> sourceAuthStatusJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+}) => StellatuneApi.instance.api.crateApiPlayerSourceAuthStatusJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+);
+
+Future<String> sourceBeginLoginJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+  String? method,
+}) => StellatuneApi.instance.api.crateApiPlayerSourceBeginLoginJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+  method: method,
+);
+
+Future<String> sourcePollLoginJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+  required String sessionId,
+}) => StellatuneApi.instance.api.crateApiPlayerSourcePollLoginJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+  sessionId: sessionId,
+);
+
+Future<String> sourceRefreshAuthJson({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+}) => StellatuneApi.instance.api.crateApiPlayerSourceRefreshAuthJson(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+);
+
+Future<void> sourceLogout({
+  required String pluginId,
+  required String typeId,
+  required String configJson,
+}) => StellatuneApi.instance.api.crateApiPlayerSourceLogout(
+  pluginId: pluginId,
+  typeId: typeId,
+  configJson: configJson,
+);
+
+/// Loads the unpacked plugin at `root_dir` and reloads it on every rebuild. Outcomes
+/// arrive on [`plugin_runtime_events_global`] as `dev_reload` events.
+Future<void> pluginsDevWatch({required String rootDir}) =>
+    StellatuneApi.instance.api.crateApiPlayerPluginsDevWatch(rootDir: rootDir);
+
+/// Stops watching `root_dir` and unloads its dev plugin.
+Future<void> pluginsDevUnwatch({required String rootDir}) =>
+    StellatuneApi.instance.api.crateApiPlayerPluginsDevUnwatch(
+      rootDir: rootDir,
+    );
+
+Future<void> setOutputMixMode({required MixMode mode}) =>
+    StellatuneApi.instance.api.crateApiPlayerSetOutputMixMode(mode: mode);
+
+Future<void> setCrossfeed({required CrossfeedSettings settings}) =>
+    StellatuneApi.instance.api.crateApiPlayerSetCrossfeed(settings: settings);
+
+Future<CrossfeedSettings> crossfeedPresetSettings({
+  required CrossfeedPreset preset,
+}) => StellatuneApi.instance.api.crateApiPlayerCrossfeedPresetSettings(
+  preset: preset,
+);
+
+/// Loads a WAV impulse response into the output convolution stage; `None` removes it.
+Future<void> setConvolutionFilter({
+  String? wavPath,
+  required ImpulseLayout layout,
+  required int crossfadeMs,
+}) => StellatuneApi.instance.api.crateApiPlayerSetConvolutionFilter(
+  wavPath: wavPath,
+  layout: layout,
+  crossfadeMs: crossfadeMs,
+);
+
+Future<void> setOutputDither({required DitherSettings settings}) =>
+    StellatuneApi.instance.api.crateApiPlayerSetOutputDither(
+      settings: settings,
+    );
+
+/// Sets playback speed (0.5 to 3.0) and pitch shift (-12 to 12 semitones) independently.
+Future<void> setPlaybackRate({
+  required double speed,
+  required double pitchSemitones,
+}) => StellatuneApi.instance.api.crateApiPlayerSetPlaybackRate(
+  speed: speed,
+  pitchSemitones: pitchSemitones,
+);
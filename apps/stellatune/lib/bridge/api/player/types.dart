@@ -10,7 +10,7 @@ part 'types.freezed.dart';
 
 // These functions are ignored because they have generic arguments: `config`, `config`, `from_payload`, `metadata`, `payload`, `set_metadata`, `target`, `with_config_target`, `with_config`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TrackPlayability`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 // These functions are ignored (category: IgnoreBecauseExplicitAttribute): `as_str`
 
 enum AudioBackend { shared, wasapiExclusive }
@@ -39,6 +39,55 @@ class AudioDevice {
           name == other.name;
 }
 
+enum CrossfeedPreset { default_, chuMoy, janMeier }
+
+class CrossfeedSettings {
+  final bool enabled;
+
+  /// Clamped to 300..=2000 Hz.
+  final int cutoffHz;
+
+  /// Clamped to 1..=15 dB.
+  final double feedDb;
+
+  const CrossfeedSettings({
+    required this.enabled,
+    required this.cutoffHz,
+    required this.feedDb,
+  });
+
+  @override
+  int get hashCode => enabled.hashCode ^ cutoffHz.hashCode ^ feedDb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CrossfeedSettings &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          cutoffHz == other.cutoffHz &&
+          feedDb == other.feedDb;
+}
+
+/// Only takes effect for 16/24-bit output; float sinks are left untouched.
+class DitherSettings {
+  final bool tpdf;
+  final NoiseShaping noiseShaping;
+
+  const DitherSettings({required this.tpdf, required this.noiseShaping});
+
+  @override
+  int get hashCode => tpdf.hashCode ^ noiseShaping.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DitherSettings &&
+          runtimeType == other.runtimeType &&
+          tpdf == other.tpdf &&
+          noiseShaping == other.noiseShaping;
+}
+
 class DspChainItem {
   final String pluginId;
   final String typeId;
@@ -126,6 +175,17 @@ sealed class Event with _$Event {
   const factory Event.log({required String message}) = Event_Log;
 }
 
+enum ImpulseLayout {
+  /// One filter per output channel, or a single filter for all channels.
+  perChannel,
+
+  /// Four-channel stereo IR ordered L->L, L->R, R->L, R->R.
+  trueStereo;
+
+  static Future<ImpulseLayout> default_() =>
+      StellatuneApi.instance.api.crateApiPlayerTypesImpulseLayoutDefault();
+}
+
 enum LfeMode {
   mute,
   mixToFront;
@@ -165,6 +225,48 @@ class LyricsProviderTypeDescriptor {
           displayName == other.displayName;
 }
 
+@freezed
+sealed class MixMode with _$MixMode {
+  const MixMode._();
+
+  const factory MixMode.auto() = MixMode_Auto;
+  const factory MixMode.preset({required MixPreset preset}) = MixMode_Preset;
+
+  /// Gain matrix indexed as `rows[output_channel][input_channel]`.
+  const factory MixMode.custom({required List<Float32List> rows}) =
+      MixMode_Custom;
+
+  static Future<MixMode> default_() =>
+      StellatuneApi.instance.api.crateApiPlayerTypesMixModeDefault();
+}
+
+@freezed
+sealed class MixPreset with _$MixPreset {
+  const MixPreset._();
+
+  /// `-1.0` is hard left, `1.0` hard right.
+  const factory MixPreset.balance({required double balance}) =
+      MixPreset_Balance;
+  const factory MixPreset.swapLeftRight() = MixPreset_SwapLeftRight;
+  const factory MixPreset.monoSum() = MixPreset_MonoSum;
+
+  /// `0.0` collapses to mono, `1.0` is unchanged, up to `2.0` widens.
+  const factory MixPreset.stereoWidth({required double width}) =
+      MixPreset_StereoWidth;
+  const factory MixPreset.matrixSurroundUpmix() = MixPreset_MatrixSurroundUpmix;
+  const factory MixPreset.ambienceUpmix() = MixPreset_AmbienceUpmix;
+}
+
+enum NoiseShaping {
+  off,
+  firstOrder,
+  lipshitz,
+  wannamaker;
+
+  static Future<NoiseShaping> default_() =>
+      StellatuneApi.instance.api.crateApiPlayerTypesNoiseShapingDefault();
+}
+
 class OutputSinkRoute {
   final String pluginId;
   final String typeId;
@@ -279,7 +381,14 @@ class PluginRuntimeEvent {
           payloadJson == other.payloadJson;
 }
 
-enum PluginRuntimeKind { notify, control, controlResult, controlFinished }
+enum PluginRuntimeKind {
+  notify,
+  control,
+  controlResult,
+  controlFinished,
+  devReload,
+  sidecarState,
+}
 
 enum ResampleQuality {
   fast,
@@ -745,4 +745,792 @@ as String,
 
 }
 
+/// @nodoc
+mixin _$MixMode {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixMode);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MixMode()';
+}
+
+
+}
+
+/// @nodoc
+class $MixModeCopyWith<$Res>  {
+$MixModeCopyWith(MixMode _, $Res Function(MixMode) __);
+}
+
+
+/// Adds pattern-matching-related methods to [MixMode].
+extension MixModePatterns on MixMode {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( MixMode_Auto value)?  auto,TResult Function( MixMode_Preset value)?  preset,TResult Function( MixMode_Custom value)?  custom,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case MixMode_Auto() when auto != null:
+return auto(_that);case MixMode_Preset() when preset != null:
+return preset(_that);case MixMode_Custom() when custom != null:
+return custom(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( MixMode_Auto value)  auto,required TResult Function( MixMode_Preset value)  preset,required TResult Function( MixMode_Custom value)  custom,}){
+final _that = this;
+switch (_that) {
+case MixMode_Auto():
+return auto(_that);case MixMode_Preset():
+return preset(_that);case MixMode_Custom():
+return custom(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( MixMode_Auto value)?  auto,TResult? Function( MixMode_Preset value)?  preset,TResult? Function( MixMode_Custom value)?  custom,}){
+final _that = this;
+switch (_that) {
+case MixMode_Auto() when auto != null:
+return auto(_that);case MixMode_Preset() when preset != null:
+return preset(_that);case MixMode_Custom() when custom != null:
+return custom(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  auto,TResult Function( MixPreset preset)?  preset,TResult Function( List<Float32List> rows)?  custom,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case MixMode_Auto() when auto != null:
+return auto();case MixMode_Preset() when preset != null:
+return preset(_that.preset);case MixMode_Custom() when custom != null:
+return custom(_that.rows);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  auto,required TResult Function( MixPreset preset)  preset,required TResult Function( List<Float32List> rows)  custom,}) {final _that = this;
+switch (_that) {
+case MixMode_Auto():
+return auto();case MixMode_Preset():
+return preset(_that.preset);case MixMode_Custom():
+return custom(_that.rows);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  auto,TResult? Function( MixPreset preset)?  preset,TResult? Function( List<Float32List> rows)?  custom,}) {final _that = this;
+switch (_that) {
+case MixMode_Auto() when auto != null:
+return auto();case MixMode_Preset() when preset != null:
+return preset(_that.preset);case MixMode_Custom() when custom != null:
+return custom(_that.rows);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class MixMode_Auto extends MixMode {
+  const MixMode_Auto(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixMode_Auto);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MixMode.auto()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class MixMode_Preset extends MixMode {
+  const MixMode_Preset({required this.preset}): super._();
+  
+
+ final  MixPreset preset;
+
+/// Create a copy of MixMode
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MixMode_PresetCopyWith<MixMode_Preset> get copyWith => _$MixMode_PresetCopyWithImpl<MixMode_Preset>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixMode_Preset&&(identical(other.preset, preset) || other.preset == preset));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,preset);
+
+@override
+String toString() {
+  return 'MixMode.preset(preset: $preset)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MixMode_PresetCopyWith<$Res> implements $MixModeCopyWith<$Res> {
+  factory $MixMode_PresetCopyWith(MixMode_Preset value, $Res Function(MixMode_Preset) _then) = _$MixMode_PresetCopyWithImpl;
+@useResult
+$Res call({
+ MixPreset preset
+});
+
+
+$MixPresetCopyWith<$Res> get preset;
+
+}
+/// @nodoc
+class _$MixMode_PresetCopyWithImpl<$Res>
+    implements $MixMode_PresetCopyWith<$Res> {
+  _$MixMode_PresetCopyWithImpl(this._self, this._then);
+
+  final MixMode_Preset _self;
+  final $Res Function(MixMode_Preset) _then;
+
+/// Create a copy of MixMode
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? preset = null,}) {
+  return _then(MixMode_Preset(
+preset: null == preset ? _self.preset : preset // ignore: cast_nullable_to_non_nullable
+as MixPreset,
+  ));
+}
+
+/// Create a copy of MixMode
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$MixPresetCopyWith<$Res> get preset {
+
+  return $MixPresetCopyWith<$Res>(_self.preset, (value) {
+    return _then(_self.copyWith(preset: value));
+  });
+}
+}
+
+/// Gain matrix indexed as `rows[output_channel][input_channel]`.
+
+
+class MixMode_Custom extends MixMode {
+  const MixMode_Custom({required final  List<Float32List> rows}): _rows = rows,super._();
+  
+
+ final  List<Float32List> _rows;
+ List<Float32List> get rows {
+  if (_rows is EqualUnmodifiableListView) return _rows;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_rows);
+}
+
+/// Create a copy of MixMode
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MixMode_CustomCopyWith<MixMode_Custom> get copyWith => _$MixMode_CustomCopyWithImpl<MixMode_Custom>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixMode_Custom&&const DeepCollectionEquality().equals(other._rows, _rows));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_rows));
+
+@override
+String toString() {
+  return 'MixMode.custom(rows: $rows)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MixMode_CustomCopyWith<$Res> implements $MixModeCopyWith<$Res> {
+  factory $MixMode_CustomCopyWith(MixMode_Custom value, $Res Function(MixMode_Custom) _then) = _$MixMode_CustomCopyWithImpl;
+@useResult
+$Res call({
+ List<Float32List> rows
+});
+
+
+
+
+}
+/// @nodoc
+class _$MixMode_CustomCopyWithImpl<$Res>
+    implements $MixMode_CustomCopyWith<$Res> {
+  _$MixMode_CustomCopyWithImpl(this._self, this._then);
+
+  final MixMode_Custom _self;
+  final $Res Function(MixMode_Custom) _then;
+
+/// Create a copy of MixMode
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? rows = null,}) {
+  return _then(MixMode_Custom(
+rows: null == rows ? _self._rows : rows // ignore: cast_nullable_to_non_nullable
+as List<Float32List>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$MixPreset {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixPreset);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MixPreset()';
+}
+
+
+}
+
+/// @nodoc
+class $MixPresetCopyWith<$Res>  {
+$MixPresetCopyWith(MixPreset _, $Res Function(MixPreset) __);
+}
+
+
+/// Adds pattern-matching-related methods to [MixPreset].
+extension MixPresetPatterns on MixPreset {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( MixPreset_Balance value)?  balance,TResult Function( MixPreset_SwapLeftRight value)?  swapLeftRight,TResult Function( MixPreset_MonoSum value)?  monoSum,TResult Function( MixPreset_StereoWidth value)?  stereoWidth,TResult Function( MixPreset_MatrixSurroundUpmix value)?  matrixSurroundUpmix,TResult Function( MixPreset_AmbienceUpmix value)?  ambienceUpmix,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case MixPreset_Balance() when balance != null:
+return balance(_that);case MixPreset_SwapLeftRight() when swapLeftRight != null:
+return swapLeftRight(_that);case MixPreset_MonoSum() when monoSum != null:
+return monoSum(_that);case MixPreset_StereoWidth() when stereoWidth != null:
+return stereoWidth(_that);case MixPreset_MatrixSurroundUpmix() when matrixSurroundUpmix != null:
+return matrixSurroundUpmix(_that);case MixPreset_AmbienceUpmix() when ambienceUpmix != null:
+return ambienceUpmix(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( MixPreset_Balance value)  balance,required TResult Function( MixPreset_SwapLeftRight value)  swapLeftRight,required TResult Function( MixPreset_MonoSum value)  monoSum,required TResult Function( MixPreset_StereoWidth value)  stereoWidth,required TResult Function( MixPreset_MatrixSurroundUpmix value)  matrixSurroundUpmix,required TResult Function( MixPreset_AmbienceUpmix value)  ambienceUpmix,}){
+final _that = this;
+switch (_that) {
+case MixPreset_Balance():
+return balance(_that);case MixPreset_SwapLeftRight():
+return swapLeftRight(_that);case MixPreset_MonoSum():
+return monoSum(_that);case MixPreset_StereoWidth():
+return stereoWidth(_that);case MixPreset_MatrixSurroundUpmix():
+return matrixSurroundUpmix(_that);case MixPreset_AmbienceUpmix():
+return ambienceUpmix(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( MixPreset_Balance value)?  balance,TResult? Function( MixPreset_SwapLeftRight value)?  swapLeftRight,TResult? Function( MixPreset_MonoSum value)?  monoSum,TResult? Function( MixPreset_StereoWidth value)?  stereoWidth,TResult? Function( MixPreset_MatrixSurroundUpmix value)?  matrixSurroundUpmix,TResult? Function( MixPreset_AmbienceUpmix value)?  ambienceUpmix,}){
+final _that = this;
+switch (_that) {
+case MixPreset_Balance() when balance != null:
+return balance(_that);case MixPreset_SwapLeftRight() when swapLeftRight != null:
+return swapLeftRight(_that);case MixPreset_MonoSum() when monoSum != null:
+return monoSum(_that);case MixPreset_StereoWidth() when stereoWidth != null:
+return stereoWidth(_that);case MixPreset_MatrixSurroundUpmix() when matrixSurroundUpmix != null:
+return matrixSurroundUpmix(_that);case MixPreset_AmbienceUpmix() when ambienceUpmix != null:
+return ambienceUpmix(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( double balance)?  balance,TResult Function()?  swapLeftRight,TResult Function()?  monoSum,TResult Function( double width)?  stereoWidth,TResult Function()?  matrixSurroundUpmix,TResult Function()?  ambienceUpmix,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case MixPreset_Balance() when balance != null:
+return balance(_that.balance);case MixPreset_SwapLeftRight() when swapLeftRight != null:
+return swapLeftRight();case MixPreset_MonoSum() when monoSum != null:
+return monoSum();case MixPreset_StereoWidth() when stereoWidth != null:
+return stereoWidth(_that.width);case MixPreset_MatrixSurroundUpmix() when matrixSurroundUpmix != null:
+return matrixSurroundUpmix();case MixPreset_AmbienceUpmix() when ambienceUpmix != null:
+return ambienceUpmix();case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( double balance)  balance,required TResult Function()  swapLeftRight,required TResult Function()  monoSum,required TResult Function( double width)  stereoWidth,required TResult Function()  matrixSurroundUpmix,required TResult Function()  ambienceUpmix,}) {final _that = this;
+switch (_that) {
+case MixPreset_Balance():
+return balance(_that.balance);case MixPreset_SwapLeftRight():
+return swapLeftRight();case MixPreset_MonoSum():
+return monoSum();case MixPreset_StereoWidth():
+return stereoWidth(_that.width);case MixPreset_MatrixSurroundUpmix():
+return matrixSurroundUpmix();case MixPreset_AmbienceUpmix():
+return ambienceUpmix();}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( double balance)?  balance,TResult? Function()?  swapLeftRight,TResult? Function()?  monoSum,TResult? Function( double width)?  stereoWidth,TResult? Function()?  matrixSurroundUpmix,TResult? Function()?  ambienceUpmix,}) {final _that = this;
+switch (_that) {
+case MixPreset_Balance() when balance != null:
+return balance(_that.balance);case MixPreset_SwapLeftRight() when swapLeftRight != null:
+return swapLeftRight();case MixPreset_MonoSum() when monoSum != null:
+return monoSum();case MixPreset_StereoWidth() when stereoWidth != null:
+return stereoWidth(_that.width);case MixPreset_MatrixSurroundUpmix() when matrixSurroundUpmix != null:
+return matrixSurroundUpmix();case MixPreset_AmbienceUpmix() when ambienceUpmix != null:
+return ambienceUpmix();case _:
+  return null;
+
+}
+}
+
+}
+
+/// `-1.0` is hard left, `1.0` hard right.
+
+
+class MixPreset_Balance extends MixPreset {
+  const MixPreset_Balance({required this.balance}): super._();
+  
+
+ final  double balance;
+
+/// Create a copy of MixPreset
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MixPreset_BalanceCopyWith<MixPreset_Balance> get copyWith => _$MixPreset_BalanceCopyWithImpl<MixPreset_Balance>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixPreset_Balance&&(identical(other.balance, balance) || other.balance == balance));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,balance);
+
+@override
+String toString() {
+  return 'MixPreset.balance(balance: $balance)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MixPreset_BalanceCopyWith<$Res> implements $MixPresetCopyWith<$Res> {
+  factory $MixPreset_BalanceCopyWith(MixPreset_Balance value, $Res Function(MixPreset_Balance) _then) = _$MixPreset_BalanceCopyWithImpl;
+@useResult
+$Res call({
+ double balance
+});
+
+
+
+
+}
+/// @nodoc
+class _$MixPreset_BalanceCopyWithImpl<$Res>
+    implements $MixPreset_BalanceCopyWith<$Res> {
+  _$MixPreset_BalanceCopyWithImpl(this._self, this._then);
+
+  final MixPreset_Balance _self;
+  final $Res Function(MixPreset_Balance) _then;
+
+/// Create a copy of MixPreset
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? balance = null,}) {
+  return _then(MixPreset_Balance(
+balance: null == balance ? _self.balance : balance // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MixPreset_SwapLeftRight extends MixPreset {
+  const MixPreset_SwapLeftRight(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixPreset_SwapLeftRight);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MixPreset.swapLeftRight()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class MixPreset_MonoSum extends MixPreset {
+  const MixPreset_MonoSum(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixPreset_MonoSum);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MixPreset.monoSum()';
+}
+
+
+}
+
+
+
+
+/// `0.0` collapses to mono, `1.0` is unchanged, up to `2.0` widens.
+
+
+class MixPreset_StereoWidth extends MixPreset {
+  const MixPreset_StereoWidth({required this.width}): super._();
+  
+
+ final  double width;
+
+/// Create a copy of MixPreset
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MixPreset_StereoWidthCopyWith<MixPreset_StereoWidth> get copyWith => _$MixPreset_StereoWidthCopyWithImpl<MixPreset_StereoWidth>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixPreset_StereoWidth&&(identical(other.width, width) || other.width == width));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,width);
+
+@override
+String toString() {
+  return 'MixPreset.stereoWidth(width: $width)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MixPreset_StereoWidthCopyWith<$Res> implements $MixPresetCopyWith<$Res> {
+  factory $MixPreset_StereoWidthCopyWith(MixPreset_StereoWidth value, $Res Function(MixPreset_StereoWidth) _then) = _$MixPreset_StereoWidthCopyWithImpl;
+@useResult
+$Res call({
+ double width
+});
+
+
+
+
+}
+/// @nodoc
+class _$MixPreset_StereoWidthCopyWithImpl<$Res>
+    implements $MixPreset_StereoWidthCopyWith<$Res> {
+  _$MixPreset_StereoWidthCopyWithImpl(this._self, this._then);
+
+  final MixPreset_StereoWidth _self;
+  final $Res Function(MixPreset_StereoWidth) _then;
+
+/// Create a copy of MixPreset
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? width = null,}) {
+  return _then(MixPreset_StereoWidth(
+width: null == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MixPreset_MatrixSurroundUpmix extends MixPreset {
+  const MixPreset_MatrixSurroundUpmix(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixPreset_MatrixSurroundUpmix);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MixPreset.matrixSurroundUpmix()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class MixPreset_AmbienceUpmix extends MixPreset {
+  const MixPreset_AmbienceUpmix(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MixPreset_AmbienceUpmix);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'MixPreset.ambienceUpmix()';
+}
+
+
+}
+
+
+
+
 // dart format on
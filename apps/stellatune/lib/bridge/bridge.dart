@@ -112,8 +112,33 @@ class PlayerBridge {
   Future<void> lyricsSetPositionMs(int positionMs) =>
       api.lyricsSetPositionMs(positionMs: BigInt.from(positionMs));
 
+  Future<void> lyricsSetProviderPolicyJson(String policyJson) =>
+      api.lyricsSetProviderPolicyJson(policyJson: policyJson);
+
+  Future<String> lyricsListProvidersJson() => api.lyricsListProvidersJson();
+
+  Future<String> lyricsTimingJson(String trackKey) =>
+      api.lyricsTimingJson(trackKey: trackKey);
+
+  Future<String> lyricsEditTimingJson({
+    required String trackKey,
+    required String editJson,
+  }) => api.lyricsEditTimingJson(trackKey: trackKey, editJson: editJson);
+
+  Future<String> lyricsExportLrc(String trackKey, {bool overwrite = false}) =>
+      api.lyricsExportLrc(trackKey: trackKey, overwrite: overwrite);
+
   Future<List<PluginDescriptor>> pluginsList() => api.pluginsList();
 
+  Future<void> pluginsSetHostVersion(String version) =>
+      api.pluginsSetHostVersion(version: version);
+
+  Future<void> pluginsDevWatch(String rootDir) =>
+      api.pluginsDevWatch(rootDir: rootDir);
+
+  Future<void> pluginsDevUnwatch(String rootDir) =>
+      api.pluginsDevUnwatch(rootDir: rootDir);
+
   Future<List<SourceCatalogTypeDescriptor>> sourceListTypes() =>
       api.sourceListTypes();
 
@@ -135,6 +160,96 @@ class PlayerBridge {
     requestJson: requestJson,
   );
 
+  Future<String> sourceCapabilitiesJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) => api.sourceCapabilitiesJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+  );
+
+  Future<String> sourceBrowseJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    String? parentId,
+    required String pageJson,
+  }) => api.sourceBrowseJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+    parentId: parentId,
+    pageJson: pageJson,
+  );
+
+  Future<String> sourceSearchJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    required String requestJson,
+  }) => api.sourceSearchJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+    requestJson: requestJson,
+  );
+
+  Future<String> sourceAuthStatusJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) => api.sourceAuthStatusJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+  );
+
+  Future<String> sourceBeginLoginJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    String? method,
+  }) => api.sourceBeginLoginJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+    method: method,
+  );
+
+  Future<String> sourcePollLoginJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    required String sessionId,
+  }) => api.sourcePollLoginJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+    sessionId: sessionId,
+  );
+
+  Future<String> sourceRefreshAuthJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) => api.sourceRefreshAuthJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+  );
+
+  Future<void> sourceLogout({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) => api.sourceLogout(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+  );
+
   Future<String> lyricsProviderSearchJson({
     required String pluginId,
     required String typeId,
@@ -376,6 +491,31 @@ class LibraryBridge {
 
   Future<List<String>> listDisabledPluginIds() =>
       api.libraryListDisabledPluginIds();
+
+  Future<String> pluginConfigLoadJson({
+    required String pluginId,
+    required String typeId,
+  }) => api.libraryPluginConfigLoadJson(pluginId: pluginId, typeId: typeId);
+
+  Future<String> pluginConfigValidateJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) => api.libraryPluginConfigValidateJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+  );
+
+  Future<String> pluginConfigSaveJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) => api.libraryPluginConfigSaveJson(
+    pluginId: pluginId,
+    typeId: typeId,
+    configJson: configJson,
+  );
 }
 
 class DlnaBridge {
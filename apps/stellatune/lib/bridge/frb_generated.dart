@@ -433,6 +433,141 @@ abstract class StellatuneApiApi extends BaseApi {
   });
 
   Future<String> crateApiPlayerTypesTrackRefStableKey({required TrackRef that});
+
+  Future<String> crateApiLibraryLibraryPluginConfigLoadJson({
+    required String pluginId,
+    required String typeId,
+  });
+
+  Future<String> crateApiLibraryLibraryPluginConfigValidateJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  });
+
+  Future<String> crateApiLibraryLibraryPluginConfigSaveJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  });
+
+  Future<void> crateApiPlayerPluginsSetHostVersion({required String version});
+
+  Future<void> crateApiPlayerLyricsSetProviderPolicyJson({
+    required String policyJson,
+  });
+
+  Future<String> crateApiPlayerLyricsListProvidersJson();
+
+  Future<String> crateApiPlayerLyricsTimingJson({required String trackKey});
+
+  Future<String> crateApiPlayerLyricsEditTimingJson({
+    required String trackKey,
+    required String editJson,
+  });
+
+  Future<String> crateApiPlayerLyricsExportLrc({
+    required String trackKey,
+    required bool overwrite,
+  });
+
+  Future<String> crateApiPlayerSourceCapabilitiesJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  });
+
+  Future<String> crateApiPlayerSourceBrowseJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    String? parentId,
+    required String pageJson,
+  });
+
+  Future<String> crateApiPlayerSourceSearchJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    required String requestJson,
+  });
+
+  Future<String> crateApiPlayerSourceAuthStatusJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  });
+
+  Future<String> crateApiPlayerSourceBeginLoginJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    String? method,
+  });
+
+  Future<String> crateApiPlayerSourcePollLoginJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    required String sessionId,
+  });
+
+  Future<String> crateApiPlayerSourceRefreshAuthJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  });
+
+  Future<void> crateApiPlayerSourceLogout({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  });
+
+  Future<void> crateApiPlayerPluginsDevWatch({required String rootDir});
+
+  Future<void> crateApiPlayerPluginsDevUnwatch({required String rootDir});
+
+  Future<DlnaHttpStreamInfo> crateApiDlnaDlnaHttpStreamStart({
+    required DlnaStreamFormat format,
+    int? clientBufferMs,
+    required bool disconnectSlowClients,
+  });
+
+  Future<void> crateApiDlnaDlnaHttpStreamStop();
+
+  Future<List<DlnaHttpStreamClient>> crateApiDlnaDlnaHttpStreamClients();
+
+  Future<MixMode> crateApiPlayerTypesMixModeDefault();
+
+  Future<void> crateApiPlayerSetOutputMixMode({required MixMode mode});
+
+  Future<void> crateApiPlayerSetCrossfeed({
+    required CrossfeedSettings settings,
+  });
+
+  Future<CrossfeedSettings> crateApiPlayerCrossfeedPresetSettings({
+    required CrossfeedPreset preset,
+  });
+
+  Future<ImpulseLayout> crateApiPlayerTypesImpulseLayoutDefault();
+
+  Future<void> crateApiPlayerSetConvolutionFilter({
+    String? wavPath,
+    required ImpulseLayout layout,
+    required int crossfadeMs,
+  });
+
+  Future<NoiseShaping> crateApiPlayerTypesNoiseShapingDefault();
+
+  Future<void> crateApiPlayerSetOutputDither({
+    required DitherSettings settings,
+  });
+
+  Future<void> crateApiPlayerSetPlaybackRate({
+    required double speed,
+    required double pitchSemitones,
+  });
 }
 
 class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
@@ -3504,72 +3639,1124 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
         argNames: ["that"],
       );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<String> crateApiLibraryLibraryPluginConfigLoadJson({
+    required String pluginId,
+    required String typeId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLibraryLibraryPluginConfigLoadJsonConstMeta,
+        argValues: [pluginId, typeId],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  RustStreamSink<Event> dco_decode_StreamSink_event_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  TaskConstMeta get kCrateApiLibraryLibraryPluginConfigLoadJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_plugin_config_load_json",
+        argNames: ["pluginId", "typeId"],
+      );
 
-  @protected
-  RustStreamSink<LibraryEvent> dco_decode_StreamSink_library_event_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  @override
+  Future<String> crateApiLibraryLibraryPluginConfigValidateJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLibraryLibraryPluginConfigValidateJsonConstMeta,
+        argValues: [pluginId, typeId, configJson],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  RustStreamSink<LyricsEvent> dco_decode_StreamSink_lyrics_event_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  TaskConstMeta get kCrateApiLibraryLibraryPluginConfigValidateJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_plugin_config_validate_json",
+        argNames: ["pluginId", "typeId", "configJson"],
+      );
 
-  @protected
-  RustStreamSink<PluginRuntimeEvent>
-  dco_decode_StreamSink_plugin_runtime_event_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  @override
+  Future<String> crateApiLibraryLibraryPluginConfigSaveJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLibraryLibraryPluginConfigSaveJsonConstMeta,
+        argValues: [pluginId, typeId, configJson],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiLibraryLibraryPluginConfigSaveJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_plugin_config_save_json",
+        argNames: ["pluginId", "typeId", "configJson"],
+      );
 
-  @protected
-  AudioBackend dco_decode_audio_backend(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioBackend.values[raw as int];
+  @override
+  Future<void> crateApiPlayerPluginsSetHostVersion({required String version}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(version, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerPluginsSetHostVersionConstMeta,
+        argValues: [version],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  AudioDevice dco_decode_audio_device(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return AudioDevice(
-      backend: dco_decode_audio_backend(arr[0]),
-      id: dco_decode_String(arr[1]),
-      name: dco_decode_String(arr[2]),
+  TaskConstMeta get kCrateApiPlayerPluginsSetHostVersionConstMeta =>
+      const TaskConstMeta(
+        debugName: "plugins_set_host_version",
+        argNames: ["version"],
+      );
+
+  @override
+  Future<void> crateApiPlayerLyricsSetProviderPolicyJson({
+    required String policyJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(policyJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerLyricsSetProviderPolicyJsonConstMeta,
+        argValues: [policyJson],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
+  TaskConstMeta get kCrateApiPlayerLyricsSetProviderPolicyJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "lyrics_set_provider_policy_json",
+        argNames: ["policyJson"],
+      );
+
+  @override
+  Future<String> crateApiPlayerLyricsListProvidersJson() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerLyricsListProvidersJsonConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerLyricsListProvidersJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "lyrics_list_providers_json",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiPlayerLyricsTimingJson({required String trackKey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(trackKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerLyricsTimingJsonConstMeta,
+        argValues: [trackKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerLyricsTimingJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "lyrics_timing_json",
+        argNames: ["trackKey"],
+      );
+
+  @override
+  Future<String> crateApiPlayerLyricsEditTimingJson({
+    required String trackKey,
+    required String editJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(trackKey, serializer);
+          sse_encode_String(editJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerLyricsEditTimingJsonConstMeta,
+        argValues: [trackKey, editJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerLyricsEditTimingJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "lyrics_edit_timing_json",
+        argNames: ["trackKey", "editJson"],
+      );
+
+  @override
+  Future<String> crateApiPlayerLyricsExportLrc({
+    required String trackKey,
+    required bool overwrite,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(trackKey, serializer);
+          sse_encode_bool(overwrite, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerLyricsExportLrcConstMeta,
+        argValues: [trackKey, overwrite],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerLyricsExportLrcConstMeta =>
+      const TaskConstMeta(
+        debugName: "lyrics_export_lrc",
+        argNames: ["trackKey", "overwrite"],
+      );
+
+  @override
+  Future<String> crateApiPlayerSourceCapabilitiesJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSourceCapabilitiesJsonConstMeta,
+        argValues: [pluginId, typeId, configJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSourceCapabilitiesJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_capabilities_json",
+        argNames: ["pluginId", "typeId", "configJson"],
+      );
+
+  @override
+  Future<String> crateApiPlayerSourceBrowseJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    String? parentId,
+    required String pageJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          sse_encode_opt_String(parentId, serializer);
+          sse_encode_String(pageJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSourceBrowseJsonConstMeta,
+        argValues: [pluginId, typeId, configJson, parentId, pageJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSourceBrowseJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_browse_json",
+        argNames: ["pluginId", "typeId", "configJson", "parentId", "pageJson"],
+      );
+
+  @override
+  Future<String> crateApiPlayerSourceSearchJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    required String requestJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          sse_encode_String(requestJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSourceSearchJsonConstMeta,
+        argValues: [pluginId, typeId, configJson, requestJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSourceSearchJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_search_json",
+        argNames: ["pluginId", "typeId", "configJson", "requestJson"],
+      );
+
+  @override
+  Future<String> crateApiPlayerSourceAuthStatusJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSourceAuthStatusJsonConstMeta,
+        argValues: [pluginId, typeId, configJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSourceAuthStatusJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_auth_status_json",
+        argNames: ["pluginId", "typeId", "configJson"],
+      );
+
+  @override
+  Future<String> crateApiPlayerSourceBeginLoginJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    String? method,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          sse_encode_opt_String(method, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSourceBeginLoginJsonConstMeta,
+        argValues: [pluginId, typeId, configJson, method],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSourceBeginLoginJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_begin_login_json",
+        argNames: ["pluginId", "typeId", "configJson", "method"],
+      );
+
+  @override
+  Future<String> crateApiPlayerSourcePollLoginJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+    required String sessionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSourcePollLoginJsonConstMeta,
+        argValues: [pluginId, typeId, configJson, sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSourcePollLoginJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_poll_login_json",
+        argNames: ["pluginId", "typeId", "configJson", "sessionId"],
+      );
+
+  @override
+  Future<String> crateApiPlayerSourceRefreshAuthJson({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSourceRefreshAuthJsonConstMeta,
+        argValues: [pluginId, typeId, configJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSourceRefreshAuthJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_refresh_auth_json",
+        argNames: ["pluginId", "typeId", "configJson"],
+      );
+
+  @override
+  Future<void> crateApiPlayerSourceLogout({
+    required String pluginId,
+    required String typeId,
+    required String configJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pluginId, serializer);
+          sse_encode_String(typeId, serializer);
+          sse_encode_String(configJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSourceLogoutConstMeta,
+        argValues: [pluginId, typeId, configJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSourceLogoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "source_logout",
+        argNames: ["pluginId", "typeId", "configJson"],
+      );
+
+  @override
+  Future<void> crateApiPlayerPluginsDevWatch({required String rootDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(rootDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerPluginsDevWatchConstMeta,
+        argValues: [rootDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerPluginsDevWatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "plugins_dev_watch",
+        argNames: ["rootDir"],
+      );
+
+  @override
+  Future<void> crateApiPlayerPluginsDevUnwatch({required String rootDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(rootDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerPluginsDevUnwatchConstMeta,
+        argValues: [rootDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerPluginsDevUnwatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "plugins_dev_unwatch",
+        argNames: ["rootDir"],
+      );
+
+  @override
+  Future<DlnaHttpStreamInfo> crateApiDlnaDlnaHttpStreamStart({
+    required DlnaStreamFormat format,
+    int? clientBufferMs,
+    required bool disconnectSlowClients,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_dlna_stream_format(format, serializer);
+          sse_encode_opt_box_autoadd_u_32(clientBufferMs, serializer);
+          sse_encode_bool(disconnectSlowClients, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dlna_http_stream_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDlnaDlnaHttpStreamStartConstMeta,
+        argValues: [format, clientBufferMs, disconnectSlowClients],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDlnaDlnaHttpStreamStartConstMeta =>
+      const TaskConstMeta(
+        debugName: "dlna_http_stream_start",
+        argNames: ["format", "clientBufferMs", "disconnectSlowClients"],
+      );
+
+  @override
+  Future<void> crateApiDlnaDlnaHttpStreamStop() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDlnaDlnaHttpStreamStopConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDlnaDlnaHttpStreamStopConstMeta =>
+      const TaskConstMeta(debugName: "dlna_http_stream_stop", argNames: []);
+
+  @override
+  Future<List<DlnaHttpStreamClient>> crateApiDlnaDlnaHttpStreamClients() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_dlna_http_stream_client,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDlnaDlnaHttpStreamClientsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDlnaDlnaHttpStreamClientsConstMeta =>
+      const TaskConstMeta(debugName: "dlna_http_stream_clients", argNames: []);
+
+  @override
+  Future<MixMode> crateApiPlayerTypesMixModeDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_mix_mode,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPlayerTypesMixModeDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerTypesMixModeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "mix_mode_default", argNames: []);
+
+  @override
+  Future<void> crateApiPlayerSetOutputMixMode({required MixMode mode}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_mix_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSetOutputMixModeConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSetOutputMixModeConstMeta =>
+      const TaskConstMeta(debugName: "set_output_mix_mode", argNames: ["mode"]);
+
+  @override
+  Future<void> crateApiPlayerSetCrossfeed({
+    required CrossfeedSettings settings,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_crossfeed_settings(settings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSetCrossfeedConstMeta,
+        argValues: [settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSetCrossfeedConstMeta =>
+      const TaskConstMeta(debugName: "set_crossfeed", argNames: ["settings"]);
+
+  @override
+  Future<CrossfeedSettings> crateApiPlayerCrossfeedPresetSettings({
+    required CrossfeedPreset preset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_crossfeed_preset(preset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_crossfeed_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPlayerCrossfeedPresetSettingsConstMeta,
+        argValues: [preset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerCrossfeedPresetSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "crossfeed_preset_settings",
+        argNames: ["preset"],
+      );
+
+  @override
+  Future<ImpulseLayout> crateApiPlayerTypesImpulseLayoutDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_impulse_layout,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPlayerTypesImpulseLayoutDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerTypesImpulseLayoutDefaultConstMeta =>
+      const TaskConstMeta(debugName: "impulse_layout_default", argNames: []);
+
+  @override
+  Future<void> crateApiPlayerSetConvolutionFilter({
+    String? wavPath,
+    required ImpulseLayout layout,
+    required int crossfadeMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(wavPath, serializer);
+          sse_encode_impulse_layout(layout, serializer);
+          sse_encode_u_32(crossfadeMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSetConvolutionFilterConstMeta,
+        argValues: [wavPath, layout, crossfadeMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSetConvolutionFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_convolution_filter",
+        argNames: ["wavPath", "layout", "crossfadeMs"],
+      );
+
+  @override
+  Future<NoiseShaping> crateApiPlayerTypesNoiseShapingDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_noise_shaping,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPlayerTypesNoiseShapingDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerTypesNoiseShapingDefaultConstMeta =>
+      const TaskConstMeta(debugName: "noise_shaping_default", argNames: []);
+
+  @override
+  Future<void> crateApiPlayerSetOutputDither({
+    required DitherSettings settings,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_dither_settings(settings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSetOutputDitherConstMeta,
+        argValues: [settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSetOutputDitherConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_output_dither",
+        argNames: ["settings"],
+      );
+
+  @override
+  Future<void> crateApiPlayerSetPlaybackRate({
+    required double speed,
+    required double pitchSemitones,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_f_32(speed, serializer);
+          sse_encode_f_32(pitchSemitones, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPlayerSetPlaybackRateConstMeta,
+        argValues: [speed, pitchSemitones],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlayerSetPlaybackRateConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_playback_rate",
+        argNames: ["speed", "pitchSemitones"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<Event> dco_decode_StreamSink_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LibraryEvent> dco_decode_StreamSink_library_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LyricsEvent> dco_decode_StreamSink_lyrics_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<PluginRuntimeEvent>
+  dco_decode_StreamSink_plugin_runtime_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AudioBackend dco_decode_audio_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioBackend.values[raw as int];
+  }
+
+  @protected
+  AudioDevice dco_decode_audio_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AudioDevice(
+      backend: dco_decode_audio_backend(arr[0]),
+      id: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as bool;
   }
 
+  @protected
+  CrossfeedSettings dco_decode_box_autoadd_crossfeed_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_crossfeed_settings(raw);
+  }
+
+  @protected
+  DitherSettings dco_decode_box_autoadd_dither_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_dither_settings(raw);
+  }
+
   @protected
   DlnaRenderer dco_decode_box_autoadd_dlna_renderer(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3594,6 +4781,18 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return dco_decode_lyrics_query(raw);
   }
 
+  @protected
+  MixMode dco_decode_box_autoadd_mix_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_mix_mode(raw);
+  }
+
+  @protected
+  MixPreset dco_decode_box_autoadd_mix_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_mix_preset(raw);
+  }
+
   @protected
   OutputSinkRoute dco_decode_box_autoadd_output_sink_route(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3618,12 +4817,49 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return raw as int;
   }
 
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_u_64(raw);
   }
 
+  @protected
+  CrossfeedPreset dco_decode_crossfeed_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CrossfeedPreset.values[raw as int];
+  }
+
+  @protected
+  CrossfeedSettings dco_decode_crossfeed_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CrossfeedSettings(
+      enabled: dco_decode_bool(arr[0]),
+      cutoffHz: dco_decode_u_32(arr[1]),
+      feedDb: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  DitherSettings dco_decode_dither_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DitherSettings(
+      tpdf: dco_decode_bool(arr[0]),
+      noiseShaping: dco_decode_noise_shaping(arr[1]),
+    );
+  }
+
   @protected
   DlnaHttpServerInfo dco_decode_dlna_http_server_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3636,6 +4872,32 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     );
   }
 
+  @protected
+  DlnaHttpStreamClient dco_decode_dlna_http_stream_client(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DlnaHttpStreamClient(
+      remoteAddr: dco_decode_String(arr[0]),
+      sentBytes: dco_decode_u_64(arr[1]),
+      droppedBytes: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  DlnaHttpStreamInfo dco_decode_dlna_http_stream_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DlnaHttpStreamInfo(
+      streamUrl: dco_decode_String(arr[0]),
+      playlistUrl: dco_decode_String(arr[1]),
+      contentType: dco_decode_String(arr[2]),
+    );
+  }
+
   @protected
   DlnaPositionInfo dco_decode_dlna_position_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3679,6 +4941,12 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     );
   }
 
+  @protected
+  DlnaStreamFormat dco_decode_dlna_stream_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DlnaStreamFormat.values[raw as int];
+  }
+
   @protected
   DlnaTransportInfo dco_decode_dlna_transport_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3773,6 +5041,12 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  ImpulseLayout dco_decode_impulse_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImpulseLayout.values[raw as int];
+  }
+
   @protected
   LfeMode dco_decode_lfe_mode(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3821,6 +5095,14 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return (raw as List<dynamic>).map(dco_decode_audio_device).toList();
   }
 
+  @protected
+  List<DlnaHttpStreamClient> dco_decode_list_dlna_http_stream_client(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_dlna_http_stream_client).toList();
+  }
+
   @protected
   List<DlnaRenderer> dco_decode_list_dlna_renderer(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3845,12 +5127,26 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return (raw as List<dynamic>).map(dco_decode_dsp_type_descriptor).toList();
   }
 
+  @protected
+  List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_list_prim_f_32_strict)
+        .toList();
+  }
+
   @protected
   List<LyricLine> dco_decode_list_lyric_line(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_lyric_line).toList();
   }
 
+  @protected
+  List<LyricWord> dco_decode_list_lyric_word(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lyric_word).toList();
+  }
+
   @protected
   List<LyricsProviderTypeDescriptor>
   dco_decode_list_lyrics_provider_type_descriptor(dynamic raw) {
@@ -3892,6 +5188,12 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return (raw as List<dynamic>).map(dco_decode_plugin_descriptor).toList();
   }
 
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
+  }
+
   @protected
   Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3923,12 +5225,28 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
   LyricLine dco_decode_lyric_line(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return LyricLine(
       startMs: dco_decode_opt_box_autoadd_i_64(arr[0]),
       endMs: dco_decode_opt_box_autoadd_i_64(arr[1]),
       text: dco_decode_String(arr[2]),
+      words: dco_decode_list_lyric_word(arr[3]),
+      translation: dco_decode_opt_String(arr[4]),
+      romanization: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  LyricWord dco_decode_lyric_word(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LyricWord(
+      startMs: dco_decode_i_64(arr[0]),
+      endMs: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      text: dco_decode_String(arr[2]),
     );
   }
 
@@ -3936,13 +5254,14 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
   LyricsDoc dco_decode_lyrics_doc(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return LyricsDoc(
       trackKey: dco_decode_String(arr[0]),
       source: dco_decode_String(arr[1]),
       isSynced: dco_decode_bool(arr[2]),
       lines: dco_decode_list_lyric_line(arr[3]),
+      meta: dco_decode_lyrics_meta(arr[4]),
     );
   }
 
@@ -3961,6 +5280,7 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
         return LyricsEvent_Cursor(
           trackKey: dco_decode_String(raw[1]),
           lineIndex: dco_decode_i_64(raw[2]),
+          wordIndex: dco_decode_i_64(raw[3]),
         );
       case 3:
         return LyricsEvent_Empty(trackKey: dco_decode_String(raw[1]));
@@ -3974,6 +5294,21 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  LyricsMeta dco_decode_lyrics_meta(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LyricsMeta(
+      title: dco_decode_opt_String(arr[0]),
+      artist: dco_decode_opt_String(arr[1]),
+      album: dco_decode_opt_String(arr[2]),
+      author: dco_decode_opt_String(arr[3]),
+      offsetMs: dco_decode_i_64(arr[4]),
+    );
+  }
+
   @protected
   LyricsProviderTypeDescriptor dco_decode_lyrics_provider_type_descriptor(
     dynamic raw,
@@ -4023,6 +5358,52 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     );
   }
 
+  @protected
+  MixMode dco_decode_mix_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MixMode_Auto();
+      case 1:
+        return MixMode_Preset(
+          preset: dco_decode_box_autoadd_mix_preset(raw[1]),
+        );
+      case 2:
+        return MixMode_Custom(
+          rows: dco_decode_list_list_prim_f_32_strict(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  MixPreset dco_decode_mix_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MixPreset_Balance(balance: dco_decode_f_32(raw[1]));
+      case 1:
+        return MixPreset_SwapLeftRight();
+      case 2:
+        return MixPreset_MonoSum();
+      case 3:
+        return MixPreset_StereoWidth(width: dco_decode_f_32(raw[1]));
+      case 4:
+        return MixPreset_MatrixSurroundUpmix();
+      case 5:
+        return MixPreset_AmbienceUpmix();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  NoiseShaping dco_decode_noise_shaping(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NoiseShaping.values[raw as int];
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4047,6 +5428,12 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
   }
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4301,6 +5688,22 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  CrossfeedSettings sse_decode_box_autoadd_crossfeed_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_crossfeed_settings(deserializer));
+  }
+
+  @protected
+  DitherSettings sse_decode_box_autoadd_dither_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_dither_settings(deserializer));
+  }
+
   @protected
   DlnaRenderer sse_decode_box_autoadd_dlna_renderer(
     SseDeserializer deserializer,
@@ -4329,6 +5732,18 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return (sse_decode_lyrics_query(deserializer));
   }
 
+  @protected
+  MixMode sse_decode_box_autoadd_mix_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_mix_mode(deserializer));
+  }
+
+  @protected
+  MixPreset sse_decode_box_autoadd_mix_preset(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_mix_preset(deserializer));
+  }
+
   @protected
   OutputSinkRoute sse_decode_box_autoadd_output_sink_route(
     SseDeserializer deserializer,
@@ -4357,12 +5772,48 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return (sse_decode_u_16(deserializer));
   }
 
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_u_64(deserializer));
   }
 
+  @protected
+  CrossfeedPreset sse_decode_crossfeed_preset(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CrossfeedPreset.values[inner];
+  }
+
+  @protected
+  CrossfeedSettings sse_decode_crossfeed_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_cutoffHz = sse_decode_u_32(deserializer);
+    var var_feedDb = sse_decode_f_32(deserializer);
+    return CrossfeedSettings(
+      enabled: var_enabled,
+      cutoffHz: var_cutoffHz,
+      feedDb: var_feedDb,
+    );
+  }
+
+  @protected
+  DitherSettings sse_decode_dither_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tpdf = sse_decode_bool(deserializer);
+    var var_noiseShaping = sse_decode_noise_shaping(deserializer);
+    return DitherSettings(tpdf: var_tpdf, noiseShaping: var_noiseShaping);
+  }
+
   @protected
   DlnaHttpServerInfo sse_decode_dlna_http_server_info(
     SseDeserializer deserializer,
@@ -4373,6 +5824,36 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return DlnaHttpServerInfo(listenAddr: var_listenAddr, baseUrl: var_baseUrl);
   }
 
+  @protected
+  DlnaHttpStreamClient sse_decode_dlna_http_stream_client(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_remoteAddr = sse_decode_String(deserializer);
+    var var_sentBytes = sse_decode_u_64(deserializer);
+    var var_droppedBytes = sse_decode_u_64(deserializer);
+    return DlnaHttpStreamClient(
+      remoteAddr: var_remoteAddr,
+      sentBytes: var_sentBytes,
+      droppedBytes: var_droppedBytes,
+    );
+  }
+
+  @protected
+  DlnaHttpStreamInfo sse_decode_dlna_http_stream_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_streamUrl = sse_decode_String(deserializer);
+    var var_playlistUrl = sse_decode_String(deserializer);
+    var var_contentType = sse_decode_String(deserializer);
+    return DlnaHttpStreamInfo(
+      streamUrl: var_streamUrl,
+      playlistUrl: var_playlistUrl,
+      contentType: var_contentType,
+    );
+  }
+
   @protected
   DlnaPositionInfo sse_decode_dlna_position_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4420,6 +5901,13 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     );
   }
 
+  @protected
+  DlnaStreamFormat sse_decode_dlna_stream_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DlnaStreamFormat.values[inner];
+  }
+
   @protected
   DlnaTransportInfo sse_decode_dlna_transport_info(
     SseDeserializer deserializer,
@@ -4530,6 +6018,13 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  ImpulseLayout sse_decode_impulse_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ImpulseLayout.values[inner];
+  }
+
   @protected
   LfeMode sse_decode_lfe_mode(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4604,6 +6099,20 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return ans_;
   }
 
+  @protected
+  List<DlnaHttpStreamClient> sse_decode_list_dlna_http_stream_client(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DlnaHttpStreamClient>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_dlna_http_stream_client(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<DlnaRenderer> sse_decode_list_dlna_renderer(
     SseDeserializer deserializer,
@@ -4660,6 +6169,19 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return ans_;
   }
 
+  @protected
+  List<Float32List> sse_decode_list_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Float32List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_f_32_strict(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<LyricLine> sse_decode_list_lyric_line(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4672,6 +6194,18 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return ans_;
   }
 
+  @protected
+  List<LyricWord> sse_decode_list_lyric_word(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LyricWord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lyric_word(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<LyricsProviderTypeDescriptor>
   sse_decode_list_lyrics_provider_type_descriptor(
@@ -4743,6 +6277,13 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     return ans_;
   }
 
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
   @protected
   Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4788,7 +6329,26 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     var var_startMs = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_endMs = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_text = sse_decode_String(deserializer);
-    return LyricLine(startMs: var_startMs, endMs: var_endMs, text: var_text);
+    var var_words = sse_decode_list_lyric_word(deserializer);
+    var var_translation = sse_decode_opt_String(deserializer);
+    var var_romanization = sse_decode_opt_String(deserializer);
+    return LyricLine(
+      startMs: var_startMs,
+      endMs: var_endMs,
+      text: var_text,
+      words: var_words,
+      translation: var_translation,
+      romanization: var_romanization,
+    );
+  }
+
+  @protected
+  LyricWord sse_decode_lyric_word(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_i_64(deserializer);
+    var var_endMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return LyricWord(startMs: var_startMs, endMs: var_endMs, text: var_text);
   }
 
   @protected
@@ -4798,11 +6358,13 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     var var_source = sse_decode_String(deserializer);
     var var_isSynced = sse_decode_bool(deserializer);
     var var_lines = sse_decode_list_lyric_line(deserializer);
+    var var_meta = sse_decode_lyrics_meta(deserializer);
     return LyricsDoc(
       trackKey: var_trackKey,
       source: var_source,
       isSynced: var_isSynced,
       lines: var_lines,
+      meta: var_meta,
     );
   }
 
@@ -4822,9 +6384,11 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
       case 2:
         var var_trackKey = sse_decode_String(deserializer);
         var var_lineIndex = sse_decode_i_64(deserializer);
+        var var_wordIndex = sse_decode_i_64(deserializer);
         return LyricsEvent_Cursor(
           trackKey: var_trackKey,
           lineIndex: var_lineIndex,
+          wordIndex: var_wordIndex,
         );
       case 3:
         var var_trackKey = sse_decode_String(deserializer);
@@ -4838,6 +6402,23 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  LyricsMeta sse_decode_lyrics_meta(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    var var_offsetMs = sse_decode_i_64(deserializer);
+    return LyricsMeta(
+      title: var_title,
+      artist: var_artist,
+      album: var_album,
+      author: var_author,
+      offsetMs: var_offsetMs,
+    );
+  }
+
   @protected
   LyricsProviderTypeDescriptor sse_decode_lyrics_provider_type_descriptor(
     SseDeserializer deserializer,
@@ -4897,6 +6478,57 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     );
   }
 
+  @protected
+  MixMode sse_decode_mix_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return MixMode_Auto();
+      case 1:
+        var var_preset = sse_decode_box_autoadd_mix_preset(deserializer);
+        return MixMode_Preset(preset: var_preset);
+      case 2:
+        var var_rows = sse_decode_list_list_prim_f_32_strict(deserializer);
+        return MixMode_Custom(rows: var_rows);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  MixPreset sse_decode_mix_preset(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_balance = sse_decode_f_32(deserializer);
+        return MixPreset_Balance(balance: var_balance);
+      case 1:
+        return MixPreset_SwapLeftRight();
+      case 2:
+        return MixPreset_MonoSum();
+      case 3:
+        var var_width = sse_decode_f_32(deserializer);
+        return MixPreset_StereoWidth(width: var_width);
+      case 4:
+        return MixPreset_MatrixSurroundUpmix();
+      case 5:
+        return MixPreset_AmbienceUpmix();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  NoiseShaping sse_decode_noise_shaping(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NoiseShaping.values[inner];
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4943,6 +6575,17 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5257,6 +6900,24 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_autoadd_crossfeed_settings(
+    CrossfeedSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_crossfeed_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_dither_settings(
+    DitherSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_dither_settings(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_dlna_renderer(
     DlnaRenderer self,
@@ -5293,6 +6954,21 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     sse_encode_lyrics_query(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_mix_mode(MixMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_mix_mode(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_mix_preset(
+    MixPreset self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_mix_preset(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_output_sink_route(
     OutputSinkRoute self,
@@ -5326,12 +7002,48 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     sse_encode_u_16(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_crossfeed_preset(
+    CrossfeedPreset self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_crossfeed_settings(
+    CrossfeedSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_32(self.cutoffHz, serializer);
+    sse_encode_f_32(self.feedDb, serializer);
+  }
+
+  @protected
+  void sse_encode_dither_settings(
+    DitherSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.tpdf, serializer);
+    sse_encode_noise_shaping(self.noiseShaping, serializer);
+  }
+
   @protected
   void sse_encode_dlna_http_server_info(
     DlnaHttpServerInfo self,
@@ -5342,6 +7054,28 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     sse_encode_String(self.baseUrl, serializer);
   }
 
+  @protected
+  void sse_encode_dlna_http_stream_client(
+    DlnaHttpStreamClient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.remoteAddr, serializer);
+    sse_encode_u_64(self.sentBytes, serializer);
+    sse_encode_u_64(self.droppedBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_dlna_http_stream_info(
+    DlnaHttpStreamInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.streamUrl, serializer);
+    sse_encode_String(self.playlistUrl, serializer);
+    sse_encode_String(self.contentType, serializer);
+  }
+
   @protected
   void sse_encode_dlna_position_info(
     DlnaPositionInfo self,
@@ -5376,6 +7110,15 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     sse_encode_opt_String(self.server, serializer);
   }
 
+  @protected
+  void sse_encode_dlna_stream_format(
+    DlnaStreamFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_dlna_transport_info(
     DlnaTransportInfo self,
@@ -5466,6 +7209,12 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_impulse_layout(ImpulseLayout self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_lfe_mode(LfeMode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5532,6 +7281,18 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_list_dlna_http_stream_client(
+    List<DlnaHttpStreamClient> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_dlna_http_stream_client(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_dlna_renderer(
     List<DlnaRenderer> self,
@@ -5580,6 +7341,18 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_list_list_prim_f_32_strict(
+    List<Float32List> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_f_32_strict(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_lyric_line(
     List<LyricLine> self,
@@ -5592,6 +7365,18 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_list_lyric_word(
+    List<LyricWord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lyric_word(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_lyrics_provider_type_descriptor(
     List<LyricsProviderTypeDescriptor> self,
@@ -5652,6 +7437,16 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
+  }
+
   @protected
   void sse_encode_list_prim_i_64_strict(
     Int64List self,
@@ -5702,6 +7497,17 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     sse_encode_opt_box_autoadd_i_64(self.startMs, serializer);
     sse_encode_opt_box_autoadd_i_64(self.endMs, serializer);
     sse_encode_String(self.text, serializer);
+    sse_encode_list_lyric_word(self.words, serializer);
+    sse_encode_opt_String(self.translation, serializer);
+    sse_encode_opt_String(self.romanization, serializer);
+  }
+
+  @protected
+  void sse_encode_lyric_word(LyricWord self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.startMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.endMs, serializer);
+    sse_encode_String(self.text, serializer);
   }
 
   @protected
@@ -5711,6 +7517,7 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     sse_encode_String(self.source, serializer);
     sse_encode_bool(self.isSynced, serializer);
     sse_encode_list_lyric_line(self.lines, serializer);
+    sse_encode_lyrics_meta(self.meta, serializer);
   }
 
   @protected
@@ -5727,10 +7534,12 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
       case LyricsEvent_Cursor(
         trackKey: final trackKey,
         lineIndex: final lineIndex,
+        wordIndex: final wordIndex,
       ):
         sse_encode_i_32(2, serializer);
         sse_encode_String(trackKey, serializer);
         sse_encode_i_64(lineIndex, serializer);
+        sse_encode_i_64(wordIndex, serializer);
       case LyricsEvent_Empty(trackKey: final trackKey):
         sse_encode_i_32(3, serializer);
         sse_encode_String(trackKey, serializer);
@@ -5741,6 +7550,16 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_lyrics_meta(LyricsMeta self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_String(self.author, serializer);
+    sse_encode_i_64(self.offsetMs, serializer);
+  }
+
   @protected
   void sse_encode_lyrics_provider_type_descriptor(
     LyricsProviderTypeDescriptor self,
@@ -5779,6 +7598,48 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     sse_encode_lyrics_doc(self.doc, serializer);
   }
 
+  @protected
+  void sse_encode_mix_mode(MixMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case MixMode_Auto():
+        sse_encode_i_32(0, serializer);
+      case MixMode_Preset(preset: final preset):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_mix_preset(preset, serializer);
+      case MixMode_Custom(rows: final rows):
+        sse_encode_i_32(2, serializer);
+        sse_encode_list_list_prim_f_32_strict(rows, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_mix_preset(MixPreset self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case MixPreset_Balance(balance: final balance):
+        sse_encode_i_32(0, serializer);
+        sse_encode_f_32(balance, serializer);
+      case MixPreset_SwapLeftRight():
+        sse_encode_i_32(1, serializer);
+      case MixPreset_MonoSum():
+        sse_encode_i_32(2, serializer);
+      case MixPreset_StereoWidth(width: final width):
+        sse_encode_i_32(3, serializer);
+        sse_encode_f_32(width, serializer);
+      case MixPreset_MatrixSurroundUpmix():
+        sse_encode_i_32(4, serializer);
+      case MixPreset_AmbienceUpmix():
+        sse_encode_i_32(5, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_noise_shaping(NoiseShaping self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5825,6 +7686,16 @@ class StellatuneApiApiImpl extends StellatuneApiApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -60,6 +60,12 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  CrossfeedSettings dco_decode_box_autoadd_crossfeed_settings(dynamic raw);
+
+  @protected
+  DitherSettings dco_decode_box_autoadd_dither_settings(dynamic raw);
+
   @protected
   DlnaRenderer dco_decode_box_autoadd_dlna_renderer(dynamic raw);
 
@@ -72,6 +78,12 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   LyricsQuery dco_decode_box_autoadd_lyrics_query(dynamic raw);
 
+  @protected
+  MixMode dco_decode_box_autoadd_mix_mode(dynamic raw);
+
+  @protected
+  MixPreset dco_decode_box_autoadd_mix_preset(dynamic raw);
+
   @protected
   OutputSinkRoute dco_decode_box_autoadd_output_sink_route(dynamic raw);
 
@@ -84,12 +96,30 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   int dco_decode_box_autoadd_u_16(dynamic raw);
 
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  CrossfeedPreset dco_decode_crossfeed_preset(dynamic raw);
+
+  @protected
+  CrossfeedSettings dco_decode_crossfeed_settings(dynamic raw);
+
+  @protected
+  DitherSettings dco_decode_dither_settings(dynamic raw);
+
   @protected
   DlnaHttpServerInfo dco_decode_dlna_http_server_info(dynamic raw);
 
+  @protected
+  DlnaHttpStreamClient dco_decode_dlna_http_stream_client(dynamic raw);
+
+  @protected
+  DlnaHttpStreamInfo dco_decode_dlna_http_stream_info(dynamic raw);
+
   @protected
   DlnaPositionInfo dco_decode_dlna_position_info(dynamic raw);
 
@@ -99,6 +129,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   DlnaSsdpDevice dco_decode_dlna_ssdp_device(dynamic raw);
 
+  @protected
+  DlnaStreamFormat dco_decode_dlna_stream_format(dynamic raw);
+
   @protected
   DlnaTransportInfo dco_decode_dlna_transport_info(dynamic raw);
 
@@ -120,6 +153,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  ImpulseLayout dco_decode_impulse_layout(dynamic raw);
+
   @protected
   LfeMode dco_decode_lfe_mode(dynamic raw);
 
@@ -132,6 +168,11 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   List<AudioDevice> dco_decode_list_audio_device(dynamic raw);
 
+  @protected
+  List<DlnaHttpStreamClient> dco_decode_list_dlna_http_stream_client(
+    dynamic raw,
+  );
+
   @protected
   List<DlnaRenderer> dco_decode_list_dlna_renderer(dynamic raw);
 
@@ -144,9 +185,15 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   List<DspTypeDescriptor> dco_decode_list_dsp_type_descriptor(dynamic raw);
 
+  @protected
+  List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw);
+
   @protected
   List<LyricLine> dco_decode_list_lyric_line(dynamic raw);
 
+  @protected
+  List<LyricWord> dco_decode_list_lyric_word(dynamic raw);
+
   @protected
   List<LyricsProviderTypeDescriptor>
   dco_decode_list_lyrics_provider_type_descriptor(dynamic raw);
@@ -167,6 +214,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   List<PluginDescriptor> dco_decode_list_plugin_descriptor(dynamic raw);
 
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
   @protected
   Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
 
@@ -183,12 +233,18 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   LyricLine dco_decode_lyric_line(dynamic raw);
 
+  @protected
+  LyricWord dco_decode_lyric_word(dynamic raw);
+
   @protected
   LyricsDoc dco_decode_lyrics_doc(dynamic raw);
 
   @protected
   LyricsEvent dco_decode_lyrics_event(dynamic raw);
 
+  @protected
+  LyricsMeta dco_decode_lyrics_meta(dynamic raw);
+
   @protected
   LyricsProviderTypeDescriptor dco_decode_lyrics_provider_type_descriptor(
     dynamic raw,
@@ -200,6 +256,15 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   LyricsSearchCandidate dco_decode_lyrics_search_candidate(dynamic raw);
 
+  @protected
+  MixMode dco_decode_mix_mode(dynamic raw);
+
+  @protected
+  MixPreset dco_decode_mix_preset(dynamic raw);
+
+  @protected
+  NoiseShaping dco_decode_noise_shaping(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -212,6 +277,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
@@ -302,6 +370,16 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  CrossfeedSettings sse_decode_box_autoadd_crossfeed_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DitherSettings sse_decode_box_autoadd_dither_settings(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DlnaRenderer sse_decode_box_autoadd_dlna_renderer(
     SseDeserializer deserializer,
@@ -316,6 +394,12 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   LyricsQuery sse_decode_box_autoadd_lyrics_query(SseDeserializer deserializer);
 
+  @protected
+  MixMode sse_decode_box_autoadd_mix_mode(SseDeserializer deserializer);
+
+  @protected
+  MixPreset sse_decode_box_autoadd_mix_preset(SseDeserializer deserializer);
+
   @protected
   OutputSinkRoute sse_decode_box_autoadd_output_sink_route(
     SseDeserializer deserializer,
@@ -332,14 +416,36 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  CrossfeedPreset sse_decode_crossfeed_preset(SseDeserializer deserializer);
+
+  @protected
+  CrossfeedSettings sse_decode_crossfeed_settings(SseDeserializer deserializer);
+
+  @protected
+  DitherSettings sse_decode_dither_settings(SseDeserializer deserializer);
+
   @protected
   DlnaHttpServerInfo sse_decode_dlna_http_server_info(
     SseDeserializer deserializer,
   );
 
+  @protected
+  DlnaHttpStreamClient sse_decode_dlna_http_stream_client(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DlnaHttpStreamInfo sse_decode_dlna_http_stream_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DlnaPositionInfo sse_decode_dlna_position_info(SseDeserializer deserializer);
 
@@ -349,6 +455,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   DlnaSsdpDevice sse_decode_dlna_ssdp_device(SseDeserializer deserializer);
 
+  @protected
+  DlnaStreamFormat sse_decode_dlna_stream_format(SseDeserializer deserializer);
+
   @protected
   DlnaTransportInfo sse_decode_dlna_transport_info(
     SseDeserializer deserializer,
@@ -374,6 +483,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  ImpulseLayout sse_decode_impulse_layout(SseDeserializer deserializer);
+
   @protected
   LfeMode sse_decode_lfe_mode(SseDeserializer deserializer);
 
@@ -386,6 +498,11 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   List<AudioDevice> sse_decode_list_audio_device(SseDeserializer deserializer);
 
+  @protected
+  List<DlnaHttpStreamClient> sse_decode_list_dlna_http_stream_client(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<DlnaRenderer> sse_decode_list_dlna_renderer(
     SseDeserializer deserializer,
@@ -406,9 +523,17 @@ abstract class StellatuneApiApiImplPlatform
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<Float32List> sse_decode_list_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<LyricLine> sse_decode_list_lyric_line(SseDeserializer deserializer);
 
+  @protected
+  List<LyricWord> sse_decode_list_lyric_word(SseDeserializer deserializer);
+
   @protected
   List<LyricsProviderTypeDescriptor>
   sse_decode_list_lyrics_provider_type_descriptor(SseDeserializer deserializer);
@@ -433,6 +558,9 @@ abstract class StellatuneApiApiImplPlatform
     SseDeserializer deserializer,
   );
 
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
   @protected
   Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
 
@@ -449,12 +577,18 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   LyricLine sse_decode_lyric_line(SseDeserializer deserializer);
 
+  @protected
+  LyricWord sse_decode_lyric_word(SseDeserializer deserializer);
+
   @protected
   LyricsDoc sse_decode_lyrics_doc(SseDeserializer deserializer);
 
   @protected
   LyricsEvent sse_decode_lyrics_event(SseDeserializer deserializer);
 
+  @protected
+  LyricsMeta sse_decode_lyrics_meta(SseDeserializer deserializer);
+
   @protected
   LyricsProviderTypeDescriptor sse_decode_lyrics_provider_type_descriptor(
     SseDeserializer deserializer,
@@ -468,6 +602,15 @@ abstract class StellatuneApiApiImplPlatform
     SseDeserializer deserializer,
   );
 
+  @protected
+  MixMode sse_decode_mix_mode(SseDeserializer deserializer);
+
+  @protected
+  MixPreset sse_decode_mix_preset(SseDeserializer deserializer);
+
+  @protected
+  NoiseShaping sse_decode_noise_shaping(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -482,6 +625,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
@@ -586,6 +732,18 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_crossfeed_settings(
+    CrossfeedSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_dither_settings(
+    DitherSettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_dlna_renderer(
     DlnaRenderer self,
@@ -610,6 +768,15 @@ abstract class StellatuneApiApiImplPlatform
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_mix_mode(MixMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_mix_preset(
+    MixPreset self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_output_sink_route(
     OutputSinkRoute self,
@@ -631,15 +798,48 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_crossfeed_preset(
+    CrossfeedPreset self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_crossfeed_settings(
+    CrossfeedSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dither_settings(
+    DitherSettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_dlna_http_server_info(
     DlnaHttpServerInfo self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_dlna_http_stream_client(
+    DlnaHttpStreamClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dlna_http_stream_info(
+    DlnaHttpStreamInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_dlna_position_info(
     DlnaPositionInfo self,
@@ -655,6 +855,12 @@ abstract class StellatuneApiApiImplPlatform
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_dlna_stream_format(
+    DlnaStreamFormat self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_dlna_transport_info(
     DlnaTransportInfo self,
@@ -682,6 +888,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_impulse_layout(ImpulseLayout self, SseSerializer serializer);
+
   @protected
   void sse_encode_lfe_mode(LfeMode self, SseSerializer serializer);
 
@@ -697,6 +906,12 @@ abstract class StellatuneApiApiImplPlatform
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_dlna_http_stream_client(
+    List<DlnaHttpStreamClient> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_dlna_renderer(
     List<DlnaRenderer> self,
@@ -721,12 +936,24 @@ abstract class StellatuneApiApiImplPlatform
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_list_prim_f_32_strict(
+    List<Float32List> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_lyric_line(
     List<LyricLine> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_lyric_word(
+    List<LyricWord> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_lyrics_provider_type_descriptor(
     List<LyricsProviderTypeDescriptor> self,
@@ -757,6 +984,12 @@ abstract class StellatuneApiApiImplPlatform
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_i_64_strict(
     Int64List self,
@@ -784,12 +1017,18 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   void sse_encode_lyric_line(LyricLine self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_lyric_word(LyricWord self, SseSerializer serializer);
+
   @protected
   void sse_encode_lyrics_doc(LyricsDoc self, SseSerializer serializer);
 
   @protected
   void sse_encode_lyrics_event(LyricsEvent self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_lyrics_meta(LyricsMeta self, SseSerializer serializer);
+
   @protected
   void sse_encode_lyrics_provider_type_descriptor(
     LyricsProviderTypeDescriptor self,
@@ -805,6 +1044,15 @@ abstract class StellatuneApiApiImplPlatform
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_mix_mode(MixMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mix_preset(MixPreset self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_noise_shaping(NoiseShaping self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -823,6 +1071,9 @@ abstract class StellatuneApiApiImplPlatform
   @protected
   void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
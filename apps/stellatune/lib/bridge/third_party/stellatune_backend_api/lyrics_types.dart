@@ -15,15 +15,58 @@ class LyricLine {
   final PlatformInt64? endMs;
   final String text;
 
-  const LyricLine({this.startMs, this.endMs, required this.text});
+  /// Word-level timing; empty for line-synced lyrics. Concatenating every word's
+  /// `text` gives the line's `text`.
+  final List<LyricWord> words;
+  final String? translation;
+  final String? romanization;
+
+  const LyricLine({
+    this.startMs,
+    this.endMs,
+    required this.text,
+    required this.words,
+    this.translation,
+    this.romanization,
+  });
 
   @override
-  int get hashCode => startMs.hashCode ^ endMs.hashCode ^ text.hashCode;
+  int get hashCode =>
+      startMs.hashCode ^
+      endMs.hashCode ^
+      text.hashCode ^
+      words.hashCode ^
+      translation.hashCode ^
+      romanization.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is LyricLine &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          text == other.text &&
+          words == other.words &&
+          translation == other.translation &&
+          romanization == other.romanization;
+}
+
+/// One timed segment (word or syllable) of a karaoke line.
+class LyricWord {
+  final PlatformInt64 startMs;
+  final PlatformInt64? endMs;
+  final String text;
+
+  const LyricWord({required this.startMs, this.endMs, required this.text});
+
+  @override
+  int get hashCode => startMs.hashCode ^ endMs.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LyricWord &&
           runtimeType == other.runtimeType &&
           startMs == other.startMs &&
           endMs == other.endMs &&
@@ -35,17 +78,23 @@ class LyricsDoc {
   final String source;
   final bool isSynced;
   final List<LyricLine> lines;
+  final LyricsMeta meta;
 
   const LyricsDoc({
     required this.trackKey,
     required this.source,
     required this.isSynced,
     required this.lines,
+    required this.meta,
   });
 
   @override
   int get hashCode =>
-      trackKey.hashCode ^ source.hashCode ^ isSynced.hashCode ^ lines.hashCode;
+      trackKey.hashCode ^
+      source.hashCode ^
+      isSynced.hashCode ^
+      lines.hashCode ^
+      meta.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -55,7 +104,8 @@ class LyricsDoc {
           trackKey == other.trackKey &&
           source == other.source &&
           isSynced == other.isSynced &&
-          lines == other.lines;
+          lines == other.lines &&
+          meta == other.meta;
 }
 
 @freezed
@@ -68,9 +118,12 @@ sealed class LyricsEvent with _$LyricsEvent {
     required String trackKey,
     required LyricsDoc doc,
   }) = LyricsEvent_Ready;
+  /// `word_index` is the active entry of `LyricLine::words`, or -1 when the line has
+  /// no word timing or no word has started yet.
   const factory LyricsEvent.cursor({
     required String trackKey,
     required PlatformInt64 lineIndex,
+    required PlatformInt64 wordIndex,
   }) = LyricsEvent_Cursor;
   const factory LyricsEvent.empty({required String trackKey}) =
       LyricsEvent_Empty;
@@ -80,6 +133,45 @@ sealed class LyricsEvent with _$LyricsEvent {
   }) = LyricsEvent_Error;
 }
 
+/// Header fields of a lyrics document.
+class LyricsMeta {
+  final String? title;
+  final String? artist;
+  final String? album;
+  final String? author;
+
+  /// Offset declared by the source (LRC `[offset:]`). Already applied to every
+  /// timestamp in the document; kept for display and export.
+  final PlatformInt64 offsetMs;
+
+  const LyricsMeta({
+    this.title,
+    this.artist,
+    this.album,
+    this.author,
+    required this.offsetMs,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      artist.hashCode ^
+      album.hashCode ^
+      author.hashCode ^
+      offsetMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LyricsMeta &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          artist == other.artist &&
+          album == other.album &&
+          author == other.author &&
+          offsetMs == other.offsetMs;
+}
+
 class LyricsQuery {
   final String trackKey;
   final String title;
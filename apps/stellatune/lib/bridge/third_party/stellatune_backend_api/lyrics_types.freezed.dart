@@ -159,12 +159,12 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String trackKey)?  loading,TResult Function( String trackKey,  LyricsDoc doc)?  ready,TResult Function( String trackKey,  PlatformInt64 lineIndex)?  cursor,TResult Function( String trackKey)?  empty,TResult Function( String trackKey,  String message)?  error,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String trackKey)?  loading,TResult Function( String trackKey,  LyricsDoc doc)?  ready,TResult Function( String trackKey,  PlatformInt64 lineIndex,  PlatformInt64 wordIndex)?  cursor,TResult Function( String trackKey)?  empty,TResult Function( String trackKey,  String message)?  error,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case LyricsEvent_Loading() when loading != null:
 return loading(_that.trackKey);case LyricsEvent_Ready() when ready != null:
 return ready(_that.trackKey,_that.doc);case LyricsEvent_Cursor() when cursor != null:
-return cursor(_that.trackKey,_that.lineIndex);case LyricsEvent_Empty() when empty != null:
+return cursor(_that.trackKey,_that.lineIndex,_that.wordIndex);case LyricsEvent_Empty() when empty != null:
 return empty(_that.trackKey);case LyricsEvent_Error() when error != null:
 return error(_that.trackKey,_that.message);case _:
   return orElse();
@@ -184,12 +184,12 @@ return error(_that.trackKey,_that.message);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String trackKey)  loading,required TResult Function( String trackKey,  LyricsDoc doc)  ready,required TResult Function( String trackKey,  PlatformInt64 lineIndex)  cursor,required TResult Function( String trackKey)  empty,required TResult Function( String trackKey,  String message)  error,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String trackKey)  loading,required TResult Function( String trackKey,  LyricsDoc doc)  ready,required TResult Function( String trackKey,  PlatformInt64 lineIndex,  PlatformInt64 wordIndex)  cursor,required TResult Function( String trackKey)  empty,required TResult Function( String trackKey,  String message)  error,}) {final _that = this;
 switch (_that) {
 case LyricsEvent_Loading():
 return loading(_that.trackKey);case LyricsEvent_Ready():
 return ready(_that.trackKey,_that.doc);case LyricsEvent_Cursor():
-return cursor(_that.trackKey,_that.lineIndex);case LyricsEvent_Empty():
+return cursor(_that.trackKey,_that.lineIndex,_that.wordIndex);case LyricsEvent_Empty():
 return empty(_that.trackKey);case LyricsEvent_Error():
 return error(_that.trackKey,_that.message);}
 }
@@ -205,12 +205,12 @@ return error(_that.trackKey,_that.message);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String trackKey)?  loading,TResult? Function( String trackKey,  LyricsDoc doc)?  ready,TResult? Function( String trackKey,  PlatformInt64 lineIndex)?  cursor,TResult? Function( String trackKey)?  empty,TResult? Function( String trackKey,  String message)?  error,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String trackKey)?  loading,TResult? Function( String trackKey,  LyricsDoc doc)?  ready,TResult? Function( String trackKey,  PlatformInt64 lineIndex,  PlatformInt64 wordIndex)?  cursor,TResult? Function( String trackKey)?  empty,TResult? Function( String trackKey,  String message)?  error,}) {final _that = this;
 switch (_that) {
 case LyricsEvent_Loading() when loading != null:
 return loading(_that.trackKey);case LyricsEvent_Ready() when ready != null:
 return ready(_that.trackKey,_that.doc);case LyricsEvent_Cursor() when cursor != null:
-return cursor(_that.trackKey,_that.lineIndex);case LyricsEvent_Empty() when empty != null:
+return cursor(_that.trackKey,_that.lineIndex,_that.wordIndex);case LyricsEvent_Empty() when empty != null:
 return empty(_that.trackKey);case LyricsEvent_Error() when error != null:
 return error(_that.trackKey,_that.message);case _:
   return null;
@@ -358,11 +358,12 @@ as LyricsDoc,
 
 
 class LyricsEvent_Cursor extends LyricsEvent {
-  const LyricsEvent_Cursor({required this.trackKey, required this.lineIndex}): super._();
+  const LyricsEvent_Cursor({required this.trackKey, required this.lineIndex, required this.wordIndex}): super._();
   
 
 @override final  String trackKey;
  final  PlatformInt64 lineIndex;
+ final  PlatformInt64 wordIndex;
 
 /// Create a copy of LyricsEvent
 /// with the given fields replaced by the non-null parameter values.
@@ -374,16 +375,16 @@ $LyricsEvent_CursorCopyWith<LyricsEvent_Cursor> get copyWith => _$LyricsEvent_Cu
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is LyricsEvent_Cursor&&(identical(other.trackKey, trackKey) || other.trackKey == trackKey)&&(identical(other.lineIndex, lineIndex) || other.lineIndex == lineIndex));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is LyricsEvent_Cursor&&(identical(other.trackKey, trackKey) || other.trackKey == trackKey)&&(identical(other.lineIndex, lineIndex) || other.lineIndex == lineIndex)&&(identical(other.wordIndex, wordIndex) || other.wordIndex == wordIndex));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,trackKey,lineIndex);
+int get hashCode => Object.hash(runtimeType,trackKey,lineIndex,wordIndex);
 
 @override
 String toString() {
-  return 'LyricsEvent.cursor(trackKey: $trackKey, lineIndex: $lineIndex)';
+  return 'LyricsEvent.cursor(trackKey: $trackKey, lineIndex: $lineIndex, wordIndex: $wordIndex)';
 }
 
 
@@ -394,7 +395,7 @@ abstract mixin class $LyricsEvent_CursorCopyWith<$Res> implements $LyricsEventCo
   factory $LyricsEvent_CursorCopyWith(LyricsEvent_Cursor value, $Res Function(LyricsEvent_Cursor) _then) = _$LyricsEvent_CursorCopyWithImpl;
 @override @useResult
 $Res call({
- String trackKey, PlatformInt64 lineIndex
+ String trackKey, PlatformInt64 lineIndex, PlatformInt64 wordIndex
 });
 
 
@@ -411,10 +412,11 @@ class _$LyricsEvent_CursorCopyWithImpl<$Res>
 
 /// Create a copy of LyricsEvent
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? trackKey = null,Object? lineIndex = null,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? trackKey = null,Object? lineIndex = null,Object? wordIndex = null,}) {
   return _then(LyricsEvent_Cursor(
 trackKey: null == trackKey ? _self.trackKey : trackKey // ignore: cast_nullable_to_non_nullable
 as String,lineIndex: null == lineIndex ? _self.lineIndex : lineIndex // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,wordIndex: null == wordIndex ? _self.wordIndex : wordIndex // ignore: cast_nullable_to_non_nullable
 as PlatformInt64,
   ));
 }
@@ -120,6 +120,7 @@ class LyricsController extends Notifier<LyricsState> {
         trackKey: null,
         doc: null,
         currentLineIndex: -1,
+        currentWordIndex: -1,
         lastError: null,
       );
       return;
@@ -133,6 +134,7 @@ class LyricsController extends Notifier<LyricsState> {
         trackKey: null,
         doc: null,
         currentLineIndex: -1,
+        currentWordIndex: -1,
         lastError: null,
       );
       return;
@@ -150,6 +152,7 @@ class LyricsController extends Notifier<LyricsState> {
       trackKey: trackKey,
       doc: null,
       currentLineIndex: -1,
+      currentWordIndex: -1,
       lastError: null,
     );
 
@@ -270,6 +273,7 @@ class LyricsController extends Notifier<LyricsState> {
           trackKey: trackKey,
           doc: null,
           currentLineIndex: -1,
+          currentWordIndex: -1,
           lastError: null,
         );
       },
@@ -279,12 +283,16 @@ class LyricsController extends Notifier<LyricsState> {
           trackKey: trackKey,
           doc: doc,
           currentLineIndex: -1,
+          currentWordIndex: -1,
           lastError: null,
         );
       },
-      cursor: (trackKey, lineIndex) {
+      cursor: (trackKey, lineIndex, wordIndex) {
         if (state.trackKey != trackKey) return;
-        state = state.copyWith(currentLineIndex: lineIndex.toInt());
+        state = state.copyWith(
+          currentLineIndex: lineIndex.toInt(),
+          currentWordIndex: wordIndex.toInt(),
+        );
       },
       empty: (trackKey) {
         state = state.copyWith(
@@ -292,6 +300,7 @@ class LyricsController extends Notifier<LyricsState> {
           trackKey: trackKey,
           doc: null,
           currentLineIndex: -1,
+          currentWordIndex: -1,
           lastError: null,
         );
       },
@@ -301,6 +310,7 @@ class LyricsController extends Notifier<LyricsState> {
           trackKey: trackKey,
           doc: null,
           currentLineIndex: -1,
+          currentWordIndex: -1,
           lastError: message,
         );
       },
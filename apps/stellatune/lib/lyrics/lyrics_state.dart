@@ -11,6 +11,7 @@ class LyricsState {
     required this.trackKey,
     required this.doc,
     required this.currentLineIndex,
+    required this.currentWordIndex,
     required this.lastError,
   });
 
@@ -20,6 +21,7 @@ class LyricsState {
       trackKey = null,
       doc = null,
       currentLineIndex = -1,
+      currentWordIndex = -1,
       lastError = null;
 
   static const Object _sentinel = Object();
@@ -29,6 +31,9 @@ class LyricsState {
   final String? trackKey;
   final LyricsDoc? doc;
   final int currentLineIndex;
+
+  /// Active word of the current line for karaoke rendering, or -1.
+  final int currentWordIndex;
   final String? lastError;
 
   bool get hasLyrics => (doc?.lines.isNotEmpty ?? false);
@@ -41,6 +46,7 @@ class LyricsState {
     Object? trackKey = _sentinel,
     Object? doc = _sentinel,
     int? currentLineIndex,
+    int? currentWordIndex,
     Object? lastError = _sentinel,
   }) {
     return LyricsState(
@@ -51,6 +57,7 @@ class LyricsState {
           : trackKey as String?,
       doc: identical(doc, _sentinel) ? this.doc : doc as LyricsDoc?,
       currentLineIndex: currentLineIndex ?? this.currentLineIndex,
+      currentWordIndex: currentWordIndex ?? this.currentWordIndex,
       lastError: identical(lastError, _sentinel)
           ? this.lastError
           : lastError as String?,
@@ -38,6 +38,7 @@ class _InstalledPlugin {
     required this.installState,
     required this.uninstallRetryCount,
     required this.uninstallLastError,
+    required this.incompatibleReason,
   });
 
   final String dirPath;
@@ -47,11 +48,13 @@ class _InstalledPlugin {
   final String installState;
   final int uninstallRetryCount;
   final String? uninstallLastError;
+  final String? incompatibleReason;
 
   String get nameOrDir => name ?? p.basename(dirPath);
   bool get isInstalled => installState == 'installed';
   bool get isPendingUninstall => installState == 'pending_uninstall';
   bool get isDeleteFailed => installState == 'delete_failed';
+  bool get isIncompatible => installState == 'incompatible';
 }
 
 class SettingsPageState extends ConsumerState<SettingsPage> {
@@ -275,6 +278,9 @@ class SettingsPageState extends ConsumerState<SettingsPage> {
       final uninstallLastErrorRaw = (map['uninstall_last_error'] ?? '')
           .toString()
           .trim();
+      final incompatibleReasonRaw = (map['incompatible_reason'] ?? '')
+          .toString()
+          .trim();
       out.add(
         _InstalledPlugin(
           dirPath: dirPath.isEmpty ? p.join(_pluginDir!, id) : dirPath,
@@ -288,6 +294,9 @@ class SettingsPageState extends ConsumerState<SettingsPage> {
           uninstallLastError: uninstallLastErrorRaw.isEmpty
               ? null
               : uninstallLastErrorRaw,
+          incompatibleReason: incompatibleReasonRaw.isEmpty
+              ? null
+              : incompatibleReasonRaw,
         ),
       );
     }
@@ -2526,6 +2535,10 @@ class _PluginTileState extends State<_PluginTile> {
         '卸载失败（后台重试中，${p.uninstallRetryCount} 次）',
         true,
       ),
+      (_, 'incompatible', _, _, _) => (
+        '与当前版本不兼容：${p.incompatibleReason ?? '未知原因'}',
+        true,
+      ),
       (_, _, true, _, _) => ('已禁用', false),
       (_, _, false, false, _) => ('正在检查加载状态...', false),
       (_, _, false, true, true) => ('已加载', false),
@@ -7,8 +7,9 @@ use ringbuf::{HeapCons, HeapProd, HeapRb};
 
 use crate::output_runtime::{
     AudioBackend, OutputHandle, OutputSpec, SampleConsumer, default_output_spec, list_host_devices,
-    output_spec_for_device,
+    output_sample_format_for_device, output_spec_for_device,
 };
+use stellatune_audio::config::dither::SinkSampleFormat;
 use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
 use stellatune_audio_core::pipeline::error::PipelineError;
 use stellatune_audio_core::pipeline::stages::StageStatus;
@@ -296,6 +297,18 @@ pub fn output_spec_for_route(
     })
 }
 
+/// Sample format the device for this route is opened with; drives the dither stage.
+pub fn output_sample_format_for_route(
+    backend: OutputBackend,
+    device_id: Option<&str>,
+) -> Result<SinkSampleFormat, String> {
+    output_sample_format_for_device(
+        backend.to_audio_backend(),
+        normalize_device_id_ref(device_id).map(str::to_string),
+    )
+    .map_err(|e| format!("{e}"))
+}
+
 pub struct DeviceSinkStage {
     control: DeviceSinkControl,
     producer: Option<HeapProd<f32>>,
@@ -488,6 +501,13 @@ impl SinkStage for DeviceSinkStage {
         self.prepared_spec = None;
         self.clear_callback_error();
     }
+
+    fn latency_frames(&self) -> u64 {
+        let (Some(producer), Some(spec)) = (self.producer.as_ref(), self.prepared_spec) else {
+            return 0;
+        };
+        (producer.occupied_len() / spec.channels.max(1) as usize) as u64
+    }
 }
 
 struct RingBufferConsumer {
@@ -0,0 +1,518 @@
+//! Minimal FLAC encoder: fixed block size, independent channels and fixed linear
+//! predictors with a single Rice partition. Compression is modest but the output is a
+//! standard stream any decoder can read.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+
+use super::{EncodedFile, FileSinkMetadata, quantize};
+
+const BLOCK_FRAMES: usize = 4096;
+const BLOCK_SIZE_CODE_4096: u64 = 0b1100;
+const BLOCK_SIZE_CODE_16BIT: u64 = 0b0111;
+const STREAMINFO_OFFSET: u64 = 8;
+const STREAMINFO_BYTES: u32 = 34;
+const MAX_FIXED_ORDER: usize = 4;
+const MAX_RICE_PARAM: u32 = 30;
+const MAX_RICE1_PARAM: u32 = 14;
+const BLOCK_TYPE_STREAMINFO: u8 = 0;
+const BLOCK_TYPE_VORBIS_COMMENT: u8 = 4;
+const BLOCK_TYPE_PICTURE: u8 = 6;
+const LAST_METADATA_BLOCK: u8 = 0x80;
+const MAX_METADATA_BLOCK_BYTES: usize = (1 << 24) - 1;
+const PICTURE_TYPE_FRONT_COVER: u32 = 3;
+const VENDOR: &str = "stellatune";
+pub(crate) const MAX_CHANNELS: u16 = 8;
+
+pub(super) struct FlacWriter {
+    file: BufWriter<File>,
+    sample_rate: u32,
+    frames: FlacFrameEncoder,
+    stats: FrameStats,
+}
+
+/// Totals STREAMINFO reports once the file is complete.
+#[derive(Default)]
+struct FrameStats {
+    total_frames: u64,
+    min_frame_bytes: u32,
+    max_frame_bytes: u32,
+}
+
+impl FrameStats {
+    fn record(&mut self, frame_bytes: usize, frames: usize) {
+        let frame_bytes = frame_bytes as u32;
+        self.min_frame_bytes = match self.min_frame_bytes {
+            0 => frame_bytes,
+            current => current.min(frame_bytes),
+        };
+        self.max_frame_bytes = self.max_frame_bytes.max(frame_bytes);
+        self.total_frames += frames as u64;
+    }
+}
+
+impl FlacWriter {
+    pub(super) fn create(
+        file: File,
+        sample_rate: u32,
+        channels: u16,
+        bits: u32,
+        metadata: &FileSinkMetadata,
+    ) -> io::Result<Self> {
+        let mut writer = Self {
+            file: BufWriter::new(file),
+            sample_rate,
+            frames: FlacFrameEncoder::new(channels, bits),
+            stats: FrameStats::default(),
+        };
+        let mut blocks = Vec::new();
+        if !metadata.tags.is_empty() {
+            blocks.push((BLOCK_TYPE_VORBIS_COMMENT, vorbis_comment(metadata)));
+        }
+        if let Some(picture) = picture(metadata) {
+            blocks.push((BLOCK_TYPE_PICTURE, picture));
+        }
+
+        writer.file.write_all(b"fLaC")?;
+        // STREAMINFO always comes first, so its offset stays fixed for `finish`.
+        let streaminfo_type = if blocks.is_empty() {
+            BLOCK_TYPE_STREAMINFO | LAST_METADATA_BLOCK
+        } else {
+            BLOCK_TYPE_STREAMINFO
+        };
+        writer.file.write_all(&[streaminfo_type])?;
+        writer
+            .file
+            .write_all(&STREAMINFO_BYTES.to_be_bytes()[1..])?;
+        let streaminfo = writer.streaminfo();
+        writer.file.write_all(&streaminfo)?;
+        let last = blocks.len().saturating_sub(1);
+        for (index, (block_type, body)) in blocks.into_iter().enumerate() {
+            let block_type = if index == last {
+                block_type | LAST_METADATA_BLOCK
+            } else {
+                block_type
+            };
+            writer.file.write_all(&[block_type])?;
+            writer
+                .file
+                .write_all(&(body.len() as u32).to_be_bytes()[1..])?;
+            writer.file.write_all(&body)?;
+        }
+        Ok(writer)
+    }
+
+    fn streaminfo(&self) -> Vec<u8> {
+        streaminfo(
+            self.sample_rate,
+            self.frames.channels(),
+            self.frames.bits,
+            self.stats.total_frames,
+            (self.stats.min_frame_bytes, self.stats.max_frame_bytes),
+        )
+    }
+}
+
+/// Header of a live FLAC stream: `fLaC` plus a STREAMINFO block whose length and frame
+/// size fields say "unknown", since nothing can be patched after it went out.
+pub(crate) fn stream_header(sample_rate: u32, channels: u16, bits: u32) -> Vec<u8> {
+    let mut out = Vec::with_capacity(4 + 4 + STREAMINFO_BYTES as usize);
+    out.extend_from_slice(b"fLaC");
+    out.push(BLOCK_TYPE_STREAMINFO | LAST_METADATA_BLOCK);
+    out.extend_from_slice(&STREAMINFO_BYTES.to_be_bytes()[1..]);
+    out.extend_from_slice(&streaminfo(sample_rate, channels, bits, 0, (0, 0)));
+    out
+}
+
+fn streaminfo(
+    sample_rate: u32,
+    channels: u16,
+    bits: u32,
+    total_frames: u64,
+    (min_frame_bytes, max_frame_bytes): (u32, u32),
+) -> Vec<u8> {
+    let block_frames = if total_frames == 0 || total_frames >= BLOCK_FRAMES as u64 {
+        BLOCK_FRAMES as u64
+    } else {
+        total_frames.max(16)
+    };
+    let mut out = BitWriter::default();
+    out.write(block_frames, 16);
+    out.write(block_frames, 16);
+    out.write(min_frame_bytes as u64, 24);
+    out.write(max_frame_bytes as u64, 24);
+    out.write(sample_rate as u64, 20);
+    out.write(channels as u64 - 1, 3);
+    out.write(bits as u64 - 1, 5);
+    out.write(total_frames >> 32, 4);
+    out.write(total_frames & 0xFFFF_FFFF, 32);
+    // MD5 of the unencoded audio is optional; zero means "not computed".
+    out.write(0, 32);
+    out.write(0, 32);
+    out.write(0, 32);
+    out.write(0, 32);
+    out.into_bytes()
+}
+
+/// Cuts interleaved samples into fixed-size FLAC frames.
+pub(crate) struct FlacFrameEncoder {
+    bits: u32,
+    pending: Vec<Vec<i32>>,
+    frame_number: u32,
+}
+
+impl FlacFrameEncoder {
+    pub(crate) fn new(channels: u16, bits: u32) -> Self {
+        Self {
+            bits,
+            pending: vec![Vec::with_capacity(BLOCK_FRAMES); channels as usize],
+            frame_number: 0,
+        }
+    }
+
+    fn channels(&self) -> u16 {
+        self.pending.len() as u16
+    }
+
+    /// Buffers `samples` and hands every completed frame to `on_frame` together with
+    /// its length in sample frames.
+    pub(crate) fn push_interleaved<E>(
+        &mut self,
+        samples: &[f32],
+        mut on_frame: impl FnMut(&[u8], usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let channels = self.pending.len();
+        for frame in samples.chunks_exact(channels) {
+            for (channel, &sample) in self.pending.iter_mut().zip(frame) {
+                channel.push(quantize(sample, self.bits));
+            }
+            if self.pending[0].len() == BLOCK_FRAMES {
+                let (frame, frames) = self.encode_pending();
+                on_frame(&frame, frames)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes the buffered partial block, which may only end a stream.
+    pub(crate) fn finish(&mut self) -> Option<(Vec<u8>, usize)> {
+        (!self.pending[0].is_empty()).then(|| self.encode_pending())
+    }
+
+    fn encode_pending(&mut self) -> (Vec<u8>, usize) {
+        let frames = self.pending[0].len();
+        let frame = encode_frame(&self.pending, self.bits, self.frame_number);
+        self.frame_number = self.frame_number.wrapping_add(1);
+        for channel in &mut self.pending {
+            channel.clear();
+        }
+        (frame, frames)
+    }
+}
+
+impl EncodedFile for FlacWriter {
+    fn write_interleaved(&mut self, samples: &[f32]) -> io::Result<()> {
+        let (file, stats) = (&mut self.file, &mut self.stats);
+        self.frames.push_interleaved(samples, |frame, frames| {
+            file.write_all(frame)?;
+            stats.record(frame.len(), frames);
+            Ok(())
+        })
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        // Only the last frame may be shorter than the block size, so a partial block
+        // stays buffered until `finish`.
+        self.file.flush()
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        if let Some((frame, frames)) = self.frames.finish() {
+            self.file.write_all(&frame)?;
+            self.stats.record(frame.len(), frames);
+        }
+        let streaminfo = self.streaminfo();
+        self.file.seek(SeekFrom::Start(STREAMINFO_OFFSET))?;
+        self.file.write_all(&streaminfo)?;
+        self.file.seek(SeekFrom::End(0))?;
+        self.file.flush()
+    }
+}
+
+fn vorbis_comment(metadata: &FileSinkMetadata) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&(VENDOR.len() as u32).to_le_bytes());
+    out.extend_from_slice(VENDOR.as_bytes());
+    let count_offset = out.len();
+    out.extend_from_slice(&0_u32.to_le_bytes());
+    let mut count = 0_u32;
+    for (key, value) in &metadata.tags {
+        let entry = format!("{}={value}", key.to_ascii_uppercase());
+        if out.len() + 4 + entry.len() > MAX_METADATA_BLOCK_BYTES {
+            break;
+        }
+        out.extend_from_slice(&(entry.len() as u32).to_le_bytes());
+        out.extend_from_slice(entry.as_bytes());
+        count += 1;
+    }
+    out[count_offset..count_offset + 4].copy_from_slice(&count.to_le_bytes());
+    out
+}
+
+/// Builds a front-cover PICTURE block. Dimensions are left zero, which the format
+/// allows when unknown; covers that do not fit a metadata block are skipped.
+fn picture(metadata: &FileSinkMetadata) -> Option<Vec<u8>> {
+    let cover = metadata.cover.as_ref()?;
+    let mut out = Vec::with_capacity(cover.data.len() + 64);
+    out.extend_from_slice(&PICTURE_TYPE_FRONT_COVER.to_be_bytes());
+    out.extend_from_slice(&(cover.mime_type.len() as u32).to_be_bytes());
+    out.extend_from_slice(cover.mime_type.as_bytes());
+    // Empty description, then width, height, depth and palette size.
+    out.extend_from_slice(&[0; 4 * 5]);
+    out.extend_from_slice(&(cover.data.len() as u32).to_be_bytes());
+    out.extend_from_slice(&cover.data);
+    (out.len() <= MAX_METADATA_BLOCK_BYTES).then_some(out)
+}
+
+fn encode_frame(channels: &[Vec<i32>], bits: u32, frame_number: u32) -> Vec<u8> {
+    let frames = channels[0].len();
+    let mut out = BitWriter::default();
+    out.write(0b11_1111_1111_1110, 14);
+    out.write(0, 1);
+    // Fixed-blocksize stream: the header carries the frame number.
+    out.write(0, 1);
+    let block_size_code = if frames == BLOCK_FRAMES {
+        BLOCK_SIZE_CODE_4096
+    } else {
+        BLOCK_SIZE_CODE_16BIT
+    };
+    out.write(block_size_code, 4);
+    // Sample rate comes from STREAMINFO.
+    out.write(0, 4);
+    out.write(channels.len() as u64 - 1, 4);
+    out.write(sample_size_code(bits), 3);
+    out.write(0, 1);
+    write_utf8_number(&mut out, frame_number);
+    if block_size_code == BLOCK_SIZE_CODE_16BIT {
+        out.write(frames as u64 - 1, 16);
+    }
+    let header_crc = crc8(out.bytes());
+    out.write(header_crc as u64, 8);
+
+    let mut residual = Vec::with_capacity(frames);
+    for samples in channels {
+        encode_subframe(&mut out, samples, bits, &mut residual);
+    }
+    out.align();
+    let frame_crc = crc16(out.bytes());
+    out.write(frame_crc as u64, 16);
+    out.into_bytes()
+}
+
+fn sample_size_code(bits: u32) -> u64 {
+    match bits {
+        8 => 0b001,
+        12 => 0b010,
+        16 => 0b100,
+        20 => 0b101,
+        24 => 0b110,
+        _ => 0b000,
+    }
+}
+
+fn write_utf8_number(out: &mut BitWriter, value: u32) {
+    if value < 0x80 {
+        out.write(value as u64, 8);
+        return;
+    }
+    let continuation_bytes = match value {
+        0..0x800 => 1,
+        0x800..0x1_0000 => 2,
+        0x1_0000..0x20_0000 => 3,
+        0x20_0000..0x400_0000 => 4,
+        _ => 5,
+    };
+    let lead_marker = (0xFF00_u32 >> (continuation_bytes + 1)) & 0xFF;
+    out.write(
+        (lead_marker | (value >> (6 * continuation_bytes))) as u64,
+        8,
+    );
+    for index in (0..continuation_bytes).rev() {
+        out.write((0x80 | ((value >> (6 * index)) & 0x3F)) as u64, 8);
+    }
+}
+
+fn encode_subframe(out: &mut BitWriter, samples: &[i32], bits: u32, residual: &mut Vec<i32>) {
+    if samples.iter().all(|&sample| sample == samples[0]) {
+        out.write(0, 1);
+        out.write(0b00_0000, 6);
+        out.write(0, 1);
+        out.write_signed(samples[0], bits);
+        return;
+    }
+
+    let max_order = MAX_FIXED_ORDER.min(samples.len().saturating_sub(1));
+    let order = (0..=max_order)
+        .min_by_key(|&order| {
+            fixed_residual(samples, order)
+                .map(|value| value.unsigned_abs() as u64)
+                .sum::<u64>()
+        })
+        .unwrap_or(0);
+    residual.clear();
+    residual.extend(fixed_residual(samples, order));
+    let (rice_param, rice_bits) = best_rice_param(residual);
+    let fixed_bits = 8 + order as u64 * bits as u64 + 6 + rice_bits;
+    let verbatim_bits = 8 + samples.len() as u64 * bits as u64;
+
+    if fixed_bits >= verbatim_bits {
+        out.write(0, 1);
+        out.write(0b00_0001, 6);
+        out.write(0, 1);
+        for &sample in samples {
+            out.write_signed(sample, bits);
+        }
+        return;
+    }
+
+    out.write(0, 1);
+    out.write(0b00_1000 | order as u64, 6);
+    out.write(0, 1);
+    for &sample in &samples[..order] {
+        out.write_signed(sample, bits);
+    }
+    if rice_param > MAX_RICE1_PARAM {
+        out.write(0b01, 2);
+        out.write(0, 4);
+        out.write(rice_param as u64, 5);
+    } else {
+        out.write(0b00, 2);
+        out.write(0, 4);
+        out.write(rice_param as u64, 4);
+    }
+    for &value in residual.iter() {
+        let folded = zigzag(value);
+        out.write_unary(folded >> rice_param);
+        out.write(folded as u64, rice_param);
+    }
+}
+
+fn fixed_residual(samples: &[i32], order: usize) -> impl Iterator<Item = i32> + '_ {
+    (order..samples.len()).map(move |index| {
+        let x = |back: usize| samples[index - back] as i64;
+        let value = match order {
+            0 => x(0),
+            1 => x(0) - x(1),
+            2 => x(0) - 2 * x(1) + x(2),
+            3 => x(0) - 3 * x(1) + 3 * x(2) - x(3),
+            _ => x(0) - 4 * x(1) + 6 * x(2) - 4 * x(3) + x(4),
+        };
+        value as i32
+    })
+}
+
+fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Picks the Rice parameter around `log2(mean)` and returns it with the encoded size in
+/// bits, including the 4- or 5-bit parameter field.
+fn best_rice_param(residual: &[i32]) -> (u32, u64) {
+    let sum = residual
+        .iter()
+        .map(|&value| zigzag(value) as u64)
+        .sum::<u64>();
+    let mean = sum / residual.len().max(1) as u64;
+    let estimate = if mean == 0 { 0 } else { mean.ilog2() };
+    let candidates = estimate.saturating_sub(1)..=(estimate + 1).min(MAX_RICE_PARAM);
+    candidates
+        .map(|param| {
+            let body = residual
+                .iter()
+                .map(|&value| (zigzag(value) >> param) as u64 + 1 + param as u64)
+                .sum::<u64>();
+            let header = if param > MAX_RICE1_PARAM { 5 } else { 4 };
+            (param, body + header)
+        })
+        .min_by_key(|&(_, bits)| bits)
+        .unwrap_or((0, 0))
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u64,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, count: u32) {
+        if count == 0 {
+            return;
+        }
+        debug_assert!(count <= 32);
+        self.acc = (self.acc << count) | (value & ((1_u64 << count) - 1));
+        self.bits += count;
+        while self.bits >= 8 {
+            self.bits -= 8;
+            self.bytes.push((self.acc >> self.bits) as u8);
+        }
+        self.acc &= (1_u64 << self.bits) - 1;
+    }
+
+    fn write_signed(&mut self, value: i32, count: u32) {
+        self.write(value as u32 as u64, count);
+    }
+
+    fn write_unary(&mut self, mut zeros: u32) {
+        while zeros >= 32 {
+            self.write(0, 32);
+            zeros -= 32;
+        }
+        self.write(1, zeros + 1);
+    }
+
+    fn align(&mut self) {
+        if self.bits > 0 {
+            self.write(0, 8 - self.bits);
+        }
+    }
+
+    /// Completed bytes; callers align first when the tail matters.
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn into_bytes(mut self) -> Vec<u8> {
+        self.align();
+        self.bytes
+    }
+}
+
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0_u8, |mut crc, &byte| {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0_u16, |mut crc, &byte| {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
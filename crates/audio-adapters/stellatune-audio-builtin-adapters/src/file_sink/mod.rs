@@ -0,0 +1,596 @@
+//! Offline sink that writes the post-mix stream to a WAV or FLAC file instead of an
+//! audio device.
+//!
+//! The sink never blocks on a clock, so pairing it with the engine's free-running clock
+//! mode renders faster than realtime. Every prepare starts a new file at the route path.
+
+pub(crate) mod flac;
+pub(crate) mod wav;
+
+use std::fs::{self, File};
+use std::io;
+use std::path::PathBuf;
+
+use stellatune_audio::config::dither::SinkSampleFormat;
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+
+use flac::FlacWriter;
+use wav::WavWriter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WavSampleFormat {
+    Pcm16,
+    Pcm24,
+    Float32,
+}
+
+impl WavSampleFormat {
+    pub(crate) fn bytes_per_sample(self) -> u64 {
+        match self {
+            Self::Pcm16 => 2,
+            Self::Pcm24 => 3,
+            Self::Float32 => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileSinkFormat {
+    Wav(WavSampleFormat),
+    /// Integer FLAC at 16 or 24 bits per sample.
+    Flac {
+        bits_per_sample: u8,
+    },
+}
+
+impl FileSinkFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Wav(_) => "wav",
+            Self::Flac { .. } => "flac",
+        }
+    }
+
+    /// Sample format the encoder writes, used to drive the dither stage.
+    pub fn sample_format(self) -> SinkSampleFormat {
+        match self {
+            Self::Wav(WavSampleFormat::Pcm16)
+            | Self::Flac {
+                bits_per_sample: 16,
+            } => SinkSampleFormat::Int16,
+            Self::Wav(WavSampleFormat::Pcm24) | Self::Flac { .. } => SinkSampleFormat::Int24,
+            Self::Wav(WavSampleFormat::Float32) => SinkSampleFormat::Float32,
+        }
+    }
+
+    pub(crate) fn validate(self) -> Result<Self, String> {
+        match self {
+            Self::Flac { bits_per_sample } if !matches!(bits_per_sample, 16 | 24) => Err(format!(
+                "flac file sink supports 16 or 24 bits, got {bits_per_sample}"
+            )),
+            format => Ok(format),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FileSinkRoute {
+    pub path: PathBuf,
+    pub format: FileSinkFormat,
+    /// Rate the pipeline resamples to before the sink.
+    pub sample_rate: u32,
+    /// Channel count the mixer produces for the sink.
+    pub channels: u16,
+}
+
+impl FileSinkRoute {
+    pub fn new(
+        path: impl Into<PathBuf>,
+        format: FileSinkFormat,
+        sample_rate: u32,
+        channels: u16,
+    ) -> Result<Self, String> {
+        let path = path.into();
+        if path.as_os_str().is_empty() {
+            return Err("file sink path is empty".to_string());
+        }
+        if sample_rate == 0 || channels == 0 {
+            return Err(format!(
+                "invalid file sink spec: sample_rate={sample_rate} channels={channels}"
+            ));
+        }
+        let format = format.validate()?;
+        if matches!(format, FileSinkFormat::Flac { .. }) && channels > flac::MAX_CHANNELS {
+            return Err(format!(
+                "flac file sink supports at most {} channels, got {channels}",
+                flac::MAX_CHANNELS
+            ));
+        }
+        Ok(Self {
+            path,
+            format,
+            sample_rate,
+            channels,
+        })
+    }
+}
+
+/// Tags and artwork embedded where the container supports them. FLAC stores both;
+/// WAV keeps the common text fields in a `LIST/INFO` chunk and drops the cover.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileSinkMetadata {
+    /// Vorbis-comment style `(FIELD, value)` pairs such as `("TITLE", "...")`.
+    pub tags: Vec<(String, String)>,
+    pub cover: Option<FileSinkCover>,
+}
+
+impl FileSinkMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.cover.is_none()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSinkCover {
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+trait EncodedFile: Send {
+    fn write_interleaved(&mut self, samples: &[f32]) -> io::Result<()>;
+    /// Pushes buffered output to disk so the file is readable mid-render.
+    fn sync(&mut self) -> io::Result<()>;
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+pub struct FileSinkStage {
+    route: FileSinkRoute,
+    metadata: FileSinkMetadata,
+    writer: Option<Box<dyn EncodedFile>>,
+    write_error: Option<String>,
+}
+
+impl FileSinkStage {
+    pub fn new(route: FileSinkRoute) -> Self {
+        Self {
+            route,
+            metadata: FileSinkMetadata::default(),
+            writer: None,
+            write_error: None,
+        }
+    }
+
+    pub fn with_metadata(mut self, metadata: FileSinkMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn route(&self) -> &FileSinkRoute {
+        &self.route
+    }
+
+    fn open(&self, spec: StreamSpec) -> io::Result<Box<dyn EncodedFile>> {
+        if let Some(parent) = self.route.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let file = File::create(&self.route.path)?;
+        Ok(match self.route.format {
+            FileSinkFormat::Wav(format) => Box::new(WavWriter::create(
+                file,
+                spec.sample_rate,
+                spec.channels,
+                format,
+                &self.metadata,
+            )?),
+            FileSinkFormat::Flac { bits_per_sample } => Box::new(FlacWriter::create(
+                file,
+                spec.sample_rate,
+                spec.channels,
+                bits_per_sample as u32,
+                &self.metadata,
+            )?),
+        })
+    }
+
+    fn io_failure(&self, action: &str, error: io::Error) -> String {
+        format!(
+            "file sink {action} `{}` failed: {error}",
+            self.route.path.display()
+        )
+    }
+}
+
+impl SinkStage for FileSinkStage {
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        ctx: &mut PipelineContext,
+    ) -> Result<(), PipelineError> {
+        let spec = spec.validate()?;
+        if matches!(self.route.format, FileSinkFormat::Flac { .. })
+            && spec.channels > flac::MAX_CHANNELS
+        {
+            return Err(PipelineError::StageFailure(format!(
+                "flac file sink supports at most {} channels, got {}",
+                flac::MAX_CHANNELS,
+                spec.channels
+            )));
+        }
+        self.stop(ctx);
+        let writer = self
+            .open(spec)
+            .map_err(|error| PipelineError::StageFailure(self.io_failure("open", error)))?;
+        self.writer = Some(writer);
+        Ok(())
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn write(&mut self, block: &AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        let Some(writer) = self.writer.as_mut() else {
+            return StageStatus::Fatal;
+        };
+        match writer.write_interleaved(&block.samples) {
+            Ok(()) => StageStatus::Ok,
+            Err(error) => {
+                self.write_error = Some(self.io_failure("write", error));
+                StageStatus::Fatal
+            },
+        }
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        if let Some(error) = self.write_error.take() {
+            return Err(PipelineError::StageFailure(error));
+        }
+        let Some(writer) = self.writer.as_mut() else {
+            return Ok(());
+        };
+        writer
+            .sync()
+            .map_err(|error| PipelineError::StageFailure(self.io_failure("flush", error)))
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        if let Some(writer) = self.writer.take() {
+            // `stop` cannot report failures; a failed finalize leaves sizes unpatched,
+            // which most readers tolerate.
+            let _ = writer.finish();
+        }
+        self.write_error = None;
+    }
+}
+
+fn quantize(sample: f32, bits: u32) -> i32 {
+    let peak = ((1_i64 << (bits - 1)) - 1) as f32;
+    (sample.clamp(-1.0, 1.0) * peak).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io;
+    use std::path::Path;
+
+    use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+    use stellatune_audio_core::pipeline::stages::StageStatus;
+    use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+    use symphonia::core::audio::SampleBuffer;
+    use symphonia::core::codecs::DecoderOptions;
+    use symphonia::core::errors::Error as SymphoniaError;
+    use symphonia::core::formats::FormatOptions;
+    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::meta::MetadataOptions;
+    use symphonia::core::probe::Hint;
+
+    use super::{
+        FileSinkCover, FileSinkFormat, FileSinkMetadata, FileSinkRoute, FileSinkStage,
+        WavSampleFormat, quantize,
+    };
+
+    const SAMPLE_RATE: u32 = 44_100;
+
+    fn test_signal(frames: usize, channels: u16) -> Vec<f32> {
+        (0..frames)
+            .flat_map(|frame| {
+                (0..channels).map(move |channel| {
+                    let phase =
+                        frame as f32 * (220.0 + 110.0 * channel as f32) / SAMPLE_RATE as f32;
+                    0.8 * (phase * std::f32::consts::TAU).sin()
+                })
+            })
+            .collect()
+    }
+
+    fn render(path: &Path, format: FileSinkFormat, channels: u16, samples: &[f32]) {
+        render_with_metadata(path, format, channels, samples, FileSinkMetadata::default());
+    }
+
+    fn render_with_metadata(
+        path: &Path,
+        format: FileSinkFormat,
+        channels: u16,
+        samples: &[f32],
+        metadata: FileSinkMetadata,
+    ) {
+        let route = FileSinkRoute::new(path, format, SAMPLE_RATE, channels).expect("route");
+        let mut sink = FileSinkStage::new(route).with_metadata(metadata);
+        let mut ctx = PipelineContext::default();
+        sink.prepare(
+            StreamSpec {
+                sample_rate: SAMPLE_RATE,
+                channels,
+            },
+            &mut ctx,
+        )
+        .expect("prepare");
+        // Uneven blocks exercise partial encoder blocks across writes.
+        for chunk in samples.chunks(1000 * channels as usize - channels as usize) {
+            let block = AudioBlock {
+                channels,
+                samples: chunk.to_vec(),
+            };
+            assert_eq!(sink.write(&block, &mut ctx), StageStatus::Ok);
+        }
+        sink.flush(&mut ctx).expect("flush");
+        sink.stop(&mut ctx);
+    }
+
+    fn read_tags(path: &Path) -> (Vec<(String, String)>, Option<FileSinkCover>) {
+        let file = File::open(path).expect("open rendered file");
+        let mss = MediaSourceStream::new(Box::new(file), Default::default());
+        let mut probed = symphonia::default::get_probe()
+            .format(
+                &Hint::new(),
+                mss,
+                &FormatOptions::default(),
+                &MetadataOptions::default(),
+            )
+            .expect("probe");
+        let metadata = probed.format.metadata();
+        let Some(revision) = metadata.current() else {
+            return (Vec::new(), None);
+        };
+        let tags = revision
+            .tags()
+            .iter()
+            // RIFF INFO strings keep their zero terminator through the reader.
+            .map(|tag| {
+                let value = tag.value.to_string();
+                (tag.key.clone(), value.trim_end_matches('\0').to_string())
+            })
+            .collect();
+        let cover = revision.visuals().first().map(|visual| FileSinkCover {
+            mime_type: visual.media_type.clone(),
+            data: visual.data.to_vec(),
+        });
+        (tags, cover)
+    }
+
+    fn test_metadata() -> FileSinkMetadata {
+        FileSinkMetadata {
+            tags: vec![
+                ("title".to_string(), "Night Drive".to_string()),
+                ("ARTIST".to_string(), "Ösel".to_string()),
+                ("TRACKNUMBER".to_string(), "7".to_string()),
+            ],
+            cover: Some(FileSinkCover {
+                mime_type: "image/png".to_string(),
+                data: vec![0x89, b'P', b'N', b'G', 1, 2, 3],
+            }),
+        }
+    }
+
+    fn decode(path: &Path) -> (u32, usize, Vec<f32>) {
+        let file = File::open(path).expect("open rendered file");
+        let mss = MediaSourceStream::new(Box::new(file), Default::default());
+        let mut hint = Hint::new();
+        if let Some(extension) = path.extension().and_then(|value| value.to_str()) {
+            hint.with_extension(extension);
+        }
+        let mut format = symphonia::default::get_probe()
+            .format(
+                &hint,
+                mss,
+                &FormatOptions::default(),
+                &MetadataOptions::default(),
+            )
+            .expect("probe")
+            .format;
+        let track = format.default_track().expect("track").clone();
+        let mut decoder = symphonia::default::get_codecs()
+            .make(&track.codec_params, &DecoderOptions { verify: true })
+            .expect("decoder");
+        let mut out = Vec::new();
+        let mut spec = None;
+        loop {
+            let packet = match format.next_packet() {
+                Ok(packet) => packet,
+                Err(SymphoniaError::IoError(error))
+                    if error.kind() == io::ErrorKind::UnexpectedEof =>
+                {
+                    break;
+                },
+                Err(error) => panic!("read packet: {error}"),
+            };
+            let decoded = decoder.decode(&packet).expect("decode packet");
+            spec = Some(*decoded.spec());
+            let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, *decoded.spec());
+            buffer.copy_interleaved_ref(decoded);
+            out.extend_from_slice(buffer.samples());
+        }
+        let spec = spec.expect("decoded at least one packet");
+        (spec.rate, spec.channels.count(), out)
+    }
+
+    fn assert_round_trip(format: FileSinkFormat, bits: u32, channels: u16, frames: usize) {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir
+            .path()
+            .join("nested")
+            .join(format!("render.{}", format.extension()));
+        let samples = test_signal(frames, channels);
+        render(&path, format, channels, &samples);
+
+        let (rate, decoded_channels, decoded) = decode(&path);
+        assert_eq!(rate, SAMPLE_RATE);
+        assert_eq!(decoded_channels, channels as usize);
+        assert_eq!(decoded.len(), samples.len());
+        let step = 1.0 / ((1_i64 << (bits - 1)) - 1) as f32;
+        for (index, (&expected, &actual)) in samples.iter().zip(&decoded).enumerate() {
+            let expected = quantize(expected, bits) as f32 * step;
+            assert!(
+                (expected - actual).abs() <= step,
+                "sample {index}: expected {expected}, decoded {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn wav_formats_round_trip_through_a_decoder() {
+        assert_round_trip(FileSinkFormat::Wav(WavSampleFormat::Pcm16), 16, 2, 5_000);
+        assert_round_trip(FileSinkFormat::Wav(WavSampleFormat::Pcm24), 24, 1, 5_000);
+        assert_round_trip(FileSinkFormat::Wav(WavSampleFormat::Float32), 24, 2, 5_000);
+    }
+
+    #[test]
+    fn flac_round_trips_losslessly_across_full_and_partial_blocks() {
+        assert_round_trip(
+            FileSinkFormat::Flac {
+                bits_per_sample: 16,
+            },
+            16,
+            2,
+            10_000,
+        );
+        assert_round_trip(
+            FileSinkFormat::Flac {
+                bits_per_sample: 24,
+            },
+            24,
+            6,
+            4_096,
+        );
+        assert_round_trip(
+            FileSinkFormat::Flac {
+                bits_per_sample: 16,
+            },
+            16,
+            1,
+            100,
+        );
+    }
+
+    #[test]
+    fn flac_encodes_silence_and_clipped_input() {
+        let mut samples = vec![0.0; 8_192];
+        samples.extend(std::iter::repeat_n(1.5, 2_000));
+        samples.extend(std::iter::repeat_n(-1.5, 2_000));
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("edge.flac");
+        render(
+            &path,
+            FileSinkFormat::Flac {
+                bits_per_sample: 16,
+            },
+            1,
+            &samples,
+        );
+        let (_, _, decoded) = decode(&path);
+        assert_eq!(decoded.len(), samples.len());
+        assert!(decoded[..8_192].iter().all(|&sample| sample == 0.0));
+        assert!(decoded[8_192..10_192].iter().all(|&sample| sample > 0.999));
+        assert!(decoded[10_192..].iter().all(|&sample| sample < -0.999));
+    }
+
+    #[test]
+    fn flac_embeds_vorbis_comments_and_front_cover() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("tagged.flac");
+        let samples = test_signal(3_000, 2);
+        let metadata = test_metadata();
+        render_with_metadata(
+            &path,
+            FileSinkFormat::Flac {
+                bits_per_sample: 16,
+            },
+            2,
+            &samples,
+            metadata.clone(),
+        );
+
+        let (tags, cover) = read_tags(&path);
+        assert_eq!(
+            tags,
+            vec![
+                ("TITLE".to_string(), "Night Drive".to_string()),
+                ("ARTIST".to_string(), "Ösel".to_string()),
+                ("TRACKNUMBER".to_string(), "7".to_string()),
+            ]
+        );
+        assert_eq!(cover, metadata.cover);
+        assert_eq!(decode(&path).2.len(), samples.len());
+    }
+
+    #[test]
+    fn wav_writes_info_tags_ahead_of_sample_data() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("tagged.wav");
+        let samples = test_signal(1_001, 1);
+        render_with_metadata(
+            &path,
+            FileSinkFormat::Wav(WavSampleFormat::Pcm24),
+            1,
+            &samples,
+            test_metadata(),
+        );
+
+        let (tags, cover) = read_tags(&path);
+        assert!(
+            tags.contains(&("INAM".to_string(), "Night Drive".to_string())),
+            "{tags:?}"
+        );
+        assert!(
+            tags.contains(&("IART".to_string(), "Ösel".to_string())),
+            "{tags:?}"
+        );
+        assert!(cover.is_none());
+        assert_eq!(decode(&path).2.len(), samples.len());
+    }
+
+    #[test]
+    fn route_rejects_unsupported_flac_depth_and_empty_path() {
+        assert!(
+            FileSinkRoute::new("", FileSinkFormat::Wav(WavSampleFormat::Pcm16), 48_000, 2).is_err()
+        );
+        assert!(
+            FileSinkRoute::new(
+                "a.flac",
+                FileSinkFormat::Flac {
+                    bits_per_sample: 32
+                },
+                48_000,
+                2
+            )
+            .is_err()
+        );
+        assert!(
+            FileSinkRoute::new(
+                "a.flac",
+                FileSinkFormat::Flac {
+                    bits_per_sample: 16
+                },
+                48_000,
+                9
+            )
+            .is_err()
+        );
+    }
+}
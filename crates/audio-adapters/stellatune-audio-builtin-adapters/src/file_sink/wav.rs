@@ -0,0 +1,168 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+
+use super::{EncodedFile, FileSinkMetadata, WavSampleFormat, quantize};
+
+const RIFF_SIZE_OFFSET: u64 = 4;
+const CANONICAL_HEADER_BYTES: u32 = 36;
+const FORMAT_TAG_PCM: u16 = 1;
+const FORMAT_TAG_IEEE_FLOAT: u16 = 3;
+const INFO_FIELDS: [(&str, &[u8; 4]); 7] = [
+    ("TITLE", b"INAM"),
+    ("ARTIST", b"IART"),
+    ("ALBUM", b"IPRD"),
+    ("GENRE", b"IGNR"),
+    ("DATE", b"ICRD"),
+    ("TRACKNUMBER", b"ITRK"),
+    ("COMMENT", b"ICMT"),
+];
+
+/// Canonical 44-byte-header WAV writer. Chunk sizes are patched on every sync, so the
+/// file stays readable while rendering is still in progress. Tags go into a `LIST/INFO`
+/// chunk ahead of `data`, where streaming readers still see them; WAV has no standard
+/// cover slot.
+pub(super) struct WavWriter {
+    file: BufWriter<File>,
+    format: WavSampleFormat,
+    data_bytes: u64,
+    scratch: Vec<u8>,
+    /// Bytes between `RIFF` size and the start of sample data, excluding `data` size.
+    header_bytes: u32,
+}
+
+impl WavWriter {
+    pub(super) fn create(
+        file: File,
+        sample_rate: u32,
+        channels: u16,
+        format: WavSampleFormat,
+        metadata: &FileSinkMetadata,
+    ) -> io::Result<Self> {
+        let mut file = BufWriter::new(file);
+        let info_chunk = info_chunk(metadata);
+        let header_bytes = CANONICAL_HEADER_BYTES + info_chunk.len() as u32;
+        file.write_all(&header(sample_rate, channels, format, &info_chunk, 0))?;
+        Ok(Self {
+            file,
+            format,
+            data_bytes: 0,
+            scratch: Vec::new(),
+            header_bytes,
+        })
+    }
+}
+
+/// Header of a live WAV stream. Both sizes are left at their maximum, which players
+/// treat as "read until the connection closes".
+pub(crate) fn stream_header(sample_rate: u32, channels: u16, format: WavSampleFormat) -> Vec<u8> {
+    header(sample_rate, channels, format, &[], u32::MAX)
+}
+
+fn header(
+    sample_rate: u32,
+    channels: u16,
+    format: WavSampleFormat,
+    info_chunk: &[u8],
+    data_bytes: u32,
+) -> Vec<u8> {
+    let (format_tag, bits) = match format {
+        WavSampleFormat::Pcm16 => (FORMAT_TAG_PCM, 16_u16),
+        WavSampleFormat::Pcm24 => (FORMAT_TAG_PCM, 24),
+        WavSampleFormat::Float32 => (FORMAT_TAG_IEEE_FLOAT, 32),
+    };
+    let block_align = channels * (bits / 8);
+    let header_bytes = CANONICAL_HEADER_BYTES + info_chunk.len() as u32;
+    let mut out = Vec::with_capacity(header_bytes as usize + 8);
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&header_bytes.saturating_add(data_bytes).to_le_bytes());
+    out.extend_from_slice(b"WAVEfmt ");
+    out.extend_from_slice(&16_u32.to_le_bytes());
+    out.extend_from_slice(&format_tag.to_le_bytes());
+    out.extend_from_slice(&channels.to_le_bytes());
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+    out.extend_from_slice(&block_align.to_le_bytes());
+    out.extend_from_slice(&bits.to_le_bytes());
+    out.extend_from_slice(info_chunk);
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_bytes.to_le_bytes());
+    out
+}
+
+/// Appends `samples` to `out` in the little-endian layout of `format`.
+pub(crate) fn encode_samples(format: WavSampleFormat, samples: &[f32], out: &mut Vec<u8>) {
+    out.reserve(samples.len() * format.bytes_per_sample() as usize);
+    for &sample in samples {
+        match format {
+            WavSampleFormat::Pcm16 => {
+                out.extend_from_slice(&(quantize(sample, 16) as i16).to_le_bytes())
+            },
+            WavSampleFormat::Pcm24 => {
+                out.extend_from_slice(&quantize(sample, 24).to_le_bytes()[..3])
+            },
+            WavSampleFormat::Float32 => out.extend_from_slice(&sample.to_le_bytes()),
+        }
+    }
+}
+
+fn info_chunk(metadata: &FileSinkMetadata) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (key, value) in &metadata.tags {
+        let Some((_, id)) = INFO_FIELDS
+            .iter()
+            .find(|(field, _)| field.eq_ignore_ascii_case(key))
+        else {
+            continue;
+        };
+        // Zero-terminated text, padded to an even length.
+        let len = value.len() + 1;
+        let Ok(size) = u32::try_from(len) else {
+            continue;
+        };
+        body.extend_from_slice(*id);
+        body.extend_from_slice(&size.to_le_bytes());
+        body.extend_from_slice(value.as_bytes());
+        body.push(0);
+        if len % 2 == 1 {
+            body.push(0);
+        }
+    }
+    if body.is_empty() {
+        return body;
+    }
+    let mut chunk = Vec::with_capacity(body.len() + 12);
+    chunk.extend_from_slice(b"LIST");
+    chunk.extend_from_slice(&(body.len() as u32 + 4).to_le_bytes());
+    chunk.extend_from_slice(b"INFO");
+    chunk.extend_from_slice(&body);
+    chunk
+}
+
+impl EncodedFile for WavWriter {
+    fn write_interleaved(&mut self, samples: &[f32]) -> io::Result<()> {
+        self.scratch.clear();
+        encode_samples(self.format, samples, &mut self.scratch);
+        self.file.write_all(&self.scratch)?;
+        self.data_bytes += samples.len() as u64 * self.format.bytes_per_sample();
+        Ok(())
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        // RIFF sizes are 32-bit; past 4 GiB the header saturates and readers fall back
+        // to the file length.
+        let data_bytes = u32::try_from(self.data_bytes).unwrap_or(u32::MAX);
+        self.file.seek(SeekFrom::Start(RIFF_SIZE_OFFSET))?;
+        self.file
+            .write_all(&self.header_bytes.saturating_add(data_bytes).to_le_bytes())?;
+        // The data size field sits right before the samples.
+        self.file
+            .seek(SeekFrom::Start(u64::from(self.header_bytes) + 4))?;
+        self.file.write_all(&data_bytes.to_le_bytes())?;
+        self.file.seek(SeekFrom::End(0))?;
+        self.file.flush()
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        self.sync()
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use stellatune_audio_core::pipeline::context::StreamSpec;
+
+use crate::file_sink::{FileSinkFormat, WavSampleFormat, flac, wav};
+
+/// Creates the encoder for a live stream. Implementations own the container and codec,
+/// which keeps lossy formats (Ogg-Opus, MP3) pluggable without touching the sink or hub.
+pub trait HttpStreamEncoder: Send + Sync {
+    /// Stable id covering the format and its settings, e.g. `"flac-16"`. Routes with the
+    /// same id are the same stream to the pipeline, so listeners stay attached.
+    fn id(&self) -> &str;
+    fn content_type(&self) -> &'static str;
+    /// Upper bound on encoded bytes per second of audio, used to size listener buffers.
+    fn max_byte_rate(&self, spec: StreamSpec) -> u64;
+    fn create(&self, spec: StreamSpec) -> Result<Box<dyn StreamEncoder>, String>;
+}
+
+/// Encodes PCM into self-contained chunks a listener can start from.
+pub trait StreamEncoder: Send {
+    /// Sent to every listener before its first chunk.
+    fn header(&self) -> Vec<u8>;
+    fn encode(&mut self, samples: &[f32], emit: &mut dyn FnMut(Vec<u8>));
+    /// The now-playing title changed. Containers that carry metadata in band, such as an
+    /// Ogg stream chaining a new comment header, emit it here. ICY listeners get titles
+    /// out of band either way, so the default ignores it.
+    fn set_title(&mut self, _title: Option<&str>, _emit: &mut dyn FnMut(Vec<u8>)) {}
+}
+
+/// The built-in WAV or FLAC encoder for `format`.
+pub(super) fn builtin_encoder(
+    format: FileSinkFormat,
+) -> Result<Arc<dyn HttpStreamEncoder>, String> {
+    Ok(match format.validate()? {
+        FileSinkFormat::Wav(format) => {
+            let id = match format {
+                WavSampleFormat::Pcm16 => "wav-pcm16",
+                WavSampleFormat::Pcm24 => "wav-pcm24",
+                WavSampleFormat::Float32 => "wav-f32",
+            };
+            Arc::new(WavHttpStreamEncoder {
+                id: id.to_string(),
+                format,
+            })
+        },
+        FileSinkFormat::Flac { bits_per_sample } => Arc::new(FlacHttpStreamEncoder {
+            id: format!("flac-{bits_per_sample}"),
+            bits: u32::from(bits_per_sample),
+        }),
+    })
+}
+
+struct WavHttpStreamEncoder {
+    id: String,
+    format: WavSampleFormat,
+}
+
+impl HttpStreamEncoder for WavHttpStreamEncoder {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn content_type(&self) -> &'static str {
+        "audio/wav"
+    }
+
+    fn max_byte_rate(&self, spec: StreamSpec) -> u64 {
+        u64::from(spec.sample_rate) * u64::from(spec.channels) * self.format.bytes_per_sample()
+    }
+
+    fn create(&self, spec: StreamSpec) -> Result<Box<dyn StreamEncoder>, String> {
+        Ok(Box::new(WavStreamEncoder {
+            spec,
+            format: self.format,
+        }))
+    }
+}
+
+struct WavStreamEncoder {
+    spec: StreamSpec,
+    format: WavSampleFormat,
+}
+
+impl StreamEncoder for WavStreamEncoder {
+    fn header(&self) -> Vec<u8> {
+        wav::stream_header(self.spec.sample_rate, self.spec.channels, self.format)
+    }
+
+    fn encode(&mut self, samples: &[f32], emit: &mut dyn FnMut(Vec<u8>)) {
+        let mut chunk = Vec::new();
+        wav::encode_samples(self.format, samples, &mut chunk);
+        emit(chunk);
+    }
+}
+
+struct FlacHttpStreamEncoder {
+    id: String,
+    bits: u32,
+}
+
+impl HttpStreamEncoder for FlacHttpStreamEncoder {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn content_type(&self) -> &'static str {
+        "audio/flac"
+    }
+
+    fn max_byte_rate(&self, spec: StreamSpec) -> u64 {
+        // Upper bound: FLAC frames never exceed the PCM they encode by much.
+        let bytes_per_sample = u64::from(self.bits).div_ceil(8);
+        u64::from(spec.sample_rate) * u64::from(spec.channels) * bytes_per_sample
+    }
+
+    fn create(&self, spec: StreamSpec) -> Result<Box<dyn StreamEncoder>, String> {
+        if spec.channels > flac::MAX_CHANNELS {
+            return Err(format!(
+                "flac stream supports at most {} channels, got {}",
+                flac::MAX_CHANNELS,
+                spec.channels
+            ));
+        }
+        Ok(Box::new(FlacStreamEncoder {
+            spec,
+            bits: self.bits,
+            frames: flac::FlacFrameEncoder::new(spec.channels, self.bits),
+        }))
+    }
+}
+
+struct FlacStreamEncoder {
+    spec: StreamSpec,
+    bits: u32,
+    frames: flac::FlacFrameEncoder,
+}
+
+impl StreamEncoder for FlacStreamEncoder {
+    fn header(&self) -> Vec<u8> {
+        flac::stream_header(self.spec.sample_rate, self.spec.channels, self.bits)
+    }
+
+    fn encode(&mut self, samples: &[f32], emit: &mut dyn FnMut(Vec<u8>)) {
+        // One chunk per frame keeps every chunk boundary a valid resync point.
+        let _ = self.frames.push_interleaved(samples, |frame, _| {
+            emit(frame.to_vec());
+            Ok::<(), ()>(())
+        });
+    }
+}
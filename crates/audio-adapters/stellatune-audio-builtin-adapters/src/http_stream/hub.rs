@@ -0,0 +1,365 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use super::HttpStreamDropPolicy;
+
+/// Audio bytes between two ICY metadata blocks.
+pub const ICY_METAINT: usize = 16 * 1024;
+const ICY_MAX_METADATA_BYTES: usize = 255 * 16;
+
+/// Encoded stream parameters every client of one stream shares.
+pub(super) struct StreamStart {
+    pub(super) content_type: &'static str,
+    pub(super) header: Vec<u8>,
+    pub(super) max_client_bytes: usize,
+    pub(super) drop_policy: HttpStreamDropPolicy,
+}
+
+struct ActiveStream {
+    content_type: &'static str,
+    header: Arc<[u8]>,
+    max_client_bytes: usize,
+    drop_policy: HttpStreamDropPolicy,
+}
+
+#[derive(Default)]
+struct HubState {
+    stream: Option<ActiveStream>,
+    clients: Vec<Arc<ClientShared>>,
+    next_client_id: u64,
+    title: Option<String>,
+    title_version: u64,
+}
+
+struct ClientShared {
+    id: u64,
+    label: String,
+    queue: Mutex<ClientQueue>,
+}
+
+#[derive(Default)]
+struct ClientQueue {
+    /// Sent ahead of everything else and never dropped, so a client that falls behind
+    /// still gets a decodable stream.
+    header: Option<Arc<[u8]>>,
+    chunks: VecDeque<Arc<[u8]>>,
+    queued_bytes: usize,
+    sent_bytes: u64,
+    dropped_bytes: u64,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl ClientShared {
+    fn lock(&self) -> MutexGuard<'_, ClientQueue> {
+        self.queue
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Queues one chunk, applying the drop policy once the client is too far behind.
+    /// Returns the waker to notify outside the queue lock.
+    fn push(&self, chunk: &Arc<[u8]>, stream: &ActiveStream) -> Option<Waker> {
+        let mut queue = self.lock();
+        if queue.closed {
+            return None;
+        }
+        queue.chunks.push_back(Arc::clone(chunk));
+        queue.queued_bytes += chunk.len();
+        while queue.queued_bytes > stream.max_client_bytes {
+            match stream.drop_policy {
+                HttpStreamDropPolicy::DropOldest if queue.chunks.len() > 1 => {
+                    let Some(dropped) = queue.chunks.pop_front() else {
+                        break;
+                    };
+                    queue.queued_bytes -= dropped.len();
+                    queue.dropped_bytes += dropped.len() as u64;
+                },
+                HttpStreamDropPolicy::DropOldest => break,
+                HttpStreamDropPolicy::Disconnect => {
+                    queue.dropped_bytes += queue.queued_bytes as u64;
+                    queue.chunks.clear();
+                    queue.queued_bytes = 0;
+                    queue.closed = true;
+                },
+            }
+        }
+        queue.waker.take()
+    }
+
+    fn close(&self) -> Option<Waker> {
+        let mut queue = self.lock();
+        queue.closed = true;
+        queue.waker.take()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpStreamClientSnapshot {
+    pub id: u64,
+    /// Caller-supplied description, usually the remote address.
+    pub label: String,
+    pub queued_bytes: usize,
+    pub sent_bytes: u64,
+    pub dropped_bytes: u64,
+}
+
+/// Fan-out point between the stream sink and HTTP connections.
+///
+/// The sink publishes encoded chunks without ever blocking; each client keeps its own
+/// bounded queue, so one slow listener only loses its own audio.
+#[derive(Clone, Default)]
+pub struct HttpStreamHub {
+    state: Arc<Mutex<HubState>>,
+}
+
+impl HttpStreamHub {
+    fn lock(&self) -> MutexGuard<'_, HubState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Starts (or continues) a stream. Clients stay connected when the encoded header
+    /// is unchanged; otherwise they are closed so players reconnect and re-read it.
+    pub(super) fn begin_stream(&self, start: StreamStart) {
+        let wakers = {
+            let mut state = self.lock();
+            let unchanged = state.stream.as_ref().is_some_and(|stream| {
+                stream.content_type == start.content_type && *stream.header == *start.header
+            });
+            let wakers = if unchanged {
+                Vec::new()
+            } else {
+                close_clients(&mut state)
+            };
+            state.stream = Some(ActiveStream {
+                content_type: start.content_type,
+                header: start.header.into(),
+                max_client_bytes: start.max_client_bytes.max(1),
+                drop_policy: start.drop_policy,
+            });
+            wakers
+        };
+        wakers.into_iter().for_each(Waker::wake);
+    }
+
+    /// Ends the stream and disconnects every client.
+    pub fn end_stream(&self) {
+        let wakers = {
+            let mut state = self.lock();
+            state.stream = None;
+            close_clients(&mut state)
+        };
+        wakers.into_iter().for_each(Waker::wake);
+    }
+
+    pub(super) fn publish(&self, chunk: Vec<u8>) {
+        if chunk.is_empty() {
+            return;
+        }
+        let chunk: Arc<[u8]> = chunk.into();
+        let wakers = {
+            let mut state = self.lock();
+            let HubState {
+                stream, clients, ..
+            } = &mut *state;
+            let Some(stream) = stream.as_ref() else {
+                return;
+            };
+            let wakers = clients
+                .iter()
+                .filter_map(|client| client.push(&chunk, stream))
+                .collect::<Vec<_>>();
+            clients.retain(|client| !client.lock().closed);
+            wakers
+        };
+        wakers.into_iter().for_each(Waker::wake);
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.lock().stream.is_some()
+    }
+
+    /// Sets the now-playing title sent to clients that asked for ICY metadata.
+    pub fn set_title(&self, title: Option<String>) {
+        let mut state = self.lock();
+        if state.title != title {
+            state.title = title;
+            state.title_version += 1;
+        }
+    }
+
+    pub(super) fn title(&self) -> (u64, Option<String>) {
+        let state = self.lock();
+        (state.title_version, state.title.clone())
+    }
+
+    /// Attaches a new listener; `None` while no stream is running.
+    pub fn subscribe(
+        &self,
+        label: impl Into<String>,
+        icy_metadata: bool,
+    ) -> Option<HttpStreamClient> {
+        let mut state = self.lock();
+        let stream = state.stream.as_ref()?;
+        let content_type = stream.content_type;
+        let queue = ClientQueue {
+            header: Some(Arc::clone(&stream.header)),
+            ..ClientQueue::default()
+        };
+        state.next_client_id += 1;
+        let shared = Arc::new(ClientShared {
+            id: state.next_client_id,
+            label: label.into(),
+            queue: Mutex::new(queue),
+        });
+        state.clients.push(Arc::clone(&shared));
+        Some(HttpStreamClient {
+            hub: self.clone(),
+            shared,
+            content_type,
+            icy: icy_metadata.then(IcyFramer::default),
+        })
+    }
+
+    pub fn clients(&self) -> Vec<HttpStreamClientSnapshot> {
+        self.lock()
+            .clients
+            .iter()
+            .map(|client| {
+                let queue = client.lock();
+                HttpStreamClientSnapshot {
+                    id: client.id,
+                    label: client.label.clone(),
+                    queued_bytes: queue.queued_bytes,
+                    sent_bytes: queue.sent_bytes,
+                    dropped_bytes: queue.dropped_bytes,
+                }
+            })
+            .collect()
+    }
+
+    fn remove_client(&self, id: u64) {
+        self.lock().clients.retain(|client| client.id != id);
+    }
+}
+
+fn close_clients(state: &mut HubState) -> Vec<Waker> {
+    state
+        .clients
+        .drain(..)
+        .filter_map(|client| client.close())
+        .collect()
+}
+
+/// One HTTP listener. Dropping it detaches the client from the hub.
+pub struct HttpStreamClient {
+    hub: HttpStreamHub,
+    shared: Arc<ClientShared>,
+    content_type: &'static str,
+    icy: Option<IcyFramer>,
+}
+
+impl HttpStreamClient {
+    pub fn content_type(&self) -> &'static str {
+        self.content_type
+    }
+
+    /// Value for the `icy-metaint` response header, when ICY metadata was requested.
+    pub fn icy_metaint(&self) -> Option<usize> {
+        self.icy.as_ref().map(|_| ICY_METAINT)
+    }
+
+    /// Next body chunk, or `None` once the client was closed.
+    pub fn poll_chunk(&mut self, cx: &mut Context<'_>) -> Poll<Option<Vec<u8>>> {
+        let chunk = {
+            let mut queue = self.shared.lock();
+            let chunk = match queue.header.take() {
+                Some(header) => Some(header),
+                None => queue.chunks.pop_front().inspect(|chunk| {
+                    queue.queued_bytes -= chunk.len();
+                }),
+            };
+            match chunk {
+                Some(chunk) => {
+                    queue.sent_bytes += chunk.len() as u64;
+                    chunk
+                },
+                None if queue.closed => return Poll::Ready(None),
+                None => {
+                    queue.waker = Some(cx.waker().clone());
+                    return Poll::Pending;
+                },
+            }
+        };
+        let body = match self.icy.as_mut() {
+            Some(icy) => icy.frame(&chunk, &self.hub),
+            None => chunk.to_vec(),
+        };
+        Poll::Ready(Some(body))
+    }
+}
+
+impl Drop for HttpStreamClient {
+    fn drop(&mut self) {
+        self.hub.remove_client(self.shared.id);
+    }
+}
+
+/// Interleaves SHOUTcast-style metadata blocks into the body every [`ICY_METAINT`]
+/// bytes. A block is empty unless the title changed since the previous one.
+struct IcyFramer {
+    until_metadata: usize,
+    sent_title_version: u64,
+}
+
+impl Default for IcyFramer {
+    fn default() -> Self {
+        Self {
+            until_metadata: ICY_METAINT,
+            sent_title_version: 0,
+        }
+    }
+}
+
+impl IcyFramer {
+    fn frame(&mut self, mut data: &[u8], hub: &HttpStreamHub) -> Vec<u8> {
+        let mut out = Vec::with_capacity(data.len() + 1);
+        while !data.is_empty() {
+            let take = self.until_metadata.min(data.len());
+            out.extend_from_slice(&data[..take]);
+            data = &data[take..];
+            self.until_metadata -= take;
+            if self.until_metadata == 0 {
+                self.write_metadata(&mut out, hub);
+                self.until_metadata = ICY_METAINT;
+            }
+        }
+        out
+    }
+
+    fn write_metadata(&mut self, out: &mut Vec<u8>, hub: &HttpStreamHub) {
+        let (version, title) = hub.title();
+        if version == self.sent_title_version {
+            out.push(0);
+            return;
+        }
+        self.sent_title_version = version;
+        // Quotes delimit the value and players do not agree on an escape.
+        let mut title = title.unwrap_or_default().replace('\'', "\u{2019}");
+        let mut end = title
+            .len()
+            .min(ICY_MAX_METADATA_BYTES - "StreamTitle='';".len());
+        while !title.is_char_boundary(end) {
+            end -= 1;
+        }
+        title.truncate(end);
+        let mut text = format!("StreamTitle='{title}';").into_bytes();
+        let blocks = text.len().div_ceil(16);
+        text.resize(blocks * 16, 0);
+        out.push(blocks as u8);
+        out.extend_from_slice(&text);
+    }
+}
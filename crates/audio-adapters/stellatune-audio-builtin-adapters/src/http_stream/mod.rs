@@ -0,0 +1,465 @@
+//! Live stream sink that serves the post-mix output to HTTP listeners on the LAN.
+//!
+//! The sink encodes blocks as they arrive and hands them to an [`HttpStreamHub`]; the
+//! HTTP server owns the connections and pulls per-client chunks from the hub. Writes
+//! never wait on the network, so the sink is meant to sit behind a tee next to a
+//! device sink that paces playback.
+//!
+//! The route carries an [`HttpStreamEncoder`]. WAV and FLAC are built in; other formats,
+//! such as Ogg-Opus or MP3 backed by native libopus or LAME bindings, plug in as their own
+//! encoders. Titles reach ICY listeners out of band, and encoders whose container carries
+//! metadata in band get them through [`StreamEncoder::set_title`].
+
+mod encoder;
+mod hub;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+
+use crate::file_sink::FileSinkFormat;
+
+pub use encoder::{HttpStreamEncoder, StreamEncoder};
+pub use hub::{HttpStreamClient, HttpStreamClientSnapshot, HttpStreamHub, ICY_METAINT};
+
+const DEFAULT_CLIENT_BUFFER_MS: u32 = 2_000;
+
+/// What happens to a listener whose queue outgrows its buffer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HttpStreamDropPolicy {
+    /// Skip the oldest queued audio; the listener hears a gap and stays connected.
+    #[default]
+    DropOldest,
+    /// Close the connection; players reconnect and start from the live edge.
+    Disconnect,
+}
+
+/// Routes compare and hash by encoder id, so two routes built for the same format are
+/// the same stream.
+#[derive(Clone)]
+pub struct HttpStreamRoute {
+    pub encoder: Arc<dyn HttpStreamEncoder>,
+    /// Audio a listener may fall behind before the drop policy applies.
+    pub client_buffer_ms: u32,
+    pub drop_policy: HttpStreamDropPolicy,
+}
+
+impl HttpStreamRoute {
+    /// A route using the built-in WAV or FLAC encoder.
+    pub fn new(format: FileSinkFormat) -> Result<Self, String> {
+        Ok(Self::with_encoder(encoder::builtin_encoder(format)?))
+    }
+
+    pub fn with_encoder(encoder: Arc<dyn HttpStreamEncoder>) -> Self {
+        Self {
+            encoder,
+            client_buffer_ms: DEFAULT_CLIENT_BUFFER_MS,
+            drop_policy: HttpStreamDropPolicy::default(),
+        }
+    }
+
+    pub fn with_client_buffer_ms(mut self, client_buffer_ms: u32) -> Self {
+        self.client_buffer_ms = client_buffer_ms;
+        self
+    }
+
+    pub fn with_drop_policy(mut self, drop_policy: HttpStreamDropPolicy) -> Self {
+        self.drop_policy = drop_policy;
+        self
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        self.encoder.content_type()
+    }
+
+    fn max_client_bytes(&self, spec: StreamSpec) -> usize {
+        let byte_rate = self.encoder.max_byte_rate(spec);
+        (byte_rate * u64::from(self.client_buffer_ms) / 1000) as usize
+    }
+}
+
+impl fmt::Debug for HttpStreamRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpStreamRoute")
+            .field("encoder", &self.encoder.id())
+            .field("client_buffer_ms", &self.client_buffer_ms)
+            .field("drop_policy", &self.drop_policy)
+            .finish()
+    }
+}
+
+impl PartialEq for HttpStreamRoute {
+    fn eq(&self, other: &Self) -> bool {
+        self.encoder.id() == other.encoder.id()
+            && self.client_buffer_ms == other.client_buffer_ms
+            && self.drop_policy == other.drop_policy
+    }
+}
+
+impl Eq for HttpStreamRoute {}
+
+impl Hash for HttpStreamRoute {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encoder.id().hash(state);
+        self.client_buffer_ms.hash(state);
+        self.drop_policy.hash(state);
+    }
+}
+
+pub struct HttpStreamSinkStage {
+    route: HttpStreamRoute,
+    hub: HttpStreamHub,
+    encoder: Option<Box<dyn StreamEncoder>>,
+    /// Hub title version the encoder last saw.
+    title_version: u64,
+}
+
+impl HttpStreamSinkStage {
+    pub fn new(route: HttpStreamRoute, hub: HttpStreamHub) -> Self {
+        Self {
+            route,
+            hub,
+            encoder: None,
+            title_version: 0,
+        }
+    }
+
+    pub fn route(&self) -> &HttpStreamRoute {
+        &self.route
+    }
+}
+
+impl SinkStage for HttpStreamSinkStage {
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<(), PipelineError> {
+        let spec = spec.validate()?;
+        let encoder = self
+            .route
+            .encoder
+            .create(spec)
+            .map_err(PipelineError::StageFailure)?;
+        self.hub.begin_stream(hub::StreamStart {
+            content_type: self.route.content_type(),
+            header: encoder.header(),
+            max_client_bytes: self.route.max_client_bytes(spec),
+            drop_policy: self.route.drop_policy,
+        });
+        self.encoder = Some(encoder);
+        // A fresh encoder learns the current title on its first write.
+        self.title_version = 0;
+        Ok(())
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn write(&mut self, block: &AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        let Some(encoder) = self.encoder.as_mut() else {
+            return StageStatus::Fatal;
+        };
+        let hub = &self.hub;
+        let (title_version, title) = hub.title();
+        if title_version != self.title_version {
+            self.title_version = title_version;
+            encoder.set_title(title.as_deref(), &mut |chunk| hub.publish(chunk));
+        }
+        encoder.encode(&block.samples, &mut |chunk| hub.publish(chunk));
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        // A short FLAC frame may only end a stream, so a partial block waits for more
+        // audio instead of going out here.
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        // Listeners stay attached across pipeline rebuilds; the owner of the route ends
+        // the stream through the hub once it is removed.
+        self.encoder = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Waker};
+
+    use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+    use stellatune_audio_core::pipeline::stages::StageStatus;
+    use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+    use symphonia::core::audio::SampleBuffer;
+    use symphonia::core::codecs::DecoderOptions;
+    use symphonia::core::errors::Error as SymphoniaError;
+    use symphonia::core::formats::FormatOptions;
+    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::meta::MetadataOptions;
+    use symphonia::core::probe::Hint;
+
+    use super::{
+        HttpStreamClient, HttpStreamDropPolicy, HttpStreamEncoder, HttpStreamHub, HttpStreamRoute,
+        HttpStreamSinkStage, ICY_METAINT, StreamEncoder,
+    };
+    use crate::file_sink::{FileSinkFormat, WavSampleFormat};
+
+    const SPEC: StreamSpec = StreamSpec {
+        sample_rate: 8_000,
+        channels: 2,
+    };
+
+    fn drain(client: &mut HttpStreamClient) -> (Vec<u8>, bool) {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut body = Vec::new();
+        loop {
+            match client.poll_chunk(&mut cx) {
+                Poll::Ready(Some(chunk)) => body.extend_from_slice(&chunk),
+                Poll::Ready(None) => return (body, true),
+                Poll::Pending => return (body, false),
+            }
+        }
+    }
+
+    fn ramp(frames: usize) -> AudioBlock {
+        AudioBlock {
+            channels: SPEC.channels,
+            samples: (0..frames * SPEC.channels as usize)
+                .map(|index| ((index % 200) as f32 / 100.0) - 1.0)
+                .collect(),
+        }
+    }
+
+    fn decode_frames(bytes: Vec<u8>, extension: &str) -> usize {
+        let mut hint = Hint::new();
+        hint.with_extension(extension);
+        let stream = MediaSourceStream::new(Box::new(Cursor::new(bytes)), Default::default());
+        let mut probed = symphonia::default::get_probe()
+            .format(
+                &hint,
+                stream,
+                &FormatOptions::default(),
+                &MetadataOptions::default(),
+            )
+            .expect("probe stream");
+        let track = probed.format.default_track().expect("track").clone();
+        let mut decoder = symphonia::default::get_codecs()
+            .make(&track.codec_params, &DecoderOptions::default())
+            .expect("decoder");
+        let mut frames = 0;
+        loop {
+            let packet = match probed.format.next_packet() {
+                Ok(packet) => packet,
+                Err(SymphoniaError::IoError(_)) => break,
+                Err(error) => panic!("read packet: {error}"),
+            };
+            let decoded = decoder.decode(&packet).expect("decode packet");
+            let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, *decoded.spec());
+            buffer.copy_interleaved_ref(decoded);
+            frames += buffer.samples().len() / SPEC.channels as usize;
+        }
+        frames
+    }
+
+    #[test]
+    fn late_flac_listener_gets_header_then_decodable_frames() {
+        let hub = HttpStreamHub::default();
+        let route = HttpStreamRoute::new(FileSinkFormat::Flac {
+            bits_per_sample: 16,
+        })
+        .expect("route");
+        let mut sink = HttpStreamSinkStage::new(route, hub.clone());
+        let mut ctx = PipelineContext::default();
+        assert!(hub.subscribe("early", false).is_none());
+        sink.prepare(SPEC, &mut ctx).expect("prepare");
+
+        // Audio published before a listener joins is not replayed to it.
+        assert_eq!(sink.write(&ramp(4096), &mut ctx), StageStatus::Ok);
+        let mut client = hub.subscribe("late", false).expect("subscribe");
+        assert_eq!(client.content_type(), "audio/flac");
+        for _ in 0..3 {
+            assert_eq!(sink.write(&ramp(4096), &mut ctx), StageStatus::Ok);
+        }
+        let (body, closed) = drain(&mut client);
+        assert!(!closed);
+        assert!(body.starts_with(b"fLaC"));
+        assert_eq!(decode_frames(body, "flac"), 3 * 4096);
+        assert_eq!(hub.clients()[0].label, "late");
+    }
+
+    /// Frames each block as `PCM:<frames>` and each title change as `TITLE:<title>`.
+    struct TaggingEncoder;
+
+    struct TaggingStream;
+
+    impl HttpStreamEncoder for TaggingEncoder {
+        fn id(&self) -> &str {
+            "tagging"
+        }
+
+        fn content_type(&self) -> &'static str {
+            "audio/x-tagging"
+        }
+
+        fn max_byte_rate(&self, _spec: StreamSpec) -> u64 {
+            1_000_000
+        }
+
+        fn create(&self, _spec: StreamSpec) -> Result<Box<dyn StreamEncoder>, String> {
+            Ok(Box::new(TaggingStream))
+        }
+    }
+
+    impl StreamEncoder for TaggingStream {
+        fn header(&self) -> Vec<u8> {
+            b"HEAD;".to_vec()
+        }
+
+        fn encode(&mut self, samples: &[f32], emit: &mut dyn FnMut(Vec<u8>)) {
+            emit(format!("PCM:{};", samples.len() / SPEC.channels as usize).into_bytes());
+        }
+
+        fn set_title(&mut self, title: Option<&str>, emit: &mut dyn FnMut(Vec<u8>)) {
+            emit(format!("TITLE:{};", title.unwrap_or_default()).into_bytes());
+        }
+    }
+
+    #[test]
+    fn custom_encoder_gets_blocks_and_in_band_title_changes() {
+        let hub = HttpStreamHub::default();
+        let route = HttpStreamRoute::with_encoder(Arc::new(TaggingEncoder));
+        assert_eq!(
+            route,
+            HttpStreamRoute::with_encoder(Arc::new(TaggingEncoder))
+        );
+        assert_ne!(
+            route,
+            HttpStreamRoute::new(FileSinkFormat::Wav(WavSampleFormat::Pcm16)).expect("route")
+        );
+        let mut sink = HttpStreamSinkStage::new(route, hub.clone());
+        let mut ctx = PipelineContext::default();
+        hub.set_title(Some("First".to_string()));
+        sink.prepare(SPEC, &mut ctx).expect("prepare");
+        let mut client = hub.subscribe("custom", false).expect("subscribe");
+        assert_eq!(client.content_type(), "audio/x-tagging");
+
+        sink.write(&ramp(10), &mut ctx);
+        sink.write(&ramp(20), &mut ctx);
+        hub.set_title(Some("Second".to_string()));
+        sink.write(&ramp(30), &mut ctx);
+        let (body, closed) = drain(&mut client);
+        assert!(!closed);
+        assert_eq!(
+            String::from_utf8(body).expect("utf8"),
+            "HEAD;TITLE:First;PCM:10;PCM:20;TITLE:Second;PCM:30;"
+        );
+    }
+
+    #[test]
+    fn slow_listeners_follow_their_drop_policy() {
+        let hub = HttpStreamHub::default();
+        let wav = FileSinkFormat::Wav(WavSampleFormat::Pcm16);
+        // 100ms at 8kHz stereo 16-bit is 3200 bytes, i.e. two 400-frame blocks.
+        let route = HttpStreamRoute::new(wav)
+            .expect("route")
+            .with_client_buffer_ms(100);
+        let mut sink = HttpStreamSinkStage::new(route.clone(), hub.clone());
+        let mut ctx = PipelineContext::default();
+        sink.prepare(SPEC, &mut ctx).expect("prepare");
+        let mut lagging = hub.subscribe("lagging", false).expect("subscribe");
+        for _ in 0..5 {
+            sink.write(&ramp(400), &mut ctx);
+        }
+        let (body, closed) = drain(&mut lagging);
+        assert!(!closed);
+        assert_eq!(body.len(), 44 + 2 * 1600);
+        assert_eq!(hub.clients()[0].dropped_bytes, 3 * 1600);
+        drop(hub.subscribe("brief", false));
+        assert_eq!(hub.clients().len(), 1);
+
+        let mut sink = HttpStreamSinkStage::new(
+            route.with_drop_policy(HttpStreamDropPolicy::Disconnect),
+            hub.clone(),
+        );
+        sink.prepare(SPEC, &mut ctx).expect("prepare");
+        let mut strict = hub.subscribe("strict", false).expect("subscribe");
+        assert_eq!(hub.clients().len(), 2, "same header keeps listeners");
+        for _ in 0..3 {
+            sink.write(&ramp(400), &mut ctx);
+        }
+        let (_, closed) = drain(&mut strict);
+        assert!(closed);
+        assert!(
+            drain(&mut lagging).1,
+            "the policy applies to every listener"
+        );
+        assert!(hub.clients().is_empty());
+    }
+
+    #[test]
+    fn format_change_and_end_of_stream_close_listeners() {
+        let hub = HttpStreamHub::default();
+        let route =
+            HttpStreamRoute::new(FileSinkFormat::Wav(WavSampleFormat::Pcm16)).expect("route");
+        let mut sink = HttpStreamSinkStage::new(route, hub.clone());
+        let mut ctx = PipelineContext::default();
+        sink.prepare(SPEC, &mut ctx).expect("prepare");
+        let mut client = hub.subscribe("a", false).expect("subscribe");
+        sink.stop(&mut ctx);
+        sink.prepare(
+            StreamSpec {
+                sample_rate: 16_000,
+                ..SPEC
+            },
+            &mut ctx,
+        )
+        .expect("prepare");
+        let (body, closed) = drain(&mut client);
+        assert_eq!(body.len(), 44);
+        assert!(closed);
+
+        let mut client = hub.subscribe("b", false).expect("subscribe");
+        hub.end_stream();
+        assert!(drain(&mut client).1);
+        assert!(!hub.is_live());
+        assert!(hub.subscribe("c", false).is_none());
+    }
+
+    #[test]
+    fn icy_listeners_get_title_updates_at_metaint_boundaries() {
+        let hub = HttpStreamHub::default();
+        let route =
+            HttpStreamRoute::new(FileSinkFormat::Wav(WavSampleFormat::Pcm16)).expect("route");
+        let mut sink = HttpStreamSinkStage::new(route, hub.clone());
+        let mut ctx = PipelineContext::default();
+        sink.prepare(SPEC, &mut ctx).expect("prepare");
+        let mut client = hub.subscribe("icy", true).expect("subscribe");
+        assert_eq!(client.icy_metaint(), Some(ICY_METAINT));
+        hub.set_title(Some("Artist - It's a Title".to_string()));
+
+        // 44 header bytes plus 3 * 4096 * 4 bytes of samples spans three metaints.
+        for _ in 0..3 {
+            sink.write(&ramp(4096), &mut ctx);
+        }
+        let (body, _) = drain(&mut client);
+        let first_block = &body[ICY_METAINT..];
+        let len = first_block[0] as usize * 16;
+        let text = String::from_utf8(first_block[1..1 + len].to_vec()).expect("utf8");
+        assert_eq!(
+            text.trim_end_matches('\0'),
+            "StreamTitle='Artist - It\u{2019}s a Title';"
+        );
+        // Unchanged titles are sent as empty blocks.
+        let second = ICY_METAINT + 1 + len + ICY_METAINT;
+        assert_eq!(body[second], 0);
+        assert_eq!(body.len(), 44 + 3 * 4096 * 4 + 1 + len + 2);
+    }
+}
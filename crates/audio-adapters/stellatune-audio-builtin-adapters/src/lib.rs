@@ -2,9 +2,12 @@
 
 pub mod builtin_decoder;
 pub mod device_sink;
+pub mod file_sink;
+pub mod http_stream;
 pub(crate) mod output_runtime;
 pub mod playlist_decoder;
 pub mod shared_device_sink;
 pub mod source_local;
+pub mod tee_sink;
 pub mod transform_chain_control;
 pub mod wasapi_exclusive_sink;
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use stellatune_audio::config::dither::SinkSampleFormat;
 use thiserror::Error;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -215,20 +216,24 @@ pub fn default_output_spec() -> Result<OutputSpec, OutputError> {
     })
 }
 
+fn shared_output_device(device_id: Option<String>) -> Result<cpal::Device, OutputError> {
+    let host = cpal::default_host();
+    match device_id {
+        Some(sel) => host
+            .output_devices()?
+            .find(|d| cpal_device_id(d) == sel)
+            .ok_or(OutputError::NoDevice),
+        None => host.default_output_device().ok_or(OutputError::NoDevice),
+    }
+}
+
 pub fn output_spec_for_device(
     backend: AudioBackend,
     device_id: Option<String>,
 ) -> Result<OutputSpec, OutputError> {
     match backend {
         AudioBackend::Shared => {
-            let host = cpal::default_host();
-            let device = if let Some(sel) = device_id {
-                host.output_devices()?
-                    .find(|d| cpal_device_id(d) == sel)
-                    .ok_or(OutputError::NoDevice)?
-            } else {
-                host.default_output_device().ok_or(OutputError::NoDevice)?
-            };
+            let device = shared_output_device(device_id)?;
             let config = device.default_output_config()?;
             Ok(OutputSpec {
                 sample_rate: config.sample_rate(),
@@ -244,6 +249,31 @@ pub fn output_spec_for_device(
     }
 }
 
+/// Sample format [`OutputHandle::start`] opens the device with.
+pub fn output_sample_format_for_device(
+    backend: AudioBackend,
+    device_id: Option<String>,
+) -> Result<SinkSampleFormat, OutputError> {
+    match backend {
+        AudioBackend::Shared => {
+            let device = shared_output_device(device_id)?;
+            let config = device.default_output_config()?;
+            Ok(match config.sample_format() {
+                cpal::SampleFormat::I16 | cpal::SampleFormat::U16 => SinkSampleFormat::Int16,
+                // Other formats are either float or rejected when the stream opens.
+                _ => SinkSampleFormat::Float32,
+            })
+        },
+        #[cfg(windows)]
+        AudioBackend::WasapiExclusive => {
+            let spec = wasapi_exclusive::output_spec_for_exclusive_device(device_id.clone())?;
+            wasapi_exclusive::exclusive_sample_format(device_id, spec)
+        },
+        #[cfg(not(windows))]
+        AudioBackend::WasapiExclusive => Err(OutputError::NoDevice),
+    }
+}
+
 impl OutputHandle {
     pub fn start<C: SampleConsumer, F>(
         backend: AudioBackend,
@@ -418,13 +448,13 @@ fn fill_u16<C: SampleConsumer>(out: &mut [u16], consumer: &mut C) {
     consumer.on_output(out.len(), provided);
 }
 
+// Rounds rather than truncates so samples the dither stage already placed on the 16-bit
+// grid convert exactly.
 fn f32_to_i16(v: f32) -> i16 {
     let v = v.clamp(-1.0, 1.0);
-    (v * i16::MAX as f32) as i16
+    (v * i16::MAX as f32).round() as i16
 }
 
 fn f32_to_u16(v: f32) -> u16 {
-    let v = v.clamp(-1.0, 1.0);
-    let normalized = (v + 1.0) * 0.5;
-    (normalized * u16::MAX as f32) as u16
+    (i32::from(f32_to_i16(v)) + 32_768) as u16
 }
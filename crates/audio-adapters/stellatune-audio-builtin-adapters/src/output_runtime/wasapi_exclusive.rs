@@ -6,6 +6,7 @@ use std::thread;
 use std::time::Duration;
 
 use anyhow::Error;
+use stellatune_audio::config::dither::SinkSampleFormat;
 use wasapi::{DeviceEnumerator, Direction, SampleType, StreamMode, WaveFormat};
 
 use super::{AudioBackend, AudioDevice, OutputError, OutputSpec, SampleConsumer};
@@ -104,6 +105,52 @@ pub fn supports_exclusive_spec(
     Ok(false)
 }
 
+/// Sample format the exclusive stream for `spec` opens with, following the same preference
+/// order as [`WasapiExclusiveHandle::start`].
+pub fn exclusive_sample_format(
+    device_id: Option<String>,
+    spec: OutputSpec,
+) -> Result<SinkSampleFormat, OutputError> {
+    let _ = wasapi::initialize_mta();
+    let enumerator = DeviceEnumerator::new().map_err(|e| OutputError::ConfigMismatch {
+        message: e.to_string(),
+    })?;
+    let device = select_device(&enumerator, device_id.as_deref())?;
+    let audio_client = device
+        .get_iaudioclient()
+        .map_err(|e| OutputError::ConfigMismatch {
+            message: e.to_string(),
+        })?;
+
+    let requested = [
+        (32, SampleType::Float, SinkSampleFormat::Float32),
+        (16, SampleType::Int, SinkSampleFormat::Int16),
+        (32, SampleType::Int, SinkSampleFormat::Int32),
+    ];
+    for (bits, sample_type, format) in requested {
+        let fmt = WaveFormat::new(
+            bits,
+            bits,
+            &sample_type,
+            spec.sample_rate as usize,
+            spec.channels as usize,
+            None,
+        );
+        if audio_client
+            .is_supported_exclusive_with_quirks(&fmt)
+            .is_ok()
+        {
+            return Ok(format);
+        }
+    }
+    Err(OutputError::ConfigMismatch {
+        message: format!(
+            "no exclusive format for {}Hz {}ch",
+            spec.sample_rate, spec.channels
+        ),
+    })
+}
+
 pub fn output_spec_for_exclusive_device(
     device_id: Option<String>,
 ) -> Result<OutputSpec, OutputError> {
@@ -301,14 +348,15 @@ fn run_exclusive_loop<C: SampleConsumer>(
     let bytes_per_frame = format.get_blockalign() as usize;
     let max_samples = (buffer_frame_count as usize) * channels;
 
+    // Rounded so samples already quantized by the dither stage convert exactly.
     fn f32_to_i16(v: f32) -> i16 {
         let v = v.clamp(-1.0, 1.0);
-        (v * i16::MAX as f32) as i16
+        (v * i16::MAX as f32).round() as i16
     }
 
     fn f32_to_i32(v: f32) -> i32 {
-        let v = v.clamp(-1.0, 1.0);
-        (v * i32::MAX as f32) as i32
+        let v = f64::from(v.clamp(-1.0, 1.0));
+        (v * f64::from(i32::MAX)).round() as i32
     }
 
     let mut f32_buf = if matches!(sample_kind, RenderSampleKind::F32) {
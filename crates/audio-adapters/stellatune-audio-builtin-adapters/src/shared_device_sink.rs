@@ -501,6 +501,13 @@ impl SinkStage for SharedDeviceSinkStage {
         self.prepared_spec = None;
         self.clear_callback_error();
     }
+
+    fn latency_frames(&self) -> u64 {
+        let (Some(producer), Some(spec)) = (self.producer.as_ref(), self.prepared_spec) else {
+            return 0;
+        };
+        (producer.occupied_len() / spec.channels.max(1) as usize) as u64
+    }
 }
 
 fn select_output_device(
@@ -597,10 +604,9 @@ fn write_u16_samples(data: &mut [u16], consumer: &mut HeapCons<f32>) -> usize {
 
 fn f32_to_i16(value: f32) -> i16 {
     let scaled = value.clamp(-1.0, 1.0) * i16::MAX as f32;
-    scaled as i16
+    scaled.round() as i16
 }
 
 fn f32_to_u16(value: f32) -> u16 {
-    let normalized = (value.clamp(-1.0, 1.0) + 1.0) * 0.5;
-    (normalized * u16::MAX as f32) as u16
+    (i32::from(f32_to_i16(value)) + 32_768) as u16
 }
@@ -0,0 +1,983 @@
+//! Fan-out sink that feeds one stream into several independent outputs.
+//!
+//! Every branch owns a worker thread and a bounded block queue, so a slow or failing
+//! output never stalls the others. The first healthy branch paces the stream: writes
+//! to it block like a plain sink would, while the remaining branches drop blocks when
+//! their queue is full. A pacing branch that stays blocked is failed and pacing moves
+//! to the next healthy one. A branch that fails is parked until the next `prepare`;
+//! the tee itself only fails once every branch has.
+//!
+//! Outputs are lined up from their measured latency: once every branch has played a
+//! short warm-up, each one is delayed by how far it runs ahead of the slowest.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{Receiver, RecvTimeoutError, SendTimeoutError, Sender, TrySendError};
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+
+const DEFAULT_QUEUE_BLOCKS: usize = 16;
+const DELAY_CHUNK_FRAMES: usize = 1024;
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(5);
+const CONTROL_TIMEOUT: Duration = Duration::from_millis(500);
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long the pacing branch may block a write before it is treated as stalled.
+const PACING_SEND_TIMEOUT: Duration = Duration::from_secs(1);
+/// How long `stop` waits for all branch workers before leaving stuck ones behind.
+const STOP_TIMEOUT: Duration = Duration::from_secs(2);
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(2);
+/// Audio every branch plays before latencies are measured, so buffers have filled.
+const LATENCY_WARMUP_MS: u64 = 200;
+
+/// One output of a [`TeeSinkStage`].
+pub struct TeeBranch {
+    name: String,
+    sink: Box<dyn SinkStage>,
+    delay_ms: u32,
+    queue_blocks: usize,
+}
+
+impl TeeBranch {
+    pub fn new(name: impl Into<String>, sink: Box<dyn SinkStage>) -> Self {
+        Self {
+            name: name.into(),
+            sink,
+            delay_ms: 0,
+            queue_blocks: DEFAULT_QUEUE_BLOCKS,
+        }
+    }
+
+    /// Delays this output by `delay_ms` on top of the measured latency compensation, for
+    /// latency past the sink the tee cannot see, such as a network player's buffer.
+    pub fn with_delay_ms(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    /// Number of blocks queued for this output before it drops (or, when pacing,
+    /// blocks) further writes.
+    pub fn with_queue_blocks(mut self, queue_blocks: usize) -> Self {
+        self.queue_blocks = queue_blocks.max(1);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeeBranchMetricsSnapshot {
+    pub name: String,
+    pub delay_ms: u32,
+    /// Latency the sink last reported, in frames.
+    pub latency_frames: u64,
+    /// Delay applied to line this output up with the others, in frames.
+    pub compensation_frames: u64,
+    pub written_frames: u64,
+    pub dropped_frames: u64,
+    pub queued_blocks: usize,
+    pub failed: bool,
+    pub last_error: Option<String>,
+}
+
+struct BranchMetrics {
+    name: String,
+    delay_ms: u32,
+    latency_frames: AtomicU64,
+    compensation_frames: AtomicU64,
+    /// Compensation decided by the tee that the worker has not written out yet.
+    pending_compensation_frames: AtomicU64,
+    written_frames: AtomicU64,
+    dropped_frames: AtomicU64,
+    queued_blocks: AtomicUsize,
+    failed: AtomicBool,
+    last_error: Mutex<Option<String>>,
+}
+
+impl BranchMetrics {
+    fn new(name: String, delay_ms: u32) -> Self {
+        Self {
+            name,
+            delay_ms,
+            latency_frames: AtomicU64::new(0),
+            compensation_frames: AtomicU64::new(0),
+            pending_compensation_frames: AtomicU64::new(0),
+            written_frames: AtomicU64::new(0),
+            dropped_frames: AtomicU64::new(0),
+            queued_blocks: AtomicUsize::new(0),
+            failed: AtomicBool::new(false),
+            last_error: Mutex::new(None),
+        }
+    }
+
+    fn is_failed(&self) -> bool {
+        self.failed.load(Ordering::Acquire)
+    }
+
+    /// Marks the branch failed, keeping the first cause when several paths report it.
+    fn fail(&self, error: String) {
+        if let Ok(mut last_error) = self.last_error.lock()
+            && !self.failed.swap(true, Ordering::AcqRel)
+        {
+            *last_error = Some(error);
+        }
+        self.failed.store(true, Ordering::Release);
+    }
+
+    fn reset_failure(&self) {
+        self.failed.store(false, Ordering::Release);
+        self.queued_blocks.store(0, Ordering::Release);
+        self.latency_frames.store(0, Ordering::Release);
+        self.compensation_frames.store(0, Ordering::Release);
+        self.pending_compensation_frames.store(0, Ordering::Release);
+    }
+
+    fn snapshot(&self) -> TeeBranchMetricsSnapshot {
+        TeeBranchMetricsSnapshot {
+            name: self.name.clone(),
+            delay_ms: self.delay_ms,
+            latency_frames: self.latency_frames.load(Ordering::Relaxed),
+            compensation_frames: self.compensation_frames.load(Ordering::Relaxed),
+            written_frames: self.written_frames.load(Ordering::Relaxed),
+            dropped_frames: self.dropped_frames.load(Ordering::Relaxed),
+            queued_blocks: self.queued_blocks.load(Ordering::Relaxed),
+            failed: self.is_failed(),
+            last_error: self.last_error.lock().ok().and_then(|error| error.clone()),
+        }
+    }
+}
+
+/// Shared read handle for per-branch tee metrics; stays valid after the stage moves
+/// into a sink worker.
+///
+/// One handle can outlive several tee stages (see [`TeeSinkStage::with_metrics`]), in
+/// which case it reports the stage that was prepared most recently.
+#[derive(Clone, Default)]
+pub struct TeeSinkMetrics {
+    branches: Arc<Mutex<Vec<Arc<BranchMetrics>>>>,
+}
+
+impl TeeSinkMetrics {
+    pub fn snapshot(&self) -> Vec<TeeBranchMetricsSnapshot> {
+        self.branches
+            .lock()
+            .map(|branches| branches.iter().map(|metrics| metrics.snapshot()).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn clear(&self) {
+        self.publish(Vec::new());
+    }
+
+    fn publish(&self, branches: Vec<Arc<BranchMetrics>>) {
+        if let Ok(mut guard) = self.branches.lock() {
+            *guard = branches;
+        }
+    }
+}
+
+enum BranchControl {
+    Sync {
+        ctx: PipelineContext,
+        resp_tx: Sender<Result<(), PipelineError>>,
+    },
+    Flush {
+        resp_tx: Sender<Result<(), PipelineError>>,
+    },
+    Stop,
+}
+
+struct RunningBranch {
+    audio_tx: Sender<AudioBlock>,
+    ctrl_tx: Sender<BranchControl>,
+    join: JoinHandle<Box<dyn SinkStage>>,
+}
+
+enum BranchState {
+    Idle(Box<dyn SinkStage>),
+    Running(RunningBranch),
+    /// The worker panicked and took the sink with it.
+    Lost,
+}
+
+struct BranchSlot {
+    state: BranchState,
+    delay_ms: u32,
+    queue_blocks: usize,
+    metrics: Arc<BranchMetrics>,
+}
+
+pub struct TeeSinkStage {
+    branches: Vec<BranchSlot>,
+    metrics: TeeSinkMetrics,
+    prepared_spec: Option<StreamSpec>,
+    /// Set once latencies of the current stream were measured and compensated.
+    compensated: bool,
+}
+
+impl TeeSinkStage {
+    pub fn new(branches: Vec<TeeBranch>) -> Self {
+        let branches = branches
+            .into_iter()
+            .map(|branch| BranchSlot {
+                metrics: Arc::new(BranchMetrics::new(branch.name, branch.delay_ms)),
+                state: BranchState::Idle(branch.sink),
+                delay_ms: branch.delay_ms,
+                queue_blocks: branch.queue_blocks,
+            })
+            .collect::<Vec<_>>();
+        let stage = Self {
+            branches,
+            metrics: TeeSinkMetrics::default(),
+            prepared_spec: None,
+            compensated: false,
+        };
+        stage.publish_metrics();
+        stage
+    }
+
+    /// Reports through `metrics` instead of a handle private to this stage. The handle
+    /// switches over on `prepare`, so a replacement tee built ahead of time does not
+    /// hide the one still running.
+    pub fn with_metrics(mut self, metrics: TeeSinkMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub fn metrics(&self) -> TeeSinkMetrics {
+        self.metrics.clone()
+    }
+
+    fn publish_metrics(&self) {
+        self.metrics.publish(
+            self.branches
+                .iter()
+                .map(|branch| Arc::clone(&branch.metrics))
+                .collect(),
+        );
+    }
+
+    fn healthy_branches(&self) -> impl Iterator<Item = &BranchSlot> {
+        self.branches.iter().filter(|branch| {
+            matches!(branch.state, BranchState::Running(_)) && !branch.metrics.is_failed()
+        })
+    }
+
+    fn healthy_count(&self) -> usize {
+        self.healthy_branches().count()
+    }
+
+    /// Once every healthy branch has played the warm-up, delays each one by how far its
+    /// measured latency trails the slowest branch, plus its configured extra delay.
+    fn compensate_latency(&mut self) {
+        let Some(spec) = self.prepared_spec else {
+            return;
+        };
+        let warmup_frames = u64::from(spec.sample_rate) * LATENCY_WARMUP_MS / 1000;
+        if self
+            .healthy_branches()
+            .any(|branch| branch.metrics.written_frames.load(Ordering::Relaxed) < warmup_frames)
+        {
+            return;
+        }
+        self.compensated = true;
+        let slowest = self
+            .healthy_branches()
+            .map(|branch| branch.metrics.latency_frames.load(Ordering::Relaxed))
+            .max()
+            .unwrap_or(0);
+        for branch in self.healthy_branches() {
+            let latency = branch.metrics.latency_frames.load(Ordering::Relaxed);
+            let extra = u64::from(spec.sample_rate) * u64::from(branch.delay_ms) / 1000;
+            let compensation = slowest.saturating_sub(latency) + extra;
+            branch
+                .metrics
+                .compensation_frames
+                .store(compensation, Ordering::Relaxed);
+            branch
+                .metrics
+                .pending_compensation_frames
+                .store(compensation, Ordering::Release);
+        }
+    }
+
+    fn all_failed_error(&self) -> PipelineError {
+        let details = self
+            .branches
+            .iter()
+            .map(|branch| branch.metrics.snapshot())
+            .map(|branch| {
+                format!(
+                    "{}: {}",
+                    branch.name,
+                    branch.last_error.as_deref().unwrap_or("not running")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        PipelineError::StageFailure(format!("all tee outputs failed ({details})"))
+    }
+
+    /// Sends one control request to every healthy branch and waits for the replies.
+    fn broadcast_control(
+        &mut self,
+        timeout: Duration,
+        constructor: impl Fn(Sender<Result<(), PipelineError>>) -> BranchControl,
+    ) -> Result<(), PipelineError> {
+        let mut pending = Vec::new();
+        for branch in &self.branches {
+            let BranchState::Running(running) = &branch.state else {
+                continue;
+            };
+            if branch.metrics.is_failed() {
+                continue;
+            }
+            let (resp_tx, resp_rx) = crossbeam_channel::bounded(1);
+            if running.ctrl_tx.send(constructor(resp_tx)).is_err() {
+                branch.metrics.fail("tee branch worker exited".to_string());
+                continue;
+            }
+            pending.push((Arc::clone(&branch.metrics), resp_rx));
+        }
+        for (metrics, resp_rx) in pending {
+            let result = match resp_rx.recv_timeout(timeout) {
+                Ok(result) => result,
+                Err(RecvTimeoutError::Timeout) => Err(PipelineError::StageFailure(format!(
+                    "tee branch control timed out after {}ms",
+                    timeout.as_millis()
+                ))),
+                Err(RecvTimeoutError::Disconnected) => Err(PipelineError::SinkDisconnected),
+            };
+            if let Err(error) = result {
+                metrics.fail(error.to_string());
+            }
+        }
+        if self.healthy_count() == 0 {
+            return Err(self.all_failed_error());
+        }
+        Ok(())
+    }
+}
+
+impl SinkStage for TeeSinkStage {
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        ctx: &mut PipelineContext,
+    ) -> Result<(), PipelineError> {
+        self.stop(ctx);
+        self.publish_metrics();
+        self.prepared_spec = Some(spec);
+        self.compensated = false;
+        let mut startups = Vec::with_capacity(self.branches.len());
+        for branch in &mut self.branches {
+            if !matches!(branch.state, BranchState::Idle(_)) {
+                continue;
+            }
+            let BranchState::Idle(sink) = std::mem::replace(&mut branch.state, BranchState::Lost)
+            else {
+                continue;
+            };
+            branch.metrics.reset_failure();
+            let (audio_tx, audio_rx) = crossbeam_channel::bounded(branch.queue_blocks);
+            let (ctrl_tx, ctrl_rx) = crossbeam_channel::unbounded();
+            let (startup_tx, startup_rx) = crossbeam_channel::bounded(1);
+            let args = BranchThreadArgs {
+                sink,
+                spec,
+                ctx: ctx.clone(),
+                audio_rx,
+                ctrl_rx,
+                startup_tx,
+                metrics: Arc::clone(&branch.metrics),
+            };
+            let join = std::thread::Builder::new()
+                .name(format!("stellatune-tee-{}", branch.metrics.name))
+                .spawn(move || branch_thread_main(args))
+                .map_err(|e| {
+                    PipelineError::StageFailure(format!("spawn tee branch failed: {e}"))
+                })?;
+            branch.state = BranchState::Running(RunningBranch {
+                audio_tx,
+                ctrl_tx,
+                join,
+            });
+            startups.push((Arc::clone(&branch.metrics), startup_rx));
+        }
+        for (metrics, startup_rx) in startups {
+            match startup_rx.recv_timeout(STARTUP_TIMEOUT) {
+                Ok(Ok(())) => {},
+                Ok(Err(error)) => metrics.fail(format!("prepare failed: {error}")),
+                Err(_) => metrics.fail("prepare did not complete".to_string()),
+            }
+        }
+        if self.healthy_count() == 0 {
+            let error = self.all_failed_error();
+            self.stop(ctx);
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    fn sync_runtime_control(&mut self, ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        self.broadcast_control(CONTROL_TIMEOUT, |resp_tx| BranchControl::Sync {
+            ctx: ctx.clone(),
+            resp_tx,
+        })
+    }
+
+    fn write(&mut self, block: &AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        let frames = block.frames() as u64;
+        let mut pacing_assigned = false;
+        for branch in &self.branches {
+            let BranchState::Running(running) = &branch.state else {
+                continue;
+            };
+            if branch.metrics.is_failed() {
+                continue;
+            }
+            let sent = if pacing_assigned {
+                match running.audio_tx.try_send(block.clone()) {
+                    Ok(()) => Ok(true),
+                    Err(TrySendError::Full(_)) => Ok(false),
+                    Err(TrySendError::Disconnected(_)) => Err("tee branch worker exited"),
+                }
+            } else {
+                match running
+                    .audio_tx
+                    .send_timeout(block.clone(), PACING_SEND_TIMEOUT)
+                {
+                    Ok(()) => {
+                        pacing_assigned = true;
+                        Ok(true)
+                    },
+                    // Pacing moves on to the next healthy branch, which gets this block.
+                    Err(SendTimeoutError::Timeout(_)) => Err("tee branch stalled while pacing"),
+                    Err(SendTimeoutError::Disconnected(_)) => Err("tee branch worker exited"),
+                }
+            };
+            match sent {
+                Ok(true) => {
+                    branch.metrics.queued_blocks.fetch_add(1, Ordering::Relaxed);
+                },
+                Ok(false) => {
+                    branch
+                        .metrics
+                        .dropped_frames
+                        .fetch_add(frames, Ordering::Relaxed);
+                },
+                Err(error) => {
+                    branch
+                        .metrics
+                        .dropped_frames
+                        .fetch_add(frames, Ordering::Relaxed);
+                    branch.metrics.fail(error.to_string());
+                },
+            }
+        }
+        if self.healthy_count() == 0 {
+            return StageStatus::Fatal;
+        }
+        if !self.compensated {
+            self.compensate_latency();
+        }
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        self.broadcast_control(FLUSH_TIMEOUT, |resp_tx| BranchControl::Flush { resp_tx })
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        let deadline = Instant::now() + STOP_TIMEOUT;
+        for branch in &self.branches {
+            if let BranchState::Running(running) = &branch.state {
+                let _ = running.ctrl_tx.send(BranchControl::Stop);
+            }
+        }
+        for branch in &mut self.branches {
+            if !matches!(branch.state, BranchState::Running(_)) {
+                continue;
+            }
+            let BranchState::Running(running) =
+                std::mem::replace(&mut branch.state, BranchState::Lost)
+            else {
+                continue;
+            };
+            // Dropping the queue first lets a worker blocked on it observe the stop.
+            drop(running.audio_tx);
+            while !running.join.is_finished() && Instant::now() < deadline {
+                std::thread::sleep(STOP_POLL_INTERVAL);
+            }
+            if !running.join.is_finished() {
+                // The worker is stuck inside its sink; leave it behind rather than hang.
+                branch.metrics.fail(format!(
+                    "tee branch worker did not stop within {}ms",
+                    STOP_TIMEOUT.as_millis()
+                ));
+                continue;
+            }
+            branch.state = match running.join.join() {
+                Ok(sink) => BranchState::Idle(sink),
+                Err(_) => {
+                    branch
+                        .metrics
+                        .fail("tee branch worker panicked".to_string());
+                    BranchState::Lost
+                },
+            };
+        }
+        self.prepared_spec = None;
+    }
+
+    /// The slowest output, which every other one is delayed to match.
+    fn latency_frames(&self) -> u64 {
+        self.healthy_branches()
+            .map(|branch| branch.metrics.latency_frames.load(Ordering::Relaxed))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Drop for TeeSinkStage {
+    fn drop(&mut self) {
+        self.stop(&mut PipelineContext::default());
+    }
+}
+
+struct BranchThreadArgs {
+    sink: Box<dyn SinkStage>,
+    spec: StreamSpec,
+    ctx: PipelineContext,
+    audio_rx: Receiver<AudioBlock>,
+    ctrl_rx: Receiver<BranchControl>,
+    startup_tx: Sender<Result<(), PipelineError>>,
+    metrics: Arc<BranchMetrics>,
+}
+
+fn branch_thread_main(args: BranchThreadArgs) -> Box<dyn SinkStage> {
+    let BranchThreadArgs {
+        mut sink,
+        spec,
+        mut ctx,
+        audio_rx,
+        ctrl_rx,
+        startup_tx,
+        metrics,
+    } = args;
+    if let Err(error) = sink.prepare(spec, &mut ctx) {
+        let _ = startup_tx.send(Err(error));
+        return sink;
+    }
+    let _ = startup_tx.send(Ok(()));
+
+    while !metrics.is_failed() {
+        crossbeam_channel::select! {
+            recv(ctrl_rx) -> msg => {
+                let Ok(control) = msg else {
+                    break;
+                };
+                match control {
+                    BranchControl::Sync { ctx: next_ctx, resp_tx } => {
+                        ctx = next_ctx;
+                        let _ = resp_tx.send(sink.sync_runtime_control(&mut ctx));
+                    },
+                    BranchControl::Flush { resp_tx } => {
+                        let result = drain_queue(sink.as_mut(), &audio_rx, &metrics, &mut ctx)
+                            .map_err(PipelineError::StageFailure)
+                            .and_then(|()| sink.flush(&mut ctx));
+                        let _ = resp_tx.send(result);
+                    },
+                    BranchControl::Stop => break,
+                }
+            }
+            recv(audio_rx) -> msg => {
+                let Ok(block) = msg else {
+                    break;
+                };
+                let written = write_compensation(sink.as_mut(), spec, &metrics, &mut ctx)
+                    .and_then(|()| write_queued(sink.as_mut(), &block, &metrics, &mut ctx));
+                if let Err(error) = written {
+                    metrics.fail(error);
+                }
+            }
+        }
+    }
+
+    sink.stop(&mut ctx);
+    sink
+}
+
+/// Writes the silence that delays this branch once the tee has measured latencies.
+fn write_compensation(
+    sink: &mut dyn SinkStage,
+    spec: StreamSpec,
+    metrics: &BranchMetrics,
+    ctx: &mut PipelineContext,
+) -> Result<(), String> {
+    let mut remaining = metrics
+        .pending_compensation_frames
+        .swap(0, Ordering::Acquire) as usize;
+    while remaining > 0 {
+        // Chunked so sinks with small internal buffers keep up instead of dropping.
+        let frames = remaining.min(DELAY_CHUNK_FRAMES);
+        let silence = AudioBlock {
+            channels: spec.channels,
+            samples: vec![0.0; frames * spec.channels as usize],
+        };
+        write_to_sink(sink, &silence, ctx)?;
+        remaining -= frames;
+    }
+    Ok(())
+}
+
+fn drain_queue(
+    sink: &mut dyn SinkStage,
+    audio_rx: &Receiver<AudioBlock>,
+    metrics: &BranchMetrics,
+    ctx: &mut PipelineContext,
+) -> Result<(), String> {
+    while let Ok(block) = audio_rx.try_recv() {
+        write_queued(sink, &block, metrics, ctx)?;
+    }
+    Ok(())
+}
+
+fn write_queued(
+    sink: &mut dyn SinkStage,
+    block: &AudioBlock,
+    metrics: &BranchMetrics,
+    ctx: &mut PipelineContext,
+) -> Result<(), String> {
+    metrics.queued_blocks.fetch_sub(1, Ordering::Relaxed);
+    write_to_sink(sink, block, ctx)?;
+    metrics
+        .written_frames
+        .fetch_add(block.frames() as u64, Ordering::Relaxed);
+    metrics
+        .latency_frames
+        .store(sink.latency_frames(), Ordering::Relaxed);
+    Ok(())
+}
+
+fn write_to_sink(
+    sink: &mut dyn SinkStage,
+    block: &AudioBlock,
+    ctx: &mut PipelineContext,
+) -> Result<(), String> {
+    match sink.write(block, ctx) {
+        StageStatus::Ok => Ok(()),
+        StageStatus::Eof => Err("sink reached eof".to_string()),
+        StageStatus::Fatal => Err("sink fatal".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use crossbeam_channel::Receiver;
+    use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+    use stellatune_audio_core::pipeline::error::PipelineError;
+    use stellatune_audio_core::pipeline::stages::StageStatus;
+    use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+
+    use super::{TeeBranch, TeeSinkMetrics, TeeSinkStage};
+
+    const SPEC: StreamSpec = StreamSpec {
+        sample_rate: 1_000,
+        channels: 2,
+    };
+
+    #[derive(Default)]
+    struct Recorded {
+        samples: Vec<f32>,
+        flushed: bool,
+        stopped: bool,
+    }
+
+    struct RecordingSink {
+        recorded: Arc<Mutex<Recorded>>,
+        fail_after_writes: Option<usize>,
+        gate: Option<Receiver<()>>,
+        latency_frames: u64,
+        writes: usize,
+    }
+
+    impl RecordingSink {
+        fn new(recorded: &Arc<Mutex<Recorded>>) -> Self {
+            Self {
+                recorded: Arc::clone(recorded),
+                fail_after_writes: None,
+                gate: None,
+                latency_frames: 0,
+                writes: 0,
+            }
+        }
+    }
+
+    impl SinkStage for RecordingSink {
+        fn prepare(
+            &mut self,
+            _spec: StreamSpec,
+            _ctx: &mut PipelineContext,
+        ) -> Result<(), PipelineError> {
+            Ok(())
+        }
+
+        fn sync_runtime_control(
+            &mut self,
+            _ctx: &mut PipelineContext,
+        ) -> Result<(), PipelineError> {
+            Ok(())
+        }
+
+        fn write(&mut self, block: &AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+            if let Some(gate) = &self.gate {
+                let _ = gate.recv();
+            }
+            if self.fail_after_writes == Some(self.writes) {
+                return StageStatus::Fatal;
+            }
+            self.writes += 1;
+            self.recorded
+                .lock()
+                .expect("recorded mutex poisoned")
+                .samples
+                .extend_from_slice(&block.samples);
+            StageStatus::Ok
+        }
+
+        fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+            self.recorded
+                .lock()
+                .expect("recorded mutex poisoned")
+                .flushed = true;
+            Ok(())
+        }
+
+        fn stop(&mut self, _ctx: &mut PipelineContext) {
+            self.recorded
+                .lock()
+                .expect("recorded mutex poisoned")
+                .stopped = true;
+        }
+
+        fn latency_frames(&self) -> u64 {
+            self.latency_frames
+        }
+    }
+
+    fn block(value: f32) -> AudioBlock {
+        AudioBlock {
+            channels: 2,
+            samples: vec![value; 20],
+        }
+    }
+
+    #[test]
+    fn tee_fans_out_every_block_and_compensates_measured_latency() {
+        let device = Arc::new(Mutex::new(Recorded::default()));
+        let stream = Arc::new(Mutex::new(Recorded::default()));
+        let mut device_sink = RecordingSink::new(&device);
+        device_sink.latency_frames = 8;
+        let mut tee = TeeSinkStage::new(vec![
+            TeeBranch::new("device", Box::new(device_sink)),
+            TeeBranch::new("stream", Box::new(RecordingSink::new(&stream))).with_delay_ms(2),
+        ]);
+        let metrics = tee.metrics();
+        let mut ctx = PipelineContext::default();
+
+        tee.prepare(SPEC, &mut ctx).expect("prepare");
+        // 300 frames at 1kHz outlasts the 200ms warm-up before latencies are measured.
+        for index in 0..30 {
+            let value = 1.0 + index as f32;
+            assert_eq!(tee.write(&block(value), &mut ctx), StageStatus::Ok);
+            // Let the branches catch up so the warm-up is reached while blocks remain.
+            tee.flush(&mut ctx).expect("flush");
+        }
+        tee.stop(&mut ctx);
+
+        let device = device.lock().expect("recorded mutex poisoned");
+        let stream = stream.lock().expect("recorded mutex poisoned");
+        assert_eq!(device.samples.len(), 600);
+        // 8 frames behind the device plus 2ms of extra delay, stereo.
+        assert_eq!(stream.samples.len(), 620);
+        let silence = stream
+            .samples
+            .iter()
+            .filter(|&&sample| sample == 0.0)
+            .count();
+        assert_eq!(silence, 20);
+        let audio = stream
+            .samples
+            .iter()
+            .copied()
+            .filter(|&sample| sample != 0.0)
+            .collect::<Vec<_>>();
+        assert_eq!(audio, device.samples);
+        assert!(device.flushed && stream.flushed);
+        assert!(device.stopped && stream.stopped);
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot[0].written_frames, 300);
+        assert_eq!(snapshot[1].written_frames, 300);
+        assert_eq!(snapshot[0].latency_frames, 8);
+        assert_eq!(snapshot[0].compensation_frames, 0);
+        assert_eq!(snapshot[1].compensation_frames, 10);
+        assert!(snapshot.iter().all(|branch| !branch.failed));
+    }
+
+    #[test]
+    fn failing_branch_is_isolated_until_every_branch_fails() {
+        let healthy = Arc::new(Mutex::new(Recorded::default()));
+        let failing = Arc::new(Mutex::new(Recorded::default()));
+        let mut failing_sink = RecordingSink::new(&failing);
+        failing_sink.fail_after_writes = Some(1);
+        let mut healthy_sink = RecordingSink::new(&healthy);
+        healthy_sink.fail_after_writes = Some(4);
+        let mut tee = TeeSinkStage::new(vec![
+            TeeBranch::new("plugin", Box::new(failing_sink)),
+            TeeBranch::new("device", Box::new(healthy_sink)),
+        ]);
+        let metrics = tee.metrics();
+        let mut ctx = PipelineContext::default();
+        tee.prepare(SPEC, &mut ctx).expect("prepare");
+
+        for value in [0.1, 0.2, 0.3] {
+            assert_eq!(tee.write(&block(value), &mut ctx), StageStatus::Ok);
+        }
+        tee.flush(&mut ctx)
+            .expect("healthy branch keeps the tee alive");
+
+        let snapshot = metrics.snapshot();
+        assert!(snapshot[0].failed);
+        assert_eq!(snapshot[0].last_error.as_deref(), Some("sink fatal"));
+        assert!(!snapshot[1].failed);
+        assert_eq!(snapshot[1].written_frames, 30);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut status = StageStatus::Ok;
+        while status == StageStatus::Ok && Instant::now() < deadline {
+            status = tee.write(&block(0.4), &mut ctx);
+        }
+        assert_eq!(status, StageStatus::Fatal);
+        assert!(matches!(
+            tee.flush(&mut ctx),
+            Err(PipelineError::StageFailure(message)) if message.contains("all tee outputs failed")
+        ));
+        tee.stop(&mut ctx);
+
+        // A new stream retries branches that failed during the previous one.
+        tee.prepare(SPEC, &mut ctx).expect("re-prepare");
+        assert!(metrics.snapshot().iter().all(|branch| !branch.failed));
+        tee.stop(&mut ctx);
+    }
+
+    #[test]
+    fn shared_metrics_handle_follows_most_recently_prepared_tee() {
+        let recorded = Arc::new(Mutex::new(Recorded::default()));
+        let shared = TeeSinkMetrics::default();
+        let mut running = TeeSinkStage::new(vec![TeeBranch::new(
+            "device",
+            Box::new(RecordingSink::new(&recorded)),
+        )])
+        .with_metrics(shared.clone());
+        let mut ctx = PipelineContext::default();
+        running.prepare(SPEC, &mut ctx).expect("prepare");
+        assert_eq!(running.write(&block(0.5), &mut ctx), StageStatus::Ok);
+        running.flush(&mut ctx).expect("flush");
+
+        let mut pending = TeeSinkStage::new(vec![
+            TeeBranch::new("device", Box::new(RecordingSink::new(&recorded))),
+            TeeBranch::new("file", Box::new(RecordingSink::new(&recorded))),
+        ])
+        .with_metrics(shared.clone());
+        let snapshot = shared.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].written_frames, 10);
+
+        running.stop(&mut ctx);
+        pending.prepare(SPEC, &mut ctx).expect("prepare");
+        let snapshot = shared.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert!(snapshot.iter().all(|branch| branch.written_frames == 0));
+        pending.stop(&mut ctx);
+        shared.clear();
+        assert!(shared.snapshot().is_empty());
+    }
+
+    #[test]
+    fn stalled_secondary_branch_drops_blocks_without_blocking_pacing_branch() {
+        let pacing = Arc::new(Mutex::new(Recorded::default()));
+        let stalled = Arc::new(Mutex::new(Recorded::default()));
+        let (release_tx, release_rx) = crossbeam_channel::unbounded();
+        let mut stalled_sink = RecordingSink::new(&stalled);
+        stalled_sink.gate = Some(release_rx);
+        let mut tee = TeeSinkStage::new(vec![
+            TeeBranch::new("device", Box::new(RecordingSink::new(&pacing))),
+            TeeBranch::new("network", Box::new(stalled_sink)).with_queue_blocks(2),
+        ]);
+        let metrics = tee.metrics();
+        let mut ctx = PipelineContext::default();
+        tee.prepare(SPEC, &mut ctx).expect("prepare");
+
+        for _ in 0..10 {
+            assert_eq!(tee.write(&block(0.5), &mut ctx), StageStatus::Ok);
+        }
+        let snapshot = metrics.snapshot();
+        assert!(snapshot[1].dropped_frames > 0, "{snapshot:?}");
+        assert_eq!(snapshot[0].dropped_frames, 0);
+
+        for _ in 0..10 {
+            let _ = release_tx.send(());
+        }
+        tee.flush(&mut ctx).expect("flush");
+        assert_eq!(metrics.snapshot()[0].written_frames, 100);
+        tee.stop(&mut ctx);
+    }
+
+    #[test]
+    fn stalled_pacing_branch_fails_and_hands_pacing_to_next_branch() {
+        let stalled = Arc::new(Mutex::new(Recorded::default()));
+        let healthy = Arc::new(Mutex::new(Recorded::default()));
+        let (release_tx, release_rx) = crossbeam_channel::unbounded();
+        let mut stalled_sink = RecordingSink::new(&stalled);
+        stalled_sink.gate = Some(release_rx);
+        let mut tee = TeeSinkStage::new(vec![
+            TeeBranch::new("device", Box::new(stalled_sink)).with_queue_blocks(1),
+            TeeBranch::new("file", Box::new(RecordingSink::new(&healthy))),
+        ]);
+        let metrics = tee.metrics();
+        let mut ctx = PipelineContext::default();
+        tee.prepare(SPEC, &mut ctx).expect("prepare");
+
+        for _ in 0..4 {
+            assert_eq!(tee.write(&block(0.5), &mut ctx), StageStatus::Ok);
+        }
+        let snapshot = metrics.snapshot();
+        assert!(snapshot[0].failed, "{snapshot:?}");
+        assert_eq!(
+            snapshot[0].last_error.as_deref(),
+            Some("tee branch stalled while pacing")
+        );
+        assert!(!snapshot[1].failed);
+
+        // The healthy branch now paces, so no further block is dropped for it.
+        for _ in 0..20 {
+            assert_eq!(tee.write(&block(0.5), &mut ctx), StageStatus::Ok);
+        }
+        tee.flush(&mut ctx).expect("flush");
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot[1].dropped_frames, 0);
+        assert_eq!(snapshot[1].written_frames, 240);
+
+        // The stalled worker is still blocked in its sink; stop must not wait forever.
+        let started = Instant::now();
+        tee.stop(&mut ctx);
+        assert!(started.elapsed() < Duration::from_secs(4));
+        drop(release_tx);
+    }
+}
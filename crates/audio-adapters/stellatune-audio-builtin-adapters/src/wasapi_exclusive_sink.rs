@@ -133,4 +133,8 @@ impl SinkStage for WasapiExclusiveSinkStage {
     fn stop(&mut self, ctx: &mut PipelineContext) {
         self.inner.stop(ctx)
     }
+
+    fn latency_frames(&self) -> u64 {
+        self.inner.latency_frames()
+    }
 }
@@ -17,8 +17,10 @@ use stellatune_plugins::host::stream::{HostStreamHandle, StreamSeekWhence};
 use stellatune_plugins::host_runtime::{
     RuntimeCapabilityKind, RuntimeDecoderPlugin, RuntimeSourcePlugin, shared_runtime_service,
 };
+use stellatune_plugins::manifest::AbilityKind;
 use stellatune_plugins::runtime::model::RuntimeSourceStreamHandle;
 
+use crate::errors::report_plugin_failure;
 use crate::source_plugin::PluginSourcePayload;
 use crate::source_plugin::plugin_track_token_from_source_handle;
 
@@ -199,9 +201,15 @@ impl PluginDecoderStage {
                 ctx.pending_seek_ms = None;
                 return Ok(());
             }
-            return Err(PipelineError::StageFailure(format!(
-                "decoder seek failed for {}::{}: {error}",
-                prepared.plugin_id, prepared.type_id
+            return Err(PipelineError::StageFailure(report_plugin_failure(
+                &prepared.plugin_id,
+                AbilityKind::Decoder,
+                &prepared.type_id,
+                &error,
+                format!(
+                    "decoder seek failed for {}::{}: {error}",
+                    prepared.plugin_id, prepared.type_id
+                ),
             )));
         }
         Ok(())
@@ -282,9 +290,15 @@ impl DecoderStage for PluginDecoderStage {
         {
             Ok(chunk) => chunk,
             Err(error) => {
-                self.last_runtime_error = Some(format!(
-                    "decoder read failed for {}::{}: {error}",
-                    prepared.plugin_id, prepared.type_id
+                self.last_runtime_error = Some(report_plugin_failure(
+                    &prepared.plugin_id,
+                    AbilityKind::Decoder,
+                    &prepared.type_id,
+                    &error,
+                    format!(
+                        "decoder read failed for {}::{}: {error}",
+                        prepared.plugin_id, prepared.type_id
+                    ),
                 ));
                 return StageStatus::Fatal;
             },
@@ -631,9 +645,15 @@ fn open_source_stream_for_decoder(
     source
         .apply_config_update_json(config_json.as_str())
         .map_err(|error| {
-            format!(
-                "source apply_config_update_json failed for {}::{}: {error}",
-                locator.plugin_id, locator.type_id
+            report_plugin_failure(
+                &locator.plugin_id,
+                AbilityKind::Source,
+                &locator.type_id,
+                &error,
+                format!(
+                    "source apply_config_update_json failed for {}::{}: {error}",
+                    locator.plugin_id, locator.type_id
+                ),
             )
         })?;
     let track_json = serde_json::to_string(&locator.track)
@@ -641,9 +661,15 @@ fn open_source_stream_for_decoder(
     let stream = source
         .open_stream_json(track_json.as_str())
         .map_err(|error| {
-            format!(
-                "source open_stream_json failed for {}::{}: {error}",
-                locator.plugin_id, locator.type_id
+            report_plugin_failure(
+                &locator.plugin_id,
+                AbilityKind::Source,
+                &locator.type_id,
+                &error,
+                format!(
+                    "source open_stream_json failed for {}::{}: {error}",
+                    locator.plugin_id, locator.type_id
+                ),
             )
         })?;
     let ext_hint = normalize_ext_hint(stream.ext_hint.as_deref().unwrap_or(fallback_ext_hint));
@@ -696,15 +722,27 @@ fn open_decoder_for_stream(
             (!ext_hint.trim().is_empty()).then_some(ext_hint.trim()),
         )
         .map_err(|error| {
-            format!(
-                "decoder open_stream failed for {}::{}: {error}",
-                plugin_id, type_id
+            report_plugin_failure(
+                plugin_id,
+                AbilityKind::Decoder,
+                type_id,
+                &error,
+                format!(
+                    "decoder open_stream failed for {}::{}: {error}",
+                    plugin_id, type_id
+                ),
             )
         })?;
     let info = decoder.info(session_handle).map_err(|error| {
-        format!(
-            "decoder info failed for {}::{} after open_stream: {error}",
-            plugin_id, type_id
+        report_plugin_failure(
+            plugin_id,
+            AbilityKind::Decoder,
+            type_id,
+            &error,
+            format!(
+                "decoder info failed for {}::{} after open_stream: {error}",
+                plugin_id, type_id
+            ),
         )
     })?;
     if info.sample_rate == 0 || info.channels == 0 {
@@ -837,9 +875,15 @@ impl HostStreamHandle for ProcessedSourceHostStreamHandle {
         let chunk = source.read(stream_handle, request_bytes).map_err(|error| {
             WasmPluginError::operation(
                 "runtime.source.read",
-                format!(
-                    "source stream read failed for {}::{}: {error}",
-                    self.plugin_id, self.type_id
+                report_plugin_failure(
+                    &self.plugin_id,
+                    AbilityKind::Source,
+                    &self.type_id,
+                    &error,
+                    format!(
+                        "source stream read failed for {}::{}: {error}",
+                        self.plugin_id, self.type_id
+                    ),
                 ),
             )
         })?;
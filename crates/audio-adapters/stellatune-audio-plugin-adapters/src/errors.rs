@@ -0,0 +1,34 @@
+//! Plugin failures seen by the pipeline stages, recorded in the plugin error timeline.
+
+use stellatune_plugins::error::Error as WasmPluginError;
+use stellatune_plugins::host_runtime::shared_runtime_service;
+use stellatune_plugins::manifest::AbilityKind;
+
+/// Records a failed plugin call and hands `detail` back for the pipeline error.
+pub(crate) fn report_plugin_failure(
+    plugin_id: &str,
+    kind: AbilityKind,
+    type_id: &str,
+    error: &WasmPluginError,
+    detail: String,
+) -> String {
+    shared_runtime_service().report_operation_failure(
+        plugin_id,
+        kind,
+        type_id,
+        error,
+        detail.clone(),
+    );
+    detail
+}
+
+/// Like [`report_plugin_failure`], for failures only available as text.
+pub(crate) fn report_plugin_failure_detail(
+    plugin_id: &str,
+    kind: AbilityKind,
+    type_id: &str,
+    detail: String,
+) -> String {
+    shared_runtime_service().report_operation_error(plugin_id, kind, type_id, detail.clone());
+    detail
+}
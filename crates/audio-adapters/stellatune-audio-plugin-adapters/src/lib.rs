@@ -2,6 +2,7 @@
 
 mod bridge;
 mod decoder_stage;
+mod errors;
 mod lifecycle;
 mod orchestrator;
 mod output_sink_runtime;
@@ -1,12 +1,16 @@
 use std::time::{Duration, Instant};
 
+use stellatune_audio::config::dither::SinkSampleFormat;
 use stellatune_plugins::host_runtime::{RuntimeOutputSinkPlugin, shared_runtime_service};
+use stellatune_plugins::runtime::model::{RuntimeHotPathRole, RuntimeSampleFormat};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NegotiatedOutputSinkSpec {
     pub sample_rate: u32,
     pub channels: u16,
     pub prefer_track_rate: bool,
+    /// Format of the sink's hot-path buffer; float when it has no hot path.
+    pub sample_format: SinkSampleFormat,
 }
 
 pub fn negotiate_output_sink_spec(
@@ -35,11 +39,26 @@ pub fn negotiate_output_sink_spec(
             desired_channels.max(1),
         )
         .map_err(|e| format!("output sink negotiate_spec_json failed: {e}"))?;
+    let sample_rate = negotiated.spec.sample_rate.max(1);
+    let channels = negotiated.spec.channels.max(1);
+    // Sinks without a usable hot-path description take the float write path.
+    let hot_path = sink.describe_hot_path(sample_rate, channels).ok().flatten();
+    let sample_format = match hot_path {
+        Some(spec) if spec.role == RuntimeHotPathRole::OutputSink => {
+            match spec.buffer.sample_format {
+                RuntimeSampleFormat::F32Le => SinkSampleFormat::Float32,
+                RuntimeSampleFormat::I16Le => SinkSampleFormat::Int16,
+                RuntimeSampleFormat::I32Le => SinkSampleFormat::Int32,
+            }
+        },
+        _ => SinkSampleFormat::Float32,
+    };
 
     Ok(NegotiatedOutputSinkSpec {
-        sample_rate: negotiated.spec.sample_rate.max(1),
-        channels: negotiated.spec.channels.max(1),
+        sample_rate,
+        channels,
         prefer_track_rate: negotiated.prefer_track_rate,
+        sample_format,
     })
 }
 
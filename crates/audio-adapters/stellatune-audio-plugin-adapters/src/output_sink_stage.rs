@@ -1,13 +1,19 @@
+use std::time::{Duration, Instant};
+
 use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
 use stellatune_audio_core::pipeline::error::PipelineError;
 use stellatune_audio_core::pipeline::stages::StageStatus;
 use stellatune_audio_core::pipeline::stages::sink::SinkStage;
 use stellatune_plugins::host_runtime::RuntimeOutputSinkPlugin;
+use stellatune_plugins::manifest::AbilityKind;
 
+use crate::errors::report_plugin_failure_detail;
 use crate::output_sink_runtime::{create_output_sink_controller_and_open, write_all_frames};
 
 const DEFAULT_WRITE_RETRY_SLEEP_MS: u64 = 2;
 const DEFAULT_WRITE_STALL_TIMEOUT_MS: u64 = 250;
+/// Minimum gap between two `query-status` calls made to refresh the reported latency.
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PluginOutputSinkRouteSpec {
@@ -54,6 +60,9 @@ pub struct PluginOutputSinkStage {
     sink: Option<RuntimeOutputSinkPlugin>,
     prepared_spec: Option<StreamSpec>,
     runtime_error: Option<String>,
+    /// Frames the plugin reported as queued at the last status poll.
+    queued_frames: u64,
+    last_status_poll: Option<Instant>,
 }
 
 impl PluginOutputSinkStage {
@@ -63,6 +72,8 @@ impl PluginOutputSinkStage {
             sink: None,
             prepared_spec: None,
             runtime_error: None,
+            queued_frames: 0,
+            last_status_poll: None,
         }
     }
 
@@ -86,6 +97,16 @@ impl PluginOutputSinkStage {
         ));
     }
 
+    /// Records `detail` in the plugin error timeline against this route.
+    fn report_failure(&self, detail: String) -> String {
+        report_plugin_failure_detail(
+            &self.route.plugin_id,
+            AbilityKind::OutputSink,
+            &self.route.type_id,
+            detail,
+        )
+    }
+
     fn open_sink(&mut self, spec: StreamSpec) -> Result<(), PipelineError> {
         let sink = create_output_sink_controller_and_open(
             &self.route.plugin_id,
@@ -95,12 +116,28 @@ impl PluginOutputSinkStage {
             spec.sample_rate,
             spec.channels,
         )
-        .map_err(|error| self.stage_failure(error))?;
+        .map_err(|error| self.stage_failure(self.report_failure(error)))?;
         self.sink = Some(sink);
         self.prepared_spec = Some(spec);
         self.runtime_error = None;
         Ok(())
     }
+
+    fn poll_queued_frames(&mut self, channels: u16) {
+        if self
+            .last_status_poll
+            .is_some_and(|polled| polled.elapsed() < STATUS_POLL_INTERVAL)
+        {
+            return;
+        }
+        let Some(sink) = self.sink.as_mut() else {
+            return;
+        };
+        self.last_status_poll = Some(Instant::now());
+        if let Ok(status) = sink.query_status() {
+            self.queued_frames = u64::from(status.queued_samples) / u64::from(channels.max(1));
+        }
+    }
 }
 
 impl SinkStage for PluginOutputSinkStage {
@@ -133,9 +170,13 @@ impl SinkStage for PluginOutputSinkStage {
             DEFAULT_WRITE_RETRY_SLEEP_MS,
             DEFAULT_WRITE_STALL_TIMEOUT_MS,
         ) {
-            Ok(()) => StageStatus::Ok,
+            Ok(()) => {
+                self.poll_queued_frames(block.channels);
+                StageStatus::Ok
+            },
             Err(error) => {
-                self.set_runtime_error(format!("write failed: {error}"));
+                let detail = self.report_failure(format!("write failed: {error}"));
+                self.set_runtime_error(detail);
                 StageStatus::Fatal
             },
         }
@@ -145,9 +186,11 @@ impl SinkStage for PluginOutputSinkStage {
         if let Some(error) = self.runtime_error.take() {
             return Err(PipelineError::StageFailure(error));
         }
-        if let Some(sink) = self.sink.as_mut() {
-            sink.flush()
-                .map_err(|e| self.stage_failure(format!("flush failed: {e}")))?;
+        if let Some(sink) = self.sink.as_mut()
+            && let Err(error) = sink.flush()
+        {
+            let detail = self.report_failure(format!("flush failed: {error}"));
+            return Err(self.stage_failure(detail));
         }
         Ok(())
     }
@@ -159,6 +202,12 @@ impl SinkStage for PluginOutputSinkStage {
         self.sink = None;
         self.prepared_spec = None;
         self.runtime_error = None;
+        self.queued_frames = 0;
+        self.last_status_poll = None;
+    }
+
+    fn latency_frames(&self) -> u64 {
+        self.queued_frames
     }
 }
 
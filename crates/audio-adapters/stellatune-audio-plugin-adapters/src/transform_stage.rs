@@ -6,9 +6,12 @@ use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, Stre
 use stellatune_audio_core::pipeline::error::PipelineError;
 use stellatune_audio_core::pipeline::stages::StageStatus;
 use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+use stellatune_plugins::error::Error as WasmPluginError;
 use stellatune_plugins::host_runtime::{RuntimeDspPlugin, shared_runtime_service};
+use stellatune_plugins::manifest::AbilityKind;
 
 use crate::bridge::PluginTransformStagePayload;
+use crate::errors::report_plugin_failure;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct TransformWorkerSpec {
@@ -104,22 +107,38 @@ impl PluginTransformStage {
         plugin
             .open_processor(spec.sample_rate.max(1), spec.channels.max(1))
             .map_err(|e| {
-                format!(
-                    "dsp open_processor failed for {}::{}: {e}",
-                    self.worker_spec.plugin_id, self.worker_spec.type_id
+                self.report_failure(
+                    &e,
+                    format!(
+                        "dsp open_processor failed for {}::{}: {e}",
+                        self.worker_spec.plugin_id, self.worker_spec.type_id
+                    ),
                 )
             })?;
         plugin
             .apply_config_update_json(&self.worker_spec.config_json)
             .map_err(|e| {
-                format!(
-                    "dsp apply_config_update_json failed for {}::{}: {e}",
-                    self.worker_spec.plugin_id, self.worker_spec.type_id
+                self.report_failure(
+                    &e,
+                    format!(
+                        "dsp apply_config_update_json failed for {}::{}: {e}",
+                        self.worker_spec.plugin_id, self.worker_spec.type_id
+                    ),
                 )
             })?;
         Ok(RuntimeTransformInstance { plugin, spec })
     }
 
+    fn report_failure(&self, error: &WasmPluginError, detail: String) -> String {
+        report_plugin_failure(
+            &self.worker_spec.plugin_id,
+            AbilityKind::Dsp,
+            &self.worker_spec.type_id,
+            error,
+            detail,
+        )
+    }
+
     fn clear_instance(&mut self) {
         if let Some(mut instance) = self.instance.take() {
             let _ = instance.plugin.close_processor();
@@ -169,6 +188,13 @@ impl PluginTransformStage {
             .apply_config_update_json(&control.config_json);
 
         if let Err(error) = apply_result {
+            self.report_failure(
+                &error,
+                format!(
+                    "plugin transform '{}' config update failed: {error}",
+                    self.stage_key
+                ),
+            );
             self.recreate_instance_preserve_state()
                 .map_err(PipelineError::StageFailure)?;
             return Err(PipelineError::StageFailure(format!(
@@ -263,9 +289,12 @@ impl TransformStage for PluginTransformStage {
         {
             Ok(()) => StageStatus::Ok,
             Err(error) => {
-                self.last_runtime_error = Some(format!(
-                    "plugin transform '{}' process failed: {error}",
-                    self.stage_key
+                self.last_runtime_error = Some(self.report_failure(
+                    &error,
+                    format!(
+                        "plugin transform '{}' process failed: {error}",
+                        self.stage_key
+                    ),
                 ));
                 StageStatus::Fatal
             },
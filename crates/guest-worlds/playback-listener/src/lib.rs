@@ -0,0 +1,5 @@
+wit_bindgen::generate!({
+    path: "../../../wit/stellatune-plugin",
+    world: "playback-listener-plugin",
+    pub_export_macro: true,
+});
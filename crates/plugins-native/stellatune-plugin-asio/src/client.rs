@@ -37,6 +37,7 @@ impl AsioSidecarClient {
             }],
             preferred_data: Vec::new(),
             env: Vec::new(),
+            supervision: None,
         };
 
         let process =
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use stellatune_plugin_sdk::__private::parking_lot::{Mutex, MutexGuard};
 use stellatune_plugin_sdk::__private::stellatune_world_source::stellatune::plugin::{
-    host_stream, sidecar,
+    http_client, sidecar,
 };
 use stellatune_plugin_sdk::prelude::*;
 use url::Url;
@@ -19,7 +19,16 @@ const DEFAULT_STREAM_READ_TIMEOUT_MS: Option<u64> = None;
 const DEFAULT_LEVEL: &str = "standard";
 const SIDECAR_READY_TIMEOUT_SECS: u64 = 10;
 const SIDECAR_READY_POLL_MS: u64 = 150;
+const SIDECAR_MAX_RESTARTS: u32 = 5;
+const SIDECAR_RESTART_BACKOFF_MS: u32 = 500;
+const SIDECAR_MAX_RESTART_BACKOFF_MS: u32 = 10_000;
 const MAX_HTTP_BODY_BYTES: usize = 16 * 1024 * 1024;
+const PLAYLIST_NODE_PREFIX: &str = "playlist:";
+const QR_LOGIN_POLL_INTERVAL_MS: u32 = 2_000;
+const QR_CHECK_EXPIRED: i64 = 800;
+const QR_CHECK_WAITING: i64 = 801;
+const QR_CHECK_SCANNED: i64 = 802;
+const QR_CHECK_CONFIRMED: i64 = 803;
 
 pub const SOURCE_TYPE_ID: &str = "netease";
 pub const SOURCE_DISPLAY_NAME: &str = "Netease Cloud Music";
@@ -67,7 +76,7 @@ impl SidecarManager {
             .as_deref()
             .map(|current| current != signature)
             .unwrap_or(true)
-            || self.process.is_none();
+            || !self.process_alive();
 
         if !needs_restart {
             return Ok(());
@@ -79,6 +88,20 @@ impl SidecarManager {
         Ok(())
     }
 
+    /// The host restarts a crashed sidecar on its own; only relaunch once it has given up.
+    fn process_alive(&self) -> bool {
+        let Some(process) = self.process.as_ref() else {
+            return false;
+        };
+        match process.status() {
+            Ok(status) => !matches!(
+                status.state,
+                sidecar::ProcessState::Failed | sidecar::ProcessState::Stopped
+            ),
+            Err(_) => false,
+        }
+    }
+
     fn shutdown_current(&mut self) {
         if let Some(process) = self.process.take() {
             let _ = process.terminate(200);
@@ -191,6 +214,13 @@ struct SidecarHealthResponse {
     ok: bool,
 }
 
+/// Sidecar auth endpoints return the raw NeteaseCloudMusicApi `body`.
+#[derive(Debug, Deserialize)]
+struct SidecarAuthResponse {
+    #[serde(default)]
+    body: Value,
+}
+
 #[derive(Debug, Deserialize)]
 struct SidecarSongListResponse {
     items: Vec<SidecarSongItem>,
@@ -279,6 +309,40 @@ impl SourceStream for UnsupportedSourceStream {
 impl SourceCatalog for NeteaseSourceCatalog {
     type Stream = UnsupportedSourceStream;
 
+    fn capabilities(&self) -> CatalogCapabilities {
+        CatalogCapabilities {
+            browse: true,
+            search: true,
+            open_uri: false,
+            search_kinds: vec![BrowseNodeKind::Track],
+        }
+    }
+
+    fn browse(&mut self, parent_id: Option<&str>, page: PageRequest) -> SdkResult<BrowsePage> {
+        let mut request = page_list_request(&page)?;
+        match parent_id {
+            None => request.action = "list_playlists".to_string(),
+            Some(id) => {
+                let playlist_id = id
+                    .strip_prefix(PLAYLIST_NODE_PREFIX)
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .ok_or_else(|| SdkError::not_found(format!("unknown browse node `{id}`")))?;
+                request.action = "playlist_tracks".to_string();
+                request.playlist_id = Some(playlist_id);
+            },
+        }
+        browse_page(&self.config, &request)
+    }
+
+    fn search(&mut self, request: SearchRequest) -> SdkResult<BrowsePage> {
+        if !request.kinds.is_empty() && !request.kinds.contains(&BrowseNodeKind::Track) {
+            return Ok(BrowsePage::default());
+        }
+        let mut list_request = page_list_request(&request.page)?;
+        list_request.keywords = request.query;
+        browse_page(&self.config, &list_request)
+    }
+
     fn list_items_json(&mut self, request_json: &str) -> SdkResult<String> {
         let request: NeteaseListRequest = serde_json::from_str(request_json).map_err(|error| {
             SdkError::invalid_arg(format!("invalid list request JSON: {error}"))
@@ -288,6 +352,81 @@ impl SourceCatalog for NeteaseSourceCatalog {
             .map_err(|error| SdkError::internal(format!("serialize list items JSON: {error}")))
     }
 
+    fn auth_status(&mut self) -> SdkResult<AuthStatus> {
+        fetch_auth_status(&self.config)
+    }
+
+    fn begin_login(&mut self, method: Option<LoginMethod>) -> SdkResult<LoginChallenge> {
+        if method.is_some_and(|method| method != LoginMethod::QrCode) {
+            return Err(SdkError::unsupported(
+                "netease source only supports QR code login",
+            ));
+        }
+        let params: Vec<(String, String)> = Vec::new();
+        let key: SidecarAuthResponse = sidecar_get_json(&self.config, "/v1/auth/qr/key", &params)?;
+        let session_id = key
+            .body
+            .pointer("/data/unikey")
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+            .ok_or_else(|| SdkError::internal("sidecar returned no QR login key"))?;
+        let params = vec![
+            ("key".to_string(), session_id.clone()),
+            ("qrimg".to_string(), "false".to_string()),
+        ];
+        let created: SidecarAuthResponse =
+            sidecar_get_json(&self.config, "/v1/auth/qr/create", &params)?;
+        let qr_url = created
+            .body
+            .pointer("/data/qrurl")
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+            .ok_or_else(|| SdkError::internal("sidecar returned no QR login URL"))?;
+        Ok(LoginChallenge {
+            method: LoginMethod::QrCode,
+            session_id,
+            url: Some(qr_url.clone()),
+            qr_payload: Some(qr_url),
+            user_code: None,
+            expires_at_ms: None,
+            poll_interval_ms: QR_LOGIN_POLL_INTERVAL_MS,
+        })
+    }
+
+    fn poll_login(&mut self, session_id: &str) -> SdkResult<LoginProgress> {
+        let params = vec![("key".to_string(), session_id.to_string())];
+        let checked: SidecarAuthResponse =
+            sidecar_get_json(&self.config, "/v1/auth/qr/check", &params)?;
+        let code = checked.body.get("code").and_then(Value::as_i64);
+        Ok(match code {
+            Some(QR_CHECK_WAITING) => LoginProgress::Waiting,
+            Some(QR_CHECK_SCANNED) => LoginProgress::Confirming,
+            Some(QR_CHECK_CONFIRMED) => LoginProgress::Completed {
+                status: fetch_auth_status(&self.config)?,
+            },
+            Some(QR_CHECK_EXPIRED) => LoginProgress::Expired,
+            _ => LoginProgress::Failed {
+                message: auth_body_message(&checked.body)
+                    .unwrap_or_else(|| format!("unexpected QR check code {code:?}")),
+            },
+        })
+    }
+
+    fn refresh_auth(&mut self) -> SdkResult<AuthStatus> {
+        let params: Vec<(String, String)> = Vec::new();
+        sidecar_get_json::<SidecarAuthResponse>(&self.config, "/v1/auth/login_refresh", &params)
+            .map_err(|error| {
+                SdkError::auth_expired(format!("netease session refresh failed: {error}"))
+            })?;
+        fetch_auth_status(&self.config)
+    }
+
+    fn logout(&mut self) -> SdkResult<()> {
+        let params: Vec<(String, String)> = Vec::new();
+        sidecar_get_json::<SidecarAuthResponse>(&self.config, "/v1/auth/logout", &params)?;
+        Ok(())
+    }
+
     fn open_stream_json(&mut self, _track_json: &str) -> SdkResult<Self::Stream> {
         Err(SdkError::unsupported(
             "source.open-stream-json requires opened-stream passthrough mode",
@@ -382,6 +521,70 @@ fn list_items(
         .collect())
 }
 
+/// Paging cursors are the decimal offset of the next page.
+fn page_list_request(page: &PageRequest) -> SdkResult<NeteaseListRequest> {
+    let offset = match page.cursor.as_deref() {
+        Some(cursor) => cursor
+            .parse::<u32>()
+            .map_err(|_| SdkError::invalid_arg(format!("invalid page cursor `{cursor}`")))?,
+        None => 0,
+    };
+    let mut request = NeteaseListRequest {
+        offset,
+        ..NeteaseListRequest::default()
+    };
+    if page.limit > 0 {
+        request.limit = page.limit;
+    }
+    Ok(request)
+}
+
+fn browse_page(
+    config: &NeteaseSourceConfig,
+    request: &NeteaseListRequest,
+) -> SdkResult<BrowsePage> {
+    let items = list_items(config, request)?;
+    // The sidecar has no total count; a full page means there may be more.
+    let next_cursor = (items.len() >= request.limit as usize)
+        .then(|| (request.offset as usize + items.len()).to_string());
+    let nodes = items
+        .into_iter()
+        .map(browse_node)
+        .collect::<SdkResult<Vec<_>>>()?;
+    Ok(BrowsePage { nodes, next_cursor })
+}
+
+fn browse_node(item: NeteaseListItem) -> SdkResult<BrowseNode> {
+    let (kind, id) = match item.kind.as_str() {
+        "playlist" => (
+            BrowseNodeKind::Playlist,
+            format!("{PLAYLIST_NODE_PREFIX}{}", item.item_id),
+        ),
+        _ => (BrowseNodeKind::Track, item.item_id),
+    };
+    let mut node = BrowseNode::new(kind, id, item.title);
+    node.subtitle = item.subtitle;
+    node.artists = item.artist.into_iter().collect();
+    node.album = item.album;
+    node.duration_ms = item.duration_ms.and_then(|ms| u64::try_from(ms).ok());
+    node.child_count = item.track_count.and_then(|count| u32::try_from(count).ok());
+    node.artwork_url = item
+        .cover
+        .filter(|cover| cover.kind.eq_ignore_ascii_case("url"))
+        .map(|cover| cover.value);
+    if let Some(track) = item.track {
+        node.extras.push(MetadataEntry {
+            key: "song_id".to_string(),
+            value: MetadataValue::Uint64(track.song_id),
+        });
+        node.track_json = Some(
+            serde_json::to_string(&track)
+                .map_err(|error| SdkError::internal(format!("serialize track JSON: {error}")))?,
+        );
+    }
+    Ok(node)
+}
+
 fn fetch_song_items(
     config: &NeteaseSourceConfig,
     request: &NeteaseListRequest,
@@ -468,6 +671,33 @@ fn fetch_playlist_items(
         .collect())
 }
 
+fn fetch_auth_status(config: &NeteaseSourceConfig) -> SdkResult<AuthStatus> {
+    let params: Vec<(String, String)> = Vec::new();
+    let response: SidecarAuthResponse = sidecar_get_json(config, "/v1/auth/login_status", &params)?;
+    let profile = response
+        .body
+        .pointer("/data/profile")
+        .filter(|profile| !profile.is_null());
+    let mut status = AuthStatus::new(if profile.is_some() {
+        AuthState::LoggedIn
+    } else {
+        AuthState::LoggedOut
+    });
+    status.account = profile
+        .and_then(|profile| profile.get("nickname"))
+        .and_then(Value::as_str)
+        .map(ToString::to_string);
+    status.login_methods = vec![LoginMethod::QrCode];
+    Ok(status)
+}
+
+fn auth_body_message(body: &Value) -> Option<String> {
+    ["message", "msg"]
+        .iter()
+        .find_map(|key| body.get(*key).and_then(Value::as_str))
+        .and_then(|message| non_empty_text(Some(message)))
+}
+
 fn extract_playlist_id(request: &NeteaseListRequest) -> Option<u64> {
     if let Some(v) = request.playlist_id {
         return Some(v);
@@ -551,25 +781,28 @@ fn build_url(
 }
 
 fn http_get_bytes(url: &str, timeout_ms: u32) -> SdkResult<Vec<u8>> {
-    let request = host_stream::OpenRequest {
-        kind: host_stream::StreamOpenKind::Http,
-        target: url.to_string(),
-        method: Some(host_stream::HttpMethod::Get),
+    let request = http_client::Request {
+        method: http_client::Method::Get,
+        url: url.to_string(),
         headers: Vec::new(),
         body: None,
-        connect_timeout_ms: Some(timeout_ms.max(500)),
-        read_timeout_ms: Some(timeout_ms.max(500)),
+        timeout_ms: Some(timeout_ms.max(500)),
     };
-    let handle = host_stream::open(&request).map_err(map_host_stream_error)?;
+    let response = http_client::send(&request).map_err(map_host_error)?;
+    let body = response.body;
+    if let Some(error) = status_error(response.status) {
+        body.close();
+        return Err(error);
+    }
 
     let mut out = Vec::<u8>::new();
     loop {
-        let chunk = handle.read(64 * 1024).map_err(map_host_stream_error)?;
+        let chunk = body.read(64 * 1024).map_err(map_host_error)?;
         if chunk.is_empty() {
             break;
         }
         if out.len().saturating_add(chunk.len()) > MAX_HTTP_BODY_BYTES {
-            handle.close();
+            body.close();
             return Err(SdkError::io(format!(
                 "HTTP response too large (> {} bytes)",
                 MAX_HTTP_BODY_BYTES
@@ -577,7 +810,7 @@ fn http_get_bytes(url: &str, timeout_ms: u32) -> SdkResult<Vec<u8>> {
         }
         out.extend_from_slice(&chunk);
     }
-    handle.close();
+    body.close();
     Ok(out)
 }
 
@@ -648,19 +881,44 @@ fn launch_sidecar_process(config: &NeteaseSourceConfig) -> SdkResult<sidecar::Pr
         }],
         preferred_data: Vec::new(),
         env: Vec::new(),
+        supervision: Some(sidecar::Supervision {
+            restart: Some(sidecar::RestartPolicy {
+                max_restarts: SIDECAR_MAX_RESTARTS,
+                initial_backoff_ms: SIDECAR_RESTART_BACKOFF_MS,
+                max_backoff_ms: SIDECAR_MAX_RESTART_BACKOFF_MS,
+            }),
+            // The sidecar serves HTTP rather than the control channel, so readiness is
+            // still checked through `/health`.
+            health_probe: None,
+            capture_output: true,
+        }),
     };
     sidecar::launch(&spec).map_err(map_sidecar_error)
 }
 
-fn map_host_stream_error(error: host_stream::PluginError) -> SdkError {
+/// Error for a non-2xx sidecar status. The sidecar answers 401 when a call needs a
+/// logged-in NetEase session.
+fn status_error(status: u16) -> Option<SdkError> {
+    match status {
+        200..=299 => None,
+        401 => Some(SdkError::auth_required(
+            "netease login required: sidecar answered HTTP 401",
+        )),
+        status => Some(SdkError::io(format!("sidecar answered HTTP {status}"))),
+    }
+}
+
+fn map_host_error(error: http_client::PluginError) -> SdkError {
     match error {
-        host_stream::PluginError::InvalidArg(message) => SdkError::invalid_arg(message),
-        host_stream::PluginError::NotFound(message) => SdkError::not_found(message),
-        host_stream::PluginError::Io(message) => SdkError::io(message),
-        host_stream::PluginError::Timeout(message) => SdkError::timeout(message),
-        host_stream::PluginError::Unsupported(message) => SdkError::unsupported(message),
-        host_stream::PluginError::Denied(message) => SdkError::denied(message),
-        host_stream::PluginError::Internal(message) => SdkError::internal(message),
+        http_client::PluginError::InvalidArg(message) => SdkError::invalid_arg(message),
+        http_client::PluginError::NotFound(message) => SdkError::not_found(message),
+        http_client::PluginError::Io(message) => SdkError::io(message),
+        http_client::PluginError::Timeout(message) => SdkError::timeout(message),
+        http_client::PluginError::Unsupported(message) => SdkError::unsupported(message),
+        http_client::PluginError::Denied(message) => SdkError::denied(message),
+        http_client::PluginError::Internal(message) => SdkError::internal(message),
+        http_client::PluginError::AuthRequired(message) => SdkError::auth_required(message),
+        http_client::PluginError::AuthExpired(message) => SdkError::auth_expired(message),
     }
 }
 
@@ -673,6 +931,8 @@ fn map_sidecar_error(error: sidecar::PluginError) -> SdkError {
         sidecar::PluginError::Unsupported(message) => SdkError::unsupported(message),
         sidecar::PluginError::Denied(message) => SdkError::denied(message),
         sidecar::PluginError::Internal(message) => SdkError::internal(message),
+        sidecar::PluginError::AuthRequired(message) => SdkError::auth_required(message),
+        sidecar::PluginError::AuthExpired(message) => SdkError::auth_expired(message),
     }
 }
 
@@ -0,0 +1,81 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static ARMED: Cell<bool> = const { Cell::new(false) };
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// System allocator wrapper that counts allocations made inside an [`AllocationScope`]
+/// on the current thread.
+///
+/// Install it as the `#[global_allocator]` of a debug, test or bench binary to make
+/// [`AllocationScope::allocations`] meaningful; without it every scope reports zero.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CountingAllocator;
+
+fn note_allocation() {
+    let _ = ARMED.try_with(|armed| {
+        if armed.get() {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get().wrapping_add(1)));
+        }
+    });
+}
+
+// SAFETY: every call is forwarded unchanged to `System`; counting only touches
+// const-initialized thread locals, which never allocate.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        note_allocation();
+        // SAFETY: the caller upholds the `GlobalAlloc::alloc` contract.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        note_allocation();
+        // SAFETY: the caller upholds the `GlobalAlloc::alloc_zeroed` contract.
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        note_allocation();
+        // SAFETY: the caller upholds the `GlobalAlloc::realloc` contract.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: the caller upholds the `GlobalAlloc::dealloc` contract.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+/// Counts allocations made by the current thread while the scope is alive.
+///
+/// Scopes nest; dropping one restores the previous arming state.
+#[derive(Debug)]
+pub struct AllocationScope {
+    start: u64,
+    was_armed: bool,
+}
+
+impl AllocationScope {
+    pub fn enter() -> Self {
+        let was_armed = ARMED.with(|armed| armed.replace(true));
+        Self {
+            start: ALLOCATIONS.with(Cell::get),
+            was_armed,
+        }
+    }
+
+    /// Allocations (including reallocations) counted since the scope was entered.
+    pub fn allocations(&self) -> u64 {
+        ALLOCATIONS.with(Cell::get).wrapping_sub(self.start)
+    }
+}
+
+impl Drop for AllocationScope {
+    fn drop(&mut self) {
+        let was_armed = self.was_armed;
+        let _ = ARMED.try_with(|armed| armed.set(was_armed));
+    }
+}
@@ -168,6 +168,15 @@ impl AudioBlock {
         self.samples.clear();
     }
 
+    /// Swaps the block's samples with `scratch`, leaving the previous samples there.
+    ///
+    /// Stages that cannot work in place render into a scratch buffer they own and swap
+    /// it in; both buffers keep their capacity, so the pair ping-pongs without
+    /// allocating once warmed up.
+    pub fn swap_samples(&mut self, scratch: &mut Vec<f32>) {
+        std::mem::swap(&mut self.samples, scratch);
+    }
+
     pub fn is_empty(&self) -> bool {
         self.samples.is_empty()
     }
@@ -1,3 +1,5 @@
+pub mod alloc_guard;
 pub mod context;
 pub mod error;
+pub mod pool;
 pub mod stages;
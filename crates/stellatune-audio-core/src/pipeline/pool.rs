@@ -0,0 +1,69 @@
+use std::sync::{Arc, Mutex};
+
+use crate::pipeline::context::AudioBlock;
+
+/// Bounded free list of sample buffers shared by the thread that fills blocks and the
+/// thread that consumes them.
+///
+/// The producer takes blocks with [`AudioBlockPool::acquire`] and the consumer hands
+/// them back with [`AudioBlockPool::recycle`] once written. After the first few blocks
+/// every buffer already has the capacity the stream needs, so steady-state playback
+/// moves blocks around without touching the allocator.
+#[derive(Debug, Clone)]
+pub struct AudioBlockPool {
+    inner: Arc<PoolInner>,
+}
+
+#[derive(Debug)]
+struct PoolInner {
+    free: Mutex<Vec<Vec<f32>>>,
+    max_buffers: usize,
+}
+
+impl AudioBlockPool {
+    /// Creates a pool keeping at most `max_buffers` idle buffers. The free list itself
+    /// is allocated up front so recycling never grows it.
+    pub fn new(max_buffers: usize) -> Self {
+        let max_buffers = max_buffers.max(1);
+        Self {
+            inner: Arc::new(PoolInner {
+                free: Mutex::new(Vec::with_capacity(max_buffers)),
+                max_buffers,
+            }),
+        }
+    }
+
+    /// Returns an empty block, reusing an idle buffer when one is available.
+    pub fn acquire(&self, channels: u16) -> AudioBlock {
+        let samples = self
+            .inner
+            .free
+            .lock()
+            .map(|mut free| free.pop())
+            .unwrap_or_default()
+            .unwrap_or_default();
+        AudioBlock { channels, samples }
+    }
+
+    /// Hands a consumed block back to the pool.
+    ///
+    /// Never blocks: when the free list is busy or full, the buffer is dropped instead.
+    pub fn recycle(&self, block: AudioBlock) {
+        let mut samples = block.samples;
+        if samples.capacity() == 0 {
+            return;
+        }
+        let Ok(mut free) = self.inner.free.try_lock() else {
+            return;
+        };
+        if free.len() < self.inner.max_buffers {
+            samples.clear();
+            free.push(samples);
+        }
+    }
+
+    /// Number of idle buffers ready for [`AudioBlockPool::acquire`].
+    pub fn available(&self) -> usize {
+        self.inner.free.lock().map(|free| free.len()).unwrap_or(0)
+    }
+}
@@ -14,4 +14,10 @@ pub trait SinkStage: Send {
     fn flush(&mut self, ctx: &mut PipelineContext) -> Result<(), PipelineError>;
 
     fn stop(&mut self, ctx: &mut PipelineContext);
+
+    /// Frames written but not audible yet, measured now at the prepared rate. Sinks that
+    /// buffer audio report their fill; the tee sink uses it to line outputs up.
+    fn latency_frames(&self) -> u64 {
+        0
+    }
 }
@@ -10,6 +10,41 @@ pub trait TransformStage: Send {
         None
     }
 
+    /// Whether `process` stays allocation-free once the stage has warmed up for the
+    /// prepared spec, matching [`StageProfile::no_alloc_hot_path`]. Debug builds check
+    /// the claim on the decode thread.
+    ///
+    /// [`StageProfile::no_alloc_hot_path`]: crate::pipeline::context::StageProfile::no_alloc_hot_path
+    fn no_alloc_hot_path(&self) -> bool {
+        false
+    }
+
+    /// Frames the stage delays audio by, counted at its output rate. Only meaningful
+    /// after `prepare`; the runner discounts it from playback position.
+    fn latency_frames(&self) -> u64 {
+        0
+    }
+
+    /// Source frames each output frame stands for, e.g. `1.5` while the stage plays audio
+    /// at 1.5x speed. The runner scales playback position and remaining-frame hints by it.
+    fn playback_rate(&self) -> f64 {
+        1.0
+    }
+
+    /// Source frames behind the block the stage last processed, given the `output_frames`
+    /// that block carried out of it. The default scales by
+    /// [`playback_rate`](Self::playback_rate); stages that buffer audio across rate
+    /// changes report what they actually consumed instead. The runner advances playback
+    /// position by it.
+    fn source_frames(&self, output_frames: u64) -> u64 {
+        let rate = self.playback_rate();
+        if rate == 1.0 {
+            output_frames
+        } else {
+            (output_frames as f64 * rate).round() as u64
+        }
+    }
+
     fn apply_control(
         &mut self,
         _control: &dyn Any,
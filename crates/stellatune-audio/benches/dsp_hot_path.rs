@@ -0,0 +1,233 @@
+//! Throughput of the built-in DSP chain, plus an allocation check for its hot path.
+//!
+//! Every iteration renders a synthetic 5.1 stream through the offline renderer with
+//! each built-in stage doing real work: downmix, 44.1k -> 48k resampling, time stretch
+//! with a pitch shift, crossfeed and noise-shaped 16-bit dither. The bench binary
+//! installs [`CountingAllocator`] so the allocation check sees what the stages do
+//! between decode and sink write.
+
+use std::cell::Cell;
+use std::hint::black_box;
+use std::sync::atomic::AtomicBool;
+
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use stellatune_audio::config::crossfeed::CrossfeedPreset;
+use stellatune_audio::config::dither::{DitherConfig, NoiseShaping, SinkSampleFormat};
+use stellatune_audio::config::engine::{LfeMode, ResampleQuality};
+use stellatune_audio::config::time_stretch::TimeStretchConfig;
+use stellatune_audio::pipeline::assembly::{
+    AssembledDecodePipeline, AssembledPipeline, BuiltinTransformSlots, MixerPlan, ResamplerPlan,
+    StaticSinkPlan, TransformChain,
+};
+use stellatune_audio::pipeline::offline::OfflineRenderer;
+use stellatune_audio_core::pipeline::alloc_guard::{AllocationScope, CountingAllocator};
+use stellatune_audio_core::pipeline::context::{
+    AudioBlock, InputRef, PipelineContext, SourceHandle, StreamSpec,
+};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::decoder::DecoderStage;
+use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+use stellatune_audio_core::pipeline::stages::source::SourceStage;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const INPUT_SPEC: StreamSpec = StreamSpec {
+    sample_rate: 44_100,
+    channels: 6,
+};
+const BLOCK_FRAMES: usize = 1024;
+const RENDERED_BLOCKS: usize = 256;
+/// Blocks the stages may spend sizing their scratch buffers before allocations count.
+const WARMUP_BLOCKS: usize = 32;
+
+thread_local! {
+    /// Opened once a block leaves the decoder and closed when it reaches the sink, so
+    /// it covers exactly the transform chain.
+    static TRANSFORM_SCOPE: Cell<Option<AllocationScope>> = const { Cell::new(None) };
+    /// Steady-state transform allocations of the last render, set when its sink stops.
+    static ALLOCATION_REPORT: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+struct SilentSource;
+
+impl SourceStage for SilentSource {
+    fn prepare(
+        &mut self,
+        _input: &InputRef,
+        _ctx: &mut PipelineContext,
+    ) -> Result<SourceHandle, PipelineError> {
+        Ok(SourceHandle::new(()))
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {}
+}
+
+struct SyntheticDecoder {
+    pattern: Vec<f32>,
+    remaining_blocks: usize,
+}
+
+impl SyntheticDecoder {
+    fn new(blocks: usize) -> Self {
+        let samples = BLOCK_FRAMES * INPUT_SPEC.channels as usize;
+        Self {
+            pattern: (0..samples)
+                .map(|i| ((i % 193) as f32 / 193.0) - 0.5)
+                .collect(),
+            remaining_blocks: blocks,
+        }
+    }
+}
+
+impl DecoderStage for SyntheticDecoder {
+    fn prepare(
+        &mut self,
+        _source: &SourceHandle,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        Ok(INPUT_SPEC)
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn next_block(&mut self, out: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        if self.remaining_blocks == 0 {
+            return StageStatus::Eof;
+        }
+        self.remaining_blocks -= 1;
+        out.channels = INPUT_SPEC.channels;
+        out.samples.extend_from_slice(&self.pattern);
+        TRANSFORM_SCOPE.set(Some(AllocationScope::enter()));
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {}
+}
+
+/// Discards output and tallies transform allocations once the warm-up has passed.
+#[derive(Default)]
+struct NullSink {
+    blocks: usize,
+    steady_state_allocations: u64,
+}
+
+impl NullSink {
+    fn observe_transform_scope(&mut self) {
+        let Some(scope) = TRANSFORM_SCOPE.take() else {
+            return;
+        };
+        self.blocks += 1;
+        if self.blocks > WARMUP_BLOCKS {
+            self.steady_state_allocations += scope.allocations();
+        }
+    }
+}
+
+impl SinkStage for NullSink {
+    fn prepare(
+        &mut self,
+        _spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn write(&mut self, block: &AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        self.observe_transform_scope();
+        black_box(block.samples.as_slice());
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        if self.blocks > WARMUP_BLOCKS {
+            ALLOCATION_REPORT.set(Some(self.steady_state_allocations));
+        }
+    }
+}
+
+fn builtin_chain_renderer() -> OfflineRenderer {
+    let decode = AssembledDecodePipeline {
+        source: Box::new(SilentSource),
+        decoder: Box::new(SyntheticDecoder::new(RENDERED_BLOCKS)),
+        transforms: Vec::new(),
+        transform_chain: TransformChain::default(),
+        mixer: Some(MixerPlan::new(2, LfeMode::MixToFront)),
+        resampler: Some(ResamplerPlan::new(48_000, ResampleQuality::Balanced)),
+        // Transition and master gain only move when the engine drives them.
+        builtin_slots: BuiltinTransformSlots {
+            gapless_trim: false,
+            transition_gain: false,
+            master_gain: false,
+            crossfeed: true,
+            convolution: false,
+            dither: true,
+            time_stretch: true,
+        },
+    };
+    let sinks: Vec<Box<dyn SinkStage>> = vec![Box::new(NullSink::default())];
+    OfflineRenderer::new(AssembledPipeline::from_parts(
+        decode,
+        Box::new(StaticSinkPlan::new(sinks)),
+    ))
+    .expect("offline renderer")
+    .with_control(TimeStretchConfig::new(1.25, 3.0))
+    .with_control(CrossfeedPreset::ChuMoy.config())
+    .with_control(DitherConfig {
+        sink_format: SinkSampleFormat::Int16,
+        tpdf: true,
+        noise_shaping: NoiseShaping::Wannamaker,
+    })
+}
+
+fn render(renderer: OfflineRenderer) {
+    renderer
+        .render(
+            &InputRef::TrackToken("bench".to_string()),
+            &AtomicBool::new(false),
+            |progress| {
+                black_box(progress);
+            },
+        )
+        .expect("render");
+}
+
+fn builtin_chain(c: &mut Criterion) {
+    ALLOCATION_REPORT.set(None);
+    render(builtin_chain_renderer());
+    let allocations = ALLOCATION_REPORT
+        .take()
+        .expect("render produced fewer blocks than the warm-up");
+    assert_eq!(
+        allocations, 0,
+        "built-in chain made {allocations} steady-state allocations"
+    );
+
+    let mut group = c.benchmark_group("dsp_hot_path");
+    group.sample_size(20);
+    group.throughput(Throughput::Elements(
+        (RENDERED_BLOCKS * BLOCK_FRAMES) as u64,
+    ));
+    group.bench_function("builtin_chain_5_1_to_stereo_48k", |b| {
+        b.iter_batched(builtin_chain_renderer, render, BatchSize::LargeInput);
+    });
+    group.finish();
+}
+
+criterion_group!(benches, builtin_chain);
+criterion_main!(benches);
@@ -0,0 +1,170 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use stellatune_audio_core::pipeline::error::PipelineError;
+
+use crate::pipeline::runtime::dsp::convolution::wav::decode_wav;
+
+/// Transform stage key of the built-in convolution stage, used with
+/// [`EngineHandle::apply_stage_control`](crate::engine::EngineHandle::apply_stage_control).
+///
+/// The stage accepts a [`ConvolutionControl`] payload.
+pub const CONVOLUTION_STAGE_KEY: &str = "builtin.convolution";
+
+/// Longest accepted impulse response, in frames at its own sample rate.
+pub const MAX_IMPULSE_FRAMES: usize = 1 << 20;
+
+/// Crossfade used when a new filter replaces one that is already playing.
+pub const DEFAULT_CONVOLUTION_CROSSFADE_MS: u32 = 50;
+
+/// How the channels of an [`ImpulseResponse`] map onto the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImpulseLayout {
+    /// One filter per stream channel, in stream channel order. A single filter is applied
+    /// to every channel.
+    #[default]
+    PerChannel,
+    /// Four filters for a stereo stream, ordered L→L, L→R, R→L, R→R.
+    TrueStereo,
+}
+
+/// Impulse response used by the convolution stage, such as a room-correction FIR or a
+/// headphone IR.
+///
+/// Filters are stored per channel (not interleaved) at the rate they were measured at;
+/// the stage resamples them to the stream rate when it is prepared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImpulseResponse {
+    sample_rate: u32,
+    layout: ImpulseLayout,
+    filters: Vec<Vec<f32>>,
+}
+
+impl ImpulseResponse {
+    /// Creates an impulse response from per-channel filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipelineError::StageFailure`] when there are no filters, filters differ in
+    /// length or are empty, exceed [`MAX_IMPULSE_FRAMES`], contain non-finite taps, or
+    /// the filter count does not suit `layout`.
+    pub fn new(
+        sample_rate: u32,
+        layout: ImpulseLayout,
+        filters: Vec<Vec<f32>>,
+    ) -> Result<Self, PipelineError> {
+        if sample_rate == 0 {
+            return Err(PipelineError::StageFailure(
+                "impulse response sample rate must be non-zero".to_string(),
+            ));
+        }
+        let frames = filters.first().map(Vec::len).unwrap_or(0);
+        if frames == 0 {
+            return Err(PipelineError::StageFailure(
+                "impulse response must have at least one channel and frame".to_string(),
+            ));
+        }
+        if frames > MAX_IMPULSE_FRAMES {
+            return Err(PipelineError::StageFailure(format!(
+                "impulse response has {frames} frames, limit is {MAX_IMPULSE_FRAMES}"
+            )));
+        }
+        if filters.iter().any(|filter| filter.len() != frames) {
+            return Err(PipelineError::StageFailure(
+                "impulse response channels differ in length".to_string(),
+            ));
+        }
+        if layout == ImpulseLayout::TrueStereo && filters.len() != 4 {
+            return Err(PipelineError::StageFailure(format!(
+                "true stereo impulse response needs 4 channels, got {}",
+                filters.len()
+            )));
+        }
+        if filters.iter().flatten().any(|tap| !tap.is_finite()) {
+            return Err(PipelineError::StageFailure(
+                "impulse response taps must be finite".to_string(),
+            ));
+        }
+        Ok(Self {
+            sample_rate,
+            layout,
+            filters,
+        })
+    }
+
+    /// Parses a RIFF/WAVE file holding integer PCM (8/16/24/32-bit) or float (32/64-bit)
+    /// samples, one filter per channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipelineError::StageFailure`] for malformed or unsupported files and for
+    /// anything [`ImpulseResponse::new`] rejects.
+    pub fn from_wav_bytes(bytes: &[u8], layout: ImpulseLayout) -> Result<Self, PipelineError> {
+        let (sample_rate, filters) = decode_wav(bytes)
+            .map_err(|error| PipelineError::StageFailure(format!("impulse wav: {error}")))?;
+        Self::new(sample_rate, layout, filters)
+    }
+
+    /// Reads and parses a WAV impulse response from disk, see
+    /// [`ImpulseResponse::from_wav_bytes`].
+    pub fn from_wav_file(
+        path: impl AsRef<Path>,
+        layout: ImpulseLayout,
+    ) -> Result<Self, PipelineError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|error| {
+            PipelineError::StageFailure(format!(
+                "failed to read impulse response {}: {error}",
+                path.display()
+            ))
+        })?;
+        Self::from_wav_bytes(&bytes, layout)
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn layout(&self) -> ImpulseLayout {
+        self.layout
+    }
+
+    /// Per-channel filter taps.
+    pub fn filters(&self) -> &[Vec<f32>] {
+        &self.filters
+    }
+
+    /// Filter length in frames at [`ImpulseResponse::sample_rate`].
+    pub fn frames(&self) -> usize {
+        self.filters.first().map(Vec::len).unwrap_or(0)
+    }
+}
+
+/// Control payload for [`CONVOLUTION_STAGE_KEY`].
+///
+/// Replacing a filter while audio is playing crossfades from the old filter to the new
+/// one over `crossfade_ms`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvolutionControl {
+    /// Filter to apply; `None` passes audio through unchanged (still delayed by the
+    /// stage latency).
+    pub impulse: Option<Arc<ImpulseResponse>>,
+    /// Crossfade length for hot swaps.
+    pub crossfade_ms: u32,
+}
+
+impl ConvolutionControl {
+    /// Loads `impulse` with the default crossfade.
+    pub fn load(impulse: Arc<ImpulseResponse>) -> Self {
+        Self {
+            impulse: Some(impulse),
+            crossfade_ms: DEFAULT_CONVOLUTION_CROSSFADE_MS,
+        }
+    }
+
+    /// Sets the crossfade used if this control replaces a playing filter.
+    pub fn with_crossfade_ms(mut self, crossfade_ms: u32) -> Self {
+        self.crossfade_ms = crossfade_ms;
+        self
+    }
+}
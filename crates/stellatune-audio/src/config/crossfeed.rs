@@ -0,0 +1,94 @@
+/// Transform stage key of the built-in crossfeed stage, used with
+/// [`EngineHandle::apply_stage_control`](crate::engine::EngineHandle::apply_stage_control).
+///
+/// The stage accepts a [`CrossfeedConfig`] payload.
+pub const CROSSFEED_STAGE_KEY: &str = "builtin.crossfeed";
+
+/// Lowest accepted crossfeed cutoff frequency.
+pub const CROSSFEED_MIN_CUTOFF_HZ: u32 = 300;
+/// Highest accepted crossfeed cutoff frequency.
+pub const CROSSFEED_MAX_CUTOFF_HZ: u32 = 2_000;
+/// Lowest accepted crossfeed level.
+pub const CROSSFEED_MIN_FEED_DB: f32 = 1.0;
+/// Highest accepted crossfeed level.
+pub const CROSSFEED_MAX_FEED_DB: f32 = 15.0;
+
+/// Well-known crossfeed settings from the bs2b project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrossfeedPreset {
+    /// bs2b default: 700 Hz, 4.5 dB. Closest to speaker listening.
+    #[default]
+    Default,
+    /// Chu Moy circuit: 700 Hz, 6.0 dB.
+    ChuMoy,
+    /// Jan Meier circuit: 650 Hz, 9.5 dB. The subtlest of the three.
+    JanMeier,
+}
+
+impl CrossfeedPreset {
+    /// Returns the cutoff and feed level of this preset.
+    pub fn config(self) -> CrossfeedConfig {
+        match self {
+            Self::Default => CrossfeedConfig {
+                cutoff_hz: 700,
+                feed_db: 4.5,
+            },
+            Self::ChuMoy => CrossfeedConfig {
+                cutoff_hz: 700,
+                feed_db: 6.0,
+            },
+            Self::JanMeier => CrossfeedConfig {
+                cutoff_hz: 650,
+                feed_db: 9.5,
+            },
+        }
+    }
+}
+
+/// Headphone crossfeed parameters.
+///
+/// Each ear receives the opposite channel low-passed at `cutoff_hz` and attenuated by
+/// `feed_db` relative to the direct signal, while the direct signal gets a matching
+/// high-frequency lift so the overall tonal balance stays flat.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrossfeedConfig {
+    /// Low-pass cutoff of the crossfed signal, in Hz.
+    pub cutoff_hz: u32,
+    /// Attenuation of the crossfed signal at low frequencies, in dB.
+    pub feed_db: f32,
+}
+
+impl Default for CrossfeedConfig {
+    fn default() -> Self {
+        CrossfeedPreset::Default.config()
+    }
+}
+
+impl From<CrossfeedPreset> for CrossfeedConfig {
+    fn from(preset: CrossfeedPreset) -> Self {
+        preset.config()
+    }
+}
+
+impl CrossfeedConfig {
+    /// Creates a config, clamping both values into their accepted ranges.
+    pub fn new(cutoff_hz: u32, feed_db: f32) -> Self {
+        Self { cutoff_hz, feed_db }.clamped()
+    }
+
+    /// Returns a copy with `cutoff_hz` and `feed_db` clamped into their accepted ranges.
+    /// Non-finite feed levels fall back to the default preset level.
+    pub fn clamped(self) -> Self {
+        let feed_db = if self.feed_db.is_finite() {
+            self.feed_db
+        } else {
+            CrossfeedPreset::Default.config().feed_db
+        };
+        Self {
+            cutoff_hz: self
+                .cutoff_hz
+                .clamp(CROSSFEED_MIN_CUTOFF_HZ, CROSSFEED_MAX_CUTOFF_HZ),
+            feed_db: feed_db.clamp(CROSSFEED_MIN_FEED_DB, CROSSFEED_MAX_FEED_DB),
+        }
+    }
+}
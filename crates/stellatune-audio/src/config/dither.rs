@@ -0,0 +1,89 @@
+/// Transform stage key of the built-in output quantizer, used with
+/// [`EngineHandle::apply_stage_control`](crate::engine::EngineHandle::apply_stage_control).
+///
+/// The stage accepts a [`DitherConfig`] payload.
+pub const DITHER_STAGE_KEY: &str = "builtin.dither";
+
+/// Sample format the output sink hands to the device, plugin or file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SinkSampleFormat {
+    /// 32-bit float. Needs no quantization.
+    #[default]
+    Float32,
+    /// 16-bit signed integer.
+    Int16,
+    /// 24-bit signed integer.
+    Int24,
+    /// 32-bit signed integer container.
+    Int32,
+}
+
+impl SinkSampleFormat {
+    /// Bit depth the stream is quantized to for this sink, or `None` when the sink takes
+    /// float samples.
+    ///
+    /// 32-bit integer sinks are quantized at 24 bits: an `f32` sample cannot carry more,
+    /// and most such devices only honour 24 valid bits.
+    pub fn quantize_bits(self) -> Option<u32> {
+        match self {
+            Self::Float32 => None,
+            Self::Int16 => Some(16),
+            Self::Int24 | Self::Int32 => Some(24),
+        }
+    }
+}
+
+/// Error-feedback curve that moves quantization noise away from the most audible band.
+///
+/// The weighted curves are designed for 44.1/48 kHz output; at higher rates they push the
+/// noise further up where it stays inaudible anyway.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NoiseShaping {
+    /// Flat (white) noise floor.
+    #[default]
+    Off,
+    /// First-order high-pass shaping. Gentle and safe at any rate.
+    FirstOrder,
+    /// Lipshitz 5-tap E-weighted curve.
+    Lipshitz,
+    /// Wannamaker 9-tap F-weighted curve. Lowest perceived noise, highest total noise power.
+    Wannamaker,
+}
+
+impl NoiseShaping {
+    /// Error-feedback filter taps, newest error first.
+    pub(crate) fn coefficients(self) -> &'static [f32] {
+        match self {
+            Self::Off => &[],
+            Self::FirstOrder => &[1.0],
+            Self::Lipshitz => &[2.033, -2.165, 1.959, -1.590, 0.6149],
+            Self::Wannamaker => &[
+                2.412, -3.370, 3.937, -4.174, 3.353, -2.205, 1.281, -0.569, 0.0847,
+            ],
+        }
+    }
+}
+
+/// Output quantizer settings.
+///
+/// For integer sinks the stage rounds every sample onto the sink's integer grid so the
+/// conversion downstream is exact. Float sinks bypass the stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DitherConfig {
+    /// Format negotiated with the current sink.
+    pub sink_format: SinkSampleFormat,
+    /// Adds TPDF dither of ±1 LSB before rounding. Without it the stage only rounds.
+    pub tpdf: bool,
+    /// Noise-shaping curve applied to the quantization error.
+    pub noise_shaping: NoiseShaping,
+}
+
+impl Default for DitherConfig {
+    fn default() -> Self {
+        Self {
+            sink_format: SinkSampleFormat::Float32,
+            tpdf: true,
+            noise_shaping: NoiseShaping::Off,
+        }
+    }
+}
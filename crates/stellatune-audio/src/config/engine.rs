@@ -56,6 +56,17 @@ pub enum ResampleQuality {
     Ultra,
 }
 
+/// Pacing policy for the decode worker while playing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockMode {
+    /// Paced by sink backpressure, sleeping between steps as configured.
+    #[default]
+    Paced,
+    /// Step back-to-back, waiting only while the sink queue is full. Meant for sinks that
+    /// are not tied to an audio device, such as offline rendering to a file.
+    FreeRunning,
+}
+
 /// Event payload emitted by the engine event stream.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Event {
@@ -135,6 +146,8 @@ pub struct EngineConfig {
     pub decode_playing_idle_sleep: Duration,
     /// Sleep interval while globally idle.
     pub decode_idle_sleep: Duration,
+    /// Initial decode worker pacing policy.
+    pub clock_mode: ClockMode,
     /// Timeout for sink control commands.
     pub sink_control_timeout: Duration,
     /// Sink queue/latency policy.
@@ -157,6 +170,7 @@ impl Default for EngineConfig {
             decode_playing_pending_block_sleep: Duration::from_micros(250),
             decode_playing_idle_sleep: Duration::from_millis(2),
             decode_idle_sleep: Duration::from_millis(8),
+            clock_mode: ClockMode::default(),
             sink_control_timeout: Duration::from_millis(500),
             sink_latency: SinkLatencyConfig::default(),
             sink_recovery: SinkRecoveryConfig::default(),
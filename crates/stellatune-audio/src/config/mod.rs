@@ -3,9 +3,17 @@
 //! This module contains user-facing settings and event payload types consumed by
 //! the engine and surrounding backend layers.
 
+/// Convolution filter (impulse response) configuration.
+pub mod convolution;
+/// Headphone crossfeed configuration.
+pub mod crossfeed;
+/// Output quantization and dither configuration.
+pub mod dither;
 /// Engine state, event, and control configuration models.
 pub mod engine;
 /// Gain transition policy configuration.
 pub mod gain;
 /// Sink latency and recovery policy configuration.
 pub mod sink;
+/// Playback speed and pitch configuration.
+pub mod time_stretch;
@@ -0,0 +1,78 @@
+/// Transform stage key of the built-in time-stretch stage, used with
+/// [`EngineHandle::apply_stage_control`](crate::engine::EngineHandle::apply_stage_control).
+///
+/// The stage accepts a [`TimeStretchConfig`] payload.
+pub const TIME_STRETCH_STAGE_KEY: &str = "builtin.time_stretch";
+
+/// Slowest accepted playback speed.
+pub const TIME_STRETCH_MIN_SPEED: f32 = 0.5;
+/// Fastest accepted playback speed.
+pub const TIME_STRETCH_MAX_SPEED: f32 = 3.0;
+/// Largest accepted pitch shift in either direction, in semitones.
+pub const TIME_STRETCH_MAX_PITCH_SEMITONES: f32 = 12.0;
+
+/// Playback speed and pitch.
+///
+/// Speed changes tempo without changing pitch; pitch shifts without changing tempo. The
+/// two combine freely, so `speed: 2.0, pitch_semitones: 12.0` sounds like the classic
+/// double-speed tape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeStretchConfig {
+    /// Playback speed, `1.0` being the original tempo.
+    pub speed: f32,
+    /// Pitch shift in semitones, `0.0` being the original pitch.
+    pub pitch_semitones: f32,
+}
+
+impl Default for TimeStretchConfig {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            pitch_semitones: 0.0,
+        }
+    }
+}
+
+impl TimeStretchConfig {
+    /// Creates a config, clamping both values into their accepted ranges.
+    pub fn new(speed: f32, pitch_semitones: f32) -> Self {
+        Self {
+            speed,
+            pitch_semitones,
+        }
+        .clamped()
+    }
+
+    /// Returns a copy with `speed` and `pitch_semitones` clamped into their accepted
+    /// ranges. Non-finite values fall back to the defaults.
+    pub fn clamped(self) -> Self {
+        let defaults = Self::default();
+        let speed = if self.speed.is_finite() {
+            self.speed
+        } else {
+            defaults.speed
+        };
+        let pitch_semitones = if self.pitch_semitones.is_finite() {
+            self.pitch_semitones
+        } else {
+            defaults.pitch_semitones
+        };
+        Self {
+            speed: speed.clamp(TIME_STRETCH_MIN_SPEED, TIME_STRETCH_MAX_SPEED),
+            pitch_semitones: pitch_semitones.clamp(
+                -TIME_STRETCH_MAX_PITCH_SEMITONES,
+                TIME_STRETCH_MAX_PITCH_SEMITONES,
+            ),
+        }
+    }
+
+    /// Whether the config leaves audio untouched.
+    pub fn is_identity(&self) -> bool {
+        self.speed == 1.0 && self.pitch_semitones == 0.0
+    }
+
+    /// Frequency ratio of the pitch shift.
+    pub(crate) fn pitch_ratio(&self) -> f64 {
+        2.0_f64.powf(f64::from(self.pitch_semitones) / 12.0)
+    }
+}
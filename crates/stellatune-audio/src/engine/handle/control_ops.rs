@@ -1,9 +1,9 @@
 use std::any::Any;
 
-use crate::config::engine::{Event, LfeMode, ResampleQuality};
+use crate::config::engine::{ClockMode, Event, LfeMode, ResampleQuality};
 use crate::engine::handle::EngineHandle;
 use crate::engine::messages::{
-    ApplyStageControlMessage, SetLfeModeMessage, SetResampleQualityMessage,
+    ApplyStageControlMessage, SetClockModeMessage, SetLfeModeMessage, SetResampleQualityMessage,
 };
 use crate::error::EngineError;
 
@@ -59,6 +59,22 @@ impl EngineHandle {
             .map_err(|error| Self::map_call_error("set_resample_quality", self.timeout, error))?
     }
 
+    /// Sets the decode worker pacing policy.
+    ///
+    /// [`ClockMode::FreeRunning`] renders as fast as the sink accepts audio; pair it with
+    /// sinks that do not play to a device.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EngineError`] when the control actor call fails or the decode
+    /// worker does not acknowledge the change.
+    pub async fn set_clock_mode(&self, mode: ClockMode) -> Result<(), EngineError> {
+        self.actor_ref
+            .call_async(SetClockModeMessage { mode }, self.timeout)
+            .await
+            .map_err(|error| Self::map_call_error("set_clock_mode", self.timeout, error))?
+    }
+
     /// Applies a typed control payload to a transform stage by key.
     ///
     /// The payload type must match what the target stage expects at runtime.
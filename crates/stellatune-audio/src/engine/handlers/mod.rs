@@ -11,6 +11,7 @@ mod pause;
 mod play;
 mod queue_next;
 mod seek;
+mod set_clock_mode;
 mod set_lfe_mode;
 mod set_resample_quality;
 mod shutdown;
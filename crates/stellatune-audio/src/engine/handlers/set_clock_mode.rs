@@ -0,0 +1,19 @@
+use stellatune_runtime::thread_actor::{ActorContext, Handler};
+
+use crate::engine::actor::ControlActor;
+use crate::engine::messages::SetClockModeMessage;
+use crate::error::EngineError;
+
+impl Handler<SetClockModeMessage> for ControlActor {
+    fn handle(
+        &mut self,
+        message: SetClockModeMessage,
+        _ctx: &mut ActorContext<Self>,
+    ) -> Result<(), EngineError> {
+        let timeout = self.config.decode_command_timeout;
+        let worker = self.ensure_worker()?;
+        worker
+            .set_clock_mode(message.mode, timeout)
+            .map_err(EngineError::from)
+    }
+}
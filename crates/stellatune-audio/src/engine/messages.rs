@@ -1,7 +1,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use crate::config::engine::{LfeMode, PauseBehavior, ResampleQuality, StopBehavior};
+use crate::config::engine::{ClockMode, LfeMode, PauseBehavior, ResampleQuality, StopBehavior};
 use crate::error::EngineError;
 use crate::pipeline::assembly::{PipelineMutation, PipelinePlan};
 use crate::workers::decode::{DecodeWorker, DecodeWorkerEvent};
@@ -36,6 +36,9 @@ pub(crate) struct SetLfeModeMessage {
 pub(crate) struct SetResampleQualityMessage {
     pub(crate) quality: ResampleQuality,
 }
+pub(crate) struct SetClockModeMessage {
+    pub(crate) mode: ClockMode,
+}
 pub(crate) struct ApplyStageControlMessage {
     pub(crate) stage_key: String,
     pub(crate) control: Box<dyn Any + Send>,
@@ -90,6 +93,10 @@ impl Message for SetResampleQualityMessage {
     type Response = Result<(), EngineError>;
 }
 
+impl Message for SetClockModeMessage {
+    type Response = Result<(), EngineError>;
+}
+
 impl Message for ApplyStageControlMessage {
     type Response = Result<(), EngineError>;
 }
@@ -46,3 +46,9 @@ pub mod error;
 mod infra;
 pub mod pipeline;
 mod workers;
+
+// Lets tests measure allocations on the realtime hot path.
+#[cfg(test)]
+#[global_allocator]
+static TEST_ALLOCATOR: stellatune_audio_core::pipeline::alloc_guard::CountingAllocator =
+    stellatune_audio_core::pipeline::alloc_guard::CountingAllocator;
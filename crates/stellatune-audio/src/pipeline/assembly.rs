@@ -9,10 +9,14 @@ use std::sync::Arc;
 use crate::config::engine::{LfeMode, ResampleQuality};
 use crate::pipeline::graph::{TransformGraphMutation, TransformGraphStage};
 use crate::pipeline::runtime::dsp::control::SharedMasterGainHotControl;
+use crate::pipeline::runtime::dsp::convolution::ConvolutionStage;
+use crate::pipeline::runtime::dsp::crossfeed::CrossfeedStage;
+use crate::pipeline::runtime::dsp::dither::DitherStage;
 use crate::pipeline::runtime::dsp::gapless_trim::GaplessTrimStage;
 use crate::pipeline::runtime::dsp::master_gain::MasterGainStage;
 use crate::pipeline::runtime::dsp::mixer::MixerStage;
 use crate::pipeline::runtime::dsp::resampler::ResamplerStage;
+use crate::pipeline::runtime::dsp::time_stretch::TimeStretchStage;
 use crate::pipeline::runtime::dsp::transition_gain::TransitionGainStage;
 use crate::pipeline::runtime::runner::PipelineRunner;
 
@@ -60,6 +64,30 @@ impl AssembledDecodePipeline {
         self
     }
 
+    /// Enables or disables the built-in headphone crossfeed stage.
+    pub fn with_crossfeed(mut self, enabled: bool) -> Self {
+        self.builtin_slots.crossfeed = enabled;
+        self
+    }
+
+    /// Enables or disables the built-in convolution stage.
+    pub fn with_convolution(mut self, enabled: bool) -> Self {
+        self.builtin_slots.convolution = enabled;
+        self
+    }
+
+    /// Enables or disables the built-in output quantizer stage.
+    pub fn with_dither(mut self, enabled: bool) -> Self {
+        self.builtin_slots.dither = enabled;
+        self
+    }
+
+    /// Enables or disables the built-in speed and pitch stage.
+    pub fn with_time_stretch(mut self, enabled: bool) -> Self {
+        self.builtin_slots.time_stretch = enabled;
+        self
+    }
+
     /// Replaces the mixer plan.
     pub fn with_mixer(mut self, mixer: Option<MixerPlan>) -> Self {
         self.mixer = mixer;
@@ -98,6 +126,14 @@ pub struct BuiltinTransformSlots {
     pub transition_gain: bool,
     /// Enables master gain stage.
     pub master_gain: bool,
+    /// Enables headphone crossfeed on stereo output.
+    pub crossfeed: bool,
+    /// Enables impulse response convolution.
+    pub convolution: bool,
+    /// Enables the output quantizer for integer sinks. It bypasses itself for float sinks.
+    pub dither: bool,
+    /// Enables playback speed and pitch control.
+    pub time_stretch: bool,
 }
 
 impl Default for BuiltinTransformSlots {
@@ -106,6 +142,10 @@ impl Default for BuiltinTransformSlots {
             gapless_trim: true,
             transition_gain: true,
             master_gain: true,
+            crossfeed: false,
+            convolution: false,
+            dither: true,
+            time_stretch: false,
         }
     }
 }
@@ -120,12 +160,14 @@ pub struct TransformChain {
 }
 
 /// Mixer configuration for channel-layout adaptation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MixerPlan {
     /// Target output channel count.
     pub target_channels: u16,
     /// LFE routing mode.
     pub lfe_mode: LfeMode,
+    /// Channel routing applied on top of layout adaptation.
+    pub mode: MixMode,
 }
 
 impl MixerPlan {
@@ -134,7 +176,103 @@ impl MixerPlan {
         Self {
             target_channels: target_channels.max(1),
             lfe_mode,
+            mode: MixMode::Auto,
+        }
+    }
+
+    /// Replaces the channel routing mode.
+    pub fn with_mode(mut self, mode: MixMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// Channel routing performed by the mixer.
+///
+/// Presets and custom matrices work in their own channel space: the source is first
+/// adapted to the matrix input layout and the matrix output is then adapted to the
+/// target layout, so any mode works with any source and device.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum MixMode {
+    /// Plain layout adaptation from source to target channel count.
+    #[default]
+    Auto,
+    /// Named routing preset.
+    Preset(MixPreset),
+    /// User-supplied gain matrix.
+    Custom(MixGainMatrix),
+}
+
+/// Named mixer routing presets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MixPreset {
+    /// Stereo balance in `-1.0..=1.0`; negative attenuates the right channel.
+    Balance(f32),
+    /// Swaps left and right.
+    SwapLeftRight,
+    /// Sums left and right to identical mono on both channels.
+    MonoSum,
+    /// Mid/side width in `0.0..=2.0`: `0.0` is mono, `1.0` unchanged, `2.0` doubles the
+    /// side signal.
+    StereoWidth(f32),
+    /// Passive Lt/Rt matrix decode of stereo into 5.1: center from the sum, surrounds
+    /// from the difference.
+    MatrixSurroundUpmix,
+    /// Stereo to 5.1 keeping the fronts untouched and sending the out-of-phase ambience
+    /// to the surrounds with opposite polarity.
+    AmbienceUpmix,
+}
+
+/// Largest channel count accepted on either side of a [`MixGainMatrix`].
+pub const MAX_MIX_MATRIX_CHANNELS: usize = 32;
+
+/// User-supplied gain matrix; `rows()[out][in]` is the gain from input channel `in`
+/// to output channel `out`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixGainMatrix {
+    rows: Vec<Vec<f32>>,
+}
+
+impl MixGainMatrix {
+    /// Validates a non-empty rectangular matrix of finite gains with at most
+    /// [`MAX_MIX_MATRIX_CHANNELS`] channels per side.
+    pub fn new(rows: Vec<Vec<f32>>) -> Result<Self, PipelineError> {
+        let inputs = rows.first().map(Vec::len).unwrap_or(0);
+        if rows.is_empty() || inputs == 0 {
+            return Err(PipelineError::StageFailure(
+                "mix matrix must have at least one row and column".to_string(),
+            ));
+        }
+        if rows.len() > MAX_MIX_MATRIX_CHANNELS || inputs > MAX_MIX_MATRIX_CHANNELS {
+            return Err(PipelineError::StageFailure(format!(
+                "mix matrix exceeds {MAX_MIX_MATRIX_CHANNELS} channels: {}x{inputs}",
+                rows.len()
+            )));
         }
+        if let Some(index) = rows.iter().position(|row| row.len() != inputs) {
+            return Err(PipelineError::StageFailure(format!(
+                "mix matrix row {index} has {} gains, expected {inputs}",
+                rows[index].len()
+            )));
+        }
+        if rows.iter().flatten().any(|gain| !gain.is_finite()) {
+            return Err(PipelineError::StageFailure(
+                "mix matrix gains must be finite".to_string(),
+            ));
+        }
+        Ok(Self { rows })
+    }
+
+    pub fn rows(&self) -> &[Vec<f32>] {
+        &self.rows
+    }
+
+    pub fn input_channels(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    pub fn output_channels(&self) -> usize {
+        self.rows.len()
     }
 }
 
@@ -238,6 +376,32 @@ impl AssembledPipeline {
         self,
         master_gain_hot_control: Option<SharedMasterGainHotControl>,
     ) -> Result<PipelineRunner, PipelineError> {
+        let stages = self.decode.into_stages(master_gain_hot_control);
+        PipelineRunner::new(
+            stages.source,
+            stages.decoder,
+            stages.transforms,
+            self.sink_plan,
+            stages.builtin_slots.transition_gain,
+            stages.builtin_slots.gapless_trim,
+        )
+    }
+}
+
+/// Decode-side stages flattened into execution order.
+pub(crate) struct DecodeStages {
+    pub(crate) source: Box<dyn SourceStage>,
+    pub(crate) decoder: Box<dyn DecoderStage>,
+    pub(crate) transforms: Vec<Box<dyn TransformStage>>,
+    pub(crate) builtin_slots: BuiltinTransformSlots,
+}
+
+impl AssembledDecodePipeline {
+    /// Materializes built-in stages and orders every transform the way playback runs them.
+    pub(crate) fn into_stages(
+        self,
+        master_gain_hot_control: Option<SharedMasterGainHotControl>,
+    ) -> DecodeStages {
         let AssembledDecodePipeline {
             source,
             decoder,
@@ -246,7 +410,7 @@ impl AssembledPipeline {
             mixer,
             resampler,
             builtin_slots,
-        } = self.decode;
+        } = self;
         let mut final_transforms: Vec<Box<dyn TransformStage>> = Vec::new();
         if builtin_slots.gapless_trim {
             final_transforms.push(Box::new(GaplessTrimStage::new()));
@@ -260,6 +424,15 @@ impl AssembledPipeline {
         }
         final_transforms.append(&mut transforms);
         final_transforms.append(&mut transform_chain.post_mix);
+        if builtin_slots.time_stretch {
+            final_transforms.push(Box::new(TimeStretchStage::new()));
+        }
+        if builtin_slots.crossfeed {
+            final_transforms.push(Box::new(CrossfeedStage::default()));
+        }
+        if builtin_slots.convolution {
+            final_transforms.push(Box::new(ConvolutionStage::new()));
+        }
         if builtin_slots.transition_gain {
             final_transforms.push(Box::new(TransitionGainStage::new()));
         }
@@ -270,14 +443,15 @@ impl AssembledPipeline {
             };
             final_transforms.push(Box::new(stage));
         }
-        PipelineRunner::new(
+        if builtin_slots.dither {
+            final_transforms.push(Box::new(DitherStage::default()));
+        }
+        DecodeStages {
             source,
             decoder,
-            final_transforms,
-            self.sink_plan,
-            builtin_slots.transition_gain,
-            builtin_slots.gapless_trim,
-        )
+            transforms: final_transforms,
+            builtin_slots,
+        }
     }
 }
 
@@ -344,6 +518,14 @@ pub enum BuiltinTransformSlot {
     TransitionGain,
     /// Master gain slot.
     MasterGain,
+    /// Headphone crossfeed slot.
+    Crossfeed,
+    /// Impulse response convolution slot.
+    Convolution,
+    /// Output quantizer slot.
+    Dither,
+    /// Playback speed and pitch slot.
+    TimeStretch,
 }
 
 /// Runtime mutation operations supported by pipeline runtimes.
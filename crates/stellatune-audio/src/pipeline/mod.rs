@@ -7,4 +7,6 @@
 pub mod assembly;
 /// Transform graph model and mutation primitives.
 pub mod graph;
+/// Engine-independent synchronous pipeline rendering.
+pub mod offline;
 pub(crate) mod runtime;
@@ -0,0 +1,310 @@
+//! Synchronous rendering of assembled pipelines outside the playback engine.
+//!
+//! [`OfflineRenderer`] drives one [`AssembledPipeline`] to completion on the calling
+//! thread. There is no control actor, sink worker or output clock: every decoded block
+//! runs through the same transform ordering playback uses and is written straight into
+//! the sinks, so rendering is only bounded by CPU and disk. This is the building block
+//! for export and transcode jobs that must not disturb the live engine.
+
+use std::any::Any;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use stellatune_audio_core::pipeline::context::{AudioBlock, InputRef, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::pool::AudioBlockPool;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::decoder::DecoderStage;
+use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+use stellatune_audio_core::pipeline::stages::source::SourceStage;
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+use crate::pipeline::assembly::AssembledPipeline;
+use crate::pipeline::runtime::dsp::chain_source_frames;
+use crate::pipeline::runtime::dsp::control::{GAPLESS_TRIM_STAGE_KEY, GaplessTrimControl};
+
+const MAX_DRAIN_TAIL_ITERATIONS: usize = 32;
+/// One block is in flight at a time; the spare covers the drain loop.
+const BLOCK_POOL_BUFFERS: usize = 2;
+
+/// Progress snapshot reported after every block written to the sinks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OfflineRenderProgress {
+    /// Stream spec delivered to the sinks.
+    pub output_spec: StreamSpec,
+    /// Frames written so far, in the output sample-rate domain.
+    pub rendered_frames: u64,
+    /// Source position reached, in milliseconds; it runs ahead of the rendered frames
+    /// while playback speed is above 1.
+    pub position_ms: i64,
+}
+
+/// Terminal state of an offline render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OfflineRenderOutcome {
+    /// The decoder reached end-of-stream and all tails were flushed.
+    Completed {
+        /// Total frames written to the sinks.
+        rendered_frames: u64,
+    },
+    /// The cancel flag was raised before the decoder finished.
+    Cancelled {
+        /// Frames written before cancellation was observed.
+        rendered_frames: u64,
+    },
+}
+
+/// Single-use renderer for one assembled pipeline.
+pub struct OfflineRenderer {
+    source: Box<dyn SourceStage>,
+    decoder: Box<dyn DecoderStage>,
+    transforms: Vec<Box<dyn TransformStage>>,
+    sinks: Vec<Box<dyn SinkStage>>,
+    controls: Vec<Box<dyn Any + Send>>,
+    blocks: AudioBlockPool,
+    supports_gapless_trim: bool,
+    rendered_frames: u64,
+}
+
+impl OfflineRenderer {
+    /// Flattens the pipeline into stages.
+    ///
+    /// Built-in transition and master gain slots are honoured as assembled; offline
+    /// callers usually disable them since nothing drives their controls.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipelineError`] when the sink plan cannot produce sink stages.
+    pub fn new(pipeline: AssembledPipeline) -> Result<Self, PipelineError> {
+        let stages = pipeline.decode.into_stages(None);
+        let sinks = pipeline.sink_plan.into_sinks()?;
+        Ok(Self {
+            source: stages.source,
+            decoder: stages.decoder,
+            transforms: stages.transforms,
+            sinks,
+            controls: Vec::new(),
+            blocks: AudioBlockPool::new(BLOCK_POOL_BUFFERS),
+            supports_gapless_trim: stages.builtin_slots.gapless_trim,
+            rendered_frames: 0,
+        })
+    }
+
+    /// Queues a typed control, such as a crossfeed or dither config, for the built-in
+    /// stages.
+    ///
+    /// Controls are offered to every transform once the chain is prepared; rendering
+    /// fails when no transform accepts one.
+    pub fn with_control<T: Any + Send>(mut self, control: T) -> Self {
+        self.controls.push(Box::new(control));
+        self
+    }
+
+    /// Renders `input` until end-of-stream or until `cancel` is raised.
+    ///
+    /// All stages are stopped before returning, including on error and cancellation,
+    /// so sinks always get the chance to finalize their output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipelineError`] when any stage fails to prepare, reports a fatal
+    /// status, or fails to flush.
+    pub fn render(
+        mut self,
+        input: &InputRef,
+        cancel: &AtomicBool,
+        mut on_progress: impl FnMut(OfflineRenderProgress),
+    ) -> Result<OfflineRenderOutcome, PipelineError> {
+        let mut ctx = PipelineContext::default();
+        let result = self.run(input, cancel, &mut on_progress, &mut ctx);
+        self.stop(&mut ctx);
+        result
+    }
+
+    fn run(
+        &mut self,
+        input: &InputRef,
+        cancel: &AtomicBool,
+        on_progress: &mut impl FnMut(OfflineRenderProgress),
+        ctx: &mut PipelineContext,
+    ) -> Result<OfflineRenderOutcome, PipelineError> {
+        let output_spec = self.prepare(input, ctx)?;
+        loop {
+            if cancel.load(Ordering::Acquire) {
+                return Ok(OfflineRenderOutcome::Cancelled {
+                    rendered_frames: self.rendered_frames,
+                });
+            }
+            self.sync_runtime_control(ctx)?;
+
+            let mut block = self.blocks.acquire(output_spec.channels);
+            match self.decoder.next_block(&mut block, ctx) {
+                StageStatus::Ok => {},
+                StageStatus::Eof => break,
+                StageStatus::Fatal => {
+                    let detail = self
+                        .decoder
+                        .runtime_error_detail()
+                        .unwrap_or("decoder returned fatal status");
+                    return Err(PipelineError::StageFailure(format!(
+                        "decoder fatal: {detail}"
+                    )));
+                },
+            }
+            if block.is_empty() {
+                self.blocks.recycle(block);
+                continue;
+            }
+            if self.process(&mut block, ctx)? == StageStatus::Eof {
+                break;
+            }
+            self.write(&block, output_spec, ctx, on_progress)?;
+            self.blocks.recycle(block);
+        }
+
+        self.decoder.flush(ctx)?;
+        for transform in &mut self.transforms {
+            transform.flush(ctx)?;
+        }
+        self.drain_transform_tail(output_spec, ctx, on_progress)?;
+        for sink in &mut self.sinks {
+            sink.flush(ctx)?;
+        }
+        Ok(OfflineRenderOutcome::Completed {
+            rendered_frames: self.rendered_frames,
+        })
+    }
+
+    fn prepare(
+        &mut self,
+        input: &InputRef,
+        ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        let source_handle = self.source.prepare(input, ctx)?;
+        let mut spec = self.decoder.prepare(&source_handle, ctx)?.validate()?;
+        for transform in &mut self.transforms {
+            spec = transform.prepare(spec, ctx)?.validate()?;
+        }
+        if self.supports_gapless_trim {
+            let control = GaplessTrimControl::new(self.decoder.current_gapless_trim_spec(), 0);
+            if let Some(stage) = self
+                .transforms
+                .iter_mut()
+                .find(|stage| stage.stage_key() == Some(GAPLESS_TRIM_STAGE_KEY))
+            {
+                stage.apply_control(&control, ctx)?;
+            }
+        }
+        for control in &self.controls {
+            let mut handled = false;
+            for transform in &mut self.transforms {
+                handled |= transform.apply_control(control.as_ref(), ctx)?;
+            }
+            if !handled {
+                return Err(PipelineError::StageFailure(
+                    "no transform accepted offline render control".to_string(),
+                ));
+            }
+        }
+        for sink in &mut self.sinks {
+            sink.prepare(spec, ctx)?;
+        }
+        Ok(spec)
+    }
+
+    fn sync_runtime_control(&mut self, ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        self.source.sync_runtime_control(ctx)?;
+        self.decoder.sync_runtime_control(ctx)?;
+        for transform in &mut self.transforms {
+            transform.sync_runtime_control(ctx)?;
+        }
+        for sink in &mut self.sinks {
+            sink.sync_runtime_control(ctx)?;
+        }
+        Ok(())
+    }
+
+    fn process(
+        &mut self,
+        block: &mut AudioBlock,
+        ctx: &mut PipelineContext,
+    ) -> Result<StageStatus, PipelineError> {
+        for transform in &mut self.transforms {
+            match transform.process(block, ctx) {
+                StageStatus::Ok => {},
+                StageStatus::Eof => return Ok(StageStatus::Eof),
+                StageStatus::Fatal => {
+                    return Err(PipelineError::StageFailure("transform fatal".to_string()));
+                },
+            }
+        }
+        Ok(StageStatus::Ok)
+    }
+
+    fn write(
+        &mut self,
+        block: &AudioBlock,
+        output_spec: StreamSpec,
+        ctx: &mut PipelineContext,
+        on_progress: &mut impl FnMut(OfflineRenderProgress),
+    ) -> Result<(), PipelineError> {
+        if block.is_empty() {
+            return Ok(());
+        }
+        for sink in &mut self.sinks {
+            match sink.write(block, ctx) {
+                StageStatus::Ok => {},
+                StageStatus::Eof => {
+                    return Err(PipelineError::StageFailure("sink reached eof".to_string()));
+                },
+                StageStatus::Fatal => {
+                    return Err(PipelineError::StageFailure("sink fatal".to_string()));
+                },
+            }
+        }
+        let frames = block.frames() as u64;
+        self.rendered_frames = self.rendered_frames.saturating_add(frames);
+        ctx.advance_frames(
+            chain_source_frames(&self.transforms, frames),
+            output_spec.sample_rate,
+        );
+        on_progress(OfflineRenderProgress {
+            output_spec,
+            rendered_frames: self.rendered_frames,
+            position_ms: ctx.position_ms,
+        });
+        Ok(())
+    }
+
+    /// Pushes empty blocks through the transforms to collect audio they still hold.
+    fn drain_transform_tail(
+        &mut self,
+        output_spec: StreamSpec,
+        ctx: &mut PipelineContext,
+        on_progress: &mut impl FnMut(OfflineRenderProgress),
+    ) -> Result<(), PipelineError> {
+        for _ in 0..MAX_DRAIN_TAIL_ITERATIONS {
+            let mut block = self.blocks.acquire(output_spec.channels);
+            if self.process(&mut block, ctx)? == StageStatus::Eof || block.is_empty() {
+                break;
+            }
+            self.write(&block, output_spec, ctx, on_progress)?;
+            self.blocks.recycle(block);
+        }
+        Ok(())
+    }
+
+    fn stop(&mut self, ctx: &mut PipelineContext) {
+        for sink in &mut self.sinks {
+            sink.stop(ctx);
+        }
+        for transform in &mut self.transforms {
+            transform.stop(ctx);
+        }
+        self.decoder.stop(ctx);
+        self.source.stop(ctx);
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/pipeline/offline.rs"]
+mod tests;
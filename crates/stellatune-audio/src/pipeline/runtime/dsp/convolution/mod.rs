@@ -0,0 +1,5 @@
+mod partitioned;
+mod stage;
+pub(crate) mod wav;
+
+pub(crate) use stage::ConvolutionStage;
@@ -0,0 +1,372 @@
+//! Uniformly partitioned overlap-save convolution.
+//!
+//! Filters are cut into [`PARTITION_FRAMES`]-long partitions whose spectra are
+//! multiplied against a frequency-domain delay line of recent input partitions, so the
+//! cost per frame grows with the filter length only through cheap complex
+//! multiply-adds. Output trails input by exactly one partition.
+
+use std::fmt;
+use std::sync::Arc;
+
+use realfft::num_complex::Complex;
+use realfft::{ComplexToReal, FftError, RealFftPlanner, RealToComplex};
+use stellatune_audio_core::pipeline::error::PipelineError;
+
+use crate::config::convolution::ImpulseLayout;
+
+/// Partition length, and therefore the latency of the convolution stage, in frames.
+pub(crate) const PARTITION_FRAMES: usize = 512;
+const FFT_LEN: usize = PARTITION_FRAMES * 2;
+const BINS: usize = PARTITION_FRAMES + 1;
+
+type Spectrum = Vec<Complex<f32>>;
+
+/// Forward and inverse transforms shared by kernels and the convolver.
+#[derive(Clone)]
+pub(crate) struct PartitionFft {
+    forward: Arc<dyn RealToComplex<f32>>,
+    inverse: Arc<dyn ComplexToReal<f32>>,
+}
+
+impl fmt::Debug for PartitionFft {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartitionFft")
+            .field("len", &FFT_LEN)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartitionFft {
+    pub(crate) fn new() -> Self {
+        let mut planner = RealFftPlanner::<f32>::new();
+        Self {
+            forward: planner.plan_fft_forward(FFT_LEN),
+            inverse: planner.plan_fft_inverse(FFT_LEN),
+        }
+    }
+
+    fn scratch_len(&self) -> usize {
+        self.forward
+            .get_scratch_len()
+            .max(self.inverse.get_scratch_len())
+    }
+}
+
+/// Connects one input channel through one filter into one output channel.
+#[derive(Debug, Clone, Copy)]
+struct Route {
+    input: usize,
+    output: usize,
+    filter: usize,
+}
+
+/// Partitioned filter spectra plus the routing between stream channels.
+#[derive(Debug, Clone)]
+pub(crate) struct ConvolutionKernel {
+    /// `filters[filter][partition]`, pre-scaled for the inverse transform.
+    filters: Vec<Vec<Spectrum>>,
+    routes: Vec<Route>,
+    partitions: usize,
+}
+
+impl ConvolutionKernel {
+    /// Kernel that passes every channel through unchanged.
+    pub(crate) fn identity(channels: usize) -> Self {
+        let unit = vec![vec![Complex::new(1.0 / FFT_LEN as f32, 0.0); BINS]];
+        Self {
+            filters: vec![unit],
+            routes: (0..channels)
+                .map(|channel| Route {
+                    input: channel,
+                    output: channel,
+                    filter: 0,
+                })
+                .collect(),
+            partitions: 1,
+        }
+    }
+
+    /// Builds a kernel from per-channel filter taps that already match the stream rate.
+    pub(crate) fn from_filters(
+        filters: &[Vec<f32>],
+        layout: ImpulseLayout,
+        channels: usize,
+        fft: &PartitionFft,
+    ) -> Result<Self, PipelineError> {
+        let routes: Vec<Route> = match layout {
+            ImpulseLayout::PerChannel if filters.len() == 1 => (0..channels)
+                .map(|channel| Route {
+                    input: channel,
+                    output: channel,
+                    filter: 0,
+                })
+                .collect(),
+            ImpulseLayout::PerChannel if filters.len() == channels => (0..channels)
+                .map(|channel| Route {
+                    input: channel,
+                    output: channel,
+                    filter: channel,
+                })
+                .collect(),
+            ImpulseLayout::TrueStereo if channels == 2 && filters.len() == 4 => {
+                [(0, 0, 0), (0, 1, 1), (1, 0, 2), (1, 1, 3)]
+                    .into_iter()
+                    .map(|(input, output, filter)| Route {
+                        input,
+                        output,
+                        filter,
+                    })
+                    .collect()
+            },
+            _ => {
+                return Err(PipelineError::StageFailure(format!(
+                    "{layout:?} impulse response with {} filters does not fit {channels} channels",
+                    filters.len()
+                )));
+            },
+        };
+
+        let mut time = fft.forward.make_input_vec();
+        let mut scratch = fft.forward.make_scratch_vec();
+        let scale = 1.0 / FFT_LEN as f32;
+        let mut spectra = Vec::with_capacity(filters.len());
+        for taps in filters {
+            let mut partitions = Vec::with_capacity(taps.len().div_ceil(PARTITION_FRAMES));
+            for chunk in taps.chunks(PARTITION_FRAMES) {
+                time.fill(0.0);
+                for (slot, tap) in time.iter_mut().zip(chunk) {
+                    *slot = tap * scale;
+                }
+                let mut spectrum = fft.forward.make_output_vec();
+                fft.forward
+                    .process_with_scratch(&mut time, &mut spectrum, &mut scratch)
+                    .map_err(|error| {
+                        PipelineError::StageFailure(format!("impulse fft failed: {error}"))
+                    })?;
+                partitions.push(spectrum);
+            }
+            spectra.push(partitions);
+        }
+        let partitions = spectra.iter().map(Vec::len).max().unwrap_or(1).max(1);
+        Ok(Self {
+            filters: spectra,
+            routes,
+            partitions,
+        })
+    }
+
+    /// Frames of output a single input frame keeps producing after it enters.
+    pub(crate) fn tail_frames(&self) -> usize {
+        self.partitions * PARTITION_FRAMES
+    }
+}
+
+/// Streaming convolver for interleaved audio, with crossfaded kernel swaps.
+#[derive(Debug)]
+pub(crate) struct PartitionedConvolver {
+    fft: PartitionFft,
+    channels: usize,
+    kernel: ConvolutionKernel,
+    incoming: Option<ConvolutionKernel>,
+    crossfade_frames: usize,
+    crossfade_done: usize,
+    /// Per-channel `[previous | current]` input partitions.
+    input: Vec<Vec<f32>>,
+    /// Per-channel output of the last completed partition, played while the next fills.
+    output: Vec<Vec<f32>>,
+    /// Output of `incoming` during a crossfade.
+    incoming_output: Vec<Vec<f32>>,
+    /// Frames of the current partition already exchanged.
+    fill: usize,
+    /// Frequency-domain delay line: recent input partition spectra per input channel.
+    history: Vec<Vec<Spectrum>>,
+    /// Slot the next input spectrum is written to.
+    head: usize,
+    time: Vec<f32>,
+    accum: Spectrum,
+    scratch: Spectrum,
+    /// Whether audio went through since the last reset; swaps before that need no fade.
+    primed: bool,
+}
+
+impl PartitionedConvolver {
+    pub(crate) fn new(fft: PartitionFft, channels: usize, kernel: ConvolutionKernel) -> Self {
+        let scratch = vec![Complex::default(); fft.scratch_len()];
+        let mut convolver = Self {
+            fft,
+            channels,
+            kernel,
+            incoming: None,
+            crossfade_frames: 0,
+            crossfade_done: 0,
+            input: vec![vec![0.0; FFT_LEN]; channels],
+            output: vec![vec![0.0; PARTITION_FRAMES]; channels],
+            incoming_output: vec![vec![0.0; PARTITION_FRAMES]; channels],
+            fill: 0,
+            history: vec![Vec::new(); channels],
+            head: 0,
+            time: vec![0.0; FFT_LEN],
+            accum: vec![Complex::default(); BINS],
+            scratch,
+            primed: false,
+        };
+        convolver.ensure_history_depth(convolver.kernel.partitions);
+        convolver
+    }
+
+    pub(crate) fn kernel(&self) -> &ConvolutionKernel {
+        self.incoming.as_ref().unwrap_or(&self.kernel)
+    }
+
+    /// Clears all signal state. A pending crossfade completes immediately.
+    pub(crate) fn reset(&mut self) {
+        if let Some(kernel) = self.incoming.take() {
+            self.kernel = kernel;
+        }
+        for buffer in self
+            .input
+            .iter_mut()
+            .chain(&mut self.output)
+            .chain(&mut self.incoming_output)
+        {
+            buffer.fill(0.0);
+        }
+        for spectrum in self.history.iter_mut().flatten() {
+            spectrum.fill(Complex::default());
+        }
+        self.fill = 0;
+        self.head = 0;
+        self.primed = false;
+    }
+
+    /// Replaces the kernel, crossfading over `crossfade_frames` if audio is flowing.
+    pub(crate) fn set_kernel(&mut self, kernel: ConvolutionKernel, crossfade_frames: usize) {
+        self.ensure_history_depth(kernel.partitions);
+        if !self.primed || crossfade_frames == 0 {
+            self.kernel = kernel;
+            self.incoming = None;
+            return;
+        }
+        self.incoming = Some(kernel);
+        self.crossfade_frames = crossfade_frames;
+        self.crossfade_done = 0;
+    }
+
+    /// Grows the delay line to `depth` partitions, keeping the spectra already in it.
+    fn ensure_history_depth(&mut self, depth: usize) {
+        let current = self.history.first().map(Vec::len).unwrap_or(0);
+        if current >= depth {
+            return;
+        }
+        for channel in &mut self.history {
+            // Oldest first, so the newest spectrum ends up in the last slot.
+            let len = channel.len();
+            channel.rotate_left(self.head.min(len));
+            let missing = depth - channel.len();
+            channel.splice(0..0, (0..missing).map(|_| vec![Complex::default(); BINS]));
+        }
+        self.head = 0;
+    }
+
+    /// Convolves interleaved samples in place; output trails input by
+    /// [`PARTITION_FRAMES`].
+    pub(crate) fn process(&mut self, samples: &mut [f32]) -> Result<(), FftError> {
+        if self.channels == 0 {
+            return Ok(());
+        }
+        self.primed = true;
+        for frame in samples.chunks_exact_mut(self.channels) {
+            for (channel, sample) in frame.iter_mut().enumerate() {
+                self.input[channel][PARTITION_FRAMES + self.fill] = *sample;
+                *sample = self.output[channel][self.fill];
+            }
+            self.fill += 1;
+            if self.fill == PARTITION_FRAMES {
+                self.fill = 0;
+                self.run_partition()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn run_partition(&mut self) -> Result<(), FftError> {
+        for (input, history) in self.input.iter_mut().zip(&mut self.history) {
+            self.time.copy_from_slice(input);
+            self.fft.forward.process_with_scratch(
+                &mut self.time,
+                &mut history[self.head],
+                &mut self.scratch,
+            )?;
+            input.copy_within(PARTITION_FRAMES.., 0);
+        }
+
+        let mut render = PartitionRender {
+            fft: &self.fft,
+            history: &self.history,
+            head: self.head,
+            time: &mut self.time,
+            accum: &mut self.accum,
+            scratch: &mut self.scratch,
+        };
+        render.render(&self.kernel, &mut self.output)?;
+        if let Some(incoming) = self.incoming.as_ref() {
+            render.render(incoming, &mut self.incoming_output)?;
+            let total = self.crossfade_frames.max(1) as f32;
+            for (output, incoming) in self.output.iter_mut().zip(&self.incoming_output) {
+                for (index, (old, new)) in output.iter_mut().zip(incoming).enumerate() {
+                    let t = ((self.crossfade_done + index) as f32 / total).min(1.0);
+                    *old += (new - *old) * t;
+                }
+            }
+            self.crossfade_done += PARTITION_FRAMES;
+        }
+        if self.crossfade_done >= self.crossfade_frames
+            && let Some(kernel) = self.incoming.take()
+        {
+            self.kernel = kernel;
+        }
+
+        self.head = (self.head + 1) % self.history.first().map(Vec::len).unwrap_or(1).max(1);
+        Ok(())
+    }
+}
+
+/// Borrowed buffers needed to turn the delay line into one partition of output.
+struct PartitionRender<'a> {
+    fft: &'a PartitionFft,
+    history: &'a [Vec<Spectrum>],
+    head: usize,
+    time: &'a mut Vec<f32>,
+    accum: &'a mut Spectrum,
+    scratch: &'a mut Spectrum,
+}
+
+impl PartitionRender<'_> {
+    fn render(
+        &mut self,
+        kernel: &ConvolutionKernel,
+        outputs: &mut [Vec<f32>],
+    ) -> Result<(), FftError> {
+        let depth = self.history.first().map(Vec::len).unwrap_or(0);
+        for (channel, output) in outputs.iter_mut().enumerate() {
+            self.accum.fill(Complex::default());
+            for route in kernel.routes.iter().filter(|route| route.output == channel) {
+                let history = &self.history[route.input];
+                for (age, partition) in kernel.filters[route.filter].iter().enumerate() {
+                    let slot = &history[(self.head + depth - age) % depth];
+                    for ((acc, x), h) in self.accum.iter_mut().zip(slot).zip(partition) {
+                        *acc += x * h;
+                    }
+                }
+            }
+            // Real signals have purely real DC and Nyquist bins; clear rounding residue.
+            self.accum[0].im = 0.0;
+            self.accum[BINS - 1].im = 0.0;
+            self.fft
+                .inverse
+                .process_with_scratch(self.accum, self.time, self.scratch)?;
+            output.copy_from_slice(&self.time[PARTITION_FRAMES..]);
+        }
+        Ok(())
+    }
+}
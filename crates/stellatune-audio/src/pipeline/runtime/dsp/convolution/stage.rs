@@ -0,0 +1,268 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use audioadapter_buffers::direct::InterleavedSlice;
+use rubato::{
+    Async, FixedAsync, Indexing, Resampler, SincInterpolationParameters, SincInterpolationType,
+    WindowFunction,
+};
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+use tracing::warn;
+
+use crate::config::convolution::{CONVOLUTION_STAGE_KEY, ConvolutionControl, ImpulseResponse};
+use crate::pipeline::runtime::dsp::convolution::partitioned::{
+    ConvolutionKernel, PARTITION_FRAMES, PartitionFft, PartitionedConvolver,
+};
+
+const IR_RESAMPLE_CHUNK_FRAMES: usize = 1024;
+/// Tail frames emitted per drain step after a flush.
+const DRAIN_BLOCK_FRAMES: usize = PARTITION_FRAMES * 8;
+
+/// Resamples one filter to `target_rate`, keeping its gain.
+///
+/// A filter sampled at a higher rate has proportionally more taps per unit of time, so
+/// the taps are scaled by the rate ratio to leave the frequency response level intact.
+fn resample_filter(
+    taps: &[f32],
+    source_rate: u32,
+    target_rate: u32,
+) -> Result<Vec<f32>, PipelineError> {
+    let ratio = f64::from(target_rate) / f64::from(source_rate);
+    let params = SincInterpolationParameters {
+        sinc_len: 256,
+        f_cutoff: 0.95,
+        oversampling_factor: 128,
+        interpolation: SincInterpolationType::Linear,
+        window: WindowFunction::BlackmanHarris2,
+    };
+    let mut resampler = Async::<f32>::new_sinc(
+        ratio,
+        1.0,
+        &params,
+        IR_RESAMPLE_CHUNK_FRAMES,
+        1,
+        FixedAsync::Input,
+    )
+    .map_err(|e| PipelineError::StageFailure(format!("failed to create ir resampler: {e}")))?;
+
+    // Driven chunk by chunk rather than through `process_all_into_buffer`, which leaves
+    // the resampler delay in place for filters shorter than one chunk.
+    let delay = resampler.output_delay();
+    let expected = (taps.len() as f64 * ratio).ceil() as usize;
+    let mut output = vec![0.0; delay + expected + resampler.output_frames_max()];
+    let input = InterleavedSlice::new(taps, 1, taps.len())
+        .map_err(|e| PipelineError::StageFailure(format!("ir resample input error: {e}")))?;
+    let output_frames = output.len();
+    let mut out = InterleavedSlice::new_mut(&mut output, 1, output_frames)
+        .map_err(|e| PipelineError::StageFailure(format!("ir resample output error: {e}")))?;
+    let mut indexing = Indexing {
+        input_offset: 0,
+        output_offset: 0,
+        partial_len: None,
+        active_channels_mask: None,
+    };
+    while indexing.output_offset < delay + expected {
+        let remaining = taps.len() - indexing.input_offset;
+        indexing.partial_len = (remaining < resampler.input_frames_next()).then_some(remaining);
+        let (consumed, written) = resampler
+            .process_into_buffer(&input, &mut out, Some(&indexing))
+            .map_err(|e| PipelineError::StageFailure(format!("ir resample error: {e}")))?;
+        indexing.input_offset = (indexing.input_offset + consumed).min(taps.len());
+        indexing.output_offset += written;
+    }
+    output.truncate(delay + expected);
+    output.drain(..delay);
+    let gain = (1.0 / ratio) as f32;
+    for tap in &mut output {
+        *tap *= gain;
+    }
+    Ok(output)
+}
+
+/// FIR convolution with user impulse responses (room correction, headphone IRs).
+///
+/// The stage adds [`PARTITION_FRAMES`] of latency, which it reports so the runner can keep
+/// the playback position aligned. Without a loaded filter it only delays the stream.
+#[derive(Debug)]
+pub(crate) struct ConvolutionStage {
+    fft: PartitionFft,
+    /// Filter the running kernel was built from at the prepared rate, or tried to be
+    /// when it does not fit the stream.
+    impulse: Option<Arc<ImpulseResponse>>,
+    sample_rate: u32,
+    channels: usize,
+    convolver: Option<PartitionedConvolver>,
+    /// Frames still owed to the sink after a flush: the latency plus the filter tail.
+    drain_remaining: usize,
+}
+
+impl Default for ConvolutionStage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConvolutionStage {
+    pub(crate) fn new() -> Self {
+        Self {
+            fft: PartitionFft::new(),
+            impulse: None,
+            sample_rate: 0,
+            channels: 0,
+            convolver: None,
+            drain_remaining: 0,
+        }
+    }
+
+    /// Builds the kernel for `impulse` at the prepared rate and channel count.
+    fn build_kernel(
+        &self,
+        impulse: Option<&ImpulseResponse>,
+    ) -> Result<ConvolutionKernel, PipelineError> {
+        let Some(impulse) = impulse else {
+            return Ok(ConvolutionKernel::identity(self.channels));
+        };
+        if impulse.sample_rate() == self.sample_rate {
+            return ConvolutionKernel::from_filters(
+                impulse.filters(),
+                impulse.layout(),
+                self.channels,
+                &self.fft,
+            );
+        }
+        let filters = impulse
+            .filters()
+            .iter()
+            .map(|taps| resample_filter(taps, impulse.sample_rate(), self.sample_rate))
+            .collect::<Result<Vec<_>, _>>()?;
+        ConvolutionKernel::from_filters(&filters, impulse.layout(), self.channels, &self.fft)
+    }
+
+    fn is_current(&self, impulse: Option<&Arc<ImpulseResponse>>) -> bool {
+        match (&self.impulse, impulse) {
+            (Some(current), Some(impulse)) => Arc::ptr_eq(current, impulse),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl TransformStage for ConvolutionStage {
+    fn stage_key(&self) -> Option<&str> {
+        Some(CONVOLUTION_STAGE_KEY)
+    }
+
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
+    fn latency_frames(&self) -> u64 {
+        if self.convolver.is_some() {
+            PARTITION_FRAMES as u64
+        } else {
+            0
+        }
+    }
+
+    fn apply_control(
+        &mut self,
+        control: &dyn Any,
+        _ctx: &mut PipelineContext,
+    ) -> Result<bool, PipelineError> {
+        let Some(control) = control.downcast_ref::<ConvolutionControl>() else {
+            return Ok(false);
+        };
+        if self.convolver.is_none() {
+            self.impulse = control.impulse.clone();
+            return Ok(true);
+        }
+        // Replays after a rebuild hand back the filter `prepare` already loaded.
+        if self.is_current(control.impulse.as_ref()) {
+            return Ok(true);
+        }
+        let kernel = self.build_kernel(control.impulse.as_deref())?;
+        let crossfade_frames =
+            (u64::from(self.sample_rate) * u64::from(control.crossfade_ms) / 1000) as usize;
+        if let Some(convolver) = self.convolver.as_mut() {
+            convolver.set_kernel(kernel, crossfade_frames);
+        }
+        self.impulse = control.impulse.clone();
+        Ok(true)
+    }
+
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        self.sample_rate = spec.sample_rate.max(1);
+        self.channels = usize::from(spec.channels.max(1));
+        self.drain_remaining = 0;
+        let kernel = self
+            .build_kernel(self.impulse.as_deref())
+            .unwrap_or_else(|error| {
+                warn!(%error, "convolution filter does not fit the stream, passing through");
+                ConvolutionKernel::identity(self.channels)
+            });
+        self.convolver = Some(PartitionedConvolver::new(
+            self.fft.clone(),
+            self.channels,
+            kernel,
+        ));
+        Ok(spec)
+    }
+
+    fn sync_runtime_control(&mut self, ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        if ctx.pending_seek_ms.is_some() {
+            self.drain_remaining = 0;
+            if let Some(convolver) = self.convolver.as_mut() {
+                convolver.reset();
+            }
+        }
+        Ok(())
+    }
+
+    fn process(&mut self, block: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        let Some(convolver) = self.convolver.as_mut() else {
+            return StageStatus::Ok;
+        };
+        if block.is_empty() {
+            if self.drain_remaining == 0 {
+                return StageStatus::Ok;
+            }
+            // Push silence through to release the delayed audio and the filter tail.
+            let frames = self.drain_remaining.min(DRAIN_BLOCK_FRAMES);
+            self.drain_remaining -= frames;
+            block.channels = self.channels as u16;
+            block.samples.resize(frames * self.channels, 0.0);
+        }
+        if usize::from(block.channels) != self.channels {
+            return StageStatus::Ok;
+        }
+        match convolver.process(&mut block.samples) {
+            Ok(()) => StageStatus::Ok,
+            Err(_) => StageStatus::Fatal,
+        }
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        if let Some(convolver) = self.convolver.as_ref() {
+            self.drain_remaining = PARTITION_FRAMES + convolver.kernel().tail_frames();
+        }
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        self.drain_remaining = 0;
+        if let Some(convolver) = self.convolver.as_mut() {
+            convolver.reset();
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../../tests/pipeline/convolution.rs"]
+mod tests;
@@ -0,0 +1,164 @@
+//! Minimal RIFF/WAVE reader for impulse response files.
+
+const FORMAT_PCM: u16 = 0x0001;
+const FORMAT_IEEE_FLOAT: u16 = 0x0003;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+#[derive(Debug, Clone, Copy)]
+struct WavFormat {
+    format: u16,
+    channels: usize,
+    sample_rate: u32,
+    bits_per_sample: u16,
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    bytes
+        .get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn parse_format(chunk: &[u8]) -> Result<WavFormat, String> {
+    let truncated = || "truncated fmt chunk".to_string();
+    let mut format = read_u16(chunk, 0).ok_or_else(truncated)?;
+    let channels = usize::from(read_u16(chunk, 2).ok_or_else(truncated)?);
+    let sample_rate = read_u32(chunk, 4).ok_or_else(truncated)?;
+    let bits_per_sample = read_u16(chunk, 14).ok_or_else(truncated)?;
+    if format == FORMAT_EXTENSIBLE {
+        // The sub-format GUID starts with the plain format tag.
+        format = read_u16(chunk, 24).ok_or_else(truncated)?;
+    }
+    if channels == 0 || sample_rate == 0 {
+        return Err(format!(
+            "invalid format: channels={channels} sample_rate={sample_rate}"
+        ));
+    }
+    Ok(WavFormat {
+        format,
+        channels,
+        sample_rate,
+        bits_per_sample,
+    })
+}
+
+fn decode_sample(format: WavFormat, bytes: &[u8]) -> f32 {
+    match (format.format, format.bits_per_sample) {
+        (FORMAT_PCM, 8) => (f32::from(bytes[0]) - 128.0) / 128.0,
+        (FORMAT_PCM, 16) => f32::from(i16::from_le_bytes([bytes[0], bytes[1]])) / 32_768.0,
+        (FORMAT_PCM, 24) => {
+            let value = i32::from_le_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 8;
+            value as f32 / 8_388_608.0
+        },
+        (FORMAT_PCM, 32) => {
+            i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32 / 2_147_483_648.0
+        },
+        (FORMAT_IEEE_FLOAT, 32) => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        (FORMAT_IEEE_FLOAT, 64) => f64::from_le_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ]) as f32,
+        _ => unreachable!("unsupported formats are rejected before decoding"),
+    }
+}
+
+/// Decodes a WAV file into its sample rate and per-channel samples.
+pub(crate) fn decode_wav(bytes: &[u8]) -> Result<(u32, Vec<Vec<f32>>), String> {
+    if bytes.get(0..4) != Some(b"RIFF") || bytes.get(8..12) != Some(b"WAVE") {
+        return Err("not a RIFF/WAVE file".to_string());
+    }
+
+    let mut format = None;
+    let mut data = None;
+    let mut offset = 12;
+    while let (Some(id), Some(size)) = (bytes.get(offset..offset + 4), read_u32(bytes, offset + 4))
+    {
+        let start = offset + 8;
+        let end = start.saturating_add(size as usize).min(bytes.len());
+        match id {
+            b"fmt " => format = Some(parse_format(&bytes[start..end])?),
+            b"data" => data = Some(&bytes[start..end]),
+            _ => {},
+        }
+        // Chunks are padded to an even size.
+        offset = end + (size as usize & 1);
+    }
+
+    let format = format.ok_or("missing fmt chunk")?;
+    let data = data.ok_or("missing data chunk")?;
+    match (format.format, format.bits_per_sample) {
+        (FORMAT_PCM, 8 | 16 | 24 | 32) | (FORMAT_IEEE_FLOAT, 32 | 64) => {},
+        (tag, bits) => {
+            return Err(format!(
+                "unsupported sample format: tag={tag:#06x} bits={bits}"
+            ));
+        },
+    }
+
+    let sample_bytes = usize::from(format.bits_per_sample / 8);
+    let frame_bytes = sample_bytes * format.channels;
+    let frames = data.len() / frame_bytes;
+    let mut channels = vec![Vec::with_capacity(frames); format.channels];
+    for frame in data.chunks_exact(frame_bytes) {
+        for (channel, sample) in channels.iter_mut().zip(frame.chunks_exact(sample_bytes)) {
+            channel.push(decode_sample(format, sample));
+        }
+    }
+    Ok((format.sample_rate, channels))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pipeline::runtime::dsp::convolution::wav::decode_wav;
+
+    fn wav(format: u16, channels: u16, bits: u16, data: &[u8]) -> Vec<u8> {
+        let block_align = channels * bits / 8;
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(b"WAVE");
+        bytes.extend_from_slice(b"fmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&format.to_le_bytes());
+        bytes.extend_from_slice(&channels.to_le_bytes());
+        bytes.extend_from_slice(&48_000u32.to_le_bytes());
+        bytes.extend_from_slice(&(48_000 * u32::from(block_align)).to_le_bytes());
+        bytes.extend_from_slice(&block_align.to_le_bytes());
+        bytes.extend_from_slice(&bits.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn decodes_stereo_pcm16_into_channels() {
+        let data = [0x00, 0x40, 0x00, 0xC0, 0xFF, 0x7F, 0x00, 0x00];
+        let (rate, channels) = decode_wav(&wav(1, 2, 16, &data)).expect("decode failed");
+        assert_eq!(rate, 48_000);
+        assert_eq!(
+            channels,
+            vec![vec![0.5, 32_767.0 / 32_768.0], vec![-0.5, 0.0]]
+        );
+    }
+
+    #[test]
+    fn decodes_float32_and_pcm24() {
+        let data = 0.25f32.to_le_bytes();
+        let (_, channels) = decode_wav(&wav(3, 1, 32, &data)).expect("decode failed");
+        assert_eq!(channels, vec![vec![0.25]]);
+
+        let (_, channels) = decode_wav(&wav(1, 1, 24, &[0x00, 0x00, 0xC0])).expect("decode failed");
+        assert_eq!(channels, vec![vec![-0.5]]);
+    }
+
+    #[test]
+    fn rejects_non_wav_and_unsupported_formats() {
+        assert!(decode_wav(b"not a wav file").is_err());
+        assert!(decode_wav(&wav(2, 1, 4, &[0, 0])).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+use std::any::Any;
+use std::f64::consts::PI;
+
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+use crate::config::crossfeed::{CROSSFEED_STAGE_KEY, CrossfeedConfig};
+
+/// First-order filter coefficients of the bs2b crossfeed model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Coefficients {
+    a0_lo: f64,
+    b1_lo: f64,
+    a0_hi: f64,
+    a1_hi: f64,
+    b1_hi: f64,
+    gain: f64,
+}
+
+impl Coefficients {
+    fn new(config: CrossfeedConfig, sample_rate: u32) -> Self {
+        let sample_rate = f64::from(sample_rate.max(1));
+        let feed_db = f64::from(config.feed_db);
+        let cutoff_lo = f64::from(config.cutoff_hz);
+
+        let gain_lo_db = feed_db * -5.0 / 6.0 - 3.0;
+        let gain_hi_db = feed_db / 6.0 - 3.0;
+        let gain_lo = 10f64.powf(gain_lo_db / 20.0);
+        let gain_hi = 1.0 - 10f64.powf(gain_hi_db / 20.0);
+        let cutoff_hi = cutoff_lo * 2f64.powf((gain_lo_db - 20.0 * gain_hi.log10()) / 12.0);
+        // Cap the shelf below Nyquist so low sample rates stay stable.
+        let cutoff_hi = cutoff_hi.min(sample_rate * 0.45);
+
+        let x_lo = (-2.0 * PI * cutoff_lo / sample_rate).exp();
+        let x_hi = (-2.0 * PI * cutoff_hi / sample_rate).exp();
+        Self {
+            a0_lo: gain_lo * (1.0 - x_lo),
+            b1_lo: x_lo,
+            a0_hi: 1.0 - gain_hi * (1.0 - x_hi),
+            a1_hi: -x_hi,
+            b1_hi: x_hi,
+            gain: 1.0 / (1.0 - gain_hi + gain_lo),
+        }
+    }
+}
+
+/// Per-channel filter memory.
+#[derive(Debug, Clone, Copy, Default)]
+struct ChannelState {
+    last_input: f64,
+    lo: f64,
+    hi: f64,
+}
+
+/// bs2b-style headphone crossfeed for stereo streams.
+///
+/// Any other channel count passes through untouched.
+#[derive(Debug)]
+pub(crate) struct CrossfeedStage {
+    config: CrossfeedConfig,
+    sample_rate: u32,
+    active: bool,
+    coefficients: Coefficients,
+    left: ChannelState,
+    right: ChannelState,
+}
+
+impl Default for CrossfeedStage {
+    fn default() -> Self {
+        Self::new(CrossfeedConfig::default())
+    }
+}
+
+impl CrossfeedStage {
+    pub(crate) fn new(config: CrossfeedConfig) -> Self {
+        let config = config.clamped();
+        Self {
+            config,
+            sample_rate: 48_000,
+            active: false,
+            coefficients: Coefficients::new(config, 48_000),
+            left: ChannelState::default(),
+            right: ChannelState::default(),
+        }
+    }
+
+    fn reset_state(&mut self) {
+        self.left = ChannelState::default();
+        self.right = ChannelState::default();
+    }
+}
+
+impl TransformStage for CrossfeedStage {
+    fn stage_key(&self) -> Option<&str> {
+        Some(CROSSFEED_STAGE_KEY)
+    }
+
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
+    fn apply_control(
+        &mut self,
+        control: &dyn Any,
+        _ctx: &mut PipelineContext,
+    ) -> Result<bool, PipelineError> {
+        let Some(config) = control.downcast_ref::<CrossfeedConfig>() else {
+            return Ok(false);
+        };
+        // Filter memory is kept so a live change does not click.
+        self.config = config.clamped();
+        self.coefficients = Coefficients::new(self.config, self.sample_rate);
+        Ok(true)
+    }
+
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        self.sample_rate = spec.sample_rate.max(1);
+        self.active = spec.channels == 2;
+        self.coefficients = Coefficients::new(self.config, self.sample_rate);
+        self.reset_state();
+        Ok(spec)
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn process(&mut self, block: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        if !self.active || block.channels != 2 || block.is_empty() {
+            return StageStatus::Ok;
+        }
+        let c = self.coefficients;
+        for frame in block.samples.chunks_exact_mut(2) {
+            let in_l = f64::from(frame[0]);
+            let in_r = f64::from(frame[1]);
+
+            self.left.lo = c.a0_lo * in_l + c.b1_lo * self.left.lo;
+            self.right.lo = c.a0_lo * in_r + c.b1_lo * self.right.lo;
+            self.left.hi = c.a0_hi * in_l + c.a1_hi * self.left.last_input + c.b1_hi * self.left.hi;
+            self.right.hi =
+                c.a0_hi * in_r + c.a1_hi * self.right.last_input + c.b1_hi * self.right.hi;
+            self.left.last_input = in_l;
+            self.right.last_input = in_r;
+
+            frame[0] = ((self.left.hi + self.right.lo) * c.gain) as f32;
+            frame[1] = ((self.right.hi + self.left.lo) * c.gain) as f32;
+        }
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        self.reset_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+    use stellatune_audio_core::pipeline::stages::StageStatus;
+    use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+    use crate::config::crossfeed::{CrossfeedConfig, CrossfeedPreset};
+    use crate::pipeline::runtime::dsp::crossfeed::CrossfeedStage;
+
+    fn prepared_stage(config: CrossfeedConfig, channels: u16) -> (CrossfeedStage, PipelineContext) {
+        let mut stage = CrossfeedStage::new(config);
+        let mut ctx = PipelineContext::default();
+        stage
+            .prepare(
+                StreamSpec {
+                    sample_rate: 44_100,
+                    channels,
+                },
+                &mut ctx,
+            )
+            .expect("prepare failed");
+        (stage, ctx)
+    }
+
+    /// Feeds a hard-left DC signal until the filters settle and returns the last frame.
+    fn settled_hard_left(stage: &mut CrossfeedStage, ctx: &mut PipelineContext) -> (f32, f32) {
+        let mut block = AudioBlock {
+            channels: 2,
+            samples: Vec::new(),
+        };
+        for _ in 0..8 {
+            block.samples.clear();
+            block.samples.extend([1.0, 0.0].repeat(4_096));
+            assert_eq!(stage.process(&mut block, ctx), StageStatus::Ok);
+        }
+        let frame = &block.samples[block.samples.len() - 2..];
+        (frame[0], frame[1])
+    }
+
+    #[test]
+    fn feeds_low_frequencies_to_opposite_channel_at_configured_level() {
+        for preset in [
+            CrossfeedPreset::Default,
+            CrossfeedPreset::ChuMoy,
+            CrossfeedPreset::JanMeier,
+        ] {
+            let config = preset.config();
+            let (mut stage, mut ctx) = prepared_stage(config, 2);
+            let (left, right) = settled_hard_left(&mut stage, &mut ctx);
+            assert!(right > 0.0, "{preset:?}: nothing was crossfed");
+            let measured_db = 20.0 * (left / right).log10();
+            assert!(
+                (measured_db - config.feed_db).abs() < 0.05,
+                "{preset:?}: crossfeed level {measured_db} dB, expected {} dB",
+                config.feed_db
+            );
+        }
+    }
+
+    #[test]
+    fn bypasses_non_stereo_streams() {
+        let (mut stage, mut ctx) = prepared_stage(CrossfeedConfig::default(), 6);
+        let mut block = AudioBlock {
+            channels: 6,
+            samples: vec![1.0, 0.0, 0.5, 0.0, 0.25, 0.0],
+        };
+        assert_eq!(stage.process(&mut block, &mut ctx), StageStatus::Ok);
+        assert_eq!(block.samples, vec![1.0, 0.0, 0.5, 0.0, 0.25, 0.0]);
+    }
+
+    #[test]
+    fn control_updates_feed_level_at_runtime() {
+        let (mut stage, mut ctx) = prepared_stage(CrossfeedPreset::Default.config(), 2);
+        let handled = stage
+            .apply_control(&CrossfeedPreset::JanMeier.config(), &mut ctx)
+            .expect("apply_control failed");
+        assert!(handled);
+
+        let (left, right) = settled_hard_left(&mut stage, &mut ctx);
+        let measured_db = 20.0 * (left / right).log10();
+        assert!((measured_db - 9.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn config_values_are_clamped() {
+        let config = CrossfeedConfig::new(10, f32::NAN);
+        assert_eq!(config, CrossfeedConfig::new(300, 4.5));
+        assert_eq!(
+            CrossfeedConfig::new(9_000, 40.0),
+            CrossfeedConfig::new(2_000, 15.0)
+        );
+    }
+}
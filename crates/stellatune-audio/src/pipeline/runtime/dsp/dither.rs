@@ -0,0 +1,288 @@
+use std::any::Any;
+
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+use crate::config::dither::{DITHER_STAGE_KEY, DitherConfig};
+
+/// Longest error-feedback filter of any [`NoiseShaping`](crate::config::dither::NoiseShaping)
+/// curve.
+const MAX_SHAPING_TAPS: usize = 9;
+
+/// Per-channel quantization error history, newest first.
+type ErrorHistory = [f64; MAX_SHAPING_TAPS];
+
+/// Small xorshift generator; dither only needs cheap, uncorrelated noise.
+#[derive(Debug, Clone, Copy)]
+struct NoiseSource {
+    state: u32,
+}
+
+impl NoiseSource {
+    fn new() -> Self {
+        Self { state: 0x9E37_79B9 }
+    }
+
+    /// Uniform value in `[-0.5, 0.5)`.
+    fn next_uniform(&mut self) -> f64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        f64::from(self.state) / 4_294_967_296.0 - 0.5
+    }
+
+    /// Triangular value in `(-1.0, 1.0)`, i.e. ±1 LSB of TPDF dither.
+    fn next_triangular(&mut self) -> f64 {
+        self.next_uniform() + self.next_uniform()
+    }
+}
+
+/// Final-stage quantizer for integer sinks, with TPDF dither and noise shaping.
+///
+/// Samples stay `f32` but land exactly on the sink's integer grid (scaled by
+/// `2^(bits-1) - 1`), so the sink's own conversion no longer truncates. Float sinks bypass
+/// the stage.
+#[derive(Debug)]
+pub(crate) struct DitherStage {
+    config: DitherConfig,
+    errors: Vec<ErrorHistory>,
+    noise: NoiseSource,
+}
+
+impl Default for DitherStage {
+    fn default() -> Self {
+        Self::new(DitherConfig::default())
+    }
+}
+
+impl DitherStage {
+    pub(crate) fn new(config: DitherConfig) -> Self {
+        Self {
+            config,
+            errors: Vec::new(),
+            noise: NoiseSource::new(),
+        }
+    }
+
+    fn reset_state(&mut self) {
+        self.errors.fill([0.0; MAX_SHAPING_TAPS]);
+    }
+}
+
+impl TransformStage for DitherStage {
+    fn stage_key(&self) -> Option<&str> {
+        Some(DITHER_STAGE_KEY)
+    }
+
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
+    fn apply_control(
+        &mut self,
+        control: &dyn Any,
+        _ctx: &mut PipelineContext,
+    ) -> Result<bool, PipelineError> {
+        let Some(config) = control.downcast_ref::<DitherConfig>() else {
+            return Ok(false);
+        };
+        // Error history belongs to the previous grid and curve.
+        if config.sink_format != self.config.sink_format
+            || config.noise_shaping != self.config.noise_shaping
+        {
+            self.reset_state();
+        }
+        self.config = *config;
+        Ok(true)
+    }
+
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        self.errors = vec![[0.0; MAX_SHAPING_TAPS]; usize::from(spec.channels.max(1))];
+        Ok(spec)
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn process(&mut self, block: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        let Some(bits) = self.config.sink_format.quantize_bits() else {
+            return StageStatus::Ok;
+        };
+        let channels = usize::from(block.channels);
+        if channels != self.errors.len() || block.is_empty() {
+            return StageStatus::Ok;
+        }
+        let peak = ((1_u32 << (bits - 1)) - 1) as f64;
+        let taps = self.config.noise_shaping.coefficients();
+        for frame in block.samples.chunks_exact_mut(channels) {
+            for (sample, history) in frame.iter_mut().zip(self.errors.iter_mut()) {
+                let feedback: f64 = taps
+                    .iter()
+                    .zip(history.iter())
+                    .map(|(tap, error)| f64::from(*tap) * error)
+                    .sum();
+                let target = f64::from(*sample) * peak - feedback;
+                let dither = if self.config.tpdf {
+                    self.noise.next_triangular()
+                } else {
+                    0.0
+                };
+                let quantized = (target + dither).round();
+                // The error is taken before clipping so a clipped peak cannot destabilize
+                // the feedback loop.
+                history.copy_within(..MAX_SHAPING_TAPS - 1, 1);
+                history[0] = quantized - target;
+                *sample = (quantized.clamp(-peak, peak) / peak) as f32;
+            }
+        }
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        self.reset_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+    use stellatune_audio_core::pipeline::stages::StageStatus;
+    use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+    use crate::config::dither::{DitherConfig, NoiseShaping, SinkSampleFormat};
+    use crate::pipeline::runtime::dsp::dither::DitherStage;
+
+    const PEAK_16: f32 = 32_767.0;
+
+    fn prepared_stage(config: DitherConfig) -> (DitherStage, PipelineContext) {
+        let mut stage = DitherStage::new(config);
+        let mut ctx = PipelineContext::default();
+        stage
+            .prepare(
+                StreamSpec {
+                    sample_rate: 44_100,
+                    channels: 1,
+                },
+                &mut ctx,
+            )
+            .expect("prepare failed");
+        (stage, ctx)
+    }
+
+    fn int16(tpdf: bool, noise_shaping: NoiseShaping) -> DitherConfig {
+        DitherConfig {
+            sink_format: SinkSampleFormat::Int16,
+            tpdf,
+            noise_shaping,
+        }
+    }
+
+    /// Quantizes a constant mono signal and returns the error in LSBs.
+    fn quantization_error(config: DitherConfig, level: f32, frames: usize) -> Vec<f32> {
+        let (mut stage, mut ctx) = prepared_stage(config);
+        let mut block = AudioBlock {
+            channels: 1,
+            samples: vec![level; frames],
+        };
+        assert_eq!(stage.process(&mut block, &mut ctx), StageStatus::Ok);
+        block
+            .samples
+            .iter()
+            .map(|sample| (sample - level) * PEAK_16)
+            .collect()
+    }
+
+    #[test]
+    fn float_sinks_are_bypassed() {
+        let (mut stage, mut ctx) = prepared_stage(DitherConfig::default());
+        let samples = vec![0.123_456_7, -0.987_654_3, 1.5];
+        let mut block = AudioBlock {
+            channels: 1,
+            samples: samples.clone(),
+        };
+        assert_eq!(stage.process(&mut block, &mut ctx), StageStatus::Ok);
+        assert_eq!(block.samples, samples);
+    }
+
+    #[test]
+    fn integer_sinks_receive_samples_on_their_grid() {
+        let (mut stage, mut ctx) = prepared_stage(int16(true, NoiseShaping::Lipshitz));
+        let mut block = AudioBlock {
+            channels: 1,
+            samples: (0..4_096).map(|i| (i as f32 * 0.01).sin() * 0.9).collect(),
+        };
+        assert_eq!(stage.process(&mut block, &mut ctx), StageStatus::Ok);
+        for sample in &block.samples {
+            let scaled = sample * PEAK_16;
+            assert!(
+                (scaled - scaled.round()).abs() < 1e-3,
+                "{scaled} is off grid"
+            );
+        }
+
+        let (mut stage, mut ctx) = prepared_stage(int16(false, NoiseShaping::Off));
+        let mut block = AudioBlock {
+            channels: 1,
+            samples: vec![0.4 / PEAK_16, 0.6 / PEAK_16, 2.0],
+        };
+        assert_eq!(stage.process(&mut block, &mut ctx), StageStatus::Ok);
+        assert_eq!(block.samples, vec![0.0, 1.0 / PEAK_16, 1.0]);
+    }
+
+    #[test]
+    fn tpdf_dither_stays_within_its_error_bound() {
+        let errors = quantization_error(int16(true, NoiseShaping::Off), 0.25 / PEAK_16, 8_192);
+        assert!(errors.iter().all(|error| error.abs() < 1.5));
+        let mean = errors.iter().sum::<f32>() / errors.len() as f32;
+        assert!(mean.abs() < 0.05, "dither is biased by {mean} LSB");
+    }
+
+    #[test]
+    fn noise_shaping_moves_error_out_of_low_band() {
+        // Summing the error over short windows keeps only its low-frequency part.
+        let low_band_energy = |errors: Vec<f32>| -> f32 {
+            errors
+                .chunks_exact(64)
+                .map(|window| window.iter().sum::<f32>().powi(2))
+                .sum()
+        };
+        let flat = low_band_energy(quantization_error(
+            int16(true, NoiseShaping::Off),
+            0.1,
+            16_384,
+        ));
+        let shaped = low_band_energy(quantization_error(
+            int16(true, NoiseShaping::FirstOrder),
+            0.1,
+            16_384,
+        ));
+        assert!(shaped * 10.0 < flat, "shaped {shaped} vs flat {flat}");
+    }
+
+    #[test]
+    fn control_switches_sink_format_at_runtime() {
+        let (mut stage, mut ctx) = prepared_stage(DitherConfig::default());
+        let handled = stage
+            .apply_control(&int16(false, NoiseShaping::Off), &mut ctx)
+            .expect("apply_control failed");
+        assert!(handled);
+
+        let mut block = AudioBlock {
+            channels: 1,
+            samples: vec![0.6 / PEAK_16],
+        };
+        assert_eq!(stage.process(&mut block, &mut ctx), StageStatus::Ok);
+        assert_eq!(block.samples, vec![1.0 / PEAK_16]);
+    }
+}
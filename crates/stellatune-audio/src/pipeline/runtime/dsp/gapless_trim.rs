@@ -24,6 +24,7 @@ pub(crate) struct GaplessTrimStage {
     entry_ramp_total_frames: usize,
     entry_ramp_applied_frames: usize,
     entry_ramp_active: bool,
+    scratch: Vec<f32>,
 }
 
 impl GaplessTrimStage {
@@ -104,32 +105,26 @@ impl GaplessTrimStage {
         }
     }
 
-    fn push_decoded_samples(&mut self, mut samples: Vec<f32>) {
+    fn push_decoded_samples(&mut self, samples: &mut [f32]) {
         if self.spec.is_none() {
-            self.pending_output.extend(samples);
+            self.pending_output.extend(samples.iter().copied());
             return;
         }
 
-        if self.head_samples_remaining > 0 {
-            let trim = self.head_samples_remaining.min(samples.len());
-            if trim == samples.len() {
-                samples.clear();
-            } else if trim > 0 {
-                samples = samples.split_off(trim);
-            }
-            self.head_samples_remaining = self.head_samples_remaining.saturating_sub(trim);
-        }
+        let trim = self.head_samples_remaining.min(samples.len());
+        self.head_samples_remaining -= trim;
+        let samples = &mut samples[trim..];
         if samples.is_empty() {
             return;
         }
 
-        self.apply_entry_ramp_in_place(&mut samples);
+        self.apply_entry_ramp_in_place(samples);
         if self.tail_hold_samples == 0 {
-            self.pending_output.extend(samples);
+            self.pending_output.extend(samples.iter().copied());
             return;
         }
 
-        self.tail_buffer.extend(samples);
+        self.tail_buffer.extend(samples.iter().copied());
         let releasable = self
             .tail_buffer
             .len()
@@ -152,6 +147,10 @@ impl TransformStage for GaplessTrimStage {
         Some(GAPLESS_TRIM_STAGE_KEY)
     }
 
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
     fn apply_control(
         &mut self,
         control: &dyn std::any::Any,
@@ -189,8 +188,13 @@ impl TransformStage for GaplessTrimStage {
         if block.is_empty() {
             return StageStatus::Ok;
         }
-        let incoming = std::mem::take(&mut block.samples);
-        self.push_decoded_samples(incoming);
+        // The decoded samples move into the scratch buffer and the block keeps the old
+        // scratch allocation for the released output, so neither side reallocates.
+        block.swap_samples(&mut self.scratch);
+        let mut incoming = std::mem::take(&mut self.scratch);
+        self.push_decoded_samples(&mut incoming);
+        incoming.clear();
+        self.scratch = incoming;
         self.drain_pending_into_block(block);
         StageStatus::Ok
     }
@@ -86,6 +86,10 @@ impl TransformStage for MasterGainStage {
         Some(MASTER_GAIN_STAGE_KEY)
     }
 
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
     fn apply_control(
         &mut self,
         control: &dyn Any,
@@ -1,4 +1,5 @@
 use crate::config::engine::LfeMode;
+use crate::pipeline::assembly::{MixMode, MixPreset, MixerPlan};
 use crate::pipeline::runtime::dsp::mixer::layout::ChannelLayout;
 
 const CENTER_COEFF: f32 = std::f32::consts::FRAC_1_SQRT_2;
@@ -47,24 +48,136 @@ impl MixMatrix {
         }
     }
 
+    /// Builds the matrix a mixer plan needs for `in_channels` input channels: the mode's
+    /// routing matrix wrapped in layout adaptation on both sides, folded into one matrix.
+    pub(crate) fn for_plan(in_channels: usize, plan: &MixerPlan) -> Self {
+        let out_channels = plan.target_channels.max(1) as usize;
+        let routing = match &plan.mode {
+            MixMode::Auto => return Self::adapt(in_channels, out_channels, plan.lfe_mode),
+            MixMode::Preset(preset) => Self::preset(*preset),
+            MixMode::Custom(matrix) => Self::from_rows(matrix.rows()),
+        };
+        Self::adapt(in_channels, routing.in_channels, plan.lfe_mode)
+            .then(&routing)
+            .then(&Self::adapt(
+                routing.out_channels,
+                out_channels,
+                plan.lfe_mode,
+            ))
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        self.in_channels == self.out_channels
+            && self.coeffs.iter().enumerate().all(|(out_ch, row)| {
+                row.iter()
+                    .enumerate()
+                    .all(|(in_ch, coeff)| *coeff == if in_ch == out_ch { 1.0 } else { 0.0 })
+            })
+    }
+
+    #[cfg(test)]
     pub(crate) fn apply(&self, input: &[f32]) -> Vec<f32> {
-        if self.in_channels == self.out_channels {
-            return input.to_vec();
-        }
+        let mut output = Vec::new();
+        self.apply_into(input, &mut output);
+        output
+    }
+
+    /// Mixes `input` into `output`, replacing its contents. Reuses the capacity of
+    /// `output`, so a caller-owned scratch buffer keeps the hot path allocation-free.
+    pub(crate) fn apply_into(&self, input: &[f32], output: &mut Vec<f32>) {
+        output.clear();
         let frames = input.len() / self.in_channels;
-        let mut output = vec![0.0; frames * self.out_channels];
-        for frame in 0..frames {
-            let in_offset = frame * self.in_channels;
-            let out_offset = frame * self.out_channels;
-            for out_ch in 0..self.out_channels {
-                let mut sum = 0.0;
-                for in_ch in 0..self.in_channels {
-                    sum += input[in_offset + in_ch] * self.coeffs[out_ch][in_ch];
-                }
-                output[out_offset + out_ch] = sum;
+        output.resize(frames * self.out_channels, 0.0);
+        for (in_frame, out_frame) in input
+            .chunks_exact(self.in_channels)
+            .zip(output.chunks_exact_mut(self.out_channels))
+        {
+            for (out_sample, row) in out_frame.iter_mut().zip(&self.coeffs) {
+                *out_sample = in_frame
+                    .iter()
+                    .zip(row)
+                    .map(|(sample, coeff)| sample * coeff)
+                    .sum();
             }
         }
-        output
+    }
+
+    fn adapt(in_channels: usize, out_channels: usize, lfe_mode: LfeMode) -> Self {
+        Self::create(
+            ChannelLayout::from_count(in_channels as u16),
+            ChannelLayout::from_count(out_channels as u16),
+            lfe_mode,
+        )
+    }
+
+    /// Matrix that applies `self` first and `next` second.
+    fn then(&self, next: &Self) -> Self {
+        let coeffs = next
+            .coeffs
+            .iter()
+            .map(|next_row| {
+                (0..self.in_channels)
+                    .map(|in_ch| {
+                        next_row
+                            .iter()
+                            .zip(&self.coeffs)
+                            .map(|(gain, row)| gain * row[in_ch])
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            coeffs,
+            in_channels: self.in_channels,
+            out_channels: next.out_channels,
+        }
+    }
+
+    fn from_rows(rows: &[Vec<f32>]) -> Self {
+        Self {
+            coeffs: rows.to_vec(),
+            in_channels: rows.first().map(Vec::len).unwrap_or(0),
+            out_channels: rows.len(),
+        }
+    }
+
+    fn preset(preset: MixPreset) -> Self {
+        let rows = match preset {
+            MixPreset::Balance(balance) => {
+                let balance = balance.clamp(-1.0, 1.0);
+                vec![
+                    vec![(1.0 - balance).min(1.0), 0.0],
+                    vec![0.0, (1.0 + balance).min(1.0)],
+                ]
+            },
+            MixPreset::SwapLeftRight => vec![vec![0.0, 1.0], vec![1.0, 0.0]],
+            MixPreset::MonoSum => vec![vec![0.5, 0.5], vec![0.5, 0.5]],
+            MixPreset::StereoWidth(width) => {
+                // L' = M + w*S and R' = M - w*S with M = (L+R)/2, S = (L-R)/2.
+                let width = width.clamp(0.0, 2.0);
+                let direct = (1.0 + width) / 2.0;
+                let cross = (1.0 - width) / 2.0;
+                vec![vec![direct, cross], vec![cross, direct]]
+            },
+            MixPreset::MatrixSurroundUpmix => vec![
+                vec![1.0, 0.0],
+                vec![0.0, 1.0],
+                vec![CENTER_COEFF, CENTER_COEFF],
+                vec![0.0, 0.0],
+                vec![SURROUND_COEFF, -SURROUND_COEFF],
+                vec![SURROUND_COEFF, -SURROUND_COEFF],
+            ],
+            MixPreset::AmbienceUpmix => vec![
+                vec![1.0, 0.0],
+                vec![0.0, 1.0],
+                vec![0.0, 0.0],
+                vec![0.0, 0.0],
+                vec![0.5, -0.5],
+                vec![-0.5, 0.5],
+            ],
+        };
+        Self::from_rows(&rows)
     }
 
     fn identity(channels: usize) -> Self {
@@ -234,9 +347,14 @@ impl MixMatrix {
 #[cfg(test)]
 mod tests {
     use crate::config::engine::LfeMode;
+    use crate::pipeline::assembly::{MixGainMatrix, MixMode, MixPreset, MixerPlan};
     use crate::pipeline::runtime::dsp::mixer::layout::ChannelLayout;
     use crate::pipeline::runtime::dsp::mixer::matrix::MixMatrix;
 
+    fn plan(target_channels: u16, mode: MixMode) -> MixerPlan {
+        MixerPlan::new(target_channels, LfeMode::Mute).with_mode(mode)
+    }
+
     #[test]
     fn mono_to_stereo_duplicates_samples() {
         let matrix = MixMatrix::create(ChannelLayout::Mono, ChannelLayout::Stereo, LfeMode::Mute);
@@ -250,4 +368,60 @@ mod tests {
         let mixed = matrix.apply(&[0.8, 0.2, 0.4, 0.6]);
         assert_eq!(mixed, vec![0.5, 0.5]);
     }
+
+    #[test]
+    fn stereo_presets_route_left_and_right() {
+        let swap = MixMatrix::for_plan(2, &plan(2, MixMode::Preset(MixPreset::SwapLeftRight)));
+        assert_eq!(swap.apply(&[0.25, 0.75]), vec![0.75, 0.25]);
+
+        let balance = MixMatrix::for_plan(2, &plan(2, MixMode::Preset(MixPreset::Balance(0.5))));
+        assert_eq!(balance.apply(&[1.0, 1.0]), vec![0.5, 1.0]);
+
+        let narrow = MixMatrix::for_plan(2, &plan(2, MixMode::Preset(MixPreset::StereoWidth(0.0))));
+        let mono = MixMatrix::for_plan(2, &plan(2, MixMode::Preset(MixPreset::MonoSum)));
+        assert_eq!(narrow.apply(&[0.8, 0.2]), mono.apply(&[0.8, 0.2]));
+        assert_eq!(mono.apply(&[0.8, 0.2]), vec![0.5, 0.5]);
+
+        let unchanged =
+            MixMatrix::for_plan(2, &plan(2, MixMode::Preset(MixPreset::StereoWidth(1.0))));
+        assert!(unchanged.is_identity());
+    }
+
+    #[test]
+    fn matrix_surround_upmix_extracts_center_and_surround() {
+        let matrix =
+            MixMatrix::for_plan(2, &plan(6, MixMode::Preset(MixPreset::MatrixSurroundUpmix)));
+        // Identical channels land in the center; opposite ones in the surrounds.
+        let mixed = matrix.apply(&[0.5, 0.5, 0.5, -0.5]);
+        let (center_only, surround_only) = mixed.split_at(6);
+        assert!(center_only[2] > 0.7 && center_only[4].abs() < 1e-6);
+        assert!(surround_only[2].abs() < 1e-6 && surround_only[4] > 0.7);
+        assert_eq!(surround_only[4], surround_only[5]);
+    }
+
+    #[test]
+    fn presets_adapt_source_and_target_layouts() {
+        // Mono source feeds the stereo preset; the 5.1 upmix is folded back to stereo.
+        let swap = MixMatrix::for_plan(1, &plan(2, MixMode::Preset(MixPreset::SwapLeftRight)));
+        assert_eq!(swap.apply(&[0.5]), vec![0.5, 0.5]);
+
+        let ambience = MixMatrix::for_plan(2, &plan(2, MixMode::Preset(MixPreset::AmbienceUpmix)));
+        assert_eq!(ambience.apply(&[0.4, 0.2]).len(), 2);
+    }
+
+    #[test]
+    fn custom_matrix_is_applied_between_layout_adaptations() {
+        let rows = vec![
+            vec![0.0, 1.0, 0.0],
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 2.0],
+        ];
+        let custom = MixGainMatrix::new(rows).expect("valid matrix");
+        let matrix = MixMatrix::for_plan(3, &plan(3, MixMode::Custom(custom)));
+        assert_eq!(matrix.apply(&[0.1, 0.2, 0.3]), vec![0.2, 0.1, 0.6]);
+
+        assert!(MixGainMatrix::new(Vec::new()).is_err());
+        assert!(MixGainMatrix::new(vec![vec![1.0, 0.0], vec![1.0]]).is_err());
+        assert!(MixGainMatrix::new(vec![vec![f32::NAN]]).is_err());
+    }
 }
@@ -4,7 +4,6 @@ use stellatune_audio_core::pipeline::stages::StageStatus;
 use stellatune_audio_core::pipeline::stages::transform::TransformStage;
 
 use crate::pipeline::assembly::MixerPlan;
-use crate::pipeline::runtime::dsp::mixer::layout::ChannelLayout;
 use crate::pipeline::runtime::dsp::mixer::matrix::MixMatrix;
 
 #[derive(Debug, Clone)]
@@ -13,6 +12,7 @@ pub(crate) struct MixerStage {
     in_channels: usize,
     out_channels: usize,
     matrix: Option<MixMatrix>,
+    scratch: Vec<f32>,
 }
 
 impl MixerStage {
@@ -23,25 +23,23 @@ impl MixerStage {
             in_channels: out_channels,
             out_channels,
             matrix: None,
+            scratch: Vec::new(),
         }
     }
 
     fn reconfigure(&mut self, in_channels: usize) {
         self.in_channels = in_channels.max(1);
         self.out_channels = self.plan.target_channels.max(1) as usize;
-        if self.in_channels == self.out_channels {
-            self.matrix = None;
-            return;
-        }
-        self.matrix = Some(MixMatrix::create(
-            ChannelLayout::from_count(self.in_channels as u16),
-            ChannelLayout::from_count(self.out_channels as u16),
-            self.plan.lfe_mode,
-        ));
+        let matrix = MixMatrix::for_plan(self.in_channels, &self.plan);
+        self.matrix = (!matrix.is_identity()).then_some(matrix);
     }
 }
 
 impl TransformStage for MixerStage {
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
     fn prepare(
         &mut self,
         spec: StreamSpec,
@@ -70,7 +68,8 @@ impl TransformStage for MixerStage {
             return StageStatus::Fatal;
         }
         if let Some(matrix) = self.matrix.as_ref() {
-            block.samples = matrix.apply(&block.samples);
+            matrix.apply_into(&block.samples, &mut self.scratch);
+            block.swap_samples(&mut self.scratch);
             block.channels = self.out_channels as u16;
         }
         StageStatus::Ok
@@ -86,7 +85,7 @@ impl TransformStage for MixerStage {
 #[cfg(test)]
 mod tests {
     use crate::config::engine::LfeMode;
-    use crate::pipeline::assembly::MixerPlan;
+    use crate::pipeline::assembly::{MixMode, MixPreset, MixerPlan};
     use crate::pipeline::runtime::dsp::mixer::stage::MixerStage;
     use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
     use stellatune_audio_core::pipeline::stages::StageStatus;
@@ -140,4 +139,26 @@ mod tests {
         assert_eq!(stereo.channels, 1);
         assert_eq!(stereo.samples, vec![0.5, 0.5]);
     }
+
+    #[test]
+    fn preset_applies_without_channel_count_change() {
+        let plan =
+            MixerPlan::new(2, LfeMode::Mute).with_mode(MixMode::Preset(MixPreset::SwapLeftRight));
+        let mut stage = MixerStage::new(plan);
+        let mut ctx = PipelineContext::default();
+        let out = stage
+            .prepare(
+                StreamSpec {
+                    sample_rate: 48_000,
+                    channels: 2,
+                },
+                &mut ctx,
+            )
+            .expect("prepare failed");
+        assert_eq!(out.channels, 2);
+
+        let mut stereo = block(&[0.1, 0.9, 0.3, 0.7], 2);
+        assert_eq!(stage.process(&mut stereo, &mut ctx), StageStatus::Ok);
+        assert_eq!(stereo.samples, vec![0.9, 0.1, 0.7, 0.3]);
+    }
 }
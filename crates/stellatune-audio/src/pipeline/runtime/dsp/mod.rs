@@ -1,6 +1,30 @@
 pub(crate) mod control;
+pub(crate) mod convolution;
+pub(crate) mod crossfeed;
+pub(crate) mod dither;
 pub(crate) mod gapless_trim;
 pub(crate) mod master_gain;
 pub(crate) mod mixer;
 pub(crate) mod resampler;
+pub(crate) mod time_stretch;
 pub(crate) mod transition_gain;
+
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+/// Source frames behind a block of `output_frames` that just ran through `transforms`;
+/// see [`TransformStage::source_frames`].
+pub(crate) fn chain_source_frames(
+    transforms: &[Box<dyn TransformStage>],
+    output_frames: u64,
+) -> u64 {
+    transforms
+        .iter()
+        .rev()
+        .fold(output_frames, |frames, transform| {
+            transform.source_frames(frames)
+        })
+}
+
+#[cfg(test)]
+#[path = "../../../tests/pipeline/dsp_hot_path.rs"]
+mod tests;
@@ -64,6 +64,9 @@ pub(crate) struct ResamplerStage {
     target_sample_rate: u32,
     active: bool,
     resampler: Option<Async<f32>>,
+    /// Ping-pong partner of the block buffer; resampled output is rendered here and
+    /// swapped into the block.
+    scratch: Vec<f32>,
 }
 
 impl ResamplerStage {
@@ -76,6 +79,7 @@ impl ResamplerStage {
             target_sample_rate,
             active: false,
             resampler: None,
+            scratch: Vec::new(),
         }
     }
 
@@ -101,14 +105,16 @@ impl ResamplerStage {
         .map_err(|e| PipelineError::StageFailure(format!("failed to create resampler: {e}")))
     }
 
+    /// Resamples one chunk and appends the result to `output`.
     fn process_chunk(
         resampler: &mut Async<f32>,
         channels: usize,
         input_chunk: &[f32],
-    ) -> Result<Vec<f32>, PipelineError> {
+        output: &mut Vec<f32>,
+    ) -> Result<(), PipelineError> {
         let frames = input_chunk.len() / channels;
         if frames == 0 {
-            return Ok(Vec::new());
+            return Ok(());
         }
         resampler.set_chunk_size(frames).map_err(|e| {
             PipelineError::StageFailure(format!("resampler set_chunk_size failed: {e}"))
@@ -117,14 +123,26 @@ impl ResamplerStage {
         let input = InterleavedSlice::new(input_chunk, channels, frames).map_err(|e| {
             PipelineError::StageFailure(format!("resample input buffer error: {e}"))
         })?;
-        let output = resampler
-            .process(&input, 0, None)
+        let out_frames = resampler.output_frames_next();
+        let start = output.len();
+        output.resize(start + out_frames * channels, 0.0);
+        let mut out = InterleavedSlice::new_mut(&mut output[start..], channels, out_frames)
+            .map_err(|e| {
+                PipelineError::StageFailure(format!("resample output buffer error: {e}"))
+            })?;
+        let (_, written) = resampler
+            .process_into_buffer(&input, &mut out, None)
             .map_err(|e| PipelineError::StageFailure(format!("resample error: {e}")))?;
-        Ok(output.take_data())
+        output.truncate(start + written * channels);
+        Ok(())
     }
 }
 
 impl TransformStage for ResamplerStage {
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
     fn prepare(
         &mut self,
         spec: StreamSpec,
@@ -136,7 +154,10 @@ impl TransformStage for ResamplerStage {
         self.active = self.target_sample_rate != self.source_sample_rate;
 
         if self.active {
-            self.resampler = Some(self.build_resampler()?);
+            let resampler = self.build_resampler()?;
+            // Sized for one full chunk; larger decoder blocks grow it once while warming up.
+            self.scratch = Vec::with_capacity(resampler.output_frames_max() * self.channels);
+            self.resampler = Some(resampler);
             Ok(StreamSpec {
                 sample_rate: self.target_sample_rate,
                 channels: spec.channels,
@@ -165,25 +186,13 @@ impl TransformStage for ResamplerStage {
             return StageStatus::Fatal;
         };
 
-        let input = std::mem::take(&mut block.samples);
-        let mut output = Vec::new();
-        let mut offset = 0usize;
-        while offset < input.len() {
-            let remaining_frames = (input.len() - offset) / channels;
-            let chunk_frames = remaining_frames.min(RESAMPLE_CHUNK_FRAMES);
-            if chunk_frames == 0 {
-                break;
+        self.scratch.clear();
+        for chunk in block.samples.chunks(RESAMPLE_CHUNK_FRAMES * channels) {
+            if Self::process_chunk(resampler, channels, chunk, &mut self.scratch).is_err() {
+                return StageStatus::Fatal;
             }
-            let chunk_samples = chunk_frames * channels;
-            let chunk = &input[offset..offset + chunk_samples];
-            let resampled = match Self::process_chunk(resampler, channels, chunk) {
-                Ok(samples) => samples,
-                Err(_) => return StageStatus::Fatal,
-            };
-            output.extend(resampled);
-            offset += chunk_samples;
         }
-        block.samples = output;
+        block.swap_samples(&mut self.scratch);
         StageStatus::Ok
     }
 
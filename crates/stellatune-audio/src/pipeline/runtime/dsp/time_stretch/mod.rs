@@ -0,0 +1,5 @@
+mod pitch;
+mod stage;
+mod wsola;
+
+pub(crate) use stage::TimeStretchStage;
@@ -0,0 +1,96 @@
+//! Variable-ratio resampling used to turn a tempo change into a pitch change.
+
+/// Streaming Catmull-Rom interpolator that reads its input `ratio` frames per output
+/// frame. At ratio 1 on an integer read position it returns its input unchanged.
+#[derive(Debug)]
+pub(super) struct Interpolator {
+    channels: usize,
+    /// Pending interleaved input; frame 0 is the one before the read position.
+    input: Vec<f32>,
+    /// Read position in frames into `input`.
+    pos: f64,
+}
+
+impl Interpolator {
+    pub(super) fn new(channels: usize, reserved_frames: usize) -> Self {
+        Self {
+            channels,
+            input: Vec::with_capacity(reserved_frames * channels),
+            pos: 0.0,
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.input.clear();
+        self.pos = 0.0;
+    }
+
+    fn frames(&self) -> usize {
+        self.input.len() / self.channels
+    }
+
+    /// Appends `samples` and renders every output frame they allow into `output`.
+    pub(super) fn process(&mut self, samples: &[f32], ratio: f64, output: &mut Vec<f32>) {
+        self.input.extend_from_slice(samples);
+        let frames = self.frames();
+        // Each output frame needs one frame behind and two ahead of its read position.
+        while (self.pos as usize) + 2 < frames {
+            self.render_frame(output);
+            self.pos += ratio;
+        }
+        self.discard_consumed();
+    }
+
+    /// Renders the remaining input against trailing silence, then resets.
+    pub(super) fn drain(&mut self, ratio: f64, output: &mut Vec<f32>) {
+        let end = self.frames();
+        self.input.resize((end + 2) * self.channels, 0.0);
+        while (self.pos as usize) < end {
+            self.render_frame(output);
+            self.pos += ratio;
+        }
+        self.reset();
+    }
+
+    /// Appends the remaining input from the read position on without resampling, then
+    /// resets.
+    pub(super) fn release(&mut self, output: &mut Vec<f32>) {
+        let start = (self.pos.round() as usize).min(self.frames());
+        output.extend_from_slice(&self.input[start * self.channels..]);
+        self.reset();
+    }
+
+    fn render_frame(&self, output: &mut Vec<f32>) {
+        let index = self.pos as usize;
+        let t = (self.pos - index as f64) as f32;
+        let channels = self.channels;
+        let frame = |offset: usize| {
+            let start = offset * channels;
+            &self.input[start..start + channels]
+        };
+        let (p0, p1, p2, p3) = (
+            frame(index.saturating_sub(1)),
+            frame(index),
+            frame(index + 1),
+            frame(index + 2),
+        );
+        for channel in 0..channels {
+            let (y0, y1, y2, y3) = (p0[channel], p1[channel], p2[channel], p3[channel]);
+            let a = -0.5 * y0 + 1.5 * y1 - 1.5 * y2 + 0.5 * y3;
+            let b = y0 - 2.5 * y1 + 2.0 * y2 - 0.5 * y3;
+            let c = -0.5 * y0 + 0.5 * y2;
+            output.push(((a * t + b) * t + c) * t + y1);
+        }
+    }
+
+    /// Drops input that lies more than one frame behind the read position.
+    fn discard_consumed(&mut self) {
+        let drop = (self.pos as usize).saturating_sub(1).min(self.frames());
+        if drop == 0 {
+            return;
+        }
+        self.input.copy_within(drop * self.channels.., 0);
+        self.input.truncate(self.input.len() - drop * self.channels);
+        self.pos -= drop as f64;
+    }
+}
@@ -0,0 +1,225 @@
+use std::any::Any;
+
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+use crate::config::time_stretch::{TIME_STRETCH_STAGE_KEY, TimeStretchConfig};
+use crate::pipeline::runtime::dsp::time_stretch::pitch::Interpolator;
+use crate::pipeline::runtime::dsp::time_stretch::wsola::{MAX_TEMPO, Wsola};
+
+/// Playback speed and pitch control.
+///
+/// WSOLA changes the tempo by `speed / pitch`, then the interpolator resamples by `pitch`,
+/// which restores the requested tempo and moves the pitch. The stage withholds output
+/// until a segment's worth of input is buffered rather than emitting silence, so it
+/// reports no latency; it reports `speed` as its playback rate instead.
+///
+/// Playback position follows the input WSOLA actually read for each block rather than
+/// the current speed, so audio buffered before a rate change, released on a switch to
+/// the identity setting or drained at the end is counted at the rate it was stretched
+/// with.
+///
+/// At the identity setting the stage passes audio through untouched. Switching to or
+/// from it splices at a segment boundary, so neither direction clicks.
+#[derive(Debug)]
+pub(crate) struct TimeStretchStage {
+    config: TimeStretchConfig,
+    channels: usize,
+    wsola: Option<Wsola>,
+    interpolator: Interpolator,
+    /// Whether audio currently runs through WSOLA and the interpolator.
+    active: bool,
+    /// Set by `flush`; the next empty block receives the buffered tail.
+    draining: bool,
+    stretched: Vec<f32>,
+    /// Ping-pong partner of the block buffer.
+    scratch: Vec<f32>,
+    /// Input frames taken in but not yet accounted to an emitted block.
+    pending_source_frames: f64,
+    /// Fraction of a source frame left over from rounding earlier blocks.
+    source_remainder: f64,
+    /// Source frames behind the last processed block.
+    block_source_frames: u64,
+}
+
+impl Default for TimeStretchStage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeStretchStage {
+    pub(crate) fn new() -> Self {
+        Self {
+            config: TimeStretchConfig::default(),
+            channels: 0,
+            wsola: None,
+            interpolator: Interpolator::new(1, 0),
+            active: false,
+            draining: false,
+            stretched: Vec::new(),
+            scratch: Vec::new(),
+            pending_source_frames: 0.0,
+            source_remainder: 0.0,
+            block_source_frames: 0,
+        }
+    }
+
+    fn reset_state(&mut self) {
+        if let Some(wsola) = self.wsola.as_mut() {
+            wsola.reset();
+        }
+        self.interpolator.reset();
+        self.active = false;
+        self.draining = false;
+        self.reset_source_accounting();
+    }
+
+    fn reset_source_accounting(&mut self) {
+        self.pending_source_frames = 0.0;
+        self.source_remainder = 0.0;
+        self.block_source_frames = 0;
+    }
+
+    /// Accounts `frames` of the pending input to the block being emitted, carrying the
+    /// fraction over to the next block.
+    fn emit_source_frames(&mut self, frames: f64) {
+        let total = frames + self.source_remainder;
+        let whole = total.max(0.0).floor();
+        self.source_remainder = total - whole;
+        self.pending_source_frames = (self.pending_source_frames - frames).max(0.0);
+        self.block_source_frames = whole as u64;
+    }
+
+    /// Accounts all pending input to the block being emitted, which empties the buffers.
+    fn emit_pending_source_frames(&mut self) {
+        let total = self.pending_source_frames + self.source_remainder;
+        self.block_source_frames = total.max(0.0).round() as u64;
+        self.pending_source_frames = 0.0;
+        self.source_remainder = 0.0;
+    }
+}
+
+impl TransformStage for TimeStretchStage {
+    fn stage_key(&self) -> Option<&str> {
+        Some(TIME_STRETCH_STAGE_KEY)
+    }
+
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
+    fn playback_rate(&self) -> f64 {
+        f64::from(self.config.speed)
+    }
+
+    fn source_frames(&self, _output_frames: u64) -> u64 {
+        self.block_source_frames
+    }
+
+    fn apply_control(
+        &mut self,
+        control: &dyn Any,
+        _ctx: &mut PipelineContext,
+    ) -> Result<bool, PipelineError> {
+        let Some(config) = control.downcast_ref::<TimeStretchConfig>() else {
+            return Ok(false);
+        };
+        self.config = config.clamped();
+        Ok(true)
+    }
+
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        self.channels = usize::from(spec.channels.max(1));
+        let wsola = Wsola::new(spec.sample_rate.max(1), self.channels);
+        // Slowest output is four times the input: half speed, an octave down.
+        let reserved_frames = wsola.reserved_frames() * 4;
+        self.interpolator = Interpolator::new(self.channels, reserved_frames);
+        self.stretched = Vec::with_capacity(reserved_frames * self.channels);
+        self.scratch = Vec::with_capacity(reserved_frames * self.channels);
+        self.wsola = Some(wsola);
+        self.active = false;
+        self.draining = false;
+        self.reset_source_accounting();
+        Ok(spec)
+    }
+
+    fn sync_runtime_control(&mut self, ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        if ctx.pending_seek_ms.is_some() {
+            self.reset_state();
+        }
+        Ok(())
+    }
+
+    fn process(&mut self, block: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        self.block_source_frames = block.frames() as u64;
+        let Some(wsola) = self.wsola.as_mut() else {
+            return StageStatus::Ok;
+        };
+        let pitch_ratio = self.config.pitch_ratio();
+        let tempo = (f64::from(self.config.speed) / pitch_ratio).min(MAX_TEMPO);
+        if block.is_empty() {
+            if !std::mem::take(&mut self.draining) || !self.active {
+                return StageStatus::Ok;
+            }
+            self.active = false;
+            self.stretched.clear();
+            self.scratch.clear();
+            wsola.drain(tempo, &mut self.stretched);
+            self.interpolator
+                .process(&self.stretched, pitch_ratio, &mut self.scratch);
+            self.interpolator.drain(pitch_ratio, &mut self.scratch);
+            block.channels = self.channels as u16;
+            block.swap_samples(&mut self.scratch);
+            self.emit_pending_source_frames();
+            return StageStatus::Ok;
+        }
+        if usize::from(block.channels) != self.channels {
+            return StageStatus::Ok;
+        }
+
+        self.scratch.clear();
+        if self.config.is_identity() {
+            if !self.active {
+                return StageStatus::Ok;
+            }
+            // Hand back what is buffered at the original speed, then pass through.
+            self.active = false;
+            self.interpolator.release(&mut self.scratch);
+            wsola.release(&mut self.scratch);
+            self.scratch.extend_from_slice(&block.samples);
+            self.pending_source_frames += block.frames() as f64;
+            self.emit_pending_source_frames();
+        } else {
+            self.active = true;
+            self.stretched.clear();
+            wsola.push(&block.samples);
+            let consumed = wsola.process(tempo, &mut self.stretched);
+            self.interpolator
+                .process(&self.stretched, pitch_ratio, &mut self.scratch);
+            self.pending_source_frames += block.frames() as f64;
+            self.emit_source_frames(consumed);
+        }
+        block.swap_samples(&mut self.scratch);
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        self.draining = self.active;
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        self.reset_state();
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../../tests/pipeline/time_stretch.rs"]
+mod tests;
@@ -0,0 +1,247 @@
+//! Waveform-similarity overlap-add (WSOLA) time stretching.
+//!
+//! Output is assembled from Hann-windowed input segments that overlap by half. Each
+//! segment is taken near its nominal input position, shifted within a small search range
+//! to where it best continues the previous segment, so tempo changes while pitch and
+//! waveform shape stay intact.
+
+use std::f64::consts::PI;
+
+/// Segment length; long enough for low voices, short enough to keep transients tight.
+const SEGMENT_MS: u32 = 40;
+/// How far a segment may move from its nominal position to find a good splice.
+const SEARCH_MS: u32 = 12;
+/// Correlation reads every n-th frame; splice quality barely changes, the cost halves.
+const CORRELATION_STRIDE: usize = 2;
+/// Fastest tempo the input buffer is sized for up front.
+pub(super) const MAX_TEMPO: f64 = 6.0;
+/// Largest decoder block the input buffer is sized for up front.
+const RESERVED_BLOCK_FRAMES: usize = 8_192;
+
+#[derive(Debug)]
+pub(super) struct Wsola {
+    channels: usize,
+    segment: usize,
+    hop: usize,
+    search: usize,
+    /// Periodic Hann window; its halves sum to one, so aligned segments add up to the
+    /// input exactly.
+    window: Vec<f32>,
+    /// Pending interleaved input.
+    input: Vec<f32>,
+    /// Nominal start of the next segment, in frames into `input`.
+    analysis_pos: f64,
+    /// Where the previous segment continues naturally, in frames into `input`.
+    natural: usize,
+    /// Falling half of the previous segment, waiting for the next one to overlap it.
+    tail: Vec<f32>,
+    primed: bool,
+    mono_candidates: Vec<f32>,
+    mono_target: Vec<f32>,
+    reserved_frames: usize,
+}
+
+impl Wsola {
+    pub(super) fn new(sample_rate: u32, channels: usize) -> Self {
+        let hop = ((u64::from(sample_rate) * u64::from(SEGMENT_MS) / 2_000) as usize).max(1);
+        let segment = hop * 2;
+        let search = (u64::from(sample_rate) * u64::from(SEARCH_MS) / 1_000) as usize;
+        let window = (0..segment)
+            .map(|n| (0.5 - 0.5 * (2.0 * PI * n as f64 / segment as f64).cos()) as f32)
+            .collect();
+        let max_analysis_hop = (hop as f64 * MAX_TEMPO).ceil() as usize;
+        let reserved_frames = segment + 2 * search + max_analysis_hop + RESERVED_BLOCK_FRAMES;
+        Self {
+            channels,
+            segment,
+            hop,
+            search,
+            window,
+            input: Vec::with_capacity(reserved_frames * channels),
+            analysis_pos: 0.0,
+            natural: 0,
+            tail: vec![0.0; hop * channels],
+            primed: false,
+            mono_candidates: Vec::with_capacity(2 * search + segment),
+            mono_target: Vec::with_capacity(hop),
+            reserved_frames,
+        }
+    }
+
+    /// Input frames buffered at most in steady state, for sizing downstream buffers.
+    pub(super) fn reserved_frames(&self) -> usize {
+        self.reserved_frames
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.input.clear();
+        self.analysis_pos = 0.0;
+        self.natural = 0;
+        self.tail.fill(0.0);
+        self.primed = false;
+    }
+
+    fn frames(&self) -> usize {
+        self.input.len() / self.channels
+    }
+
+    pub(super) fn push(&mut self, samples: &[f32]) {
+        self.input.extend_from_slice(samples);
+    }
+
+    /// Stretches as much pending input as possible and appends the result to `output`.
+    ///
+    /// `tempo` is input frames consumed per output frame. Returns the input frames the
+    /// appended output stands for, i.e. how far the analysis position moved.
+    pub(super) fn process(&mut self, tempo: f64, output: &mut Vec<f32>) -> f64 {
+        let start = self.analysis_pos;
+        while self.next_hop(tempo, output) {}
+        let consumed = self.analysis_pos - start;
+        self.discard_consumed();
+        consumed
+    }
+
+    /// Stretches the remaining input against trailing silence, then releases the last
+    /// segment.
+    ///
+    /// Output so far matches the analysis position divided by `tempo`, so the remainder
+    /// owes exactly the unread input divided by `tempo`; hops are cut to that length.
+    pub(super) fn drain(&mut self, tempo: f64, output: &mut Vec<f32>) {
+        let end = self.frames();
+        let owed = ((end as f64 - self.analysis_pos).max(0.0) / tempo).round() as usize;
+        let start = output.len();
+        let target = start + owed * self.channels;
+        while output.len() < target {
+            let needed = self.required_frames(self.analysis_pos.round() as usize);
+            if self.frames() < needed {
+                self.input.resize(needed * self.channels, 0.0);
+            }
+            self.next_hop(tempo, output);
+        }
+        output.truncate(target);
+        self.reset();
+    }
+
+    /// Appends the unstretched remainder of the input, continuing seamlessly from the
+    /// last emitted hop, and resets.
+    ///
+    /// The pending falling half plus the rising half of the natural continuation is the
+    /// input itself, so handing back the input from there is an exact splice.
+    pub(super) fn release(&mut self, output: &mut Vec<f32>) {
+        let start = if self.primed { self.natural } else { 0 };
+        output.extend_from_slice(&self.input[start * self.channels..]);
+        self.reset();
+    }
+
+    fn required_frames(&self, nominal: usize) -> usize {
+        (nominal + self.search + self.segment).max(self.natural + self.hop)
+    }
+
+    /// Emits one hop of output when enough input is buffered.
+    fn next_hop(&mut self, tempo: f64, output: &mut Vec<f32>) -> bool {
+        let nominal = self.analysis_pos.round() as usize;
+        if self.frames() < self.required_frames(nominal) {
+            return false;
+        }
+        let start = if self.primed {
+            self.best_splice(nominal)
+        } else {
+            // Pretend a previous segment ended exactly here so the first hop passes the
+            // input through instead of fading in.
+            self.natural = nominal;
+            for (index, tail) in self.tail.iter_mut().enumerate() {
+                let frame = index / self.channels;
+                *tail = self.input[nominal * self.channels + index] * self.window[self.hop + frame];
+            }
+            self.primed = true;
+            nominal
+        };
+
+        let channels = self.channels;
+        let rising = &self.input[start * channels..(start + self.hop) * channels];
+        let falling = &self.input[(start + self.hop) * channels..(start + self.segment) * channels];
+        for (index, (tail, sample)) in self.tail.iter_mut().zip(rising).enumerate() {
+            let frame = index / channels;
+            output.push(*tail + sample * self.window[frame]);
+        }
+        for (index, (tail, sample)) in self.tail.iter_mut().zip(falling).enumerate() {
+            let frame = index / channels;
+            *tail = sample * self.window[self.hop + frame];
+        }
+        self.natural = start + self.hop;
+        self.analysis_pos += self.hop as f64 * tempo;
+        true
+    }
+
+    /// Finds the segment start near `nominal` whose opening best matches the natural
+    /// continuation of the previous segment, by normalized cross-correlation of the
+    /// channel sum.
+    fn best_splice(&mut self, nominal: usize) -> usize {
+        let first = nominal.saturating_sub(self.search);
+        let last = nominal + self.search;
+        let channels = self.channels;
+        let mono = |input: &[f32], frame: usize| -> f32 {
+            input[frame * channels..(frame + 1) * channels].iter().sum()
+        };
+        self.mono_target.clear();
+        self.mono_target.extend(
+            (self.natural..self.natural + self.hop)
+                .step_by(CORRELATION_STRIDE)
+                .map(|frame| mono(&self.input, frame)),
+        );
+        self.mono_candidates.clear();
+        self.mono_candidates
+            .extend((first..last + self.hop).map(|frame| mono(&self.input, frame)));
+
+        let target = &self.mono_target;
+        let candidates = &self.mono_candidates;
+        let score = |offset: usize| -> f32 {
+            let mut dot = 0.0;
+            let mut energy = 1e-9;
+            for (t, c) in target
+                .iter()
+                .zip(candidates[offset..].iter().step_by(CORRELATION_STRIDE))
+            {
+                dot += t * c;
+                energy += c * c;
+            }
+            dot / energy.sqrt()
+        };
+        // The natural continuation is tried first so ties keep it; at tempo 1 that makes
+        // the stretch an exact pass-through. Then a coarse scan on every other lag, refined
+        // around the winner.
+        let span = last - first;
+        let mut best = (0, f32::NEG_INFINITY);
+        if (first..=last).contains(&self.natural) {
+            let offset = self.natural - first;
+            best = (offset, score(offset));
+        }
+        for offset in (0..=span).step_by(2) {
+            let value = score(offset);
+            if value > best.1 {
+                best = (offset, value);
+            }
+        }
+        let coarse = best.0;
+        for offset in [coarse.saturating_sub(1), (coarse + 1).min(span)] {
+            let value = score(offset);
+            if value > best.1 {
+                best = (offset, value);
+            }
+        }
+        first + best.0
+    }
+
+    /// Drops input that no future segment can reach.
+    fn discard_consumed(&mut self) {
+        let reachable = (self.analysis_pos.floor() as usize).saturating_sub(self.search);
+        let drop = reachable.min(self.natural).min(self.frames());
+        if drop == 0 {
+            return;
+        }
+        self.input.copy_within(drop * self.channels.., 0);
+        self.input.truncate(self.input.len() - drop * self.channels);
+        self.natural -= drop;
+        self.analysis_pos -= drop as f64;
+    }
+}
@@ -134,6 +134,10 @@ impl TransformStage for TransitionGainStage {
         Some(TRANSITION_GAIN_STAGE_KEY)
     }
 
+    fn no_alloc_hot_path(&self) -> bool {
+        true
+    }
+
     fn apply_control(
         &mut self,
         control: &dyn Any,
@@ -131,13 +131,28 @@ impl PipelineRunner {
         } else {
             0
         };
+        let playback_rate = self.playback_rate();
         let hint = self.decoder.estimated_remaining_frames().map(|frames| {
             let playable_decoder_frames = frames.saturating_sub(tail_frames);
-            self.scale_decoder_frames_to_output_domain(playable_decoder_frames)
+            let output_frames = self.scale_decoder_frames_to_output_domain(playable_decoder_frames);
+            if playback_rate == 1.0 {
+                output_frames
+            } else {
+                (output_frames as f64 / playback_rate) as u64
+            }
         });
         self.playable_remaining_frames_hint = hint;
     }
 
+    /// Combined playback rate of the transform chain; see
+    /// [`TransformStage::playback_rate`].
+    pub(crate) fn playback_rate(&self) -> f64 {
+        self.transforms
+            .iter()
+            .map(|transform| transform.playback_rate())
+            .product()
+    }
+
     pub(crate) fn apply_gapless_trim_control(
         &mut self,
         ctx: &mut PipelineContext,
@@ -0,0 +1,52 @@
+//! Debug-build allocation checks for transforms that claim an allocation-free hot path.
+//!
+//! Counting only works when the binary installs
+//! [`CountingAllocator`](stellatune_audio_core::pipeline::alloc_guard::CountingAllocator)
+//! as its global allocator. Debug builds of the FFI library, this crate's tests and the
+//! `dsp_hot_path` bench do; release builds do not, and every scope there reports zero.
+
+use tracing::warn;
+
+/// Blocks processed after decode preparation before allocations count as violations.
+/// Stages size their scratch buffers from the first blocks they see.
+const WARMUP_BLOCKS: u64 = 64;
+
+#[derive(Debug, Default)]
+pub(crate) struct HotPathAllocGuard {
+    blocks: u64,
+    reported_stages: Vec<usize>,
+}
+
+impl HotPathAllocGuard {
+    pub(crate) fn reset(&mut self) {
+        self.blocks = 0;
+        self.reported_stages.clear();
+    }
+
+    pub(crate) fn begin_block(&mut self) {
+        self.blocks = self.blocks.saturating_add(1);
+    }
+
+    /// Records the allocations one transform made for the current block and warns once
+    /// per stage when a steady-state block allocated.
+    pub(crate) fn observe(
+        &mut self,
+        stage_index: usize,
+        stage_key: Option<&str>,
+        allocations: u64,
+    ) {
+        if allocations == 0
+            || self.blocks <= WARMUP_BLOCKS
+            || self.reported_stages.contains(&stage_index)
+        {
+            return;
+        }
+        self.reported_stages.push(stage_index);
+        warn!(
+            stage_index,
+            stage_key = stage_key.unwrap_or("<unkeyed>"),
+            allocations,
+            "transform allocated on the realtime hot path"
+        );
+    }
+}
@@ -8,6 +8,7 @@ use stellatune_audio_core::pipeline::stages::transform::TransformStage;
 
 use crate::config::engine::{PauseBehavior, StopBehavior};
 use crate::pipeline::assembly::SinkPlan;
+use crate::pipeline::runtime::runner::hot_path::HotPathAllocGuard;
 use crate::pipeline::runtime::runner::{PipelineRunner, RunnerState};
 use crate::pipeline::runtime::sink_session::{SinkActivationMode, SinkSession};
 
@@ -32,12 +33,16 @@ impl PipelineRunner {
             sink_plan: Some(sink_plan),
             sink_route_fingerprint,
             pending_sink_block: None,
+            pending_sink_source_frames: 0,
             source_handle: None,
             decoder_spec: None,
             output_spec: None,
             decoder_gapless_trim_spec: None,
             playable_remaining_frames_hint: None,
             transform_control_routes,
+            hot_path_guard: HotPathAllocGuard::default(),
+            transform_latency_frames: 0,
+            latency_frames_to_skip: 0,
             #[cfg(test)]
             transition_request_log_sink: None,
             state: RunnerState::Stopped,
@@ -60,15 +65,28 @@ impl PipelineRunner {
         let decoder_gapless_trim_spec =
             Self::normalize_gapless_trim_spec(self.decoder.current_gapless_trim_spec());
         let mut spec = decoder_spec;
+        let mut latencies = Vec::new();
         for transform in &mut self.transforms {
             spec = transform.prepare(spec, ctx)?.validate()?;
+            let latency = transform.latency_frames();
+            if latency > 0 {
+                latencies.push((latency, spec.sample_rate));
+            }
         }
+        // Rescale each stage's latency from its own output rate to the final one.
+        let transform_latency_frames = latencies
+            .into_iter()
+            .map(|(frames, rate)| frames * u64::from(spec.sample_rate) / u64::from(rate))
+            .sum();
 
         self.source_handle = Some(source_handle);
         self.decoder_spec = Some(decoder_spec);
         self.output_spec = Some(spec);
         self.decoder_gapless_trim_spec = decoder_gapless_trim_spec;
         self.pending_sink_block = None;
+        self.hot_path_guard.reset();
+        self.transform_latency_frames = transform_latency_frames;
+        self.latency_frames_to_skip = transform_latency_frames;
         self.apply_gapless_trim_control(ctx)?;
         self.refresh_playable_remaining_frames_hint();
         Ok(spec)
@@ -34,8 +34,10 @@ use stellatune_audio_core::pipeline::stages::source::SourceStage;
 use stellatune_audio_core::pipeline::stages::transform::TransformStage;
 
 use crate::pipeline::assembly::SinkPlan;
+use crate::pipeline::runtime::runner::hot_path::HotPathAllocGuard;
 
 mod control;
+mod hot_path;
 mod lifecycle;
 mod step;
 
@@ -65,12 +67,20 @@ pub(crate) struct PipelineRunner {
     sink_plan: Option<Box<dyn SinkPlan>>,
     sink_route_fingerprint: u64,
     pending_sink_block: Option<AudioBlock>,
+    /// Source frames behind `pending_sink_block`, captured when it was produced.
+    pending_sink_source_frames: u64,
     source_handle: Option<SourceHandle>,
     decoder_spec: Option<StreamSpec>,
     output_spec: Option<StreamSpec>,
     decoder_gapless_trim_spec: Option<GaplessTrimSpec>,
     playable_remaining_frames_hint: Option<u64>,
     transform_control_routes: HashMap<String, usize>,
+    hot_path_guard: HotPathAllocGuard,
+    /// Combined transform latency in output frames, as reported after preparation.
+    transform_latency_frames: u64,
+    /// Output frames still carrying transform latency rather than track audio; they do
+    /// not advance the playback position.
+    latency_frames_to_skip: u64,
     #[cfg(test)]
     transition_request_log_sink: Option<Arc<Mutex<Vec<GainTransitionRequest>>>>,
     state: RunnerState,
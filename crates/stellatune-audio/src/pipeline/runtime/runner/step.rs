@@ -1,9 +1,11 @@
 //! Runner step and drain execution helpers.
 
+use stellatune_audio_core::pipeline::alloc_guard::AllocationScope;
 use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
 use stellatune_audio_core::pipeline::error::PipelineError;
 use stellatune_audio_core::pipeline::stages::StageStatus;
 
+use crate::pipeline::runtime::dsp::chain_source_frames;
 use crate::pipeline::runtime::runner::{
     MAX_DRAIN_TAIL_ITERATIONS, MAX_PENDING_SINK_FLUSH_ATTEMPTS, PipelineRunner, RunnerState,
     StepResult,
@@ -29,14 +31,17 @@ impl PipelineRunner {
         self.sync_runtime_control(sink_session, ctx)?;
         if let Some(seek_ms) = ctx.clear_pending_seek() {
             ctx.position_ms = seek_ms;
+            // Stages with latency restart from silence after a seek.
+            self.latency_frames_to_skip = self.transform_latency_frames;
         }
         self.refresh_playable_remaining_frames_hint();
 
         let out_spec = self.output_spec.ok_or(PipelineError::NotPrepared)?;
         if let Some(block) = self.pending_sink_block.take() {
-            return self.try_push_sink_block(sink_session, block, out_spec, ctx);
+            let source_frames = self.pending_sink_source_frames;
+            return self.try_push_sink_block(sink_session, block, source_frames, out_spec, ctx);
         }
-        let mut block = AudioBlock::new(out_spec.channels);
+        let mut block = sink_session.acquire_block(out_spec.channels);
 
         match self.decoder.next_block(&mut block, ctx) {
             StageStatus::Ok => {},
@@ -56,11 +61,20 @@ impl PipelineRunner {
         }
         self.refresh_playable_remaining_frames_hint();
         if block.is_empty() {
+            sink_session.recycle_block(block);
             return Ok(StepResult::Idle);
         }
 
-        for transform in &mut self.transforms {
-            match transform.process(&mut block, ctx) {
+        self.hot_path_guard.begin_block();
+        for (index, transform) in self.transforms.iter_mut().enumerate() {
+            let scope = (cfg!(debug_assertions) && transform.no_alloc_hot_path())
+                .then(AllocationScope::enter);
+            let status = transform.process(&mut block, ctx);
+            if let Some(allocations) = scope.map(|scope| scope.allocations()) {
+                self.hot_path_guard
+                    .observe(index, transform.stage_key(), allocations);
+            }
+            match status {
                 StageStatus::Ok => {},
                 StageStatus::Eof => {
                     self.playable_remaining_frames_hint = Some(0);
@@ -72,10 +86,12 @@ impl PipelineRunner {
             }
         }
         if block.is_empty() {
+            sink_session.recycle_block(block);
             return Ok(StepResult::Idle);
         }
 
-        self.try_push_sink_block(sink_session, block, out_spec, ctx)
+        let source_frames = chain_source_frames(&self.transforms, block.frames() as u64);
+        self.try_push_sink_block(sink_session, block, source_frames, out_spec, ctx)
     }
 
     /// Flushes decoder and transform tails, then drains sink queued audio.
@@ -98,24 +114,36 @@ impl PipelineRunner {
 
     /// Attempts to push one block into sink session queue.
     ///
-    /// On full queue, the block is retained as pending and the step reports idle.
+    /// `source_frames` is the source audio behind the block, captured when the
+    /// transforms produced it. On full queue, the block is retained as pending and the
+    /// step reports idle.
     fn try_push_sink_block(
         &mut self,
         sink_session: &mut SinkSession,
         block: AudioBlock,
+        source_frames: u64,
         out_spec: StreamSpec,
         ctx: &mut PipelineContext,
     ) -> Result<StepResult, PipelineError> {
         let produced_frames = block.frames();
         match sink_session.try_send_block(block) {
             Ok(()) => {
-                ctx.advance_frames(produced_frames as u64, out_spec.sample_rate);
+                let latency_frames = self.latency_frames_to_skip.min(produced_frames as u64);
+                self.latency_frames_to_skip -= latency_frames;
+                let played_source_frames = if latency_frames == 0 {
+                    source_frames
+                } else {
+                    let played_frames = produced_frames as u64 - latency_frames;
+                    source_frames * played_frames / produced_frames as u64
+                };
+                ctx.advance_frames(played_source_frames, out_spec.sample_rate);
                 Ok(StepResult::Produced {
                     frames: produced_frames,
                 })
             },
             Err(SinkWriteError::Full(block)) => {
                 self.pending_sink_block = Some(block);
+                self.pending_sink_source_frames = source_frames;
                 Ok(StepResult::Idle)
             },
             Err(SinkWriteError::Disconnected) => Err(PipelineError::SinkDisconnected),
@@ -131,7 +159,8 @@ impl PipelineRunner {
     ) -> Result<(), PipelineError> {
         let mut attempts = 0usize;
         while let Some(block) = self.pending_sink_block.take() {
-            match self.try_push_sink_block(sink_session, block, out_spec, ctx)? {
+            let source_frames = self.pending_sink_source_frames;
+            match self.try_push_sink_block(sink_session, block, source_frames, out_spec, ctx)? {
                 StepResult::Produced { .. } => {},
                 StepResult::Idle => {
                     attempts = attempts.saturating_add(1);
@@ -156,7 +185,7 @@ impl PipelineRunner {
         ctx: &mut PipelineContext,
     ) -> Result<(), PipelineError> {
         for _ in 0..MAX_DRAIN_TAIL_ITERATIONS {
-            let mut block = AudioBlock::new(out_spec.channels);
+            let mut block = sink_session.acquire_block(out_spec.channels);
             for transform in &mut self.transforms {
                 match transform.process(&mut block, ctx) {
                     StageStatus::Ok => {},
@@ -168,10 +197,12 @@ impl PipelineRunner {
             }
 
             if block.is_empty() {
+                sink_session.recycle_block(block);
                 break;
             }
 
-            match self.try_push_sink_block(sink_session, block, out_spec, ctx)? {
+            let source_frames = chain_source_frames(&self.transforms, block.frames() as u64);
+            match self.try_push_sink_block(sink_session, block, source_frames, out_spec, ctx)? {
                 StepResult::Produced { .. } => {},
                 StepResult::Idle => {
                     sink_session.drain()?;
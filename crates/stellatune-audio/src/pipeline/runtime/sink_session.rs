@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
 use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::pool::AudioBlockPool;
 
 use crate::config::sink::SinkLatencyConfig;
 use crate::pipeline::assembly::SinkPlan;
@@ -23,16 +24,21 @@ pub(crate) struct SinkSession {
     sink_route_fingerprint: Option<u64>,
     sink_latency: SinkLatencyConfig,
     sink_control_timeout: Duration,
+    block_pool: AudioBlockPool,
 }
 
 impl SinkSession {
     pub(crate) fn new(sink_latency: SinkLatencyConfig, sink_control_timeout: Duration) -> Self {
+        // Queued blocks plus the pending block, the one being decoded, and the one the
+        // sink thread is writing.
+        let block_pool = AudioBlockPool::new(sink_latency.max_queue_blocks.max(1) + 3);
         Self {
             sink_worker: None,
             sink_spec: None,
             sink_route_fingerprint: None,
             sink_latency,
             sink_control_timeout,
+            block_pool,
         }
     }
 
@@ -80,13 +86,29 @@ impl SinkSession {
             .ok_or_else(|| PipelineError::StageFailure("sink plan already consumed".to_string()))?;
         let sinks = sink_plan.into_sinks()?;
         let queue_capacity = self.sink_latency.queue_capacity(spec.sample_rate);
-        let sink_worker = SinkWorker::start(sinks, spec, ctx.clone(), queue_capacity)?;
+        let sink_worker = SinkWorker::start(
+            sinks,
+            spec,
+            ctx.clone(),
+            queue_capacity,
+            self.block_pool.clone(),
+        )?;
         self.sink_worker = Some(sink_worker);
         self.sink_spec = Some(spec);
         self.sink_route_fingerprint = Some(route_fingerprint);
         Ok(false)
     }
 
+    /// Returns an empty block backed by a buffer the sink thread already released.
+    pub(crate) fn acquire_block(&self, channels: u16) -> AudioBlock {
+        self.block_pool.acquire(channels)
+    }
+
+    /// Returns a block that never reached the sink to the pool.
+    pub(crate) fn recycle_block(&self, block: AudioBlock) {
+        self.block_pool.recycle(block);
+    }
+
     pub(crate) fn try_send_block(&mut self, block: AudioBlock) -> Result<(), SinkWriteError> {
         let worker = self
             .sink_worker
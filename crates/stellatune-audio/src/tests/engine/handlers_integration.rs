@@ -6,11 +6,13 @@ use stellatune_audio_core::pipeline::context::InputRef;
 use stellatune_audio_core::pipeline::error::PipelineError;
 use stellatune_runtime::thread_actor::{ActorRef, spawn_actor_named};
 
-use crate::config::engine::{EngineConfig, LfeMode, PlayerState, ResampleQuality, StopBehavior};
+use crate::config::engine::{
+    ClockMode, EngineConfig, LfeMode, PlayerState, ResampleQuality, StopBehavior,
+};
 use crate::engine::actor::ControlActor;
 use crate::engine::messages::{
     ApplyPipelineMutationMessage, ApplyStageControlMessage, GetSnapshotMessage,
-    InstallDecodeWorkerMessage, OnDecodeWorkerEventMessage, SetLfeModeMessage,
+    InstallDecodeWorkerMessage, OnDecodeWorkerEventMessage, SetClockModeMessage, SetLfeModeMessage,
     SetResampleQualityMessage, ShutdownMessage, StopMessage,
 };
 use crate::error::DecodeError;
@@ -247,6 +249,25 @@ fn set_resample_quality_message_forwards_to_decode_worker() {
     shutdown_and_join(actor_ref, join);
 }
 
+#[test]
+fn set_clock_mode_message_forwards_to_decode_worker() {
+    let config = test_config();
+    let (actor_ref, join) = spawn_control_actor(config.clone());
+    install_decode_worker(&actor_ref, &config);
+
+    actor_ref
+        .call(
+            SetClockModeMessage {
+                mode: ClockMode::FreeRunning,
+            },
+            TEST_TIMEOUT,
+        )
+        .expect("failed to call set clock mode")
+        .expect("set clock mode failed");
+
+    shutdown_and_join(actor_ref, join);
+}
+
 #[test]
 fn apply_stage_control_message_reaches_decode_worker() {
     let config = test_config();
@@ -82,6 +82,11 @@ impl DecoderStage for TestDecoder {
         StageStatus::Ok
     }
 
+    fn estimated_remaining_frames(&self) -> Option<u64> {
+        let samples: usize = self.blocks.iter().map(Vec::len).sum();
+        Some((samples / usize::from(self.channels)) as u64)
+    }
+
     fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
         Ok(())
     }
@@ -281,6 +286,107 @@ impl TransformStage for KeyedNoopTransform {
     fn stop(&mut self, _ctx: &mut PipelineContext) {}
 }
 
+struct LatencyTap {
+    latency_frames: u64,
+}
+
+impl TransformStage for LatencyTap {
+    fn latency_frames(&self) -> u64 {
+        self.latency_frames
+    }
+
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        Ok(spec)
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn process(&mut self, _block: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {}
+}
+
+/// Plays mono audio at double speed by dropping every other frame.
+struct DoubleSpeedTap;
+
+impl TransformStage for DoubleSpeedTap {
+    fn playback_rate(&self) -> f64 {
+        2.0
+    }
+
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        Ok(spec)
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn process(&mut self, block: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        block.samples = block.samples.iter().step_by(2).copied().collect();
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {}
+}
+
+/// Reports double speed while playing 4x, the way a stage releases audio it buffered at
+/// another rate, and accounts each block by the source frames it actually consumed.
+struct AccountedSpeedTap;
+
+impl TransformStage for AccountedSpeedTap {
+    fn playback_rate(&self) -> f64 {
+        2.0
+    }
+
+    fn source_frames(&self, output_frames: u64) -> u64 {
+        output_frames * 4
+    }
+
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        Ok(spec)
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn process(&mut self, block: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        block.samples = block.samples.iter().step_by(4).copied().collect();
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {}
+}
+
 #[test]
 fn pre_post_transform_chain_wraps_mixer_and_resampler() {
     let pre_seen = Arc::new(Mutex::new(Vec::new()));
@@ -405,6 +511,10 @@ fn duplicate_stage_keys_are_rejected() {
                 gapless_trim: false,
                 transition_gain: false,
                 master_gain: false,
+                crossfeed: false,
+                convolution: false,
+                dither: false,
+                time_stretch: false,
             },
         },
         Box::new(StaticSinkPlan::new(vec![Box::new(TestSink)])),
@@ -419,3 +529,142 @@ fn duplicate_stage_keys_are_rejected() {
         Err(other) => panic!("unexpected error: {other:?}"),
     }
 }
+
+#[test]
+fn transform_latency_is_not_counted_as_playback_position() {
+    let blocks = vec![vec![0.0; 300], vec![0.0; 300], vec![0.0; 300]];
+    let assembled = AssembledPipeline::from_parts(
+        AssembledDecodePipeline {
+            source: Box::new(TestSource),
+            decoder: Box::new(TestDecoder::new(blocks, 1, 1_000)),
+            transforms: vec![Box::new(LatencyTap {
+                latency_frames: 100,
+            })],
+            transform_chain: TransformChain::default(),
+            mixer: None,
+            resampler: None,
+            builtin_slots: BuiltinTransformSlots {
+                gapless_trim: false,
+                ..BuiltinTransformSlots::default()
+            },
+        },
+        Box::new(StaticSinkPlan::new(vec![Box::new(TestSink)])),
+    );
+
+    let mut runner = assembled.into_runner(None).expect("into_runner failed");
+    let mut sink_session =
+        SinkSession::new(SinkLatencyConfig::default(), Duration::from_millis(100));
+    let mut ctx = PipelineContext::default();
+    runner
+        .prepare_decode(&InputRef::TrackToken("track-a".to_string()), &mut ctx)
+        .expect("prepare_decode failed");
+    runner
+        .activate_sink(
+            &mut sink_session,
+            &ctx,
+            SinkActivationMode::ImmediateCutover,
+        )
+        .expect("activate_sink failed");
+    runner.set_state(RunnerState::Playing);
+
+    for _ in 0..2 {
+        assert!(matches!(
+            runner.step(&mut sink_session, &mut ctx),
+            Ok(StepResult::Produced { frames: 300 })
+        ));
+    }
+    assert_eq!(ctx.position_ms, 500);
+
+    ctx.request_seek(10_000);
+    assert!(matches!(
+        runner.step(&mut sink_session, &mut ctx),
+        Ok(StepResult::Produced { frames: 300 })
+    ));
+    assert_eq!(ctx.position_ms, 10_200);
+}
+
+#[test]
+fn playback_rate_scales_position_and_remaining_frames() {
+    let blocks = vec![vec![0.0; 600], vec![0.0; 600], vec![0.0; 600]];
+    let assembled = AssembledPipeline::from_parts(
+        AssembledDecodePipeline {
+            source: Box::new(TestSource),
+            decoder: Box::new(TestDecoder::new(blocks, 1, 1_000)),
+            transforms: vec![Box::new(DoubleSpeedTap)],
+            transform_chain: TransformChain::default(),
+            mixer: None,
+            resampler: None,
+            builtin_slots: BuiltinTransformSlots {
+                gapless_trim: false,
+                ..BuiltinTransformSlots::default()
+            },
+        },
+        Box::new(StaticSinkPlan::new(vec![Box::new(TestSink)])),
+    );
+
+    let mut runner = assembled.into_runner(None).expect("into_runner failed");
+    let mut sink_session =
+        SinkSession::new(SinkLatencyConfig::default(), Duration::from_millis(100));
+    let mut ctx = PipelineContext::default();
+    runner
+        .prepare_decode(&InputRef::TrackToken("track-a".to_string()), &mut ctx)
+        .expect("prepare_decode failed");
+    runner
+        .activate_sink(
+            &mut sink_session,
+            &ctx,
+            SinkActivationMode::ImmediateCutover,
+        )
+        .expect("activate_sink failed");
+    runner.set_state(RunnerState::Playing);
+    assert_eq!(runner.playable_remaining_frames_hint(), Some(900));
+
+    assert!(matches!(
+        runner.step(&mut sink_session, &mut ctx),
+        Ok(StepResult::Produced { frames: 300 })
+    ));
+    assert_eq!(ctx.position_ms, 600);
+    assert_eq!(runner.playable_remaining_frames_hint(), Some(600));
+}
+
+#[test]
+fn position_follows_reported_source_frames_over_playback_rate() {
+    let blocks = vec![vec![0.0; 600], vec![0.0; 600]];
+    let assembled = AssembledPipeline::from_parts(
+        AssembledDecodePipeline {
+            source: Box::new(TestSource),
+            decoder: Box::new(TestDecoder::new(blocks, 1, 1_000)),
+            transforms: vec![Box::new(AccountedSpeedTap)],
+            transform_chain: TransformChain::default(),
+            mixer: None,
+            resampler: None,
+            builtin_slots: BuiltinTransformSlots {
+                gapless_trim: false,
+                ..BuiltinTransformSlots::default()
+            },
+        },
+        Box::new(StaticSinkPlan::new(vec![Box::new(TestSink)])),
+    );
+
+    let mut runner = assembled.into_runner(None).expect("into_runner failed");
+    let mut sink_session =
+        SinkSession::new(SinkLatencyConfig::default(), Duration::from_millis(100));
+    let mut ctx = PipelineContext::default();
+    runner
+        .prepare_decode(&InputRef::TrackToken("track-a".to_string()), &mut ctx)
+        .expect("prepare_decode failed");
+    runner
+        .activate_sink(
+            &mut sink_session,
+            &ctx,
+            SinkActivationMode::ImmediateCutover,
+        )
+        .expect("activate_sink failed");
+    runner.set_state(RunnerState::Playing);
+
+    assert!(matches!(
+        runner.step(&mut sink_session, &mut ctx),
+        Ok(StepResult::Produced { frames: 150 })
+    ));
+    assert_eq!(ctx.position_ms, 600);
+}
@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+use crate::config::convolution::{ConvolutionControl, ImpulseLayout, ImpulseResponse};
+use crate::pipeline::runtime::dsp::convolution::ConvolutionStage;
+use crate::pipeline::runtime::dsp::convolution::partitioned::PARTITION_FRAMES;
+
+const TOLERANCE: f32 = 1e-4;
+
+fn prepared_stage(
+    impulse: Option<ImpulseResponse>,
+    sample_rate: u32,
+    channels: u16,
+) -> (ConvolutionStage, PipelineContext) {
+    let mut stage = ConvolutionStage::new();
+    let mut ctx = PipelineContext::default();
+    if let Some(impulse) = impulse {
+        let handled = stage
+            .apply_control(&ConvolutionControl::load(Arc::new(impulse)), &mut ctx)
+            .expect("apply_control failed");
+        assert!(handled);
+    }
+    stage
+        .prepare(
+            StreamSpec {
+                sample_rate,
+                channels,
+            },
+            &mut ctx,
+        )
+        .expect("prepare failed");
+    (stage, ctx)
+}
+
+/// Runs interleaved `input` through the stage in uneven blocks and returns the output.
+fn run(
+    stage: &mut ConvolutionStage,
+    ctx: &mut PipelineContext,
+    channels: u16,
+    input: &[f32],
+) -> Vec<f32> {
+    let mut output = Vec::with_capacity(input.len());
+    let step = 301 * usize::from(channels);
+    for chunk in input.chunks(step) {
+        let mut block = AudioBlock {
+            channels,
+            samples: chunk.to_vec(),
+        };
+        assert_eq!(stage.process(&mut block, ctx), StageStatus::Ok);
+        assert_eq!(block.samples.len(), chunk.len());
+        output.extend_from_slice(&block.samples);
+    }
+    output
+}
+
+fn signal(frames: usize, seed: u32) -> Vec<f32> {
+    let mut state = seed;
+    (0..frames)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 8) as f32 / (1u32 << 24) as f32 - 0.5
+        })
+        .collect()
+}
+
+fn direct_convolution(input: &[f32], taps: &[f32]) -> Vec<f32> {
+    (0..input.len())
+        .map(|n| {
+            taps.iter()
+                .enumerate()
+                .take(n + 1)
+                .map(|(k, tap)| tap * input[n - k])
+                .sum()
+        })
+        .collect()
+}
+
+fn mono(impulse: Vec<f32>, sample_rate: u32) -> ImpulseResponse {
+    ImpulseResponse::new(sample_rate, ImpulseLayout::PerChannel, vec![impulse])
+        .expect("invalid impulse response")
+}
+
+#[test]
+fn passes_through_with_one_partition_of_latency_without_filter() {
+    let (mut stage, mut ctx) = prepared_stage(None, 48_000, 2);
+    assert_eq!(stage.latency_frames(), PARTITION_FRAMES as u64);
+
+    let input = signal(3_000 * 2, 1);
+    let output = run(&mut stage, &mut ctx, 2, &input);
+    let delay = PARTITION_FRAMES * 2;
+    assert!(output[..delay].iter().all(|sample| *sample == 0.0));
+    for (out, expected) in output[delay..].iter().zip(&input) {
+        assert!((out - expected).abs() < TOLERANCE);
+    }
+}
+
+#[test]
+fn matches_direct_convolution_across_partitions() {
+    let taps = signal(1_500, 7);
+    let (mut stage, mut ctx) = prepared_stage(Some(mono(taps.clone(), 48_000)), 48_000, 1);
+
+    let input = signal(4_000, 3);
+    let output = run(&mut stage, &mut ctx, 1, &input);
+    let expected = direct_convolution(&input, &taps);
+    for (out, expected) in output[PARTITION_FRAMES..].iter().zip(&expected) {
+        assert!((out - expected).abs() < 1e-3, "{out} != {expected}");
+    }
+}
+
+#[test]
+fn true_stereo_filters_feed_across_channels() {
+    let mut left_to_right = vec![0.0; 64];
+    left_to_right[10] = 0.5;
+    let mut unit = vec![0.0; 64];
+    unit[0] = 1.0;
+    let impulse = ImpulseResponse::new(
+        48_000,
+        ImpulseLayout::TrueStereo,
+        vec![unit.clone(), left_to_right, vec![0.0; 64], unit],
+    )
+    .expect("invalid impulse response");
+    let (mut stage, mut ctx) = prepared_stage(Some(impulse), 48_000, 2);
+
+    let mut input = vec![0.0; 1_200 * 2];
+    input[0] = 1.0;
+    let output = run(&mut stage, &mut ctx, 2, &input);
+    let left = |frame: usize| output[frame * 2];
+    let right = |frame: usize| output[frame * 2 + 1];
+    assert!((left(PARTITION_FRAMES) - 1.0).abs() < TOLERANCE);
+    assert!((right(PARTITION_FRAMES + 10) - 0.5).abs() < TOLERANCE);
+    assert!(right(PARTITION_FRAMES).abs() < TOLERANCE);
+}
+
+#[test]
+fn resampled_filter_keeps_its_gain() {
+    let mut taps = vec![0.0; 256];
+    taps[128] = 1.0;
+    let (mut stage, mut ctx) = prepared_stage(Some(mono(taps, 44_100)), 48_000, 1);
+
+    let output = run(&mut stage, &mut ctx, 1, &vec![1.0; 8_000]);
+    let settled = output[output.len() - 1];
+    assert!((settled - 1.0).abs() < 0.01, "dc gain {settled}");
+}
+
+#[test]
+fn filter_swap_crossfades_without_jumps() {
+    let (mut stage, mut ctx) = prepared_stage(Some(mono(vec![1.0], 48_000)), 48_000, 1);
+    run(&mut stage, &mut ctx, 1, &vec![1.0; 4_000]);
+
+    let control = ConvolutionControl::load(Arc::new(mono(vec![0.5], 48_000))).with_crossfade_ms(20);
+    assert!(
+        stage
+            .apply_control(&control, &mut ctx)
+            .expect("apply_control failed")
+    );
+    let output = run(&mut stage, &mut ctx, 1, &vec![1.0; 4_000]);
+    let max_step = output
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs())
+        .fold(0.0, f32::max);
+    assert!(max_step < 0.01, "step of {max_step} during crossfade");
+    assert!((output[0] - 1.0).abs() < TOLERANCE);
+    assert!((output[output.len() - 1] - 0.5).abs() < TOLERANCE);
+}
+
+#[test]
+fn flush_drains_delayed_audio_and_filter_tail() {
+    let mut taps = vec![0.0; 700];
+    taps[699] = 1.0;
+    let (mut stage, mut ctx) = prepared_stage(Some(mono(taps, 48_000)), 48_000, 1);
+    let output = run(&mut stage, &mut ctx, 1, &[1.0]);
+    assert_eq!(output, vec![0.0]);
+
+    stage.flush(&mut ctx).expect("flush failed");
+    let mut drained = Vec::new();
+    loop {
+        let mut block = AudioBlock::new(1);
+        assert_eq!(stage.process(&mut block, &mut ctx), StageStatus::Ok);
+        if block.is_empty() {
+            break;
+        }
+        drained.extend_from_slice(&block.samples);
+    }
+    assert_eq!(drained.len(), PARTITION_FRAMES * 3);
+    let peak = drained
+        .iter()
+        .position(|sample| (sample - 1.0).abs() < TOLERANCE)
+        .expect("impulse tail missing from drain");
+    assert_eq!(peak + 1, PARTITION_FRAMES + 699);
+}
+
+#[test]
+fn filter_that_does_not_fit_is_rejected_live_and_bypassed_on_prepare() {
+    let stereo = || {
+        ImpulseResponse::new(
+            48_000,
+            ImpulseLayout::PerChannel,
+            vec![vec![0.5], vec![0.5]],
+        )
+        .expect("invalid impulse response")
+    };
+
+    let (mut stage, mut ctx) = prepared_stage(None, 48_000, 6);
+    assert!(
+        stage
+            .apply_control(&ConvolutionControl::load(Arc::new(stereo())), &mut ctx)
+            .is_err()
+    );
+
+    let (mut stage, mut ctx) = prepared_stage(Some(stereo()), 48_000, 6);
+    let input = signal(1_000 * 6, 5);
+    let output = run(&mut stage, &mut ctx, 6, &input);
+    for (out, expected) in output[PARTITION_FRAMES * 6..].iter().zip(&input) {
+        assert!((out - expected).abs() < TOLERANCE);
+    }
+}
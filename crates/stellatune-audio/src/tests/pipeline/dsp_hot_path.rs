@@ -0,0 +1,151 @@
+use stellatune_audio_core::pipeline::alloc_guard::AllocationScope;
+use stellatune_audio_core::pipeline::context::{
+    GainTransitionRequest, GaplessTrimSpec, PipelineContext, StreamSpec,
+};
+use stellatune_audio_core::pipeline::pool::AudioBlockPool;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+use crate::config::crossfeed::CrossfeedPreset;
+use crate::config::dither::{DitherConfig, NoiseShaping, SinkSampleFormat};
+use crate::config::engine::{LfeMode, ResampleQuality};
+use crate::config::time_stretch::TimeStretchConfig;
+use crate::pipeline::assembly::{MixerPlan, ResamplerPlan};
+use crate::pipeline::runtime::dsp::control::{
+    GaplessTrimControl, MasterGainControl, TransitionGainControl,
+};
+use crate::pipeline::runtime::dsp::crossfeed::CrossfeedStage;
+use crate::pipeline::runtime::dsp::dither::DitherStage;
+use crate::pipeline::runtime::dsp::gapless_trim::GaplessTrimStage;
+use crate::pipeline::runtime::dsp::master_gain::MasterGainStage;
+use crate::pipeline::runtime::dsp::mixer::MixerStage;
+use crate::pipeline::runtime::dsp::resampler::ResamplerStage;
+use crate::pipeline::runtime::dsp::time_stretch::TimeStretchStage;
+use crate::pipeline::runtime::dsp::transition_gain::TransitionGainStage;
+
+const BLOCK_FRAMES: usize = 1024;
+const WARMUP_BLOCKS: usize = 32;
+const MEASURED_BLOCKS: usize = 256;
+
+/// Full built-in chain with every stage doing real work: 5.1 downmix, 44.1k -> 48k,
+/// gapless trim with a held tail, time stretch with a pitch shift, crossfeed, both gain
+/// stages mid-ramp, and noise-shaped 16-bit dither.
+fn builtin_chain(ctx: &mut PipelineContext) -> Vec<Box<dyn TransformStage>> {
+    let mut stages: Vec<Box<dyn TransformStage>> = vec![
+        Box::new(GaplessTrimStage::new()),
+        Box::new(MixerStage::new(MixerPlan::new(2, LfeMode::MixToFront))),
+        Box::new(ResamplerStage::new(ResamplerPlan::new(
+            48_000,
+            ResampleQuality::Balanced,
+        ))),
+        Box::new(TimeStretchStage::new()),
+        Box::new(CrossfeedStage::default()),
+        Box::new(TransitionGainStage::new()),
+        Box::new(MasterGainStage::new()),
+        Box::new(DitherStage::default()),
+    ];
+    let mut spec = StreamSpec {
+        sample_rate: 44_100,
+        channels: 6,
+    };
+    for stage in &mut stages {
+        spec = stage.prepare(spec, ctx).expect("prepare failed");
+    }
+    assert_eq!(
+        spec,
+        StreamSpec {
+            sample_rate: 48_000,
+            channels: 2,
+        }
+    );
+
+    let controls: [&dyn std::any::Any; 6] = [
+        &GaplessTrimControl::new(
+            Some(GaplessTrimSpec {
+                head_frames: 576,
+                tail_frames: 1_105,
+            }),
+            0,
+        ),
+        &TransitionGainControl::new(GainTransitionRequest {
+            target_gain: 0.25,
+            ramp_ms: 60_000,
+            ..GainTransitionRequest::default()
+        }),
+        &MasterGainControl::new(0.5, 60_000),
+        &CrossfeedPreset::ChuMoy.config(),
+        &TimeStretchConfig::new(1.25, 3.0),
+        &DitherConfig {
+            sink_format: SinkSampleFormat::Int16,
+            tpdf: true,
+            noise_shaping: NoiseShaping::Wannamaker,
+        },
+    ];
+    for control in controls {
+        let handled = stages
+            .iter_mut()
+            .filter_map(|stage| stage.apply_control(control, ctx).ok())
+            .any(|handled| handled);
+        assert!(handled, "control was not routed to any stage");
+    }
+    stages
+}
+
+/// Runs one block through the chain the way the runner and sink thread do: take a
+/// pooled block, decode into it, transform, then recycle it after the "sink write".
+fn run_block(
+    stages: &mut [Box<dyn TransformStage>],
+    pool: &AudioBlockPool,
+    ctx: &mut PipelineContext,
+    block_index: usize,
+) {
+    let mut block = pool.acquire(6);
+    let base = block_index * BLOCK_FRAMES * 6;
+    block
+        .samples
+        .extend((base..base + BLOCK_FRAMES * 6).map(|i| ((i % 193) as f32 / 193.0) - 0.5));
+    for stage in stages.iter_mut() {
+        assert_eq!(stage.process(&mut block, ctx), StageStatus::Ok);
+    }
+    // Gapless trim holds back the first blocks entirely while it fills its tail buffer.
+    assert!(block.is_empty() || block.channels == 2);
+    pool.recycle(block);
+}
+
+#[test]
+fn counting_allocator_sees_test_allocations() {
+    let scope = AllocationScope::enter();
+    let buffer = std::hint::black_box(Vec::<f32>::with_capacity(64));
+    assert!(scope.allocations() >= 1);
+    drop(buffer);
+}
+
+#[test]
+fn builtin_chain_is_allocation_free_in_steady_state() {
+    let mut ctx = PipelineContext::default();
+    let mut stages = builtin_chain(&mut ctx);
+    let pool = AudioBlockPool::new(4);
+
+    for index in 0..WARMUP_BLOCKS {
+        run_block(&mut stages, &pool, &mut ctx, index);
+    }
+
+    let scope = AllocationScope::enter();
+    for index in WARMUP_BLOCKS..WARMUP_BLOCKS + MEASURED_BLOCKS {
+        run_block(&mut stages, &pool, &mut ctx, index);
+    }
+    let allocations = scope.allocations();
+    drop(scope);
+
+    assert_eq!(
+        allocations, 0,
+        "{allocations} allocations over {MEASURED_BLOCKS} steady-state blocks"
+    );
+}
+
+#[test]
+fn each_stage_claims_no_alloc_hot_path() {
+    let mut ctx = PipelineContext::default();
+    let stages = builtin_chain(&mut ctx);
+    assert!(stages.iter().all(|stage| stage.no_alloc_hot_path()));
+}
@@ -0,0 +1,324 @@
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+
+use stellatune_audio_core::pipeline::context::{
+    AudioBlock, InputRef, PipelineContext, SourceHandle, StreamSpec,
+};
+use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::decoder::DecoderStage;
+use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+use stellatune_audio_core::pipeline::stages::source::SourceStage;
+
+use crate::config::engine::{LfeMode, ResampleQuality};
+use crate::config::time_stretch::TimeStretchConfig;
+use crate::pipeline::assembly::{
+    AssembledDecodePipeline, AssembledPipeline, BuiltinTransformSlots, MixerPlan, ResamplerPlan,
+    StaticSinkPlan, TransformChain,
+};
+use crate::pipeline::offline::{OfflineRenderOutcome, OfflineRenderer};
+
+struct TestSource;
+
+impl SourceStage for TestSource {
+    fn prepare(
+        &mut self,
+        _input: &InputRef,
+        _ctx: &mut PipelineContext,
+    ) -> Result<SourceHandle, PipelineError> {
+        Ok(SourceHandle::new(()))
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {}
+}
+
+struct TestDecoder {
+    blocks: VecDeque<StageStatus>,
+    spec: StreamSpec,
+    frames_per_block: usize,
+}
+
+impl TestDecoder {
+    fn new(spec: StreamSpec, statuses: Vec<StageStatus>, frames_per_block: usize) -> Self {
+        Self {
+            blocks: statuses.into(),
+            spec,
+            frames_per_block,
+        }
+    }
+}
+
+impl DecoderStage for TestDecoder {
+    fn prepare(
+        &mut self,
+        _source: &SourceHandle,
+        _ctx: &mut PipelineContext,
+    ) -> Result<StreamSpec, PipelineError> {
+        Ok(self.spec)
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn runtime_error_detail(&self) -> Option<&str> {
+        Some("corrupt frame")
+    }
+
+    fn next_block(&mut self, out: &mut AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        let status = self.blocks.pop_front().unwrap_or(StageStatus::Eof);
+        if status == StageStatus::Ok {
+            out.channels = self.spec.channels;
+            out.samples = vec![0.25; self.frames_per_block * self.spec.channels as usize];
+        }
+        status
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {}
+}
+
+#[derive(Default)]
+struct SinkLog {
+    prepared: Option<StreamSpec>,
+    frames: usize,
+    flushed: bool,
+    stopped: bool,
+}
+
+struct LogSink {
+    log: Arc<Mutex<SinkLog>>,
+}
+
+impl SinkStage for LogSink {
+    fn prepare(
+        &mut self,
+        spec: StreamSpec,
+        _ctx: &mut PipelineContext,
+    ) -> Result<(), PipelineError> {
+        self.log.lock().expect("sink log mutex poisoned").prepared = Some(spec);
+        Ok(())
+    }
+
+    fn sync_runtime_control(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        Ok(())
+    }
+
+    fn write(&mut self, block: &AudioBlock, _ctx: &mut PipelineContext) -> StageStatus {
+        self.log.lock().expect("sink log mutex poisoned").frames += block.frames();
+        StageStatus::Ok
+    }
+
+    fn flush(&mut self, _ctx: &mut PipelineContext) -> Result<(), PipelineError> {
+        self.log.lock().expect("sink log mutex poisoned").flushed = true;
+        Ok(())
+    }
+
+    fn stop(&mut self, _ctx: &mut PipelineContext) {
+        self.log.lock().expect("sink log mutex poisoned").stopped = true;
+    }
+}
+
+fn renderer(
+    decoder: TestDecoder,
+    mixer: Option<MixerPlan>,
+    resampler: Option<ResamplerPlan>,
+    log: Arc<Mutex<SinkLog>>,
+) -> OfflineRenderer {
+    renderer_with_time_stretch(decoder, mixer, resampler, false, log)
+}
+
+fn renderer_with_time_stretch(
+    decoder: TestDecoder,
+    mixer: Option<MixerPlan>,
+    resampler: Option<ResamplerPlan>,
+    time_stretch: bool,
+    log: Arc<Mutex<SinkLog>>,
+) -> OfflineRenderer {
+    let decode = AssembledDecodePipeline {
+        source: Box::new(TestSource),
+        decoder: Box::new(decoder),
+        transforms: Vec::new(),
+        transform_chain: TransformChain::default(),
+        mixer,
+        resampler,
+        builtin_slots: BuiltinTransformSlots {
+            gapless_trim: true,
+            transition_gain: false,
+            master_gain: false,
+            crossfeed: false,
+            convolution: false,
+            dither: false,
+            time_stretch,
+        },
+    };
+    let sinks: Vec<Box<dyn SinkStage>> = vec![Box::new(LogSink { log })];
+    OfflineRenderer::new(AssembledPipeline::from_parts(
+        decode,
+        Box::new(StaticSinkPlan::new(sinks)),
+    ))
+    .expect("offline renderer")
+}
+
+fn input() -> InputRef {
+    InputRef::TrackToken("offline".to_string())
+}
+
+#[test]
+fn render_runs_mixer_and_resampler_and_reports_progress() {
+    let log = Arc::new(Mutex::new(SinkLog::default()));
+    let decoder = TestDecoder::new(
+        StreamSpec {
+            sample_rate: 44_100,
+            channels: 2,
+        },
+        vec![StageStatus::Ok; 40],
+        1_024,
+    );
+    let renderer = renderer(
+        decoder,
+        Some(MixerPlan::new(1, LfeMode::Mute)),
+        Some(ResamplerPlan::new(22_050, ResampleQuality::Fast)),
+        Arc::clone(&log),
+    );
+
+    let mut last_progress = None;
+    let outcome = renderer
+        .render(&input(), &AtomicBool::new(false), |progress| {
+            last_progress = Some(progress)
+        })
+        .expect("render");
+
+    let log = log.lock().expect("sink log mutex poisoned");
+    assert_eq!(
+        log.prepared,
+        Some(StreamSpec {
+            sample_rate: 22_050,
+            channels: 1,
+        })
+    );
+    let OfflineRenderOutcome::Completed { rendered_frames } = outcome else {
+        panic!("expected completed render, got {outcome:?}");
+    };
+    assert_eq!(rendered_frames, log.frames as u64);
+    // 40 * 1024 frames at half rate, minus resampler latency.
+    assert!(
+        (18_000..=20_480).contains(&log.frames),
+        "frames={}",
+        log.frames
+    );
+    assert!(log.flushed);
+    assert!(log.stopped);
+    let progress = last_progress.expect("progress reported");
+    assert_eq!(progress.rendered_frames, rendered_frames);
+    // Position advances per block with millisecond truncation.
+    let exact_ms = (rendered_frames * 1000 / 22_050) as i64;
+    assert!(
+        (exact_ms - 50..=exact_ms).contains(&progress.position_ms),
+        "position_ms={} exact_ms={exact_ms}",
+        progress.position_ms
+    );
+}
+
+#[test]
+fn render_stops_sinks_when_cancelled() {
+    let log = Arc::new(Mutex::new(SinkLog::default()));
+    let decoder = TestDecoder::new(
+        StreamSpec {
+            sample_rate: 48_000,
+            channels: 2,
+        },
+        vec![StageStatus::Ok; 10],
+        256,
+    );
+    let renderer = renderer(decoder, None, None, Arc::clone(&log));
+    let cancel = AtomicBool::new(false);
+
+    let outcome = renderer
+        .render(&input(), &cancel, |progress| {
+            if progress.rendered_frames >= 512 {
+                cancel.store(true, std::sync::atomic::Ordering::Release);
+            }
+        })
+        .expect("render");
+
+    assert_eq!(
+        outcome,
+        OfflineRenderOutcome::Cancelled {
+            rendered_frames: 512
+        }
+    );
+    let log = log.lock().expect("sink log mutex poisoned");
+    assert!(!log.flushed);
+    assert!(log.stopped);
+}
+
+#[test]
+fn render_surfaces_decoder_fatal_detail_and_still_stops_sinks() {
+    let log = Arc::new(Mutex::new(SinkLog::default()));
+    let decoder = TestDecoder::new(
+        StreamSpec {
+            sample_rate: 48_000,
+            channels: 2,
+        },
+        vec![StageStatus::Ok, StageStatus::Fatal],
+        256,
+    );
+    let renderer = renderer(decoder, None, None, Arc::clone(&log));
+
+    let error = renderer
+        .render(&input(), &AtomicBool::new(false), |_| {})
+        .expect_err("decoder fatal must fail the render");
+
+    assert!(error.to_string().contains("corrupt frame"), "{error}");
+    assert!(log.lock().expect("sink log mutex poisoned").stopped);
+}
+
+#[test]
+fn render_applies_queued_controls_to_builtin_stages() {
+    let log = Arc::new(Mutex::new(SinkLog::default()));
+    let decoder = TestDecoder::new(
+        StreamSpec {
+            sample_rate: 48_000,
+            channels: 2,
+        },
+        vec![StageStatus::Ok; 40],
+        1_024,
+    );
+    let renderer = renderer_with_time_stretch(decoder, None, None, true, Arc::clone(&log))
+        .with_control(TimeStretchConfig::new(2.0, 0.0));
+
+    renderer
+        .render(&input(), &AtomicBool::new(false), |_| {})
+        .expect("render");
+
+    // Double speed consumes the 40 * 1024 source frames in about half the output.
+    let frames = log.lock().expect("sink log mutex poisoned").frames;
+    assert!((18_432..=22_528).contains(&frames), "frames={frames}");
+}
+
+#[test]
+fn render_rejects_controls_no_stage_accepts() {
+    let log = Arc::new(Mutex::new(SinkLog::default()));
+    let decoder = TestDecoder::new(
+        StreamSpec {
+            sample_rate: 48_000,
+            channels: 2,
+        },
+        vec![StageStatus::Ok; 4],
+        256,
+    );
+    let renderer = renderer(decoder, None, None, Arc::clone(&log))
+        .with_control(TimeStretchConfig::new(2.0, 0.0));
+
+    let error = renderer
+        .render(&input(), &AtomicBool::new(false), |_| {})
+        .expect_err("unrouted control must fail the render");
+
+    assert!(error.to_string().contains("control"), "{error}");
+    assert!(log.lock().expect("sink log mutex poisoned").stopped);
+}
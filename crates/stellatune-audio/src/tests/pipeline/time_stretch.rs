@@ -0,0 +1,267 @@
+use std::f32::consts::TAU;
+
+use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+use stellatune_audio_core::pipeline::stages::StageStatus;
+use stellatune_audio_core::pipeline::stages::transform::TransformStage;
+
+use crate::config::time_stretch::TimeStretchConfig;
+use crate::pipeline::runtime::dsp::time_stretch::TimeStretchStage;
+
+const SAMPLE_RATE: u32 = 48_000;
+const BLOCK_FRAMES: usize = 1_024;
+
+fn prepared_stage(channels: u16) -> (TimeStretchStage, PipelineContext) {
+    let mut stage = TimeStretchStage::new();
+    let mut ctx = PipelineContext::default();
+    stage
+        .prepare(
+            StreamSpec {
+                sample_rate: SAMPLE_RATE,
+                channels,
+            },
+            &mut ctx,
+        )
+        .expect("prepare failed");
+    (stage, ctx)
+}
+
+fn configure(
+    stage: &mut TimeStretchStage,
+    ctx: &mut PipelineContext,
+    speed: f32,
+    pitch_semitones: f32,
+) {
+    let handled = stage
+        .apply_control(&TimeStretchConfig::new(speed, pitch_semitones), ctx)
+        .expect("apply_control failed");
+    assert!(handled);
+}
+
+fn sine(freq: f32, frames: usize, channels: usize) -> Vec<f32> {
+    (0..frames)
+        .flat_map(|frame| {
+            let value = 0.5 * (TAU * freq * frame as f32 / SAMPLE_RATE as f32).sin();
+            std::iter::repeat_n(value, channels)
+        })
+        .collect()
+}
+
+/// Runs interleaved `input` through the stage in blocks without draining it.
+fn run(
+    stage: &mut TimeStretchStage,
+    ctx: &mut PipelineContext,
+    channels: u16,
+    input: &[f32],
+) -> Vec<f32> {
+    let mut output = Vec::new();
+    for chunk in input.chunks(BLOCK_FRAMES * usize::from(channels)) {
+        let mut block = AudioBlock {
+            channels,
+            samples: chunk.to_vec(),
+        };
+        assert_eq!(stage.process(&mut block, ctx), StageStatus::Ok);
+        output.extend_from_slice(&block.samples);
+    }
+    output
+}
+
+/// Flushes the stage and collects its tail.
+fn drain(stage: &mut TimeStretchStage, ctx: &mut PipelineContext) -> Vec<f32> {
+    stage.flush(ctx).expect("flush failed");
+    let mut output = Vec::new();
+    loop {
+        let mut block = AudioBlock::new(1);
+        assert_eq!(stage.process(&mut block, ctx), StageStatus::Ok);
+        if block.is_empty() {
+            break;
+        }
+        output.extend_from_slice(&block.samples);
+    }
+    output
+}
+
+/// Estimates the frequency of a mono signal from its rising zero crossings.
+fn frequency(samples: &[f32]) -> f32 {
+    let crossings = samples
+        .windows(2)
+        .filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0)
+        .count();
+    crossings as f32 * SAMPLE_RATE as f32 / samples.len() as f32
+}
+
+fn max_step(samples: &[f32]) -> f32 {
+    samples
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs())
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn identity_setting_passes_audio_through() {
+    let (mut stage, mut ctx) = prepared_stage(2);
+    let input = sine(440.0, 10_000, 2);
+    let output = run(&mut stage, &mut ctx, 2, &input);
+    assert_eq!(output, input);
+    assert!(drain(&mut stage, &mut ctx).is_empty());
+    assert_eq!(stage.playback_rate(), 1.0);
+}
+
+#[test]
+fn speed_changes_duration_but_not_pitch() {
+    for speed in [0.5, 2.0] {
+        let (mut stage, mut ctx) = prepared_stage(1);
+        configure(&mut stage, &mut ctx, speed, 0.0);
+        assert_eq!(stage.playback_rate(), f64::from(speed));
+
+        let input = sine(440.0, 72_000, 1);
+        let mut output = run(&mut stage, &mut ctx, 1, &input);
+        output.extend(drain(&mut stage, &mut ctx));
+
+        let expected = input.len() as f32 / speed;
+        let ratio = output.len() as f32 / expected;
+        assert!(
+            (ratio - 1.0).abs() < 0.03,
+            "speed {speed}: length ratio {ratio}"
+        );
+        let measured = frequency(&output[4_800..output.len() - 4_800]);
+        assert!(
+            (measured - 440.0).abs() < 8.0,
+            "speed {speed}: pitch moved to {measured} Hz"
+        );
+    }
+}
+
+#[test]
+fn pitch_shift_keeps_duration() {
+    for (semitones, expected_hz) in [(12.0, 880.0), (-12.0, 220.0)] {
+        let (mut stage, mut ctx) = prepared_stage(1);
+        configure(&mut stage, &mut ctx, 1.0, semitones);
+        assert_eq!(stage.playback_rate(), 1.0);
+
+        let input = sine(440.0, 72_000, 1);
+        let mut output = run(&mut stage, &mut ctx, 1, &input);
+        output.extend(drain(&mut stage, &mut ctx));
+
+        let ratio = output.len() as f32 / input.len() as f32;
+        assert!(
+            (ratio - 1.0).abs() < 0.03,
+            "{semitones} st: length ratio {ratio}"
+        );
+        let measured = frequency(&output[4_800..output.len() - 4_800]);
+        assert!(
+            (measured - expected_hz).abs() < expected_hz * 0.02,
+            "{semitones} st: expected {expected_hz} Hz, got {measured} Hz"
+        );
+    }
+}
+
+#[test]
+fn switching_speed_live_splices_without_jumps() {
+    let (mut stage, mut ctx) = prepared_stage(1);
+    let input = sine(100.0, 96_000, 1);
+    let (first, rest) = input.split_at(24_000);
+    let (second, third) = rest.split_at(48_000);
+
+    let mut output = run(&mut stage, &mut ctx, 1, first);
+    configure(&mut stage, &mut ctx, 1.5, 0.0);
+    output.extend(run(&mut stage, &mut ctx, 1, second));
+    configure(&mut stage, &mut ctx, 1.0, 0.0);
+    output.extend(run(&mut stage, &mut ctx, 1, third));
+
+    // A 100 Hz sine at half scale moves at most ~0.0065 per sample.
+    let step = max_step(&output);
+    assert!(step < 0.01, "step of {step} across speed changes");
+    // After returning to 1x, the tail of the output is the input again.
+    assert_eq!(output[output.len() - 1_000..], input[input.len() - 1_000..]);
+}
+
+/// Runs `input` through the stage in blocks and returns, per block, its output frames
+/// and the source frames the stage reports behind them.
+fn run_accounted(
+    stage: &mut TimeStretchStage,
+    ctx: &mut PipelineContext,
+    input: &[f32],
+) -> Vec<(u64, u64)> {
+    input
+        .chunks(BLOCK_FRAMES)
+        .map(|chunk| {
+            let mut block = AudioBlock {
+                channels: 1,
+                samples: chunk.to_vec(),
+            };
+            assert_eq!(stage.process(&mut block, ctx), StageStatus::Ok);
+            let frames = block.frames() as u64;
+            (frames, stage.source_frames(frames))
+        })
+        .collect()
+}
+
+#[test]
+fn source_frames_follow_the_input_actually_read() {
+    let (mut stage, mut ctx) = prepared_stage(1);
+    let input = sine(100.0, 96_000, 1);
+    let (first, rest) = input.split_at(48_000);
+    let (second, third) = rest.split_at(24_000);
+
+    configure(&mut stage, &mut ctx, 2.0, 0.0);
+    let mut blocks = run_accounted(&mut stage, &mut ctx, first);
+    // Steady-state blocks stand for twice their length, give or take one segment hop.
+    for &(frames, source) in &blocks[8..] {
+        let expected = frames as f64 * 2.0;
+        assert!(
+            (source as f64 - expected).abs() <= 960.0,
+            "{frames} frames at 2x stood for {source} source frames"
+        );
+    }
+    // Input buffered at 2x is released at 0.5x; switching back to 1x hands the rest back.
+    configure(&mut stage, &mut ctx, 0.5, 0.0);
+    blocks.extend(run_accounted(&mut stage, &mut ctx, second));
+    configure(&mut stage, &mut ctx, 1.0, 0.0);
+    blocks.extend(run_accounted(&mut stage, &mut ctx, third));
+
+    let source: u64 = blocks.iter().map(|&(_, source)| source).sum();
+    assert_eq!(source, input.len() as u64);
+}
+
+#[test]
+fn drained_tail_accounts_for_the_remaining_input() {
+    let (mut stage, mut ctx) = prepared_stage(1);
+    configure(&mut stage, &mut ctx, 1.5, 3.0);
+    let input = sine(440.0, 30_000, 1);
+    let mut source: u64 = run_accounted(&mut stage, &mut ctx, &input)
+        .iter()
+        .map(|&(_, source)| source)
+        .sum();
+
+    stage.flush(&mut ctx).expect("flush failed");
+    let mut block = AudioBlock::new(1);
+    assert_eq!(stage.process(&mut block, &mut ctx), StageStatus::Ok);
+    assert!(!block.is_empty());
+    source += stage.source_frames(block.frames() as u64);
+    assert_eq!(source, input.len() as u64);
+}
+
+#[test]
+fn seek_drops_buffered_audio() {
+    let (mut stage, mut ctx) = prepared_stage(1);
+    configure(&mut stage, &mut ctx, 2.0, 0.0);
+    run(&mut stage, &mut ctx, 1, &sine(440.0, 3_000, 1));
+
+    ctx.request_seek(5_000);
+    stage
+        .sync_runtime_control(&mut ctx)
+        .expect("sync_runtime_control failed");
+    let mut output = run(&mut stage, &mut ctx, 1, &vec![0.0; 10_000]);
+    output.extend(drain(&mut stage, &mut ctx));
+    assert!(!output.is_empty());
+    assert!(output.iter().all(|sample| *sample == 0.0));
+}
+
+#[test]
+fn config_is_clamped_into_supported_range() {
+    let config = TimeStretchConfig::new(10.0, -40.0);
+    assert_eq!(config.speed, 3.0);
+    assert_eq!(config.pitch_semitones, -12.0);
+    let config = TimeStretchConfig::new(f32::NAN, f32::INFINITY);
+    assert!(config.is_identity());
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use crate::config::engine::{EngineConfig, PlayerState};
+use crate::config::engine::{ClockMode, EngineConfig, PlayerState};
 use crate::pipeline::runtime::dsp::control::MasterGainHotControl;
 use crate::workers::decode::state::DecodeWorkerState;
 
@@ -58,3 +58,18 @@ fn compute_loop_timeout_uses_playing_idle_sleep_without_recovery() {
 
     assert_eq!(timeout, config.decode_playing_idle_sleep);
 }
+
+#[test]
+fn compute_loop_timeout_does_not_sleep_between_steps_when_free_running() {
+    let config = EngineConfig {
+        decode_playing_idle_sleep: Duration::from_millis(3),
+        ..EngineConfig::default()
+    };
+    let mut state = test_state(&config);
+    state.state = PlayerState::Playing;
+    state.clock_mode = ClockMode::FreeRunning;
+
+    let timeout = crate::workers::decode::worker_loop::compute_loop_timeout(&state, &config);
+
+    assert_eq!(timeout, Duration::ZERO);
+}
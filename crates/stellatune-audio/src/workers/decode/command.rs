@@ -4,7 +4,7 @@ use std::sync::Arc;
 use crossbeam_channel::Sender;
 use stellatune_audio_core::pipeline::context::InputRef;
 
-use crate::config::engine::{LfeMode, PauseBehavior, ResampleQuality, StopBehavior};
+use crate::config::engine::{ClockMode, LfeMode, PauseBehavior, ResampleQuality, StopBehavior};
 use crate::error::DecodeError;
 use crate::pipeline::assembly::{PipelineMutation, PipelinePlan};
 
@@ -49,6 +49,10 @@ pub(crate) enum DecodeWorkerCommand {
         quality: ResampleQuality,
         resp_tx: Sender<Result<(), DecodeError>>,
     },
+    SetClockMode {
+        mode: ClockMode,
+        resp_tx: Sender<Result<(), DecodeError>>,
+    },
     ApplyStageControl {
         stage_key: String,
         control: Box<dyn Any + Send>,
@@ -29,7 +29,7 @@ pub(crate) fn replay_persisted_stage_controls_to_runner(
     ctx: &mut PipelineContext,
 ) -> Result<(), DecodeError> {
     let mut entries = stage_controls.iter().collect::<Vec<_>>();
-    entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+    entries.sort_by_key(|(key, _)| *key);
     for (stage_key, control) in entries {
         match runner.apply_transform_control_to(stage_key, control.as_ref(), ctx) {
             Ok(true) => {},
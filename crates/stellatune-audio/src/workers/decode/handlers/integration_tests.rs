@@ -141,6 +141,10 @@ impl PipelineRuntime for TestRuntime {
                     gapless_trim: self.pipeline_config.gapless_trim_spec.is_some(),
                     transition_gain: true,
                     master_gain: false,
+                    crossfeed: false,
+                    convolution: false,
+                    dither: false,
+                    time_stretch: false,
                 },
             },
             Box::new(StaticSinkPlan::new(vec![Box::new(TestSink)])),
@@ -11,6 +11,7 @@ mod play;
 mod queue_next;
 mod reconfigure_active;
 mod seek;
+mod set_clock_mode;
 mod set_lfe_mode;
 mod set_resample_quality;
 mod shutdown;
@@ -88,6 +89,9 @@ pub(crate) fn handle_command(
                 state,
             )
         },
+        DecodeWorkerCommand::SetClockMode { mode, resp_tx } => {
+            set_clock_mode::handle(mode, resp_tx, state)
+        },
         DecodeWorkerCommand::ApplyStageControl {
             stage_key,
             control,
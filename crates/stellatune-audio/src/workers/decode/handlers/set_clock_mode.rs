@@ -0,0 +1,16 @@
+use crossbeam_channel::Sender;
+
+use crate::config::engine::ClockMode;
+use crate::error::DecodeError;
+use crate::workers::decode::state::DecodeWorkerState;
+
+pub(crate) fn handle(
+    mode: ClockMode,
+    resp_tx: Sender<Result<(), DecodeError>>,
+    state: &mut DecodeWorkerState,
+) -> bool {
+    // Pacing is read by the loop on every wake; nothing needs rebuilding.
+    state.clock_mode = mode;
+    let _ = resp_tx.send(Ok(()));
+    false
+}
@@ -16,7 +16,7 @@ use crossbeam_channel::Receiver;
 use stellatune_audio_core::pipeline::error::PipelineError;
 use tracing::warn;
 
-use crate::config::engine::{EngineConfig, PlayerState, StopBehavior};
+use crate::config::engine::{ClockMode, EngineConfig, PlayerState, StopBehavior};
 use crate::error::DecodeError;
 use crate::pipeline::assembly::PipelineAssembler;
 use crate::pipeline::runtime::dsp::control::SharedMasterGainHotControl;
@@ -53,6 +53,7 @@ pub(crate) fn decode_worker_main(
         config.sink_control_timeout,
         master_gain_hot_control,
     );
+    state.clock_mode = config.clock_mode;
 
     loop {
         let timeout = compute_loop_timeout(&state, &config);
@@ -270,7 +271,10 @@ fn compute_loop_timeout(state: &DecodeWorkerState, config: &EngineConfig) -> Dur
     {
         return config.decode_playing_pending_block_sleep;
     }
-    config.decode_playing_idle_sleep
+    match state.clock_mode {
+        ClockMode::Paced => config.decode_playing_idle_sleep,
+        ClockMode::FreeRunning => Duration::ZERO,
+    }
 }
 
 #[cfg(test)]
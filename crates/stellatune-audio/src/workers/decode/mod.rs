@@ -34,7 +34,7 @@ use crossbeam_channel::{SendTimeoutError, Sender};
 use stellatune_audio_core::pipeline::context::InputRef;
 
 use crate::config::engine::{
-    EngineConfig, LfeMode, PauseBehavior, PlayerState, ResampleQuality, StopBehavior,
+    ClockMode, EngineConfig, LfeMode, PauseBehavior, PlayerState, ResampleQuality, StopBehavior,
 };
 use crate::error::DecodeError;
 use crate::pipeline::assembly::{PipelineAssembler, PipelineMutation, PipelinePlan};
@@ -202,6 +202,17 @@ impl DecodeWorker {
         )
     }
 
+    pub(crate) fn set_clock_mode(
+        &self,
+        mode: ClockMode,
+        timeout: Duration,
+    ) -> Result<(), DecodeError> {
+        self.call_simple(
+            |resp_tx| DecodeWorkerCommand::SetClockMode { mode, resp_tx },
+            timeout,
+        )
+    }
+
     pub(crate) fn apply_stage_control(
         &self,
         stage_key: impl Into<String>,
@@ -6,7 +6,7 @@ use std::time::Instant;
 
 use stellatune_audio_core::pipeline::context::{InputRef, PipelineContext};
 
-use crate::config::engine::{LfeMode, PlayerState, ResampleQuality};
+use crate::config::engine::{ClockMode, LfeMode, PlayerState, ResampleQuality};
 use crate::config::gain::GainTransitionConfig;
 use crate::config::sink::{SinkLatencyConfig, SinkRecoveryConfig};
 use crate::pipeline::assembly::PipelinePlan;
@@ -35,6 +35,7 @@ pub(crate) struct DecodeWorkerState {
     pub(crate) sink_session: SinkSession,
     pub(crate) lfe_mode: LfeMode,
     pub(crate) resample_quality: ResampleQuality,
+    pub(crate) clock_mode: ClockMode,
     pub(crate) persisted_stage_controls: HashMap<String, Box<dyn Any + Send>>,
     pub(crate) recovery_attempts: u32,
     pub(crate) recovery_retry_at: Option<Instant>,
@@ -65,6 +66,7 @@ impl DecodeWorkerState {
             sink_session: SinkSession::new(sink_latency, sink_control_timeout),
             lfe_mode: LfeMode::default(),
             resample_quality: ResampleQuality::default(),
+            clock_mode: ClockMode::default(),
             persisted_stage_controls: HashMap::new(),
             recovery_attempts: 0,
             recovery_retry_at: None,
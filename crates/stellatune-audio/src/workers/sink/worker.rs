@@ -13,6 +13,7 @@ use ringbuf::traits::{Consumer as _, Producer as _, Split as _};
 use ringbuf::{HeapCons, HeapProd, HeapRb};
 use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
 use stellatune_audio_core::pipeline::error::PipelineError;
+use stellatune_audio_core::pipeline::pool::AudioBlockPool;
 use stellatune_audio_core::pipeline::stages::StageStatus;
 use stellatune_audio_core::pipeline::stages::sink::SinkStage;
 
@@ -53,6 +54,7 @@ impl SinkWorker {
         spec: StreamSpec,
         initial_ctx: PipelineContext,
         queue_capacity: usize,
+        block_pool: AudioBlockPool,
     ) -> Result<Self, PipelineError> {
         let capacity = queue_capacity.max(1);
         let rb = HeapRb::<AudioBlock>::new(capacity);
@@ -74,6 +76,7 @@ impl SinkWorker {
                     audio_cons,
                     wake_rx,
                     ctrl_rx,
+                    block_pool,
                     running: running_for_thread,
                 })
             })
@@ -219,6 +222,7 @@ struct SinkThreadArgs {
     audio_cons: HeapCons<AudioBlock>,
     wake_rx: Receiver<()>,
     ctrl_rx: Receiver<SinkControl>,
+    block_pool: AudioBlockPool,
     running: Arc<AtomicBool>,
 }
 
@@ -235,6 +239,7 @@ fn sink_thread_main(args: SinkThreadArgs) {
         mut audio_cons,
         wake_rx,
         ctrl_rx,
+        block_pool,
         running,
     } = args;
     let _running_guard = RunningFlagGuard::new(running);
@@ -254,7 +259,7 @@ fn sink_thread_main(args: SinkThreadArgs) {
                     break;
                 };
                 // Control commands are handled eagerly to keep external RPCs responsive.
-                if handle_control(control, &mut sinks, &mut audio_cons, &mut ctx) {
+                if handle_control(control, &mut sinks, &mut audio_cons, &block_pool, &mut ctx) {
                     break;
                 }
             }
@@ -262,7 +267,9 @@ fn sink_thread_main(args: SinkThreadArgs) {
                 if msg.is_err() {
                     break;
                 }
-                if drain_audio_ring_to_sinks(&mut sinks, &mut audio_cons, &mut ctx).is_err() {
+                if drain_audio_ring_to_sinks(&mut sinks, &mut audio_cons, &block_pool, &mut ctx)
+                    .is_err()
+                {
                     break;
                 }
             }
@@ -312,13 +319,16 @@ fn flush_sinks(
     Ok(())
 }
 
+/// Writes every queued block and hands its buffer back to the decode side.
 fn drain_audio_ring_to_sinks(
     sinks: &mut [Box<dyn SinkStage>],
     audio_cons: &mut HeapCons<AudioBlock>,
+    block_pool: &AudioBlockPool,
     ctx: &mut PipelineContext,
 ) -> Result<(), PipelineError> {
     while let Some(block) = audio_cons.try_pop() {
         write_block(sinks, &block, ctx)?;
+        block_pool.recycle(block);
     }
     Ok(())
 }
@@ -346,6 +356,7 @@ fn handle_control(
     control: SinkControl,
     sinks: &mut [Box<dyn SinkStage>],
     audio_cons: &mut HeapCons<AudioBlock>,
+    block_pool: &AudioBlockPool,
     ctx: &mut PipelineContext,
 ) -> bool {
     match control {
@@ -359,7 +370,7 @@ fn handle_control(
             false
         },
         SinkControl::Drain { resp_tx } => {
-            let result = drain_audio_ring_to_sinks(sinks, audio_cons, ctx)
+            let result = drain_audio_ring_to_sinks(sinks, audio_cons, block_pool, ctx)
                 .and_then(|_| flush_sinks(sinks, ctx));
             let _ = resp_tx.send(result);
             false
@@ -373,7 +384,7 @@ fn handle_control(
             if !drain {
                 let _ = audio_cons.clear();
             } else {
-                let _ = drain_audio_ring_to_sinks(sinks, audio_cons, ctx);
+                let _ = drain_audio_ring_to_sinks(sinks, audio_cons, block_pool, ctx);
                 let _ = flush_sinks(sinks, ctx);
             }
             let _ = resp_tx.send(Ok(()));
@@ -389,6 +400,7 @@ mod tests {
     use std::time::Duration;
     use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
     use stellatune_audio_core::pipeline::error::PipelineError;
+    use stellatune_audio_core::pipeline::pool::AudioBlockPool;
     use stellatune_audio_core::pipeline::stages::StageStatus;
     use stellatune_audio_core::pipeline::stages::sink::SinkStage;
 
@@ -431,6 +443,7 @@ mod tests {
             },
             PipelineContext::default(),
             2,
+            AudioBlockPool::new(4),
         )
         .expect("sink worker should start");
 
@@ -5,5 +5,10 @@ pub mod lyrics_types;
 pub mod player;
 pub mod runtime;
 pub mod session;
+pub mod transcode;
 
-pub use lyrics_types::{LyricLine, LyricsDoc, LyricsEvent, LyricsQuery, LyricsSearchCandidate};
+pub use lyrics_types::{
+    LyricLine, LyricLineStart, LyricWord, LyricsDoc, LyricsEvent, LyricsMeta, LyricsProviderMode,
+    LyricsProviderPolicy, LyricsProviderStatus, LyricsQuery, LyricsSearchCandidate, LyricsTiming,
+    LyricsTimingEdit,
+};
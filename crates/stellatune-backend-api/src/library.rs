@@ -4,8 +4,11 @@ use anyhow::Result;
 use tokio::sync::broadcast;
 
 use crate::runtime::init_tracing;
+use crate::transcode::{TranscodeOptions, TranscodeService};
 
-use stellatune_library::{LibraryEvent, LibraryHandle, PlaylistLite, TrackLite, start_library};
+use stellatune_library::{
+    LibraryEvent, LibraryHandle, PlaylistLite, PluginErrorEntry, TrackLite, start_library,
+};
 
 pub struct LibraryService {
     instance_id: u64,
@@ -19,6 +22,10 @@ impl LibraryService {
         init_tracing();
         tracing::info!(instance_id, "creating library: {}", db_path);
         let handle = start_library(db_path).await?;
+        crate::runtime::attach_plugin_error_store(handle.clone());
+        if let Err(error) = crate::runtime::load_persisted_plugin_configs(&handle).await {
+            tracing::warn!(%error, "failed to load persisted plugin configs");
+        }
         Ok(Self {
             instance_id,
             handle,
@@ -252,6 +259,61 @@ impl LibraryService {
     pub async fn list_disabled_plugin_ids(&self) -> Result<Vec<String>> {
         self.handle.list_disabled_plugin_ids().await
     }
+
+    pub async fn list_plugin_errors(
+        &self,
+        plugin_id: Option<String>,
+        limit: u32,
+    ) -> Result<Vec<PluginErrorEntry>> {
+        self.handle.list_plugin_errors(plugin_id, limit).await
+    }
+
+    pub async fn clear_plugin_errors(&self, plugin_id: Option<String>) -> Result<()> {
+        self.handle.clear_plugin_errors(plugin_id).await
+    }
+
+    pub fn subscribe_plugin_errors(&self) -> broadcast::Receiver<PluginErrorEntry> {
+        crate::runtime::subscribe_plugin_error_events()
+    }
+
+    pub async fn plugin_config_load_json(
+        &self,
+        plugin_id: String,
+        type_id: String,
+    ) -> Result<String> {
+        crate::runtime::plugin_config_load_json(&self.handle, &plugin_id, &type_id).await
+    }
+
+    pub fn plugin_config_validate_json(
+        &self,
+        plugin_id: String,
+        type_id: String,
+        config_json: String,
+    ) -> Result<String> {
+        crate::runtime::plugin_config_validate_json(&plugin_id, &type_id, &config_json)
+    }
+
+    pub async fn plugin_config_save_json(
+        &self,
+        plugin_id: String,
+        type_id: String,
+        config_json: String,
+    ) -> Result<String> {
+        crate::runtime::plugin_config_save_json(&self.handle, &plugin_id, &type_id, &config_json)
+            .await
+    }
+
+    /// Submits the given library tracks to `transcoder`; ids that are no longer
+    /// indexed are skipped.
+    pub async fn transcode_tracks(
+        &self,
+        transcoder: &TranscodeService,
+        track_ids: Vec<i64>,
+        options: TranscodeOptions,
+    ) -> Result<u64> {
+        let tracks = self.handle.get_tracks_by_ids(track_ids).await?;
+        transcoder.submit(tracks, options)
+    }
 }
 
 impl Drop for LibraryService {
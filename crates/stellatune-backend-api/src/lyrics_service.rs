@@ -16,18 +16,32 @@ use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
 use url::Url;
 
-use crate::{LyricLine, LyricsDoc, LyricsEvent, LyricsQuery, LyricsSearchCandidate};
+use crate::{
+    LyricsDoc, LyricsEvent, LyricsProviderPolicy, LyricsProviderStatus, LyricsQuery,
+    LyricsSearchCandidate, LyricsTiming, LyricsTimingEdit,
+};
 
+mod formats;
 mod handlers;
+mod providers;
+mod timing;
 
+use self::formats::{format_lrc, parse_lrc, parse_lyrics_text, parse_plain, parse_timed_lines};
 use self::handlers::apply_candidate::ApplyCandidateMessage;
 use self::handlers::clear_cache::ClearCacheMessage;
+use self::handlers::edit_timing::EditTimingMessage;
+use self::handlers::export_lrc::ExportLrcMessage;
+use self::handlers::get_timing::GetTimingMessage;
+use self::handlers::list_providers::ListProvidersMessage;
 use self::handlers::prefetch::PrefetchMessage;
 use self::handlers::prepare::PrepareMessage;
 use self::handlers::refresh_current::RefreshCurrentMessage;
 use self::handlers::search_candidates::SearchCandidatesMessage;
 use self::handlers::set_cache_db_path::SetCacheDbPathMessage;
 use self::handlers::set_position_ms::SetPositionMsMessage;
+use self::handlers::set_provider_policy::SetProviderPolicyMessage;
+use self::providers::{DocRank, LyricsPluginCache, LyricsProvider, ProviderCacheEntry};
+use self::timing::{apply_edit, apply_timing, find_line_index, find_word_index, timing_for_doc};
 
 struct LyricsEventHub {
     tx: broadcast::Sender<LyricsEvent>,
@@ -56,7 +70,9 @@ struct LyricsState {
     current_query: Option<LyricsQuery>,
     current_doc: Option<LyricsDoc>,
     current_line_index: i64,
+    current_word_index: i64,
     cache: HashMap<String, LyricsDoc>,
+    timings: HashMap<String, LyricsTiming>,
 }
 
 const CACHE_TTL_MS: i64 = 30_i64 * 24 * 60 * 60 * 1000;
@@ -94,7 +110,10 @@ struct LyricsServiceCore {
     client: reqwest::Client,
     cache_db_path: ArcSwapOption<PathBuf>,
     http_rate: Mutex<HttpRateState>,
-    source_health: Mutex<HashMap<&'static str, SourceHealth>>,
+    source_health: Mutex<HashMap<String, SourceHealth>>,
+    provider_policy: Mutex<LyricsProviderPolicy>,
+    provider_cache: Mutex<HashMap<String, ProviderCacheEntry>>,
+    lyrics_plugins: Arc<Mutex<LyricsPluginCache>>,
     active_fetch: Mutex<ActiveFetchState>,
 }
 
@@ -206,6 +225,69 @@ impl LyricsService {
     pub fn set_position_ms(&self, position_ms: u64) {
         let _ = self.actor_ref.cast(SetPositionMsMessage { position_ms });
     }
+
+    pub async fn timing(&self, track_key: String) -> Result<LyricsTiming> {
+        self.actor_ref
+            .call(GetTimingMessage { track_key }, LYRICS_ACTOR_CALL_TIMEOUT)
+            .await
+            .map_err(|err| anyhow!("lyrics actor unavailable: {err:?}"))
+    }
+
+    /// Applies a timing correction for `track_key`, persists it and returns the result.
+    pub async fn edit_timing(
+        &self,
+        track_key: String,
+        edit: LyricsTimingEdit,
+    ) -> Result<LyricsTiming> {
+        match self
+            .actor_ref
+            .call(
+                EditTimingMessage { track_key, edit },
+                LYRICS_ACTOR_CALL_TIMEOUT,
+            )
+            .await
+        {
+            Ok(result) => result,
+            Err(err) => Err(anyhow!("lyrics actor unavailable: {err:?}")),
+        }
+    }
+
+    /// Writes the track's lyrics, with its timing folded in, to an `.lrc` next to the
+    /// audio file and returns the written path. An existing `.lrc` is only replaced
+    /// when `overwrite` is set.
+    pub async fn export_lrc(&self, track_key: String, overwrite: bool) -> Result<String> {
+        match self
+            .actor_ref
+            .call(
+                ExportLrcMessage {
+                    track_key,
+                    overwrite,
+                },
+                LYRICS_ACTOR_CALL_TIMEOUT,
+            )
+            .await
+        {
+            Ok(result) => result,
+            Err(err) => Err(anyhow!("lyrics actor unavailable: {err:?}")),
+        }
+    }
+
+    pub async fn set_provider_policy(&self, policy: LyricsProviderPolicy) -> Result<()> {
+        self.actor_ref
+            .call(
+                SetProviderPolicyMessage { policy },
+                LYRICS_ACTOR_CALL_TIMEOUT,
+            )
+            .await
+            .map_err(|err| anyhow!("lyrics actor unavailable: {err:?}"))
+    }
+
+    pub async fn list_providers(&self) -> Result<Vec<LyricsProviderStatus>> {
+        self.actor_ref
+            .call(ListProvidersMessage, LYRICS_ACTOR_CALL_TIMEOUT)
+            .await
+            .map_err(|err| anyhow!("lyrics actor unavailable: {err:?}"))
+    }
 }
 
 impl LyricsServiceCore {
@@ -220,6 +302,9 @@ impl LyricsServiceCore {
             cache_db_path: ArcSwapOption::new(None),
             http_rate: Mutex::new(HttpRateState::default()),
             source_health: Mutex::new(HashMap::new()),
+            provider_policy: Mutex::new(LyricsProviderPolicy::default()),
+            provider_cache: Mutex::new(HashMap::new()),
+            lyrics_plugins: Arc::default(),
             active_fetch: Mutex::new(ActiveFetchState::default()),
         }
     }
@@ -251,8 +336,10 @@ impl LyricsServiceCore {
             state.cache.clear();
             state.current_doc = None;
             state.current_line_index = -1;
+            state.current_word_index = -1;
             state.current_track_key.clone()
         };
+        self.clear_provider_cache();
 
         if let Some(db_path) = self.cache_db_path() {
             let mut conn = Self::open_cache_db(&db_path).await?;
@@ -279,28 +366,36 @@ impl LyricsServiceCore {
 
         let mut out = Vec::new();
         let mut seen = HashSet::new();
-
-        match self.search_candidates_lrclib(&query).await {
-            Ok(items) => {
-                for item in items {
-                    push_candidate_unique(&mut out, &mut seen, item);
-                }
-            },
-            Err(err) => {
-                tracing::warn!("lyrics candidate search (lrclib) failed: {err}");
-            },
-        }
-
-        match self.candidate_from_lyrics_ovh(&query).await {
-            Ok(Some(item)) => {
-                push_candidate_unique(&mut out, &mut seen, item);
-            },
-            Ok(None) => {},
-            Err(err) => {
-                tracing::warn!("lyrics candidate search (lyrics.ovh) failed: {err}");
-            },
+        for provider in self.resolve_providers().await {
+            let source = provider.id();
+            if !self.source_available(&source) {
+                continue;
+            }
+            let items = match &provider {
+                LyricsProvider::Lrclib => self.search_candidates_lrclib(&query).await,
+                LyricsProvider::LyricsOvh => self
+                    .candidate_from_lyrics_ovh(&query)
+                    .await
+                    .map(|item| item.into_iter().collect()),
+                LyricsProvider::Plugin { plugin_id, type_id } => {
+                    self.search_candidates_plugin(plugin_id, type_id, &query)
+                        .await
+                },
+            };
+            match items {
+                Ok(items) => {
+                    for item in items {
+                        push_candidate_unique(&mut out, &mut seen, item);
+                    }
+                },
+                Err(err) => {
+                    tracing::warn!("lyrics candidate search ({source}) failed: {err}");
+                },
+            }
         }
 
+        // Stable, so providers keep their priority among equally ranked candidates.
+        out.sort_by_key(|item| std::cmp::Reverse(DocRank::new(&item.doc, &query, None)));
         Ok(out)
     }
 
@@ -322,6 +417,7 @@ impl LyricsServiceCore {
             if state.current_track_key.as_deref() == Some(track_key.as_str()) {
                 state.current_doc = Some(doc.clone());
                 state.current_line_index = -1;
+                state.current_word_index = -1;
                 emit_ready = true;
             }
         }
@@ -338,7 +434,7 @@ impl LyricsServiceCore {
             return Ok(());
         }
 
-        if let Some(doc) = load_local_lrc_doc_async(query.track_key.clone()).await {
+        if let Some(doc) = load_local_doc_async(query.track_key.clone()).await {
             self.state
                 .lock()
                 .expect("lyrics state mutex poisoned")
@@ -381,14 +477,17 @@ impl LyricsServiceCore {
         if query.track_key.is_empty() || query.title.is_empty() {
             return Ok(());
         }
+        // Loads the track's timing into memory before the cursor needs it.
+        self.timing(&query.track_key).await;
 
-        if let Some(doc) = load_local_lrc_doc_async(query.track_key.clone()).await {
+        if let Some(doc) = load_local_doc_async(query.track_key.clone()).await {
             {
                 let mut state = self.state.lock().expect("lyrics state mutex poisoned");
                 state.current_track_key = Some(query.track_key.clone());
                 state.current_query = Some(query.clone());
                 state.current_doc = Some(doc.clone());
                 state.current_line_index = -1;
+                state.current_word_index = -1;
                 state.cache.insert(query.track_key.clone(), doc.clone());
             }
             self.hub.emit(LyricsEvent::Ready {
@@ -403,6 +502,7 @@ impl LyricsServiceCore {
             state.current_track_key = Some(query.track_key.clone());
             state.current_query = Some(query.clone());
             state.current_line_index = -1;
+            state.current_word_index = -1;
 
             if let Some(doc) = state.cache.get(&query.track_key).cloned() {
                 state.current_doc = Some(doc.clone());
@@ -424,6 +524,7 @@ impl LyricsServiceCore {
                 if state.current_track_key.as_deref() == Some(query.track_key.as_str()) {
                     state.current_doc = Some(doc.clone());
                     state.current_line_index = -1;
+                    state.current_word_index = -1;
                 }
             }
             self.hub.emit(LyricsEvent::Ready {
@@ -474,19 +575,27 @@ impl LyricsServiceCore {
                 return;
             }
 
-            let idx = find_line_index(&doc.lines, position_ms as i64);
-            if idx == state.current_line_index {
+            let timing = timing_for_doc(
+                state.timings.get(&track_key).cloned().unwrap_or_default(),
+                doc,
+            );
+            let position_ms = position_ms as i64;
+            let line_index = find_line_index(&doc.lines, &timing, position_ms);
+            let word_index = find_word_index(&doc.lines, &timing, line_index, position_ms);
+            if (line_index, word_index) == (state.current_line_index, state.current_word_index) {
                 None
             } else {
-                state.current_line_index = idx;
-                Some((track_key, idx))
+                state.current_line_index = line_index;
+                state.current_word_index = word_index;
+                Some((track_key, line_index, word_index))
             }
         };
 
-        if let Some((track_key, line_index)) = to_emit {
+        if let Some((track_key, line_index, word_index)) = to_emit {
             self.hub.emit(LyricsEvent::Cursor {
                 track_key,
                 line_index,
+                word_index,
             });
         }
     }
@@ -557,6 +666,7 @@ impl LyricsServiceCore {
                     if state.current_track_key.as_deref() == Some(track_key.as_str()) {
                         state.current_doc = Some(doc.clone());
                         state.current_line_index = -1;
+                        state.current_word_index = -1;
                         should_emit = true;
                     }
                 }
@@ -571,6 +681,7 @@ impl LyricsServiceCore {
                     if state.current_track_key.as_deref() == Some(track_key.as_str()) {
                         state.current_doc = None;
                         state.current_line_index = -1;
+                        state.current_word_index = -1;
                         should_emit = true;
                     }
                 }
@@ -611,56 +722,47 @@ impl LyricsServiceCore {
 
     async fn fetch_online(&self, query: &LyricsQuery) -> Result<Option<LyricsDoc>> {
         let mut had_network_error = false;
+        let mut best: Option<(DocRank, LyricsDoc)> = None;
 
-        if self.source_available(SOURCE_LRCLIB) {
-            match self.fetch_lrclib_get(query).await {
+        for provider in self.resolve_providers().await {
+            let source = provider.id();
+            if !self.source_available(&source) {
+                continue;
+            }
+            match self.fetch_from_provider(&provider, query).await {
                 Ok(Some(doc)) => {
-                    self.mark_source_success(SOURCE_LRCLIB);
-                    return Ok(Some(doc));
-                },
-                Ok(None) => {
-                    self.mark_source_success(SOURCE_LRCLIB);
-                    match self.fetch_lrclib_search(query).await {
-                        Ok(Some(doc)) => return Ok(Some(doc)),
-                        Ok(None) => {},
-                        Err(err) => {
-                            had_network_error = true;
-                            self.mark_source_failure(SOURCE_LRCLIB);
-                            tracing::warn!("lyrics source lrclib search failed: {err}");
-                        },
+                    let rank = DocRank::new(&doc, query, None);
+                    if rank.is_best() {
+                        return Ok(Some(doc));
+                    }
+                    if best.as_ref().is_none_or(|(known, _)| rank > *known) {
+                        best = Some((rank, doc));
                     }
                 },
+                Ok(None) => {},
                 Err(err) => {
                     had_network_error = true;
-                    self.mark_source_failure(SOURCE_LRCLIB);
-                    tracing::warn!("lyrics source lrclib get failed: {err}");
+                    tracing::warn!("lyrics source {source} failed: {err}");
                 },
             }
         }
 
-        if self.source_available(SOURCE_LYRICS_OVH) {
-            match self.fetch_lyrics_ovh_doc(query).await {
-                Ok(Some(doc)) => {
-                    self.mark_source_success(SOURCE_LYRICS_OVH);
-                    return Ok(Some(doc));
-                },
-                Ok(None) => {
-                    self.mark_source_success(SOURCE_LYRICS_OVH);
-                },
-                Err(err) => {
-                    had_network_error = true;
-                    self.mark_source_failure(SOURCE_LYRICS_OVH);
-                    tracing::warn!("lyrics source lyrics.ovh failed: {err}");
-                },
-            }
+        if let Some((_, doc)) = best {
+            return Ok(Some(doc));
         }
-
         if had_network_error {
             return Err(anyhow!("all online lyrics sources failed"));
         }
         Ok(None)
     }
 
+    async fn fetch_lrclib(&self, query: &LyricsQuery) -> Result<Option<LyricsDoc>> {
+        if let Some(doc) = self.fetch_lrclib_get(query).await? {
+            return Ok(Some(doc));
+        }
+        self.fetch_lrclib_search(query).await
+    }
+
     async fn search_candidates_lrclib(
         &self,
         query: &LyricsQuery,
@@ -771,12 +873,24 @@ impl LyricsServiceCore {
             return Ok(None);
         };
 
+        let mut best: Option<(DocRank, LyricsDoc)> = None;
         for item in items {
-            if let Some(doc) = doc_from_lrclib_value(&query.track_key, item) {
+            let Some(doc) = doc_from_lrclib_value(&query.track_key, item) else {
+                continue;
+            };
+            let reported_duration_ms = item
+                .get("duration")
+                .and_then(Value::as_f64)
+                .map(|secs| (secs * 1_000.0) as i64);
+            let rank = DocRank::new(&doc, query, reported_duration_ms);
+            if rank.is_best() {
                 return Ok(Some(doc));
             }
+            if best.as_ref().is_none_or(|(known, _)| rank > *known) {
+                best = Some((rank, doc));
+            }
         }
-        Ok(None)
+        Ok(best.map(|(_, doc)| doc))
     }
 
     async fn fetch_lyrics_ovh_doc(&self, query: &LyricsQuery) -> Result<Option<LyricsDoc>> {
@@ -810,7 +924,7 @@ impl LyricsServiceCore {
         ))
     }
 
-    fn source_available(&self, source: &'static str) -> bool {
+    fn source_available(&self, source: &str) -> bool {
         let now_ms = unix_now_ms();
         let health = self
             .source_health
@@ -822,21 +936,21 @@ impl LyricsServiceCore {
         }
     }
 
-    fn mark_source_success(&self, source: &'static str) {
+    fn mark_source_success(&self, source: &str) {
         let mut health = self
             .source_health
             .lock()
             .expect("lyrics source health mutex poisoned");
-        health.insert(source, SourceHealth::default());
+        health.insert(source.to_string(), SourceHealth::default());
     }
 
-    fn mark_source_failure(&self, source: &'static str) {
+    fn mark_source_failure(&self, source: &str) {
         let now_ms = unix_now_ms();
         let mut health = self
             .source_health
             .lock()
             .expect("lyrics source health mutex poisoned");
-        let entry = health.entry(source).or_default();
+        let entry = health.entry(source.to_string()).or_default();
         entry.consecutive_failures = entry.consecutive_failures.saturating_add(1);
         if entry.consecutive_failures >= SOURCE_FAILURE_THRESHOLD {
             entry.blocked_until_ms = now_ms.saturating_add(SOURCE_COOLDOWN_MS);
@@ -871,7 +985,7 @@ impl LyricsServiceCore {
         url: Url,
         op_name: &str,
         allow_not_found: bool,
-        source: &'static str,
+        source: &str,
     ) -> Result<Option<Value>> {
         let mut last_error: Option<Error> = None;
 
@@ -1015,6 +1129,183 @@ impl LyricsServiceCore {
         Ok(())
     }
 
+    /// The track's timing: memory first, then the cache DB, else the default.
+    async fn timing(&self, track_key: &str) -> LyricsTiming {
+        if let Some(timing) = self
+            .state
+            .lock()
+            .expect("lyrics state mutex poisoned")
+            .timings
+            .get(track_key)
+        {
+            return timing.clone();
+        }
+        let timing = match self.load_timing_from_cache_db(track_key).await {
+            Ok(timing) => timing.unwrap_or_default(),
+            Err(err) => {
+                tracing::warn!("load lyrics timing failed: {err}");
+                LyricsTiming::default()
+            },
+        };
+        self.state
+            .lock()
+            .expect("lyrics state mutex poisoned")
+            .timings
+            .insert(track_key.to_string(), timing.clone());
+        timing
+    }
+
+    /// The track's timing as it applies to its current lyrics.
+    pub async fn get_timing(&self, track_key: String) -> LyricsTiming {
+        let track_key = track_key.trim();
+        let timing = self.timing(track_key).await;
+        match self.doc_for_track(track_key).await {
+            Some(doc) => timing_for_doc(timing, &doc),
+            None => timing,
+        }
+    }
+
+    pub async fn edit_timing(
+        &self,
+        track_key: String,
+        edit: LyricsTimingEdit,
+    ) -> Result<LyricsTiming> {
+        let track_key = track_key.trim().to_string();
+        if track_key.is_empty() {
+            return Err(anyhow!("track_key is empty"));
+        }
+        let mut timing = self.timing(&track_key).await;
+        let doc = self.doc_for_track(&track_key).await;
+        apply_edit(&mut timing, doc.as_ref(), edit)?;
+        self.store_timing(&track_key, timing.clone()).await?;
+        Ok(timing)
+    }
+
+    pub async fn export_lrc(&self, track_key: String, overwrite: bool) -> Result<String> {
+        let track_key = track_key.trim().to_string();
+        let track_path = Path::new(&track_key);
+        if !track_path.is_file() {
+            return Err(anyhow!("track is not a local file: {track_key}"));
+        }
+        let lrc_path = track_path.with_extension("lrc");
+        if !overwrite && lrc_path.exists() {
+            return Err(anyhow!("lrc already exists: {}", lrc_path.display()));
+        }
+        let doc = self
+            .doc_for_track(&track_key)
+            .await
+            .filter(|doc| doc.is_synced)
+            .ok_or_else(|| anyhow!("no synced lyrics loaded for track"))?;
+        let timing = timing_for_doc(self.timing(&track_key).await, &doc);
+        let mut doc = apply_timing(&doc, &timing);
+        doc.meta.offset_ms = 0;
+
+        fs::write(&lrc_path, format_lrc(&doc))
+            .with_context(|| format!("failed to write lrc: {}", lrc_path.display()))?;
+
+        // The sidecar now carries the corrected timing, so the next load must not add it
+        // again.
+        self.store_timing(&track_key, LyricsTiming::default())
+            .await?;
+        doc.source = "local_lrc".to_string();
+        let mut emit_ready = false;
+        {
+            let mut state = self.state.lock().expect("lyrics state mutex poisoned");
+            state.cache.insert(track_key.clone(), doc.clone());
+            if state.current_track_key.as_deref() == Some(track_key.as_str()) {
+                state.current_doc = Some(doc.clone());
+                state.current_line_index = -1;
+                state.current_word_index = -1;
+                emit_ready = true;
+            }
+        }
+        if emit_ready {
+            self.hub.emit(LyricsEvent::Ready { track_key, doc });
+        }
+        Ok(lrc_path.to_string_lossy().into_owned())
+    }
+
+    /// The lyrics currently known for `track_key`: current doc, memory cache, cache DB.
+    async fn doc_for_track(&self, track_key: &str) -> Option<LyricsDoc> {
+        {
+            let state = self.state.lock().expect("lyrics state mutex poisoned");
+            if state.current_track_key.as_deref() == Some(track_key)
+                && let Some(doc) = state.current_doc.as_ref()
+            {
+                return Some(doc.clone());
+            }
+            if let Some(doc) = state.cache.get(track_key) {
+                return Some(doc.clone());
+            }
+        }
+        self.load_doc_from_cache_db(track_key).await
+    }
+
+    async fn store_timing(&self, track_key: &str, timing: LyricsTiming) -> Result<()> {
+        self.persist_timing_to_cache_db(track_key, &timing).await?;
+        self.state
+            .lock()
+            .expect("lyrics state mutex poisoned")
+            .timings
+            .insert(track_key.to_string(), timing);
+        Ok(())
+    }
+
+    async fn load_timing_from_cache_db(&self, track_key: &str) -> Result<Option<LyricsTiming>> {
+        let Some(db_path) = self.cache_db_path() else {
+            return Ok(None);
+        };
+        let mut conn = Self::open_cache_db(&db_path).await?;
+        let row = sqlx::query("SELECT timing_json FROM lyrics_timing WHERE track_key = ?1 LIMIT 1")
+            .bind(track_key)
+            .fetch_optional(&mut conn)
+            .await
+            .context("query lyrics timing failed")?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let timing_json: String = row
+            .try_get("timing_json")
+            .context("lyrics timing missing timing_json")?;
+        let timing = serde_json::from_str(&timing_json).context("parse lyrics timing failed")?;
+        Ok(Some(timing))
+    }
+
+    async fn persist_timing_to_cache_db(
+        &self,
+        track_key: &str,
+        timing: &LyricsTiming,
+    ) -> Result<()> {
+        let Some(db_path) = self.cache_db_path() else {
+            return Ok(());
+        };
+        let mut conn = Self::open_cache_db(&db_path).await?;
+        if *timing == LyricsTiming::default() {
+            sqlx::query("DELETE FROM lyrics_timing WHERE track_key = ?1")
+                .bind(track_key)
+                .execute(&mut conn)
+                .await
+                .context("delete lyrics timing failed")?;
+            return Ok(());
+        }
+        let timing_json =
+            serde_json::to_string(timing).context("serialize lyrics timing failed")?;
+        sqlx::query(
+            "INSERT INTO lyrics_timing (track_key, timing_json, updated_at_ms)
+             VALUES (?1, ?2, ?3)
+             ON CONFLICT(track_key) DO UPDATE SET
+               timing_json = excluded.timing_json,
+               updated_at_ms = excluded.updated_at_ms",
+        )
+        .bind(track_key)
+        .bind(timing_json)
+        .bind(unix_now_ms())
+        .execute(&mut conn)
+        .await
+        .context("upsert lyrics timing failed")?;
+        Ok(())
+    }
+
     async fn init_cache_db(path: &Path) -> Result<()> {
         let mut conn = Self::open_cache_db(path).await?;
         sqlx::query(
@@ -1029,6 +1320,16 @@ impl LyricsServiceCore {
         .execute(&mut conn)
         .await
         .context("create lyrics cache table failed")?;
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS lyrics_timing (
+               track_key TEXT PRIMARY KEY NOT NULL,
+               timing_json TEXT NOT NULL,
+               updated_at_ms INTEGER NOT NULL
+             )",
+        )
+        .execute(&mut conn)
+        .await
+        .context("create lyrics timing table failed")?;
         Ok(())
     }
 
@@ -1051,16 +1352,60 @@ fn unix_now_ms() -> i64 {
     }
 }
 
-async fn load_local_lrc_doc_async(track_key: String) -> Option<LyricsDoc> {
-    match tokio::task::spawn_blocking(move || load_local_lrc_doc_blocking(&track_key)).await {
+async fn load_local_doc_async(track_key: String) -> Option<LyricsDoc> {
+    match tokio::task::spawn_blocking(move || load_local_doc_blocking(&track_key)).await {
         Ok(doc) => doc,
         Err(err) => {
-            tracing::warn!("load local lrc task failed: {err}");
+            tracing::warn!("load local lyrics task failed: {err}");
             None
         },
     }
 }
 
+/// Sidecar `.lrc` first, then lyrics embedded in the file's tags. Synced lyrics win:
+/// a plain sidecar only beats embedded lyrics that are plain as well.
+fn load_local_doc_blocking(track_key: &str) -> Option<LyricsDoc> {
+    let sidecar = load_local_lrc_doc_blocking(track_key);
+    if sidecar.as_ref().is_some_and(|doc| doc.is_synced) {
+        return sidecar;
+    }
+    match load_embedded_doc_blocking(track_key) {
+        Some(embedded) if embedded.is_synced || sidecar.is_none() => Some(embedded),
+        _ => sidecar,
+    }
+}
+
+fn load_embedded_doc_blocking(track_key: &str) -> Option<LyricsDoc> {
+    let track_path = Path::new(track_key);
+    if !track_path.is_file() {
+        return None;
+    }
+    let embedded = match stellatune_library::read_embedded_lyrics(track_path) {
+        Ok(embedded) => embedded?,
+        Err(err) => {
+            tracing::debug!(track_key, "read embedded lyrics failed: {err:#}");
+            return None;
+        },
+    };
+    if let Some(doc) = parse_timed_lines(
+        track_key,
+        "embedded",
+        embedded
+            .synced
+            .into_iter()
+            .map(|line| (line.start_ms, line.text)),
+    ) {
+        return Some(doc);
+    }
+    let docs: Vec<LyricsDoc> = embedded
+        .texts
+        .iter()
+        .filter_map(|text| parse_lyrics_text(track_key, "embedded", text))
+        .collect();
+    let synced = docs.iter().position(|doc| doc.is_synced).unwrap_or(0);
+    docs.into_iter().nth(synced)
+}
+
 fn load_local_lrc_doc_blocking(track_key: &str) -> Option<LyricsDoc> {
     let track_path = Path::new(track_key);
     if !track_path.exists() || !track_path.is_file() {
@@ -1100,8 +1445,7 @@ fn load_local_lrc_doc_blocking(track_key: &str) -> Option<LyricsDoc> {
 
 fn read_and_parse_lrc(path: &Path, track_key: &str) -> Option<LyricsDoc> {
     let content = fs::read_to_string(path).ok()?;
-    parse_lrc(track_key, "local_lrc", &content)
-        .or_else(|| parse_plain(track_key, "local_lrc", content.trim()))
+    parse_lyrics_text(track_key, "local_lrc", &content)
 }
 
 fn normalize_query(query: LyricsQuery) -> LyricsQuery {
@@ -1120,20 +1464,6 @@ fn trim_to_option(input: Option<String>) -> Option<String> {
         .filter(|s| !s.is_empty())
 }
 
-fn find_line_index(lines: &[LyricLine], position_ms: i64) -> i64 {
-    let mut idx = -1_i64;
-    for (i, line) in lines.iter().enumerate() {
-        match line.start_ms {
-            Some(start_ms) if position_ms >= start_ms => {
-                idx = i as i64;
-            },
-            Some(_) => break,
-            None => {},
-        }
-    }
-    idx
-}
-
 fn doc_from_lrclib_value(track_key: &str, value: &Value) -> Option<LyricsDoc> {
     let synced = value
         .get("syncedLyrics")
@@ -1259,109 +1589,3 @@ fn json_string(value: &Value, keys: &[&str]) -> Option<String> {
 fn json_opt_string(value: &Value, keys: &[&str]) -> Option<String> {
     json_string(value, keys)
 }
-
-fn parse_plain(track_key: &str, source: &str, plain: &str) -> Option<LyricsDoc> {
-    let lines: Vec<LyricLine> = plain
-        .lines()
-        .map(str::trim)
-        .filter(|line| !line.is_empty())
-        .map(|text| LyricLine {
-            start_ms: None,
-            end_ms: None,
-            text: text.to_string(),
-        })
-        .collect();
-
-    if lines.is_empty() {
-        return None;
-    }
-
-    Some(LyricsDoc {
-        track_key: track_key.to_string(),
-        source: source.to_string(),
-        is_synced: false,
-        lines,
-    })
-}
-
-fn parse_lrc(track_key: &str, source: &str, raw: &str) -> Option<LyricsDoc> {
-    let mut lines: Vec<LyricLine> = Vec::new();
-
-    for row in raw.lines() {
-        let mut s = row.trim();
-        if s.is_empty() {
-            continue;
-        }
-
-        let mut timestamps = Vec::new();
-        while s.starts_with('[') {
-            let Some(end_idx) = s.find(']') else {
-                break;
-            };
-            let tag = &s[1..end_idx];
-            if let Some(ms) = parse_timestamp_tag(tag) {
-                timestamps.push(ms);
-            }
-            s = s[end_idx + 1..].trim_start();
-        }
-
-        if timestamps.is_empty() {
-            continue;
-        }
-        if s.is_empty() {
-            continue;
-        }
-
-        for start_ms in timestamps {
-            lines.push(LyricLine {
-                start_ms: Some(start_ms),
-                end_ms: None,
-                text: s.to_string(),
-            });
-        }
-    }
-
-    if lines.is_empty() {
-        return None;
-    }
-
-    lines.sort_by_key(|l| l.start_ms.unwrap_or(i64::MAX));
-    for i in 0..lines.len().saturating_sub(1) {
-        let next_start = lines[i + 1].start_ms;
-        lines[i].end_ms = next_start;
-    }
-
-    Some(LyricsDoc {
-        track_key: track_key.to_string(),
-        source: source.to_string(),
-        is_synced: true,
-        lines,
-    })
-}
-
-fn parse_timestamp_tag(tag: &str) -> Option<i64> {
-    // Supports mm:ss, mm:ss.xx and mm:ss.xxx.
-    let (min_part, sec_part) = tag.split_once(':')?;
-    let minutes: i64 = min_part.parse().ok()?;
-
-    let (sec_str, frac_str) = match sec_part.split_once('.') {
-        Some((sec, frac)) => (sec, frac),
-        None => (sec_part, ""),
-    };
-    let seconds: i64 = sec_str.parse().ok()?;
-    if !(0..60).contains(&seconds) {
-        return None;
-    }
-
-    let mut frac_digits = frac_str.chars().take(3).collect::<String>();
-    while frac_digits.len() < 3 {
-        frac_digits.push('0');
-    }
-    let frac_ms: i64 = if frac_digits.is_empty() {
-        0
-    } else {
-        frac_digits.parse().ok()?
-    };
-
-    Some(minutes * 60_000 + seconds * 1_000 + frac_ms)
-}
@@ -0,0 +1,899 @@
+//! Lyrics payload parsers: plain text, (enhanced) LRC, TTML and NetEase yrc.
+//!
+//! Parsers apply the source's own timing offset, so every timestamp in a returned
+//! [`LyricsDoc`] is already on the track's timeline.
+
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node};
+use serde_json::Value;
+
+use crate::{LyricLine, LyricWord, LyricsDoc, LyricsMeta};
+
+/// Max distance between a translation/romanization line and the lyric line it annotates.
+const ALIGN_TOLERANCE_MS: i64 = 1_000;
+
+/// Detects the payload format and parses it, falling back to plain text.
+pub(super) fn parse_lyrics_text(track_key: &str, source: &str, raw: &str) -> Option<LyricsDoc> {
+    let trimmed = raw.trim_start_matches('\u{feff}').trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    if trimmed.starts_with('{')
+        && let Ok(value) = serde_json::from_str::<Value>(trimmed)
+        && let Some(doc) = parse_netease_payload(track_key, source, &value)
+    {
+        return Some(doc);
+    }
+    if trimmed.starts_with('<')
+        && let Some(doc) = parse_ttml(track_key, source, trimmed)
+    {
+        return Some(doc);
+    }
+    if trimmed
+        .lines()
+        .any(|row| parse_yrc_line_head(row.trim()).is_some())
+        && let Some(doc) = parse_yrc(track_key, source, trimmed)
+    {
+        return Some(doc);
+    }
+    parse_lrc(track_key, source, trimmed).or_else(|| parse_plain(track_key, source, trimmed))
+}
+
+pub(super) fn parse_plain(track_key: &str, source: &str, plain: &str) -> Option<LyricsDoc> {
+    let lines: Vec<LyricLine> = plain
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|text| line(None, None, text.to_string(), Vec::new()))
+        .collect();
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    Some(LyricsDoc {
+        track_key: track_key.to_string(),
+        source: source.to_string(),
+        is_synced: false,
+        lines,
+        meta: LyricsMeta::default(),
+    })
+}
+
+/// Builds a synced doc from already-timed lines, e.g. ID3 `SYLT` frames.
+pub(super) fn parse_timed_lines(
+    track_key: &str,
+    source: &str,
+    timed: impl IntoIterator<Item = (i64, String)>,
+) -> Option<LyricsDoc> {
+    let mut lines: Vec<LyricLine> = timed
+        .into_iter()
+        .filter_map(|(start_ms, text)| {
+            let text = text.trim();
+            (!text.is_empty())
+                .then(|| line(Some(start_ms.max(0)), None, text.to_string(), Vec::new()))
+        })
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+    lines.sort_by_key(|line| line.start_ms);
+    Some(synced_doc(track_key, source, lines, LyricsMeta::default()))
+}
+
+/// Parses LRC including enhanced `<mm:ss.xx>` word tags and header tags. A line that
+/// repeats the previous line's timestamp is taken as its translation.
+pub(super) fn parse_lrc(track_key: &str, source: &str, raw: &str) -> Option<LyricsDoc> {
+    let mut meta = LyricsMeta::default();
+    let mut lines: Vec<LyricLine> = Vec::new();
+
+    for row in raw.lines() {
+        let mut s = row.trim();
+        if s.is_empty() {
+            continue;
+        }
+
+        let mut timestamps = Vec::new();
+        while s.starts_with('[') {
+            let Some(end_idx) = s.find(']') else {
+                break;
+            };
+            let tag = &s[1..end_idx];
+            match parse_timestamp_tag(tag) {
+                Some(ms) => timestamps.push(ms),
+                None => read_lrc_header_tag(&mut meta, tag),
+            }
+            s = s[end_idx + 1..].trim_start();
+        }
+
+        if timestamps.is_empty() || s.is_empty() {
+            continue;
+        }
+
+        let (text, words) = parse_lrc_words(s, timestamps[0]);
+        if text.is_empty() {
+            continue;
+        }
+        for &start_ms in &timestamps {
+            // Word tags are absolute; shift them for repeated line timestamps.
+            let delta = start_ms - timestamps[0];
+            let words = words
+                .iter()
+                .map(|word| LyricWord {
+                    start_ms: word.start_ms + delta,
+                    end_ms: word.end_ms.map(|end_ms| end_ms + delta),
+                    text: word.text.clone(),
+                })
+                .collect();
+            lines.push(line(Some(start_ms), None, text.clone(), words));
+        }
+    }
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    lines.sort_by_key(|l| l.start_ms.unwrap_or(i64::MAX));
+    let lines = merge_repeated_timestamps(lines);
+    Some(synced_doc(track_key, source, lines, meta))
+}
+
+/// Parses TTML, including Apple Music's word-timed `<span>`s, `x-translation` /
+/// `x-roman` roles and `iTunesMetadata` translation/transliteration tracks.
+pub(super) fn parse_ttml(track_key: &str, source: &str, raw: &str) -> Option<LyricsDoc> {
+    let document = Document::parse(raw).ok()?;
+    let root = document.root_element();
+    if root.tag_name().name() != "tt" {
+        return None;
+    }
+
+    let mut meta = LyricsMeta::default();
+    let mut translations = HashMap::new();
+    let mut romanizations = HashMap::new();
+    if let Some(head) = root.children().find(|node| node.has_tag_name("head")) {
+        for node in head.descendants().filter(Node::is_element) {
+            match node.tag_name().name() {
+                "title" if meta.title.is_none() => meta.title = non_empty(node_text(node)),
+                "songwriter" if meta.author.is_none() => {
+                    meta.author = non_empty(node_text(node));
+                },
+                "translation" => collect_keyed_texts(node, &mut translations),
+                "transliteration" => collect_keyed_texts(node, &mut romanizations),
+                _ => {},
+            }
+        }
+    }
+
+    let body = root.children().find(|node| node.has_tag_name("body"))?;
+    let mut lines = Vec::new();
+    for p in body.descendants().filter(|node| node.has_tag_name("p")) {
+        let mut parsed = parse_ttml_line(p);
+        if parsed.text.is_empty() {
+            continue;
+        }
+        if let Some(key) = local_attribute(p, "key") {
+            if parsed.translation.is_none() {
+                parsed.translation = translations.get(key).cloned();
+            }
+            if parsed.romanization.is_none() {
+                parsed.romanization = romanizations.get(key).cloned();
+            }
+        }
+        lines.push(parsed);
+    }
+
+    if lines.is_empty() {
+        return None;
+    }
+    if lines.iter().all(|line| line.start_ms.is_none()) {
+        return Some(LyricsDoc {
+            track_key: track_key.to_string(),
+            source: source.to_string(),
+            is_synced: false,
+            lines,
+            meta,
+        });
+    }
+    lines.sort_by_key(|l| l.start_ms.unwrap_or(i64::MAX));
+    Some(synced_doc(track_key, source, lines, meta))
+}
+
+/// Parses NetEase yrc: `[start,duration](start,duration,0)word(start,duration,0)word`.
+/// JSON credit rows (`{"t":0,"c":[{"tx":"作词: "},...]}`) fill `meta.author`.
+pub(super) fn parse_yrc(track_key: &str, source: &str, raw: &str) -> Option<LyricsDoc> {
+    let mut meta = LyricsMeta::default();
+    let mut lines = Vec::new();
+
+    for row in raw.lines() {
+        let row = row.trim();
+        if row.starts_with('{') {
+            if meta.author.is_none() {
+                meta.author = yrc_credit_author(row);
+            }
+            continue;
+        }
+        let Some((start_ms, duration_ms, body)) = parse_yrc_line_head(row) else {
+            continue;
+        };
+
+        let mut words: Vec<LyricWord> = Vec::new();
+        let mut rest = body;
+        let mut leading = String::new();
+        loop {
+            let next = find_delimited_tag(rest, '(', ')', parse_yrc_word_tag);
+            let segment = next.map_or(rest, |(tag_start, _, _)| &rest[..tag_start]);
+            match words.last_mut() {
+                Some(word) => word.text.push_str(segment),
+                None => leading.push_str(segment),
+            }
+            let Some((_, tag_end, (word_start_ms, word_duration_ms))) = next else {
+                break;
+            };
+            words.push(LyricWord {
+                start_ms: word_start_ms,
+                end_ms: Some(word_start_ms + word_duration_ms),
+                text: String::new(),
+            });
+            rest = &rest[tag_end..];
+        }
+        if let Some(first) = words.first_mut() {
+            first.text.insert_str(0, &leading);
+        }
+
+        let (text, words) = if words.is_empty() {
+            (leading.trim().to_string(), words)
+        } else {
+            finish_words(words)
+        };
+        if text.is_empty() {
+            continue;
+        }
+        lines.push(line(
+            Some(start_ms),
+            Some(start_ms + duration_ms),
+            text,
+            words,
+        ));
+    }
+
+    if lines.is_empty() {
+        return None;
+    }
+    lines.sort_by_key(|l| l.start_ms.unwrap_or(i64::MAX));
+    Some(synced_doc(track_key, source, lines, meta))
+}
+
+/// Parses a NetEase lyric API response (`lrc`, `tlyric`, `romalrc`, `yrc`, `ytlrc`,
+/// `yromalrc`, each `{ "lyric": "..." }`). Word-timed `yrc` wins over `lrc` when present.
+pub(super) fn parse_netease_payload(
+    track_key: &str,
+    source: &str,
+    value: &Value,
+) -> Option<LyricsDoc> {
+    let track = |key: &str| {
+        value
+            .get(key)
+            .and_then(|entry| entry.get("lyric"))
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|raw| !raw.is_empty())
+    };
+
+    let (mut doc, translation, romanization) =
+        match track("yrc").and_then(|raw| parse_yrc(track_key, source, raw)) {
+            Some(doc) => (
+                doc,
+                track("ytlrc").or_else(|| track("tlyric")),
+                track("yromalrc").or_else(|| track("romalrc")),
+            ),
+            None => (
+                parse_lrc(track_key, source, track("lrc")?)?,
+                track("tlyric"),
+                track("romalrc"),
+            ),
+        };
+
+    if let Some(translation) = translation.and_then(|raw| parse_lrc("", "", raw)) {
+        align_track(&mut doc.lines, &translation.lines, |line, text| {
+            line.translation = Some(text);
+        });
+    }
+    if let Some(romanization) = romanization.and_then(|raw| parse_lrc("", "", raw)) {
+        align_track(&mut doc.lines, &romanization.lines, |line, text| {
+            line.romanization = Some(text);
+        });
+    }
+    Some(doc)
+}
+
+pub(super) fn parse_timestamp_tag(tag: &str) -> Option<i64> {
+    // Supports mm:ss, mm:ss.xx and mm:ss.xxx.
+    let (min_part, sec_part) = tag.split_once(':')?;
+    let minutes: i64 = min_part.trim().parse().ok()?;
+
+    let (sec_str, frac_str) = match sec_part.split_once('.') {
+        Some((sec, frac)) => (sec, frac),
+        None => (sec_part, ""),
+    };
+    let seconds: i64 = sec_str.trim().parse().ok()?;
+    if !(0..60).contains(&seconds) {
+        return None;
+    }
+
+    let mut frac_digits = frac_str.trim().chars().take(3).collect::<String>();
+    while frac_digits.len() < 3 {
+        frac_digits.push('0');
+    }
+    let frac_ms: i64 = frac_digits.parse().ok()?;
+
+    Some(minutes * 60_000 + seconds * 1_000 + frac_ms)
+}
+
+/// Writes `doc` as LRC: header tags from `meta`, enhanced word tags when the line has
+/// word timing, and translations as a second line with the same timestamp. Timestamps
+/// are written as-is, so no `[offset:]` tag is emitted.
+pub(super) fn format_lrc(doc: &LyricsDoc) -> String {
+    let mut out = String::new();
+    let headers = [
+        ("ti", &doc.meta.title),
+        ("ar", &doc.meta.artist),
+        ("al", &doc.meta.album),
+        ("au", &doc.meta.author),
+    ];
+    for (key, value) in headers {
+        if let Some(value) = value {
+            out.push_str(&format!("[{key}:{value}]\n"));
+        }
+    }
+    for line in &doc.lines {
+        let tag = line.start_ms.map(format_timestamp_tag).unwrap_or_default();
+        out.push_str(&tag);
+        if line.words.is_empty() || line.start_ms.is_none() {
+            out.push_str(&line.text);
+        } else {
+            for (i, word) in line.words.iter().enumerate() {
+                out.push_str(&format!("<{}>", format_timestamp(word.start_ms)));
+                out.push_str(&word.text);
+                let next_start = line.words.get(i + 1).map(|next| next.start_ms);
+                if let Some(end_ms) = word.end_ms
+                    && next_start != Some(end_ms)
+                {
+                    out.push_str(&format!("<{}>", format_timestamp(end_ms)));
+                }
+            }
+        }
+        out.push('\n');
+        if let Some(translation) = line.translation.as_deref().filter(|_| !tag.is_empty()) {
+            out.push_str(&format!("{tag}{translation}\n"));
+        }
+    }
+    out
+}
+
+fn format_timestamp_tag(ms: i64) -> String {
+    format!("[{}]", format_timestamp(ms))
+}
+
+fn format_timestamp(ms: i64) -> String {
+    let ms = ms.max(0);
+    format!(
+        "{:02}:{:02}.{:03}",
+        ms / 60_000,
+        ms / 1_000 % 60,
+        ms % 1_000
+    )
+}
+
+fn line(
+    start_ms: Option<i64>,
+    end_ms: Option<i64>,
+    text: String,
+    words: Vec<LyricWord>,
+) -> LyricLine {
+    LyricLine {
+        start_ms,
+        end_ms,
+        text,
+        words,
+        translation: None,
+        romanization: None,
+    }
+}
+
+/// Applies `meta.offset_ms` and fills missing line/word end times from what follows.
+fn synced_doc(
+    track_key: &str,
+    source: &str,
+    mut lines: Vec<LyricLine>,
+    meta: LyricsMeta,
+) -> LyricsDoc {
+    if meta.offset_ms != 0 {
+        // A positive LRC offset makes lyrics show up earlier.
+        let shift = |ms: i64| (ms - meta.offset_ms).max(0);
+        for line in &mut lines {
+            line.start_ms = line.start_ms.map(shift);
+            line.end_ms = line.end_ms.map(shift);
+            for word in &mut line.words {
+                word.start_ms = shift(word.start_ms);
+                word.end_ms = word.end_ms.map(shift);
+            }
+        }
+    }
+
+    for i in 0..lines.len() {
+        if lines[i].end_ms.is_none() {
+            lines[i].end_ms = match lines.get(i + 1).and_then(|next| next.start_ms) {
+                Some(next_start) => Some(next_start),
+                None => lines[i].words.last().and_then(|word| word.end_ms),
+            };
+        }
+        let line_end = lines[i].end_ms;
+        let words = &mut lines[i].words;
+        for j in 0..words.len() {
+            if words[j].end_ms.is_none() {
+                words[j].end_ms = words.get(j + 1).map(|next| next.start_ms).or(line_end);
+            }
+        }
+    }
+
+    LyricsDoc {
+        track_key: track_key.to_string(),
+        source: source.to_string(),
+        is_synced: true,
+        lines,
+        meta,
+    }
+}
+
+fn read_lrc_header_tag(meta: &mut LyricsMeta, tag: &str) {
+    let Some((key, value)) = tag.split_once(':') else {
+        return;
+    };
+    let value = value.trim();
+    match key.trim().to_ascii_lowercase().as_str() {
+        "ti" => meta.title = non_empty(value.to_string()),
+        "ar" => meta.artist = non_empty(value.to_string()),
+        "al" => meta.album = non_empty(value.to_string()),
+        "au" => meta.author = non_empty(value.to_string()),
+        "by" if meta.author.is_none() => meta.author = non_empty(value.to_string()),
+        "offset" => meta.offset_ms = value.trim_start_matches('+').parse().unwrap_or(0),
+        _ => {},
+    }
+}
+
+/// Splits an LRC line body into text and enhanced-LRC words. Text before the first word
+/// tag is timed at `line_start_ms`; a tag without text only closes the previous word.
+fn parse_lrc_words(body: &str, line_start_ms: i64) -> (String, Vec<LyricWord>) {
+    let mut words: Vec<LyricWord> = Vec::new();
+    let mut rest = body;
+    let mut leading = String::new();
+    loop {
+        let next = find_delimited_tag(rest, '<', '>', parse_timestamp_tag);
+        let segment = next.map_or(rest, |(tag_start, _, _)| &rest[..tag_start]);
+        match words.last_mut() {
+            Some(word) => word.text.push_str(segment),
+            None => leading.push_str(segment),
+        }
+        let Some((_, tag_end, start_ms)) = next else {
+            break;
+        };
+        close_empty_word(&mut words);
+        words.push(LyricWord {
+            start_ms,
+            end_ms: None,
+            text: String::new(),
+        });
+        rest = &rest[tag_end..];
+    }
+    close_empty_word(&mut words);
+
+    if words.is_empty() {
+        return (leading.trim().to_string(), words);
+    }
+    if !leading.trim().is_empty() {
+        words.insert(
+            0,
+            LyricWord {
+                start_ms: line_start_ms,
+                end_ms: None,
+                text: leading,
+            },
+        );
+    }
+    finish_words(words)
+}
+
+fn close_empty_word(words: &mut Vec<LyricWord>) {
+    if let Some(marker) = words.pop_if(|word| word.text.is_empty())
+        && let Some(prev) = words.last_mut()
+        && prev.end_ms.is_none()
+    {
+        prev.end_ms = Some(marker.start_ms);
+    }
+}
+
+/// Drops empty words, trims the outer whitespace and returns the joined line text.
+fn finish_words(words: Vec<LyricWord>) -> (String, Vec<LyricWord>) {
+    let mut words: Vec<LyricWord> = words
+        .into_iter()
+        .filter(|word| !word.text.is_empty())
+        .collect();
+    if let Some(first) = words.first_mut() {
+        first.text = first.text.trim_start().to_string();
+    }
+    if let Some(last) = words.last_mut() {
+        last.text = last.text.trim_end().to_string();
+    }
+    words.retain(|word| !word.text.is_empty());
+    let text = words.iter().map(|word| word.text.as_str()).collect();
+    (text, words)
+}
+
+/// Finds the first `open ... close` tag whose content `parse` accepts; delimiters around
+/// anything else are treated as text. Returns `(tag_start, tag_end, parsed)`.
+fn find_delimited_tag<T>(
+    s: &str,
+    open: char,
+    close: char,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Option<(usize, usize, T)> {
+    let mut from = 0;
+    while let Some(offset) = s[from..].find(open) {
+        let tag_start = from + offset;
+        let inner_start = tag_start + open.len_utf8();
+        let inner_len = s[inner_start..].find(close)?;
+        let tag_end = inner_start + inner_len + close.len_utf8();
+        if let Some(parsed) = parse(&s[inner_start..inner_start + inner_len]) {
+            return Some((tag_start, tag_end, parsed));
+        }
+        from = inner_start;
+    }
+    None
+}
+
+/// Merges a line that repeats the previous line's timestamp into its translation.
+fn merge_repeated_timestamps(lines: Vec<LyricLine>) -> Vec<LyricLine> {
+    let mut merged: Vec<LyricLine> = Vec::with_capacity(lines.len());
+    for line in lines {
+        if let Some(prev) = merged.last_mut()
+            && prev.start_ms == line.start_ms
+            && prev.translation.is_none()
+        {
+            if prev.text != line.text {
+                prev.translation = Some(line.text);
+            }
+            continue;
+        }
+        merged.push(line);
+    }
+    merged
+}
+
+/// Attaches each `track` line to the `lines` entry with the nearest start time.
+fn align_track(
+    lines: &mut [LyricLine],
+    track: &[LyricLine],
+    mut set: impl FnMut(&mut LyricLine, String),
+) {
+    for entry in track {
+        let Some(start_ms) = entry.start_ms else {
+            continue;
+        };
+        let text = entry.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let split = lines.partition_point(|line| line.start_ms.is_some_and(|s| s < start_ms));
+        let nearest = [split.checked_sub(1), Some(split)]
+            .into_iter()
+            .flatten()
+            .filter_map(|idx| {
+                let line_start = lines.get(idx)?.start_ms?;
+                Some((idx, (line_start - start_ms).abs()))
+            })
+            .min_by_key(|&(_, distance)| distance);
+        if let Some((idx, distance)) = nearest
+            && distance <= ALIGN_TOLERANCE_MS
+        {
+            set(&mut lines[idx], text.to_string());
+        }
+    }
+}
+
+fn parse_yrc_line_head(row: &str) -> Option<(i64, i64, &str)> {
+    let rest = row.strip_prefix('[')?;
+    let (head, body) = rest.split_once(']')?;
+    let (start, duration) = head.split_once(',')?;
+    Some((
+        start.trim().parse().ok()?,
+        duration.trim().parse().ok()?,
+        body,
+    ))
+}
+
+fn parse_yrc_word_tag(tag: &str) -> Option<(i64, i64)> {
+    let mut parts = tag.split(',').map(str::trim);
+    let start = parts.next()?.parse().ok()?;
+    let duration = parts.next()?.parse().ok()?;
+    parts.next()?.parse::<i64>().ok()?;
+    parts.next().is_none().then_some((start, duration))
+}
+
+fn yrc_credit_author(row: &str) -> Option<String> {
+    let value = serde_json::from_str::<Value>(row).ok()?;
+    let text = value
+        .get("c")?
+        .as_array()?
+        .iter()
+        .filter_map(|part| part.get("tx").and_then(Value::as_str))
+        .collect::<String>();
+    let (label, name) = text.split_once([':', '：'])?;
+    if !label.contains("作词") {
+        return None;
+    }
+    non_empty(name.trim().to_string())
+}
+
+fn parse_ttml_line(p: Node<'_, '_>) -> LyricLine {
+    let mut words: Vec<LyricWord> = Vec::new();
+    let mut leading = String::new();
+    let mut translation = None;
+    let mut romanization = None;
+
+    for child in p.children() {
+        let segment = if child.is_text() {
+            child.text().unwrap_or_default().to_string()
+        } else if child.has_tag_name("span") {
+            match local_attribute(child, "role") {
+                Some("x-translation") => {
+                    translation = non_empty(node_text(child));
+                    continue;
+                },
+                Some("x-roman") => {
+                    romanization = non_empty(node_text(child));
+                    continue;
+                },
+                // Background vocals overlap the main line; keep the lead line clean.
+                Some("x-bg") => continue,
+                _ => {},
+            }
+            if let Some(start_ms) = child.attribute("begin").and_then(parse_ttml_time) {
+                words.push(LyricWord {
+                    start_ms,
+                    end_ms: child.attribute("end").and_then(parse_ttml_time),
+                    text: String::new(),
+                });
+            }
+            node_text(child)
+        } else {
+            continue;
+        };
+        match words.last_mut() {
+            Some(word) => word.text.push_str(&segment),
+            None => leading.push_str(&segment),
+        }
+    }
+
+    let (text, words) = match words.first_mut() {
+        None => (collapse_whitespace(&leading), words),
+        Some(first) => {
+            first.text.insert_str(0, &leading);
+            let (_, words) = finish_words(words);
+            let words: Vec<LyricWord> = words
+                .into_iter()
+                .map(|word| LyricWord {
+                    text: collapse_inner_whitespace(&word.text),
+                    ..word
+                })
+                .collect();
+            (words.iter().map(|word| word.text.as_str()).collect(), words)
+        },
+    };
+
+    LyricLine {
+        start_ms: p.attribute("begin").and_then(parse_ttml_time),
+        end_ms: p.attribute("end").and_then(parse_ttml_time),
+        text,
+        words,
+        translation,
+        romanization,
+    }
+}
+
+/// Parses TTML clock (`hh:mm:ss.fff`, `mm:ss.fff`, `ss.fff`) and offset (`1.5s`,
+/// `200ms`) time expressions.
+fn parse_ttml_time(value: &str) -> Option<i64> {
+    let value = value.trim();
+    if let Some(ms) = value.strip_suffix("ms") {
+        return ms.trim().parse::<f64>().ok().map(|ms| ms.round() as i64);
+    }
+    if let Some(seconds) = value.strip_suffix('s') {
+        return seconds
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .map(|s| (s * 1_000.0).round() as i64);
+    }
+    let parts = value.split(':').collect::<Vec<_>>();
+    if parts.is_empty() || parts.len() > 3 {
+        return None;
+    }
+    let mut seconds = 0.0_f64;
+    for part in parts {
+        seconds = seconds * 60.0 + part.trim().parse::<f64>().ok()?;
+    }
+    (seconds >= 0.0).then(|| (seconds * 1_000.0).round() as i64)
+}
+
+fn collect_keyed_texts(node: Node<'_, '_>, out: &mut HashMap<String, String>) {
+    for text in node.children().filter(|child| child.has_tag_name("text")) {
+        if let Some(key) = text.attribute("for")
+            && let Some(value) = non_empty(collapse_whitespace(&node_text(text)))
+        {
+            // Only the first translation language is kept.
+            out.entry(key.to_string()).or_insert(value);
+        }
+    }
+}
+
+fn local_attribute<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.attributes()
+        .find(|attribute| attribute.name() == name)
+        .map(|attribute| attribute.value())
+}
+
+fn node_text(node: Node<'_, '_>) -> String {
+    node.descendants()
+        .filter(Node::is_text)
+        .filter_map(|text| text.text())
+        .collect()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Collapses whitespace runs to a single space but keeps a leading/trailing one, which
+/// separates words in a TTML line.
+fn collapse_inner_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_space = false;
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            if !in_space {
+                out.push(' ');
+            }
+            in_space = true;
+        } else {
+            out.push(ch);
+            in_space = false;
+        }
+    }
+    out
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        format_lrc, parse_lrc, parse_lyrics_text, parse_netease_payload, parse_ttml, parse_yrc,
+    };
+
+    fn word_texts(line: &crate::LyricLine) -> Vec<(i64, Option<i64>, &str)> {
+        line.words
+            .iter()
+            .map(|word| (word.start_ms, word.end_ms, word.text.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn lrc_reads_word_tags_header_and_translation() {
+        let raw = "[ti:Song]\n[au:Writer]\n[offset:+100]\n\
+                   [00:01.00]<00:01.00>Hello <00:01.50>world<00:02.00>\n\
+                   [00:01.00]你好世界\n\
+                   [00:03.00]Plain line";
+        let doc = parse_lrc("t", "test", raw).expect("parsed");
+        assert_eq!(doc.meta.title.as_deref(), Some("Song"));
+        assert_eq!(doc.meta.author.as_deref(), Some("Writer"));
+        assert_eq!(doc.meta.offset_ms, 100);
+        assert_eq!(doc.lines.len(), 2);
+
+        let first = &doc.lines[0];
+        assert_eq!(first.start_ms, Some(900));
+        assert_eq!(first.end_ms, Some(2_900));
+        assert_eq!(first.text, "Hello world");
+        assert_eq!(first.translation.as_deref(), Some("你好世界"));
+        assert_eq!(
+            word_texts(first),
+            vec![(900, Some(1_400), "Hello "), (1_400, Some(1_900), "world")]
+        );
+        assert!(doc.lines[1].words.is_empty());
+    }
+
+    #[test]
+    fn lrc_export_round_trips() {
+        let raw = "[ti:Song]\n[offset:+100]\n\
+                   [00:01.00]<00:01.00>Hello <00:01.50>world<00:02.00>\n\
+                   [00:01.00]你好世界\n\
+                   [01:03.25]Plain line";
+        let doc = parse_lrc("t", "test", raw).expect("parsed");
+        let exported = format_lrc(&doc);
+        assert!(exported.starts_with("[ti:Song]\n[00:00.900]<00:00.900>Hello "));
+        assert!(exported.contains("[00:00.900]你好世界\n[01:03.150]Plain line\n"));
+
+        let reparsed = parse_lrc("t", "test", &exported).expect("reparsed");
+        assert_eq!(reparsed.lines, doc.lines);
+        assert_eq!(reparsed.meta.offset_ms, 0);
+    }
+
+    #[test]
+    fn ttml_reads_spans_roles_and_itunes_translations() {
+        let raw = r#"<tt xmlns="http://www.w3.org/ns/ttml" xmlns:itunes="http://music.apple.com/lyric-ttml-internal" xmlns:ttm="http://www.w3.org/ns/ttml#metadata">
+<head><metadata><iTunesMetadata xmlns="http://music.apple.com/lyric-ttml-internal">
+<translations><translation xml:lang="en"><text for="L1">Hello world</text></translation></translations>
+<songwriters><songwriter>Writer</songwriter></songwriters>
+</iTunesMetadata></metadata></head>
+<body><div>
+<p begin="1.000" end="2.500" itunes:key="L1"><span begin="1.000" end="1.400">你</span><span begin="1.400" end="2.000">好</span> <span begin="2.000" end="2.500">世界</span><span ttm:role="x-roman">ni hao shi jie</span></p>
+<p begin="00:03.000" end="00:04.000">Second line</p>
+</div></body></tt>"#;
+        let doc = parse_ttml("t", "test", raw).expect("parsed");
+        assert_eq!(doc.meta.author.as_deref(), Some("Writer"));
+        assert_eq!(doc.lines.len(), 2);
+
+        let first = &doc.lines[0];
+        assert_eq!(first.text, "你好 世界");
+        assert_eq!(first.translation.as_deref(), Some("Hello world"));
+        assert_eq!(first.romanization.as_deref(), Some("ni hao shi jie"));
+        assert_eq!(
+            word_texts(first),
+            vec![
+                (1_000, Some(1_400), "你"),
+                (1_400, Some(2_000), "好 "),
+                (2_000, Some(2_500), "世界"),
+            ]
+        );
+        assert_eq!(doc.lines[1].start_ms, Some(3_000));
+        assert_eq!(doc.lines[1].text, "Second line");
+    }
+
+    #[test]
+    fn yrc_payload_merges_translation_and_romanization() {
+        let payload = json!({
+            "lrc": { "lyric": "[00:01.00]你好世界" },
+            "yrc": { "lyric": "{\"t\":0,\"c\":[{\"tx\":\"作词: \"},{\"tx\":\"Writer\"}]}\n[1000,1500](1000,400,0)你(1400,600,0)好(2000,500,0)世界" },
+            "tlyric": { "lyric": "[00:01.05]Hello world" },
+            "romalrc": { "lyric": "[00:01.00]ni hao shi jie" },
+        });
+        let doc = parse_netease_payload("t", "test", &payload).expect("parsed");
+        assert_eq!(doc.meta.author.as_deref(), Some("Writer"));
+        let line = &doc.lines[0];
+        assert_eq!((line.start_ms, line.end_ms), (Some(1_000), Some(2_500)));
+        assert_eq!(line.text, "你好世界");
+        assert_eq!(line.words.len(), 3);
+        assert_eq!(line.translation.as_deref(), Some("Hello world"));
+        assert_eq!(line.romanization.as_deref(), Some("ni hao shi jie"));
+
+        let raw = "[1000,1500](1000,400,0)A (1400,600,0)(b)";
+        let doc = parse_yrc("t", "test", raw).expect("parsed");
+        assert_eq!(doc.lines[0].text, "A (b)");
+    }
+
+    #[test]
+    fn detects_payload_format() {
+        let lrc = parse_lyrics_text("t", "test", "[00:01.00]line").expect("lrc");
+        assert!(lrc.is_synced);
+        let yrc = parse_lyrics_text("t", "test", "[1000,500](1000,500,0)line").expect("yrc");
+        assert_eq!(yrc.lines[0].words.len(), 1);
+        let plain = parse_lyrics_text("t", "test", "just\ntext").expect("plain");
+        assert!(!plain.is_synced);
+        assert_eq!(plain.lines.len(), 2);
+    }
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use stellatune_runtime::tokio_actor::{ActorContext, Handler, Message};
+
+use crate::lyrics_service::LyricsServiceActor;
+use crate::{LyricsTiming, LyricsTimingEdit};
+
+pub(crate) struct EditTimingMessage {
+    pub(crate) track_key: String,
+    pub(crate) edit: LyricsTimingEdit,
+}
+
+impl Message for EditTimingMessage {
+    type Response = Result<LyricsTiming>;
+}
+
+#[async_trait::async_trait]
+impl Handler<EditTimingMessage> for LyricsServiceActor {
+    async fn handle(
+        &mut self,
+        message: EditTimingMessage,
+        _ctx: &mut ActorContext<Self>,
+    ) -> Result<LyricsTiming> {
+        self.core.edit_timing(message.track_key, message.edit).await
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use stellatune_runtime::tokio_actor::{ActorContext, Handler, Message};
+
+use crate::lyrics_service::LyricsServiceActor;
+
+pub(crate) struct ExportLrcMessage {
+    pub(crate) track_key: String,
+    pub(crate) overwrite: bool,
+}
+
+impl Message for ExportLrcMessage {
+    type Response = Result<String>;
+}
+
+#[async_trait::async_trait]
+impl Handler<ExportLrcMessage> for LyricsServiceActor {
+    async fn handle(
+        &mut self,
+        message: ExportLrcMessage,
+        _ctx: &mut ActorContext<Self>,
+    ) -> Result<String> {
+        self.core
+            .export_lrc(message.track_key, message.overwrite)
+            .await
+    }
+}
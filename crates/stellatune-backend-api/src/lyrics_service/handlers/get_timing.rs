@@ -0,0 +1,23 @@
+use stellatune_runtime::tokio_actor::{ActorContext, Handler, Message};
+
+use crate::LyricsTiming;
+use crate::lyrics_service::LyricsServiceActor;
+
+pub(crate) struct GetTimingMessage {
+    pub(crate) track_key: String,
+}
+
+impl Message for GetTimingMessage {
+    type Response = LyricsTiming;
+}
+
+#[async_trait::async_trait]
+impl Handler<GetTimingMessage> for LyricsServiceActor {
+    async fn handle(
+        &mut self,
+        message: GetTimingMessage,
+        _ctx: &mut ActorContext<Self>,
+    ) -> LyricsTiming {
+        self.core.get_timing(message.track_key).await
+    }
+}
@@ -0,0 +1,21 @@
+use stellatune_runtime::tokio_actor::{ActorContext, Handler, Message};
+
+use crate::LyricsProviderStatus;
+use crate::lyrics_service::LyricsServiceActor;
+
+pub(crate) struct ListProvidersMessage;
+
+impl Message for ListProvidersMessage {
+    type Response = Vec<LyricsProviderStatus>;
+}
+
+#[async_trait::async_trait]
+impl Handler<ListProvidersMessage> for LyricsServiceActor {
+    async fn handle(
+        &mut self,
+        _message: ListProvidersMessage,
+        _ctx: &mut ActorContext<Self>,
+    ) -> Vec<LyricsProviderStatus> {
+        self.core.list_providers().await
+    }
+}
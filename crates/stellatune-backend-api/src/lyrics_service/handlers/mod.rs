@@ -1,8 +1,13 @@
 pub(super) mod apply_candidate;
 pub(super) mod clear_cache;
+pub(super) mod edit_timing;
+pub(super) mod export_lrc;
+pub(super) mod get_timing;
+pub(super) mod list_providers;
 pub(super) mod prefetch;
 pub(super) mod prepare;
 pub(super) mod refresh_current;
 pub(super) mod search_candidates;
 pub(super) mod set_cache_db_path;
 pub(super) mod set_position_ms;
+pub(super) mod set_provider_policy;
@@ -0,0 +1,23 @@
+use stellatune_runtime::tokio_actor::{ActorContext, Handler, Message};
+
+use crate::LyricsProviderPolicy;
+use crate::lyrics_service::LyricsServiceActor;
+
+pub(crate) struct SetProviderPolicyMessage {
+    pub(crate) policy: LyricsProviderPolicy,
+}
+
+impl Message for SetProviderPolicyMessage {
+    type Response = ();
+}
+
+#[async_trait::async_trait]
+impl Handler<SetProviderPolicyMessage> for LyricsServiceActor {
+    async fn handle(
+        &mut self,
+        message: SetProviderPolicyMessage,
+        _ctx: &mut ActorContext<Self>,
+    ) -> () {
+        self.core.set_provider_policy(message.policy);
+    }
+}
@@ -0,0 +1,673 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result, anyhow};
+use stellatune_plugins::host_runtime::RuntimeLyricsPlugin;
+use stellatune_plugins::host_runtime::runtime_service::SharedPluginRuntime;
+use stellatune_plugins::manifest::AbilityKind;
+use stellatune_plugins::runtime::model::RuntimeLyricCandidate;
+
+use super::formats::parse_lyrics_text;
+use super::{LyricsServiceCore, SOURCE_LRCLIB, SOURCE_LYRICS_OVH, SourceHealth, unix_now_ms};
+use crate::runtime::shared_plugin_runtime;
+use crate::{
+    LyricsDoc, LyricsProviderMode, LyricsProviderPolicy, LyricsProviderStatus, LyricsQuery,
+    LyricsSearchCandidate,
+};
+
+const PLUGIN_PROVIDER_PREFIX: &str = "plugin:";
+const PROVIDER_CACHE_HIT_TTL_MS: i64 = 30 * 60 * 1_000;
+const PROVIDER_CACHE_MISS_TTL_MS: i64 = 5 * 60 * 1_000;
+const PROVIDER_CACHE_MAX_ENTRIES: usize = 256;
+const PLUGIN_FETCH_MAX_CANDIDATES: usize = 3;
+const PLUGIN_SEARCH_MAX_CANDIDATES: usize = 5;
+const DURATION_TOLERANCE_MS: i64 = 3_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum LyricsProvider {
+    Lrclib,
+    LyricsOvh,
+    Plugin { plugin_id: String, type_id: String },
+}
+
+impl LyricsProvider {
+    pub(super) fn id(&self) -> String {
+        match self {
+            Self::Lrclib => SOURCE_LRCLIB.to_string(),
+            Self::LyricsOvh => SOURCE_LYRICS_OVH.to_string(),
+            Self::Plugin { plugin_id, type_id } => {
+                format!("{PLUGIN_PROVIDER_PREFIX}{plugin_id}/{type_id}")
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct KnownProvider {
+    provider: LyricsProvider,
+    display_name: String,
+}
+
+#[derive(Clone)]
+pub(super) struct ProviderCacheEntry {
+    doc: Option<LyricsDoc>,
+    cached_at_ms: i64,
+}
+
+/// Lyrics plugin instances kept across calls, keyed by provider id. Dropped wholesale
+/// when the set of active plugins changes, and per provider after a failed call.
+#[derive(Default)]
+pub(super) struct LyricsPluginCache {
+    revision: Option<u64>,
+    instances: HashMap<String, Arc<Mutex<RuntimeLyricsPlugin>>>,
+}
+
+impl LyricsPluginCache {
+    fn get(
+        &mut self,
+        runtime: &SharedPluginRuntime,
+        key: &str,
+    ) -> Option<Arc<Mutex<RuntimeLyricsPlugin>>> {
+        let revision = runtime.active_plugins_revision();
+        if self.revision != Some(revision) {
+            self.revision = Some(revision);
+            self.instances.clear();
+        }
+        self.instances.get(key).cloned()
+    }
+
+    fn evict(&mut self, key: &str, instance: &Arc<Mutex<RuntimeLyricsPlugin>>) {
+        if self
+            .instances
+            .get(key)
+            .is_some_and(|cached| Arc::ptr_eq(cached, instance))
+        {
+            self.instances.remove(key);
+        }
+    }
+}
+
+/// Conflict resolution between results: a doc whose timing fits the track beats one that
+/// does not, then synced beats plain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct DocRank {
+    duration_ok: bool,
+    synced: bool,
+}
+
+impl DocRank {
+    /// `reported_duration_ms` is the track duration the provider claims for the result.
+    pub(super) fn new(
+        doc: &LyricsDoc,
+        query: &LyricsQuery,
+        reported_duration_ms: Option<i64>,
+    ) -> Self {
+        let expected = query.duration_ms.filter(|duration| *duration > 0);
+        let reported_ok = match (expected, reported_duration_ms) {
+            (Some(expected), Some(reported)) => {
+                (reported - expected).abs() <= DURATION_TOLERANCE_MS
+            },
+            _ => true,
+        };
+        let last_line_ok = match (expected, doc.lines.iter().filter_map(|l| l.start_ms).max()) {
+            (Some(expected), Some(last_start)) => {
+                last_start <= expected.saturating_add(DURATION_TOLERANCE_MS)
+            },
+            _ => true,
+        };
+        Self {
+            duration_ok: reported_ok && last_line_ok,
+            synced: doc.is_synced,
+        }
+    }
+
+    pub(super) fn is_best(self) -> bool {
+        self.duration_ok && self.synced
+    }
+}
+
+impl LyricsServiceCore {
+    pub fn set_provider_policy(&self, policy: LyricsProviderPolicy) {
+        *self
+            .provider_policy
+            .lock()
+            .expect("lyrics provider policy mutex poisoned") = normalize_policy(policy);
+    }
+
+    pub async fn list_providers(&self) -> Vec<LyricsProviderStatus> {
+        let known = known_providers().await;
+        let enabled = self.order_providers(known.clone());
+        let enabled_ids = enabled
+            .iter()
+            .map(|item| item.provider.id())
+            .collect::<HashSet<_>>();
+        let now_ms = unix_now_ms();
+        enabled
+            .into_iter()
+            .chain(
+                known
+                    .into_iter()
+                    .filter(|item| !enabled_ids.contains(&item.provider.id())),
+            )
+            .map(|item| {
+                let id = item.provider.id();
+                let health = self.source_health(&id);
+                LyricsProviderStatus {
+                    enabled: enabled_ids.contains(&id),
+                    available: now_ms >= health.blocked_until_ms,
+                    consecutive_failures: health.consecutive_failures,
+                    blocked_until_ms: health.blocked_until_ms,
+                    plugin_id: match &item.provider {
+                        LyricsProvider::Plugin { plugin_id, .. } => Some(plugin_id.clone()),
+                        _ => None,
+                    },
+                    display_name: item.display_name,
+                    id,
+                }
+            })
+            .collect()
+    }
+
+    /// Enabled providers in the order they should be consulted.
+    pub(super) async fn resolve_providers(&self) -> Vec<LyricsProvider> {
+        self.order_providers(known_providers().await)
+            .into_iter()
+            .map(|item| item.provider)
+            .collect()
+    }
+
+    fn order_providers(&self, known: Vec<KnownProvider>) -> Vec<KnownProvider> {
+        let policy = self
+            .provider_policy
+            .lock()
+            .expect("lyrics provider policy mutex poisoned")
+            .clone();
+        order_providers(&policy, known, |id| self.source_health(id))
+    }
+
+    pub(super) async fn fetch_from_provider(
+        &self,
+        provider: &LyricsProvider,
+        query: &LyricsQuery,
+    ) -> Result<Option<LyricsDoc>> {
+        let source = provider.id();
+        let cache_key = provider_cache_key(&source, query);
+        if let Some(doc) = self.cached_provider_result(&cache_key) {
+            return Ok(doc);
+        }
+        let result = match provider {
+            LyricsProvider::Lrclib => self.fetch_lrclib(query).await,
+            LyricsProvider::LyricsOvh => self.fetch_lyrics_ovh_doc(query).await,
+            LyricsProvider::Plugin { plugin_id, type_id } => {
+                self.fetch_plugin_doc(plugin_id, type_id, query).await
+            },
+        };
+        if let Ok(doc) = &result {
+            self.store_provider_result(cache_key, doc.clone());
+        }
+        result
+    }
+
+    async fn fetch_plugin_doc(
+        &self,
+        plugin_id: &str,
+        type_id: &str,
+        query: &LyricsQuery,
+    ) -> Result<Option<LyricsDoc>> {
+        let source = LyricsProvider::Plugin {
+            plugin_id: plugin_id.to_string(),
+            type_id: type_id.to_string(),
+        }
+        .id();
+        let result = self
+            .with_lyrics_plugin(plugin_id, type_id, {
+                let source = source.clone();
+                let query = query.clone();
+                move |plugin| {
+                    let mut best: Option<(DocRank, LyricsDoc)> = None;
+                    for candidate in search_plugin(plugin, &query)?
+                        .into_iter()
+                        .take(PLUGIN_FETCH_MAX_CANDIDATES)
+                    {
+                        let Some(doc) =
+                            fetch_plugin_candidate(plugin, &source, &query, &candidate)?
+                        else {
+                            continue;
+                        };
+                        let rank = DocRank::new(&doc, &query, None);
+                        if best.as_ref().is_none_or(|(known, _)| rank > *known) {
+                            best = Some((rank, doc));
+                        }
+                        if rank.is_best() {
+                            break;
+                        }
+                    }
+                    Ok(best.map(|(_, doc)| doc))
+                }
+            })
+            .await;
+        self.mark_plugin_result(&source, &result);
+        result
+    }
+
+    pub(super) async fn search_candidates_plugin(
+        &self,
+        plugin_id: &str,
+        type_id: &str,
+        query: &LyricsQuery,
+    ) -> Result<Vec<LyricsSearchCandidate>> {
+        let source = LyricsProvider::Plugin {
+            plugin_id: plugin_id.to_string(),
+            type_id: type_id.to_string(),
+        }
+        .id();
+        let result = self
+            .with_lyrics_plugin(plugin_id, type_id, {
+                let source = source.clone();
+                let query = query.clone();
+                move |plugin| {
+                    let mut out = Vec::new();
+                    for candidate in search_plugin(plugin, &query)?
+                        .into_iter()
+                        .take(PLUGIN_SEARCH_MAX_CANDIDATES)
+                    {
+                        let Some(doc) =
+                            fetch_plugin_candidate(plugin, &source, &query, &candidate)?
+                        else {
+                            continue;
+                        };
+                        out.push(LyricsSearchCandidate {
+                            candidate_id: format!("{source}:{}", candidate.id),
+                            title: candidate.title,
+                            artist: Some(candidate.artist)
+                                .filter(|artist| !artist.trim().is_empty()),
+                            album: None,
+                            source: source.clone(),
+                            is_synced: doc.is_synced,
+                            preview: doc
+                                .lines
+                                .iter()
+                                .map(|line| line.text.trim())
+                                .find(|text| !text.is_empty())
+                                .map(str::to_string),
+                            doc,
+                        });
+                    }
+                    Ok(out)
+                }
+            })
+            .await;
+        self.mark_plugin_result(&source, &result);
+        result
+    }
+
+    /// Runs `f` on the cached instance of a lyrics plugin on a blocking thread, creating
+    /// the instance on first use.
+    async fn with_lyrics_plugin<T, F>(&self, plugin_id: &str, type_id: &str, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut RuntimeLyricsPlugin) -> Result<T> + Send + 'static,
+    {
+        let cache = Arc::clone(&self.lyrics_plugins);
+        let plugin_id = plugin_id.to_string();
+        let type_id = type_id.to_string();
+        tokio::task::spawn_blocking(move || {
+            let runtime = shared_plugin_runtime();
+            let key = LyricsProvider::Plugin {
+                plugin_id: plugin_id.clone(),
+                type_id: type_id.clone(),
+            }
+            .id();
+            let cached = cache
+                .lock()
+                .expect("lyrics plugin cache mutex poisoned")
+                .get(&runtime, &key);
+            let instance = match cached {
+                Some(instance) => instance,
+                None => {
+                    let plugin = runtime
+                        .create_lyrics_plugin(&plugin_id, &type_id)
+                        .with_context(|| {
+                            format!("create lyrics plugin {plugin_id}/{type_id} failed")
+                        })?;
+                    let instance = Arc::new(Mutex::new(plugin));
+                    cache
+                        .lock()
+                        .expect("lyrics plugin cache mutex poisoned")
+                        .instances
+                        .insert(key.clone(), Arc::clone(&instance));
+                    instance
+                },
+            };
+            let result = {
+                let mut plugin = instance
+                    .lock()
+                    .expect("lyrics plugin instance mutex poisoned");
+                f(&mut plugin)
+            };
+            if let Err(error) = &result {
+                // The instance may be left in a bad state; the next call starts fresh.
+                cache
+                    .lock()
+                    .expect("lyrics plugin cache mutex poisoned")
+                    .evict(&key, &instance);
+                runtime.report_operation_error(
+                    &plugin_id,
+                    AbilityKind::Lyrics,
+                    &type_id,
+                    format!("{error:#}"),
+                );
+            }
+            result
+        })
+        .await
+        .context("lyrics plugin task failed")?
+    }
+
+    fn mark_plugin_result<T>(&self, source: &str, result: &Result<T>) {
+        match result {
+            Ok(_) => self.mark_source_success(source),
+            Err(_) => self.mark_source_failure(source),
+        }
+    }
+
+    pub(super) fn source_health(&self, source: &str) -> SourceHealth {
+        self.source_health
+            .lock()
+            .expect("lyrics source health mutex poisoned")
+            .get(source)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub(super) fn clear_provider_cache(&self) {
+        self.provider_cache
+            .lock()
+            .expect("lyrics provider cache mutex poisoned")
+            .clear();
+    }
+
+    fn cached_provider_result(&self, key: &str) -> Option<Option<LyricsDoc>> {
+        let cache = self
+            .provider_cache
+            .lock()
+            .expect("lyrics provider cache mutex poisoned");
+        let entry = cache.get(key)?;
+        let ttl_ms = if entry.doc.is_some() {
+            PROVIDER_CACHE_HIT_TTL_MS
+        } else {
+            PROVIDER_CACHE_MISS_TTL_MS
+        };
+        (unix_now_ms().saturating_sub(entry.cached_at_ms) <= ttl_ms).then(|| entry.doc.clone())
+    }
+
+    fn store_provider_result(&self, key: String, doc: Option<LyricsDoc>) {
+        let now_ms = unix_now_ms();
+        let mut cache = self
+            .provider_cache
+            .lock()
+            .expect("lyrics provider cache mutex poisoned");
+        if cache.len() >= PROVIDER_CACHE_MAX_ENTRIES {
+            cache.retain(|_, entry| {
+                now_ms.saturating_sub(entry.cached_at_ms) <= PROVIDER_CACHE_MISS_TTL_MS
+            });
+        }
+        if cache.len() >= PROVIDER_CACHE_MAX_ENTRIES
+            && let Some(oldest) = cache
+                .iter()
+                .min_by_key(|(_, entry)| entry.cached_at_ms)
+                .map(|(key, _)| key.clone())
+        {
+            cache.remove(&oldest);
+        }
+        cache.insert(
+            key,
+            ProviderCacheEntry {
+                doc,
+                cached_at_ms: now_ms,
+            },
+        );
+    }
+}
+
+async fn known_providers() -> Vec<KnownProvider> {
+    let mut out = vec![
+        KnownProvider {
+            provider: LyricsProvider::Lrclib,
+            display_name: "LRCLIB".to_string(),
+        },
+        KnownProvider {
+            provider: LyricsProvider::LyricsOvh,
+            display_name: "lyrics.ovh".to_string(),
+        },
+    ];
+    let runtime = shared_plugin_runtime();
+    for plugin in runtime.active_plugins().await {
+        for capability in runtime.list_lyrics_capabilities(&plugin.id) {
+            let display_name = if capability.display_name.trim().is_empty() {
+                format!("{} / {}", plugin.name, capability.type_id)
+            } else {
+                capability.display_name
+            };
+            out.push(KnownProvider {
+                provider: LyricsProvider::Plugin {
+                    plugin_id: plugin.id.clone(),
+                    type_id: capability.type_id,
+                },
+                display_name,
+            });
+        }
+    }
+    out
+}
+
+fn order_providers(
+    policy: &LyricsProviderPolicy,
+    known: Vec<KnownProvider>,
+    health: impl Fn(&str) -> SourceHealth,
+) -> Vec<KnownProvider> {
+    let disabled = policy
+        .disabled
+        .iter()
+        .map(String::as_str)
+        .collect::<HashSet<_>>();
+    let mut remaining = known
+        .into_iter()
+        .filter(|item| !disabled.contains(item.provider.id().as_str()))
+        .collect::<Vec<_>>();
+    let mut out = Vec::with_capacity(remaining.len());
+    for id in &policy.order {
+        if let Some(pos) = remaining.iter().position(|item| &item.provider.id() == id) {
+            out.push(remaining.remove(pos));
+        }
+    }
+    if policy.mode == LyricsProviderMode::Auto {
+        // Explicitly ordered providers keep their place; the rest follow by health. Stable:
+        // providers with equal health keep the default order.
+        remaining.sort_by_key(|item| health(&item.provider.id()).consecutive_failures);
+        out.extend(remaining);
+    }
+    out
+}
+
+fn normalize_policy(policy: LyricsProviderPolicy) -> LyricsProviderPolicy {
+    let normalize = |ids: Vec<String>| {
+        let mut seen = HashSet::new();
+        ids.into_iter()
+            .map(|id| id.trim().to_string())
+            .filter(|id| !id.is_empty() && seen.insert(id.clone()))
+            .collect::<Vec<_>>()
+    };
+    LyricsProviderPolicy {
+        mode: policy.mode,
+        order: normalize(policy.order),
+        disabled: normalize(policy.disabled),
+    }
+}
+
+fn provider_cache_key(source: &str, query: &LyricsQuery) -> String {
+    format!(
+        "{source}\u{1f}{}\u{1f}{}\u{1f}{}\u{1f}{}\u{1f}{}",
+        query.track_key,
+        query.title,
+        query.artist.as_deref().unwrap_or_default(),
+        query.album.as_deref().unwrap_or_default(),
+        query.duration_ms.unwrap_or_default()
+    )
+}
+
+/// Plugin search results whose title fits the query, best artist matches first.
+fn search_plugin(
+    plugin: &mut RuntimeLyricsPlugin,
+    query: &LyricsQuery,
+) -> Result<Vec<RuntimeLyricCandidate>> {
+    let keyword = match query.artist.as_deref() {
+        Some(artist) => format!("{} {artist}", query.title),
+        None => query.title.clone(),
+    };
+    let mut candidates = plugin
+        .search(&keyword)
+        .map_err(|error| anyhow!("lyrics plugin search failed: {error}"))?
+        .into_iter()
+        .filter(|candidate| loosely_matches(&candidate.title, &query.title))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|candidate| {
+        Reverse(
+            query
+                .artist
+                .as_deref()
+                .is_some_and(|artist| loosely_matches(&candidate.artist, artist)),
+        )
+    });
+    Ok(candidates)
+}
+
+fn fetch_plugin_candidate(
+    plugin: &mut RuntimeLyricsPlugin,
+    source: &str,
+    query: &LyricsQuery,
+    candidate: &RuntimeLyricCandidate,
+) -> Result<Option<LyricsDoc>> {
+    let text = plugin
+        .fetch_text(&candidate.id)
+        .map_err(|error| anyhow!("lyrics plugin fetch `{}` failed: {error}", candidate.id))?;
+    Ok(parse_lyrics_text(&query.track_key, source, &text))
+}
+
+fn loosely_matches(candidate: &str, wanted: &str) -> bool {
+    let candidate = candidate.trim().to_lowercase();
+    let wanted = wanted.trim().to_lowercase();
+    !candidate.is_empty() && (candidate.contains(&wanted) || wanted.contains(&candidate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocRank, KnownProvider, LyricsProvider, order_providers};
+    use crate::lyrics_service::SourceHealth;
+    use crate::{LyricLine, LyricsDoc, LyricsProviderMode, LyricsProviderPolicy, LyricsQuery};
+
+    fn known() -> Vec<KnownProvider> {
+        [
+            LyricsProvider::Lrclib,
+            LyricsProvider::LyricsOvh,
+            LyricsProvider::Plugin {
+                plugin_id: "dev.lyrics".to_string(),
+                type_id: "main".to_string(),
+            },
+        ]
+        .into_iter()
+        .map(|provider| KnownProvider {
+            display_name: provider.id(),
+            provider,
+        })
+        .collect()
+    }
+
+    fn ids(items: &[KnownProvider]) -> Vec<String> {
+        items.iter().map(|item| item.provider.id()).collect()
+    }
+
+    #[test]
+    fn auto_mode_prefers_order_then_health() {
+        let policy = LyricsProviderPolicy {
+            mode: LyricsProviderMode::Auto,
+            order: vec!["plugin:dev.lyrics/main".to_string()],
+            disabled: vec![],
+        };
+        let ordered = order_providers(&policy, known(), |id| SourceHealth {
+            consecutive_failures: u32::from(id == "lrclib"),
+            blocked_until_ms: 0,
+        });
+        assert_eq!(
+            ids(&ordered),
+            vec!["plugin:dev.lyrics/main", "lyrics_ovh", "lrclib"]
+        );
+    }
+
+    #[test]
+    fn auto_mode_keeps_explicit_order_despite_failures() {
+        let policy = LyricsProviderPolicy {
+            mode: LyricsProviderMode::Auto,
+            order: vec!["lrclib".to_string(), "lyrics_ovh".to_string()],
+            disabled: vec![],
+        };
+        let ordered = order_providers(&policy, known(), |id| SourceHealth {
+            consecutive_failures: u32::from(id == "lrclib") * 3,
+            blocked_until_ms: 0,
+        });
+        assert_eq!(
+            ids(&ordered),
+            vec!["lrclib", "lyrics_ovh", "plugin:dev.lyrics/main"]
+        );
+    }
+
+    #[test]
+    fn manual_mode_uses_only_listed_enabled_providers() {
+        let policy = LyricsProviderPolicy {
+            mode: LyricsProviderMode::Manual,
+            order: vec![
+                "lyrics_ovh".to_string(),
+                "missing".to_string(),
+                "lrclib".to_string(),
+            ],
+            disabled: vec!["lrclib".to_string()],
+        };
+        let ordered = order_providers(&policy, known(), |_| SourceHealth::default());
+        assert_eq!(ids(&ordered), vec!["lyrics_ovh"]);
+    }
+
+    #[test]
+    fn rank_prefers_duration_fit_over_sync() {
+        let query = LyricsQuery {
+            track_key: "t".to_string(),
+            title: "Song".to_string(),
+            artist: None,
+            album: None,
+            duration_ms: Some(60_000),
+        };
+        let doc = |synced: bool, last_start_ms: i64| LyricsDoc {
+            track_key: "t".to_string(),
+            source: "test".to_string(),
+            is_synced: synced,
+            lines: vec![LyricLine {
+                start_ms: synced.then_some(last_start_ms),
+                end_ms: None,
+                text: "line".to_string(),
+                words: Vec::new(),
+                translation: None,
+                romanization: None,
+            }],
+            meta: Default::default(),
+        };
+        let synced_fit = DocRank::new(&doc(true, 30_000), &query, None);
+        let synced_too_long = DocRank::new(&doc(true, 120_000), &query, None);
+        let plain = DocRank::new(&doc(false, 0), &query, None);
+        let wrong_reported = DocRank::new(&doc(true, 30_000), &query, Some(200_000));
+
+        assert!(synced_fit.is_best());
+        assert!(synced_fit > plain);
+        assert!(plain > synced_too_long);
+        assert!(plain > wrong_reported);
+    }
+}
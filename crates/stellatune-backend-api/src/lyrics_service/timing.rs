@@ -0,0 +1,358 @@
+//! Per-track timing corrections layered over a lyrics document.
+//!
+//! A [`LyricsTiming`] never rewrites the cached document: the cursor reads line starts
+//! through it, and [`apply_timing`] folds it into a copy only for export. Line starts
+//! are tied to the document they were made against through [`doc_fingerprint`].
+
+use anyhow::{Result, anyhow};
+
+use crate::{LyricLine, LyricLineStart, LyricsDoc, LyricsTiming, LyricsTimingEdit};
+
+/// Start of `lines[index]` with any retime applied, before the global offset.
+pub(super) fn line_start(timing: &LyricsTiming, lines: &[LyricLine], index: usize) -> Option<i64> {
+    let index_u32 = u32::try_from(index).ok()?;
+    match timing
+        .line_starts
+        .binary_search_by_key(&index_u32, |entry| entry.line_index)
+    {
+        Ok(pos) => Some(timing.line_starts[pos].start_ms),
+        Err(_) => lines.get(index)?.start_ms,
+    }
+}
+
+/// Stable fingerprint of the line timestamps and texts of `doc` (FNV-1a, 64 bit).
+pub(super) fn doc_fingerprint(doc: &LyricsDoc) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = OFFSET_BASIS;
+    let mut feed = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    };
+    for line in &doc.lines {
+        feed(&line.start_ms.unwrap_or(-1).to_le_bytes());
+        feed(line.text.as_bytes());
+        feed(&[0]);
+    }
+    format!("{hash:016x}")
+}
+
+/// `timing` as it applies to `doc`: line starts made against another document are
+/// dropped, the offset is kept.
+pub(super) fn timing_for_doc(mut timing: LyricsTiming, doc: &LyricsDoc) -> LyricsTiming {
+    if !timing.line_starts.is_empty()
+        && timing.lines_fingerprint.as_deref() != Some(doc_fingerprint(doc).as_str())
+    {
+        timing.line_starts.clear();
+        timing.lines_fingerprint = None;
+    }
+    timing
+}
+
+/// Applies `edit` to `timing`. Line edits need the synced document they refer to.
+pub(super) fn apply_edit(
+    timing: &mut LyricsTiming,
+    doc: Option<&LyricsDoc>,
+    edit: LyricsTimingEdit,
+) -> Result<()> {
+    if let Some(doc) = doc {
+        *timing = timing_for_doc(std::mem::take(timing), doc);
+    }
+    match edit {
+        LyricsTimingEdit::SetOffset { offset_ms } => timing.offset_ms = offset_ms,
+        LyricsTimingEdit::Reset => *timing = LyricsTiming::default(),
+        LyricsTimingEdit::NudgeLine {
+            line_index,
+            delta_ms,
+        } => {
+            let doc = synced(doc)?;
+            let current = line_start(timing, &doc.lines, line_index as usize)
+                .ok_or_else(|| anyhow!("lyrics line {line_index} has no timestamp"))?;
+            set_line_start(timing, doc, line_index, current.saturating_add(delta_ms))?;
+        },
+        LyricsTimingEdit::RetimeLine {
+            line_index,
+            start_ms,
+        } => set_line_start(timing, synced(doc)?, line_index, start_ms)?,
+    }
+    timing.lines_fingerprint = match doc {
+        Some(doc) if !timing.line_starts.is_empty() => Some(doc_fingerprint(doc)),
+        _ => None,
+    };
+    Ok(())
+}
+
+fn synced(doc: Option<&LyricsDoc>) -> Result<&LyricsDoc> {
+    match doc {
+        Some(doc) if doc.is_synced => Ok(doc),
+        Some(_) => Err(anyhow!("lyrics are not synced")),
+        None => Err(anyhow!("no lyrics loaded for track")),
+    }
+}
+
+fn set_line_start(
+    timing: &mut LyricsTiming,
+    doc: &LyricsDoc,
+    line_index: u32,
+    start_ms: i64,
+) -> Result<()> {
+    let line = doc
+        .lines
+        .get(line_index as usize)
+        .ok_or_else(|| anyhow!("lyrics line {line_index} out of range"))?;
+    let start_ms = start_ms.max(0);
+    timing
+        .line_starts
+        .retain(|entry| entry.line_index != line_index);
+    // Retiming a line back to where it was drops the entry.
+    if line.start_ms != Some(start_ms) {
+        let pos = timing
+            .line_starts
+            .partition_point(|entry| entry.line_index < line_index);
+        timing.line_starts.insert(
+            pos,
+            LyricLineStart {
+                line_index,
+                start_ms,
+            },
+        );
+    }
+    Ok(())
+}
+
+/// Returns `doc` with `timing` folded into every line and word timestamp.
+pub(super) fn apply_timing(doc: &LyricsDoc, timing: &LyricsTiming) -> LyricsDoc {
+    let mut out = doc.clone();
+    if !doc.is_synced {
+        return out;
+    }
+    let shift = |ms: i64, delta: i64| (ms + delta + timing.offset_ms).max(0);
+    for (index, line) in out.lines.iter_mut().enumerate() {
+        let Some(original) = line.start_ms else {
+            continue;
+        };
+        let delta = line_start(timing, &doc.lines, index).unwrap_or(original) - original;
+        line.start_ms = Some(shift(original, delta));
+        line.end_ms = line.end_ms.map(|ms| shift(ms, delta));
+        for word in &mut line.words {
+            word.start_ms = shift(word.start_ms, delta);
+            word.end_ms = word.end_ms.map(|ms| shift(ms, delta));
+        }
+    }
+    // A line that ended where the next one started keeps ending there.
+    for index in 1..out.lines.len() {
+        let previous = &doc.lines[index - 1];
+        if previous.end_ms.is_some() && previous.end_ms == doc.lines[index].start_ms {
+            out.lines[index - 1].end_ms = out.lines[index].start_ms;
+        }
+    }
+    out
+}
+
+/// Index of the line active at `position_ms`: the one with the latest start not after
+/// it, the later line on ties. Starts need not be sorted, since a retime can move a
+/// line past its neighbours. -1 before the first line.
+pub(super) fn find_line_index(lines: &[LyricLine], timing: &LyricsTiming, position_ms: i64) -> i64 {
+    let position_ms = position_ms - timing.offset_ms;
+    (0..lines.len())
+        .filter_map(|index| Some((line_start(timing, lines, index)?, index)))
+        .filter(|(start_ms, _)| *start_ms <= position_ms)
+        .max()
+        .map_or(-1, |(_, index)| index as i64)
+}
+
+pub(super) fn find_word_index(
+    lines: &[LyricLine],
+    timing: &LyricsTiming,
+    line_index: i64,
+    position_ms: i64,
+) -> i64 {
+    let Some(index) = usize::try_from(line_index).ok() else {
+        return -1;
+    };
+    let Some(line) = lines.get(index) else {
+        return -1;
+    };
+    // Words move with their line when it is retimed.
+    let delta = match (line_start(timing, lines, index), line.start_ms) {
+        (Some(start), Some(original)) => start - original,
+        _ => 0,
+    };
+    let position_ms = position_ms - timing.offset_ms - delta;
+    line.words
+        .iter()
+        .rposition(|word| position_ms >= word.start_ms)
+        .map_or(-1, |idx| idx as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_edit, apply_timing, doc_fingerprint, find_line_index, find_word_index, timing_for_doc,
+    };
+    use crate::{LyricLine, LyricLineStart, LyricWord, LyricsDoc, LyricsTiming, LyricsTimingEdit};
+
+    fn doc() -> LyricsDoc {
+        let line = |start_ms: i64, end_ms: i64, text: &str, words: Vec<LyricWord>| LyricLine {
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+            text: text.to_string(),
+            words,
+            translation: None,
+            romanization: None,
+        };
+        let word = |start_ms: i64, end_ms: i64, text: &str| LyricWord {
+            start_ms,
+            end_ms: Some(end_ms),
+            text: text.to_string(),
+        };
+        LyricsDoc {
+            track_key: "t".to_string(),
+            source: "test".to_string(),
+            is_synced: true,
+            lines: vec![
+                line(1_000, 2_000, "one", Vec::new()),
+                line(
+                    2_000,
+                    3_000,
+                    "two words",
+                    vec![word(2_000, 2_500, "two "), word(2_500, 3_000, "words")],
+                ),
+                line(3_000, 4_000, "three", Vec::new()),
+            ],
+            meta: Default::default(),
+        }
+    }
+
+    #[test]
+    fn edits_keep_line_starts_sorted_and_minimal() {
+        let doc = doc();
+        let mut timing = LyricsTiming::default();
+        let edits = [
+            LyricsTimingEdit::RetimeLine {
+                line_index: 2,
+                start_ms: 3_300,
+            },
+            LyricsTimingEdit::NudgeLine {
+                line_index: 1,
+                delta_ms: 200,
+            },
+            LyricsTimingEdit::NudgeLine {
+                line_index: 1,
+                delta_ms: 100,
+            },
+            LyricsTimingEdit::SetOffset { offset_ms: -150 },
+        ];
+        for edit in edits {
+            apply_edit(&mut timing, Some(&doc), edit).expect("edit");
+        }
+        assert_eq!(timing.offset_ms, -150);
+        assert_eq!(
+            timing.line_starts,
+            vec![
+                LyricLineStart {
+                    line_index: 1,
+                    start_ms: 2_300
+                },
+                LyricLineStart {
+                    line_index: 2,
+                    start_ms: 3_300
+                },
+            ]
+        );
+
+        let back = LyricsTimingEdit::RetimeLine {
+            line_index: 2,
+            start_ms: 3_000,
+        };
+        apply_edit(&mut timing, Some(&doc), back).expect("edit");
+        assert_eq!(timing.line_starts.len(), 1);
+
+        let out_of_range = LyricsTimingEdit::NudgeLine {
+            line_index: 9,
+            delta_ms: 10,
+        };
+        assert!(apply_edit(&mut timing, Some(&doc), out_of_range).is_err());
+        assert!(apply_edit(&mut timing, None, LyricsTimingEdit::Reset).is_ok());
+        assert_eq!(timing, LyricsTiming::default());
+    }
+
+    #[test]
+    fn cursor_and_export_follow_timing() {
+        let doc = doc();
+        let timing = LyricsTiming {
+            offset_ms: 100,
+            line_starts: vec![LyricLineStart {
+                line_index: 1,
+                start_ms: 2_400,
+            }],
+            lines_fingerprint: Some(doc_fingerprint(&doc)),
+        };
+
+        assert_eq!(find_line_index(&doc.lines, &timing, 1_050), -1);
+        assert_eq!(find_line_index(&doc.lines, &timing, 2_450), 0);
+        assert_eq!(find_line_index(&doc.lines, &timing, 2_500), 1);
+        assert_eq!(find_word_index(&doc.lines, &timing, 1, 2_950), 0);
+        assert_eq!(find_word_index(&doc.lines, &timing, 1, 3_000), 1);
+
+        let folded = apply_timing(&doc, &timing);
+        let starts: Vec<_> = folded
+            .lines
+            .iter()
+            .map(|line| (line.start_ms, line.end_ms))
+            .collect();
+        assert_eq!(
+            starts,
+            vec![
+                (Some(1_100), Some(2_500)),
+                (Some(2_500), Some(3_100)),
+                (Some(3_100), Some(4_100)),
+            ]
+        );
+        assert_eq!(folded.lines[1].words[1].start_ms, 3_000);
+    }
+
+    #[test]
+    fn cursor_handles_lines_retimed_out_of_order() {
+        let doc = doc();
+        let timing = LyricsTiming {
+            offset_ms: 0,
+            line_starts: vec![LyricLineStart {
+                line_index: 0,
+                start_ms: 2_500,
+            }],
+            lines_fingerprint: Some(doc_fingerprint(&doc)),
+        };
+
+        assert_eq!(find_line_index(&doc.lines, &timing, 1_500), -1);
+        assert_eq!(find_line_index(&doc.lines, &timing, 2_200), 1);
+        assert_eq!(find_line_index(&doc.lines, &timing, 2_700), 0);
+        assert_eq!(find_line_index(&doc.lines, &timing, 3_100), 2);
+    }
+
+    #[test]
+    fn line_starts_only_apply_to_their_document() {
+        let doc = doc();
+        let mut timing = LyricsTiming::default();
+        let edit = LyricsTimingEdit::RetimeLine {
+            line_index: 1,
+            start_ms: 2_300,
+        };
+        apply_edit(&mut timing, Some(&doc), edit).expect("edit");
+        apply_edit(
+            &mut timing,
+            Some(&doc),
+            LyricsTimingEdit::SetOffset { offset_ms: 50 },
+        )
+        .expect("edit");
+        assert_eq!(timing_for_doc(timing.clone(), &doc), timing);
+
+        let mut other = doc.clone();
+        other.lines[2].text = "changed".to_string();
+        let stale = timing_for_doc(timing, &other);
+        assert!(stale.line_starts.is_empty());
+        assert_eq!(stale.offset_ms, 50);
+    }
+}
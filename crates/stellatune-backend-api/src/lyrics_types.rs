@@ -10,12 +10,42 @@ pub struct LyricsQuery {
     pub duration_ms: Option<i64>,
 }
 
+/// One timed segment (word or syllable) of a karaoke line.
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LyricWord {
+    pub start_ms: i64,
+    pub end_ms: Option<i64>,
+    pub text: String,
+}
+
 #[flutter_rust_bridge::frb(non_opaque)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LyricLine {
     pub start_ms: Option<i64>,
     pub end_ms: Option<i64>,
     pub text: String,
+    /// Word-level timing; empty for line-synced lyrics. Concatenating every word's
+    /// `text` gives the line's `text`.
+    #[serde(default)]
+    pub words: Vec<LyricWord>,
+    #[serde(default)]
+    pub translation: Option<String>,
+    #[serde(default)]
+    pub romanization: Option<String>,
+}
+
+/// Header fields of a lyrics document.
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LyricsMeta {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub author: Option<String>,
+    /// Offset declared by the source (LRC `[offset:]`). Already applied to every
+    /// timestamp in the document; kept for display and export.
+    pub offset_ms: i64,
 }
 
 #[flutter_rust_bridge::frb(non_opaque)]
@@ -25,6 +55,8 @@ pub struct LyricsDoc {
     pub source: String,
     pub is_synced: bool,
     pub lines: Vec<LyricLine>,
+    #[serde(default)]
+    pub meta: LyricsMeta,
 }
 
 #[flutter_rust_bridge::frb(non_opaque)]
@@ -43,9 +75,100 @@ pub struct LyricsSearchCandidate {
 #[flutter_rust_bridge::frb(non_opaque)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum LyricsEvent {
-    Loading { track_key: String },
-    Ready { track_key: String, doc: LyricsDoc },
-    Cursor { track_key: String, line_index: i64 },
-    Empty { track_key: String },
-    Error { track_key: String, message: String },
+    Loading {
+        track_key: String,
+    },
+    Ready {
+        track_key: String,
+        doc: LyricsDoc,
+    },
+    /// `word_index` is the active entry of `LyricLine::words`, or -1 when the line has
+    /// no word timing or no word has started yet.
+    Cursor {
+        track_key: String,
+        line_index: i64,
+        word_index: i64,
+    },
+    Empty {
+        track_key: String,
+    },
+    Error {
+        track_key: String,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LyricsProviderMode {
+    /// Every known provider is tried: `order` first, then the rest, healthiest first.
+    #[default]
+    Auto,
+    /// Only providers listed in `order` are tried, in that order.
+    Manual,
+}
+
+/// Which lyrics providers `LyricsService` consults and in what order.
+///
+/// Provider ids are `lrclib`, `lyrics_ovh` and `plugin:<plugin_id>/<type_id>`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LyricsProviderPolicy {
+    #[serde(default)]
+    pub mode: LyricsProviderMode,
+    #[serde(default)]
+    pub order: Vec<String>,
+    #[serde(default)]
+    pub disabled: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LyricsProviderStatus {
+    pub id: String,
+    pub display_name: String,
+    pub plugin_id: Option<String>,
+    pub enabled: bool,
+    pub available: bool,
+    pub consecutive_failures: u32,
+    pub blocked_until_ms: i64,
+}
+
+/// User timing corrections for one track, kept apart from the lyrics document so they
+/// survive re-fetches of the same lyrics.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LyricsTiming {
+    /// Added to every timestamp; positive values show lyrics later.
+    #[serde(default)]
+    pub offset_ms: i64,
+    /// Replacement start times for individual lines, sorted by `line_index`.
+    #[serde(default)]
+    pub line_starts: Vec<LyricLineStart>,
+    /// Fingerprint of the document `line_starts` index into. Line starts are ignored
+    /// once the track's lyrics change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lines_fingerprint: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LyricLineStart {
+    pub line_index: u32,
+    pub start_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LyricsTimingEdit {
+    SetOffset {
+        offset_ms: i64,
+    },
+    /// Moves one line (and its words) by `delta_ms` from its current start.
+    NudgeLine {
+        line_index: u32,
+        delta_ms: i64,
+    },
+    /// Sets one line's start; its words keep their position relative to the line.
+    RetimeLine {
+        line_index: u32,
+        start_ms: i64,
+    },
+    Reset,
 }
@@ -26,6 +26,7 @@ pub fn plugins_uninstall_by_id(plugins_dir: String, plugin_id: String) -> Result
 
     stellatune_plugins::package::uninstall_by_id(&plugins_dir, &plugin_id)
         .map_err(|e| anyhow!(e.to_string()))?;
+    shared_plugin_runtime().remove_plugin_storage(&plugin_id)?;
     sync_runtime_after_package_change(&plugins_dir)
 }
 
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use stellatune_plugins::runtime::model::RuntimeDevReloadEvent;
+use tokio::sync::broadcast;
+
+use super::shared_plugin_runtime;
+
+const DEV_PLUGIN_EVENT_CAPACITY: usize = 64;
+/// How often a forwarding thread checks whether it was asked to stop.
+const DEV_PLUGIN_STOP_POLL_MS: u64 = 250;
+
+/// One watched dev plugin directory. The forwarding thread owns the runtime watcher, so
+/// stopping the thread stops watching.
+struct DevPluginWatch {
+    stop: Arc<AtomicBool>,
+    /// Plugin id from the latest reload event, unloaded when watching stops.
+    plugin_id: Arc<Mutex<Option<String>>>,
+}
+
+fn dev_plugin_watches() -> &'static Mutex<HashMap<PathBuf, DevPluginWatch>> {
+    static WATCHES: OnceLock<Mutex<HashMap<PathBuf, DevPluginWatch>>> = OnceLock::new();
+    WATCHES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn dev_plugin_events() -> &'static broadcast::Sender<RuntimeDevReloadEvent> {
+    static EVENTS: OnceLock<broadcast::Sender<RuntimeDevReloadEvent>> = OnceLock::new();
+    EVENTS.get_or_init(|| broadcast::channel(DEV_PLUGIN_EVENT_CAPACITY).0)
+}
+
+/// Load and reload outcomes of every watched dev plugin directory.
+pub fn subscribe_dev_plugin_events() -> broadcast::Receiver<RuntimeDevReloadEvent> {
+    dev_plugin_events().subscribe()
+}
+
+/// Loads the unpacked plugin at `root_dir` and reloads it whenever its build output or
+/// manifest changes, reporting each attempt on [`subscribe_dev_plugin_events`]. Watching a
+/// directory again restarts its watcher.
+pub fn watch_dev_plugin(root_dir: &Path) -> Result<()> {
+    let root_dir = root_dir.to_path_buf();
+    stop_watch(&root_dir);
+    let watcher = shared_plugin_runtime().watch_dev_plugin(&root_dir)?;
+    let stop = Arc::new(AtomicBool::new(false));
+    let plugin_id = Arc::new(Mutex::new(None::<String>));
+    let thread_stop = Arc::clone(&stop);
+    let thread_plugin_id = Arc::clone(&plugin_id);
+    std::thread::Builder::new()
+        .name("stellatune-dev-plugin-events".to_string())
+        .spawn(move || {
+            let events = dev_plugin_events();
+            while !thread_stop.load(Ordering::Acquire) {
+                match watcher
+                    .events()
+                    .recv_timeout(Duration::from_millis(DEV_PLUGIN_STOP_POLL_MS))
+                {
+                    Ok(event) => {
+                        if let Some(id) = event.plugin_id.clone()
+                            && let Ok(mut plugin_id) = thread_plugin_id.lock()
+                        {
+                            *plugin_id = Some(id);
+                        }
+                        let _ = events.send(event);
+                    },
+                    Err(RecvTimeoutError::Timeout) => {},
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        })
+        .map_err(|error| anyhow!("failed to spawn dev plugin event thread: {error}"))?;
+    dev_plugin_watches()
+        .lock()
+        .map_err(|_| anyhow!("dev plugin watch registry poisoned"))?
+        .insert(root_dir, DevPluginWatch { stop, plugin_id });
+    Ok(())
+}
+
+/// Stops watching `root_dir` and unloads the dev plugin loaded from it.
+pub fn unwatch_dev_plugin(root_dir: &Path) -> Result<()> {
+    let Some(plugin_id) = stop_watch(root_dir) else {
+        return Ok(());
+    };
+    shared_plugin_runtime().unload_dev_plugin(&plugin_id)
+}
+
+/// Stops the watcher of `root_dir`, returning the plugin id it last loaded.
+fn stop_watch(root_dir: &Path) -> Option<String> {
+    let watch = dev_plugin_watches().lock().ok()?.remove(root_dir)?;
+    watch.stop.store(true, Ordering::Release);
+    watch.plugin_id.lock().ok()?.take()
+}
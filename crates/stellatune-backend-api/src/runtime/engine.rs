@@ -1,21 +1,37 @@
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
-use stellatune_audio::config::engine::{LfeMode, ResampleQuality};
+use stellatune_audio::config::convolution::{
+    CONVOLUTION_STAGE_KEY, ConvolutionControl, ImpulseResponse,
+};
+use stellatune_audio::config::dither::{
+    DITHER_STAGE_KEY, DitherConfig, NoiseShaping, SinkSampleFormat,
+};
+use stellatune_audio::config::engine::{ClockMode, ResampleQuality};
+use stellatune_audio::config::time_stretch::{TIME_STRETCH_STAGE_KEY, TimeStretchConfig};
 use stellatune_audio::engine::{EngineHandle, start_engine};
-use stellatune_audio::pipeline::assembly::{MixerPlan, PipelineMutation, ResamplerPlan};
+use stellatune_audio::pipeline::assembly::{
+    BuiltinTransformSlot, MixMode, PipelineMutation, ResamplerPlan,
+};
 use stellatune_audio_builtin_adapters::device_sink::{
     OutputBackend as AdapterOutputBackend, OutputDeviceSpec, default_output_spec_for_backend,
-    list_output_devices, output_spec_for_route,
+    list_output_devices, output_sample_format_for_route, output_spec_for_route,
 };
+use stellatune_audio_builtin_adapters::file_sink::FileSinkRoute;
+use stellatune_audio_builtin_adapters::http_stream::HttpStreamRoute;
+use stellatune_audio_builtin_adapters::tee_sink::TeeBranchMetricsSnapshot;
 use stellatune_audio_plugin_adapters::stages::{
     PluginOutputSinkRouteSpec, negotiate_output_sink_spec,
 };
 
+use super::http_stream_titles::ensure_http_stream_title_updates_started;
 use super::pipeline::{
-    V2BackendAssembler, shared_device_sink_control, shared_runtime_sink_route_control,
+    RuntimeTeeOutput, RuntimeTeeOutputRoute, V2BackendAssembler, current_output_mix_mode,
+    output_mixer_plan, set_output_mix_mode, shared_device_sink_control, shared_http_stream_hub,
+    shared_runtime_sink_route_control,
 };
+use super::playback_listeners::ensure_playback_listener_dispatcher_started;
 use super::{
     DeviceSinkMetricsSnapshot, OutputBackend, OutputDeviceDescriptor,
     RuntimeOutputDeviceApplyReport, init_tracing,
@@ -25,6 +41,7 @@ struct RuntimeEngineMetrics {
     runtime_engine_inits_total: AtomicU64,
 }
 
+const HTTP_STREAM_TEE_OUTPUT_NAME: &str = "http-stream";
 const OUTPUT_SINK_MONITOR_POLL_INTERVAL: Duration = Duration::from_millis(250);
 const OUTPUT_SINK_UNDERRUN_LOG_INTERVAL: Duration = Duration::from_secs(1);
 const OUTPUT_SINK_ACTIVITY_TIMEOUT: Duration = Duration::from_millis(1_500);
@@ -74,10 +91,17 @@ struct RuntimeOutputOptions {
     resample_quality: ResampleQuality,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RuntimeDitherOptions {
+    tpdf: bool,
+    noise_shaping: NoiseShaping,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct ResolvedOutputSpec {
     spec: OutputDeviceSpec,
     plugin_prefers_track_rate: Option<bool>,
+    sample_format: SinkSampleFormat,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -95,6 +119,16 @@ impl Default for RuntimeOutputOptions {
     }
 }
 
+impl Default for RuntimeDitherOptions {
+    fn default() -> Self {
+        let config = DitherConfig::default();
+        Self {
+            tpdf: config.tpdf,
+            noise_shaping: config.noise_shaping,
+        }
+    }
+}
+
 impl RuntimeEngineMetrics {
     fn new() -> Self {
         Self {
@@ -113,6 +147,11 @@ fn runtime_output_options() -> &'static Mutex<RuntimeOutputOptions> {
     OPTIONS.get_or_init(|| Mutex::new(RuntimeOutputOptions::default()))
 }
 
+fn runtime_dither_options() -> &'static Mutex<RuntimeDitherOptions> {
+    static OPTIONS: OnceLock<Mutex<RuntimeDitherOptions>> = OnceLock::new();
+    OPTIONS.get_or_init(|| Mutex::new(RuntimeDitherOptions::default()))
+}
+
 fn plugin_resolved_spec_cache() -> &'static Mutex<Option<CachedPluginResolvedSpec>> {
     static CACHE: OnceLock<Mutex<Option<CachedPluginResolvedSpec>>> = OnceLock::new();
     CACHE.get_or_init(|| Mutex::new(None))
@@ -161,6 +200,13 @@ fn snapshot_runtime_output_options() -> RuntimeOutputOptions {
         .unwrap_or_else(|poisoned| *poisoned.into_inner())
 }
 
+fn snapshot_runtime_dither_options() -> RuntimeDitherOptions {
+    runtime_dither_options()
+        .lock()
+        .map(|guard| *guard)
+        .unwrap_or_else(|poisoned| *poisoned.into_inner())
+}
+
 fn output_sink_watermarks_ms() -> (i64, i64) {
     let high = ((OUTPUT_SINK_RING_CAPACITY_MS.saturating_mul(3)) / 4)
         .max(OUTPUT_SINK_MIN_LOW_WATERMARK_MS + 1);
@@ -181,9 +227,9 @@ fn estimate_buffered_ms(metrics: DeviceSinkMetricsSnapshot, spec: OutputDeviceSp
 }
 
 fn monitor_output_sink_metrics(state: &mut OutputSinkMonitorState) {
-    if shared_runtime_sink_route_control()
-        .current_plugin_route()
-        .is_some()
+    let route_control = shared_runtime_sink_route_control();
+    if route_control.current_file_route().is_some()
+        || route_control.current_plugin_route().is_some()
     {
         state.reset_watermark();
         return;
@@ -291,6 +337,8 @@ fn new_runtime_engine() -> Arc<EngineHandle> {
         "runtime engine initialized"
     );
     ensure_output_sink_monitor_started();
+    ensure_playback_listener_dispatcher_started(engine.subscribe_events());
+    ensure_http_stream_title_updates_started(engine.subscribe_events());
     Arc::new(engine)
 }
 
@@ -342,6 +390,7 @@ pub async fn runtime_set_output_device(
 
     let (previous_backend, previous_device_id) = control.desired_route();
     let previous_plugin_route = sink_route_control.current_plugin_route();
+    let previous_file_route = sink_route_control.current_file_route();
     let previous_spec = resolve_current_output_spec().ok();
 
     let (applied_backend, applied_device_id, output_spec, fallback_to_default) =
@@ -349,15 +398,24 @@ pub async fn runtime_set_output_device(
     let resolved_output_spec = ResolvedOutputSpec {
         spec: output_spec,
         plugin_prefers_track_rate: None,
+        sample_format: output_sample_format_for_route(
+            applied_backend,
+            applied_device_id.as_deref(),
+        )
+        .unwrap_or_default(),
     };
 
     sink_route_control.clear_plugin_route();
+    sink_route_control.clear_file_route();
     clear_plugin_resolved_spec_cache();
     control.set_route(applied_backend, applied_device_id.clone());
     if let Err(error) = apply_output_spec_mutations(engine.as_ref(), resolved_output_spec).await {
         if let Some(route) = previous_plugin_route {
             sink_route_control.set_plugin_route(route);
         }
+        if let Some(route) = previous_file_route {
+            sink_route_control.set_file_route(route);
+        }
         control.set_route(previous_backend, previous_device_id.clone());
         if let Some(spec) = previous_spec {
             let _ = apply_output_spec_mutations(engine.as_ref(), spec).await;
@@ -368,6 +426,13 @@ pub async fn runtime_set_output_device(
         ));
     }
 
+    if previous_file_route.is_some() {
+        engine
+            .set_clock_mode(ClockMode::Paced)
+            .await
+            .map_err(|error| error.to_string())?;
+    }
+
     Ok(RuntimeOutputDeviceApplyReport {
         requested_backend: backend,
         applied_backend: from_adapter_backend(applied_backend),
@@ -397,6 +462,109 @@ pub async fn runtime_set_output_options(
     apply_output_spec_mutations(shared_runtime_engine().as_ref(), output_spec).await
 }
 
+/// Sets the mixer routing for playback output. The active pipeline is rebuilt in place;
+/// the sink is kept because the output channel count does not change.
+pub async fn runtime_set_output_mix_mode(mode: MixMode) -> Result<(), String> {
+    let previous = current_output_mix_mode();
+    set_output_mix_mode(mode);
+    let result = match resolve_current_output_spec_for_output_options() {
+        Ok(output_spec) => shared_runtime_engine()
+            .apply_pipeline_mutation(PipelineMutation::SetMixerPlan {
+                mixer: Some(output_mixer_plan(output_spec.spec.channels)),
+            })
+            .await
+            .map_err(|error| error.to_string()),
+        Err(error) => Err(error),
+    };
+    if result.is_err() {
+        set_output_mix_mode(previous);
+    }
+    result
+}
+
+pub fn runtime_output_mix_mode() -> MixMode {
+    current_output_mix_mode()
+}
+
+/// Sets how output is quantized for integer sinks. The sink format itself follows the
+/// current output route; float sinks leave the stage bypassed.
+pub async fn runtime_set_output_dither(
+    tpdf: bool,
+    noise_shaping: NoiseShaping,
+) -> Result<(), String> {
+    {
+        let mut guard = runtime_dither_options()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *guard = RuntimeDitherOptions {
+            tpdf,
+            noise_shaping,
+        };
+    }
+
+    let resolved = resolve_current_output_spec_for_output_options()?;
+    apply_dither_control(shared_runtime_engine().as_ref(), resolved.sample_format).await
+}
+
+/// Loads an impulse response into the output convolution stage, or removes the stage
+/// when `impulse` is `None`.
+///
+/// Swapping filters while one is loaded crossfades in place over `crossfade_ms`; only
+/// switching the stage on or off rebuilds the pipeline.
+pub async fn runtime_set_output_convolution(
+    impulse: Option<Arc<ImpulseResponse>>,
+    crossfade_ms: u32,
+) -> Result<(), String> {
+    static ENABLED: AtomicBool = AtomicBool::new(false);
+    let engine = shared_runtime_engine();
+    let enabled = impulse.is_some();
+    if ENABLED.load(Ordering::Acquire) != enabled {
+        engine
+            .apply_pipeline_mutation(PipelineMutation::SetBuiltinTransformSlot {
+                slot: BuiltinTransformSlot::Convolution,
+                enabled,
+            })
+            .await
+            .map_err(|error| error.to_string())?;
+        ENABLED.store(enabled, Ordering::Release);
+    }
+    let Some(impulse) = impulse else {
+        return Ok(());
+    };
+    engine
+        .apply_stage_control(
+            CONVOLUTION_STAGE_KEY,
+            ConvolutionControl::load(impulse).with_crossfade_ms(crossfade_ms),
+        )
+        .await
+        .map_err(|error| error.to_string())
+}
+
+/// Sets playback speed and pitch. The stage is only inserted once a non-identity setting is
+/// requested; afterwards it stays in place so returning to 1x does not rebuild the pipeline.
+pub async fn runtime_set_playback_rate(speed: f32, pitch_semitones: f32) -> Result<(), String> {
+    static ENABLED: AtomicBool = AtomicBool::new(false);
+    let engine = shared_runtime_engine();
+    let config = TimeStretchConfig::new(speed, pitch_semitones);
+    if !ENABLED.load(Ordering::Acquire) {
+        if config.is_identity() {
+            return Ok(());
+        }
+        engine
+            .apply_pipeline_mutation(PipelineMutation::SetBuiltinTransformSlot {
+                slot: BuiltinTransformSlot::TimeStretch,
+                enabled: true,
+            })
+            .await
+            .map_err(|error| error.to_string())?;
+        ENABLED.store(true, Ordering::Release);
+    }
+    engine
+        .apply_stage_control(TIME_STRETCH_STAGE_KEY, config)
+        .await
+        .map_err(|error| error.to_string())
+}
+
 pub async fn runtime_set_output_sink_route(
     plugin_id: String,
     type_id: String,
@@ -471,29 +639,196 @@ pub async fn runtime_clear_output_sink_route() -> Result<(), String> {
     Ok(())
 }
 
+/// Routes engine output into a file and lets the decode worker run ahead of realtime.
+pub async fn runtime_set_output_file_route(route: FileSinkRoute) -> Result<(), String> {
+    let engine = shared_runtime_engine();
+    let route_control = shared_runtime_sink_route_control();
+    let previous_route = route_control.current_file_route();
+    let previous_spec = resolve_current_output_spec().ok();
+    route_control.set_file_route(route);
+
+    let output_spec = resolve_current_output_spec()?;
+    if let Err(error) = apply_output_spec_mutations(engine.as_ref(), output_spec).await {
+        restore_file_route(&route_control, previous_route);
+        if let Some(spec) = previous_spec {
+            let _ = apply_output_spec_mutations(engine.as_ref(), spec).await;
+        }
+        return Err(format!("failed to apply file output route: {error}"));
+    }
+    engine
+        .set_clock_mode(ClockMode::FreeRunning)
+        .await
+        .map_err(|error| error.to_string())
+}
+
+pub async fn runtime_clear_output_file_route() -> Result<(), String> {
+    let route_control = shared_runtime_sink_route_control();
+    let Some(previous_route) = route_control.current_file_route() else {
+        return Ok(());
+    };
+    let engine = shared_runtime_engine();
+    route_control.clear_file_route();
+
+    let output_spec = match resolve_current_output_spec() {
+        Ok(spec) => spec,
+        Err(error) => {
+            route_control.set_file_route(previous_route);
+            return Err(error);
+        },
+    };
+    if let Err(error) = apply_output_spec_mutations(engine.as_ref(), output_spec).await {
+        route_control.set_file_route(previous_route);
+        if let Ok(spec) = resolve_current_output_spec() {
+            let _ = apply_output_spec_mutations(engine.as_ref(), spec).await;
+        }
+        return Err(format!("failed to clear file output route: {error}"));
+    }
+    engine
+        .set_clock_mode(ClockMode::Paced)
+        .await
+        .map_err(|error| error.to_string())
+}
+
+fn restore_file_route(
+    route_control: &super::pipeline::RuntimeSinkRouteControl,
+    previous_route: Option<FileSinkRoute>,
+) {
+    match previous_route {
+        Some(route) => route_control.set_file_route(route),
+        None => route_control.clear_file_route(),
+    }
+}
+
+/// Mirrors the primary output into `outputs`; an empty list removes the tee.
+pub async fn runtime_set_output_tee(outputs: Vec<RuntimeTeeOutput>) -> Result<(), String> {
+    let mut names = std::collections::HashSet::new();
+    for output in &outputs {
+        if output.name.trim().is_empty() {
+            return Err("tee output name is empty".to_string());
+        }
+        if !names.insert(output.name.as_str()) {
+            return Err(format!("duplicate tee output name: {}", output.name));
+        }
+    }
+    let has_http_stream = outputs.iter().any(is_http_stream_output);
+    if outputs
+        .iter()
+        .filter(|output| is_http_stream_output(output))
+        .count()
+        > 1
+    {
+        return Err("only one http stream tee output is supported".to_string());
+    }
+
+    let engine = shared_runtime_engine();
+    let route_control = shared_runtime_sink_route_control();
+    let previous_outputs = route_control.current_tee_outputs();
+    if previous_outputs == outputs {
+        return Ok(());
+    }
+    route_control.set_tee_outputs(outputs);
+
+    let output_spec = match resolve_current_output_spec() {
+        Ok(spec) => spec,
+        Err(error) => {
+            route_control.set_tee_outputs(previous_outputs);
+            return Err(error);
+        },
+    };
+    if let Err(error) = apply_output_spec_mutations(engine.as_ref(), output_spec).await {
+        route_control.set_tee_outputs(previous_outputs);
+        if let Ok(spec) = resolve_current_output_spec() {
+            let _ = apply_output_spec_mutations(engine.as_ref(), spec).await;
+        }
+        return Err(format!("failed to apply tee outputs: {error}"));
+    }
+    if !has_http_stream {
+        shared_http_stream_hub().end_stream();
+    }
+    Ok(())
+}
+
+fn is_http_stream_output(output: &RuntimeTeeOutput) -> bool {
+    matches!(output.route, RuntimeTeeOutputRoute::HttpStream(_))
+}
+
+/// Adds (or reconfigures) the LAN stream output next to the current tee outputs. The
+/// stream goes live once playback prepares the sink.
+pub async fn runtime_start_http_stream(route: HttpStreamRoute) -> Result<(), String> {
+    let mut outputs = shared_runtime_sink_route_control().current_tee_outputs();
+    outputs.retain(|output| !is_http_stream_output(output));
+    outputs.push(RuntimeTeeOutput {
+        name: HTTP_STREAM_TEE_OUTPUT_NAME.to_string(),
+        route: RuntimeTeeOutputRoute::HttpStream(route),
+        delay_ms: 0,
+    });
+    runtime_set_output_tee(outputs).await
+}
+
+pub async fn runtime_stop_http_stream() -> Result<(), String> {
+    let mut outputs = shared_runtime_sink_route_control().current_tee_outputs();
+    outputs.retain(|output| !is_http_stream_output(output));
+    runtime_set_output_tee(outputs).await
+}
+
+pub async fn runtime_clear_output_tee() -> Result<(), String> {
+    runtime_set_output_tee(Vec::new()).await
+}
+
+pub fn runtime_output_tee_metrics() -> Vec<TeeBranchMetricsSnapshot> {
+    let route_control = shared_runtime_sink_route_control();
+    if route_control.current_tee_outputs().is_empty() {
+        return Vec::new();
+    }
+    route_control.tee_metrics().snapshot()
+}
+
 pub async fn runtime_clear_output_sink_route_for_plugin(plugin_id: &str) -> Result<bool, String> {
     let plugin_id = plugin_id.trim();
     if plugin_id.is_empty() {
         return Ok(false);
     }
     let route_control = shared_runtime_sink_route_control();
+    let mut cleared = retain_plugin_tee_outputs(|route| route.plugin_id != plugin_id).await?;
     let current_route = route_control.current_plugin_route();
-    if !should_clear_route_for_plugin(current_route.as_ref(), plugin_id) {
-        return Ok(false);
+    if should_clear_route_for_plugin(current_route.as_ref(), plugin_id) {
+        runtime_clear_output_sink_route().await?;
+        cleared = true;
     }
-    runtime_clear_output_sink_route().await?;
-    Ok(true)
+    Ok(cleared)
 }
 
 pub async fn runtime_clear_output_sink_route_if_plugin_unavailable(
     active_plugin_ids: &[String],
 ) -> Result<bool, String> {
     let route_control = shared_runtime_sink_route_control();
+    let mut cleared =
+        retain_plugin_tee_outputs(|route| active_plugin_ids.contains(&route.plugin_id)).await?;
     let current_route = route_control.current_plugin_route();
-    if !should_clear_route_if_plugin_unavailable(current_route.as_ref(), active_plugin_ids) {
+    if should_clear_route_if_plugin_unavailable(current_route.as_ref(), active_plugin_ids) {
+        runtime_clear_output_sink_route().await?;
+        cleared = true;
+    }
+    Ok(cleared)
+}
+
+/// Drops plugin tee outputs rejected by `keep`; returns whether any were removed.
+async fn retain_plugin_tee_outputs(
+    keep: impl Fn(&PluginOutputSinkRouteSpec) -> bool,
+) -> Result<bool, String> {
+    let outputs = shared_runtime_sink_route_control().current_tee_outputs();
+    let retained = outputs
+        .iter()
+        .filter(|output| match &output.route {
+            RuntimeTeeOutputRoute::Plugin(route) => keep(route),
+            RuntimeTeeOutputRoute::File(_) | RuntimeTeeOutputRoute::HttpStream(_) => true,
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    if retained.len() == outputs.len() {
         return Ok(false);
     }
-    runtime_clear_output_sink_route().await?;
+    runtime_set_output_tee(retained).await?;
     Ok(true)
 }
 
@@ -546,6 +881,7 @@ fn resolve_rollback_output_spec(
         .map(|spec| ResolvedOutputSpec {
             spec,
             plugin_prefers_track_rate: None,
+            sample_format: resolve_device_output_sample_format(),
         })
 }
 
@@ -582,13 +918,25 @@ fn resolve_target_output_spec(
 }
 
 fn resolve_current_output_spec() -> Result<ResolvedOutputSpec, String> {
-    let device_spec = resolve_device_output_spec()?;
     let route_control = shared_runtime_sink_route_control();
+    // File routes fix their own format and must resolve without any audio device.
+    if let Some(route) = route_control.current_file_route() {
+        return Ok(ResolvedOutputSpec {
+            spec: OutputDeviceSpec {
+                sample_rate: route.sample_rate,
+                channels: route.channels,
+            },
+            plugin_prefers_track_rate: Some(false),
+            sample_format: route.format.sample_format(),
+        });
+    }
+    let device_spec = resolve_device_output_spec()?;
     let Some(route) = route_control.current_plugin_route() else {
         clear_plugin_resolved_spec_cache();
         return Ok(ResolvedOutputSpec {
             spec: device_spec,
             plugin_prefers_track_rate: None,
+            sample_format: resolve_device_output_sample_format(),
         });
     };
     let negotiated = negotiate_output_sink_spec(
@@ -605,6 +953,7 @@ fn resolve_current_output_spec() -> Result<ResolvedOutputSpec, String> {
             channels: negotiated.channels,
         },
         plugin_prefers_track_rate: Some(negotiated.prefer_track_rate),
+        sample_format: negotiated.sample_format,
     };
     cache_plugin_resolved_spec(route, resolved);
     Ok(resolved)
@@ -618,9 +967,18 @@ fn resolve_device_output_spec() -> Result<OutputDeviceSpec, String> {
         .map_err(|error| format!("failed to resolve output spec for current route: {error}"))
 }
 
+/// Falls back to float, which leaves the dither stage bypassed, when the device cannot be
+/// queried.
+fn resolve_device_output_sample_format() -> SinkSampleFormat {
+    let control = shared_device_sink_control();
+    let (backend, device_id) = control.desired_route();
+    output_sample_format_for_route(backend, device_id.as_deref()).unwrap_or_default()
+}
+
 fn resolve_current_output_spec_for_output_options() -> Result<ResolvedOutputSpec, String> {
     let route_control = shared_runtime_sink_route_control();
-    if let Some(route) = route_control.current_plugin_route()
+    if route_control.current_file_route().is_none()
+        && let Some(route) = route_control.current_plugin_route()
         && let Some(cached) = cached_plugin_resolved_spec(&route)
     {
         return Ok(cached);
@@ -640,7 +998,7 @@ async fn apply_output_spec_mutations(
     );
     engine
         .apply_pipeline_mutation(PipelineMutation::SetMixerPlan {
-            mixer: Some(MixerPlan::new(spec.channels, LfeMode::Mute)),
+            mixer: Some(output_mixer_plan(spec.channels)),
         })
         .await
         .map_err(|error| error.to_string())?;
@@ -655,6 +1013,25 @@ async fn apply_output_spec_mutations(
     engine
         .apply_pipeline_mutation(PipelineMutation::SetResamplerPlan { resampler })
         .await
+        .map_err(|error| error.to_string())?;
+    apply_dither_control(engine, resolved.sample_format).await
+}
+
+async fn apply_dither_control(
+    engine: &EngineHandle,
+    sink_format: SinkSampleFormat,
+) -> Result<(), String> {
+    let options = snapshot_runtime_dither_options();
+    engine
+        .apply_stage_control(
+            DITHER_STAGE_KEY,
+            DitherConfig {
+                sink_format,
+                tpdf: options.tpdf,
+                noise_shaping: options.noise_shaping,
+            },
+        )
+        .await
         .map_err(|error| error.to_string())
 }
 
@@ -0,0 +1,52 @@
+use std::sync::OnceLock;
+
+use stellatune_audio::config::engine::Event;
+use stellatune_plugins::runtime::model::RuntimePlaybackTrackInfo;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+use super::pipeline::shared_http_stream_hub;
+use super::playback_listeners::resolve_track_info;
+
+/// Keeps the HTTP stream hub's now-playing title in step with `Event::TrackChanged`.
+pub(super) fn ensure_http_stream_title_updates_started(events: broadcast::Receiver<Event>) {
+    static STARTED: OnceLock<()> = OnceLock::new();
+    STARTED.get_or_init(|| {
+        stellatune_runtime::spawn(run_http_stream_title_updates(events));
+    });
+}
+
+async fn run_http_stream_title_updates(mut events: broadcast::Receiver<Event>) {
+    loop {
+        match events.recv().await {
+            Ok(Event::TrackChanged { track_token }) => {
+                let track = resolve_track_info(track_token).await;
+                shared_http_stream_hub().set_title(stream_title(&track));
+            },
+            Ok(_) => {},
+            Err(RecvError::Lagged(skipped)) => {
+                tracing::debug!(
+                    skipped,
+                    "http stream title updates lagged behind engine events"
+                );
+            },
+            Err(RecvError::Closed) => break,
+        }
+    }
+}
+
+/// `Artist - Title` for ICY listeners, falling back to the file name.
+fn stream_title(track: &RuntimePlaybackTrackInfo) -> Option<String> {
+    let tags = track.metadata.as_ref().map(|metadata| &metadata.tags);
+    let title = tags.and_then(|tags| tags.title.as_deref()).or_else(|| {
+        std::path::Path::new(&track.locator)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+    })?;
+    let artists = tags.map(|tags| tags.artists.join(", ")).unwrap_or_default();
+    if artists.is_empty() {
+        Some(title.to_string())
+    } else {
+        Some(format!("{artists} - {title}"))
+    }
+}
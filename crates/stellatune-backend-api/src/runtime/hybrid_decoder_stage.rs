@@ -544,21 +544,22 @@ struct SourceStreamLocator {
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 struct TrackRefTokenWire {
     source_id: String,
-    #[serde(rename = "track_id")]
-    _track_id: String,
+    track_id: String,
     locator: String,
 }
 
 #[derive(Debug, Clone)]
-struct TrackRefToken {
-    source_id: String,
-    locator: String,
+pub(super) struct TrackRefToken {
+    pub(super) source_id: String,
+    pub(super) track_id: String,
+    pub(super) locator: String,
 }
 
 impl TrackRefToken {
     fn for_local_path(path: String) -> Self {
         Self {
             source_id: "local".to_string(),
+            track_id: path.clone(),
             locator: path,
         }
     }
@@ -587,7 +588,7 @@ impl HybridDecoderCandidate {
     }
 }
 
-fn decode_track_ref_token(track_token: &str) -> Result<TrackRefToken, String> {
+pub(super) fn decode_track_ref_token(track_token: &str) -> Result<TrackRefToken, String> {
     let token = track_token.trim();
     if token.is_empty() {
         return Err("track token is empty".to_string());
@@ -598,6 +599,7 @@ fn decode_track_ref_token(track_token: &str) -> Result<TrackRefToken, String> {
     {
         return Ok(TrackRefToken {
             source_id: parsed.source_id,
+            track_id: parsed.track_id,
             locator: parsed.locator,
         });
     }
@@ -8,17 +8,49 @@ use std::{
 use anyhow::{Result, anyhow};
 use std::time::Instant;
 
+use stellatune_audio::config::convolution::ImpulseResponse;
+use stellatune_audio::config::dither::NoiseShaping;
 use stellatune_audio::config::engine::ResampleQuality;
 use stellatune_audio::engine::EngineHandle;
+use stellatune_audio::pipeline::assembly::MixMode;
+pub use stellatune_audio_builtin_adapters::file_sink::{
+    FileSinkFormat, FileSinkRoute, WavSampleFormat,
+};
+pub use stellatune_audio_builtin_adapters::http_stream::{
+    HttpStreamClient, HttpStreamClientSnapshot, HttpStreamDropPolicy, HttpStreamEncoder,
+    HttpStreamHub, HttpStreamRoute, StreamEncoder,
+};
+pub use stellatune_audio_builtin_adapters::tee_sink::TeeBranchMetricsSnapshot;
+pub use stellatune_audio_plugin_adapters::stages::PluginOutputSinkRouteSpec;
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::fmt::time::LocalTime;
 
 use stellatune_plugins::host_runtime::runtime_service::SharedPluginRuntime;
 
 mod apply_state;
+mod dev_plugins;
 mod engine;
+mod http_stream_titles;
 mod hybrid_decoder_stage;
 mod pipeline;
+mod playback_listeners;
+mod plugin_config;
+mod plugin_errors;
+mod sidecar_events;
+mod source_catalog;
+
+pub use dev_plugins::{subscribe_dev_plugin_events, unwatch_dev_plugin, watch_dev_plugin};
+pub(crate) use plugin_config::{
+    load_persisted_plugin_configs, plugin_config_load_json, plugin_config_save_json,
+    plugin_config_validate_json,
+};
+pub(crate) use plugin_errors::attach_plugin_error_store;
+pub use plugin_errors::subscribe_plugin_error_events;
+pub use sidecar_events::subscribe_sidecar_state_events;
+pub use source_catalog::{
+    source_auth_status_json, source_begin_login_json, source_logout, source_poll_login_json,
+    source_refresh_auth_json, with_source_catalog,
+};
 
 pub use hybrid_decoder_stage::{
     HybridDecoderStage, HybridProbedTrackDecodeInfo, SharedUserDecoderProvider,
@@ -26,6 +58,7 @@ pub use hybrid_decoder_stage::{
     decoder_supported_extensions_hybrid_with_user_decoders, default_user_decoder_providers,
     probe_track_decode_info_hybrid, probe_track_decode_info_hybrid_with_user_decoders,
 };
+pub use pipeline::{RuntimeTeeOutput, RuntimeTeeOutputRoute, shared_http_stream_hub};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputBackend {
@@ -90,6 +123,32 @@ pub async fn runtime_set_output_options(
     engine::runtime_set_output_options(match_track_sample_rate, resample_quality).await
 }
 
+pub async fn runtime_set_output_mix_mode(mode: MixMode) -> Result<(), String> {
+    engine::runtime_set_output_mix_mode(mode).await
+}
+
+pub fn runtime_output_mix_mode() -> MixMode {
+    engine::runtime_output_mix_mode()
+}
+
+pub async fn runtime_set_output_dither(
+    tpdf: bool,
+    noise_shaping: NoiseShaping,
+) -> Result<(), String> {
+    engine::runtime_set_output_dither(tpdf, noise_shaping).await
+}
+
+pub async fn runtime_set_playback_rate(speed: f32, pitch_semitones: f32) -> Result<(), String> {
+    engine::runtime_set_playback_rate(speed, pitch_semitones).await
+}
+
+pub async fn runtime_set_output_convolution(
+    impulse: Option<Arc<ImpulseResponse>>,
+    crossfade_ms: u32,
+) -> Result<(), String> {
+    engine::runtime_set_output_convolution(impulse, crossfade_ms).await
+}
+
 pub async fn runtime_set_output_sink_route(
     plugin_id: String,
     type_id: String,
@@ -103,6 +162,34 @@ pub async fn runtime_clear_output_sink_route() -> Result<(), String> {
     engine::runtime_clear_output_sink_route().await
 }
 
+pub async fn runtime_set_output_file_route(route: FileSinkRoute) -> Result<(), String> {
+    engine::runtime_set_output_file_route(route).await
+}
+
+pub async fn runtime_clear_output_file_route() -> Result<(), String> {
+    engine::runtime_clear_output_file_route().await
+}
+
+pub async fn runtime_set_output_tee(outputs: Vec<RuntimeTeeOutput>) -> Result<(), String> {
+    engine::runtime_set_output_tee(outputs).await
+}
+
+pub async fn runtime_clear_output_tee() -> Result<(), String> {
+    engine::runtime_clear_output_tee().await
+}
+
+pub fn runtime_output_tee_metrics() -> Vec<TeeBranchMetricsSnapshot> {
+    engine::runtime_output_tee_metrics()
+}
+
+pub async fn runtime_start_http_stream(route: HttpStreamRoute) -> Result<(), String> {
+    engine::runtime_start_http_stream(route).await
+}
+
+pub async fn runtime_stop_http_stream() -> Result<(), String> {
+    engine::runtime_stop_http_stream().await
+}
+
 #[derive(Clone)]
 struct TeeWriter {
     file: Option<Arc<Mutex<std::fs::File>>>,
@@ -6,14 +6,19 @@ use std::sync::{Arc, Mutex, OnceLock};
 use stellatune_audio::config::engine::{LfeMode, ResampleQuality};
 use stellatune_audio::pipeline::assembly::{
     AssembledDecodePipeline, AssembledPipeline, BuiltinTransformSlot, BuiltinTransformSlots,
-    MixerPlan, OpaqueTransformStageSpec, PipelineAssembler, PipelineMutation, PipelinePlan,
-    PipelineRuntime, ResamplerPlan, StaticSinkPlan, TransformChain,
+    MixMode, MixerPlan, OpaqueTransformStageSpec, PipelineAssembler, PipelineMutation,
+    PipelinePlan, PipelineRuntime, ResamplerPlan, StaticSinkPlan, TransformChain,
 };
 use stellatune_audio::pipeline::graph::TransformGraph;
 use stellatune_audio_builtin_adapters::device_sink::{
     DeviceSinkControl, DeviceSinkStage, OutputBackend, default_output_spec_for_backend,
     output_spec_for_route,
 };
+use stellatune_audio_builtin_adapters::file_sink::{FileSinkRoute, FileSinkStage};
+use stellatune_audio_builtin_adapters::http_stream::{
+    HttpStreamHub, HttpStreamRoute, HttpStreamSinkStage,
+};
+use stellatune_audio_builtin_adapters::tee_sink::{TeeBranch, TeeSinkMetrics, TeeSinkStage};
 use stellatune_audio_builtin_adapters::wasapi_exclusive_sink::WasapiExclusiveSinkStage;
 use stellatune_audio_core::pipeline::context::InputRef;
 use stellatune_audio_core::pipeline::error::PipelineError;
@@ -49,6 +54,33 @@ fn fingerprint_plugin_output_route(route: &PluginOutputSinkRouteSpec) -> u64 {
     hasher.finish()
 }
 
+fn fingerprint_file_output_route(route: &FileSinkRoute) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    "file_output_route".hash(&mut hasher);
+    route.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn fingerprint_tee_output_routes(primary_fingerprint: u64, outputs: &[RuntimeTeeOutput]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    "tee_output_routes".hash(&mut hasher);
+    primary_fingerprint.hash(&mut hasher);
+    for output in outputs {
+        output.name.hash(&mut hasher);
+        output.delay_ms.hash(&mut hasher);
+        match &output.route {
+            RuntimeTeeOutputRoute::Plugin(route) => {
+                fingerprint_plugin_output_route(route).hash(&mut hasher)
+            },
+            RuntimeTeeOutputRoute::File(route) => {
+                fingerprint_file_output_route(route).hash(&mut hasher)
+            },
+            RuntimeTeeOutputRoute::HttpStream(route) => route.hash(&mut hasher),
+        }
+    }
+    hasher.finish()
+}
+
 fn shared_sink_control_cell() -> &'static OnceLock<DeviceSinkControl> {
     static CONTROL: OnceLock<DeviceSinkControl> = OnceLock::new();
     &CONTROL
@@ -59,6 +91,11 @@ fn shared_sink_route_control_cell() -> &'static OnceLock<RuntimeSinkRouteControl
     &CONTROL
 }
 
+fn shared_http_stream_hub_cell() -> &'static OnceLock<HttpStreamHub> {
+    static HUB: OnceLock<HttpStreamHub> = OnceLock::new();
+    &HUB
+}
+
 pub fn shared_device_sink_control() -> DeviceSinkControl {
     shared_sink_control_cell()
         .get_or_init(DeviceSinkControl::default)
@@ -71,26 +108,143 @@ pub fn shared_runtime_sink_route_control() -> RuntimeSinkRouteControl {
         .clone()
 }
 
+fn output_mix_mode_cell() -> &'static Mutex<MixMode> {
+    static MODE: Mutex<MixMode> = Mutex::new(MixMode::Auto);
+    &MODE
+}
+
+/// Channel routing the user picked for playback output.
+pub(crate) fn current_output_mix_mode() -> MixMode {
+    output_mix_mode_cell()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+pub(crate) fn set_output_mix_mode(mode: MixMode) {
+    *output_mix_mode_cell()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = mode;
+}
+
+/// Mixer plan for an output with `channels` channels, carrying the user's routing.
+pub(crate) fn output_mixer_plan(channels: u16) -> MixerPlan {
+    MixerPlan::new(channels, LfeMode::Mute).with_mode(current_output_mix_mode())
+}
+
+/// Hub the HTTP stream tee output publishes into and the HTTP server reads from.
+pub fn shared_http_stream_hub() -> HttpStreamHub {
+    shared_http_stream_hub_cell()
+        .get_or_init(HttpStreamHub::default)
+        .clone()
+}
+
+/// Sink an additional tee output writes into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuntimeTeeOutputRoute {
+    Plugin(PluginOutputSinkRouteSpec),
+    File(FileSinkRoute),
+    /// Live stream for LAN listeners; at most one per tee.
+    HttpStream(HttpStreamRoute),
+}
+
+/// Output mirrored alongside the primary route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeTeeOutput {
+    /// Label reported in tee metrics.
+    pub name: String,
+    pub route: RuntimeTeeOutputRoute,
+    /// Extra delay on top of the measured latency compensation, for latency the sink
+    /// cannot report such as a network player's buffer.
+    pub delay_ms: u32,
+}
+
+impl RuntimeTeeOutput {
+    fn create_sink(&self) -> Box<dyn SinkStage> {
+        match &self.route {
+            RuntimeTeeOutputRoute::Plugin(route) => {
+                Box::new(PluginOutputSinkStage::new(route.clone()))
+            },
+            RuntimeTeeOutputRoute::File(route) => Box::new(FileSinkStage::new(route.clone())),
+            RuntimeTeeOutputRoute::HttpStream(route) => Box::new(HttpStreamSinkStage::new(
+                route.clone(),
+                shared_http_stream_hub(),
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
+struct RuntimeSinkRoutes {
+    plugin: Option<PluginOutputSinkRouteSpec>,
+    file: Option<FileSinkRoute>,
+    tee_outputs: Vec<RuntimeTeeOutput>,
+}
+
+/// Sink route overrides on top of the device route. A file route takes precedence over a
+/// plugin route, so clearing it falls back to whichever route was active before. Tee
+/// outputs mirror whichever route is primary; the primary keeps pacing the engine.
+#[derive(Clone, Default)]
 pub struct RuntimeSinkRouteControl {
-    inner: Arc<Mutex<Option<PluginOutputSinkRouteSpec>>>,
+    inner: Arc<Mutex<RuntimeSinkRoutes>>,
+    tee_metrics: TeeSinkMetrics,
 }
 
 impl RuntimeSinkRouteControl {
     pub fn set_plugin_route(&self, route: PluginOutputSinkRouteSpec) {
         if let Ok(mut guard) = self.inner.lock() {
-            *guard = Some(route);
+            guard.plugin = Some(route);
         }
     }
 
     pub fn clear_plugin_route(&self) {
         if let Ok(mut guard) = self.inner.lock() {
-            *guard = None;
+            guard.plugin = None;
         }
     }
 
     pub fn current_plugin_route(&self) -> Option<PluginOutputSinkRouteSpec> {
-        self.inner.lock().ok().and_then(|guard| guard.clone())
+        self.inner
+            .lock()
+            .ok()
+            .and_then(|guard| guard.plugin.clone())
+    }
+
+    pub fn set_file_route(&self, route: FileSinkRoute) {
+        if let Ok(mut guard) = self.inner.lock() {
+            guard.file = Some(route);
+        }
+    }
+
+    pub fn clear_file_route(&self) {
+        if let Ok(mut guard) = self.inner.lock() {
+            guard.file = None;
+        }
+    }
+
+    pub fn current_file_route(&self) -> Option<FileSinkRoute> {
+        self.inner.lock().ok().and_then(|guard| guard.file.clone())
+    }
+
+    pub fn set_tee_outputs(&self, outputs: Vec<RuntimeTeeOutput>) {
+        if let Ok(mut guard) = self.inner.lock() {
+            if outputs.is_empty() {
+                self.tee_metrics.clear();
+            }
+            guard.tee_outputs = outputs;
+        }
+    }
+
+    pub fn current_tee_outputs(&self) -> Vec<RuntimeTeeOutput> {
+        self.inner
+            .lock()
+            .map(|guard| guard.tee_outputs.clone())
+            .unwrap_or_default()
+    }
+
+    /// Per-output metrics of the tee currently feeding the sink worker.
+    pub fn tee_metrics(&self) -> TeeSinkMetrics {
+        self.tee_metrics.clone()
     }
 }
 
@@ -159,6 +313,11 @@ impl V2BackendRuntime {
     }
 
     fn reset_output_plans(&mut self) {
+        if let Some(route) = shared_runtime_sink_route_control().current_file_route() {
+            self.mixer = Some(output_mixer_plan(route.channels));
+            self.resampler = Some(ResamplerPlan::new(route.sample_rate, ResampleQuality::High));
+            return;
+        }
         let control = shared_device_sink_control();
         let (backend, device_id) = control.desired_route();
         let output = output_spec_for_route(backend, device_id.as_deref())
@@ -169,7 +328,7 @@ impl V2BackendRuntime {
                     channels: self.fallback_output_channels,
                 },
             );
-        self.mixer = Some(MixerPlan::new(output.channels, LfeMode::Mute));
+        self.mixer = Some(output_mixer_plan(output.channels));
         self.resampler = Some(ResamplerPlan::new(
             output.sample_rate,
             ResampleQuality::High,
@@ -195,14 +354,17 @@ impl PipelineRuntime for V2BackendRuntime {
                 pre_mix: plugin_stages.pre_mix,
                 post_mix: plugin_stages.post_mix,
             },
-            mixer: self.mixer,
+            mixer: self.mixer.clone(),
             resampler: self.resampler,
             builtin_slots: self.builtin_slots,
         };
         let control = shared_device_sink_control();
         let route_control = shared_runtime_sink_route_control();
         let (sink_stage, sink_route_fingerprint): (Box<dyn SinkStage>, u64) =
-            if let Some(plugin_route) = route_control.current_plugin_route() {
+            if let Some(file_route) = route_control.current_file_route() {
+                let route_fingerprint = fingerprint_file_output_route(&file_route);
+                (Box::new(FileSinkStage::new(file_route)), route_fingerprint)
+            } else if let Some(plugin_route) = route_control.current_plugin_route() {
                 let route_fingerprint = fingerprint_plugin_output_route(&plugin_route);
                 (
                     Box::new(PluginOutputSinkStage::new(plugin_route)),
@@ -220,6 +382,20 @@ impl PipelineRuntime for V2BackendRuntime {
                 };
                 (stage, route_fingerprint)
             };
+        let tee_outputs = route_control.current_tee_outputs();
+        let (sink_stage, sink_route_fingerprint) = if tee_outputs.is_empty() {
+            (sink_stage, sink_route_fingerprint)
+        } else {
+            let route_fingerprint =
+                fingerprint_tee_output_routes(sink_route_fingerprint, &tee_outputs);
+            let mut branches = vec![TeeBranch::new("primary", sink_stage)];
+            branches.extend(tee_outputs.iter().map(|output| {
+                TeeBranch::new(output.name.clone(), output.create_sink())
+                    .with_delay_ms(output.delay_ms)
+            }));
+            let tee = TeeSinkStage::new(branches).with_metrics(route_control.tee_metrics());
+            (Box::new(tee) as Box<dyn SinkStage>, route_fingerprint)
+        };
         Ok(AssembledPipeline::from_parts(
             decode,
             Box::new(StaticSinkPlan::with_route_fingerprint(
@@ -254,6 +430,10 @@ impl PipelineRuntime for V2BackendRuntime {
                         self.builtin_slots.transition_gain = enabled
                     },
                     BuiltinTransformSlot::MasterGain => self.builtin_slots.master_gain = enabled,
+                    BuiltinTransformSlot::Crossfeed => self.builtin_slots.crossfeed = enabled,
+                    BuiltinTransformSlot::Convolution => self.builtin_slots.convolution = enabled,
+                    BuiltinTransformSlot::Dither => self.builtin_slots.dither = enabled,
+                    BuiltinTransformSlot::TimeStretch => self.builtin_slots.time_stretch = enabled,
                 }
                 Ok(())
             },
@@ -262,7 +442,13 @@ impl PipelineRuntime for V2BackendRuntime {
 
     fn reset(&mut self) {
         self.transform_graph = TransformGraph::default();
-        self.builtin_slots = BuiltinTransformSlots::default();
+        // Output DSP the user switched on outlives stop; the rest returns to defaults.
+        self.builtin_slots = BuiltinTransformSlots {
+            crossfeed: self.builtin_slots.crossfeed,
+            convolution: self.builtin_slots.convolution,
+            time_stretch: self.builtin_slots.time_stretch,
+            ..BuiltinTransformSlots::default()
+        };
         self.reset_output_plans();
     }
 }
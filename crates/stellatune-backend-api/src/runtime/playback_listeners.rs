@@ -0,0 +1,569 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use stellatune_audio::config::engine::{Event, PlayerState};
+use stellatune_plugins::manifest::AbilityKind;
+use stellatune_plugins::runtime::model::{
+    RuntimeMediaMetadata, RuntimePlaybackEvent, RuntimePlaybackSkipReason,
+    RuntimePlaybackTrackInfo, RuntimePluginErrorAction, RuntimePluginErrorCode,
+    RuntimePluginErrorRecord,
+};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+use super::hybrid_decoder_stage::decode_track_ref_token;
+use super::{probe_track_decode_info_hybrid, shared_plugin_runtime};
+
+const PLAYBACK_LISTENER_PROGRESS_INTERVAL_MS: u64 = 5_000;
+const PLAYBACK_LISTENER_FINISH_TOLERANCE_MS: u64 = 2_000;
+const PLAYBACK_LISTENER_QUEUE_CAPACITY: usize = 64;
+const PLAYBACK_LISTENER_DROP_LOG_INTERVAL: Duration = Duration::from_secs(5);
+const LOCAL_SOURCE_ID: &str = "local";
+
+pub(super) fn ensure_playback_listener_dispatcher_started(events: broadcast::Receiver<Event>) {
+    static DISPATCHER_STARTED: OnceLock<()> = OnceLock::new();
+    DISPATCHER_STARTED.get_or_init(|| {
+        stellatune_runtime::spawn(run_playback_listener_dispatcher(events));
+    });
+}
+
+async fn run_playback_listener_dispatcher(mut events: broadcast::Receiver<Event>) {
+    let mut tracker = PlaybackEventTracker::default();
+    let mut fanout = PlaybackListenerFanout::default();
+    loop {
+        let event = match events.recv().await {
+            Ok(event) => event,
+            Err(RecvError::Lagged(skipped)) => {
+                tracing::warn!(
+                    skipped,
+                    "playback listener dispatcher lagged behind engine events"
+                );
+                continue;
+            },
+            Err(RecvError::Closed) => break,
+        };
+        let emitted = match event {
+            Event::TrackChanged { track_token } => {
+                let track = resolve_track_info(track_token).await;
+                tracker.track_changed(track)
+            },
+            other => tracker.observe(&other),
+        };
+        for event in emitted {
+            fanout.publish(event);
+        }
+    }
+    tracing::debug!("playback listener dispatcher stopped: engine event channel closed");
+}
+
+pub(super) async fn resolve_track_info(track_token: String) -> RuntimePlaybackTrackInfo {
+    let fallback_token = track_token.clone();
+    match tokio::task::spawn_blocking(move || build_track_info(track_token)).await {
+        Ok(track) => track,
+        Err(error) => {
+            tracing::warn!(%error, "playback listener track info task failed");
+            bare_track_info(fallback_token)
+        },
+    }
+}
+
+fn bare_track_info(track_token: String) -> RuntimePlaybackTrackInfo {
+    let (source_id, track_id, locator) = match decode_track_ref_token(&track_token) {
+        Ok(track) => (track.source_id, track.track_id, track.locator),
+        Err(_) => (String::new(), String::new(), String::new()),
+    };
+    RuntimePlaybackTrackInfo {
+        track_token,
+        source_id,
+        track_id,
+        locator,
+        duration_ms: None,
+        metadata: None,
+    }
+}
+
+fn build_track_info(track_token: String) -> RuntimePlaybackTrackInfo {
+    let mut track = bare_track_info(track_token);
+    // Only local files are probed: source tracks may need a network round trip to open.
+    if track.source_id != LOCAL_SOURCE_ID {
+        return track;
+    }
+    match probe_track_decode_info_hybrid(&track.track_token) {
+        Ok(probed) => {
+            track.duration_ms = probed.duration_ms;
+            track.metadata = probed
+                .metadata_json
+                .as_deref()
+                .and_then(|raw| serde_json::from_str::<RuntimeMediaMetadata>(raw).ok());
+            if track.duration_ms.is_none() {
+                track.duration_ms = track.metadata.as_ref().and_then(|meta| meta.duration_ms);
+            }
+        },
+        Err(error) => {
+            tracing::debug!(
+                track_token = %track.track_token,
+                %error,
+                "playback listener track probe failed"
+            );
+        },
+    }
+    track
+}
+
+/// Turns the engine's low-level event stream into track-level playback events.
+#[derive(Debug, Default)]
+struct PlaybackEventTracker {
+    current: Option<RuntimePlaybackTrackInfo>,
+    state: Option<PlayerState>,
+    position_ms: u64,
+    position_before_reset_ms: Option<u64>,
+    last_progress_ms: Option<u64>,
+    ended: bool,
+    pending_stop: bool,
+}
+
+impl PlaybackEventTracker {
+    fn track_changed(&mut self, track: RuntimePlaybackTrackInfo) -> Vec<RuntimePlaybackEvent> {
+        let mut out = Vec::new();
+        if self.pending_stop {
+            self.flush_pending_stop(&mut out);
+        } else if let Some(previous) = self.current.as_ref()
+            && !self.ended
+        {
+            // Gapless cutover resets the position right before switching tracks.
+            let position_ms = self.position_before_reset_ms.unwrap_or(self.position_ms);
+            if self.is_near_end(position_ms) {
+                out.push(RuntimePlaybackEvent::Finished {
+                    track: previous.clone(),
+                });
+            } else {
+                out.push(RuntimePlaybackEvent::Skipped {
+                    track: previous.clone(),
+                    position_ms,
+                    reason: RuntimePlaybackSkipReason::UserSwitch,
+                });
+            }
+        }
+
+        self.current = Some(track.clone());
+        self.position_ms = 0;
+        self.position_before_reset_ms = None;
+        self.last_progress_ms = None;
+        self.ended = false;
+        out.push(RuntimePlaybackEvent::TrackStarted { track });
+        out
+    }
+
+    fn observe(&mut self, event: &Event) -> Vec<RuntimePlaybackEvent> {
+        let mut out = Vec::new();
+        match event {
+            Event::Eof => {
+                self.pending_stop = false;
+                if let Some(track) = self.current.as_ref()
+                    && !self.ended
+                {
+                    out.push(RuntimePlaybackEvent::Finished {
+                        track: track.clone(),
+                    });
+                }
+                self.ended = true;
+            },
+            Event::StateChanged { state } => {
+                self.flush_pending_stop(&mut out);
+                self.state = Some(*state);
+                match state {
+                    PlayerState::Paused => {
+                        if let Some(track) = self.current.as_ref()
+                            && !self.ended
+                        {
+                            out.push(RuntimePlaybackEvent::Paused {
+                                track: track.clone(),
+                                position_ms: self.position_ms,
+                            });
+                        }
+                    },
+                    PlayerState::Stopped => {
+                        if self.current.is_some() && !self.ended {
+                            // A natural end reports `Stopped` right before `Eof`; hold the
+                            // skip back unless the track clearly stopped early.
+                            self.pending_stop = true;
+                            if self.is_known_early_stop(self.position_ms) {
+                                self.flush_pending_stop(&mut out);
+                            }
+                        }
+                    },
+                    PlayerState::Playing => {},
+                }
+            },
+            Event::Position { position_ms } => {
+                self.flush_pending_stop(&mut out);
+                let position_ms = (*position_ms).max(0) as u64;
+                self.position_before_reset_ms =
+                    (position_ms == 0 && self.position_ms > 0).then_some(self.position_ms);
+                self.position_ms = position_ms;
+                if self.state == Some(PlayerState::Playing)
+                    && !self.ended
+                    && let Some(track) = self.current.as_ref()
+                {
+                    let due = self.last_progress_ms.is_none_or(|last| {
+                        last.abs_diff(position_ms) >= PLAYBACK_LISTENER_PROGRESS_INTERVAL_MS
+                    });
+                    if due {
+                        self.last_progress_ms = Some(position_ms);
+                        out.push(RuntimePlaybackEvent::Progress {
+                            track: track.clone(),
+                            position_ms,
+                        });
+                    }
+                }
+            },
+            _ => {},
+        }
+        out
+    }
+
+    fn flush_pending_stop(&mut self, out: &mut Vec<RuntimePlaybackEvent>) {
+        if !self.pending_stop {
+            return;
+        }
+        self.pending_stop = false;
+        if let Some(track) = self.current.as_ref()
+            && !self.ended
+        {
+            out.push(RuntimePlaybackEvent::Skipped {
+                track: track.clone(),
+                position_ms: self.position_ms,
+                reason: RuntimePlaybackSkipReason::Stopped,
+            });
+        }
+        self.ended = true;
+    }
+
+    fn current_duration_ms(&self) -> Option<u64> {
+        self.current.as_ref().and_then(|track| track.duration_ms)
+    }
+
+    fn is_near_end(&self, position_ms: u64) -> bool {
+        self.current_duration_ms().is_some_and(|duration_ms| {
+            position_ms.saturating_add(PLAYBACK_LISTENER_FINISH_TOLERANCE_MS) >= duration_ms
+        })
+    }
+
+    fn is_known_early_stop(&self, position_ms: u64) -> bool {
+        self.current_duration_ms().is_some() && !self.is_near_end(position_ms)
+    }
+}
+
+struct PlaybackListenerWorker {
+    tx: SyncSender<Arc<RuntimePlaybackEvent>>,
+    dropped_total: u64,
+    last_drop_log_at: Option<Instant>,
+}
+
+#[derive(Default)]
+struct PlaybackListenerFanout {
+    workers: HashMap<(String, String), PlaybackListenerWorker>,
+    /// Plugin runtime revision the workers were last reconciled against.
+    reconciled_revision: Option<u64>,
+}
+
+impl PlaybackListenerFanout {
+    fn publish(&mut self, event: RuntimePlaybackEvent) {
+        self.reconcile_workers();
+        if self.workers.is_empty() {
+            return;
+        }
+        let event = Arc::new(event);
+        let mut disconnected = Vec::new();
+        for (key, worker) in &mut self.workers {
+            match worker.tx.try_send(Arc::clone(&event)) {
+                Ok(()) => {},
+                Err(TrySendError::Full(_)) => {
+                    // Listeners must never stall playback: a slow plugin loses events instead.
+                    worker.dropped_total = worker.dropped_total.saturating_add(1);
+                    let now = Instant::now();
+                    let should_log = worker.last_drop_log_at.is_none_or(|at| {
+                        now.duration_since(at) >= PLAYBACK_LISTENER_DROP_LOG_INTERVAL
+                    });
+                    if should_log {
+                        worker.last_drop_log_at = Some(now);
+                        tracing::warn!(
+                            plugin_id = %key.0,
+                            type_id = %key.1,
+                            dropped_total = worker.dropped_total,
+                            "playback listener queue full, dropping events"
+                        );
+                    }
+                },
+                Err(TrySendError::Disconnected(_)) => disconnected.push(key.clone()),
+            }
+        }
+        if !disconnected.is_empty() {
+            for key in disconnected {
+                self.workers.remove(&key);
+            }
+            // A worker whose thread died is respawned on the next publish.
+            self.reconciled_revision = None;
+        }
+    }
+
+    fn reconcile_workers(&mut self) {
+        let service = shared_plugin_runtime();
+        // Capabilities only change when plugins load or unload; skip the listing otherwise.
+        let revision = service.active_plugins_revision();
+        if self.reconciled_revision == Some(revision) {
+            return;
+        }
+        self.reconciled_revision = Some(revision);
+        let mut desired = HashSet::<(String, String)>::new();
+        for plugin_id in service.active_plugin_ids() {
+            for capability in service.list_playback_listener_capabilities(&plugin_id) {
+                desired.insert((plugin_id.clone(), capability.type_id));
+            }
+        }
+        // Dropping a worker's sender lets its thread drain and close the listener.
+        self.workers.retain(|key, _| desired.contains(key));
+        for key in desired {
+            if self.workers.contains_key(&key) {
+                continue;
+            }
+            if let Some(worker) = spawn_playback_listener_worker(&key.0, &key.1) {
+                self.workers.insert(key, worker);
+            }
+        }
+    }
+}
+
+fn spawn_playback_listener_worker(
+    plugin_id: &str,
+    type_id: &str,
+) -> Option<PlaybackListenerWorker> {
+    let (tx, rx) = mpsc::sync_channel(PLAYBACK_LISTENER_QUEUE_CAPACITY);
+    let thread_plugin_id = plugin_id.to_string();
+    let thread_type_id = type_id.to_string();
+    let spawned = std::thread::Builder::new()
+        .name(format!("stellatune-playback-listener-{plugin_id}"))
+        .spawn(move || run_playback_listener_worker(thread_plugin_id, thread_type_id, rx));
+    match spawned {
+        Ok(_) => Some(PlaybackListenerWorker {
+            tx,
+            dropped_total: 0,
+            last_drop_log_at: None,
+        }),
+        Err(error) => {
+            tracing::warn!(
+                plugin_id,
+                type_id,
+                %error,
+                "failed to spawn playback listener worker"
+            );
+            None
+        },
+    }
+}
+
+fn run_playback_listener_worker(
+    plugin_id: String,
+    type_id: String,
+    rx: Receiver<Arc<RuntimePlaybackEvent>>,
+) {
+    let mut listener = None;
+    while let Ok(event) = rx.recv() {
+        if listener.is_none() {
+            match shared_plugin_runtime().create_playback_listener_plugin(&plugin_id, &type_id) {
+                Ok(created) => listener = Some(created),
+                Err(error) => {
+                    tracing::warn!(
+                        plugin_id,
+                        type_id,
+                        %error,
+                        "failed to create playback listener"
+                    );
+                    continue;
+                },
+            }
+        }
+        if let Some(active) = listener.as_mut()
+            && let Err(error) = active.on_event(&event)
+        {
+            tracing::warn!(
+                plugin_id,
+                type_id,
+                %error,
+                "playback listener on_event failed; recreating on next event"
+            );
+            shared_plugin_runtime().report_plugin_error(
+                RuntimePluginErrorRecord::new(
+                    RuntimePluginErrorCode::OperationFailed,
+                    &plugin_id,
+                    RuntimePluginErrorAction::Operation,
+                    format!("playback listener on_event failed: {error:#}"),
+                )
+                .with_capability(AbilityKind::PlaybackListener, &type_id),
+            );
+            listener = None;
+        }
+    }
+    if let Some(mut active) = listener
+        && let Err(error) = active.close()
+    {
+        tracing::debug!(plugin_id, type_id, %error, "playback listener close failed");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlaybackEventTracker, RuntimePlaybackEvent, RuntimePlaybackSkipReason};
+    use stellatune_audio::config::engine::{Event, PlayerState};
+    use stellatune_plugins::runtime::model::RuntimePlaybackTrackInfo;
+
+    fn track(id: &str, duration_ms: Option<u64>) -> RuntimePlaybackTrackInfo {
+        RuntimePlaybackTrackInfo {
+            track_token: id.to_string(),
+            source_id: "local".to_string(),
+            track_id: id.to_string(),
+            locator: id.to_string(),
+            duration_ms,
+            metadata: None,
+        }
+    }
+
+    fn start(tracker: &mut PlaybackEventTracker, id: &str, duration_ms: Option<u64>) {
+        tracker.track_changed(track(id, duration_ms));
+        tracker.observe(&Event::StateChanged {
+            state: PlayerState::Playing,
+        });
+    }
+
+    #[test]
+    fn progress_is_throttled_and_reemitted_after_seek() {
+        let mut tracker = PlaybackEventTracker::default();
+        start(&mut tracker, "a", Some(60_000));
+
+        let first = tracker.observe(&Event::Position { position_ms: 100 });
+        let throttled = tracker.observe(&Event::Position { position_ms: 2_000 });
+        let due = tracker.observe(&Event::Position { position_ms: 5_100 });
+        let seeked = tracker.observe(&Event::Position {
+            position_ms: 40_000,
+        });
+
+        assert!(matches!(
+            first.as_slice(),
+            [RuntimePlaybackEvent::Progress {
+                position_ms: 100,
+                ..
+            }]
+        ));
+        assert!(throttled.is_empty());
+        assert_eq!(due.len(), 1);
+        assert_eq!(seeked.len(), 1);
+    }
+
+    #[test]
+    fn natural_end_reports_finished_instead_of_stopped_skip() {
+        let mut tracker = PlaybackEventTracker::default();
+        start(&mut tracker, "a", None);
+        tracker.observe(&Event::Position {
+            position_ms: 30_000,
+        });
+
+        let stopped = tracker.observe(&Event::StateChanged {
+            state: PlayerState::Stopped,
+        });
+        let eof = tracker.observe(&Event::Eof);
+
+        assert!(stopped.is_empty());
+        assert!(matches!(
+            eof.as_slice(),
+            [RuntimePlaybackEvent::Finished { .. }]
+        ));
+    }
+
+    #[test]
+    fn early_stop_reports_stopped_skip_once() {
+        let mut tracker = PlaybackEventTracker::default();
+        start(&mut tracker, "a", Some(60_000));
+        tracker.observe(&Event::Position {
+            position_ms: 10_000,
+        });
+
+        let stopped = tracker.observe(&Event::StateChanged {
+            state: PlayerState::Stopped,
+        });
+        let eof = tracker.observe(&Event::Eof);
+
+        assert!(matches!(
+            stopped.as_slice(),
+            [RuntimePlaybackEvent::Skipped {
+                position_ms: 10_000,
+                reason: RuntimePlaybackSkipReason::Stopped,
+                ..
+            }]
+        ));
+        assert!(eof.is_empty());
+    }
+
+    #[test]
+    fn switching_tracks_mid_play_reports_user_switch_skip() {
+        let mut tracker = PlaybackEventTracker::default();
+        start(&mut tracker, "a", Some(60_000));
+        tracker.observe(&Event::Position {
+            position_ms: 12_000,
+        });
+
+        let events = tracker.track_changed(track("b", Some(60_000)));
+
+        assert!(matches!(
+            events.as_slice(),
+            [
+                RuntimePlaybackEvent::Skipped {
+                    position_ms: 12_000,
+                    reason: RuntimePlaybackSkipReason::UserSwitch,
+                    ..
+                },
+                RuntimePlaybackEvent::TrackStarted { .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn gapless_cutover_reports_finished_for_previous_track() {
+        let mut tracker = PlaybackEventTracker::default();
+        start(&mut tracker, "a", Some(60_000));
+        tracker.observe(&Event::Position {
+            position_ms: 59_500,
+        });
+        tracker.observe(&Event::Position { position_ms: 0 });
+
+        let events = tracker.track_changed(track("b", Some(60_000)));
+
+        assert!(matches!(
+            events.as_slice(),
+            [
+                RuntimePlaybackEvent::Finished { .. },
+                RuntimePlaybackEvent::TrackStarted { .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn pause_reports_current_position() {
+        let mut tracker = PlaybackEventTracker::default();
+        start(&mut tracker, "a", Some(60_000));
+        tracker.observe(&Event::Position { position_ms: 7_000 });
+
+        let events = tracker.observe(&Event::StateChanged {
+            state: PlayerState::Paused,
+        });
+
+        assert!(matches!(
+            events.as_slice(),
+            [RuntimePlaybackEvent::Paused {
+                position_ms: 7_000,
+                ..
+            }]
+        ));
+    }
+}
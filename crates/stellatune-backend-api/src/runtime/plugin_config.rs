@@ -0,0 +1,169 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, anyhow};
+use serde_json::{Value, json};
+
+use stellatune_library::{LibraryHandle, PluginConfigRecord};
+use stellatune_plugins::config_schema::ConfigSchema;
+use stellatune_plugins::host_runtime::RuntimeCapabilityDescriptor;
+
+use super::shared_plugin_runtime;
+
+/// Loads the persisted config of `plugin_id`/`type_id` together with its render panel.
+///
+/// Configs saved under an older schema version are migrated and written back, so the
+/// returned `config` always matches `schema_version`.
+pub(crate) async fn plugin_config_load_json(
+    library: &LibraryHandle,
+    plugin_id: &str,
+    type_id: &str,
+) -> Result<String> {
+    let (capability, schema) = capability_schema(plugin_id, type_id)?;
+    let stored = library.load_plugin_config(plugin_id, type_id).await?;
+
+    let (config, migrated_from) = match &stored {
+        Some(record) => {
+            let config = serde_json::from_str::<Value>(&record.config_json).map_err(|error| {
+                anyhow!("stored config of {plugin_id}::{type_id} is not JSON: {error}")
+            })?;
+            let migrated_from =
+                (record.schema_version != schema.version).then_some(record.schema_version);
+            (schema.migrate(config, record.schema_version), migrated_from)
+        },
+        None => {
+            let mut config = serde_json::from_str::<Value>(&capability.default_config_json)
+                .unwrap_or_else(|_| json!({}));
+            if !config.is_object() {
+                config = json!({});
+            }
+            schema.apply_defaults(&mut config);
+            (config, None)
+        },
+    };
+
+    if migrated_from.is_some() {
+        save_config(
+            library,
+            plugin_id,
+            type_id,
+            schema.version,
+            config.to_string(),
+        )
+        .await?;
+    }
+
+    Ok(json!({
+        "plugin_id": plugin_id.trim(),
+        "type_id": type_id.trim(),
+        "display_name": capability.display_name,
+        "schema_version": schema.version,
+        "panel": schema,
+        "config": config,
+        "migrated_from": migrated_from,
+        "errors": schema.validate(&config),
+    })
+    .to_string())
+}
+
+/// Validates `config_json` without persisting it. Returns `{ "valid", "errors" }`.
+pub(crate) fn plugin_config_validate_json(
+    plugin_id: &str,
+    type_id: &str,
+    config_json: &str,
+) -> Result<String> {
+    let (_, schema) = capability_schema(plugin_id, type_id)?;
+    let errors = schema.validate_json(config_json);
+    Ok(json!({ "valid": errors.is_empty(), "errors": errors }).to_string())
+}
+
+/// Validates and, when valid, persists `config_json` under the current schema version.
+///
+/// Returns the same shape as [`plugin_config_validate_json`]; nothing is written when
+/// `valid` is false.
+pub(crate) async fn plugin_config_save_json(
+    library: &LibraryHandle,
+    plugin_id: &str,
+    type_id: &str,
+    config_json: &str,
+) -> Result<String> {
+    let (_, schema) = capability_schema(plugin_id, type_id)?;
+    let errors = schema.validate_json(config_json);
+    if errors.is_empty() {
+        save_config(
+            library,
+            plugin_id,
+            type_id,
+            schema.version,
+            config_json.to_string(),
+        )
+        .await?;
+    }
+    Ok(json!({ "valid": errors.is_empty(), "errors": errors }).to_string())
+}
+
+/// Hands every saved config to the plugin runtime so instances created from now on start
+/// with it. Called when a library attaches.
+pub(crate) async fn load_persisted_plugin_configs(library: &LibraryHandle) -> Result<()> {
+    let runtime = shared_plugin_runtime();
+    for record in library.list_plugin_configs().await? {
+        runtime.set_persisted_config(
+            &record.plugin_id,
+            &record.type_id,
+            record.schema_version,
+            record.config_json,
+        );
+    }
+    Ok(())
+}
+
+async fn save_config(
+    library: &LibraryHandle,
+    plugin_id: &str,
+    type_id: &str,
+    schema_version: u32,
+    config_json: String,
+) -> Result<()> {
+    let record = config_record(plugin_id, type_id, schema_version, config_json);
+    library.save_plugin_config(record.clone()).await?;
+    shared_plugin_runtime().set_persisted_config(
+        &record.plugin_id,
+        &record.type_id,
+        record.schema_version,
+        record.config_json,
+    );
+    Ok(())
+}
+
+fn capability_schema(
+    plugin_id: &str,
+    type_id: &str,
+) -> Result<(RuntimeCapabilityDescriptor, ConfigSchema)> {
+    let plugin_id = plugin_id.trim();
+    let type_id = type_id.trim();
+    let capability = shared_plugin_runtime()
+        .list_capabilities(plugin_id)
+        .into_iter()
+        .find(|capability| capability.type_id == type_id)
+        .ok_or_else(|| anyhow!("capability not found: {plugin_id}::{type_id}"))?;
+    let schema = ConfigSchema::parse(&capability.config_schema_json)
+        .map_err(|error| anyhow!("invalid config schema of {plugin_id}::{type_id}: {error}"))?;
+    Ok((capability, schema))
+}
+
+fn config_record(
+    plugin_id: &str,
+    type_id: &str,
+    schema_version: u32,
+    config_json: String,
+) -> PluginConfigRecord {
+    PluginConfigRecord {
+        plugin_id: plugin_id.trim().to_string(),
+        type_id: type_id.trim().to_string(),
+        schema_version,
+        config_json,
+        updated_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis().min(i64::MAX as u128) as i64)
+            .unwrap_or(0),
+    }
+}
@@ -0,0 +1,126 @@
+use std::sync::{Mutex, OnceLock};
+
+use stellatune_library::{LibraryHandle, PluginErrorEntry};
+use stellatune_plugins::runtime::model::RuntimePluginErrorRecord;
+use tokio::sync::broadcast;
+
+use super::shared_plugin_runtime;
+
+const PLUGIN_ERROR_EVENT_CAPACITY: usize = 256;
+/// Errors held back while no library is attached; matches the timeline's live window.
+const PLUGIN_ERROR_PENDING_CAPACITY: usize = 256;
+
+struct PluginErrorStore {
+    library: Option<LibraryHandle>,
+    /// Errors reported while no library was attached, persisted on the next attach.
+    pending: Vec<PluginErrorEntry>,
+}
+
+static PLUGIN_ERROR_STORE: Mutex<PluginErrorStore> = Mutex::new(PluginErrorStore {
+    library: None,
+    pending: Vec::new(),
+});
+
+/// Persists plugin errors into `library`'s timeline from now on, starting with the ones
+/// reported before any library was attached.
+///
+/// The most recently attached library wins.
+pub(crate) fn attach_plugin_error_store(library: LibraryHandle) {
+    // Starting the recorder queues the runtime's recent history as pending.
+    ensure_plugin_error_recorder_started();
+    let pending = match PLUGIN_ERROR_STORE.lock() {
+        Ok(mut store) => {
+            store.library = Some(library.clone());
+            std::mem::take(&mut store.pending)
+        },
+        Err(_) => return,
+    };
+    if pending.is_empty() {
+        return;
+    }
+    stellatune_runtime::spawn(async move {
+        if let Err(error) = library.record_plugin_errors(pending).await {
+            tracing::warn!(%error, "failed to persist plugin errors reported before attach");
+        }
+    });
+}
+
+/// Live plugin errors as they are reported by the runtime. Entries carry `id == 0` since
+/// they are delivered before (and independently of) persistence.
+pub fn subscribe_plugin_error_events() -> broadcast::Receiver<PluginErrorEntry> {
+    ensure_plugin_error_recorder_started().subscribe()
+}
+
+pub(crate) fn plugin_error_entry(record: &RuntimePluginErrorRecord) -> PluginErrorEntry {
+    PluginErrorEntry {
+        id: 0,
+        plugin_id: record.plugin_id.clone(),
+        code: record.code.as_str().to_string(),
+        capability_kind: record.capability_kind.map(|kind| kind.as_str().to_string()),
+        type_id: record.type_id.clone(),
+        action: record.action.as_str().to_string(),
+        severity: record.severity.as_str().to_string(),
+        detail: record.detail.clone(),
+        occurred_at_ms: record.timestamp_ms.min(i64::MAX as u64) as i64,
+    }
+}
+
+fn ensure_plugin_error_recorder_started() -> &'static broadcast::Sender<PluginErrorEntry> {
+    static EVENTS: OnceLock<broadcast::Sender<PluginErrorEntry>> = OnceLock::new();
+    EVENTS.get_or_init(|| {
+        let (tx, _rx) = broadcast::channel(PLUGIN_ERROR_EVENT_CAPACITY);
+        let (backlog, errors) = shared_plugin_runtime().subscribe_plugin_errors_with_backlog();
+        queue_pending(backlog.iter().map(plugin_error_entry).collect());
+        let events = tx.clone();
+        let spawned = std::thread::Builder::new()
+            .name("stellatune-plugin-errors".to_string())
+            .spawn(move || {
+                while let Ok(record) = errors.recv() {
+                    // Persist bursts (e.g. a sync failing several plugins) in one write.
+                    let batch = std::iter::once(record)
+                        .chain(errors.try_iter())
+                        .map(|record| {
+                            let entry = plugin_error_entry(&record);
+                            let _ = events.send(entry.clone());
+                            entry
+                        })
+                        .collect();
+                    persist_plugin_errors(batch);
+                }
+            });
+        if let Err(error) = spawned {
+            tracing::warn!(%error, "failed to spawn plugin error recorder");
+        }
+        tx
+    })
+}
+
+fn queue_pending(entries: Vec<PluginErrorEntry>) {
+    if let Ok(mut store) = PLUGIN_ERROR_STORE.lock() {
+        push_pending(&mut store.pending, entries);
+    }
+}
+
+fn push_pending(pending: &mut Vec<PluginErrorEntry>, entries: Vec<PluginErrorEntry>) {
+    pending.extend(entries);
+    let overflow = pending.len().saturating_sub(PLUGIN_ERROR_PENDING_CAPACITY);
+    pending.drain(..overflow);
+}
+
+fn persist_plugin_errors(entries: Vec<PluginErrorEntry>) {
+    let library = {
+        let Ok(mut store) = PLUGIN_ERROR_STORE.lock() else {
+            return;
+        };
+        match store.library.clone() {
+            Some(library) => library,
+            None => {
+                push_pending(&mut store.pending, entries);
+                return;
+            },
+        }
+    };
+    if let Err(error) = stellatune_runtime::block_on(library.record_plugin_errors(entries)) {
+        tracing::warn!(%error, "failed to persist plugin errors");
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::OnceLock;
+
+use stellatune_plugins::host::sidecar::SidecarStateEvent;
+use tokio::sync::broadcast;
+
+use super::shared_plugin_runtime;
+
+const SIDECAR_EVENT_CAPACITY: usize = 64;
+
+/// State transitions of supervised plugin sidecars (starting, running, restarting, ...)
+/// as the runtime publishes them.
+pub fn subscribe_sidecar_state_events() -> broadcast::Receiver<SidecarStateEvent> {
+    ensure_sidecar_event_forwarder_started().subscribe()
+}
+
+fn ensure_sidecar_event_forwarder_started() -> &'static broadcast::Sender<SidecarStateEvent> {
+    static EVENTS: OnceLock<broadcast::Sender<SidecarStateEvent>> = OnceLock::new();
+    EVENTS.get_or_init(|| {
+        let (tx, _rx) = broadcast::channel(SIDECAR_EVENT_CAPACITY);
+        let states = shared_plugin_runtime().subscribe_sidecar_events();
+        let events = tx.clone();
+        let spawned = std::thread::Builder::new()
+            .name("stellatune-sidecar-events".to_string())
+            .spawn(move || {
+                while let Ok(event) = states.recv() {
+                    let _ = events.send(event);
+                }
+            });
+        if let Err(error) = spawned {
+            tracing::warn!(%error, "failed to spawn sidecar event forwarder");
+        }
+        tx
+    })
+}
@@ -0,0 +1,119 @@
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use stellatune_plugins::error::Error as WasmPluginError;
+use stellatune_plugins::host_runtime::RuntimeSourcePlugin;
+use stellatune_plugins::manifest::AbilityKind;
+use stellatune_plugins::runtime::model::RuntimeLoginMethod;
+
+use super::shared_plugin_runtime;
+
+/// Runs `call` against a fresh catalog of `plugin_id::type_id` configured with
+/// `config_json`.
+///
+/// Failures are also reported as plugin errors; auth failures as
+/// `auth_required`/`auth_expired`, so listeners of [`super::subscribe_plugin_error_events`]
+/// can prompt for login.
+pub fn with_source_catalog<T>(
+    plugin_id: &str,
+    type_id: &str,
+    config_json: &str,
+    operation: &str,
+    call: impl FnOnce(&mut RuntimeSourcePlugin) -> std::result::Result<T, WasmPluginError>,
+) -> Result<T> {
+    let config = serde_json::from_str::<serde_json::Value>(config_json)
+        .map_err(|e| anyhow!("invalid source config_json: {e}"))?;
+    let config =
+        serde_json::to_string(&config).map_err(|e| anyhow!("serialize source config_json: {e}"))?;
+    let runtime = shared_plugin_runtime();
+    let mut source = runtime
+        .create_source_plugin(plugin_id, type_id)
+        .map_err(|e| anyhow!("create source plugin failed: {e}"))?;
+    source.apply_config_update_json(&config).map_err(|error| {
+        let detail = format!("source apply_config_update_json failed: {error}");
+        runtime.report_operation_failure(
+            plugin_id,
+            AbilityKind::Source,
+            type_id,
+            &error,
+            detail.clone(),
+        );
+        anyhow!(detail)
+    })?;
+    call(&mut source).map_err(|error| {
+        let detail = format!("source {operation} failed: {error}");
+        runtime.report_operation_failure(
+            plugin_id,
+            AbilityKind::Source,
+            type_id,
+            &error,
+            detail.clone(),
+        );
+        anyhow!(detail)
+    })
+}
+
+pub fn source_auth_status_json(
+    plugin_id: &str,
+    type_id: &str,
+    config_json: &str,
+) -> Result<String> {
+    let status = with_source_catalog(plugin_id, type_id, config_json, "auth_status", |source| {
+        source.auth_status()
+    })?;
+    to_json(&status)
+}
+
+/// `method` is `qr_code`, `url` or `device_code`; `None` lets the plugin choose.
+pub fn source_begin_login_json(
+    plugin_id: &str,
+    type_id: &str,
+    config_json: &str,
+    method: Option<&str>,
+) -> Result<String> {
+    let method = method
+        .map(|method| {
+            serde_json::from_value::<RuntimeLoginMethod>(serde_json::Value::String(
+                method.to_string(),
+            ))
+            .map_err(|_| anyhow!("unknown login method `{method}`"))
+        })
+        .transpose()?;
+    let challenge =
+        with_source_catalog(plugin_id, type_id, config_json, "begin_login", |source| {
+            source.begin_login(method)
+        })?;
+    to_json(&challenge)
+}
+
+pub fn source_poll_login_json(
+    plugin_id: &str,
+    type_id: &str,
+    config_json: &str,
+    session_id: &str,
+) -> Result<String> {
+    let progress = with_source_catalog(plugin_id, type_id, config_json, "poll_login", |source| {
+        source.poll_login(session_id)
+    })?;
+    to_json(&progress)
+}
+
+pub fn source_refresh_auth_json(
+    plugin_id: &str,
+    type_id: &str,
+    config_json: &str,
+) -> Result<String> {
+    let status = with_source_catalog(plugin_id, type_id, config_json, "refresh_auth", |source| {
+        source.refresh_auth()
+    })?;
+    to_json(&status)
+}
+
+pub fn source_logout(plugin_id: &str, type_id: &str, config_json: &str) -> Result<()> {
+    with_source_catalog(plugin_id, type_id, config_json, "logout", |source| {
+        source.logout()
+    })
+}
+
+fn to_json(value: &impl Serialize) -> Result<String> {
+    serde_json::to_string(value).map_err(|e| anyhow!("serialize source response: {e}"))
+}
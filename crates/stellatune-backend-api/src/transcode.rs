@@ -0,0 +1,562 @@
+//! Batch export of library tracks into files.
+//!
+//! Jobs render each track through its own offline pipeline (hybrid decoder, built-in
+//! mixer and resampler, optional plugin DSP chain, encoder sink) on blocking worker
+//! threads. They never touch the live playback engine, so exports can run while music
+//! keeps playing.
+//!
+//! Only WAV and FLAC encoders are built in. Opus and MP3 need libopus and LAME, native
+//! libraries the workspace does not link, so lossy targets are left to encoders the
+//! app registers through [`TranscodeService::register_encoder`].
+
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result, anyhow};
+use stellatune_audio::config::engine::ResampleQuality;
+use stellatune_audio::pipeline::offline::OfflineRenderOutcome;
+use stellatune_audio_plugin_adapters::pipeline::PluginTransformStageSpec;
+use stellatune_library::TrackLite;
+use tokio::sync::{Semaphore, broadcast};
+use tokio::task::JoinSet;
+
+mod encoder;
+mod render;
+
+pub use self::encoder::{
+    FlacTranscodeEncoder, TranscodeEncoder, TranscodeSinkRequest, WavTranscodeEncoder,
+};
+
+use self::render::render_track;
+
+/// Minimum rendered-position step between two progress events of a track.
+const PROGRESS_INTERVAL_MS: i64 = 500;
+
+#[derive(Debug, Clone)]
+pub struct TranscodeOptions {
+    /// Id of a registered [`TranscodeEncoder`], e.g. `"flac"`.
+    pub encoder_id: String,
+    pub output_dir: PathBuf,
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub bits_per_sample: u8,
+    pub resample_quality: ResampleQuality,
+    /// Plugin DSP stages applied in their segments, like the playback chain.
+    pub dsp_chain: Vec<PluginTransformStageSpec>,
+    /// Copy source tags and cover into the output when the encoder supports it.
+    pub copy_tags: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscodeEvent {
+    JobStarted {
+        job_id: u64,
+        total_tracks: usize,
+    },
+    TrackStarted {
+        job_id: u64,
+        track_id: i64,
+        output_path: String,
+    },
+    TrackProgress {
+        job_id: u64,
+        track_id: i64,
+        position_ms: i64,
+        duration_ms: Option<i64>,
+    },
+    TrackCompleted {
+        job_id: u64,
+        track_id: i64,
+        output_path: String,
+    },
+    TrackFailed {
+        job_id: u64,
+        track_id: i64,
+        error: String,
+    },
+    JobFinished {
+        job_id: u64,
+        completed: usize,
+        failed: usize,
+        cancelled: usize,
+    },
+}
+
+enum TrackResult {
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+/// Runs transcode jobs with a shared cap on concurrently rendered tracks.
+#[derive(Clone)]
+pub struct TranscodeService {
+    shared: Arc<TranscodeShared>,
+}
+
+struct TranscodeShared {
+    encoders: Mutex<HashMap<String, Arc<dyn TranscodeEncoder>>>,
+    permits: Arc<Semaphore>,
+    jobs: Mutex<HashMap<u64, Arc<AtomicBool>>>,
+    events: broadcast::Sender<TranscodeEvent>,
+    next_job_id: AtomicU64,
+}
+
+impl TranscodeShared {
+    fn emit(&self, event: TranscodeEvent) {
+        let _ = self.events.send(event);
+    }
+}
+
+impl TranscodeService {
+    /// Creates a service with the built-in WAV and FLAC encoders registered.
+    pub fn new(max_concurrent_tracks: usize) -> Self {
+        let (events, _rx) = broadcast::channel(1024);
+        let encoders = encoder::builtin_encoders()
+            .into_iter()
+            .map(|encoder| (encoder.id().to_string(), encoder))
+            .collect();
+        Self {
+            shared: Arc::new(TranscodeShared {
+                encoders: Mutex::new(encoders),
+                permits: Arc::new(Semaphore::new(max_concurrent_tracks.max(1))),
+                jobs: Mutex::new(HashMap::new()),
+                events,
+                next_job_id: AtomicU64::new(1),
+            }),
+        }
+    }
+
+    /// Registers `encoder`, replacing any encoder with the same id.
+    pub fn register_encoder(&self, encoder: Arc<dyn TranscodeEncoder>) {
+        self.shared
+            .encoders
+            .lock()
+            .expect("transcode encoders mutex poisoned")
+            .insert(encoder.id().to_string(), encoder);
+    }
+
+    pub fn encoder_ids(&self) -> Vec<String> {
+        let mut ids = self
+            .shared
+            .encoders
+            .lock()
+            .expect("transcode encoders mutex poisoned")
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+
+    pub fn subscribe_events(&self) -> broadcast::Receiver<TranscodeEvent> {
+        self.shared.events.subscribe()
+    }
+
+    /// Starts a job rendering `tracks` into `options.output_dir` and returns its id.
+    ///
+    /// Outputs are named after the source file stem; existing files are never
+    /// overwritten, a ` (n)` suffix is appended instead. Each output path is reserved by
+    /// creating it before the job starts, so concurrent jobs cannot pick the same name.
+    pub fn submit(&self, tracks: Vec<TrackLite>, options: TranscodeOptions) -> Result<u64> {
+        let encoder = self
+            .shared
+            .encoders
+            .lock()
+            .expect("transcode encoders mutex poisoned")
+            .get(options.encoder_id.trim())
+            .cloned()
+            .ok_or_else(|| {
+                anyhow!(
+                    "no transcode encoder registered for '{}'",
+                    options.encoder_id
+                )
+            })?;
+        if options.sample_rate == 0 || options.channels == 0 {
+            return Err(anyhow!(
+                "transcode output needs a non-zero sample rate and channel count"
+            ));
+        }
+        std::fs::create_dir_all(&options.output_dir).with_context(|| {
+            format!(
+                "failed to create transcode output dir: {}",
+                options.output_dir.display()
+            )
+        })?;
+
+        let outputs = allocate_output_paths(&options.output_dir, &tracks, encoder.extension())?;
+        let job_id = self.shared.next_job_id.fetch_add(1, Ordering::Relaxed);
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.shared
+            .jobs
+            .lock()
+            .expect("transcode jobs mutex poisoned")
+            .insert(job_id, Arc::clone(&cancel));
+        self.shared.emit(TranscodeEvent::JobStarted {
+            job_id,
+            total_tracks: tracks.len(),
+        });
+        stellatune_runtime::spawn(run_job(
+            Arc::clone(&self.shared),
+            job_id,
+            tracks.into_iter().zip(outputs).collect(),
+            Arc::new(options),
+            encoder,
+            cancel,
+        ));
+        Ok(job_id)
+    }
+
+    /// Requests cancellation of a running job. Returns `false` for unknown or finished
+    /// jobs. Tracks already rendering stop at the next block and their partial
+    /// outputs are deleted.
+    pub fn cancel(&self, job_id: u64) -> bool {
+        let jobs = self
+            .shared
+            .jobs
+            .lock()
+            .expect("transcode jobs mutex poisoned");
+        let Some(cancel) = jobs.get(&job_id) else {
+            return false;
+        };
+        cancel.store(true, Ordering::Release);
+        true
+    }
+}
+
+async fn run_job(
+    shared: Arc<TranscodeShared>,
+    job_id: u64,
+    items: Vec<(TrackLite, PathBuf)>,
+    options: Arc<TranscodeOptions>,
+    encoder: Arc<dyn TranscodeEncoder>,
+    cancel: Arc<AtomicBool>,
+) {
+    let mut tasks = JoinSet::new();
+    for (track, output_path) in items {
+        tasks.spawn(run_track(
+            Arc::clone(&shared),
+            job_id,
+            track,
+            output_path,
+            Arc::clone(&options),
+            Arc::clone(&encoder),
+            Arc::clone(&cancel),
+        ));
+    }
+
+    let (mut completed, mut failed, mut cancelled) = (0, 0, 0);
+    while let Some(result) = tasks.join_next().await {
+        match result {
+            Ok(TrackResult::Completed) => completed += 1,
+            Ok(TrackResult::Cancelled) => cancelled += 1,
+            Ok(TrackResult::Failed) | Err(_) => failed += 1,
+        }
+    }
+    shared
+        .jobs
+        .lock()
+        .expect("transcode jobs mutex poisoned")
+        .remove(&job_id);
+    shared.emit(TranscodeEvent::JobFinished {
+        job_id,
+        completed,
+        failed,
+        cancelled,
+    });
+}
+
+async fn run_track(
+    shared: Arc<TranscodeShared>,
+    job_id: u64,
+    track: TrackLite,
+    output_path: PathBuf,
+    options: Arc<TranscodeOptions>,
+    encoder: Arc<dyn TranscodeEncoder>,
+    cancel: Arc<AtomicBool>,
+) -> TrackResult {
+    let permit = Arc::clone(&shared.permits).acquire_owned().await;
+    if permit.is_err() || cancel.load(Ordering::Acquire) {
+        // Drop the empty file that reserved this output.
+        let _ = std::fs::remove_file(&output_path);
+        return TrackResult::Cancelled;
+    }
+
+    let track_id = track.id;
+    let output = output_path.to_string_lossy().into_owned();
+    shared.emit(TranscodeEvent::TrackStarted {
+        job_id,
+        track_id,
+        output_path: output.clone(),
+    });
+    let render_shared = Arc::clone(&shared);
+    let rendered = tokio::task::spawn_blocking(move || {
+        let mut next_progress_ms = 0;
+        render_track(
+            &track,
+            &output_path,
+            &options,
+            encoder.as_ref(),
+            &cancel,
+            |progress| {
+                if progress.position_ms < next_progress_ms {
+                    return;
+                }
+                next_progress_ms = progress.position_ms + PROGRESS_INTERVAL_MS;
+                render_shared.emit(TranscodeEvent::TrackProgress {
+                    job_id,
+                    track_id,
+                    position_ms: progress.position_ms,
+                    duration_ms: track.duration_ms,
+                });
+            },
+        )
+    })
+    .await
+    .unwrap_or_else(|error| Err(format!("transcode worker panicked: {error}")));
+
+    match rendered {
+        Ok(OfflineRenderOutcome::Completed { .. }) => {
+            shared.emit(TranscodeEvent::TrackCompleted {
+                job_id,
+                track_id,
+                output_path: output,
+            });
+            TrackResult::Completed
+        },
+        Ok(OfflineRenderOutcome::Cancelled { .. }) => TrackResult::Cancelled,
+        Err(error) => {
+            tracing::warn!(job_id, track_id, %error, "transcode track failed");
+            shared.emit(TranscodeEvent::TrackFailed {
+                job_id,
+                track_id,
+                error,
+            });
+            TrackResult::Failed
+        },
+    }
+}
+
+/// Picks a free output path per track and reserves it with an empty file created via
+/// `create_new`, which fails atomically if anything else already took the name.
+fn allocate_output_paths(
+    dir: &Path,
+    tracks: &[TrackLite],
+    extension: &str,
+) -> Result<Vec<PathBuf>> {
+    let mut reserved = Vec::with_capacity(tracks.len());
+    for track in tracks {
+        match reserve_output_path(dir, track, extension) {
+            Ok(path) => reserved.push(path),
+            Err(error) => {
+                for path in &reserved {
+                    let _ = std::fs::remove_file(path);
+                }
+                return Err(error);
+            },
+        }
+    }
+    Ok(reserved)
+}
+
+fn reserve_output_path(dir: &Path, track: &TrackLite, extension: &str) -> Result<PathBuf> {
+    let stem = Path::new(&track.path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().trim().to_string())
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or_else(|| format!("track-{}", track.id));
+    let mut candidate = dir.join(format!("{stem}.{extension}"));
+    let mut suffix = 1;
+    loop {
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+        {
+            Ok(_) => return Ok(candidate),
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => {
+                suffix += 1;
+                candidate = dir.join(format!("{stem} ({suffix}).{extension}"));
+            },
+            Err(error) => {
+                return Err(error).with_context(|| {
+                    format!(
+                        "failed to reserve transcode output: {}",
+                        candidate.display()
+                    )
+                });
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use stellatune_audio::config::engine::ResampleQuality;
+    use stellatune_audio_builtin_adapters::file_sink::{
+        FileSinkFormat, FileSinkRoute, FileSinkStage, WavSampleFormat,
+    };
+    use stellatune_audio_core::pipeline::context::{AudioBlock, PipelineContext, StreamSpec};
+    use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+    use stellatune_library::TrackLite;
+
+    use super::{TranscodeEvent, TranscodeOptions, TranscodeService, allocate_output_paths};
+
+    fn track(id: i64, path: &Path) -> TrackLite {
+        TrackLite {
+            id,
+            path: path.to_string_lossy().into_owned(),
+            title: Some(format!("Track {id}")),
+            artist: None,
+            album: None,
+            duration_ms: Some(500),
+        }
+    }
+
+    fn options(encoder_id: &str, output_dir: &Path) -> TranscodeOptions {
+        TranscodeOptions {
+            encoder_id: encoder_id.to_string(),
+            output_dir: output_dir.to_path_buf(),
+            sample_rate: 22_050,
+            channels: 1,
+            bits_per_sample: 16,
+            resample_quality: ResampleQuality::Fast,
+            dsp_chain: Vec::new(),
+            copy_tags: true,
+        }
+    }
+
+    fn write_source_wav(path: &Path) {
+        let route =
+            FileSinkRoute::new(path, FileSinkFormat::Wav(WavSampleFormat::Pcm16), 44_100, 2)
+                .expect("route");
+        let mut sink = FileSinkStage::new(route);
+        let mut ctx = PipelineContext::default();
+        sink.prepare(
+            StreamSpec {
+                sample_rate: 44_100,
+                channels: 2,
+            },
+            &mut ctx,
+        )
+        .expect("prepare");
+        let samples = (0..22_050)
+            .flat_map(|frame| {
+                let value = (frame as f32 * 440.0 / 44_100.0 * std::f32::consts::TAU).sin() * 0.5;
+                [value, value]
+            })
+            .collect();
+        sink.write(
+            &AudioBlock {
+                channels: 2,
+                samples,
+            },
+            &mut ctx,
+        );
+        sink.flush(&mut ctx).expect("flush");
+        sink.stop(&mut ctx);
+    }
+
+    #[test]
+    fn submit_rejects_unregistered_encoder() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let service = TranscodeService::new(2);
+        assert_eq!(service.encoder_ids(), vec!["flac", "wav"]);
+        let error = service
+            .submit(Vec::new(), options("opus", dir.path()))
+            .expect_err("opus has no built-in encoder");
+        assert!(error.to_string().contains("'opus'"), "{error}");
+    }
+
+    #[test]
+    fn output_paths_never_collide_with_each_other_or_existing_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("song.flac"), b"").expect("existing output");
+        let tracks = [
+            track(1, Path::new("/music/a/song.mp3")),
+            track(2, Path::new("/music/b/song.m4a")),
+            track(3, Path::new("/music/other.wav")),
+        ];
+
+        let paths = allocate_output_paths(dir.path(), &tracks, "flac").expect("allocate");
+
+        assert_eq!(
+            paths,
+            vec![
+                dir.path().join("song (2).flac"),
+                dir.path().join("song (3).flac"),
+                dir.path().join("other.flac"),
+            ]
+        );
+    }
+
+    #[test]
+    fn job_renders_wav_to_flac_and_reports_events() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("source.wav");
+        write_source_wav(&source);
+        let service = TranscodeService::new(1);
+        let mut events = service.subscribe_events();
+        let output_dir = dir.path().join("out");
+
+        let job_id = service
+            .submit(
+                vec![track(7, &source), track(8, &dir.path().join("missing.wav"))],
+                options("flac", &output_dir),
+            )
+            .expect("submit");
+
+        let mut seen = Vec::new();
+        stellatune_runtime::block_on(async {
+            loop {
+                let event = tokio::time::timeout(Duration::from_secs(30), events.recv())
+                    .await
+                    .expect("transcode event timeout")
+                    .expect("transcode event");
+                let finished = matches!(event, TranscodeEvent::JobFinished { .. });
+                seen.push(event);
+                if finished {
+                    break;
+                }
+            }
+        });
+
+        assert_eq!(
+            seen.first(),
+            Some(&TranscodeEvent::JobStarted {
+                job_id,
+                total_tracks: 2
+            })
+        );
+        assert_eq!(
+            seen.last(),
+            Some(&TranscodeEvent::JobFinished {
+                job_id,
+                completed: 1,
+                failed: 1,
+                cancelled: 0,
+            })
+        );
+        let output = output_dir.join("source.flac");
+        assert!(seen.contains(&TranscodeEvent::TrackCompleted {
+            job_id,
+            track_id: 7,
+            output_path: output.to_string_lossy().into_owned(),
+        }));
+        assert!(
+            seen.iter()
+                .any(|event| matches!(event, TranscodeEvent::TrackFailed { track_id: 8, .. }))
+        );
+        let bytes = std::fs::read(&output).expect("flac output");
+        assert_eq!(&bytes[..4], b"fLaC");
+        assert!(!output_dir.join("missing.flac").exists());
+        assert!(!service.cancel(job_id));
+    }
+}
@@ -0,0 +1,85 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use stellatune_audio_builtin_adapters::file_sink::{
+    FileSinkFormat, FileSinkMetadata, FileSinkRoute, FileSinkStage, WavSampleFormat,
+};
+use stellatune_audio_core::pipeline::stages::sink::SinkStage;
+
+/// Output parameters handed to an encoder for one track.
+pub struct TranscodeSinkRequest<'a> {
+    pub path: &'a Path,
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub bits_per_sample: u8,
+    /// Tags and cover to embed; empty when tag copying is disabled.
+    pub metadata: FileSinkMetadata,
+}
+
+/// Encodes rendered PCM into a file. Implementations own container and tag writing,
+/// which keeps lossy codecs (Opus, MP3) pluggable without touching the job service.
+///
+/// The output path already exists as an empty file reserving the name; sinks truncate it.
+pub trait TranscodeEncoder: Send + Sync {
+    /// Stable id referenced by [`super::TranscodeOptions::encoder_id`], e.g. `"flac"`.
+    fn id(&self) -> &str;
+    /// File extension without the leading dot.
+    fn extension(&self) -> &str;
+    fn create_sink(&self, request: TranscodeSinkRequest<'_>) -> Result<Box<dyn SinkStage>, String>;
+}
+
+pub struct WavTranscodeEncoder;
+
+impl TranscodeEncoder for WavTranscodeEncoder {
+    fn id(&self) -> &str {
+        "wav"
+    }
+
+    fn extension(&self) -> &str {
+        "wav"
+    }
+
+    fn create_sink(&self, request: TranscodeSinkRequest<'_>) -> Result<Box<dyn SinkStage>, String> {
+        let sample_format = match request.bits_per_sample {
+            16 => WavSampleFormat::Pcm16,
+            24 => WavSampleFormat::Pcm24,
+            32 => WavSampleFormat::Float32,
+            other => return Err(format!("wav encoder does not support {other}-bit output")),
+        };
+        file_sink(request, FileSinkFormat::Wav(sample_format))
+    }
+}
+
+pub struct FlacTranscodeEncoder;
+
+impl TranscodeEncoder for FlacTranscodeEncoder {
+    fn id(&self) -> &str {
+        "flac"
+    }
+
+    fn extension(&self) -> &str {
+        "flac"
+    }
+
+    fn create_sink(&self, request: TranscodeSinkRequest<'_>) -> Result<Box<dyn SinkStage>, String> {
+        let bits_per_sample = request.bits_per_sample;
+        file_sink(request, FileSinkFormat::Flac { bits_per_sample })
+    }
+}
+
+fn file_sink(
+    request: TranscodeSinkRequest<'_>,
+    format: FileSinkFormat,
+) -> Result<Box<dyn SinkStage>, String> {
+    let route = FileSinkRoute::new(request.path, format, request.sample_rate, request.channels)?;
+    Ok(Box::new(
+        FileSinkStage::new(route).with_metadata(request.metadata),
+    ))
+}
+
+pub(super) fn builtin_encoders() -> Vec<Arc<dyn TranscodeEncoder>> {
+    vec![
+        Arc::new(WavTranscodeEncoder),
+        Arc::new(FlacTranscodeEncoder),
+    ]
+}
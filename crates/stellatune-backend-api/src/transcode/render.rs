@@ -0,0 +1,136 @@
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+use stellatune_audio::config::engine::LfeMode;
+use stellatune_audio::pipeline::assembly::{
+    AssembledDecodePipeline, AssembledPipeline, BuiltinTransformSlots, MixerPlan, PipelineMutation,
+    ResamplerPlan, StaticSinkPlan, TransformChain,
+};
+use stellatune_audio::pipeline::graph::TransformGraph;
+use stellatune_audio::pipeline::offline::{
+    OfflineRenderOutcome, OfflineRenderProgress, OfflineRenderer,
+};
+use stellatune_audio_builtin_adapters::file_sink::{FileSinkCover, FileSinkMetadata};
+use stellatune_audio_core::pipeline::context::InputRef;
+use stellatune_audio_plugin_adapters::pipeline::plan_replace_managed_transform_chain;
+use stellatune_audio_plugin_adapters::stages::{
+    build_plugin_source, build_plugin_transform_stage_set_from_graph,
+};
+use stellatune_library::{EmbeddedTags, TrackLite, read_embedded_tags};
+
+use super::TranscodeOptions;
+use super::encoder::{TranscodeEncoder, TranscodeSinkRequest};
+use crate::runtime::HybridDecoderStage;
+
+/// Renders one library track into `output_path`.
+///
+/// The partial output is removed when rendering fails or is cancelled.
+pub(super) fn render_track(
+    track: &TrackLite,
+    output_path: &Path,
+    options: &TranscodeOptions,
+    encoder: &dyn TranscodeEncoder,
+    cancel: &AtomicBool,
+    on_progress: impl FnMut(OfflineRenderProgress),
+) -> Result<OfflineRenderOutcome, String> {
+    let result = build_renderer(track, output_path, options, encoder).and_then(|renderer| {
+        renderer
+            .render(
+                &InputRef::TrackToken(track.path.clone()),
+                cancel,
+                on_progress,
+            )
+            .map_err(|error| error.to_string())
+    });
+    if !matches!(result, Ok(OfflineRenderOutcome::Completed { .. })) {
+        let _ = std::fs::remove_file(output_path);
+    }
+    result
+}
+
+fn build_renderer(
+    track: &TrackLite,
+    output_path: &Path,
+    options: &TranscodeOptions,
+    encoder: &dyn TranscodeEncoder,
+) -> Result<OfflineRenderer, String> {
+    let mut graph = TransformGraph::default();
+    for mutation in plan_replace_managed_transform_chain(&[], &options.dsp_chain).mutations {
+        if let PipelineMutation::MutateTransformGraph { mutation } = mutation {
+            graph
+                .apply_mutation(mutation)
+                .map_err(|error| error.to_string())?;
+        }
+    }
+    let plugin_stages = build_plugin_transform_stage_set_from_graph(&graph)?;
+
+    let metadata = if options.copy_tags {
+        track_metadata(track)
+    } else {
+        FileSinkMetadata::default()
+    };
+    let sink = encoder.create_sink(TranscodeSinkRequest {
+        path: output_path,
+        sample_rate: options.sample_rate,
+        channels: options.channels,
+        bits_per_sample: options.bits_per_sample,
+        metadata,
+    })?;
+
+    let decode = AssembledDecodePipeline {
+        source: build_plugin_source(track.path.clone()),
+        decoder: Box::new(HybridDecoderStage::new()),
+        transforms: plugin_stages.main,
+        transform_chain: TransformChain {
+            pre_mix: plugin_stages.pre_mix,
+            post_mix: plugin_stages.post_mix,
+        },
+        mixer: Some(MixerPlan::new(options.channels, LfeMode::Mute)),
+        resampler: Some(ResamplerPlan::new(
+            options.sample_rate,
+            options.resample_quality,
+        )),
+        // Nothing drives transition or master gain outside the engine.
+        builtin_slots: BuiltinTransformSlots {
+            gapless_trim: true,
+            transition_gain: false,
+            master_gain: false,
+            crossfeed: false,
+            convolution: false,
+            dither: false,
+            time_stretch: false,
+        },
+    };
+    OfflineRenderer::new(AssembledPipeline::from_parts(
+        decode,
+        Box::new(StaticSinkPlan::new(vec![sink])),
+    ))
+    .map_err(|error| error.to_string())
+}
+
+/// Embedded tags of the source, with indexed library fields filling the gaps.
+fn track_metadata(track: &TrackLite) -> FileSinkMetadata {
+    let embedded = read_embedded_tags(Path::new(&track.path)).unwrap_or_else(|error| {
+        tracing::debug!(path = %track.path, %error, "transcode tag read failed");
+        EmbeddedTags::default()
+    });
+    let mut tags = embedded.tags.clone();
+    for (field, value) in [
+        ("TITLE", &track.title),
+        ("ARTIST", &track.artist),
+        ("ALBUM", &track.album),
+    ] {
+        if let Some(value) = value
+            && embedded.get(field).is_none()
+        {
+            tags.push((field.to_string(), value.clone()));
+        }
+    }
+    FileSinkMetadata {
+        tags,
+        cover: embedded.cover.map(|cover| FileSinkCover {
+            mime_type: cover.mime_type,
+            data: cover.data,
+        }),
+    }
+}
@@ -35,6 +35,27 @@ pub async fn dlna_http_unpublish_all() -> Result<()> {
     dlna_impl::Dlna::http_unpublish_all().await
 }
 
+/// Streams the engine output to LAN listeners through the DLNA HTTP server, next to
+/// the current output device.
+pub async fn dlna_http_stream_start(
+    format: DlnaStreamFormat,
+    client_buffer_ms: Option<u32>,
+    disconnect_slow_clients: bool,
+) -> Result<DlnaHttpStreamInfo> {
+    init_tracing();
+    dlna_impl::Dlna::http_stream_start(format, client_buffer_ms, disconnect_slow_clients).await
+}
+
+pub async fn dlna_http_stream_stop() -> Result<()> {
+    init_tracing();
+    dlna_impl::Dlna::http_stream_stop().await
+}
+
+pub async fn dlna_http_stream_clients() -> Result<Vec<DlnaHttpStreamClient>> {
+    init_tracing();
+    Ok(dlna_impl::Dlna::http_stream_clients())
+}
+
 pub async fn dlna_av_transport_set_uri(
     control_url: String,
     service_type: Option<String>,
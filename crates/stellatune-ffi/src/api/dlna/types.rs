@@ -42,3 +42,29 @@ pub struct DlnaPositionInfo {
     pub rel_time_ms: u64,
     pub track_duration_ms: Option<u64>,
 }
+
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DlnaStreamFormat {
+    Wav16,
+    Flac16,
+    Flac24,
+}
+
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DlnaHttpStreamInfo {
+    /// Live stream URL; players may send `Icy-MetaData: 1` for now-playing titles.
+    pub stream_url: String,
+    /// `.m3u` playlist pointing at `stream_url`, for players that only open playlists.
+    pub playlist_url: String,
+    pub content_type: String,
+}
+
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DlnaHttpStreamClient {
+    pub remote_addr: String,
+    pub sent_bytes: u64,
+    pub dropped_bytes: u64,
+}
@@ -8,11 +8,12 @@ use std::time::Duration;
 use stellatune_runtime::spawn;
 
 use crate::api::dlna::{
-    DlnaHttpServerInfo, DlnaPositionInfo, DlnaRenderer, DlnaSsdpDevice, DlnaTransportInfo,
+    DlnaHttpServerInfo, DlnaHttpStreamClient, DlnaHttpStreamInfo, DlnaPositionInfo, DlnaRenderer,
+    DlnaSsdpDevice, DlnaStreamFormat, DlnaTransportInfo,
 };
 use anyhow::Result;
 use axum::Router;
-use axum::extract::{Path, State};
+use axum::extract::{ConnectInfo, Path, State};
 use axum::http::Method;
 use axum::http::{HeaderMap, HeaderValue, StatusCode};
 use axum::response::IntoResponse;
@@ -21,6 +22,10 @@ use mime_guess::MimeGuess;
 use mime_guess::mime;
 use roxmltree::Document;
 use socket2::{Domain, Protocol, Socket, Type};
+use stellatune_backend_api::runtime::{
+    FileSinkFormat, HttpStreamDropPolicy, HttpStreamHub, HttpStreamRoute, WavSampleFormat,
+    runtime_start_http_stream, runtime_stop_http_stream, shared_http_stream_hub,
+};
 use tokio::net::TcpListener;
 use tokio::net::UdpSocket;
 use tokio::sync::Mutex;
@@ -36,6 +41,9 @@ const ST_AV_TRANSPORT: &str = "urn:schemas-upnp-org:service:AVTransport:";
 const ST_RENDERING_CONTROL: &str = "urn:schemas-upnp-org:service:RenderingControl:";
 const SERVICE_AV_TRANSPORT_1: &str = "urn:schemas-upnp-org:service:AVTransport:1";
 const SERVICE_RENDERING_CONTROL_1: &str = "urn:schemas-upnp-org:service:RenderingControl:1";
+const LIVE_STREAM_PATH: &str = "/stream/live";
+const LIVE_PLAYLIST_PATH: &str = "/stream/live.m3u";
+const LIVE_STREAM_NAME: &str = "StellaTune";
 
 pub(crate) struct Dlna {}
 
@@ -106,6 +114,65 @@ impl Dlna {
         Ok(())
     }
 
+    pub(crate) async fn http_stream_start(
+        format: DlnaStreamFormat,
+        client_buffer_ms: Option<u32>,
+        disconnect_slow_clients: bool,
+    ) -> Result<DlnaHttpStreamInfo> {
+        let info = ensure_http_server(None, None).await?;
+        let format = match format {
+            DlnaStreamFormat::Wav16 => FileSinkFormat::Wav(WavSampleFormat::Pcm16),
+            DlnaStreamFormat::Flac16 => FileSinkFormat::Flac {
+                bits_per_sample: 16,
+            },
+            DlnaStreamFormat::Flac24 => FileSinkFormat::Flac {
+                bits_per_sample: 24,
+            },
+        };
+        let mut route = HttpStreamRoute::new(format).map_err(anyhow::Error::msg)?;
+        if let Some(client_buffer_ms) = client_buffer_ms {
+            route = route.with_client_buffer_ms(client_buffer_ms);
+        }
+        if disconnect_slow_clients {
+            route = route.with_drop_policy(HttpStreamDropPolicy::Disconnect);
+        }
+        let content_type = route.content_type().to_string();
+        runtime_start_http_stream(route)
+            .await
+            .map_err(anyhow::Error::msg)?;
+
+        // Same reasoning as `http_publish_track`: resolve the LAN host at start time.
+        let listen_addr: SocketAddr = info.listen_addr.parse()?;
+        let base_url = format!(
+            "http://{}:{}",
+            default_advertise_host()?,
+            listen_addr.port()
+        );
+        let stream_info = DlnaHttpStreamInfo {
+            stream_url: format!("{base_url}{LIVE_STREAM_PATH}"),
+            playlist_url: format!("{base_url}{LIVE_PLAYLIST_PATH}"),
+            content_type,
+        };
+        tracing::info!("dlna live stream url={}", stream_info.stream_url);
+        Ok(stream_info)
+    }
+
+    pub(crate) async fn http_stream_stop() -> Result<()> {
+        runtime_stop_http_stream().await.map_err(anyhow::Error::msg)
+    }
+
+    pub(crate) fn http_stream_clients() -> Vec<DlnaHttpStreamClient> {
+        shared_http_stream_hub()
+            .clients()
+            .into_iter()
+            .map(|client| DlnaHttpStreamClient {
+                remote_addr: client.label,
+                sent_bytes: client.sent_bytes,
+                dropped_bytes: client.dropped_bytes,
+            })
+            .collect()
+    }
+
     pub(crate) async fn av_transport_set_uri(
         control_url: String,
         service_type: Option<String>,
@@ -680,6 +747,7 @@ fn parse_ssdp_response(bytes: &[u8]) -> Option<DlnaSsdpDevice> {
 #[derive(Clone)]
 struct HttpState {
     tracks: Arc<RwLock<HashMap<String, PathBuf>>>,
+    stream_hub: HttpStreamHub,
 }
 
 static HTTP_SERVER: OnceLock<Arc<HttpServer>> = OnceLock::new();
@@ -717,10 +785,16 @@ async fn ensure_http_server(
 
     let state = HttpState {
         tracks: Arc::new(RwLock::new(HashMap::new())),
+        stream_hub: shared_http_stream_hub(),
     };
 
     let app = Router::new()
         .route("/track/{token}", get(http_track).head(http_track))
+        .route(
+            LIVE_STREAM_PATH,
+            get(http_live_stream).head(http_live_stream),
+        )
+        .route(LIVE_PLAYLIST_PATH, get(http_live_playlist))
         .with_state(state.clone());
 
     tracing::info!(
@@ -730,6 +804,7 @@ async fn ensure_http_server(
     );
 
     spawn(async move {
+        let app = app.into_make_service_with_connect_info::<SocketAddr>();
         if let Err(e) = axum::serve(listener, app).await {
             tracing::error!("dlna http server exited: {e:#}");
         }
@@ -910,6 +985,82 @@ async fn http_track(
     (status, out_headers, axum::body::Body::from_stream(stream)).into_response()
 }
 
+async fn http_live_stream(
+    State(state): State<HttpState>,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    method: Method,
+) -> impl IntoResponse {
+    let icy_metadata = headers
+        .get("icy-metadata")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.trim() == "1");
+    let Some(mut client) = state.stream_hub.subscribe(remote.to_string(), icy_metadata) else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "live stream is not running",
+        )
+            .into_response();
+    };
+    tracing::debug!(
+        "dlna live stream client remote={} method={} icy={}",
+        remote,
+        method,
+        icy_metadata
+    );
+
+    let mut out_headers = HeaderMap::new();
+    let _ = out_headers.insert(
+        axum::http::header::CONTENT_TYPE,
+        HeaderValue::from_static(client.content_type()),
+    );
+    let _ = out_headers.insert(
+        axum::http::header::CACHE_CONTROL,
+        HeaderValue::from_static("no-cache, no-store"),
+    );
+    let _ = out_headers.insert("icy-name", HeaderValue::from_static(LIVE_STREAM_NAME));
+    if let Some(metaint) = client.icy_metaint() {
+        let _ = out_headers.insert("icy-metaint", HeaderValue::from(metaint));
+    }
+
+    if method == Method::HEAD {
+        return (StatusCode::OK, out_headers, "").into_response();
+    }
+
+    let body = futures_util::stream::poll_fn(move |cx| {
+        client
+            .poll_chunk(cx)
+            .map(|chunk| chunk.map(Ok::<_, std::convert::Infallible>))
+    });
+    (
+        StatusCode::OK,
+        out_headers,
+        axum::body::Body::from_stream(body),
+    )
+        .into_response()
+}
+
+async fn http_live_playlist(headers: HeaderMap) -> impl IntoResponse {
+    // Echo the host the client reached us on, so the playlist works behind any
+    // interface the listener could already route to.
+    let Some(host) = headers
+        .get(axum::http::header::HOST)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return (StatusCode::BAD_REQUEST, "missing host header").into_response();
+    };
+    let playlist =
+        format!("#EXTM3U\n#EXTINF:-1,{LIVE_STREAM_NAME}\nhttp://{host}{LIVE_STREAM_PATH}\n");
+    (
+        [(
+            axum::http::header::CONTENT_TYPE,
+            HeaderValue::from_static("audio/x-mpegurl"),
+        )],
+        playlist,
+    )
+        .into_response()
+}
+
 fn parse_single_range(header: &str, len: u64) -> Option<(u64, u64)> {
     // Only supports a single range of the form:
     // - bytes=start-end
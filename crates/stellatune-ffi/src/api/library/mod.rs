@@ -6,7 +6,7 @@ use stellatune_runtime as global_runtime;
 use tracing::debug;
 
 use stellatune_backend_api::library::LibraryService;
-use stellatune_library::{LibraryEvent, PlaylistLite, TrackLite};
+use stellatune_library::{LibraryEvent, PlaylistLite, PluginErrorEntry, TrackLite};
 
 static LIBRARY_SERVICE: OnceLock<Arc<LibraryService>> = OnceLock::new();
 static LIBRARY_INIT_LOCK: OnceLock<tokio::sync::Mutex<()>> = OnceLock::new();
@@ -204,3 +204,59 @@ pub async fn library_plugin_apply_state_status_json() -> Result<String> {
 pub async fn library_list_disabled_plugin_ids() -> Result<Vec<String>> {
     shared_library()?.list_disabled_plugin_ids().await
 }
+
+pub async fn library_list_plugin_errors(
+    plugin_id: Option<String>,
+    limit: u32,
+) -> Result<Vec<PluginErrorEntry>> {
+    shared_library()?.list_plugin_errors(plugin_id, limit).await
+}
+
+pub async fn library_clear_plugin_errors(plugin_id: Option<String>) -> Result<()> {
+    shared_library()?.clear_plugin_errors(plugin_id).await
+}
+
+pub async fn library_plugin_config_load_json(plugin_id: String, type_id: String) -> Result<String> {
+    shared_library()?
+        .plugin_config_load_json(plugin_id, type_id)
+        .await
+}
+
+pub fn library_plugin_config_validate_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+) -> Result<String> {
+    shared_library()?.plugin_config_validate_json(plugin_id, type_id, config_json)
+}
+
+pub async fn library_plugin_config_save_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+) -> Result<String> {
+    shared_library()?
+        .plugin_config_save_json(plugin_id, type_id, config_json)
+        .await
+}
+
+pub fn library_plugin_error_events(sink: StreamSink<PluginErrorEntry>) -> Result<()> {
+    let mut rx = shared_library()?.subscribe_plugin_errors();
+    global_runtime::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(entry) => {
+                    if sink.add(entry).is_err() {
+                        debug!("library_plugin_error_events stream sink closed");
+                        break;
+                    }
+                },
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug!(skipped, "library_plugin_error_events lagged");
+                },
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::path::Path;
 use std::sync::{Arc, Mutex, OnceLock};
 
 use crate::frb_generated::StreamSink;
@@ -10,12 +11,20 @@ use tracing::{debug, warn};
 use crate::api::library::shared_library_if_initialized;
 
 pub(crate) mod types;
+use stellatune_audio::config::convolution::{ImpulseLayout as V2ImpulseLayout, ImpulseResponse};
+use stellatune_audio::config::crossfeed::{
+    CROSSFEED_STAGE_KEY, CrossfeedConfig, CrossfeedPreset as V2CrossfeedPreset,
+};
+use stellatune_audio::config::dither::NoiseShaping as V2NoiseShaping;
 use stellatune_audio::config::engine::{
     Event as V2Event, LfeMode as V2LfeMode, PlayerState as V2PlayerState,
     ResampleQuality as V2ResampleQuality,
 };
 use stellatune_audio::engine::EngineHandle as AudioEngineHandle;
-use stellatune_audio::pipeline::assembly::{BuiltinTransformSlot, PipelineMutation};
+use stellatune_audio::pipeline::assembly::{
+    BuiltinTransformSlot, MixGainMatrix, MixMode as V2MixMode, MixPreset as V2MixPreset,
+    PipelineMutation,
+};
 use stellatune_audio_plugin_adapters::pipeline::{
     PluginPipelineOrchestrator, PluginTransformSegment, PluginTransformStageSpec,
 };
@@ -29,15 +38,27 @@ use stellatune_backend_api::runtime::{
     OutputBackend as RuntimeOutputBackend,
     decoder_supported_extensions_hybrid as runtime_decoder_supported_extensions,
     probe_track_decode_info_hybrid, runtime_clear_output_sink_route, runtime_list_output_devices,
-    runtime_set_output_device, runtime_set_output_options, runtime_set_output_sink_route,
-    shared_plugin_runtime, shared_runtime_engine,
+    runtime_set_output_convolution, runtime_set_output_device, runtime_set_output_dither,
+    runtime_set_output_mix_mode, runtime_set_output_options, runtime_set_output_sink_route,
+    runtime_set_playback_rate, shared_plugin_runtime, shared_runtime_engine,
+    source_auth_status_json as backend_source_auth_status_json,
+    source_begin_login_json as backend_source_begin_login_json,
+    source_logout as backend_source_logout,
+    source_poll_login_json as backend_source_poll_login_json,
+    source_refresh_auth_json as backend_source_refresh_auth_json, subscribe_dev_plugin_events,
+    subscribe_sidecar_state_events, unwatch_dev_plugin as backend_unwatch_dev_plugin,
+    watch_dev_plugin as backend_watch_dev_plugin, with_source_catalog,
+};
+use stellatune_backend_api::{
+    LyricsDoc, LyricsEvent, LyricsProviderPolicy, LyricsQuery, LyricsSearchCandidate,
+    LyricsTimingEdit,
 };
-use stellatune_backend_api::{LyricsDoc, LyricsEvent, LyricsQuery, LyricsSearchCandidate};
 use types::{
-    AudioBackend, AudioDevice, DspChainItem, DspTypeDescriptor, Event, LfeMode,
-    LyricsProviderTypeDescriptor, OutputSinkRoute, OutputSinkTypeDescriptor, PlayerState,
-    PluginDescriptor, PluginRuntimeEvent, ResampleQuality, SourceCatalogTypeDescriptor,
-    TrackDecodeInfo, TrackRef,
+    AudioBackend, AudioDevice, CrossfeedPreset, CrossfeedSettings, DitherSettings, DspChainItem,
+    DspTypeDescriptor, Event, ImpulseLayout, LfeMode, LyricsProviderTypeDescriptor, MixMode,
+    MixPreset, NoiseShaping, OutputSinkRoute, OutputSinkTypeDescriptor, PlayerState,
+    PluginDescriptor, PluginRuntimeEvent, PluginRuntimeKind, ResampleQuality,
+    SourceCatalogTypeDescriptor, TrackDecodeInfo, TrackRef,
 };
 
 struct PlayerContext {
@@ -147,6 +168,84 @@ pub async fn set_lfe_mode(mode: LfeMode) -> Result<()> {
         .map_err(anyhow::Error::msg)
 }
 
+pub async fn set_output_mix_mode(mode: MixMode) -> Result<()> {
+    runtime_set_output_mix_mode(map_mix_mode(mode)?)
+        .await
+        .map_err(anyhow::Error::msg)
+}
+
+pub async fn set_crossfeed(settings: CrossfeedSettings) -> Result<()> {
+    let handle = engine();
+    handle
+        .apply_pipeline_mutation(PipelineMutation::SetBuiltinTransformSlot {
+            slot: BuiltinTransformSlot::Crossfeed,
+            enabled: settings.enabled,
+        })
+        .await
+        .map_err(anyhow::Error::msg)?;
+    if !settings.enabled {
+        return Ok(());
+    }
+    handle
+        .apply_stage_control(
+            CROSSFEED_STAGE_KEY,
+            CrossfeedConfig::new(settings.cutoff_hz, settings.feed_db),
+        )
+        .await
+        .map_err(anyhow::Error::msg)
+}
+
+pub fn crossfeed_preset_settings(preset: CrossfeedPreset) -> CrossfeedSettings {
+    let config = match preset {
+        CrossfeedPreset::Default => V2CrossfeedPreset::Default,
+        CrossfeedPreset::ChuMoy => V2CrossfeedPreset::ChuMoy,
+        CrossfeedPreset::JanMeier => V2CrossfeedPreset::JanMeier,
+    }
+    .config();
+    CrossfeedSettings {
+        enabled: true,
+        cutoff_hz: config.cutoff_hz,
+        feed_db: config.feed_db,
+    }
+}
+
+/// Loads a WAV impulse response into the output convolution stage; `None` removes it.
+pub async fn set_convolution_filter(
+    wav_path: Option<String>,
+    layout: ImpulseLayout,
+    crossfade_ms: u32,
+) -> Result<()> {
+    let layout = match layout {
+        ImpulseLayout::PerChannel => V2ImpulseLayout::PerChannel,
+        ImpulseLayout::TrueStereo => V2ImpulseLayout::TrueStereo,
+    };
+    let impulse = wav_path
+        .map(|path| ImpulseResponse::from_wav_file(path, layout).map(Arc::new))
+        .transpose()?;
+    runtime_set_output_convolution(impulse, crossfade_ms)
+        .await
+        .map_err(anyhow::Error::msg)
+}
+
+pub async fn set_output_dither(settings: DitherSettings) -> Result<()> {
+    let noise_shaping = match settings.noise_shaping {
+        NoiseShaping::Off => V2NoiseShaping::Off,
+        NoiseShaping::FirstOrder => V2NoiseShaping::FirstOrder,
+        NoiseShaping::Lipshitz => V2NoiseShaping::Lipshitz,
+        NoiseShaping::Wannamaker => V2NoiseShaping::Wannamaker,
+    };
+    runtime_set_output_dither(settings.tpdf, noise_shaping)
+        .await
+        .map_err(anyhow::Error::msg)
+}
+
+/// Sets playback speed (0.5 to 3.0) and pitch shift (-12 to 12 semitones) independently.
+pub async fn set_playback_rate(speed: f32, pitch_semitones: f32) -> Result<()> {
+    runtime_set_playback_rate(speed, pitch_semitones)
+        .await
+        .map_err(anyhow::Error::msg)
+}
+
 pub async fn stop() -> Result<()> {
     let result = engine().stop().await.map_err(anyhow::Error::msg);
     if result.is_ok() {
@@ -194,11 +293,64 @@ pub fn events(sink: StreamSink<Event>) -> Result<()> {
     Ok(())
 }
 
+/// Host-side plugin runtime events: dev plugin reloads and sidecar state changes.
 pub fn plugin_runtime_events_global(sink: StreamSink<PluginRuntimeEvent>) -> Result<()> {
-    let _ = sink;
-    Err(anyhow!(
-        "plugin runtime host-event channel has been removed"
-    ))
+    let mut dev_rx = subscribe_dev_plugin_events();
+    let mut sidecar_rx = subscribe_sidecar_state_events();
+    global_runtime::spawn(async move {
+        loop {
+            let mapped = tokio::select! {
+                event = dev_rx.recv() => match event {
+                    Ok(event) => PluginRuntimeEvent::from_payload(
+                        event.plugin_id.clone().unwrap_or_default(),
+                        PluginRuntimeKind::DevReload,
+                        &event,
+                    ),
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        debug!(skipped, "plugin_runtime_events_global dev events lagged");
+                        continue;
+                    },
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                },
+                event = sidecar_rx.recv() => match event {
+                    Ok(event) => PluginRuntimeEvent::from_payload(
+                        event.plugin_id.clone(),
+                        PluginRuntimeKind::SidecarState,
+                        &event,
+                    ),
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        debug!(skipped, "plugin_runtime_events_global sidecar events lagged");
+                        continue;
+                    },
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                },
+            };
+            let mapped = match mapped {
+                Ok(mapped) => mapped,
+                Err(error) => {
+                    warn!(%error, "failed to serialize plugin runtime event");
+                    continue;
+                },
+            };
+            if sink.add(mapped).is_err() {
+                debug!("plugin_runtime_events_global stream sink closed");
+                break;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Loads the unpacked plugin at `root_dir` and reloads it on every rebuild. Outcomes
+/// arrive on [`plugin_runtime_events_global`] as `dev_reload` events.
+pub fn plugins_dev_watch(root_dir: String) -> Result<()> {
+    backend_watch_dev_plugin(Path::new(&root_dir))
+}
+
+/// Stops watching `root_dir` and unloads its dev plugin.
+pub fn plugins_dev_unwatch(root_dir: String) -> Result<()> {
+    backend_unwatch_dev_plugin(Path::new(&root_dir))
 }
 
 pub async fn lyrics_prepare(query: LyricsQuery) -> Result<()> {
@@ -233,6 +385,33 @@ pub fn lyrics_set_position_ms(position_ms: u64) {
     lyrics().set_position_ms(position_ms);
 }
 
+pub async fn lyrics_set_provider_policy_json(policy_json: String) -> Result<()> {
+    let policy = serde_json::from_str::<LyricsProviderPolicy>(&policy_json)
+        .map_err(|e| anyhow!("invalid lyrics provider policy_json: {e}"))?;
+    lyrics().set_provider_policy(policy).await
+}
+
+pub async fn lyrics_list_providers_json() -> Result<String> {
+    let providers = lyrics().list_providers().await?;
+    serde_json::to_string(&providers).context("serialize lyrics providers")
+}
+
+pub async fn lyrics_timing_json(track_key: String) -> Result<String> {
+    let timing = lyrics().timing(track_key).await?;
+    serde_json::to_string(&timing).context("serialize lyrics timing")
+}
+
+pub async fn lyrics_edit_timing_json(track_key: String, edit_json: String) -> Result<String> {
+    let edit = serde_json::from_str::<LyricsTimingEdit>(&edit_json)
+        .map_err(|e| anyhow!("invalid lyrics timing edit_json: {e}"))?;
+    let timing = lyrics().edit_timing(track_key, edit).await?;
+    serde_json::to_string(&timing).context("serialize lyrics timing")
+}
+
+pub async fn lyrics_export_lrc(track_key: String, overwrite: bool) -> Result<String> {
+    lyrics().export_lrc(track_key, overwrite).await
+}
+
 pub fn lyrics_events(sink: StreamSink<LyricsEvent>) -> Result<()> {
     let mut rx = lyrics().subscribe_events();
     global_runtime::spawn(async move {
@@ -267,6 +446,12 @@ pub async fn plugins_list() -> Vec<PluginDescriptor> {
         .collect()
 }
 
+/// Sets the app version checked against plugin manifests' `requires.host`. Call before
+/// the first plugin state sync.
+pub fn plugins_set_host_version(version: String) -> Result<()> {
+    shared_plugin_runtime().set_host_version(&version)
+}
+
 pub fn plugin_publish_event_json(plugin_id: Option<String>, event_json: String) -> Result<()> {
     let _ = (plugin_id, event_json);
     Err(anyhow!(
@@ -356,34 +541,103 @@ pub async fn output_sink_list_types() -> Vec<OutputSinkTypeDescriptor> {
     out
 }
 
+pub async fn source_capabilities_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+) -> Result<String> {
+    with_source_catalog(
+        &plugin_id,
+        &type_id,
+        &config_json,
+        "capabilities",
+        |source| source.capabilities_json(),
+    )
+}
+
+/// `page_json` is `{"cursor": string | null, "limit": u32}`; `parent_id: None` lists the
+/// catalog root.
+pub async fn source_browse_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+    parent_id: Option<String>,
+    page_json: String,
+) -> Result<String> {
+    with_source_catalog(&plugin_id, &type_id, &config_json, "browse", |source| {
+        source.browse_json(parent_id.as_deref(), &page_json)
+    })
+}
+
+/// `request_json` is `{"query": string, "kinds": [kind], "page": {"cursor", "limit"}}`.
+pub async fn source_search_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+    request_json: String,
+) -> Result<String> {
+    with_source_catalog(&plugin_id, &type_id, &config_json, "search", |source| {
+        source.search_json(&request_json)
+    })
+}
+
 pub async fn source_list_items_json(
     plugin_id: String,
     type_id: String,
     config_json: String,
     request_json: String,
 ) -> Result<String> {
-    let config = serde_json::from_str::<serde_json::Value>(&config_json)
-        .map_err(|e| anyhow!("invalid source config_json: {e}"))?;
     let request = serde_json::from_str::<serde_json::Value>(&request_json)
         .map_err(|e| anyhow!("invalid source request_json: {e}"))?;
-    let mut source = shared_plugin_runtime()
-        .create_source_plugin(&plugin_id, &type_id)
-        .map_err(|e| anyhow!("create source plugin failed: {e}"))?;
-    source
-        .apply_config_update_json(
-            &serde_json::to_string(&config)
-                .map_err(|e| anyhow!("serialize source config_json: {e}"))?,
-        )
-        .map_err(|e| anyhow!("source apply_config_update_json failed: {e}"))?;
-    let payload = source
-        .list_items_json(
-            &serde_json::to_string(&request)
-                .map_err(|e| anyhow!("serialize source request_json: {e}"))?,
-        )
-        .map_err(|e| anyhow!("source list_items failed: {e}"))?;
+    let request = serde_json::to_string(&request)
+        .map_err(|e| anyhow!("serialize source request_json: {e}"))?;
+    let payload =
+        with_source_catalog(&plugin_id, &type_id, &config_json, "list_items", |source| {
+            source.list_items_json(&request)
+        })?;
     normalize_json_string_payload("source list response", payload)
 }
 
+pub async fn source_auth_status_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+) -> Result<String> {
+    backend_source_auth_status_json(&plugin_id, &type_id, &config_json)
+}
+
+/// Starts an interactive login. The returned challenge carries the QR payload, URL or
+/// device code to show, plus the `session_id` to pass to `source_poll_login_json`.
+pub async fn source_begin_login_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+    method: Option<String>,
+) -> Result<String> {
+    backend_source_begin_login_json(&plugin_id, &type_id, &config_json, method.as_deref())
+}
+
+pub async fn source_poll_login_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+    session_id: String,
+) -> Result<String> {
+    backend_source_poll_login_json(&plugin_id, &type_id, &config_json, &session_id)
+}
+
+pub async fn source_refresh_auth_json(
+    plugin_id: String,
+    type_id: String,
+    config_json: String,
+) -> Result<String> {
+    backend_source_refresh_auth_json(&plugin_id, &type_id, &config_json)
+}
+
+pub async fn source_logout(plugin_id: String, type_id: String, config_json: String) -> Result<()> {
+    backend_source_logout(&plugin_id, &type_id, &config_json)
+}
+
 pub async fn lyrics_provider_search_json(
     plugin_id: String,
     type_id: String,
@@ -865,6 +1119,27 @@ fn map_lfe_mode(mode: LfeMode) -> V2LfeMode {
     }
 }
 
+fn map_mix_mode(mode: MixMode) -> Result<V2MixMode> {
+    Ok(match mode {
+        MixMode::Auto => V2MixMode::Auto,
+        MixMode::Preset { preset } => V2MixMode::Preset(map_mix_preset(preset)),
+        MixMode::Custom { rows } => {
+            V2MixMode::Custom(MixGainMatrix::new(rows).map_err(anyhow::Error::from)?)
+        },
+    })
+}
+
+fn map_mix_preset(preset: MixPreset) -> V2MixPreset {
+    match preset {
+        MixPreset::Balance { balance } => V2MixPreset::Balance(balance),
+        MixPreset::SwapLeftRight => V2MixPreset::SwapLeftRight,
+        MixPreset::MonoSum => V2MixPreset::MonoSum,
+        MixPreset::StereoWidth { width } => V2MixPreset::StereoWidth(width),
+        MixPreset::MatrixSurroundUpmix => V2MixPreset::MatrixSurroundUpmix,
+        MixPreset::AmbienceUpmix => V2MixPreset::AmbienceUpmix,
+    }
+}
+
 fn map_resample_quality(quality: ResampleQuality) -> V2ResampleQuality {
     match quality {
         ResampleQuality::Fast => V2ResampleQuality::Fast,
@@ -17,6 +17,83 @@ pub enum LfeMode {
     MixToFront,
 }
 
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ImpulseLayout {
+    /// One filter per output channel, or a single filter for all channels.
+    #[default]
+    PerChannel,
+    /// Four-channel stereo IR ordered L->L, L->R, R->L, R->R.
+    TrueStereo,
+}
+
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum NoiseShaping {
+    #[default]
+    Off,
+    FirstOrder,
+    Lipshitz,
+    Wannamaker,
+}
+
+/// Only takes effect for 16/24-bit output; float sinks are left untouched.
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DitherSettings {
+    pub tpdf: bool,
+    pub noise_shaping: NoiseShaping,
+}
+
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrossfeedPreset {
+    Default,
+    ChuMoy,
+    JanMeier,
+}
+
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CrossfeedSettings {
+    pub enabled: bool,
+    /// Clamped to 300..=2000 Hz.
+    pub cutoff_hz: u32,
+    /// Clamped to 1..=15 dB.
+    pub feed_db: f32,
+}
+
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MixPreset {
+    /// `-1.0` is hard left, `1.0` hard right.
+    Balance {
+        balance: f32,
+    },
+    SwapLeftRight,
+    MonoSum,
+    /// `0.0` collapses to mono, `1.0` is unchanged, up to `2.0` widens.
+    StereoWidth {
+        width: f32,
+    },
+    MatrixSurroundUpmix,
+    AmbienceUpmix,
+}
+
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub enum MixMode {
+    #[default]
+    Auto,
+    Preset {
+        preset: MixPreset,
+    },
+    /// Gain matrix indexed as `rows[output_channel][input_channel]`.
+    Custom {
+        rows: Vec<Vec<f32>>,
+    },
+}
+
 #[flutter_rust_bridge::frb(non_opaque)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum ResampleQuality {
@@ -235,6 +312,10 @@ pub enum PluginRuntimeKind {
     Control,
     ControlResult,
     ControlFinished,
+    /// Payload is a `RuntimeDevReloadEvent`.
+    DevReload,
+    /// Payload is a `SidecarStateEvent`.
+    SidecarState,
 }
 
 impl PluginRuntimeKind {
@@ -245,6 +326,8 @@ impl PluginRuntimeKind {
             Self::Control => "control",
             Self::ControlResult => "control_result",
             Self::ControlFinished => "control_finished",
+            Self::DevReload => "dev_reload",
+            Self::SidecarState => "sidecar_state",
         }
     }
 }
@@ -3682,6 +3682,1222 @@ fn wire__crate__api__player__types__track_ref_stable_key_impl(
     )
 }
 
+fn wire__crate__api__library__library_plugin_config_load_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_plugin_config_load_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::library::library_plugin_config_load_json(
+                            api_plugin_id,
+                            api_type_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_plugin_config_validate_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_plugin_config_validate_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::library::library_plugin_config_validate_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_plugin_config_save_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_plugin_config_save_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::library::library_plugin_config_save_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__plugins_set_host_version_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "plugins_set_host_version",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_version = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::player::plugins_set_host_version(api_version)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__lyrics_set_provider_policy_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lyrics_set_provider_policy_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::player::lyrics_set_provider_policy_json(api_policy_json)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__lyrics_list_providers_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lyrics_list_providers_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::lyrics_list_providers_json().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__lyrics_timing_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lyrics_timing_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::player::lyrics_timing_json(api_track_key).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__lyrics_edit_timing_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lyrics_edit_timing_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_key = <String>::sse_decode(&mut deserializer);
+            let api_edit_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::lyrics_edit_timing_json(
+                            api_track_key,
+                            api_edit_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__lyrics_export_lrc_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lyrics_export_lrc",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_key = <String>::sse_decode(&mut deserializer);
+            let api_overwrite = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::player::lyrics_export_lrc(api_track_key, api_overwrite)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__source_capabilities_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_capabilities_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::source_capabilities_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__source_browse_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_browse_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_page_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::source_browse_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                            api_parent_id,
+                            api_page_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__source_search_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_search_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            let api_request_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::source_search_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                            api_request_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__source_auth_status_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_auth_status_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::source_auth_status_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__source_begin_login_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_begin_login_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            let api_method = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::source_begin_login_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                            api_method,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__source_poll_login_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_poll_login_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::source_poll_login_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                            api_session_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__source_refresh_auth_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_refresh_auth_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::source_refresh_auth_json(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__source_logout_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_logout",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plugin_id = <String>::sse_decode(&mut deserializer);
+            let api_type_id = <String>::sse_decode(&mut deserializer);
+            let api_config_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::source_logout(
+                            api_plugin_id,
+                            api_type_id,
+                            api_config_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__plugins_dev_watch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "plugins_dev_watch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::player::plugins_dev_watch(api_root_dir)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__plugins_dev_unwatch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "plugins_dev_unwatch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::player::plugins_dev_unwatch(api_root_dir)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dlna__dlna_http_stream_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dlna_http_stream_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_format =
+                <crate::api::dlna::types::DlnaStreamFormat>::sse_decode(&mut deserializer);
+            let api_client_buffer_ms = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_disconnect_slow_clients = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::dlna::dlna_http_stream_start(
+                            api_format,
+                            api_client_buffer_ms,
+                            api_disconnect_slow_clients,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dlna__dlna_http_stream_stop_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dlna_http_stream_stop",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::dlna::dlna_http_stream_stop().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dlna__dlna_http_stream_clients_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dlna_http_stream_clients",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::dlna::dlna_http_stream_clients().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__types__mix_mode_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mix_mode_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::player::types::MixMode::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__player__set_output_mix_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_output_mix_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mode = <crate::api::player::types::MixMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::set_output_mix_mode(api_mode).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__set_crossfeed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_crossfeed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::player::types::CrossfeedSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::set_crossfeed(api_settings).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__crossfeed_preset_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "crossfeed_preset_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_preset =
+                <crate::api::player::types::CrossfeedPreset>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::player::crossfeed_preset_settings(api_preset),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__player__types__impulse_layout_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "impulse_layout_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::player::types::ImpulseLayout::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__player__set_convolution_filter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_convolution_filter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wav_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_layout =
+                <crate::api::player::types::ImpulseLayout>::sse_decode(&mut deserializer);
+            let api_crossfade_ms = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::set_convolution_filter(
+                            api_wav_path,
+                            api_layout,
+                            api_crossfade_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__types__noise_shaping_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "noise_shaping_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::player::types::NoiseShaping::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__player__set_output_dither_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_output_dither",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::player::types::DitherSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::player::set_output_dither(api_settings).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__player__set_playback_rate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_playback_rate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_speed = <f32>::sse_decode(&mut deserializer);
+            let api_pitch_semitones = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::player::set_playback_rate(api_speed, api_pitch_semitones)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 // Section: static_checks
 
 #[allow(clippy::unnecessary_literal_unwrap)]
@@ -3724,6 +4940,15 @@ const _: fn() = || {
         let _: Option<i64> = LyricLine.start_ms;
         let _: Option<i64> = LyricLine.end_ms;
         let _: String = LyricLine.text;
+        let _: Vec<stellatune_backend_api::lyrics_types::LyricWord> = LyricLine.words;
+        let _: Option<String> = LyricLine.translation;
+        let _: Option<String> = LyricLine.romanization;
+    }
+    {
+        let LyricWord = None::<stellatune_backend_api::lyrics_types::LyricWord>.unwrap();
+        let _: i64 = LyricWord.start_ms;
+        let _: Option<i64> = LyricWord.end_ms;
+        let _: String = LyricWord.text;
     }
     {
         let LyricsDoc = None::<stellatune_backend_api::lyrics_types::LyricsDoc>.unwrap();
@@ -3731,6 +4956,7 @@ const _: fn() = || {
         let _: String = LyricsDoc.source;
         let _: bool = LyricsDoc.is_synced;
         let _: Vec<stellatune_backend_api::lyrics_types::LyricLine> = LyricsDoc.lines;
+        let _: stellatune_backend_api::lyrics_types::LyricsMeta = LyricsDoc.meta;
     }
     match None::<stellatune_backend_api::lyrics_types::LyricsEvent>.unwrap() {
         stellatune_backend_api::lyrics_types::LyricsEvent::Loading { track_key } => {
@@ -3743,9 +4969,11 @@ const _: fn() = || {
         stellatune_backend_api::lyrics_types::LyricsEvent::Cursor {
             track_key,
             line_index,
+            word_index,
         } => {
             let _: String = track_key;
             let _: i64 = line_index;
+            let _: i64 = word_index;
         },
         stellatune_backend_api::lyrics_types::LyricsEvent::Empty { track_key } => {
             let _: String = track_key;
@@ -3755,6 +4983,14 @@ const _: fn() = || {
             let _: String = message;
         },
     }
+    {
+        let LyricsMeta = None::<stellatune_backend_api::lyrics_types::LyricsMeta>.unwrap();
+        let _: Option<String> = LyricsMeta.title;
+        let _: Option<String> = LyricsMeta.artist;
+        let _: Option<String> = LyricsMeta.album;
+        let _: Option<String> = LyricsMeta.author;
+        let _: i64 = LyricsMeta.offset_ms;
+    }
     {
         let LyricsQuery = None::<stellatune_backend_api::lyrics_types::LyricsQuery>.unwrap();
         let _: String = LyricsQuery.track_key;
@@ -3891,6 +5127,46 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::api::player::types::CrossfeedPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::player::types::CrossfeedPreset::Default,
+            1 => crate::api::player::types::CrossfeedPreset::ChuMoy,
+            2 => crate::api::player::types::CrossfeedPreset::JanMeier,
+            _ => unreachable!("Invalid variant for CrossfeedPreset: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::player::types::CrossfeedSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_cutoffHz = <u32>::sse_decode(deserializer);
+        let mut var_feedDb = <f32>::sse_decode(deserializer);
+        return crate::api::player::types::CrossfeedSettings {
+            enabled: var_enabled,
+            cutoff_hz: var_cutoffHz,
+            feed_db: var_feedDb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::player::types::DitherSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tpdf = <bool>::sse_decode(deserializer);
+        let mut var_noiseShaping =
+            <crate::api::player::types::NoiseShaping>::sse_decode(deserializer);
+        return crate::api::player::types::DitherSettings {
+            tpdf: var_tpdf,
+            noise_shaping: var_noiseShaping,
+        };
+    }
+}
+
 impl SseDecode for crate::api::dlna::types::DlnaHttpServerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3903,6 +5179,34 @@ impl SseDecode for crate::api::dlna::types::DlnaHttpServerInfo {
     }
 }
 
+impl SseDecode for crate::api::dlna::types::DlnaHttpStreamClient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_remoteAddr = <String>::sse_decode(deserializer);
+        let mut var_sentBytes = <u64>::sse_decode(deserializer);
+        let mut var_droppedBytes = <u64>::sse_decode(deserializer);
+        return crate::api::dlna::types::DlnaHttpStreamClient {
+            remote_addr: var_remoteAddr,
+            sent_bytes: var_sentBytes,
+            dropped_bytes: var_droppedBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dlna::types::DlnaHttpStreamInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_streamUrl = <String>::sse_decode(deserializer);
+        let mut var_playlistUrl = <String>::sse_decode(deserializer);
+        let mut var_contentType = <String>::sse_decode(deserializer);
+        return crate::api::dlna::types::DlnaHttpStreamInfo {
+            stream_url: var_streamUrl,
+            playlist_url: var_playlistUrl,
+            content_type: var_contentType,
+        };
+    }
+}
+
 impl SseDecode for crate::api::dlna::types::DlnaPositionInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3953,6 +5257,19 @@ impl SseDecode for crate::api::dlna::types::DlnaSsdpDevice {
     }
 }
 
+impl SseDecode for crate::api::dlna::types::DlnaStreamFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::dlna::types::DlnaStreamFormat::Wav16,
+            1 => crate::api::dlna::types::DlnaStreamFormat::Flac16,
+            2 => crate::api::dlna::types::DlnaStreamFormat::Flac24,
+            _ => unreachable!("Invalid variant for DlnaStreamFormat: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::dlna::types::DlnaTransportInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4083,6 +5400,18 @@ impl SseDecode for i64 {
     }
 }
 
+impl SseDecode for crate::api::player::types::ImpulseLayout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::player::types::ImpulseLayout::PerChannel,
+            1 => crate::api::player::types::ImpulseLayout::TrueStereo,
+            _ => unreachable!("Invalid variant for ImpulseLayout: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::player::types::LfeMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4174,6 +5503,20 @@ impl SseDecode for Vec<crate::api::player::types::AudioDevice> {
     }
 }
 
+impl SseDecode for Vec<crate::api::dlna::types::DlnaHttpStreamClient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dlna::types::DlnaHttpStreamClient>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::dlna::types::DlnaRenderer> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4230,6 +5573,18 @@ impl SseDecode for Vec<crate::api::player::types::DspTypeDescriptor> {
     }
 }
 
+impl SseDecode for Vec<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<f32>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<stellatune_backend_api::lyrics_types::LyricLine> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4242,6 +5597,18 @@ impl SseDecode for Vec<stellatune_backend_api::lyrics_types::LyricLine> {
     }
 }
 
+impl SseDecode for Vec<stellatune_backend_api::lyrics_types::LyricWord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<stellatune_backend_api::lyrics_types::LyricWord>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::player::types::LyricsProviderTypeDescriptor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4312,6 +5679,18 @@ impl SseDecode for Vec<crate::api::player::types::PluginDescriptor> {
     }
 }
 
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4368,10 +5747,31 @@ impl SseDecode for stellatune_backend_api::lyrics_types::LyricLine {
         let mut var_startMs = <Option<i64>>::sse_decode(deserializer);
         let mut var_endMs = <Option<i64>>::sse_decode(deserializer);
         let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_words =
+            <Vec<stellatune_backend_api::lyrics_types::LyricWord>>::sse_decode(deserializer);
+        let mut var_translation = <Option<String>>::sse_decode(deserializer);
+        let mut var_romanization = <Option<String>>::sse_decode(deserializer);
         return stellatune_backend_api::lyrics_types::LyricLine {
             start_ms: var_startMs,
             end_ms: var_endMs,
             text: var_text,
+            words: var_words,
+            translation: var_translation,
+            romanization: var_romanization,
+        };
+    }
+}
+
+impl SseDecode for stellatune_backend_api::lyrics_types::LyricWord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <i64>::sse_decode(deserializer);
+        let mut var_endMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        return stellatune_backend_api::lyrics_types::LyricWord {
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            text: var_text,
         };
     }
 }
@@ -4384,11 +5784,14 @@ impl SseDecode for stellatune_backend_api::lyrics_types::LyricsDoc {
         let mut var_isSynced = <bool>::sse_decode(deserializer);
         let mut var_lines =
             <Vec<stellatune_backend_api::lyrics_types::LyricLine>>::sse_decode(deserializer);
+        let mut var_meta =
+            <stellatune_backend_api::lyrics_types::LyricsMeta>::sse_decode(deserializer);
         return stellatune_backend_api::lyrics_types::LyricsDoc {
             track_key: var_trackKey,
             source: var_source,
             is_synced: var_isSynced,
             lines: var_lines,
+            meta: var_meta,
         };
     }
 }
@@ -4416,9 +5819,11 @@ impl SseDecode for stellatune_backend_api::lyrics_types::LyricsEvent {
             2 => {
                 let mut var_trackKey = <String>::sse_decode(deserializer);
                 let mut var_lineIndex = <i64>::sse_decode(deserializer);
+                let mut var_wordIndex = <i64>::sse_decode(deserializer);
                 return stellatune_backend_api::lyrics_types::LyricsEvent::Cursor {
                     track_key: var_trackKey,
                     line_index: var_lineIndex,
+                    word_index: var_wordIndex,
                 };
             },
             3 => {
@@ -4442,6 +5847,24 @@ impl SseDecode for stellatune_backend_api::lyrics_types::LyricsEvent {
     }
 }
 
+impl SseDecode for stellatune_backend_api::lyrics_types::LyricsMeta {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_author = <Option<String>>::sse_decode(deserializer);
+        let mut var_offsetMs = <i64>::sse_decode(deserializer);
+        return stellatune_backend_api::lyrics_types::LyricsMeta {
+            title: var_title,
+            artist: var_artist,
+            album: var_album,
+            author: var_author,
+            offset_ms: var_offsetMs,
+        };
+    }
+}
+
 impl SseDecode for crate::api::player::types::LyricsProviderTypeDescriptor {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4476,27 +5899,99 @@ impl SseDecode for stellatune_backend_api::lyrics_types::LyricsQuery {
     }
 }
 
-impl SseDecode for stellatune_backend_api::lyrics_types::LyricsSearchCandidate {
+impl SseDecode for stellatune_backend_api::lyrics_types::LyricsSearchCandidate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_candidateId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_isSynced = <bool>::sse_decode(deserializer);
+        let mut var_preview = <Option<String>>::sse_decode(deserializer);
+        let mut var_doc =
+            <stellatune_backend_api::lyrics_types::LyricsDoc>::sse_decode(deserializer);
+        return stellatune_backend_api::lyrics_types::LyricsSearchCandidate {
+            candidate_id: var_candidateId,
+            title: var_title,
+            artist: var_artist,
+            album: var_album,
+            source: var_source,
+            is_synced: var_isSynced,
+            preview: var_preview,
+            doc: var_doc,
+        };
+    }
+}
+
+impl SseDecode for crate::api::player::types::MixMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::player::types::MixMode::Auto;
+            },
+            1 => {
+                let mut var_preset =
+                    <crate::api::player::types::MixPreset>::sse_decode(deserializer);
+                return crate::api::player::types::MixMode::Preset { preset: var_preset };
+            },
+            2 => {
+                let mut var_rows = <Vec<Vec<f32>>>::sse_decode(deserializer);
+                return crate::api::player::types::MixMode::Custom { rows: var_rows };
+            },
+            _ => {
+                unimplemented!("");
+            },
+        }
+    }
+}
+
+impl SseDecode for crate::api::player::types::MixPreset {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_candidateId = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_artist = <Option<String>>::sse_decode(deserializer);
-        let mut var_album = <Option<String>>::sse_decode(deserializer);
-        let mut var_source = <String>::sse_decode(deserializer);
-        let mut var_isSynced = <bool>::sse_decode(deserializer);
-        let mut var_preview = <Option<String>>::sse_decode(deserializer);
-        let mut var_doc =
-            <stellatune_backend_api::lyrics_types::LyricsDoc>::sse_decode(deserializer);
-        return stellatune_backend_api::lyrics_types::LyricsSearchCandidate {
-            candidate_id: var_candidateId,
-            title: var_title,
-            artist: var_artist,
-            album: var_album,
-            source: var_source,
-            is_synced: var_isSynced,
-            preview: var_preview,
-            doc: var_doc,
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_balance = <f32>::sse_decode(deserializer);
+                return crate::api::player::types::MixPreset::Balance {
+                    balance: var_balance,
+                };
+            },
+            1 => {
+                return crate::api::player::types::MixPreset::SwapLeftRight;
+            },
+            2 => {
+                return crate::api::player::types::MixPreset::MonoSum;
+            },
+            3 => {
+                let mut var_width = <f32>::sse_decode(deserializer);
+                return crate::api::player::types::MixPreset::StereoWidth { width: var_width };
+            },
+            4 => {
+                return crate::api::player::types::MixPreset::MatrixSurroundUpmix;
+            },
+            5 => {
+                return crate::api::player::types::MixPreset::AmbienceUpmix;
+            },
+            _ => {
+                unimplemented!("");
+            },
+        }
+    }
+}
+
+impl SseDecode for crate::api::player::types::NoiseShaping {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::player::types::NoiseShaping::Off,
+            1 => crate::api::player::types::NoiseShaping::FirstOrder,
+            2 => crate::api::player::types::NoiseShaping::Lipshitz,
+            3 => crate::api::player::types::NoiseShaping::Wannamaker,
+            _ => unreachable!("Invalid variant for NoiseShaping: {}", inner),
         };
     }
 }
@@ -4547,6 +6042,17 @@ impl SseDecode for Option<u16> {
     }
 }
 
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -4661,6 +6167,8 @@ impl SseDecode for crate::api::player::types::PluginRuntimeKind {
             1 => crate::api::player::types::PluginRuntimeKind::Control,
             2 => crate::api::player::types::PluginRuntimeKind::ControlResult,
             3 => crate::api::player::types::PluginRuntimeKind::ControlFinished,
+            4 => crate::api::player::types::PluginRuntimeKind::DevReload,
+            5 => crate::api::player::types::PluginRuntimeKind::SidecarState,
             _ => unreachable!("Invalid variant for PluginRuntimeKind: {}", inner),
         };
     }
@@ -5145,6 +6653,126 @@ fn pde_ffi_dispatcher_primary_impl(
             rust_vec_len,
             data_len,
         ),
+        96 => wire__crate__api__library__library_plugin_config_load_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        97 => wire__crate__api__library__library_plugin_config_validate_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        98 => wire__crate__api__library__library_plugin_config_save_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => wire__crate__api__player__plugins_set_host_version_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        100 => wire__crate__api__player__lyrics_set_provider_policy_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        101 => wire__crate__api__player__lyrics_list_providers_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        102 => wire__crate__api__player__lyrics_timing_json_impl(port, ptr, rust_vec_len, data_len),
+        103 => wire__crate__api__player__lyrics_edit_timing_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        104 => wire__crate__api__player__lyrics_export_lrc_impl(port, ptr, rust_vec_len, data_len),
+        105 => wire__crate__api__player__source_capabilities_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        106 => wire__crate__api__player__source_browse_json_impl(port, ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__player__source_search_json_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__player__source_auth_status_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        109 => wire__crate__api__player__source_begin_login_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => {
+            wire__crate__api__player__source_poll_login_json_impl(port, ptr, rust_vec_len, data_len)
+        },
+        111 => wire__crate__api__player__source_refresh_auth_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        112 => wire__crate__api__player__source_logout_impl(port, ptr, rust_vec_len, data_len),
+        113 => wire__crate__api__player__plugins_dev_watch_impl(port, ptr, rust_vec_len, data_len),
+        114 => {
+            wire__crate__api__player__plugins_dev_unwatch_impl(port, ptr, rust_vec_len, data_len)
+        },
+        115 => {
+            wire__crate__api__dlna__dlna_http_stream_start_impl(port, ptr, rust_vec_len, data_len)
+        },
+        116 => {
+            wire__crate__api__dlna__dlna_http_stream_stop_impl(port, ptr, rust_vec_len, data_len)
+        },
+        117 => {
+            wire__crate__api__dlna__dlna_http_stream_clients_impl(port, ptr, rust_vec_len, data_len)
+        },
+        118 => wire__crate__api__player__types__mix_mode_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => {
+            wire__crate__api__player__set_output_mix_mode_impl(port, ptr, rust_vec_len, data_len)
+        },
+        120 => wire__crate__api__player__set_crossfeed_impl(port, ptr, rust_vec_len, data_len),
+        121 => wire__crate__api__player__crossfeed_preset_settings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        122 => wire__crate__api__player__types__impulse_layout_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        123 => {
+            wire__crate__api__player__set_convolution_filter_impl(port, ptr, rust_vec_len, data_len)
+        },
+        124 => wire__crate__api__player__types__noise_shaping_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        125 => wire__crate__api__player__set_output_dither_impl(port, ptr, rust_vec_len, data_len),
+        126 => wire__crate__api__player__set_playback_rate_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -5207,6 +6835,71 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::AudioDevice>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::player::types::CrossfeedPreset {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Default => 0.into_dart(),
+            Self::ChuMoy => 1.into_dart(),
+            Self::JanMeier => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::player::types::CrossfeedPreset
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::CrossfeedPreset>
+    for crate::api::player::types::CrossfeedPreset
+{
+    fn into_into_dart(self) -> crate::api::player::types::CrossfeedPreset {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::player::types::CrossfeedSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.cutoff_hz.into_into_dart().into_dart(),
+            self.feed_db.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::player::types::CrossfeedSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::CrossfeedSettings>
+    for crate::api::player::types::CrossfeedSettings
+{
+    fn into_into_dart(self) -> crate::api::player::types::CrossfeedSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::player::types::DitherSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tpdf.into_into_dart().into_dart(),
+            self.noise_shaping.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::player::types::DitherSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::DitherSettings>
+    for crate::api::player::types::DitherSettings
+{
+    fn into_into_dart(self) -> crate::api::player::types::DitherSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::dlna::types::DlnaHttpServerInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5228,6 +6921,50 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::dlna::types::DlnaHttpServerIn
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dlna::types::DlnaHttpStreamClient {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.remote_addr.into_into_dart().into_dart(),
+            self.sent_bytes.into_into_dart().into_dart(),
+            self.dropped_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dlna::types::DlnaHttpStreamClient
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dlna::types::DlnaHttpStreamClient>
+    for crate::api::dlna::types::DlnaHttpStreamClient
+{
+    fn into_into_dart(self) -> crate::api::dlna::types::DlnaHttpStreamClient {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dlna::types::DlnaHttpStreamInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stream_url.into_into_dart().into_dart(),
+            self.playlist_url.into_into_dart().into_dart(),
+            self.content_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dlna::types::DlnaHttpStreamInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dlna::types::DlnaHttpStreamInfo>
+    for crate::api::dlna::types::DlnaHttpStreamInfo
+{
+    fn into_into_dart(self) -> crate::api::dlna::types::DlnaHttpStreamInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::dlna::types::DlnaPositionInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5300,6 +7037,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::dlna::types::DlnaSsdpDevice>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dlna::types::DlnaStreamFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Wav16 => 0.into_dart(),
+            Self::Flac16 => 1.into_dart(),
+            Self::Flac24 => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dlna::types::DlnaStreamFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dlna::types::DlnaStreamFormat>
+    for crate::api::dlna::types::DlnaStreamFormat
+{
+    fn into_into_dart(self) -> crate::api::dlna::types::DlnaStreamFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::dlna::types::DlnaTransportInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5424,6 +7183,27 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::Event>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::player::types::ImpulseLayout {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::PerChannel => 0.into_dart(),
+            Self::TrueStereo => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::player::types::ImpulseLayout
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::ImpulseLayout>
+    for crate::api::player::types::ImpulseLayout
+{
+    fn into_into_dart(self) -> crate::api::player::types::ImpulseLayout {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::player::types::LfeMode {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -5501,7 +7281,32 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<stellatune_library::LibraryEve
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<stellatune_backend_api::lyrics_types::LyricLine> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<stellatune_backend_api::lyrics_types::LyricLine> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.start_ms.into_into_dart().into_dart(),
+            self.0.end_ms.into_into_dart().into_dart(),
+            self.0.text.into_into_dart().into_dart(),
+            self.0.words.into_into_dart().into_dart(),
+            self.0.translation.into_into_dart().into_dart(),
+            self.0.romanization.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<stellatune_backend_api::lyrics_types::LyricLine>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<stellatune_backend_api::lyrics_types::LyricLine>>
+    for stellatune_backend_api::lyrics_types::LyricLine
+{
+    fn into_into_dart(self) -> FrbWrapper<stellatune_backend_api::lyrics_types::LyricLine> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<stellatune_backend_api::lyrics_types::LyricWord> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.0.start_ms.into_into_dart().into_dart(),
@@ -5512,13 +7317,13 @@ impl flutter_rust_bridge::IntoDart for FrbWrapper<stellatune_backend_api::lyrics
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<stellatune_backend_api::lyrics_types::LyricLine>
+    for FrbWrapper<stellatune_backend_api::lyrics_types::LyricWord>
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<stellatune_backend_api::lyrics_types::LyricLine>>
-    for stellatune_backend_api::lyrics_types::LyricLine
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<stellatune_backend_api::lyrics_types::LyricWord>>
+    for stellatune_backend_api::lyrics_types::LyricWord
 {
-    fn into_into_dart(self) -> FrbWrapper<stellatune_backend_api::lyrics_types::LyricLine> {
+    fn into_into_dart(self) -> FrbWrapper<stellatune_backend_api::lyrics_types::LyricWord> {
         self.into()
     }
 }
@@ -5530,6 +7335,7 @@ impl flutter_rust_bridge::IntoDart for FrbWrapper<stellatune_backend_api::lyrics
             self.0.source.into_into_dart().into_dart(),
             self.0.is_synced.into_into_dart().into_dart(),
             self.0.lines.into_into_dart().into_dart(),
+            self.0.meta.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -5563,10 +7369,12 @@ impl flutter_rust_bridge::IntoDart
             stellatune_backend_api::lyrics_types::LyricsEvent::Cursor {
                 track_key,
                 line_index,
+                word_index,
             } => [
                 2.into_dart(),
                 track_key.into_into_dart().into_dart(),
                 line_index.into_into_dart().into_dart(),
+                word_index.into_into_dart().into_dart(),
             ]
             .into_dart(),
             stellatune_backend_api::lyrics_types::LyricsEvent::Empty { track_key } => {
@@ -5597,6 +7405,32 @@ impl
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<stellatune_backend_api::lyrics_types::LyricsMeta>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.title.into_into_dart().into_dart(),
+            self.0.artist.into_into_dart().into_dart(),
+            self.0.album.into_into_dart().into_dart(),
+            self.0.author.into_into_dart().into_dart(),
+            self.0.offset_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<stellatune_backend_api::lyrics_types::LyricsMeta>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<stellatune_backend_api::lyrics_types::LyricsMeta>>
+    for stellatune_backend_api::lyrics_types::LyricsMeta
+{
+    fn into_into_dart(self) -> FrbWrapper<stellatune_backend_api::lyrics_types::LyricsMeta> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::player::types::LyricsProviderTypeDescriptor {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5680,6 +7514,90 @@ impl
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::player::types::MixMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::player::types::MixMode::Auto => [0.into_dart()].into_dart(),
+            crate::api::player::types::MixMode::Preset { preset } => {
+                [1.into_dart(), preset.into_into_dart().into_dart()].into_dart()
+            },
+            crate::api::player::types::MixMode::Custom { rows } => {
+                [2.into_dart(), rows.into_into_dart().into_dart()].into_dart()
+            },
+            _ => {
+                unimplemented!("");
+            },
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::player::types::MixMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::MixMode>
+    for crate::api::player::types::MixMode
+{
+    fn into_into_dart(self) -> crate::api::player::types::MixMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::player::types::MixPreset {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::player::types::MixPreset::Balance { balance } => {
+                [0.into_dart(), balance.into_into_dart().into_dart()].into_dart()
+            },
+            crate::api::player::types::MixPreset::SwapLeftRight => [1.into_dart()].into_dart(),
+            crate::api::player::types::MixPreset::MonoSum => [2.into_dart()].into_dart(),
+            crate::api::player::types::MixPreset::StereoWidth { width } => {
+                [3.into_dart(), width.into_into_dart().into_dart()].into_dart()
+            },
+            crate::api::player::types::MixPreset::MatrixSurroundUpmix => {
+                [4.into_dart()].into_dart()
+            },
+            crate::api::player::types::MixPreset::AmbienceUpmix => [5.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            },
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::player::types::MixPreset
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::MixPreset>
+    for crate::api::player::types::MixPreset
+{
+    fn into_into_dart(self) -> crate::api::player::types::MixPreset {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::player::types::NoiseShaping {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Off => 0.into_dart(),
+            Self::FirstOrder => 1.into_dart(),
+            Self::Lipshitz => 2.into_dart(),
+            Self::Wannamaker => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::player::types::NoiseShaping
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::player::types::NoiseShaping>
+    for crate::api::player::types::NoiseShaping
+{
+    fn into_into_dart(self) -> crate::api::player::types::NoiseShaping {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::player::types::OutputSinkRoute {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5825,6 +7743,8 @@ impl flutter_rust_bridge::IntoDart for crate::api::player::types::PluginRuntimeK
             Self::Control => 1.into_dart(),
             Self::ControlResult => 2.into_dart(),
             Self::ControlFinished => 3.into_dart(),
+            Self::DevReload => 4.into_dart(),
+            Self::SidecarState => 5.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -6049,6 +7969,40 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::api::player::types::CrossfeedPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::player::types::CrossfeedPreset::Default => 0,
+                crate::api::player::types::CrossfeedPreset::ChuMoy => 1,
+                crate::api::player::types::CrossfeedPreset::JanMeier => 2,
+                _ => {
+                    unimplemented!("");
+                },
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::player::types::CrossfeedSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <u32>::sse_encode(self.cutoff_hz, serializer);
+        <f32>::sse_encode(self.feed_db, serializer);
+    }
+}
+
+impl SseEncode for crate::api::player::types::DitherSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.tpdf, serializer);
+        <crate::api::player::types::NoiseShaping>::sse_encode(self.noise_shaping, serializer);
+    }
+}
+
 impl SseEncode for crate::api::dlna::types::DlnaHttpServerInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6057,6 +8011,24 @@ impl SseEncode for crate::api::dlna::types::DlnaHttpServerInfo {
     }
 }
 
+impl SseEncode for crate::api::dlna::types::DlnaHttpStreamClient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.remote_addr, serializer);
+        <u64>::sse_encode(self.sent_bytes, serializer);
+        <u64>::sse_encode(self.dropped_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dlna::types::DlnaHttpStreamInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.stream_url, serializer);
+        <String>::sse_encode(self.playlist_url, serializer);
+        <String>::sse_encode(self.content_type, serializer);
+    }
+}
+
 impl SseEncode for crate::api::dlna::types::DlnaPositionInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6088,6 +8060,23 @@ impl SseEncode for crate::api::dlna::types::DlnaSsdpDevice {
     }
 }
 
+impl SseEncode for crate::api::dlna::types::DlnaStreamFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::dlna::types::DlnaStreamFormat::Wav16 => 0,
+                crate::api::dlna::types::DlnaStreamFormat::Flac16 => 1,
+                crate::api::dlna::types::DlnaStreamFormat::Flac24 => 2,
+                _ => {
+                    unimplemented!("");
+                },
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::dlna::types::DlnaTransportInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6191,6 +8180,22 @@ impl SseEncode for i64 {
     }
 }
 
+impl SseEncode for crate::api::player::types::ImpulseLayout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::player::types::ImpulseLayout::PerChannel => 0,
+                crate::api::player::types::ImpulseLayout::TrueStereo => 1,
+                _ => {
+                    unimplemented!("");
+                },
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::player::types::LfeMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6275,6 +8280,16 @@ impl SseEncode for Vec<crate::api::player::types::AudioDevice> {
     }
 }
 
+impl SseEncode for Vec<crate::api::dlna::types::DlnaHttpStreamClient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dlna::types::DlnaHttpStreamClient>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::dlna::types::DlnaRenderer> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6315,6 +8330,16 @@ impl SseEncode for Vec<crate::api::player::types::DspTypeDescriptor> {
     }
 }
 
+impl SseEncode for Vec<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<f32>>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<stellatune_backend_api::lyrics_types::LyricLine> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6325,6 +8350,16 @@ impl SseEncode for Vec<stellatune_backend_api::lyrics_types::LyricLine> {
     }
 }
 
+impl SseEncode for Vec<stellatune_backend_api::lyrics_types::LyricWord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <stellatune_backend_api::lyrics_types::LyricWord>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::player::types::LyricsProviderTypeDescriptor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6367,6 +8402,16 @@ impl SseEncode for Vec<stellatune_library::PlaylistLite> {
     }
 }
 
+impl SseEncode for Vec<stellatune_library::PluginErrorEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <stellatune_library::PluginErrorEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::player::types::PluginDescriptor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6377,6 +8422,16 @@ impl SseEncode for Vec<crate::api::player::types::PluginDescriptor> {
     }
 }
 
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6423,6 +8478,18 @@ impl SseEncode for stellatune_backend_api::lyrics_types::LyricLine {
         <Option<i64>>::sse_encode(self.start_ms, serializer);
         <Option<i64>>::sse_encode(self.end_ms, serializer);
         <String>::sse_encode(self.text, serializer);
+        <Vec<stellatune_backend_api::lyrics_types::LyricWord>>::sse_encode(self.words, serializer);
+        <Option<String>>::sse_encode(self.translation, serializer);
+        <Option<String>>::sse_encode(self.romanization, serializer);
+    }
+}
+
+impl SseEncode for stellatune_backend_api::lyrics_types::LyricWord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.start_ms, serializer);
+        <Option<i64>>::sse_encode(self.end_ms, serializer);
+        <String>::sse_encode(self.text, serializer);
     }
 }
 
@@ -6433,6 +8500,7 @@ impl SseEncode for stellatune_backend_api::lyrics_types::LyricsDoc {
         <String>::sse_encode(self.source, serializer);
         <bool>::sse_encode(self.is_synced, serializer);
         <Vec<stellatune_backend_api::lyrics_types::LyricLine>>::sse_encode(self.lines, serializer);
+        <stellatune_backend_api::lyrics_types::LyricsMeta>::sse_encode(self.meta, serializer);
     }
 }
 
@@ -6452,10 +8520,12 @@ impl SseEncode for stellatune_backend_api::lyrics_types::LyricsEvent {
             stellatune_backend_api::lyrics_types::LyricsEvent::Cursor {
                 track_key,
                 line_index,
+                word_index,
             } => {
                 <i32>::sse_encode(2, serializer);
                 <String>::sse_encode(track_key, serializer);
                 <i64>::sse_encode(line_index, serializer);
+                <i64>::sse_encode(word_index, serializer);
             },
             stellatune_backend_api::lyrics_types::LyricsEvent::Empty { track_key } => {
                 <i32>::sse_encode(3, serializer);
@@ -6473,6 +8543,17 @@ impl SseEncode for stellatune_backend_api::lyrics_types::LyricsEvent {
     }
 }
 
+impl SseEncode for stellatune_backend_api::lyrics_types::LyricsMeta {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.album, serializer);
+        <Option<String>>::sse_encode(self.author, serializer);
+        <i64>::sse_encode(self.offset_ms, serializer);
+    }
+}
+
 impl SseEncode for crate::api::player::types::LyricsProviderTypeDescriptor {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6508,6 +8589,77 @@ impl SseEncode for stellatune_backend_api::lyrics_types::LyricsSearchCandidate {
     }
 }
 
+impl SseEncode for crate::api::player::types::MixMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::player::types::MixMode::Auto => {
+                <i32>::sse_encode(0, serializer);
+            },
+            crate::api::player::types::MixMode::Preset { preset } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::player::types::MixPreset>::sse_encode(preset, serializer);
+            },
+            crate::api::player::types::MixMode::Custom { rows } => {
+                <i32>::sse_encode(2, serializer);
+                <Vec<Vec<f32>>>::sse_encode(rows, serializer);
+            },
+            _ => {
+                unimplemented!("");
+            },
+        }
+    }
+}
+
+impl SseEncode for crate::api::player::types::MixPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::player::types::MixPreset::Balance { balance } => {
+                <i32>::sse_encode(0, serializer);
+                <f32>::sse_encode(balance, serializer);
+            },
+            crate::api::player::types::MixPreset::SwapLeftRight => {
+                <i32>::sse_encode(1, serializer);
+            },
+            crate::api::player::types::MixPreset::MonoSum => {
+                <i32>::sse_encode(2, serializer);
+            },
+            crate::api::player::types::MixPreset::StereoWidth { width } => {
+                <i32>::sse_encode(3, serializer);
+                <f32>::sse_encode(width, serializer);
+            },
+            crate::api::player::types::MixPreset::MatrixSurroundUpmix => {
+                <i32>::sse_encode(4, serializer);
+            },
+            crate::api::player::types::MixPreset::AmbienceUpmix => {
+                <i32>::sse_encode(5, serializer);
+            },
+            _ => {
+                unimplemented!("");
+            },
+        }
+    }
+}
+
+impl SseEncode for crate::api::player::types::NoiseShaping {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::player::types::NoiseShaping::Off => 0,
+                crate::api::player::types::NoiseShaping::FirstOrder => 1,
+                crate::api::player::types::NoiseShaping::Lipshitz => 2,
+                crate::api::player::types::NoiseShaping::Wannamaker => 3,
+                _ => {
+                    unimplemented!("");
+                },
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6548,6 +8700,16 @@ impl SseEncode for Option<u16> {
     }
 }
 
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6609,6 +8771,21 @@ impl SseEncode for stellatune_library::PlaylistLite {
     }
 }
 
+impl SseEncode for stellatune_library::PluginErrorEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.plugin_id, serializer);
+        <String>::sse_encode(self.code, serializer);
+        <Option<String>>::sse_encode(self.capability_kind, serializer);
+        <Option<String>>::sse_encode(self.type_id, serializer);
+        <String>::sse_encode(self.action, serializer);
+        <String>::sse_encode(self.severity, serializer);
+        <String>::sse_encode(self.detail, serializer);
+        <i64>::sse_encode(self.occurred_at_ms, serializer);
+    }
+}
+
 impl SseEncode for crate::api::player::types::PluginDescriptor {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6635,6 +8812,8 @@ impl SseEncode for crate::api::player::types::PluginRuntimeKind {
                 crate::api::player::types::PluginRuntimeKind::Control => 1,
                 crate::api::player::types::PluginRuntimeKind::ControlResult => 2,
                 crate::api::player::types::PluginRuntimeKind::ControlFinished => 3,
+                crate::api::player::types::PluginRuntimeKind::DevReload => 4,
+                crate::api::player::types::PluginRuntimeKind::SidecarState => 5,
                 _ => {
                     unimplemented!("");
                 },
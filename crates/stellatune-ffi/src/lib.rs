@@ -2,3 +2,10 @@ pub mod api;
 #[allow(clippy::wildcard_imports)]
 // flutter_rust_bridge generated module intentionally uses glob imports.
 mod frb_generated;
+
+// Debug builds count allocations so the decode thread can flag built-in DSP stages
+// that allocate on the realtime hot path. Release builds keep the system allocator.
+#[cfg(debug_assertions)]
+#[global_allocator]
+static ALLOCATOR: stellatune_audio_core::pipeline::alloc_guard::CountingAllocator =
+    stellatune_audio_core::pipeline::alloc_guard::CountingAllocator;
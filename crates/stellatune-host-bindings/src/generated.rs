@@ -34,3 +34,10 @@ pub mod output_sink_plugin {
         world: "output-sink-plugin",
     });
 }
+
+pub mod playback_listener_plugin {
+    wasmtime::component::bindgen!({
+        path: "../../wit/stellatune-plugin",
+        world: "playback-listener-plugin",
+    });
+}
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS plugin_error_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  plugin_id TEXT NOT NULL,
+  code TEXT NOT NULL,
+  capability_kind TEXT,
+  type_id TEXT,
+  action TEXT NOT NULL,
+  severity TEXT NOT NULL,
+  detail TEXT NOT NULL,
+  occurred_at_ms INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_plugin_error_log_plugin
+ON plugin_error_log(plugin_id, id);
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS plugin_config (
+  plugin_id TEXT NOT NULL,
+  type_id TEXT NOT NULL,
+  schema_version INTEGER NOT NULL,
+  config_json TEXT NOT NULL,
+  updated_at_ms INTEGER NOT NULL,
+  PRIMARY KEY (plugin_id, type_id)
+);
@@ -3,4 +3,8 @@ mod types;
 mod worker;
 
 pub use service::{LibraryHandle, start_library};
-pub use types::{LibraryEvent, PlaylistLite, TrackLite};
+pub use types::{
+    EmbeddedCover, EmbeddedLyricLine, EmbeddedLyrics, EmbeddedTags, LibraryEvent, PlaylistLite,
+    PluginConfigRecord, PluginErrorEntry, TrackLite,
+};
+pub use worker::{read_embedded_lyrics, read_embedded_tags};
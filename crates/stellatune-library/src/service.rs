@@ -3,10 +3,11 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, Result, anyhow};
-use tokio::sync::broadcast;
+use sqlx::SqlitePool;
+use tokio::sync::{OnceCell, broadcast};
 use tracing::info;
 
-use crate::{LibraryEvent, PlaylistLite, TrackLite};
+use crate::{LibraryEvent, PlaylistLite, PluginConfigRecord, PluginErrorEntry, TrackLite};
 use stellatune_runtime::tokio_actor::{ActorRef, CallError, Handler, Message, spawn_actor};
 
 use crate::worker::{LibraryWorker, WorkerDeps};
@@ -22,9 +23,9 @@ use self::service_actor::handlers::command::{
     ShutdownMessage,
 };
 use self::service_actor::handlers::query::{
-    ListExcludedFoldersMessage, ListFoldersMessage, ListLikedTrackIdsMessage,
-    ListPlaylistTracksMessage, ListPlaylistsMessage, ListRootsMessage, ListTracksMessage,
-    SearchTracksMessage,
+    GetTracksByIdsMessage, ListExcludedFoldersMessage, ListFoldersMessage,
+    ListLikedTrackIdsMessage, ListPlaylistTracksMessage, ListPlaylistsMessage, ListRootsMessage,
+    ListTracksMessage, SearchTracksMessage,
 };
 
 use std::collections::HashSet;
@@ -35,6 +36,9 @@ pub struct LibraryHandle {
     events: Arc<EventHub>,
     plugins_dir: PathBuf,
     db_path: PathBuf,
+    /// State-db pool for plugin errors and configs, opened on first use and shared by
+    /// every clone of the handle.
+    state_pool: Arc<OnceCell<SqlitePool>>,
 }
 
 impl LibraryHandle {
@@ -259,6 +263,16 @@ impl LibraryHandle {
         result.map_err(|e| anyhow!(e))
     }
 
+    /// Looks up tracks in the order given, skipping ids that are no longer indexed.
+    pub async fn get_tracks_by_ids(&self, track_ids: Vec<i64>) -> Result<Vec<TrackLite>> {
+        let result = self
+            .actor_ref
+            .call(GetTracksByIdsMessage { track_ids }, Self::QUERY_TIMEOUT)
+            .await
+            .map_err(map_call_error)?;
+        result.map_err(|e| anyhow!(e))
+    }
+
     pub async fn list_liked_track_ids(&self) -> Result<Vec<i64>> {
         let result = self
             .actor_ref
@@ -293,6 +307,59 @@ impl LibraryHandle {
         out.sort();
         Ok(out)
     }
+
+    /// Appends entries to the bounded plugin error timeline.
+    pub async fn record_plugin_errors(&self, entries: Vec<PluginErrorEntry>) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        crate::worker::db::insert_plugin_errors(self.state_pool().await?, &entries).await
+    }
+
+    /// Newest-first plugin error timeline, optionally restricted to one plugin.
+    pub async fn list_plugin_errors(
+        &self,
+        plugin_id: Option<String>,
+        limit: u32,
+    ) -> Result<Vec<PluginErrorEntry>> {
+        let plugin_id = normalize_plugin_id_filter(plugin_id);
+        let pool = self.state_pool().await?;
+        crate::worker::db::list_plugin_errors(pool, plugin_id.as_deref(), limit as i64).await
+    }
+
+    pub async fn clear_plugin_errors(&self, plugin_id: Option<String>) -> Result<()> {
+        let plugin_id = normalize_plugin_id_filter(plugin_id);
+        crate::worker::db::clear_plugin_errors(self.state_pool().await?, plugin_id.as_deref()).await
+    }
+
+    pub async fn load_plugin_config(
+        &self,
+        plugin_id: &str,
+        type_id: &str,
+    ) -> Result<Option<PluginConfigRecord>> {
+        let pool = self.state_pool().await?;
+        crate::worker::db::load_plugin_config(pool, plugin_id.trim(), type_id.trim()).await
+    }
+
+    pub async fn list_plugin_configs(&self) -> Result<Vec<PluginConfigRecord>> {
+        crate::worker::db::list_plugin_configs(self.state_pool().await?).await
+    }
+
+    pub async fn save_plugin_config(&self, record: PluginConfigRecord) -> Result<()> {
+        crate::worker::db::upsert_plugin_config(self.state_pool().await?, &record).await
+    }
+
+    async fn state_pool(&self) -> Result<&SqlitePool> {
+        self.state_pool
+            .get_or_try_init(|| crate::worker::db::open_state_db_pool(&self.db_path))
+            .await
+    }
+}
+
+fn normalize_plugin_id_filter(plugin_id: Option<String>) -> Option<String> {
+    plugin_id
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
 }
 
 fn map_call_error(err: CallError) -> anyhow::Error {
@@ -324,6 +391,7 @@ pub async fn start_library(db_path: String) -> Result<LibraryHandle> {
         events,
         plugins_dir,
         db_path,
+        state_pool: Arc::new(OnceCell::new()),
     })
 }
 
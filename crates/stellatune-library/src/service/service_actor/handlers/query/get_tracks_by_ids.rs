@@ -0,0 +1,23 @@
+use super::{ActorContext, Handler, LibraryServiceActor, Message, TrackLite};
+
+pub(crate) struct GetTracksByIdsMessage {
+    pub(crate) track_ids: Vec<i64>,
+}
+
+impl Message for GetTracksByIdsMessage {
+    type Response = Result<Vec<TrackLite>, String>;
+}
+
+#[async_trait::async_trait]
+impl Handler<GetTracksByIdsMessage> for LibraryServiceActor {
+    async fn handle(
+        &mut self,
+        message: GetTracksByIdsMessage,
+        _ctx: &mut ActorContext<Self>,
+    ) -> Result<Vec<TrackLite>, String> {
+        self.worker
+            .get_tracks_by_ids(message.track_ids)
+            .await
+            .map_err(|e| format!("{e:#}"))
+    }
+}
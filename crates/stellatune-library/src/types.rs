@@ -45,3 +45,76 @@ pub enum LibraryEvent {
         message: String,
     },
 }
+
+/// One entry of the persisted plugin error timeline.
+///
+/// `code`, `capability_kind`, `action` and `severity` hold the snake_case names used by
+/// the plugin runtime so the timeline stays readable across runtime upgrades.
+#[flutter_rust_bridge::frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginErrorEntry {
+    pub id: i64,
+    pub plugin_id: String,
+    pub code: String,
+    pub capability_kind: Option<String>,
+    pub type_id: Option<String>,
+    pub action: String,
+    pub severity: String,
+    pub detail: String,
+    pub occurred_at_ms: i64,
+}
+
+/// Persisted capability config, tagged with the schema version it was saved under.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigRecord {
+    pub plugin_id: String,
+    pub type_id: String,
+    pub schema_version: u32,
+    pub config_json: String,
+    pub updated_at_ms: i64,
+}
+
+/// Portable tags read from an audio file, keyed by Vorbis-comment field names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EmbeddedTags {
+    /// `(FIELD, value)` pairs such as `("TITLE", "...")` in tag order.
+    pub tags: Vec<(String, String)>,
+    pub cover: Option<EmbeddedCover>,
+}
+
+impl EmbeddedTags {
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(field))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedCover {
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+/// Lyrics stored inside an audio file's tags.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddedLyrics {
+    /// Lyric tag texts (ID3 `USLT`, Vorbis `LYRICS`, MP4 `©lyr`, plugin extras) in tag
+    /// order. A text may itself be LRC.
+    pub texts: Vec<String>,
+    /// Entries of the first ID3v2 `SYLT` frame with millisecond timestamps.
+    pub synced: Vec<EmbeddedLyricLine>,
+}
+
+impl EmbeddedLyrics {
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty() && self.synced.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddedLyricLine {
+    pub start_ms: i64,
+    pub text: String,
+}
@@ -129,6 +129,243 @@ pub(crate) async fn replace_disabled_plugin_ids(
     Ok(())
 }
 
+/// Upper bound on rows kept per plugin in `plugin_error_log`; each plugin's older entries
+/// are pruned on insert, so a noisy plugin cannot evict another plugin's history.
+pub(crate) const PLUGIN_ERROR_LOG_MAX_ROWS_PER_PLUGIN: i64 = 500;
+
+/// Appends entries to the plugin error timeline and mirrors the newest detail per plugin
+/// into `plugin_state.last_error`. `id` of the given entries is ignored.
+pub(crate) async fn insert_plugin_errors(
+    pool: &SqlitePool,
+    entries: &[crate::PluginErrorEntry],
+) -> Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    let mut tx = pool.begin().await?;
+
+    for entry in entries {
+        sqlx::query(
+            r#"
+            INSERT INTO plugin_error_log(
+                plugin_id,
+                code,
+                capability_kind,
+                type_id,
+                action,
+                severity,
+                detail,
+                occurred_at_ms
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#,
+        )
+        .bind(&entry.plugin_id)
+        .bind(&entry.code)
+        .bind(&entry.capability_kind)
+        .bind(&entry.type_id)
+        .bind(&entry.action)
+        .bind(&entry.severity)
+        .bind(&entry.detail)
+        .bind(entry.occurred_at_ms)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO plugin_state(plugin_id, last_error, updated_at_ms)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(plugin_id) DO UPDATE SET
+                last_error = excluded.last_error,
+                updated_at_ms = excluded.updated_at_ms
+            "#,
+        )
+        .bind(&entry.plugin_id)
+        .bind(&entry.detail)
+        .bind(entry.occurred_at_ms)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    sqlx::query(
+        r#"
+        DELETE FROM plugin_error_log
+        WHERE id IN (
+            SELECT id FROM (
+                SELECT
+                    id,
+                    ROW_NUMBER() OVER (PARTITION BY plugin_id ORDER BY id DESC) AS rank
+                FROM plugin_error_log
+            )
+            WHERE rank > ?1
+        )
+        "#,
+    )
+    .bind(PLUGIN_ERROR_LOG_MAX_ROWS_PER_PLUGIN)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Newest-first timeline, optionally restricted to one plugin.
+pub(crate) async fn list_plugin_errors(
+    pool: &SqlitePool,
+    plugin_id: Option<&str>,
+    limit: i64,
+) -> Result<Vec<crate::PluginErrorEntry>> {
+    let rows = sqlx::query_as::<
+        _,
+        (
+            i64,
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            String,
+            String,
+            String,
+            i64,
+        ),
+    >(
+        r#"
+        SELECT id, plugin_id, code, capability_kind, type_id, action, severity, detail,
+               occurred_at_ms
+        FROM plugin_error_log
+        WHERE ?1 IS NULL OR plugin_id = ?1
+        ORDER BY id DESC
+        LIMIT ?2
+        "#,
+    )
+    .bind(plugin_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(
+                id,
+                plugin_id,
+                code,
+                capability_kind,
+                type_id,
+                action,
+                severity,
+                detail,
+                occurred_at_ms,
+            )| crate::PluginErrorEntry {
+                id,
+                plugin_id,
+                code,
+                capability_kind,
+                type_id,
+                action,
+                severity,
+                detail,
+                occurred_at_ms,
+            },
+        )
+        .collect())
+}
+
+pub(crate) async fn clear_plugin_errors(pool: &SqlitePool, plugin_id: Option<&str>) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM plugin_error_log WHERE ?1 IS NULL OR plugin_id = ?1")
+        .bind(plugin_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE plugin_state SET last_error = NULL WHERE ?1 IS NULL OR plugin_id = ?1")
+        .bind(plugin_id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+pub(crate) async fn load_plugin_config(
+    pool: &SqlitePool,
+    plugin_id: &str,
+    type_id: &str,
+) -> Result<Option<crate::PluginConfigRecord>> {
+    let row = sqlx::query_as::<_, (i64, String, i64)>(
+        r#"
+        SELECT schema_version, config_json, updated_at_ms
+        FROM plugin_config
+        WHERE plugin_id = ?1 AND type_id = ?2
+        "#,
+    )
+    .bind(plugin_id)
+    .bind(type_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(
+        |(schema_version, config_json, updated_at_ms)| crate::PluginConfigRecord {
+            plugin_id: plugin_id.to_string(),
+            type_id: type_id.to_string(),
+            schema_version: schema_version.clamp(0, u32::MAX as i64) as u32,
+            config_json,
+            updated_at_ms,
+        },
+    ))
+}
+
+pub(crate) async fn list_plugin_configs(
+    pool: &SqlitePool,
+) -> Result<Vec<crate::PluginConfigRecord>> {
+    let rows = sqlx::query_as::<_, (String, String, i64, String, i64)>(
+        r#"
+        SELECT plugin_id, type_id, schema_version, config_json, updated_at_ms
+        FROM plugin_config
+        ORDER BY plugin_id, type_id
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(plugin_id, type_id, schema_version, config_json, updated_at_ms)| {
+                crate::PluginConfigRecord {
+                    plugin_id,
+                    type_id,
+                    schema_version: schema_version.clamp(0, u32::MAX as i64) as u32,
+                    config_json,
+                    updated_at_ms,
+                }
+            },
+        )
+        .collect())
+}
+
+pub(crate) async fn upsert_plugin_config(
+    pool: &SqlitePool,
+    record: &crate::PluginConfigRecord,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO plugin_config(plugin_id, type_id, schema_version, config_json, updated_at_ms)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT(plugin_id, type_id) DO UPDATE SET
+            schema_version = excluded.schema_version,
+            config_json = excluded.config_json,
+            updated_at_ms = excluded.updated_at_ms
+        "#,
+    )
+    .bind(&record.plugin_id)
+    .bind(&record.type_id)
+    .bind(record.schema_version as i64)
+    .bind(&record.config_json)
+    .bind(record.updated_at_ms)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
 async fn backfill_norm_paths(pool: &SqlitePool) -> Result<()> {
     // Populate path_norm/dir_norm for tracks created before this feature existed.
     // Done at startup so folder browsing works without requiring a full re-scan.
@@ -178,3 +415,62 @@ async fn ensure_fts5(pool: &SqlitePool) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        PLUGIN_ERROR_LOG_MAX_ROWS_PER_PLUGIN, insert_plugin_errors, list_plugin_errors,
+        open_state_db_pool,
+    };
+    use crate::PluginErrorEntry;
+
+    fn entry(plugin_id: &str, occurred_at_ms: i64) -> PluginErrorEntry {
+        PluginErrorEntry {
+            id: 0,
+            plugin_id: plugin_id.to_string(),
+            code: "runtime.call_failed".to_string(),
+            capability_kind: None,
+            type_id: None,
+            action: "call".to_string(),
+            severity: "error".to_string(),
+            detail: format!("failure at {occurred_at_ms}"),
+            occurred_at_ms,
+        }
+    }
+
+    #[tokio::test]
+    async fn noisy_plugin_does_not_evict_other_plugins_errors() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let pool = open_state_db_pool(&dir.path().join("state.sqlite"))
+            .await
+            .expect("open state db");
+
+        insert_plugin_errors(&pool, &[entry("quiet", 1), entry("quiet", 2)])
+            .await
+            .expect("insert quiet errors");
+        let noisy = (0..PLUGIN_ERROR_LOG_MAX_ROWS_PER_PLUGIN + 20)
+            .map(|i| entry("noisy", 10 + i))
+            .collect::<Vec<_>>();
+        insert_plugin_errors(&pool, &noisy)
+            .await
+            .expect("insert noisy errors");
+
+        let quiet = list_plugin_errors(&pool, Some("quiet"), 10)
+            .await
+            .expect("list quiet errors");
+        assert_eq!(
+            quiet.iter().map(|e| e.occurred_at_ms).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+
+        let noisy = list_plugin_errors(&pool, Some("noisy"), i64::MAX)
+            .await
+            .expect("list noisy errors");
+        assert_eq!(noisy.len() as i64, PLUGIN_ERROR_LOG_MAX_ROWS_PER_PLUGIN);
+        assert_eq!(
+            noisy.last().map(|e| e.occurred_at_ms),
+            Some(10 + 20),
+            "the oldest noisy entries are pruned first"
+        );
+    }
+}
@@ -18,6 +18,10 @@ use stellatune_audio_builtin_adapters::builtin_decoder::builtin_decoder_score_fo
 use stellatune_plugins::host_runtime::{
     RuntimeCapabilityKind, RuntimeDecoderPlugin, shared_runtime_service,
 };
+use stellatune_plugins::runtime::model::RuntimeMetadataValue;
+
+use super::sylt::read_sylt;
+use crate::{EmbeddedCover, EmbeddedLyrics, EmbeddedTags};
 
 #[derive(Default)]
 pub(super) struct ExtractedMetadata {
@@ -275,6 +279,16 @@ pub(super) fn has_plugin_decoder_for_path(path: &Path) -> bool {
     }
 }
 
+/// Whether a plugin decoder outranks the builtin decoder for `ext`.
+fn prefers_plugin_decoder(ext: &str) -> bool {
+    let builtin_score = builtin_decoder_score_for_ext(ext).unwrap_or(0);
+    !ext.is_empty() && best_decoder_score_for_ext(ext).is_some_and(|score| score > builtin_score)
+}
+
+fn symphonia_supports_ext(ext: &str) -> bool {
+    builtin_decoder_score_for_ext(ext).unwrap_or(0) > 0
+}
+
 pub(super) fn extract_metadata_with_plugins(path: &Path) -> Result<ExtractedMetadata> {
     let ext = path
         .extension()
@@ -282,13 +296,8 @@ pub(super) fn extract_metadata_with_plugins(path: &Path) -> Result<ExtractedMeta
         .unwrap_or("")
         .trim()
         .to_ascii_lowercase();
-    let builtin_score = builtin_decoder_score_for_ext(&ext).unwrap_or(0);
-    let symphonia_supported = builtin_score > 0;
-    let prefer_plugin = if ext.is_empty() {
-        false
-    } else {
-        best_decoder_score_for_ext(&ext).is_some_and(|score| score > builtin_score)
-    };
+    let symphonia_supported = symphonia_supports_ext(&ext);
+    let prefer_plugin = prefers_plugin_decoder(&ext);
 
     if prefer_plugin {
         debug!(
@@ -325,6 +334,247 @@ pub(super) fn extract_metadata_with_plugins(path: &Path) -> Result<ExtractedMeta
     extract_metadata(path)
 }
 
+/// Reads lyrics embedded in `path`: plugin decoder `extras` when a plugin decoder is
+/// preferred for the extension, otherwise symphonia lyric tags plus ID3v2 `SYLT`.
+///
+/// Returns `Ok(None)` when the file carries no lyrics.
+pub fn read_embedded_lyrics(path: &Path) -> Result<Option<EmbeddedLyrics>> {
+    let ext = path
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+
+    let lyrics = if prefers_plugin_decoder(&ext) {
+        match read_plugin_embedded_lyrics(path) {
+            Ok(lyrics) => lyrics,
+            Err(error) if symphonia_supports_ext(&ext) => {
+                debug!(
+                    target: "stellatune_library::metadata",
+                    path = %path.display(),
+                    err = %error,
+                    "plugin embedded lyrics read failed, fallback to symphonia"
+                );
+                read_symphonia_embedded_lyrics(path)?
+            },
+            Err(error) => return Err(error),
+        }
+    } else {
+        read_symphonia_embedded_lyrics(path)?
+    };
+    Ok((!lyrics.is_empty()).then_some(lyrics))
+}
+
+fn read_symphonia_embedded_lyrics(path: &Path) -> Result<EmbeddedLyrics> {
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|s| s.to_str()) {
+        hint.with_extension(ext);
+    }
+    let src = std::fs::File::open(path)
+        .with_context(|| format!("failed to open for lyrics: {}", path.display()))?;
+    let mss = MediaSourceStream::new(Box::new(src), Default::default());
+    let mut probed = get_probe()
+        .format(
+            &hint,
+            mss,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .context("symphonia probe failed")?;
+
+    let mut out = EmbeddedLyrics::default();
+    if let Some(mut m) = probed.metadata.get()
+        && let Some(rev) = m.skip_to_latest()
+    {
+        collect_lyric_tags(rev, &mut out);
+    }
+    if let Some(rev) = probed.format.metadata().skip_to_latest() {
+        collect_lyric_tags(rev, &mut out);
+    }
+    out.synced = read_sylt(path).unwrap_or_default();
+    Ok(out)
+}
+
+fn collect_lyric_tags(rev: &symphonia::core::meta::MetadataRevision, out: &mut EmbeddedLyrics) {
+    for tag in rev.tags() {
+        // ID3 `USLT!eng`, Vorbis `LYRICS`/`UNSYNCEDLYRICS`/`SYNCEDLYRICS`, MP4 `©lyr`.
+        let key = tag.key.trim().to_ascii_lowercase();
+        let is_lyrics = matches!(tag.std_key, Some(StandardTagKey::Lyrics))
+            || key.starts_with("uslt")
+            || key.contains("lyrics")
+            || key == "\u{a9}lyr";
+        if is_lyrics
+            && let Some(text) = value_to_string(&tag.value)
+            && !out.texts.contains(&text)
+        {
+            out.texts.push(text);
+        }
+    }
+}
+
+/// Reads the common text tags and the front cover of `path` with symphonia, falling
+/// back to a sidecar image for the cover. Used to carry tags across format conversion.
+pub fn read_embedded_tags(path: &Path) -> Result<EmbeddedTags> {
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|s| s.to_str()) {
+        hint.with_extension(ext);
+    }
+    let src = std::fs::File::open(path)
+        .with_context(|| format!("failed to open for tags: {}", path.display()))?;
+    let mss = MediaSourceStream::new(Box::new(src), Default::default());
+    let meta_opts = MetadataOptions {
+        limit_visual_bytes: Limit::Maximum(COVER_BYTES_LIMIT as usize),
+        ..Default::default()
+    };
+    let mut probed = get_probe()
+        .format(&hint, mss, &FormatOptions::default(), &meta_opts)
+        .context("symphonia probe failed")?;
+
+    let mut out = EmbeddedTags::default();
+    if let Some(mut m) = probed.metadata.get()
+        && let Some(rev) = m.skip_to_latest()
+    {
+        collect_portable_tags(rev, &mut out);
+    }
+    if let Some(rev) = probed.format.metadata().skip_to_latest() {
+        collect_portable_tags(rev, &mut out);
+    }
+    if out.cover.is_none() {
+        out.cover = load_sidecar_cover(path).and_then(|data| {
+            let mime_type = sniff_image_mime(&data)?;
+            Some(EmbeddedCover {
+                mime_type: mime_type.to_string(),
+                data,
+            })
+        });
+    }
+    Ok(out)
+}
+
+fn collect_portable_tags(rev: &symphonia::core::meta::MetadataRevision, out: &mut EmbeddedTags) {
+    for tag in rev.tags() {
+        let Some(field) = tag.std_key.and_then(portable_tag_field) else {
+            continue;
+        };
+        if out.get(field).is_some() {
+            continue;
+        }
+        if let Some(value) = value_to_string(&tag.value) {
+            out.tags.push((field.to_string(), value));
+        }
+    }
+
+    if out.cover.is_none() {
+        let front = rev
+            .visuals()
+            .iter()
+            .find(|v| v.usage == Some(StandardVisualKey::FrontCover));
+        let chosen = front.or(rev.visuals().first());
+        if let Some(v) = chosen.filter(|v| !v.data.is_empty()) {
+            let mime_type = if v.media_type.trim().is_empty() {
+                sniff_image_mime(&v.data)
+                    .unwrap_or("image/jpeg")
+                    .to_string()
+            } else {
+                v.media_type.trim().to_string()
+            };
+            out.cover = Some(EmbeddedCover {
+                mime_type,
+                data: v.data.to_vec(),
+            });
+        }
+    }
+}
+
+fn portable_tag_field(key: StandardTagKey) -> Option<&'static str> {
+    let field = match key {
+        StandardTagKey::TrackTitle => "TITLE",
+        StandardTagKey::Artist => "ARTIST",
+        StandardTagKey::Album => "ALBUM",
+        StandardTagKey::AlbumArtist => "ALBUMARTIST",
+        StandardTagKey::Composer => "COMPOSER",
+        StandardTagKey::Genre => "GENRE",
+        StandardTagKey::Date => "DATE",
+        StandardTagKey::TrackNumber => "TRACKNUMBER",
+        StandardTagKey::TrackTotal => "TRACKTOTAL",
+        StandardTagKey::DiscNumber => "DISCNUMBER",
+        StandardTagKey::DiscTotal => "DISCTOTAL",
+        StandardTagKey::Comment => "COMMENT",
+        StandardTagKey::Lyrics => "LYRICS",
+        _ => return None,
+    };
+    Some(field)
+}
+
+fn sniff_image_mime(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if bytes.starts_with(b"\x89PNG") {
+        Some("image/png")
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+fn read_plugin_embedded_lyrics(path: &Path) -> Result<EmbeddedLyrics> {
+    let ext = path
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let mut last_err: Option<String> = None;
+    for candidate in select_plugin_metadata_decoder_candidates(path) {
+        match with_cached_metadata_decoder(&candidate, |decoder| {
+            let ext_hint = (!ext.trim().is_empty()).then_some(ext.as_str());
+            let session = decoder
+                .open_file(path, ext_hint)
+                .map_err(|error| anyhow!("{error:#}"))?;
+            let metadata = decoder
+                .metadata(session)
+                .map_err(|error| anyhow!("{error:#}"));
+            let _ = decoder.close(session);
+            metadata
+        }) {
+            Ok(metadata) => {
+                let mut out = EmbeddedLyrics::default();
+                for entry in metadata.extras {
+                    let key = entry.key.trim().to_ascii_lowercase();
+                    if !key.contains("lyric") && key != "uslt" && key != "sylt" {
+                        continue;
+                    }
+                    let text = match entry.value {
+                        RuntimeMetadataValue::Text(text) => text,
+                        RuntimeMetadataValue::Bytes(bytes) => {
+                            String::from_utf8_lossy(&bytes).into_owned()
+                        },
+                        _ => continue,
+                    };
+                    if let Some(text) = normalize_text_field(&text)
+                        && !out.texts.contains(&text)
+                    {
+                        out.texts.push(text);
+                    }
+                }
+                return Ok(out);
+            },
+            Err(error) => {
+                last_err = Some(format!(
+                    "{}::{}: {error}",
+                    candidate.plugin_id, candidate.type_id
+                ))
+            },
+        }
+    }
+    Err(anyhow!(
+        "failed to read plugin embedded lyrics for {}: {}",
+        path.display(),
+        last_err.unwrap_or_else(|| "no decoder candidate".to_string())
+    ))
+}
+
 fn duration_ms_from_track_params(
     time_base: Option<TimeBase>,
     n_frames: Option<u64>,
@@ -719,20 +969,14 @@ fn apply_revision(rev: &symphonia::core::meta::MetadataRevision, out: &mut Extra
         if tag.std_key.is_none() {
             let key = tag.key.trim().to_ascii_lowercase();
             match key.as_str() {
-                "title" | "tracktitle" => {
-                    if out.title.is_none() {
-                        out.title = value_to_string(&tag.value);
-                    }
+                "title" | "tracktitle" if out.title.is_none() => {
+                    out.title = value_to_string(&tag.value);
                 },
-                "artist" => {
-                    if out.artist.is_none() {
-                        out.artist = value_to_string(&tag.value);
-                    }
+                "artist" if out.artist.is_none() => {
+                    out.artist = value_to_string(&tag.value);
                 },
-                "album" => {
-                    if out.album.is_none() {
-                        out.album = value_to_string(&tag.value);
-                    }
+                "album" if out.album.is_none() => {
+                    out.album = value_to_string(&tag.value);
                 },
                 _ => {},
             }
@@ -3,9 +3,12 @@ mod fts;
 mod metadata;
 mod paths;
 mod scan;
+mod sylt;
 mod tracks;
 mod watch;
 
+pub use self::metadata::{read_embedded_lyrics, read_embedded_tags};
+
 use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -58,6 +61,9 @@ impl WorkerDeps {
             format!("failed to create cover cache dir: {}", cover_dir.display())
         })?;
 
+        if let Some(data_dir) = db_path.parent() {
+            shared_runtime_service().set_data_dir(data_dir);
+        }
         if plugins_dir.exists() {
             tracing::info!(
                 plugins_dir = %plugins_dir.display(),
@@ -551,6 +557,34 @@ impl LibraryWorker {
         Ok(items)
     }
 
+    pub(crate) async fn get_tracks_by_ids(&self, track_ids: Vec<i64>) -> Result<Vec<TrackLite>> {
+        let mut items = Vec::with_capacity(track_ids.len());
+        for track_id in track_ids {
+            let row = sqlx::query_as::<_, tracks::TrackLiteRow>(
+                r#"
+                SELECT id, path, title, artist, album, duration_ms
+                FROM tracks
+                WHERE id = ?1
+                "#,
+            )
+            .bind(track_id)
+            .fetch_optional(&self.pool)
+            .await
+            .context("get track by id failed")?;
+            if let Some(row) = row {
+                items.push(TrackLite {
+                    id: row.id,
+                    path: row.path,
+                    title: row.title,
+                    artist: row.artist,
+                    album: row.album,
+                    duration_ms: row.duration_ms,
+                });
+            }
+        }
+        Ok(items)
+    }
+
     pub(crate) async fn add_track_to_playlist(
         &self,
         playlist_id: i64,
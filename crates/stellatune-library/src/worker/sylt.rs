@@ -0,0 +1,250 @@
+//! Minimal ID3v2 `SYLT` (synchronised lyrics) reader.
+//!
+//! symphonia skips `SYLT` frames, so the tag at the head of the file is walked here.
+//! Only millisecond timestamps are supported; MPEG-frame timestamps need the stream's
+//! frame duration and are ignored.
+
+use std::io::Read;
+use std::path::Path;
+
+use crate::EmbeddedLyricLine;
+
+const TAG_HEADER_LEN: usize = 10;
+const TAG_MAX_BYTES: usize = 16 * 1024 * 1024;
+const TIMESTAMP_FORMAT_MS: u8 = 2;
+
+pub(super) fn read_sylt(path: &Path) -> Option<Vec<EmbeddedLyricLine>> {
+    let mut file = std::fs::File::open(path).ok()?;
+    let mut header = [0u8; TAG_HEADER_LEN];
+    file.read_exact(&mut header).ok()?;
+    if &header[..3] != b"ID3" {
+        return None;
+    }
+    let size = syncsafe(&header[6..10]) as usize;
+    if size == 0 || size > TAG_MAX_BYTES {
+        return None;
+    }
+    let mut tag = vec![0u8; size];
+    file.read_exact(&mut tag).ok()?;
+    parse_tag(header[3], header[5], tag)
+}
+
+fn parse_tag(major: u8, flags: u8, mut tag: Vec<u8>) -> Option<Vec<EmbeddedLyricLine>> {
+    if !(2..=4).contains(&major) {
+        return None;
+    }
+    // v2.2/v2.3 unsynchronise the whole tag; v2.4 does it per frame.
+    if major < 4 && flags & 0x80 != 0 {
+        tag = resync(&tag);
+    }
+
+    let mut pos = 0;
+    if major >= 3 && flags & 0x40 != 0 {
+        let extended = tag.get(..4)?;
+        pos = if major == 4 {
+            syncsafe(extended) as usize
+        } else {
+            u32::from_be_bytes(extended.try_into().ok()?) as usize + 4
+        };
+    }
+
+    let (id_len, header_len) = if major == 2 { (3, 6) } else { (4, 10) };
+    while pos + header_len <= tag.len() {
+        let header = &tag[pos..pos + header_len];
+        if header[0] == 0 {
+            break;
+        }
+        let id = &header[..id_len];
+        let size = match major {
+            2 => u32::from_be_bytes([0, header[3], header[4], header[5]]) as usize,
+            3 => u32::from_be_bytes(header[4..8].try_into().ok()?) as usize,
+            _ => syncsafe(&header[4..8]) as usize,
+        };
+        let body_start = pos + header_len;
+        let body_end = body_start.checked_add(size)?.min(tag.len());
+        pos = body_end;
+
+        if id != b"SYLT" && id != b"SLT" {
+            continue;
+        }
+        let frame_flags = if major == 2 { 0 } else { header[9] };
+        let Some(body) = frame_body(major, frame_flags, &tag[body_start..body_end]) else {
+            continue;
+        };
+        if let Some(lines) = parse_sylt_body(&body)
+            && !lines.is_empty()
+        {
+            return Some(lines);
+        }
+    }
+    None
+}
+
+/// Strips per-frame header extras; `None` for compressed or encrypted frames.
+fn frame_body(major: u8, flags: u8, body: &[u8]) -> Option<Vec<u8>> {
+    let mut body = body;
+    match major {
+        3 => {
+            if flags & 0xC0 != 0 {
+                return None;
+            }
+            if flags & 0x20 != 0 {
+                body = body.get(1..)?;
+            }
+            Some(body.to_vec())
+        },
+        4 => {
+            if flags & 0x0C != 0 {
+                return None;
+            }
+            if flags & 0x40 != 0 {
+                body = body.get(1..)?;
+            }
+            if flags & 0x01 != 0 {
+                body = body.get(4..)?;
+            }
+            Some(if flags & 0x02 != 0 {
+                resync(body)
+            } else {
+                body.to_vec()
+            })
+        },
+        _ => Some(body.to_vec()),
+    }
+}
+
+fn parse_sylt_body(body: &[u8]) -> Option<Vec<EmbeddedLyricLine>> {
+    let encoding = *body.first()?;
+    // encoding(1) language(3) timestamp format(1) content type(1)
+    if *body.get(4)? != TIMESTAMP_FORMAT_MS {
+        return None;
+    }
+    let mut rest = body.get(6..)?;
+    let (_, consumed) = read_text(rest, encoding)?;
+    rest = &rest[consumed..];
+
+    let mut lines = Vec::new();
+    while !rest.is_empty() {
+        let (text, consumed) = read_text(rest, encoding)?;
+        let timestamp = rest.get(consumed..consumed + 4)?;
+        rest = &rest[consumed + 4..];
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        lines.push(EmbeddedLyricLine {
+            start_ms: i64::from(u32::from_be_bytes(timestamp.try_into().ok()?)),
+            text: text.to_string(),
+        });
+    }
+    lines.sort_by_key(|line| line.start_ms);
+    Some(lines)
+}
+
+/// Reads one terminated string; returns it with the bytes consumed (terminator included).
+fn read_text(data: &[u8], encoding: u8) -> Option<(String, usize)> {
+    match encoding {
+        0 | 3 => {
+            let end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+            let text = if encoding == 0 {
+                data[..end].iter().map(|&b| char::from(b)).collect()
+            } else {
+                String::from_utf8_lossy(&data[..end]).into_owned()
+            };
+            Some((text, (end + 1).min(data.len())))
+        },
+        1 | 2 => {
+            let end = data
+                .chunks_exact(2)
+                .position(|unit| unit == [0, 0])
+                .map_or(data.len() & !1, |idx| idx * 2);
+            let mut units = &data[..end];
+            let mut little_endian = encoding == 1;
+            if encoding == 1 && units.len() >= 2 {
+                match [units[0], units[1]] {
+                    [0xFF, 0xFE] => units = &units[2..],
+                    [0xFE, 0xFF] => {
+                        little_endian = false;
+                        units = &units[2..];
+                    },
+                    _ => {},
+                }
+            }
+            let units = units
+                .chunks_exact(2)
+                .map(|unit| {
+                    if little_endian {
+                        u16::from_le_bytes([unit[0], unit[1]])
+                    } else {
+                        u16::from_be_bytes([unit[0], unit[1]])
+                    }
+                })
+                .collect::<Vec<_>>();
+            Some((String::from_utf16_lossy(&units), (end + 2).min(data.len())))
+        },
+        _ => None,
+    }
+}
+
+fn syncsafe(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0u32, |acc, &b| (acc << 7) | u32::from(b & 0x7F))
+}
+
+/// Reverses ID3 unsynchronisation (`FF 00` -> `FF`).
+fn resync(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut prev = 0u8;
+    for &b in data {
+        if !(prev == 0xFF && b == 0x00) {
+            out.push(b);
+        }
+        prev = b;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_tag;
+
+    fn sylt_frame(entries: &[(&str, u32)]) -> Vec<u8> {
+        let mut body = vec![3, b'e', b'n', b'g', 2, 1, 0];
+        for (text, ms) in entries {
+            body.extend_from_slice(text.as_bytes());
+            body.push(0);
+            body.extend_from_slice(&ms.to_be_bytes());
+        }
+        let mut frame = b"SYLT".to_vec();
+        frame.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&[0, 0]);
+        frame.extend_from_slice(&body);
+        frame
+    }
+
+    #[test]
+    fn reads_millisecond_sylt_frames() {
+        let mut tag = b"TIT2\0\0\0\x03\0\0\x03Hi".to_vec();
+        tag.extend(sylt_frame(&[
+            ("second", 2_000),
+            ("first", 1_000),
+            ("", 3_000),
+        ]));
+        tag.extend([0u8; 8]);
+
+        let lines = parse_tag(3, 0, tag).expect("sylt");
+        let lines: Vec<_> = lines
+            .iter()
+            .map(|line| (line.start_ms, line.text.as_str()))
+            .collect();
+        assert_eq!(lines, vec![(1_000, "first"), (2_000, "second")]);
+    }
+
+    #[test]
+    fn ignores_mpeg_frame_timestamps() {
+        let mut frame = sylt_frame(&[("line", 10)]);
+        frame[10 + 4] = 1;
+        assert!(parse_tag(3, 0, frame).is_none());
+    }
+}
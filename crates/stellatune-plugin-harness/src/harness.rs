@@ -0,0 +1,391 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, anyhow, bail};
+use stellatune_plugins::compat::check_compatibility;
+use stellatune_plugins::executor::plugin_instance::decoder::DecoderPluginApi;
+use stellatune_plugins::executor::plugin_instance::dsp::DspPluginApi;
+use stellatune_plugins::executor::plugin_instance::lyrics::LyricsPluginApi;
+use stellatune_plugins::executor::plugin_instance::output_sink::OutputSinkPluginApi;
+use stellatune_plugins::executor::plugin_instance::source::{
+    RuntimeOpenedSourceStreamHandle, SourcePluginApi, WasmtimeSourcePlugin,
+};
+use stellatune_plugins::executor::{WasmPluginController, WasmtimePluginController};
+use stellatune_plugins::host::stream::open_local_file_stream;
+use stellatune_plugins::manifest::{AbilityKind, PLUGIN_MANIFEST_FILE_NAME, read_manifest};
+use stellatune_plugins::runtime::install_descriptors_from_manifest;
+use stellatune_plugins::runtime::model::{
+    PluginDisableReason, RuntimeAudioSpec, RuntimeBrowsePage, RuntimeCapabilityDescriptor,
+    RuntimeDecoderInfo, RuntimeLyricCandidate, RuntimeMediaMetadata, RuntimeOutputSinkStatus,
+    RuntimePageRequest, RuntimePluginInfo, RuntimeSearchRequest,
+};
+
+use crate::mock::{MockHttpClient, MockSidecarHost, MockStreamService};
+use crate::wav::WavAudio;
+
+const DECODE_CHUNK_FRAMES: u32 = 4096;
+const SOURCE_READ_BYTES: u32 = 64 * 1024;
+/// Consecutive empty, non-EOF reads tolerated before a decode counts as stalled.
+const MAX_EMPTY_READS: u32 = 64;
+const SINK_STALL_TIMEOUT: Duration = Duration::from_secs(5);
+const SINK_RETRY_SLEEP: Duration = Duration::from_millis(2);
+
+/// Host services handed to the plugin under test.
+#[derive(Clone, Default)]
+pub struct MockHosts {
+    pub http: Arc<MockHttpClient>,
+    pub streams: Arc<MockStreamService>,
+    pub sidecars: Arc<MockSidecarHost>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DecodedAudio {
+    pub info: RuntimeDecoderInfo,
+    pub metadata: RuntimeMediaMetadata,
+    pub audio: WavAudio,
+}
+
+#[derive(Debug, Clone)]
+pub struct SinkReport {
+    pub frames_written: u64,
+    pub status: RuntimeOutputSinkStatus,
+}
+
+/// One plugin package installed into a private [`WasmtimePluginController`].
+///
+/// Each driver call creates a fresh plugin instance, runs it to completion and tears
+/// it down, mirroring how the app uses one-shot instances for catalog and lyrics calls.
+pub struct PluginHarness {
+    controller: WasmtimePluginController,
+    hosts: MockHosts,
+    info: RuntimePluginInfo,
+    capabilities: Vec<RuntimeCapabilityDescriptor>,
+    /// Plugin storage root, deleted together with the harness.
+    _storage: tempfile::TempDir,
+}
+
+impl PluginHarness {
+    pub fn load(plugin_dir: &Path) -> Result<Self> {
+        Self::load_with_hosts(plugin_dir, MockHosts::default())
+    }
+
+    /// Loads the package whose `plugin.json` sits in `plugin_dir`. The package does not
+    /// need an install receipt, so a build output directory works as-is.
+    pub fn load_with_hosts(plugin_dir: &Path, hosts: MockHosts) -> Result<Self> {
+        let manifest_path = plugin_dir.join(PLUGIN_MANIFEST_FILE_NAME);
+        let manifest = read_manifest(&manifest_path)?;
+        check_compatibility(&manifest)?;
+        let (info, capabilities) =
+            install_descriptors_from_manifest(plugin_dir.to_path_buf(), manifest_path, manifest);
+        let controller = WasmtimePluginController::new_with_sidecar_host(
+            hosts.http.clone(),
+            hosts.streams.clone(),
+            hosts.sidecars.clone(),
+        )?;
+        let storage = tempfile::tempdir().context("create plugin storage dir")?;
+        controller.set_storage_root(storage.path().to_path_buf());
+        controller
+            .install_plugin(&info, &capabilities)
+            .with_context(|| format!("install plugin `{}`", info.id))?;
+        Ok(Self {
+            controller,
+            hosts,
+            info,
+            capabilities,
+            _storage: storage,
+        })
+    }
+
+    pub fn hosts(&self) -> &MockHosts {
+        &self.hosts
+    }
+
+    pub fn controller(&self) -> &WasmtimePluginController {
+        &self.controller
+    }
+
+    pub fn info(&self) -> &RuntimePluginInfo {
+        &self.info
+    }
+
+    pub fn capabilities(&self) -> &[RuntimeCapabilityDescriptor] {
+        &self.capabilities
+    }
+
+    /// Returns `type_id` if the plugin declares it for `kind`; with `None`, the only
+    /// capability of that kind.
+    pub fn resolve_type_id(&self, kind: AbilityKind, type_id: Option<&str>) -> Result<String> {
+        let mut matching = self
+            .capabilities
+            .iter()
+            .filter(|capability| capability.kind == kind);
+        match type_id {
+            Some(type_id) => matching
+                .find(|capability| capability.type_id == type_id)
+                .map(|capability| capability.type_id.clone())
+                .ok_or_else(|| anyhow!("plugin has no {} `{type_id}`", kind.as_str())),
+            None => {
+                let first = matching
+                    .next()
+                    .ok_or_else(|| anyhow!("plugin has no {} capability", kind.as_str()))?;
+                if matching.next().is_some() {
+                    bail!(
+                        "plugin has several {} capabilities; pick a type id",
+                        kind.as_str()
+                    );
+                }
+                Ok(first.type_id.clone())
+            },
+        }
+    }
+
+    pub fn decode_file(&self, type_id: &str, path: &Path) -> Result<DecodedAudio> {
+        let mut decoder = self
+            .controller
+            .create_decoder_plugin(&self.info.id, type_id)?;
+        let stream = open_local_file_stream(path)?;
+        let ext_hint = path.extension().and_then(|ext| ext.to_str());
+        let session = decoder.open_stream(stream, ext_hint)?;
+        let info = decoder.info(session)?;
+        let metadata = decoder.metadata(session)?;
+
+        let mut samples = Vec::new();
+        let mut empty_reads = 0;
+        loop {
+            let chunk = decoder.read_pcm_f32(session, DECODE_CHUNK_FRAMES)?;
+            samples.extend(f32le_to_samples(&chunk.interleaved_f32le));
+            if chunk.eof {
+                break;
+            }
+            if chunk.frames == 0 {
+                empty_reads += 1;
+                if empty_reads >= MAX_EMPTY_READS {
+                    bail!("decoder returned {MAX_EMPTY_READS} empty chunks without eof");
+                }
+            } else {
+                empty_reads = 0;
+            }
+        }
+        decoder.close(session)?;
+        let audio = WavAudio {
+            sample_rate: info.sample_rate,
+            channels: info.channels,
+            samples,
+        };
+        Ok(DecodedAudio {
+            info,
+            metadata,
+            audio,
+        })
+    }
+
+    /// Runs `input` through one DSP processor in blocks of `chunk_frames`.
+    pub fn run_dsp(
+        &self,
+        type_id: &str,
+        config_json: Option<&str>,
+        input: &WavAudio,
+        chunk_frames: usize,
+    ) -> Result<WavAudio> {
+        let mut dsp = self.controller.create_dsp_plugin(&self.info.id, type_id)?;
+        let spec = RuntimeAudioSpec {
+            sample_rate: input.sample_rate,
+            channels: input.channels,
+        };
+        let processor = dsp.create_processor(spec)?;
+        if let Some(config_json) = config_json {
+            dsp.apply_config_update_json(processor, config_json)?;
+        }
+        let output_channels = dsp.output_channels(processor)?;
+        let block = chunk_frames.max(1) * usize::from(input.channels.max(1));
+        let mut samples = Vec::with_capacity(input.samples.len());
+        for chunk in input.samples.chunks(block) {
+            let out =
+                dsp.process_interleaved_f32(processor, input.channels, samples_to_f32le(chunk))?;
+            samples.extend(f32le_to_samples(&out));
+        }
+        dsp.close_processor(processor)?;
+        Ok(WavAudio {
+            sample_rate: input.sample_rate,
+            channels: output_channels,
+            samples,
+        })
+    }
+
+    pub fn lyrics_search(
+        &self,
+        type_id: &str,
+        keyword: &str,
+    ) -> Result<Vec<RuntimeLyricCandidate>> {
+        let mut lyrics = self
+            .controller
+            .create_lyrics_plugin(&self.info.id, type_id)?;
+        Ok(lyrics.search(keyword)?)
+    }
+
+    pub fn lyrics_fetch(&self, type_id: &str, lyric_id: &str) -> Result<String> {
+        let mut lyrics = self
+            .controller
+            .create_lyrics_plugin(&self.info.id, type_id)?;
+        Ok(lyrics.fetch(lyric_id)?)
+    }
+
+    pub fn source_browse(
+        &self,
+        type_id: &str,
+        config_json: Option<&str>,
+        parent_id: Option<&str>,
+        page: &RuntimePageRequest,
+    ) -> Result<RuntimeBrowsePage> {
+        self.with_source(type_id, config_json, |source| {
+            Ok(source.browse(parent_id, page)?)
+        })
+    }
+
+    pub fn source_search(
+        &self,
+        type_id: &str,
+        config_json: Option<&str>,
+        request: &RuntimeSearchRequest,
+    ) -> Result<RuntimeBrowsePage> {
+        self.with_source(type_id, config_json, |source| Ok(source.search(request)?))
+    }
+
+    /// Opens `uri` on the source and reads the encoded stream to the end.
+    pub fn source_read_uri(
+        &self,
+        type_id: &str,
+        config_json: Option<&str>,
+        uri: &str,
+    ) -> Result<Vec<u8>> {
+        self.with_source(type_id, config_json, |source| {
+            let mut bytes = Vec::new();
+            match source.open_uri(uri)?.handle {
+                RuntimeOpenedSourceStreamHandle::Passthrough(mut stream) => {
+                    loop {
+                        let chunk = stream.read(SOURCE_READ_BYTES)?;
+                        if chunk.is_empty() {
+                            break;
+                        }
+                        bytes.extend_from_slice(&chunk);
+                    }
+                    stream.close();
+                },
+                RuntimeOpenedSourceStreamHandle::Processed(stream) => {
+                    loop {
+                        let chunk = source.read(stream, SOURCE_READ_BYTES)?;
+                        bytes.extend_from_slice(&chunk.bytes);
+                        if chunk.eof {
+                            break;
+                        }
+                    }
+                    source.close_stream(stream)?;
+                },
+            }
+            Ok(bytes)
+        })
+    }
+
+    /// Writes `audio` to the sink target and reports the status after a flush. With
+    /// `target_json` unset, the first target the sink lists is used.
+    pub fn sink_write(
+        &self,
+        type_id: &str,
+        config_json: Option<&str>,
+        target_json: Option<&str>,
+        audio: &WavAudio,
+        chunk_frames: usize,
+    ) -> Result<SinkReport> {
+        let mut sink = self
+            .controller
+            .create_output_sink_plugin(&self.info.id, type_id)?;
+        if let Some(config_json) = config_json {
+            sink.apply_config_update_json(config_json)?;
+        }
+        let target_json = match target_json {
+            Some(target_json) => target_json.to_string(),
+            None => first_target_json(&sink.list_targets_json()?)?,
+        };
+        let spec = RuntimeAudioSpec {
+            sample_rate: audio.sample_rate,
+            channels: audio.channels,
+        };
+        sink.open_json(&target_json, spec)?;
+
+        let channels = usize::from(audio.channels.max(1));
+        let block = chunk_frames.max(1) * channels;
+        let mut offset = 0;
+        let mut stalled_since: Option<Instant> = None;
+        while offset < audio.samples.len() {
+            let end = (offset + block).min(audio.samples.len());
+            let accepted = sink.write_interleaved_f32(
+                audio.channels,
+                samples_to_f32le(&audio.samples[offset..end]),
+            )? as usize;
+            if accepted == 0 {
+                let started = *stalled_since.get_or_insert_with(Instant::now);
+                if started.elapsed() >= SINK_STALL_TIMEOUT {
+                    bail!("output sink accepted no frames for {SINK_STALL_TIMEOUT:?}");
+                }
+                std::thread::sleep(SINK_RETRY_SLEEP);
+                continue;
+            }
+            stalled_since = None;
+            offset = (offset + accepted * channels).min(audio.samples.len());
+        }
+        sink.flush()?;
+        let status = sink.query_status()?;
+        sink.close()?;
+        Ok(SinkReport {
+            frames_written: (audio.samples.len() / channels) as u64,
+            status,
+        })
+    }
+
+    fn with_source<T>(
+        &self,
+        type_id: &str,
+        config_json: Option<&str>,
+        call: impl FnOnce(&mut WasmtimeSourcePlugin) -> Result<T>,
+    ) -> Result<T> {
+        let mut source = self
+            .controller
+            .create_source_plugin(&self.info.id, type_id)?;
+        if let Some(config_json) = config_json {
+            source.apply_config_update_json(config_json)?;
+        }
+        call(&mut source)
+    }
+}
+
+impl Drop for PluginHarness {
+    fn drop(&mut self) {
+        let _ = self
+            .controller
+            .uninstall_plugin(&self.info.id, PluginDisableReason::Shutdown);
+    }
+}
+
+fn first_target_json(targets_json: &str) -> Result<String> {
+    let targets = serde_json::from_str::<serde_json::Value>(targets_json)
+        .context("parse output sink targets")?;
+    let first = targets
+        .as_array()
+        .and_then(|targets| targets.first())
+        .ok_or_else(|| anyhow!("output sink lists no targets"))?;
+    Ok(first.to_string())
+}
+
+pub(crate) fn f32le_to_samples(bytes: &[u8]) -> impl Iterator<Item = f32> + '_ {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+pub(crate) fn samples_to_f32le(samples: &[f32]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect()
+}
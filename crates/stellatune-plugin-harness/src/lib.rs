@@ -0,0 +1,13 @@
+//! Headless harness for running built plugin packages against mock hosts.
+//!
+//! [`PluginHarness`] installs a package directory into its own
+//! `WasmtimePluginController`, wired to the scriptable hosts in [`mock`], and drives
+//! the decoder, DSP, source, lyrics and output-sink APIs from plain Rust. The
+//! `stellatune-plugin-harness` binary wraps the same drivers for plugin CI.
+
+mod harness;
+pub mod mock;
+pub mod signal;
+pub mod wav;
+
+pub use harness::{DecodedAudio, MockHosts, PluginHarness, SinkReport};
@@ -0,0 +1,356 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
+use clap::{Args, Parser, Subcommand};
+use serde_json::json;
+use stellatune_plugin_harness::signal::{peak, rms, sine};
+use stellatune_plugin_harness::wav::{WavAudio, read_wav, write_wav_f32};
+use stellatune_plugin_harness::{MockHosts, PluginHarness};
+use stellatune_plugins::manifest::AbilityKind;
+use stellatune_plugins::runtime::model::{RuntimePageRequest, RuntimeSearchRequest};
+
+#[derive(Debug, Parser)]
+#[command(name = "stellatune-plugin-harness")]
+#[command(about = "Run a built Stellatune plugin package against mock hosts")]
+struct Cli {
+    #[command(flatten)]
+    plugin: PluginArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Args)]
+struct PluginArgs {
+    /// Plugin package directory containing `plugin.json`.
+    #[arg(long, global = true)]
+    plugin: Option<PathBuf>,
+
+    /// Capability type id; may be omitted when the plugin has one of the kind.
+    #[arg(long, global = true)]
+    type_id: Option<String>,
+
+    /// Instance config JSON applied before the run.
+    #[arg(long, global = true)]
+    config: Option<String>,
+
+    /// Serve `URL=FILE` to `fetch_json` calls. Repeatable.
+    #[arg(long = "http-fixture", global = true, value_name = "URL=FILE")]
+    http_fixtures: Vec<String>,
+
+    /// Serve `TARGET=FILE` to host stream opens. Repeatable.
+    #[arg(long = "stream-fixture", global = true, value_name = "TARGET=FILE")]
+    stream_fixtures: Vec<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the plugin info and declared capabilities.
+    Info,
+    /// Decode a file and optionally write it as a float WAV.
+    Decode {
+        input: PathBuf,
+        #[arg(long)]
+        output: Option<PathBuf>,
+        #[arg(long)]
+        expect_sample_rate: Option<u32>,
+        #[arg(long)]
+        expect_channels: Option<u16>,
+        #[arg(long)]
+        min_frames: Option<u64>,
+    },
+    /// Run a DSP over a WAV file or a generated sine.
+    Dsp {
+        #[command(flatten)]
+        signal: SignalArgs,
+        #[arg(long, default_value_t = 1024)]
+        chunk_frames: usize,
+        #[arg(long)]
+        output: Option<PathBuf>,
+        #[arg(long)]
+        expect_rms_min: Option<f32>,
+        #[arg(long)]
+        expect_rms_max: Option<f32>,
+    },
+    /// Search lyrics and optionally fetch the first candidate.
+    Lyrics {
+        keyword: String,
+        #[arg(long)]
+        fetch_first: bool,
+        #[arg(long)]
+        min_candidates: Option<usize>,
+    },
+    /// Browse a source catalog node; the root when no parent is given.
+    Browse {
+        #[arg(long)]
+        parent: Option<String>,
+        #[arg(long)]
+        cursor: Option<String>,
+        #[arg(long, default_value_t = 50)]
+        limit: u32,
+        #[arg(long)]
+        min_nodes: Option<usize>,
+    },
+    /// Search a source catalog.
+    Search {
+        query: String,
+        #[arg(long, default_value_t = 50)]
+        limit: u32,
+        #[arg(long)]
+        min_nodes: Option<usize>,
+    },
+    /// Open a source URI and read the encoded stream to the end.
+    ReadUri {
+        uri: String,
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Write a WAV file or a generated sine to an output sink.
+    Sink {
+        #[command(flatten)]
+        signal: SignalArgs,
+        /// Target JSON; the first listed target when omitted.
+        #[arg(long)]
+        target: Option<String>,
+        #[arg(long, default_value_t = 1024)]
+        chunk_frames: usize,
+    },
+}
+
+#[derive(Debug, Args)]
+struct SignalArgs {
+    /// Input WAV; a sine is generated when omitted.
+    #[arg(long)]
+    input: Option<PathBuf>,
+    #[arg(long, default_value_t = 1_000.0)]
+    sine_hz: f32,
+    #[arg(long, default_value_t = 0.5)]
+    amplitude: f32,
+    #[arg(long, default_value_t = 48_000)]
+    sample_rate: u32,
+    #[arg(long, default_value_t = 2)]
+    channels: u16,
+    #[arg(long, default_value_t = 1.0)]
+    seconds: f32,
+}
+
+impl SignalArgs {
+    fn load(&self) -> Result<WavAudio> {
+        match &self.input {
+            Some(path) => read_wav(path),
+            None => Ok(sine(
+                self.sample_rate,
+                self.channels,
+                self.sine_hz,
+                self.amplitude,
+                (self.sample_rate as f32 * self.seconds) as usize,
+            )),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let plugin_dir = cli
+        .plugin
+        .plugin
+        .as_deref()
+        .ok_or_else(|| anyhow!("--plugin is required"))?;
+    let hosts = mock_hosts(&cli.plugin)?;
+    let harness = PluginHarness::load_with_hosts(plugin_dir, hosts)?;
+    let type_id = |kind| harness.resolve_type_id(kind, cli.plugin.type_id.as_deref());
+    let config = cli.plugin.config.as_deref();
+
+    let report = match cli.command {
+        Command::Info => json!({
+            "plugin": harness.info(),
+            "capabilities": harness.capabilities(),
+        }),
+        Command::Decode {
+            input,
+            output,
+            expect_sample_rate,
+            expect_channels,
+            min_frames,
+        } => {
+            let decoded = harness.decode_file(&type_id(AbilityKind::Decoder)?, &input)?;
+            let frames = decoded.audio.frames() as u64;
+            if let Some(path) = &output {
+                write_wav_f32(path, &decoded.audio)?;
+            }
+            expect_eq("sample rate", expect_sample_rate, decoded.info.sample_rate)?;
+            expect_eq("channels", expect_channels, decoded.info.channels)?;
+            if let Some(min_frames) = min_frames
+                && frames < min_frames
+            {
+                bail!("decoded {frames} frames, expected at least {min_frames}");
+            }
+            json!({
+                "info": decoded.info,
+                "metadata": decoded.metadata,
+                "frames": frames,
+                "peak": peak(&decoded.audio.samples),
+                "rms": rms(&decoded.audio.samples),
+            })
+        },
+        Command::Dsp {
+            signal,
+            chunk_frames,
+            output,
+            expect_rms_min,
+            expect_rms_max,
+        } => {
+            let input = signal.load()?;
+            let out = harness.run_dsp(&type_id(AbilityKind::Dsp)?, config, &input, chunk_frames)?;
+            if let Some(path) = &output {
+                write_wav_f32(path, &out)?;
+            }
+            let out_rms = rms(&out.samples);
+            if expect_rms_min.is_some_and(|min| out_rms < min)
+                || expect_rms_max.is_some_and(|max| out_rms > max)
+            {
+                bail!(
+                    "output rms {out_rms} outside [{}, {}]",
+                    expect_rms_min.unwrap_or(f32::NEG_INFINITY),
+                    expect_rms_max.unwrap_or(f32::INFINITY)
+                );
+            }
+            json!({
+                "input_rms": rms(&input.samples),
+                "output_rms": out_rms,
+                "output_peak": peak(&out.samples),
+                "output_channels": out.channels,
+                "output_frames": out.frames(),
+            })
+        },
+        Command::Lyrics {
+            keyword,
+            fetch_first,
+            min_candidates,
+        } => {
+            let type_id = type_id(AbilityKind::Lyrics)?;
+            let candidates = harness.lyrics_search(&type_id, &keyword)?;
+            expect_min("lyrics candidates", min_candidates, candidates.len())?;
+            let fetched = match candidates.first() {
+                Some(first) if fetch_first => Some(harness.lyrics_fetch(&type_id, &first.id)?),
+                _ => None,
+            };
+            json!({ "candidates": candidates, "fetched": fetched })
+        },
+        Command::Browse {
+            parent,
+            cursor,
+            limit,
+            min_nodes,
+        } => {
+            let page = harness.source_browse(
+                &type_id(AbilityKind::Source)?,
+                config,
+                parent.as_deref(),
+                &RuntimePageRequest { cursor, limit },
+            )?;
+            expect_min("browse nodes", min_nodes, page.nodes.len())?;
+            serde_json::to_value(page)?
+        },
+        Command::Search {
+            query,
+            limit,
+            min_nodes,
+        } => {
+            let request = RuntimeSearchRequest {
+                query,
+                kinds: Vec::new(),
+                page: RuntimePageRequest {
+                    cursor: None,
+                    limit,
+                },
+            };
+            let page = harness.source_search(&type_id(AbilityKind::Source)?, config, &request)?;
+            expect_min("search nodes", min_nodes, page.nodes.len())?;
+            serde_json::to_value(page)?
+        },
+        Command::ReadUri { uri, output } => {
+            let bytes = harness.source_read_uri(&type_id(AbilityKind::Source)?, config, &uri)?;
+            if let Some(path) = &output {
+                std::fs::write(path, &bytes)
+                    .with_context(|| format!("write {}", path.display()))?;
+            }
+            json!({ "bytes": bytes.len() })
+        },
+        Command::Sink {
+            signal,
+            target,
+            chunk_frames,
+        } => {
+            let audio = signal.load()?;
+            let report = harness.sink_write(
+                &type_id(AbilityKind::OutputSink)?,
+                config,
+                target.as_deref(),
+                &audio,
+                chunk_frames,
+            )?;
+            json!({ "frames_written": report.frames_written, "status": report.status })
+        },
+    };
+    report_requests(&harness, report)
+}
+
+fn mock_hosts(args: &PluginArgs) -> Result<MockHosts> {
+    let hosts = MockHosts::default();
+    for fixture in &args.http_fixtures {
+        let (url, path) = split_fixture(fixture)?;
+        let body =
+            std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+        hosts.http.respond_json(url, body);
+    }
+    for fixture in &args.stream_fixtures {
+        let (target, path) = split_fixture(fixture)?;
+        hosts.streams.serve_file(target, path);
+    }
+    Ok(hosts)
+}
+
+fn split_fixture(fixture: &str) -> Result<(&str, &Path)> {
+    // URLs contain `=` in query strings, so split on the last one.
+    let (key, path) = fixture
+        .rsplit_once('=')
+        .ok_or_else(|| anyhow!("fixture `{fixture}` is not KEY=FILE"))?;
+    Ok((key, Path::new(path)))
+}
+
+fn report_requests(harness: &PluginHarness, result: serde_json::Value) -> Result<()> {
+    let hosts = harness.hosts();
+    let stream_targets = hosts
+        .streams
+        .requests()
+        .into_iter()
+        .map(|request| request.target)
+        .collect::<Vec<_>>();
+    let report = json!({
+        "result": result,
+        "http_requests": hosts.http.requests(),
+        "stream_requests": stream_targets,
+        "sidecar_launches": hosts.sidecars.launches().len(),
+    });
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+fn expect_eq<T: PartialEq + std::fmt::Display>(
+    what: &str,
+    expected: Option<T>,
+    actual: T,
+) -> Result<()> {
+    match expected {
+        Some(expected) if expected != actual => bail!("{what} is {actual}, expected {expected}"),
+        _ => Ok(()),
+    }
+}
+
+fn expect_min(what: &str, min: Option<usize>, actual: usize) -> Result<()> {
+    match min {
+        Some(min) if actual < min => bail!("got {actual} {what}, expected at least {min}"),
+        _ => Ok(()),
+    }
+}
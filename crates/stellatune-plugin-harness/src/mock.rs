@@ -0,0 +1,393 @@
+//! Scriptable stand-ins for the host services a plugin can reach.
+//!
+//! Every mock answers only what it was told to and records what the plugin asked for,
+//! so a test can assert on both the plugin output and the requests behind it.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use stellatune_plugins::error::{Error, Result};
+use stellatune_plugins::host::http::{
+    HttpAllowlist, HttpClientHost, HttpHeader, HttpRequest, HttpResponse, HttpResponseBody,
+};
+use stellatune_plugins::host::sidecar::{
+    SidecarChannelHandle, SidecarHost, SidecarLaunchSpec, SidecarProcessHandle,
+    SidecarTransportKind, SidecarTransportOption,
+};
+use stellatune_plugins::host::stream::{
+    HostStreamHandle, HostStreamOpenRequest, HostStreamService, StreamSeekWhence,
+    open_local_file_stream,
+};
+
+/// Channel role used for the sidecar control channel in [`MockSidecarHost`] scripts.
+pub const CONTROL_CHANNEL: &str = "control";
+
+/// Answers `fetch_json` and `send` from a fixed URL → body table. `send` serves a fixture
+/// as a `200` response and ignores the request method, headers and body.
+#[derive(Default)]
+pub struct MockHttpClient {
+    responses: Mutex<BTreeMap<String, std::result::Result<String, String>>>,
+    requests: Mutex<Vec<String>>,
+}
+
+impl MockHttpClient {
+    pub fn respond_json(&self, url: impl Into<String>, body: impl Into<String>) -> &Self {
+        self.responses.lock().insert(url.into(), Ok(body.into()));
+        self
+    }
+
+    pub fn respond_error(&self, url: impl Into<String>, message: impl Into<String>) -> &Self {
+        self.responses
+            .lock()
+            .insert(url.into(), Err(message.into()));
+        self
+    }
+
+    /// URLs fetched so far, in call order.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().clone()
+    }
+}
+
+impl HttpClientHost for MockHttpClient {
+    fn fetch_json(&self, url: &str, _allowlist: &HttpAllowlist) -> Result<String> {
+        self.requests.lock().push(url.to_string());
+        match self.responses.lock().get(url) {
+            Some(Ok(body)) => Ok(body.clone()),
+            Some(Err(message)) => Err(Error::operation("fetch_json", message.clone())),
+            None => Err(Error::not_found("mock http response", url)),
+        }
+    }
+
+    fn send(&self, request: &HttpRequest, allowlist: &HttpAllowlist) -> Result<HttpResponse> {
+        let body = self.fetch_json(&request.url, allowlist)?;
+        Ok(HttpResponse {
+            status: 200,
+            headers: vec![HttpHeader {
+                name: "content-length".to_string(),
+                value: body.len().to_string(),
+            }],
+            body: Box::new(MockResponseBody(Cursor::new(body.into_bytes()))),
+        })
+    }
+}
+
+struct MockResponseBody(Cursor<Vec<u8>>);
+
+impl HttpResponseBody for MockResponseBody {
+    fn read(&mut self, max_bytes: u32) -> Result<Vec<u8>> {
+        let mut chunk = vec![0; max_bytes.max(1) as usize];
+        let read = self
+            .0
+            .read(&mut chunk)
+            .map_err(|e| Error::operation("http body read", e.to_string()))?;
+        chunk.truncate(read);
+        Ok(chunk)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum StreamFixture {
+    Bytes(Arc<[u8]>),
+    File(PathBuf),
+    Error(String),
+}
+
+/// Serves `open` requests from in-memory bytes or local files keyed by target.
+#[derive(Default)]
+pub struct MockStreamService {
+    fixtures: Mutex<BTreeMap<String, StreamFixture>>,
+    requests: Mutex<Vec<HostStreamOpenRequest>>,
+}
+
+impl MockStreamService {
+    pub fn serve_bytes(&self, target: impl Into<String>, bytes: Vec<u8>) -> &Self {
+        self.fixtures
+            .lock()
+            .insert(target.into(), StreamFixture::Bytes(Arc::from(bytes)));
+        self
+    }
+
+    pub fn serve_file(&self, target: impl Into<String>, path: impl Into<PathBuf>) -> &Self {
+        self.fixtures
+            .lock()
+            .insert(target.into(), StreamFixture::File(path.into()));
+        self
+    }
+
+    pub fn serve_error(&self, target: impl Into<String>, message: impl Into<String>) -> &Self {
+        self.fixtures
+            .lock()
+            .insert(target.into(), StreamFixture::Error(message.into()));
+        self
+    }
+
+    /// Open requests received so far, in call order.
+    pub fn requests(&self) -> Vec<HostStreamOpenRequest> {
+        self.requests.lock().clone()
+    }
+}
+
+impl HostStreamService for MockStreamService {
+    fn open(&self, request: &HostStreamOpenRequest) -> Result<Box<dyn HostStreamHandle>> {
+        self.requests.lock().push(request.clone());
+        let fixture = self.fixtures.lock().get(request.target.trim()).cloned();
+        match fixture {
+            Some(StreamFixture::Bytes(bytes)) => Ok(Box::new(MemoryStream {
+                cursor: Cursor::new(bytes),
+            })),
+            Some(StreamFixture::File(path)) => open_local_file_stream(&path),
+            Some(StreamFixture::Error(message)) => Err(Error::operation("stream open", message)),
+            None => Err(Error::not_found("mock stream", request.target.clone())),
+        }
+    }
+}
+
+struct MemoryStream {
+    cursor: Cursor<Arc<[u8]>>,
+}
+
+impl HostStreamHandle for MemoryStream {
+    fn read(&mut self, max_bytes: u32) -> Result<Vec<u8>> {
+        let mut out = vec![0u8; max_bytes as usize];
+        let read = self
+            .cursor
+            .read(&mut out)
+            .map_err(|e| Error::operation("stream read", e.to_string()))?;
+        out.truncate(read);
+        Ok(out)
+    }
+
+    fn seek(&mut self, offset: i64, whence: StreamSeekWhence) -> Result<u64> {
+        let target = match whence {
+            StreamSeekWhence::Start => SeekFrom::Start(
+                u64::try_from(offset).map_err(|_| Error::invalid_input("negative seek"))?,
+            ),
+            StreamSeekWhence::Current => SeekFrom::Current(offset),
+            StreamSeekWhence::End => SeekFrom::End(offset),
+        };
+        self.cursor
+            .seek(target)
+            .map_err(|e| Error::operation("stream seek", e.to_string()))
+    }
+
+    fn tell(&mut self) -> Result<u64> {
+        Ok(self.cursor.position())
+    }
+
+    fn size(&mut self) -> Result<u64> {
+        Ok(self.cursor.get_ref().len() as u64)
+    }
+}
+
+#[derive(Default)]
+struct ChannelScript {
+    replies: VecDeque<Vec<u8>>,
+    writes: Vec<Vec<u8>>,
+}
+
+/// Pretends to launch sidecars; each channel role replays scripted replies.
+///
+/// Reads pop one reply per call and return empty once the script runs out. Writes
+/// are recorded per role and shared by every process the plugin launches.
+#[derive(Default)]
+pub struct MockSidecarHost {
+    scripts: Arc<Mutex<BTreeMap<String, ChannelScript>>>,
+    launches: Mutex<Vec<SidecarLaunchSpec>>,
+}
+
+impl MockSidecarHost {
+    /// Queues `reply` on channel `role`; use [`CONTROL_CHANNEL`] for the control channel.
+    pub fn push_reply(&self, role: impl Into<String>, reply: impl Into<Vec<u8>>) -> &Self {
+        self.scripts
+            .lock()
+            .entry(role.into())
+            .or_default()
+            .replies
+            .push_back(reply.into());
+        self
+    }
+
+    pub fn writes(&self, role: &str) -> Vec<Vec<u8>> {
+        self.scripts
+            .lock()
+            .get(role)
+            .map(|script| script.writes.clone())
+            .unwrap_or_default()
+    }
+
+    /// Launch specs received so far, in call order.
+    pub fn launches(&self) -> Vec<SidecarLaunchSpec> {
+        self.launches.lock().clone()
+    }
+}
+
+impl SidecarHost for MockSidecarHost {
+    fn launch(&self, spec: &SidecarLaunchSpec) -> Result<Box<dyn SidecarProcessHandle>> {
+        self.launches.lock().push(spec.clone());
+        Ok(Box::new(MockProcess {
+            scripts: Arc::clone(&self.scripts),
+            terminated: false,
+        }))
+    }
+}
+
+struct MockProcess {
+    scripts: Arc<Mutex<BTreeMap<String, ChannelScript>>>,
+    terminated: bool,
+}
+
+impl MockProcess {
+    fn channel(
+        &self,
+        role: &str,
+        transport: SidecarTransportKind,
+    ) -> Box<dyn SidecarChannelHandle> {
+        self.scripts.lock().entry(role.to_string()).or_default();
+        Box::new(MockChannel {
+            scripts: Arc::clone(&self.scripts),
+            role: role.to_string(),
+            transport,
+        })
+    }
+}
+
+impl SidecarProcessHandle for MockProcess {
+    fn open_control(&mut self) -> Result<Box<dyn SidecarChannelHandle>> {
+        Ok(self.channel(CONTROL_CHANNEL, SidecarTransportKind::Stdio))
+    }
+
+    fn open_data(
+        &mut self,
+        role: &str,
+        preferred: &[SidecarTransportOption],
+    ) -> Result<Box<dyn SidecarChannelHandle>> {
+        let transport = preferred
+            .iter()
+            .max_by_key(|option| option.priority)
+            .map_or(SidecarTransportKind::Stdio, |option| option.kind);
+        Ok(self.channel(role, transport))
+    }
+
+    fn wait_exit(&mut self, _timeout_ms: Option<u32>) -> Result<Option<i32>> {
+        Ok(self.terminated.then_some(0))
+    }
+
+    fn terminate(&mut self, _grace_ms: u32) -> Result<()> {
+        self.terminated = true;
+        Ok(())
+    }
+}
+
+struct MockChannel {
+    scripts: Arc<Mutex<BTreeMap<String, ChannelScript>>>,
+    role: String,
+    transport: SidecarTransportKind,
+}
+
+impl SidecarChannelHandle for MockChannel {
+    fn transport(&self) -> SidecarTransportKind {
+        self.transport
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<u32> {
+        let mut scripts = self.scripts.lock();
+        let script = scripts.entry(self.role.clone()).or_default();
+        script.writes.push(data.to_vec());
+        Ok(data.len() as u32)
+    }
+
+    fn read(&mut self, max_bytes: u32, _timeout_ms: Option<u32>) -> Result<Vec<u8>> {
+        let mut scripts = self.scripts.lock();
+        let Some(script) = scripts.get_mut(&self.role) else {
+            return Ok(Vec::new());
+        };
+        let Some(mut reply) = script.replies.pop_front() else {
+            return Ok(Vec::new());
+        };
+        let max_bytes = max_bytes as usize;
+        if reply.len() > max_bytes {
+            script.replies.push_front(reply.split_off(max_bytes));
+        }
+        Ok(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stellatune_plugins::host::http::{HttpAllowlist, HttpClientHost, HttpRequest};
+    use stellatune_plugins::host::sidecar::{SidecarHost, SidecarLaunchScope, SidecarLaunchSpec};
+    use stellatune_plugins::host::stream::{
+        HostStreamOpenRequest, HostStreamService, StreamSeekWhence,
+    };
+
+    use super::{CONTROL_CHANNEL, MockHttpClient, MockSidecarHost, MockStreamService};
+
+    #[test]
+    fn http_and_stream_mocks_serve_fixtures_and_record_requests() {
+        let http = MockHttpClient::default();
+        let allowlist = HttpAllowlist::default();
+        http.respond_json("https://api.test/a", r#"{"ok":true}"#);
+        assert_eq!(
+            http.fetch_json("https://api.test/a", &allowlist)
+                .expect("fixture"),
+            r#"{"ok":true}"#
+        );
+        assert!(
+            http.fetch_json("https://api.test/missing", &allowlist)
+                .is_err()
+        );
+        let mut response = http
+            .send(&HttpRequest::get("https://api.test/a"), &allowlist)
+            .expect("send fixture");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body.read(4).expect("read body"), br#"{"ok"#);
+        assert_eq!(response.body.read(64).expect("read body"), br#"":true}"#);
+        assert!(response.body.read(64).expect("read body").is_empty());
+        assert_eq!(http.requests().len(), 3);
+
+        let streams = MockStreamService::default();
+        streams.serve_bytes("mem://track", b"0123456789".to_vec());
+        let mut stream = streams
+            .open(&HostStreamOpenRequest::http("mem://track"))
+            .expect("open");
+        assert_eq!(stream.size().expect("size"), 10);
+        stream.seek(-4, StreamSeekWhence::End).expect("seek");
+        assert_eq!(stream.read(16).expect("read"), b"6789");
+        assert!(
+            streams
+                .open(&HostStreamOpenRequest::file("missing"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn sidecar_mock_replays_replies_in_chunks() {
+        let host = MockSidecarHost::default();
+        host.push_reply(CONTROL_CHANNEL, b"hello".to_vec());
+        let spec = SidecarLaunchSpec {
+            scope: SidecarLaunchScope::Instance,
+            executable: "bin/helper".to_string(),
+            args: Vec::new(),
+            preferred_control: Vec::new(),
+            preferred_data: Vec::new(),
+            env: Vec::new(),
+            supervision: None,
+        };
+        let mut process = host.launch(&spec).expect("launch");
+        let mut control = process.open_control().expect("control");
+        control.write(b"ping").expect("write");
+        assert_eq!(control.read(3, None).expect("read"), b"hel");
+        assert_eq!(control.read(16, None).expect("read"), b"lo");
+        assert!(control.read(16, None).expect("read").is_empty());
+
+        assert_eq!(host.writes(CONTROL_CHANNEL), vec![b"ping".to_vec()]);
+        assert_eq!(host.launches(), vec![spec]);
+        assert_eq!(process.wait_exit(None).expect("wait"), None);
+        process.terminate(0).expect("terminate");
+        assert_eq!(process.wait_exit(None).expect("wait"), Some(0));
+    }
+}
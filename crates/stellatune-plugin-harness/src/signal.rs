@@ -0,0 +1,63 @@
+//! Test signals and the level measurements harness checks assert on.
+
+use crate::wav::WavAudio;
+
+/// A sine at `frequency_hz` with the same samples on every channel.
+pub fn sine(
+    sample_rate: u32,
+    channels: u16,
+    frequency_hz: f32,
+    amplitude: f32,
+    frames: usize,
+) -> WavAudio {
+    let channels_usize = usize::from(channels.max(1));
+    let step = std::f32::consts::TAU * frequency_hz / sample_rate.max(1) as f32;
+    let mut samples = Vec::with_capacity(frames * channels_usize);
+    for frame in 0..frames {
+        let value = amplitude * (step * frame as f32).sin();
+        samples.extend(std::iter::repeat_n(value, channels_usize));
+    }
+    WavAudio {
+        sample_rate,
+        channels,
+        samples,
+    }
+}
+
+pub fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum = samples
+        .iter()
+        .map(|&sample| f64::from(sample) * f64::from(sample))
+        .sum::<f64>();
+    (sum / samples.len() as f64).sqrt() as f32
+}
+
+pub fn peak(samples: &[f32]) -> f32 {
+    samples
+        .iter()
+        .fold(0.0_f32, |peak, &sample| peak.max(sample.abs()))
+}
+
+/// Level in dBFS for a linear amplitude; silence reports `f32::NEG_INFINITY`.
+pub fn to_dbfs(level: f32) -> f32 {
+    20.0 * level.log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{peak, rms, sine, to_dbfs};
+
+    #[test]
+    fn sine_levels_match_theory() {
+        let audio = sine(48_000, 2, 1_000.0, 0.5, 48_000);
+        assert_eq!(audio.frames(), 48_000);
+        assert_eq!(audio.samples[0], audio.samples[1]);
+        assert!((peak(&audio.samples) - 0.5).abs() < 1e-3);
+        assert!((rms(&audio.samples) - 0.5 / 2f32.sqrt()).abs() < 1e-3);
+        assert!((to_dbfs(0.5) + 6.02).abs() < 0.01);
+        assert_eq!(rms(&[]), 0.0);
+    }
+}
@@ -0,0 +1,178 @@
+//! Minimal RIFF/WAVE reader and writer for harness fixtures and outputs.
+//!
+//! Output is always 32-bit float. Input accepts 16/24/32-bit integer PCM and 32-bit
+//! float, which covers test signals exported by common tools.
+
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow, bail};
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_IEEE_FLOAT: u16 = 3;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WavAudio {
+    pub sample_rate: u32,
+    pub channels: u16,
+    /// Interleaved samples in `-1.0..=1.0`.
+    pub samples: Vec<f32>,
+}
+
+impl WavAudio {
+    pub fn frames(&self) -> usize {
+        self.samples.len() / usize::from(self.channels.max(1))
+    }
+}
+
+pub fn write_wav_f32(path: &Path, audio: &WavAudio) -> Result<()> {
+    let bytes = encode_wav_f32(audio)?;
+    std::fs::write(path, bytes).with_context(|| format!("write {}", path.display()))
+}
+
+pub fn encode_wav_f32(audio: &WavAudio) -> Result<Vec<u8>> {
+    if audio.channels == 0 {
+        bail!("wav needs at least one channel");
+    }
+    let data_len =
+        u32::try_from(audio.samples.len() * 4).map_err(|_| anyhow!("wav data exceeds 4 GiB"))?;
+    let block_align = audio.channels * 4;
+    let mut out = Vec::with_capacity(44 + data_len as usize);
+    out.write_all(b"RIFF")?;
+    out.write_all(&(36 + data_len).to_le_bytes())?;
+    out.write_all(b"WAVEfmt ")?;
+    out.write_all(&16u32.to_le_bytes())?;
+    out.write_all(&FORMAT_IEEE_FLOAT.to_le_bytes())?;
+    out.write_all(&audio.channels.to_le_bytes())?;
+    out.write_all(&audio.sample_rate.to_le_bytes())?;
+    out.write_all(&(audio.sample_rate * u32::from(block_align)).to_le_bytes())?;
+    out.write_all(&block_align.to_le_bytes())?;
+    out.write_all(&32u16.to_le_bytes())?;
+    out.write_all(b"data")?;
+    out.write_all(&data_len.to_le_bytes())?;
+    for sample in &audio.samples {
+        out.write_all(&sample.to_le_bytes())?;
+    }
+    Ok(out)
+}
+
+pub fn read_wav(path: &Path) -> Result<WavAudio> {
+    let bytes = std::fs::read(path).with_context(|| format!("read {}", path.display()))?;
+    decode_wav(&bytes).with_context(|| format!("parse {}", path.display()))
+}
+
+pub fn decode_wav(bytes: &[u8]) -> Result<WavAudio> {
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        bail!("not a RIFF/WAVE file");
+    }
+    let mut format = None;
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let id = &bytes[pos..pos + 4];
+        let len = u32::from_le_bytes(bytes[pos + 4..pos + 8].try_into()?) as usize;
+        let body_start = pos + 8;
+        let body = bytes
+            .get(body_start..body_start + len)
+            .unwrap_or(&bytes[body_start..]);
+        match id {
+            b"fmt " => format = Some(parse_fmt(body)?),
+            b"data" => {
+                let (tag, channels, sample_rate, bits) =
+                    format.ok_or_else(|| anyhow!("data chunk before fmt chunk"))?;
+                return Ok(WavAudio {
+                    sample_rate,
+                    channels,
+                    samples: decode_samples(body, tag, bits)?,
+                });
+            },
+            _ => {},
+        }
+        // Chunks are padded to an even length.
+        pos = body_start + len + (len & 1);
+    }
+    bail!("missing data chunk")
+}
+
+fn parse_fmt(body: &[u8]) -> Result<(u16, u16, u32, u16)> {
+    if body.len() < 16 {
+        bail!("fmt chunk too short");
+    }
+    let u16_at = |at: usize| u16::from_le_bytes([body[at], body[at + 1]]);
+    let mut tag = u16_at(0);
+    let channels = u16_at(2);
+    let sample_rate = u32::from_le_bytes(body[4..8].try_into()?);
+    let bits = u16_at(14);
+    if tag == FORMAT_EXTENSIBLE {
+        // The sub-format GUID starts with the plain format tag.
+        if body.len() < 26 {
+            bail!("extensible fmt chunk too short");
+        }
+        tag = u16_at(24);
+    }
+    if channels == 0 {
+        bail!("wav has no channels");
+    }
+    Ok((tag, channels, sample_rate, bits))
+}
+
+fn decode_samples(data: &[u8], tag: u16, bits: u16) -> Result<Vec<f32>> {
+    let samples = match (tag, bits) {
+        (FORMAT_IEEE_FLOAT, 32) => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        (FORMAT_PCM, 16) => data
+            .chunks_exact(2)
+            .map(|b| f32::from(i16::from_le_bytes([b[0], b[1]])) / 32_768.0)
+            .collect(),
+        (FORMAT_PCM, 24) => data
+            .chunks_exact(3)
+            .map(|b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / 8_388_608.0)
+            .collect(),
+        (FORMAT_PCM, 32) => data
+            .chunks_exact(4)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0)
+            .collect(),
+        _ => bail!("unsupported wav format tag {tag} with {bits} bits"),
+    };
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WavAudio, decode_wav, encode_wav_f32};
+
+    #[test]
+    fn float_wav_round_trips() {
+        let audio = WavAudio {
+            sample_rate: 48_000,
+            channels: 2,
+            samples: vec![0.0, 0.5, -0.5, 1.0, -1.0, 0.25],
+        };
+        let bytes = encode_wav_f32(&audio).expect("encode");
+        assert_eq!(bytes.len(), 44 + 24);
+        assert_eq!(decode_wav(&bytes).expect("decode"), audio);
+        assert_eq!(audio.frames(), 3);
+    }
+
+    #[test]
+    fn reads_pcm16_after_unknown_chunks() {
+        let mut bytes = b"RIFF\0\0\0\0WAVE".to_vec();
+        bytes.extend_from_slice(b"LIST\x03\0\0\0abc\0");
+        bytes.extend_from_slice(b"fmt \x10\0\0\0");
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&8_000u32.to_le_bytes());
+        bytes.extend_from_slice(&16_000u32.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data\x04\0\0\0");
+        bytes.extend_from_slice(&i16::MIN.to_le_bytes());
+        bytes.extend_from_slice(&16_384i16.to_le_bytes());
+
+        let audio = decode_wav(&bytes).expect("decode");
+        assert_eq!((audio.sample_rate, audio.channels), (8_000, 1));
+        assert_eq!(audio.samples, vec![-1.0, 0.5]);
+    }
+}
@@ -105,8 +105,15 @@ impl SourceStream for ExampleSourceStream {
 impl SourceCatalog for ExampleCatalog {
     type Stream = ExampleSourceStream;
 
-    fn list_items_json(&mut self, _request_json: &str) -> SdkResult<String> {
-        Ok("[]".to_string())
+    fn capabilities(&self) -> CatalogCapabilities {
+        CatalogCapabilities {
+            browse: true,
+            ..CatalogCapabilities::default()
+        }
+    }
+
+    fn browse(&mut self, _parent_id: Option<&str>, _page: PageRequest) -> SdkResult<BrowsePage> {
+        Ok(BrowsePage::default())
     }
 
     fn open_stream_json(&mut self, _track_json: &str) -> SdkResult<Self::Stream> {
@@ -313,4 +320,41 @@ stellatune_plugin_sdk::export_dsp_plugin! {
     display_name: "Example DSP",
 }
 
+pub struct ExamplePlaybackListener;
+pub struct ExampleListener;
+
+impl PluginLifecycle for ExamplePlaybackListener {}
+impl ConfigStateOps for ExampleListener {}
+
+impl PlaybackListener for ExampleListener {
+    fn on_event(&mut self, _event: PlaybackEvent) -> SdkResult<()> {
+        Ok(())
+    }
+}
+
+impl PlaybackListenerPlugin for ExamplePlaybackListener {
+    type Listener = ExampleListener;
+
+    const TYPE_ID: &'static str = "example-playback-listener";
+    const DISPLAY_NAME: &'static str = "Example Playback Listener";
+
+    fn create_listener(&mut self) -> SdkResult<Self::Listener> {
+        Ok(ExampleListener)
+    }
+}
+
+fn create_playback_listener() -> SdkResult<ExamplePlaybackListener> {
+    Ok(ExamplePlaybackListener)
+}
+
+stellatune_plugin_sdk::export_playback_listener_plugin! {
+    export: playback_listener_export,
+    plugin_type: crate::ExamplePlaybackListener,
+    create: crate::create_playback_listener,
+    plugin_id: "dev.stellatune.example.playback-listener",
+    component_id: "playback-listener-main",
+    type_id: "example-playback-listener",
+    display_name: "Example Playback Listener",
+}
+
 fn main() {}
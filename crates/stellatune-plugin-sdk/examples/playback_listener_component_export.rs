@@ -0,0 +1,40 @@
+use stellatune_plugin_sdk::prelude::*;
+
+pub struct ExamplePlaybackListenerPlugin;
+pub struct ExampleListener {
+    finished_tracks: u64,
+}
+
+impl PluginLifecycle for ExamplePlaybackListenerPlugin {}
+
+impl ConfigStateOps for ExampleListener {}
+
+impl PlaybackListener for ExampleListener {
+    fn on_event(&mut self, event: PlaybackEvent) -> SdkResult<()> {
+        if let PlaybackEvent::Finished { .. } = event {
+            self.finished_tracks = self.finished_tracks.saturating_add(1);
+        }
+        Ok(())
+    }
+}
+
+impl PlaybackListenerPlugin for ExamplePlaybackListenerPlugin {
+    type Listener = ExampleListener;
+    const TYPE_ID: &'static str = "playback-listener-example";
+    const DISPLAY_NAME: &'static str = "Playback Listener Export Example";
+
+    fn create_listener(&mut self) -> SdkResult<Self::Listener> {
+        Ok(ExampleListener { finished_tracks: 0 })
+    }
+}
+
+fn create_plugin() -> SdkResult<ExamplePlaybackListenerPlugin> {
+    Ok(ExamplePlaybackListenerPlugin)
+}
+
+stellatune_plugin_sdk::export_playback_listener_component! {
+    plugin_type: crate::ExamplePlaybackListenerPlugin,
+    create: crate::create_plugin,
+}
+
+fn main() {}
@@ -2,12 +2,14 @@ mod decoder;
 mod dsp;
 mod lyrics;
 mod output_sink;
+mod playback_listener;
 mod source;
 
 pub use decoder::*;
 pub use dsp::*;
 pub use lyrics::*;
 pub use output_sink::*;
+pub use playback_listener::*;
 pub use source::*;
 
 use crate::common::ConfigUpdatePlan;
@@ -20,6 +22,7 @@ pub enum AbilityKind {
     Lyrics,
     OutputSink,
     Dsp,
+    PlaybackListener,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,32 @@
+use crate::capabilities::{AbilityDescriptor, AbilityKind, ConfigStateOps};
+use crate::common::PlaybackEvent;
+use crate::error::SdkResult;
+use crate::lifecycle::PluginLifecycle;
+
+pub trait PlaybackListener: ConfigStateOps + Send {
+    fn on_event(&mut self, event: PlaybackEvent) -> SdkResult<()>;
+    fn close(&mut self) -> SdkResult<()> {
+        Ok(())
+    }
+}
+
+pub trait PlaybackListenerPlugin: PluginLifecycle + Send + 'static {
+    type Listener: PlaybackListener;
+
+    const TYPE_ID: &'static str;
+    const DISPLAY_NAME: &'static str;
+    const CONFIG_SCHEMA_JSON: &'static str = "{}";
+    const DEFAULT_CONFIG_JSON: &'static str = "{}";
+
+    fn descriptor() -> AbilityDescriptor {
+        AbilityDescriptor {
+            kind: AbilityKind::PlaybackListener,
+            type_id: Self::TYPE_ID,
+            display_name: Self::DISPLAY_NAME,
+            config_schema_json: Self::CONFIG_SCHEMA_JSON,
+            default_config_json: Self::DEFAULT_CONFIG_JSON,
+        }
+    }
+
+    fn create_listener(&mut self) -> SdkResult<Self::Listener>;
+}
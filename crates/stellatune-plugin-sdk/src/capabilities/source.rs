@@ -1,5 +1,8 @@
 use crate::capabilities::{AbilityDescriptor, AbilityKind, ConfigStateOps};
-use crate::common::{EncodedChunk, MediaMetadata};
+use crate::common::{
+    AuthStatus, BrowsePage, CatalogCapabilities, EncodedChunk, LoginChallenge, LoginMethod,
+    LoginProgress, MediaMetadata, PageRequest, SearchRequest,
+};
 use crate::error::{SdkError, SdkResult};
 use crate::host_stream::HostStreamOpenRequest;
 use crate::lifecycle::PluginLifecycle;
@@ -38,7 +41,48 @@ pub trait SourceStream: Send {
 pub trait SourceCatalog: ConfigStateOps + Send {
     type Stream: SourceStream;
 
-    fn list_items_json(&mut self, request_json: &str) -> SdkResult<String>;
+    /// What the typed catalog calls support; everything is off by default.
+    fn capabilities(&self) -> CatalogCapabilities {
+        CatalogCapabilities::default()
+    }
+
+    /// Children of `parent_id`, or the catalog roots when it is `None`.
+    fn browse(&mut self, _parent_id: Option<&str>, _page: PageRequest) -> SdkResult<BrowsePage> {
+        Err(SdkError::unsupported("browse is unsupported"))
+    }
+
+    fn search(&mut self, _request: SearchRequest) -> SdkResult<BrowsePage> {
+        Err(SdkError::unsupported("search is unsupported"))
+    }
+
+    /// Plugin-specific escape hatch next to `browse`/`search`.
+    fn list_items_json(&mut self, _request_json: &str) -> SdkResult<String> {
+        Err(SdkError::unsupported("list-items-json is unsupported"))
+    }
+
+    /// Catalogs without accounts keep the default; calls that need a session should fail
+    /// with [`SdkError::AuthRequired`] or [`SdkError::AuthExpired`].
+    fn auth_status(&mut self) -> SdkResult<AuthStatus> {
+        Ok(AuthStatus::not_required())
+    }
+
+    /// Starts an interactive login; `None` lets the catalog pick its preferred method.
+    fn begin_login(&mut self, _method: Option<LoginMethod>) -> SdkResult<LoginChallenge> {
+        Err(SdkError::unsupported("login is unsupported"))
+    }
+
+    fn poll_login(&mut self, _session_id: &str) -> SdkResult<LoginProgress> {
+        Err(SdkError::unsupported("login is unsupported"))
+    }
+
+    fn refresh_auth(&mut self) -> SdkResult<AuthStatus> {
+        Err(SdkError::unsupported("auth refresh is unsupported"))
+    }
+
+    fn logout(&mut self) -> SdkResult<()> {
+        Err(SdkError::unsupported("logout is unsupported"))
+    }
+
     fn open_stream_json(&mut self, track_json: &str) -> SdkResult<Self::Stream>;
     fn open_stream_opened_json(
         &mut self,
@@ -167,3 +167,193 @@ pub struct LyricCandidate {
     pub title: String,
     pub artist: String,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrowseNodeKind {
+    Folder,
+    Playlist,
+    Album,
+    Artist,
+    Track,
+}
+
+/// One entry of a source catalog listing. Non-track nodes are browsed by `id`; track
+/// nodes are opened by passing `track_json` to `open-stream-json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BrowseNode {
+    pub kind: BrowseNodeKind,
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub artists: Vec<String>,
+    pub album: Option<String>,
+    pub duration_ms: Option<u64>,
+    pub child_count: Option<u32>,
+    pub artwork_url: Option<String>,
+    pub track_json: Option<String>,
+    pub extras: Vec<MetadataEntry>,
+}
+
+impl BrowseNode {
+    pub fn new(kind: BrowseNodeKind, id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            kind,
+            id: id.into(),
+            title: title.into(),
+            subtitle: None,
+            artists: Vec::new(),
+            album: None,
+            duration_ms: None,
+            child_count: None,
+            artwork_url: None,
+            track_json: None,
+            extras: Vec::new(),
+        }
+    }
+}
+
+/// `cursor` is `None` for the first page, then the previous page's `next_cursor`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageRequest {
+    pub cursor: Option<String>,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct BrowsePage {
+    pub nodes: Vec<BrowseNode>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchRequest {
+    pub query: String,
+    /// Node kinds wanted in the results; empty means any.
+    pub kinds: Vec<BrowseNodeKind>,
+    pub page: PageRequest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct CatalogCapabilities {
+    pub browse: bool,
+    pub search: bool,
+    pub open_uri: bool,
+    pub search_kinds: Vec<BrowseNodeKind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthState {
+    NotRequired,
+    LoggedOut,
+    LoggedIn,
+    Expired,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoginMethod {
+    QrCode,
+    Url,
+    DeviceCode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthStatus {
+    pub state: AuthState,
+    /// Display name of the signed-in account.
+    pub account: Option<String>,
+    pub expires_at_ms: Option<u64>,
+    /// Methods accepted by `begin_login`, preferred first.
+    pub login_methods: Vec<LoginMethod>,
+    pub message: Option<String>,
+}
+
+impl AuthStatus {
+    pub fn not_required() -> Self {
+        Self::new(AuthState::NotRequired)
+    }
+
+    pub fn new(state: AuthState) -> Self {
+        Self {
+            state,
+            account: None,
+            expires_at_ms: None,
+            login_methods: Vec::new(),
+            message: None,
+        }
+    }
+}
+
+/// What the user has to act on to finish a login started by `begin_login`.
+///
+/// `qr_payload` is the text to encode as a QR code, `url` a page to open, and `user_code`
+/// the code to type there for device-code flows. The host polls `poll_login` with
+/// `session_id` every `poll_interval_ms` until it stops returning `Waiting`/`Confirming`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoginChallenge {
+    pub method: LoginMethod,
+    pub session_id: String,
+    pub url: Option<String>,
+    pub qr_payload: Option<String>,
+    pub user_code: Option<String>,
+    pub expires_at_ms: Option<u64>,
+    pub poll_interval_ms: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum LoginProgress {
+    Waiting,
+    /// The challenge was picked up (e.g. QR scanned) and awaits confirmation.
+    Confirming,
+    Completed {
+        status: AuthStatus,
+    },
+    Expired,
+    Failed {
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaybackTrackInfo {
+    pub track_token: String,
+    pub source_id: String,
+    pub track_id: String,
+    pub locator: String,
+    pub duration_ms: Option<u64>,
+    pub metadata: Option<MediaMetadata>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaybackSkipReason {
+    UserSwitch,
+    Stopped,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum PlaybackEvent {
+    TrackStarted {
+        track: PlaybackTrackInfo,
+    },
+    Progress {
+        track: PlaybackTrackInfo,
+        position_ms: u64,
+    },
+    Paused {
+        track: PlaybackTrackInfo,
+        position_ms: u64,
+    },
+    Finished {
+        track: PlaybackTrackInfo,
+    },
+    Skipped {
+        track: PlaybackTrackInfo,
+        position_ms: u64,
+        reason: PlaybackSkipReason,
+    },
+}
@@ -18,6 +18,12 @@ pub enum SdkError {
     Denied(String),
     #[error("internal: {0}")]
     Internal(String),
+    /// The source needs an interactive login before the call can succeed.
+    #[error("auth required: {0}")]
+    AuthRequired(String),
+    /// A previously valid session expired; refreshing or logging in again may help.
+    #[error("auth expired: {0}")]
+    AuthExpired(String),
 }
 
 impl SdkError {
@@ -48,4 +54,12 @@ impl SdkError {
     pub fn internal(message: impl Into<String>) -> Self {
         Self::Internal(message.into())
     }
+
+    pub fn auth_required(message: impl Into<String>) -> Self {
+        Self::AuthRequired(message.into())
+    }
+
+    pub fn auth_expired(message: impl Into<String>) -> Self {
+        Self::AuthExpired(message.into())
+    }
 }
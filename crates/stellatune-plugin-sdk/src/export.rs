@@ -3,6 +3,7 @@ mod dsp;
 mod lyrics;
 mod metadata;
 mod output_sink;
+mod playback_listener;
 mod source;
 
 pub use metadata::{ComponentExport, ComponentExportMetadata};
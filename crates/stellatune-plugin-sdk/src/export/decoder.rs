@@ -58,6 +58,8 @@ macro_rules! export_decoder_component {
                     $crate::SdkError::Unsupported(message) => __StPluginError::Unsupported(message),
                     $crate::SdkError::Denied(message) => __StPluginError::Denied(message),
                     $crate::SdkError::Internal(message) => __StPluginError::Internal(message),
+                    $crate::SdkError::AuthRequired(message) => __StPluginError::AuthRequired(message),
+                    $crate::SdkError::AuthExpired(message) => __StPluginError::AuthExpired(message),
                 }
             }
 
@@ -86,6 +88,12 @@ macro_rules! export_decoder_component {
                     __st_bindings::stellatune::plugin::host_stream::PluginError::Internal(message) => {
                         $crate::SdkError::Internal(message)
                     }
+                    __st_bindings::stellatune::plugin::host_stream::PluginError::AuthRequired(message) => {
+                        $crate::SdkError::AuthRequired(message)
+                    }
+                    __st_bindings::stellatune::plugin::host_stream::PluginError::AuthExpired(message) => {
+                        $crate::SdkError::AuthExpired(message)
+                    }
                 }
             }
 
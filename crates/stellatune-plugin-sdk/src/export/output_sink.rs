@@ -46,6 +46,8 @@ macro_rules! export_output_sink_component {
                     $crate::SdkError::Unsupported(message) => __StPluginError::Unsupported(message),
                     $crate::SdkError::Denied(message) => __StPluginError::Denied(message),
                     $crate::SdkError::Internal(message) => __StPluginError::Internal(message),
+                    $crate::SdkError::AuthRequired(message) => __StPluginError::AuthRequired(message),
+                    $crate::SdkError::AuthExpired(message) => __StPluginError::AuthExpired(message),
                 }
             }
 
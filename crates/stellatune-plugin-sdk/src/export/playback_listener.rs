@@ -0,0 +1,287 @@
+#[macro_export]
+macro_rules! export_playback_listener_component {
+    (
+        plugin_type: $plugin_ty:ty,
+        create: $create:path $(,)?
+    ) => {
+        mod __st_playback_listener_component_export {
+            use super::*;
+            use $crate::__private::parking_lot::{Mutex, MutexGuard};
+            use std::sync::OnceLock;
+            use $crate::__private::stellatune_world_playback_listener as __st_bindings;
+
+            type __StPlugin = $plugin_ty;
+            type __StPluginError =
+                __st_bindings::exports::stellatune::plugin::playback_listener::PluginError;
+            type __StDisableReason =
+                __st_bindings::exports::stellatune::plugin::lifecycle::DisableReason;
+            type __StConfigUpdateMode =
+                __st_bindings::stellatune::plugin::common::ConfigUpdateMode;
+            type __StConfigUpdatePlan =
+                __st_bindings::exports::stellatune::plugin::playback_listener::ConfigUpdatePlan;
+            type __StPlaybackEvent =
+                __st_bindings::exports::stellatune::plugin::playback_listener::PlaybackEvent;
+            type __StTrackInfo =
+                __st_bindings::exports::stellatune::plugin::playback_listener::TrackInfo;
+            type __StSkipReason =
+                __st_bindings::exports::stellatune::plugin::playback_listener::SkipReason;
+            type __StMediaMetadata =
+                __st_bindings::exports::stellatune::plugin::playback_listener::MediaMetadata;
+            type __StEncodedAudioFormat =
+                __st_bindings::stellatune::plugin::common::EncodedAudioFormat;
+            type __StAudioTags = __st_bindings::stellatune::plugin::common::AudioTags;
+            type __StMetadataEntry = __st_bindings::stellatune::plugin::common::MetadataEntry;
+            type __StMetadataValue = __st_bindings::stellatune::plugin::common::MetadataValue;
+
+            static __ST_PLUGIN: OnceLock<Mutex<__StPlugin>> = OnceLock::new();
+
+            struct __StRoot;
+            struct __StListener {
+                inner: Mutex<<__StPlugin as $crate::PlaybackListenerPlugin>::Listener>,
+            }
+
+            fn __map_error(error: $crate::SdkError) -> __StPluginError {
+                match error {
+                    $crate::SdkError::InvalidArg(message) => __StPluginError::InvalidArg(message),
+                    $crate::SdkError::NotFound(message) => __StPluginError::NotFound(message),
+                    $crate::SdkError::Io(message) => __StPluginError::Io(message),
+                    $crate::SdkError::Timeout(message) => __StPluginError::Timeout(message),
+                    $crate::SdkError::Unsupported(message) => __StPluginError::Unsupported(message),
+                    $crate::SdkError::Denied(message) => __StPluginError::Denied(message),
+                    $crate::SdkError::Internal(message) => __StPluginError::Internal(message),
+                    $crate::SdkError::AuthRequired(message) => __StPluginError::AuthRequired(message),
+                    $crate::SdkError::AuthExpired(message) => __StPluginError::AuthExpired(message),
+                }
+            }
+
+            fn __map_disable_reason(reason: __StDisableReason) -> $crate::common::DisableReason {
+                match reason {
+                    __StDisableReason::HostDisable => $crate::common::DisableReason::HostDisable,
+                    __StDisableReason::Unload => $crate::common::DisableReason::Unload,
+                    __StDisableReason::Shutdown => $crate::common::DisableReason::Shutdown,
+                    __StDisableReason::Reload => $crate::common::DisableReason::Reload,
+                }
+            }
+
+            fn __map_config_update_mode(
+                mode: $crate::common::ConfigUpdateMode,
+            ) -> __StConfigUpdateMode {
+                match mode {
+                    $crate::common::ConfigUpdateMode::HotApply => __StConfigUpdateMode::HotApply,
+                    $crate::common::ConfigUpdateMode::Recreate => __StConfigUpdateMode::Recreate,
+                    $crate::common::ConfigUpdateMode::Reject => __StConfigUpdateMode::Reject,
+                }
+            }
+
+            fn __map_config_update_plan(plan: $crate::common::ConfigUpdatePlan) -> __StConfigUpdatePlan {
+                __StConfigUpdatePlan {
+                    mode: __map_config_update_mode(plan.mode),
+                    reason: plan.reason,
+                }
+            }
+
+            fn __map_metadata_value(value: __StMetadataValue) -> $crate::common::MetadataValue {
+                match value {
+                    __StMetadataValue::Text(text) => $crate::common::MetadataValue::Text(text),
+                    __StMetadataValue::Boolean(v) => $crate::common::MetadataValue::Boolean(v),
+                    __StMetadataValue::Uint32(v) => $crate::common::MetadataValue::Uint32(v),
+                    __StMetadataValue::Uint64(v) => $crate::common::MetadataValue::Uint64(v),
+                    __StMetadataValue::Int64(v) => $crate::common::MetadataValue::Int64(v),
+                    __StMetadataValue::Float64(v) => $crate::common::MetadataValue::Float64(v),
+                    __StMetadataValue::Bytes(bytes) => $crate::common::MetadataValue::Bytes(bytes),
+                }
+            }
+
+            fn __map_metadata_entry(entry: __StMetadataEntry) -> $crate::common::MetadataEntry {
+                $crate::common::MetadataEntry {
+                    key: entry.key,
+                    value: __map_metadata_value(entry.value),
+                }
+            }
+
+            fn __map_audio_tags(tags: __StAudioTags) -> $crate::common::AudioTags {
+                $crate::common::AudioTags {
+                    title: tags.title,
+                    album: tags.album,
+                    artists: tags.artists,
+                    album_artists: tags.album_artists,
+                    genres: tags.genres,
+                    track_number: tags.track_number,
+                    track_total: tags.track_total,
+                    disc_number: tags.disc_number,
+                    disc_total: tags.disc_total,
+                    year: tags.year,
+                    comment: tags.comment,
+                }
+            }
+
+            fn __map_encoded_audio_format(
+                format: __StEncodedAudioFormat,
+            ) -> $crate::common::EncodedAudioFormat {
+                $crate::common::EncodedAudioFormat {
+                    codec: format.codec,
+                    sample_rate: format.sample_rate,
+                    channels: format.channels,
+                    bitrate_kbps: format.bitrate_kbps,
+                    container: format.container,
+                }
+            }
+
+            fn __map_media_metadata(metadata: __StMediaMetadata) -> $crate::common::MediaMetadata {
+                $crate::common::MediaMetadata {
+                    tags: __map_audio_tags(metadata.tags),
+                    duration_ms: metadata.duration_ms,
+                    format: __map_encoded_audio_format(metadata.format),
+                    extras: metadata.extras.into_iter().map(__map_metadata_entry).collect(),
+                }
+            }
+
+            fn __map_track_info(track: __StTrackInfo) -> $crate::common::PlaybackTrackInfo {
+                $crate::common::PlaybackTrackInfo {
+                    track_token: track.track_token,
+                    source_id: track.source_id,
+                    track_id: track.track_id,
+                    locator: track.locator,
+                    duration_ms: track.duration_ms,
+                    metadata: track.metadata.map(__map_media_metadata),
+                }
+            }
+
+            fn __map_skip_reason(reason: __StSkipReason) -> $crate::common::PlaybackSkipReason {
+                match reason {
+                    __StSkipReason::UserSwitch => $crate::common::PlaybackSkipReason::UserSwitch,
+                    __StSkipReason::Stopped => $crate::common::PlaybackSkipReason::Stopped,
+                }
+            }
+
+            fn __map_playback_event(event: __StPlaybackEvent) -> $crate::common::PlaybackEvent {
+                match event {
+                    __StPlaybackEvent::TrackStarted(track) => {
+                        $crate::common::PlaybackEvent::TrackStarted {
+                            track: __map_track_info(track),
+                        }
+                    },
+                    __StPlaybackEvent::Progress(progress) => {
+                        $crate::common::PlaybackEvent::Progress {
+                            track: __map_track_info(progress.track),
+                            position_ms: progress.position_ms,
+                        }
+                    },
+                    __StPlaybackEvent::Paused(progress) => $crate::common::PlaybackEvent::Paused {
+                        track: __map_track_info(progress.track),
+                        position_ms: progress.position_ms,
+                    },
+                    __StPlaybackEvent::Finished(track) => {
+                        $crate::common::PlaybackEvent::Finished {
+                            track: __map_track_info(track),
+                        }
+                    },
+                    __StPlaybackEvent::Skipped(skipped) => {
+                        $crate::common::PlaybackEvent::Skipped {
+                            track: __map_track_info(skipped.track),
+                            position_ms: skipped.position_ms,
+                            reason: __map_skip_reason(skipped.reason),
+                        }
+                    },
+                }
+            }
+
+            fn __plugin_guard() -> Result<MutexGuard<'static, __StPlugin>, __StPluginError> {
+                if __ST_PLUGIN.get().is_none() {
+                    let plugin = ($create)().map_err(__map_error)?;
+                    let _ = __ST_PLUGIN.set(Mutex::new(plugin));
+                }
+                let plugin = __ST_PLUGIN.get().ok_or_else(|| {
+                    __StPluginError::Internal(
+                        "plugin factory did not initialize global plugin state".to_string(),
+                    )
+                })?;
+                Ok(plugin.lock())
+            }
+
+            impl __st_bindings::exports::stellatune::plugin::lifecycle::Guest for __StRoot {
+                fn on_enable() -> Result<(), __StPluginError> {
+                    let mut plugin = __plugin_guard()?;
+                    plugin.on_enable().map_err(__map_error)
+                }
+
+                fn on_disable(reason: __StDisableReason) -> Result<(), __StPluginError> {
+                    let mut plugin = __plugin_guard()?;
+                    plugin
+                        .on_disable(__map_disable_reason(reason))
+                        .map_err(__map_error)
+                }
+            }
+
+            impl __st_bindings::exports::stellatune::plugin::playback_listener::Guest for __StRoot {
+                type Listener = __StListener;
+
+                fn create() -> Result<
+                    __st_bindings::exports::stellatune::plugin::playback_listener::Listener,
+                    __StPluginError,
+                > {
+                    let mut plugin = __plugin_guard()?;
+                    let listener = plugin.create_listener().map_err(__map_error)?;
+                    Ok(
+                        __st_bindings::exports::stellatune::plugin::playback_listener::Listener::new(
+                            __StListener {
+                                inner: Mutex::new(listener),
+                            },
+                        ),
+                    )
+                }
+            }
+
+            impl __st_bindings::exports::stellatune::plugin::playback_listener::GuestListener
+                for __StListener
+            {
+                fn on_event(&self, event: __StPlaybackEvent) -> Result<(), __StPluginError> {
+                    let mut listener = self.inner.lock();
+                    listener
+                        .on_event(__map_playback_event(event))
+                        .map_err(__map_error)
+                }
+
+                fn plan_config_update_json(
+                    &self,
+                    new_config_json: String,
+                ) -> Result<__StConfigUpdatePlan, __StPluginError> {
+                    let mut listener = self.inner.lock();
+                    listener
+                        .plan_config_update_json(new_config_json.as_str())
+                        .map(__map_config_update_plan)
+                        .map_err(__map_error)
+                }
+
+                fn apply_config_update_json(
+                    &self,
+                    new_config_json: String,
+                ) -> Result<(), __StPluginError> {
+                    let mut listener = self.inner.lock();
+                    listener
+                        .apply_config_update_json(new_config_json.as_str())
+                        .map_err(__map_error)
+                }
+
+                fn export_state_json(&self) -> Result<Option<String>, __StPluginError> {
+                    let listener = self.inner.lock();
+                    listener.export_state_json().map_err(__map_error)
+                }
+
+                fn import_state_json(&self, state_json: String) -> Result<(), __StPluginError> {
+                    let mut listener = self.inner.lock();
+                    listener
+                        .import_state_json(state_json.as_str())
+                        .map_err(__map_error)
+                }
+
+                fn close(&self) {
+                    let mut listener = self.inner.lock();
+                    let _ = listener.close();
+                }
+            }
+
+            __st_bindings::export!(__StRoot with_types_in __st_bindings);
+        }
+    };
+}
@@ -40,6 +40,22 @@ macro_rules! export_source_component {
             type __StAudioTags = __st_bindings::stellatune::plugin::common::AudioTags;
             type __StMetadataEntry = __st_bindings::stellatune::plugin::common::MetadataEntry;
             type __StMetadataValue = __st_bindings::stellatune::plugin::common::MetadataValue;
+            type __StBrowseNodeKind =
+                __st_bindings::exports::stellatune::plugin::source::BrowseNodeKind;
+            type __StBrowseNode = __st_bindings::exports::stellatune::plugin::source::BrowseNode;
+            type __StBrowsePage = __st_bindings::exports::stellatune::plugin::source::BrowsePage;
+            type __StPageRequest = __st_bindings::exports::stellatune::plugin::source::PageRequest;
+            type __StSearchRequest =
+                __st_bindings::exports::stellatune::plugin::source::SearchRequest;
+            type __StCatalogCapabilities =
+                __st_bindings::exports::stellatune::plugin::source::CatalogCapabilities;
+            type __StAuthState = __st_bindings::exports::stellatune::plugin::source::AuthState;
+            type __StAuthStatus = __st_bindings::exports::stellatune::plugin::source::AuthStatus;
+            type __StLoginMethod = __st_bindings::exports::stellatune::plugin::source::LoginMethod;
+            type __StLoginChallenge =
+                __st_bindings::exports::stellatune::plugin::source::LoginChallenge;
+            type __StLoginProgress =
+                __st_bindings::exports::stellatune::plugin::source::LoginProgress;
 
             static __ST_PLUGIN: OnceLock<Mutex<__StPlugin>> = OnceLock::new();
 
@@ -60,6 +76,8 @@ macro_rules! export_source_component {
                     $crate::SdkError::Unsupported(message) => __StPluginError::Unsupported(message),
                     $crate::SdkError::Denied(message) => __StPluginError::Denied(message),
                     $crate::SdkError::Internal(message) => __StPluginError::Internal(message),
+                    $crate::SdkError::AuthRequired(message) => __StPluginError::AuthRequired(message),
+                    $crate::SdkError::AuthExpired(message) => __StPluginError::AuthExpired(message),
                 }
             }
 
@@ -88,6 +106,12 @@ macro_rules! export_source_component {
                     __st_bindings::stellatune::plugin::host_stream::PluginError::Internal(message) => {
                         $crate::SdkError::Internal(message)
                     }
+                    __st_bindings::stellatune::plugin::host_stream::PluginError::AuthRequired(message) => {
+                        $crate::SdkError::AuthRequired(message)
+                    }
+                    __st_bindings::stellatune::plugin::host_stream::PluginError::AuthExpired(message) => {
+                        $crate::SdkError::AuthExpired(message)
+                    }
                 }
             }
 
@@ -173,6 +197,144 @@ macro_rules! export_source_component {
                 }
             }
 
+            fn __map_browse_node_kind_to_wit(kind: $crate::common::BrowseNodeKind) -> __StBrowseNodeKind {
+                match kind {
+                    $crate::common::BrowseNodeKind::Folder => __StBrowseNodeKind::Folder,
+                    $crate::common::BrowseNodeKind::Playlist => __StBrowseNodeKind::Playlist,
+                    $crate::common::BrowseNodeKind::Album => __StBrowseNodeKind::Album,
+                    $crate::common::BrowseNodeKind::Artist => __StBrowseNodeKind::Artist,
+                    $crate::common::BrowseNodeKind::Track => __StBrowseNodeKind::Track,
+                }
+            }
+
+            fn __map_browse_node_kind_from_wit(kind: __StBrowseNodeKind) -> $crate::common::BrowseNodeKind {
+                match kind {
+                    __StBrowseNodeKind::Folder => $crate::common::BrowseNodeKind::Folder,
+                    __StBrowseNodeKind::Playlist => $crate::common::BrowseNodeKind::Playlist,
+                    __StBrowseNodeKind::Album => $crate::common::BrowseNodeKind::Album,
+                    __StBrowseNodeKind::Artist => $crate::common::BrowseNodeKind::Artist,
+                    __StBrowseNodeKind::Track => $crate::common::BrowseNodeKind::Track,
+                }
+            }
+
+            fn __map_browse_node(node: $crate::common::BrowseNode) -> __StBrowseNode {
+                __StBrowseNode {
+                    kind: __map_browse_node_kind_to_wit(node.kind),
+                    id: node.id,
+                    title: node.title,
+                    subtitle: node.subtitle,
+                    artists: node.artists,
+                    album: node.album,
+                    duration_ms: node.duration_ms,
+                    child_count: node.child_count,
+                    artwork_url: node.artwork_url,
+                    track_json: node.track_json,
+                    extras: node.extras.into_iter().map(__map_metadata_entry).collect(),
+                }
+            }
+
+            fn __map_browse_page(page: $crate::common::BrowsePage) -> __StBrowsePage {
+                __StBrowsePage {
+                    nodes: page.nodes.into_iter().map(__map_browse_node).collect(),
+                    next_cursor: page.next_cursor,
+                }
+            }
+
+            fn __map_page_request(page: __StPageRequest) -> $crate::common::PageRequest {
+                $crate::common::PageRequest {
+                    cursor: page.cursor,
+                    limit: page.limit,
+                }
+            }
+
+            fn __map_search_request(request: __StSearchRequest) -> $crate::common::SearchRequest {
+                $crate::common::SearchRequest {
+                    query: request.query,
+                    kinds: request
+                        .kinds
+                        .into_iter()
+                        .map(__map_browse_node_kind_from_wit)
+                        .collect(),
+                    page: __map_page_request(request.page),
+                }
+            }
+
+            fn __map_catalog_capabilities(
+                capabilities: $crate::common::CatalogCapabilities,
+            ) -> __StCatalogCapabilities {
+                __StCatalogCapabilities {
+                    browse: capabilities.browse,
+                    search: capabilities.search,
+                    open_uri: capabilities.open_uri,
+                    search_kinds: capabilities
+                        .search_kinds
+                        .into_iter()
+                        .map(__map_browse_node_kind_to_wit)
+                        .collect(),
+                }
+            }
+
+            fn __map_login_method_to_wit(method: $crate::common::LoginMethod) -> __StLoginMethod {
+                match method {
+                    $crate::common::LoginMethod::QrCode => __StLoginMethod::QrCode,
+                    $crate::common::LoginMethod::Url => __StLoginMethod::Url,
+                    $crate::common::LoginMethod::DeviceCode => __StLoginMethod::DeviceCode,
+                }
+            }
+
+            fn __map_login_method_from_wit(method: __StLoginMethod) -> $crate::common::LoginMethod {
+                match method {
+                    __StLoginMethod::QrCode => $crate::common::LoginMethod::QrCode,
+                    __StLoginMethod::Url => $crate::common::LoginMethod::Url,
+                    __StLoginMethod::DeviceCode => $crate::common::LoginMethod::DeviceCode,
+                }
+            }
+
+            fn __map_auth_status(status: $crate::common::AuthStatus) -> __StAuthStatus {
+                __StAuthStatus {
+                    state: match status.state {
+                        $crate::common::AuthState::NotRequired => __StAuthState::NotRequired,
+                        $crate::common::AuthState::LoggedOut => __StAuthState::LoggedOut,
+                        $crate::common::AuthState::LoggedIn => __StAuthState::LoggedIn,
+                        $crate::common::AuthState::Expired => __StAuthState::Expired,
+                    },
+                    account: status.account,
+                    expires_at_ms: status.expires_at_ms,
+                    login_methods: status
+                        .login_methods
+                        .into_iter()
+                        .map(__map_login_method_to_wit)
+                        .collect(),
+                    message: status.message,
+                }
+            }
+
+            fn __map_login_challenge(challenge: $crate::common::LoginChallenge) -> __StLoginChallenge {
+                __StLoginChallenge {
+                    method: __map_login_method_to_wit(challenge.method),
+                    session_id: challenge.session_id,
+                    url: challenge.url,
+                    qr_payload: challenge.qr_payload,
+                    user_code: challenge.user_code,
+                    expires_at_ms: challenge.expires_at_ms,
+                    poll_interval_ms: challenge.poll_interval_ms,
+                }
+            }
+
+            fn __map_login_progress(progress: $crate::common::LoginProgress) -> __StLoginProgress {
+                match progress {
+                    $crate::common::LoginProgress::Waiting => __StLoginProgress::Waiting,
+                    $crate::common::LoginProgress::Confirming => __StLoginProgress::Confirming,
+                    $crate::common::LoginProgress::Completed { status } => {
+                        __StLoginProgress::Completed(__map_auth_status(status))
+                    }
+                    $crate::common::LoginProgress::Expired => __StLoginProgress::Expired,
+                    $crate::common::LoginProgress::Failed { message } => {
+                        __StLoginProgress::Failed(message)
+                    }
+                }
+            }
+
             fn __map_encoded_chunk(chunk: $crate::common::EncodedChunk) -> __StEncodedChunk {
                 __StEncodedChunk {
                     bytes: chunk.bytes,
@@ -307,6 +469,31 @@ macro_rules! export_source_component {
             }
 
             impl __st_bindings::exports::stellatune::plugin::source::GuestCatalog for __StCatalog {
+                fn capabilities(&self) -> __StCatalogCapabilities {
+                    let catalog = self.inner.lock();
+                    __map_catalog_capabilities(catalog.capabilities())
+                }
+
+                fn browse(
+                    &self,
+                    parent_id: Option<String>,
+                    page: __StPageRequest,
+                ) -> Result<__StBrowsePage, __StPluginError> {
+                    let mut catalog = self.inner.lock();
+                    catalog
+                        .browse(parent_id.as_deref(), __map_page_request(page))
+                        .map(__map_browse_page)
+                        .map_err(__map_error)
+                }
+
+                fn search(&self, request: __StSearchRequest) -> Result<__StBrowsePage, __StPluginError> {
+                    let mut catalog = self.inner.lock();
+                    catalog
+                        .search(__map_search_request(request))
+                        .map(__map_browse_page)
+                        .map_err(__map_error)
+                }
+
                 fn list_items_json(&self, request_json: String) -> Result<String, __StPluginError> {
                     let mut catalog = self.inner.lock();
                     catalog
@@ -314,6 +501,40 @@ macro_rules! export_source_component {
                         .map_err(__map_error)
                 }
 
+                fn auth_status(&self) -> Result<__StAuthStatus, __StPluginError> {
+                    let mut catalog = self.inner.lock();
+                    catalog.auth_status().map(__map_auth_status).map_err(__map_error)
+                }
+
+                fn begin_login(
+                    &self,
+                    method: Option<__StLoginMethod>,
+                ) -> Result<__StLoginChallenge, __StPluginError> {
+                    let mut catalog = self.inner.lock();
+                    catalog
+                        .begin_login(method.map(__map_login_method_from_wit))
+                        .map(__map_login_challenge)
+                        .map_err(__map_error)
+                }
+
+                fn poll_login(&self, session_id: String) -> Result<__StLoginProgress, __StPluginError> {
+                    let mut catalog = self.inner.lock();
+                    catalog
+                        .poll_login(session_id.as_str())
+                        .map(__map_login_progress)
+                        .map_err(__map_error)
+                }
+
+                fn refresh_auth(&self) -> Result<__StAuthStatus, __StPluginError> {
+                    let mut catalog = self.inner.lock();
+                    catalog.refresh_auth().map(__map_auth_status).map_err(__map_error)
+                }
+
+                fn logout(&self) -> Result<(), __StPluginError> {
+                    let mut catalog = self.inner.lock();
+                    catalog.logout().map_err(__map_error)
+                }
+
                 fn open_stream_json(
                     &self,
                     track_json: String,
@@ -2,8 +2,113 @@ use serde::de::DeserializeOwned;
 
 use crate::error::{SdkError, SdkResult};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    pub url: String,
+    pub headers: Vec<HttpHeader>,
+    pub body: Option<Vec<u8>>,
+    pub timeout_ms: Option<u32>,
+}
+
+impl HttpRequest {
+    pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+            timeout_ms: None,
+        }
+    }
+
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(HttpMethod::Get, url)
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push(HttpHeader {
+            name: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    pub fn timeout_ms(mut self, timeout_ms: u32) -> Self {
+        self.timeout_ms = Some(timeout_ms);
+        self
+    }
+}
+
+/// Streaming response body. `read` returns an empty chunk once the body is exhausted.
+pub trait HttpResponseBody {
+    fn read(&mut self, max_bytes: u32) -> SdkResult<Vec<u8>>;
+    fn close(&mut self) {}
+}
+
+pub trait HttpResponseBodyExt: HttpResponseBody {
+    fn read_to_end(&mut self, chunk_bytes: u32) -> SdkResult<Vec<u8>> {
+        let mut out = Vec::new();
+        loop {
+            let chunk = self.read(chunk_bytes)?;
+            if chunk.is_empty() {
+                return Ok(out);
+            }
+            out.extend_from_slice(&chunk);
+        }
+    }
+}
+
+impl<T: HttpResponseBody + ?Sized> HttpResponseBodyExt for T {}
+
+/// Non-2xx statuses are returned as responses, not errors.
+#[derive(Debug)]
+pub struct HttpResponse<B> {
+    pub status: u16,
+    pub headers: Vec<HttpHeader>,
+    pub body: B,
+}
+
+impl<B> HttpResponse<B> {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|header| header.name.eq_ignore_ascii_case(name))
+            .map(|header| header.value.as_str())
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
 pub trait HttpClient {
+    type Body: HttpResponseBody;
+
     fn fetch_json(&mut self, url: &str) -> SdkResult<String>;
+    fn send(&mut self, request: &HttpRequest) -> SdkResult<HttpResponse<Self::Body>>;
 }
 
 pub trait HttpClientExt: HttpClient {
@@ -10,6 +10,7 @@ pub mod http_client;
 pub mod lifecycle;
 pub mod prelude;
 pub mod sidecar;
+pub mod storage;
 
 pub use capabilities::*;
 pub use error::{SdkError, SdkResult};
@@ -23,5 +24,6 @@ pub mod __private {
     pub use stellatune_world_dsp;
     pub use stellatune_world_lyrics;
     pub use stellatune_world_output_sink;
+    pub use stellatune_world_playback_listener;
     pub use stellatune_world_source;
 }
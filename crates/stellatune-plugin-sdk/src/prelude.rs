@@ -1,14 +1,16 @@
 pub use crate::capabilities::{
     AbilityDescriptor, AbilityKind, ConfigStateOps, DecoderInput, DecoderInputStream,
     DecoderPlugin, DecoderSession, DspPlugin, DspProcessor, LyricsPlugin, LyricsProvider,
-    OpenedSourceStream, OutputSinkPlugin, OutputSinkSession, SourceCatalog, SourcePlugin,
-    SourceStream,
+    OpenedSourceStream, OutputSinkPlugin, OutputSinkSession, PlaybackListener,
+    PlaybackListenerPlugin, SourceCatalog, SourcePlugin, SourceStream,
 };
 pub use crate::common::{
-    AudioSpec, AudioTags, BufferLayout, ConfigUpdateMode, ConfigUpdatePlan, CoreModuleSpec,
-    DecoderInfo, DisableReason, EncodedAudioFormat, EncodedChunk, HotPathRole, LyricCandidate,
-    MediaMetadata, MetadataEntry, MetadataValue, NegotiatedSpec, OutputSinkStatus, PcmF32Chunk,
-    SampleFormat, SeekWhence,
+    AudioSpec, AudioTags, AuthState, AuthStatus, BrowseNode, BrowseNodeKind, BrowsePage,
+    BufferLayout, CatalogCapabilities, ConfigUpdateMode, ConfigUpdatePlan, CoreModuleSpec,
+    DecoderInfo, DisableReason, EncodedAudioFormat, EncodedChunk, HotPathRole, LoginChallenge,
+    LoginMethod, LoginProgress, LyricCandidate, MediaMetadata, MetadataEntry, MetadataValue,
+    NegotiatedSpec, OutputSinkStatus, PageRequest, PcmF32Chunk, PlaybackEvent, PlaybackSkipReason,
+    PlaybackTrackInfo, SampleFormat, SearchRequest, SeekWhence,
 };
 pub use crate::error::{SdkError, SdkResult};
 pub use crate::export::{ComponentExport, ComponentExportMetadata};
@@ -22,10 +24,17 @@ pub use crate::hot_path::{
     DEFAULT_PROCESS_EXPORT, DEFAULT_RESET_EXPORT, HOT_INIT_ARGS_SIZE, HOT_PATH_ABI_VERSION_V1,
     HotInitArgs, validate_buffer_layout, validate_core_module_spec,
 };
-pub use crate::http_client::{HttpClient, HttpClientExt};
+pub use crate::http_client::{
+    HttpClient, HttpClientExt, HttpHeader, HttpRequest, HttpResponse, HttpResponseBody,
+    HttpResponseBodyExt,
+};
 pub use crate::lifecycle::PluginLifecycle;
 pub use crate::sidecar::{
     SidecarChannel, SidecarChannelExt, SidecarClient, SidecarLaunchSpec, SidecarLockGuard,
-    SidecarProcess, SidecarProcessExt, SidecarScope, TransportKind, TransportOption,
+    SidecarProcess, SidecarProcessExt, SidecarScope, SidecarStatus, TransportKind, TransportOption,
     ordered_transport_options,
 };
+pub use crate::storage::{
+    DecoderHostStorage, DspHostStorage, LyricsHostStorage, OutputSinkHostStorage,
+    PlaybackListenerHostStorage, PluginStorage, PluginStorageExt, SourceHostStorage, StorageUsage,
+};
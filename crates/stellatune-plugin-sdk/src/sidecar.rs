@@ -33,6 +33,48 @@ pub struct SidecarLaunchSpec {
     pub preferred_control: Vec<TransportOption>,
     pub preferred_data: Vec<TransportOption>,
     pub env: Vec<(String, String)>,
+    pub supervision: Option<SidecarSupervision>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SidecarSupervision {
+    pub restart: Option<SidecarRestartPolicy>,
+    pub health_probe: Option<SidecarHealthProbe>,
+    pub capture_output: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidecarRestartPolicy {
+    pub max_restarts: u32,
+    pub initial_backoff_ms: u32,
+    pub max_backoff_ms: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidecarHealthProbe {
+    pub request: Vec<u8>,
+    pub expect_prefix: Vec<u8>,
+    pub interval_ms: u32,
+    pub timeout_ms: u32,
+    pub failure_threshold: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidecarState {
+    Starting,
+    Running,
+    Unhealthy,
+    Restarting,
+    Failed,
+    Stopped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidecarStatus {
+    pub state: SidecarState,
+    pub restarts: u32,
+    pub last_exit_code: Option<i32>,
+    pub detail: Option<String>,
 }
 
 pub trait SidecarChannel: Send {
@@ -72,6 +114,7 @@ pub trait SidecarProcess: Send {
     fn open_data(&mut self, role: &str, preferred: &[TransportOption]) -> SdkResult<Self::Channel>;
     fn wait_exit(&mut self, timeout_ms: Option<u32>) -> SdkResult<Option<i32>>;
     fn terminate(&mut self, grace_ms: u32) -> SdkResult<()>;
+    fn status(&mut self) -> SdkResult<SidecarStatus>;
 }
 
 pub trait SidecarProcessExt: SidecarProcess {
@@ -0,0 +1,127 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::{SdkError, SdkResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageUsage {
+    pub used_bytes: u64,
+    pub quota_bytes: u64,
+    pub key_count: u32,
+}
+
+/// Persistent key/value storage owned by the plugin.
+///
+/// Data survives reloads, restarts and upgrades and is wiped when the plugin
+/// is uninstalled. Keys and values count against a per-plugin quota; writes
+/// past it fail with [`SdkError::Denied`].
+pub trait PluginStorage {
+    fn get(&self, key: &str) -> SdkResult<Option<Vec<u8>>>;
+    fn set(&self, key: &str, value: &[u8]) -> SdkResult<()>;
+    fn delete(&self, key: &str) -> SdkResult<bool>;
+    fn list_prefix(&self, prefix: &str) -> SdkResult<Vec<String>>;
+    fn usage(&self) -> SdkResult<StorageUsage>;
+}
+
+pub trait PluginStorageExt: PluginStorage {
+    fn get_json<T: DeserializeOwned>(&self, key: &str) -> SdkResult<Option<T>> {
+        let Some(raw) = self.get(key)? else {
+            return Ok(None);
+        };
+        serde_json::from_slice::<T>(&raw)
+            .map(Some)
+            .map_err(|error| {
+                SdkError::invalid_arg(format!("invalid stored JSON for `{key}`: {error}"))
+            })
+    }
+
+    fn set_json<T: Serialize + ?Sized>(&self, key: &str, value: &T) -> SdkResult<()> {
+        let payload = serde_json::to_vec(value)
+            .map_err(|error| SdkError::invalid_arg(format!("serialize storage JSON: {error}")))?;
+        self.set(key, &payload)
+    }
+
+    fn get_string(&self, key: &str) -> SdkResult<Option<String>> {
+        let Some(raw) = self.get(key)? else {
+            return Ok(None);
+        };
+        String::from_utf8(raw).map(Some).map_err(|error| {
+            SdkError::invalid_arg(format!("stored value for `{key}` is not UTF-8: {error}"))
+        })
+    }
+
+    fn set_string(&self, key: &str, value: &str) -> SdkResult<()> {
+        self.set(key, value.as_bytes())
+    }
+}
+
+impl<T: PluginStorage + ?Sized> PluginStorageExt for T {}
+
+macro_rules! define_world_storage {
+    ($name:ident, $world:ident) => {
+        /// Host storage import for this world.
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name;
+
+        impl $name {
+            fn map_error(
+                error: crate::__private::$world::stellatune::plugin::common::PluginError,
+            ) -> SdkError {
+                use crate::__private::$world::stellatune::plugin::common::PluginError;
+                match error {
+                    PluginError::InvalidArg(message) => SdkError::InvalidArg(message),
+                    PluginError::NotFound(message) => SdkError::NotFound(message),
+                    PluginError::Io(message) => SdkError::Io(message),
+                    PluginError::Timeout(message) => SdkError::Timeout(message),
+                    PluginError::Unsupported(message) => SdkError::Unsupported(message),
+                    PluginError::Denied(message) => SdkError::Denied(message),
+                    PluginError::Internal(message) => SdkError::Internal(message),
+                    PluginError::AuthRequired(message) => SdkError::AuthRequired(message),
+                    PluginError::AuthExpired(message) => SdkError::AuthExpired(message),
+                }
+            }
+        }
+
+        impl PluginStorage for $name {
+            fn get(&self, key: &str) -> SdkResult<Option<Vec<u8>>> {
+                crate::__private::$world::stellatune::plugin::storage::get(key)
+                    .map_err(Self::map_error)
+            }
+
+            fn set(&self, key: &str, value: &[u8]) -> SdkResult<()> {
+                crate::__private::$world::stellatune::plugin::storage::set(key, value)
+                    .map_err(Self::map_error)
+            }
+
+            fn delete(&self, key: &str) -> SdkResult<bool> {
+                crate::__private::$world::stellatune::plugin::storage::delete(key)
+                    .map_err(Self::map_error)
+            }
+
+            fn list_prefix(&self, prefix: &str) -> SdkResult<Vec<String>> {
+                crate::__private::$world::stellatune::plugin::storage::list_prefix(prefix)
+                    .map_err(Self::map_error)
+            }
+
+            fn usage(&self) -> SdkResult<StorageUsage> {
+                let usage = crate::__private::$world::stellatune::plugin::storage::usage()
+                    .map_err(Self::map_error)?;
+                Ok(StorageUsage {
+                    used_bytes: usage.used_bytes,
+                    quota_bytes: usage.quota_bytes,
+                    key_count: usage.key_count,
+                })
+            }
+        }
+    };
+}
+
+define_world_storage!(DecoderHostStorage, stellatune_world_decoder);
+define_world_storage!(SourceHostStorage, stellatune_world_source);
+define_world_storage!(LyricsHostStorage, stellatune_world_lyrics);
+define_world_storage!(OutputSinkHostStorage, stellatune_world_output_sink);
+define_world_storage!(DspHostStorage, stellatune_world_dsp);
+define_world_storage!(
+    PlaybackListenerHostStorage,
+    stellatune_world_playback_listener
+);
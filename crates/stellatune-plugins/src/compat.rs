@@ -0,0 +1,216 @@
+//! Host/plugin compatibility checks run at discovery and install time, before any
+//! component is compiled or linked.
+
+use std::sync::{PoisonError, RwLock};
+
+use semver::{Version, VersionReq};
+use stellatune_host_bindings::{WIT_PACKAGE, WIT_PACKAGE_VERSION};
+
+use crate::error::{Error, Result};
+use crate::executor::{WorldKind, classify_world};
+use crate::manifest::WasmPluginManifest;
+
+/// Application version matched against `requires.host`, set by the embedding app.
+static HOST_VERSION: RwLock<Option<Version>> = RwLock::new(None);
+
+/// Sets the version `requires.host` is checked against. Until the app calls this,
+/// `requires.host` is not enforced.
+pub fn set_host_version(version: &str) -> Result<()> {
+    let version = Version::parse(version.trim()).map_err(|error| {
+        Error::invalid_input(format!(
+            "host version `{version}` is not valid semver: {error}"
+        ))
+    })?;
+    *HOST_VERSION.write().unwrap_or_else(PoisonError::into_inner) = Some(version);
+    Ok(())
+}
+
+pub fn host_version() -> Option<Version> {
+    HOST_VERSION
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+pub fn wit_package_version() -> Version {
+    Version::parse(WIT_PACKAGE_VERSION).expect("WIT package version is valid semver")
+}
+
+pub(crate) fn parse_requirement(field: &str, requirement: &str) -> Result<VersionReq> {
+    VersionReq::parse(requirement.trim()).map_err(|error| {
+        crate::op_error!(
+            "manifest.{field} is not a valid semver requirement `{requirement}`: {error}"
+        )
+    })
+}
+
+/// Rejects manifests this host cannot run with an [`Error::Unsupported`] naming the first
+/// mismatch: `requires.host`, `requires.wit`, or a component world that the host
+/// linkers do not provide.
+pub fn check_compatibility(manifest: &WasmPluginManifest) -> Result<()> {
+    check_against(manifest, host_version().as_ref())
+}
+
+fn check_against(manifest: &WasmPluginManifest, host: Option<&Version>) -> Result<()> {
+    if let Some(requirement) = manifest.requires.host.as_deref() {
+        let requirement_parsed = parse_requirement("requires.host", requirement)?;
+        match host {
+            Some(host) if !requirement_parsed.matches(host) => {
+                return Err(Error::unsupported(format!(
+                    "plugin requires host `{requirement}`, host is {host}"
+                )));
+            },
+            Some(_) => {},
+            None => tracing::debug!(
+                target: "stellatune_plugins::compat",
+                plugin_id = %manifest.id,
+                requirement,
+                "host version not set, skipping requires.host"
+            ),
+        }
+    }
+    let wit = wit_package_version();
+    if let Some(requirement) = manifest.requires.wit.as_deref()
+        && !parse_requirement("requires.wit", requirement)?.matches(&wit)
+    {
+        return Err(Error::unsupported(format!(
+            "plugin requires {WIT_PACKAGE} `{requirement}`, host provides {wit}"
+        )));
+    }
+    for component in &manifest.components {
+        check_world(&component.world, &wit).map_err(|message| {
+            Error::unsupported(format!("component `{}` {message}", component.id))
+        })?;
+    }
+    Ok(())
+}
+
+/// Accepts `name`, `package/name` and `package/name@version` world references.
+fn check_world(world: &str, wit: &Version) -> std::result::Result<(), String> {
+    let world = world.trim();
+    let (path, version) = match world.split_once('@') {
+        Some((path, version)) => (path, Some(version.trim())),
+        None => (world, None),
+    };
+    if let Some((package, _)) = path.rsplit_once('/')
+        && package.trim() != WIT_PACKAGE
+    {
+        return Err(format!(
+            "targets package `{}`, host provides `{WIT_PACKAGE}`",
+            package.trim()
+        ));
+    }
+    if classify_world(world) == WorldKind::Unknown {
+        return Err(format!("targets unknown world `{world}`"));
+    }
+    if let Some(version) = version {
+        let version = Version::parse(version)
+            .map_err(|error| format!("has invalid world version `{version}`: {error}"))?;
+        // The component was built against `version`; the host must be caret-compatible.
+        let requirement = VersionReq::parse(&format!("^{version}"))
+            .map_err(|error| format!("has invalid world version `{version}`: {error}"))?;
+        if !requirement.matches(wit) {
+            return Err(format!(
+                "was built against {WIT_PACKAGE}@{version}, host provides {wit}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use super::check_against;
+    use crate::manifest::{
+        AbilityKind, AbilitySpec, ComponentSpec, PluginRequirements, WasmPluginManifest,
+    };
+
+    fn manifest(world: &str, requires: PluginRequirements) -> WasmPluginManifest {
+        WasmPluginManifest {
+            schema_version: 1,
+            id: "dev.test".to_string(),
+            name: "Test".to_string(),
+            version: "1.0.0".to_string(),
+            api_version: 1,
+            requires,
+            permissions: Default::default(),
+            components: vec![ComponentSpec {
+                id: "main".to_string(),
+                path: "plugin.wasm".to_string(),
+                world: world.to_string(),
+                abilities: vec![AbilitySpec {
+                    kind: AbilityKind::Decoder,
+                    type_id: "test".to_string(),
+                    display_name: None,
+                    config_schema_json: None,
+                    default_config_json: None,
+                    decoder: None,
+                }],
+            }],
+        }
+    }
+
+    fn app_version() -> Version {
+        Version::new(1, 0, 0)
+    }
+
+    #[test]
+    fn accepts_known_worlds_and_matching_requirements() {
+        for world in [
+            "decoder-plugin",
+            "stellatune:plugin/decoder-plugin",
+            "stellatune:plugin/decoder-plugin@0.2.0",
+        ] {
+            let requires = PluginRequirements {
+                host: Some(">=0.1".to_string()),
+                wit: Some("^0.2".to_string()),
+            };
+            assert!(
+                check_against(&manifest(world, requires), Some(&app_version())).is_ok(),
+                "{world}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_mismatched_requirements_and_worlds() {
+        let cases = [
+            ("decoder-plugin", Some(">=99.0"), None, "requires host"),
+            (
+                "decoder-plugin",
+                None,
+                Some("^0.3"),
+                "requires stellatune:plugin",
+            ),
+            ("audio-plugin", None, None, "unknown world"),
+            ("other:pkg/decoder-plugin", None, None, "targets package"),
+            (
+                "stellatune:plugin/decoder-plugin@0.3.0",
+                None,
+                None,
+                "built against",
+            ),
+        ];
+        for (world, host, wit, expected) in cases {
+            let requires = PluginRequirements {
+                host: host.map(str::to_string),
+                wit: wit.map(str::to_string),
+            };
+            let error = check_against(&manifest(world, requires), Some(&app_version()))
+                .expect_err(world)
+                .to_string();
+            assert!(error.contains(expected), "{world}: {error}");
+        }
+    }
+
+    #[test]
+    fn skips_host_requirement_until_the_host_version_is_set() {
+        let requires = PluginRequirements {
+            host: Some(">=99.0".to_string()),
+            wit: None,
+        };
+        assert!(check_against(&manifest("decoder-plugin", requires), None).is_ok());
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::ConfigSchema;
+
+/// Declarative upgrade step from schema version `from` to `from + 1`.
+///
+/// Steps run in order: `rename`, then `remove`, then `set`. Fields still missing after
+/// the last step are filled from their `default`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigMigration {
+    pub from: u32,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rename: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<String>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub set: Map<String, Value>,
+}
+
+impl ConfigMigration {
+    fn apply(&self, object: &mut Map<String, Value>) {
+        for (from, to) in &self.rename {
+            if let Some(value) = object.remove(from) {
+                object.insert(to.clone(), value);
+            }
+        }
+        for key in &self.remove {
+            object.remove(key);
+        }
+        for (key, value) in &self.set {
+            object.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+impl ConfigSchema {
+    /// Upgrades a config persisted under `from_version` to this schema's version.
+    ///
+    /// Configs from a newer schema (a downgraded plugin) are returned unchanged apart
+    /// from defaults, and validation decides whether they are still usable.
+    pub fn migrate(&self, mut config: Value, from_version: u32) -> Value {
+        if !config.is_object() {
+            config = Value::Object(Map::new());
+        }
+        if let Some(object) = config.as_object_mut() {
+            for migration in &self.migrations {
+                if migration.from >= from_version && migration.from < self.version {
+                    migration.apply(object);
+                }
+            }
+        }
+        self.apply_defaults(&mut config);
+        config
+    }
+}
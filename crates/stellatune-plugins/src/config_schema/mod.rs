@@ -0,0 +1,176 @@
+//! Host-side subset of JSON Schema used for plugin configuration panels.
+//!
+//! Capabilities ship `config_schema_json`; the host parses it into a [`ConfigSchema`]
+//! that frontends render as a panel and that the runtime validates configs against
+//! before they reach `plan-config-update-json` / `apply-config-update-json`.
+//!
+//! Supported keywords per field: `type` (optionally with `"null"`), `title`,
+//! `description`, `default`, `enum`, `oneOf` of `const`/`title`, `minimum`,
+//! `maximum`, `minLength`, `maxLength`, `pattern`, `minItems`, `maxItems` and
+//! `items: { "type": "string" }`. Root keywords: `properties`, `required` and
+//! `additionalProperties`. Host extensions live under `x-stellatune`:
+//!
+//! - root: `version`, `groups: [{ id, title, description }]`, `migrations`
+//! - field: `group`, `order`, `widget` (`password` | `textarea`)
+
+mod migrate;
+mod parse;
+mod validate;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub use migrate::ConfigMigration;
+pub use validate::{ConfigErrorCode, ConfigFieldError};
+
+pub const CONFIG_SCHEMA_EXTENSION_KEY: &str = "x-stellatune";
+pub const DEFAULT_CONFIG_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSchema {
+    pub version: u32,
+    pub additional_properties: bool,
+    pub groups: Vec<ConfigFieldGroup>,
+    pub fields: Vec<ConfigField>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub migrations: Vec<ConfigMigration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigFieldGroup {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigField {
+    pub key: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub order: i64,
+    pub required: bool,
+    pub nullable: bool,
+    #[serde(default)]
+    pub default: Option<Value>,
+    pub control: ConfigFieldControl,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ConfigFieldControl {
+    Text {
+        min_length: Option<u32>,
+        max_length: Option<u32>,
+        pattern: Option<ConfigPattern>,
+        secret: bool,
+        multiline: bool,
+    },
+    Number {
+        integer: bool,
+        minimum: Option<f64>,
+        maximum: Option<f64>,
+    },
+    Toggle,
+    Select {
+        options: Vec<ConfigSelectOption>,
+    },
+    TextList {
+        min_items: Option<u32>,
+        max_items: Option<u32>,
+    },
+    Json,
+}
+
+/// `pattern` keyword compiled once at parse time; serializes as its source string.
+#[derive(Debug, Clone)]
+pub struct ConfigPattern(regex::Regex);
+
+impl ConfigPattern {
+    pub fn new(pattern: &str) -> std::result::Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Self)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+}
+
+impl PartialEq for ConfigPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl std::fmt::Display for ConfigPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ConfigPattern {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfigPattern {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSelectOption {
+    pub value: Value,
+    pub label: String,
+}
+
+impl ConfigSchema {
+    /// Schema that accepts any object; used when a capability ships no schema.
+    pub fn permissive() -> Self {
+        Self {
+            version: DEFAULT_CONFIG_SCHEMA_VERSION,
+            additional_properties: true,
+            groups: Vec::new(),
+            fields: Vec::new(),
+            migrations: Vec::new(),
+        }
+    }
+
+    pub fn field(&self, key: &str) -> Option<&ConfigField> {
+        self.fields.iter().find(|field| field.key == key)
+    }
+
+    /// Fills missing fields that declare a `default`.
+    pub fn apply_defaults(&self, config: &mut Value) {
+        let Some(object) = config.as_object_mut() else {
+            return;
+        };
+        for field in &self.fields {
+            if let Some(default) = &field.default
+                && !object.contains_key(&field.key)
+            {
+                object.insert(field.key.clone(), default.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,277 @@
+use std::collections::BTreeSet;
+
+use serde_json::{Map, Value};
+
+use crate::error::{Error, Result};
+
+use super::{
+    CONFIG_SCHEMA_EXTENSION_KEY, ConfigField, ConfigFieldControl, ConfigFieldGroup,
+    ConfigMigration, ConfigPattern, ConfigSchema, ConfigSelectOption,
+    DEFAULT_CONFIG_SCHEMA_VERSION,
+};
+
+impl ConfigSchema {
+    /// Parses a capability `config_schema_json`. Blank input yields [`ConfigSchema::permissive`].
+    pub fn parse(schema_json: &str) -> Result<Self> {
+        if schema_json.trim().is_empty() {
+            return Ok(Self::permissive());
+        }
+        let root = serde_json::from_str::<Value>(schema_json)
+            .map_err(|error| Error::invalid_input(format!("config schema is not JSON: {error}")))?;
+        Self::from_value(&root)
+    }
+
+    pub fn from_value(root: &Value) -> Result<Self> {
+        let Some(root) = root.as_object() else {
+            return Err(Error::invalid_input("config schema root must be an object"));
+        };
+        if let Some(kind) = root.get("type").and_then(Value::as_str)
+            && kind != "object"
+        {
+            return Err(Error::invalid_input(format!(
+                "config schema root type must be `object`, got `{kind}`"
+            )));
+        }
+        let ext = extension(root);
+
+        let version = match ext.and_then(|ext| ext.get("version")) {
+            None => DEFAULT_CONFIG_SCHEMA_VERSION,
+            Some(value) => value
+                .as_u64()
+                .filter(|version| *version >= 1 && *version <= u32::MAX as u64)
+                .map(|version| version as u32)
+                .ok_or_else(|| {
+                    Error::invalid_input("x-stellatune.version must be a positive integer")
+                })?,
+        };
+        let groups = match ext.and_then(|ext| ext.get("groups")) {
+            None => Vec::new(),
+            Some(value) => {
+                serde_json::from_value::<Vec<ConfigFieldGroup>>(value.clone()).map_err(|error| {
+                    Error::invalid_input(format!("invalid x-stellatune.groups: {error}"))
+                })?
+            },
+        };
+        let mut migrations = match ext.and_then(|ext| ext.get("migrations")) {
+            None => Vec::new(),
+            Some(value) => {
+                serde_json::from_value::<Vec<ConfigMigration>>(value.clone()).map_err(|error| {
+                    Error::invalid_input(format!("invalid x-stellatune.migrations: {error}"))
+                })?
+            },
+        };
+        migrations.sort_by_key(|migration| migration.from);
+        if let Some(migration) = migrations
+            .iter()
+            .find(|migration| migration.from >= version)
+        {
+            return Err(Error::invalid_input(format!(
+                "migration from version {} does not lead to schema version {version}",
+                migration.from
+            )));
+        }
+
+        let required = root
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect::<BTreeSet<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut fields = Vec::new();
+        if let Some(properties) = root.get("properties") {
+            let Some(properties) = properties.as_object() else {
+                return Err(Error::invalid_input(
+                    "config schema `properties` must be an object",
+                ));
+            };
+            for (key, spec) in properties {
+                let Some(spec) = spec.as_object() else {
+                    return Err(Error::invalid_input(format!(
+                        "config schema property `{key}` must be an object"
+                    )));
+                };
+                fields.push(parse_field(key, spec, required.contains(key))?);
+            }
+        }
+        for field in &fields {
+            if let Some(group) = &field.group
+                && !groups.iter().any(|item| &item.id == group)
+            {
+                return Err(Error::invalid_input(format!(
+                    "field `{}` references unknown group `{group}`",
+                    field.key
+                )));
+            }
+        }
+        fields.sort_by(|a, b| a.order.cmp(&b.order).then_with(|| a.key.cmp(&b.key)));
+
+        Ok(Self {
+            version,
+            additional_properties: root
+                .get("additionalProperties")
+                .and_then(Value::as_bool)
+                .unwrap_or(true),
+            groups,
+            fields,
+            migrations,
+        })
+    }
+}
+
+fn extension(object: &Map<String, Value>) -> Option<&Map<String, Value>> {
+    object
+        .get(CONFIG_SCHEMA_EXTENSION_KEY)
+        .and_then(Value::as_object)
+}
+
+fn parse_field(key: &str, spec: &Map<String, Value>, required: bool) -> Result<ConfigField> {
+    let ext = extension(spec);
+    let (kind, nullable) = read_type(spec.get("type"));
+    let order = ext
+        .and_then(|ext| ext.get("order"))
+        .or_else(|| spec.get("x-order"))
+        .and_then(Value::as_i64)
+        .unwrap_or(0);
+    let widget = ext
+        .and_then(|ext| ext.get("widget"))
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    let control = if let Some(options) = read_options(key, spec)? {
+        ConfigFieldControl::Select { options }
+    } else {
+        match kind.as_deref() {
+            Some("string") => {
+                let pattern = read_str(spec, "pattern")
+                    .map(|pattern| {
+                        ConfigPattern::new(&pattern).map_err(|error| {
+                            Error::invalid_input(format!(
+                                "field `{key}` has an invalid pattern: {error}"
+                            ))
+                        })
+                    })
+                    .transpose()?;
+                ConfigFieldControl::Text {
+                    min_length: read_u32(spec, "minLength"),
+                    max_length: read_u32(spec, "maxLength"),
+                    pattern,
+                    secret: widget == "password",
+                    multiline: widget == "textarea",
+                }
+            },
+            Some(kind @ ("integer" | "number")) => ConfigFieldControl::Number {
+                integer: kind == "integer",
+                minimum: spec.get("minimum").and_then(Value::as_f64),
+                maximum: spec.get("maximum").and_then(Value::as_f64),
+            },
+            Some("boolean") => ConfigFieldControl::Toggle,
+            Some("array")
+                if spec
+                    .get("items")
+                    .and_then(|items| items.get("type"))
+                    .and_then(Value::as_str)
+                    == Some("string") =>
+            {
+                ConfigFieldControl::TextList {
+                    min_items: read_u32(spec, "minItems"),
+                    max_items: read_u32(spec, "maxItems"),
+                }
+            },
+            _ => ConfigFieldControl::Json,
+        }
+    };
+
+    Ok(ConfigField {
+        key: key.to_string(),
+        title: read_str(spec, "title").unwrap_or_else(|| key.to_string()),
+        description: read_str(spec, "description"),
+        group: ext
+            .and_then(|ext| ext.get("group"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        order,
+        required,
+        nullable,
+        default: spec.get("default").cloned(),
+        control,
+    })
+}
+
+/// Returns the first non-null type and whether `"null"` is allowed.
+fn read_type(raw: Option<&Value>) -> (Option<String>, bool) {
+    match raw {
+        Some(Value::String(kind)) => (Some(kind.clone()), kind == "null"),
+        Some(Value::Array(kinds)) => {
+            let kinds = kinds.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+            let nullable = kinds.contains(&"null");
+            let kind = kinds.into_iter().find(|kind| *kind != "null");
+            (kind.map(str::to_string), nullable)
+        },
+        _ => (None, false),
+    }
+}
+
+fn read_options(key: &str, spec: &Map<String, Value>) -> Result<Option<Vec<ConfigSelectOption>>> {
+    if let Some(values) = spec.get("enum") {
+        let Some(values) = values.as_array() else {
+            return Err(Error::invalid_input(format!(
+                "field `{key}` enum must be an array"
+            )));
+        };
+        let options = values
+            .iter()
+            .filter(|value| !value.is_null())
+            .map(|value| ConfigSelectOption {
+                value: value.clone(),
+                label: option_label(value),
+            })
+            .collect();
+        return Ok(Some(options));
+    }
+    let Some(variants) = spec.get("oneOf").and_then(Value::as_array) else {
+        return Ok(None);
+    };
+    let mut options = Vec::with_capacity(variants.len());
+    for variant in variants {
+        let Some(value) = variant.get("const") else {
+            // `oneOf` over sub-schemas is outside the supported subset.
+            return Ok(None);
+        };
+        options.push(ConfigSelectOption {
+            value: value.clone(),
+            label: variant
+                .get("title")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| option_label(value)),
+        });
+    }
+    Ok(Some(options))
+}
+
+fn option_label(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn read_str(spec: &Map<String, Value>, key: &str) -> Option<String> {
+    spec.get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn read_u32(spec: &Map<String, Value>, key: &str) -> Option<u32> {
+    spec.get(key)
+        .and_then(Value::as_u64)
+        .map(|value| value.min(u32::MAX as u64) as u32)
+}
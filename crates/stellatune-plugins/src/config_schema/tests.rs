@@ -0,0 +1,176 @@
+use serde_json::json;
+
+use crate::config_schema::{ConfigErrorCode, ConfigFieldControl, ConfigSchema};
+
+const SCHEMA: &str = r#"{
+  "type": "object",
+  "additionalProperties": false,
+  "required": ["base_url", "level"],
+  "x-stellatune": {
+    "version": 3,
+    "groups": [{ "id": "network", "title": "Network" }],
+    "migrations": [
+      { "from": 1, "rename": { "url": "base_url" } },
+      { "from": 2, "remove": ["legacy"], "set": { "level": "standard" } }
+    ]
+  },
+  "properties": {
+    "base_url": {
+      "type": "string",
+      "title": "Base URL",
+      "pattern": "^https?://",
+      "x-stellatune": { "group": "network", "order": 1 }
+    },
+    "token": { "type": ["string", "null"], "x-stellatune": { "widget": "password" } },
+    "timeout_ms": { "type": "integer", "minimum": 500, "maximum": 60000, "default": 8000 },
+    "level": {
+      "oneOf": [
+        { "const": "standard", "title": "Standard" },
+        { "const": "lossless", "title": "Lossless" }
+      ]
+    },
+    "args": { "type": "array", "items": { "type": "string" }, "maxItems": 2 },
+    "enabled": { "type": "boolean", "default": true }
+  }
+}"#;
+
+fn schema() -> ConfigSchema {
+    ConfigSchema::parse(SCHEMA).expect("parse schema")
+}
+
+fn codes(
+    errors: &[crate::config_schema::ConfigFieldError],
+) -> Vec<(Option<&str>, ConfigErrorCode)> {
+    errors
+        .iter()
+        .map(|error| (error.field.as_deref(), error.code))
+        .collect()
+}
+
+#[test]
+fn parses_fields_groups_and_controls() {
+    let schema = schema();
+    assert_eq!(schema.version, 3);
+    assert_eq!(schema.groups.len(), 1);
+    assert!(!schema.additional_properties);
+
+    let base_url = schema.field("base_url").expect("base_url field");
+    assert_eq!(base_url.title, "Base URL");
+    assert_eq!(base_url.group.as_deref(), Some("network"));
+    assert!(base_url.required);
+
+    assert!(matches!(
+        schema.field("token").expect("token").control,
+        ConfigFieldControl::Text { secret: true, .. }
+    ));
+    assert!(schema.field("token").expect("token").nullable);
+    assert!(matches!(
+        &schema.field("level").expect("level").control,
+        ConfigFieldControl::Select { options } if options.len() == 2 && options[1].label == "Lossless"
+    ));
+    assert!(matches!(
+        schema.field("args").expect("args").control,
+        ConfigFieldControl::TextList {
+            max_items: Some(2),
+            ..
+        }
+    ));
+    assert_eq!(
+        schema.fields.last().map(|field| field.key.as_str()),
+        Some("base_url")
+    );
+}
+
+#[test]
+fn validate_reports_field_level_errors() {
+    let schema = schema();
+    let errors = schema.validate(&json!({
+        "base_url": "ftp://example",
+        "timeout_ms": 100,
+        "level": "ultra",
+        "args": ["a", "b", "c"],
+        "enabled": "yes",
+        "extra": 1
+    }));
+    assert_eq!(
+        codes(&errors),
+        vec![
+            (Some("args"), ConfigErrorCode::TooLong),
+            (Some("enabled"), ConfigErrorCode::TypeMismatch),
+            (Some("level"), ConfigErrorCode::NotAnOption),
+            (Some("timeout_ms"), ConfigErrorCode::BelowMinimum),
+            (Some("base_url"), ConfigErrorCode::PatternMismatch),
+            (Some("extra"), ConfigErrorCode::UnknownField),
+        ]
+    );
+
+    let errors = schema.validate(&json!({ "token": null, "timeout_ms": 1.5 }));
+    assert_eq!(
+        codes(&errors),
+        vec![
+            (Some("level"), ConfigErrorCode::Required),
+            (Some("timeout_ms"), ConfigErrorCode::TypeMismatch),
+            (Some("base_url"), ConfigErrorCode::Required),
+        ]
+    );
+
+    assert_eq!(
+        codes(&schema.validate_json("[]")),
+        vec![(None, ConfigErrorCode::NotObject)]
+    );
+    assert!(
+        schema
+            .validate(&json!({ "base_url": "https://a", "level": "standard" }))
+            .is_empty()
+    );
+}
+
+#[test]
+fn migrate_applies_steps_from_persisted_version_and_fills_defaults() {
+    let schema = schema();
+    let migrated = schema.migrate(json!({ "url": "https://a", "legacy": true }), 1);
+    assert_eq!(
+        migrated,
+        json!({
+            "base_url": "https://a",
+            "level": "standard",
+            "timeout_ms": 8000,
+            "enabled": true
+        })
+    );
+    assert!(schema.validate(&migrated).is_empty());
+
+    let current = schema.migrate(json!({ "url": "kept", "level": "lossless" }), 3);
+    assert_eq!(current["url"], "kept");
+    assert_eq!(current["level"], "lossless");
+}
+
+#[test]
+fn rejects_malformed_schemas() {
+    for schema in [
+        r#"{ "type": "array" }"#,
+        r#"{ "properties": { "a": { "type": "string", "pattern": "(" } } }"#,
+        r#"{ "properties": { "a": { "x-stellatune": { "group": "missing" } } } }"#,
+        r#"{ "x-stellatune": { "version": 1, "migrations": [{ "from": 1 }] } }"#,
+    ] {
+        assert!(ConfigSchema::parse(schema).is_err(), "{schema}");
+    }
+    assert_eq!(
+        ConfigSchema::parse("").expect("blank schema"),
+        ConfigSchema::permissive()
+    );
+}
+
+#[test]
+fn panel_round_trips_with_its_compiled_pattern() {
+    let schema = schema();
+    let panel = serde_json::to_value(&schema).expect("serialize panel");
+    let base_url = panel["fields"]
+        .as_array()
+        .and_then(|fields| fields.iter().find(|field| field["key"] == "base_url"))
+        .expect("base_url field");
+    assert_eq!(base_url["control"]["pattern"], "^https?://");
+
+    let restored = serde_json::from_value::<ConfigSchema>(panel).expect("deserialize panel");
+    assert_eq!(restored, schema);
+}
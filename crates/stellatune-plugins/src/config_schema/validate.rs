@@ -0,0 +1,244 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+
+use super::{ConfigField, ConfigFieldControl, ConfigSchema};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigErrorCode {
+    InvalidJson,
+    NotObject,
+    Required,
+    UnknownField,
+    TypeMismatch,
+    BelowMinimum,
+    AboveMaximum,
+    TooShort,
+    TooLong,
+    PatternMismatch,
+    NotAnOption,
+}
+
+/// One validation failure. `field` is `None` for errors about the config as a whole.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigFieldError {
+    pub field: Option<String>,
+    pub code: ConfigErrorCode,
+    pub message: String,
+}
+
+impl ConfigFieldError {
+    fn root(code: ConfigErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            field: None,
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn field(field: &str, code: ConfigErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            field: Some(field.to_string()),
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl ConfigSchema {
+    pub fn validate_json(&self, config_json: &str) -> Vec<ConfigFieldError> {
+        match serde_json::from_str::<Value>(config_json) {
+            Ok(config) => self.validate(&config),
+            Err(error) => vec![ConfigFieldError::root(
+                ConfigErrorCode::InvalidJson,
+                format!("config is not JSON: {error}"),
+            )],
+        }
+    }
+
+    pub fn validate(&self, config: &Value) -> Vec<ConfigFieldError> {
+        let Some(object) = config.as_object() else {
+            return vec![ConfigFieldError::root(
+                ConfigErrorCode::NotObject,
+                "config must be a JSON object",
+            )];
+        };
+        let mut errors = Vec::new();
+        for field in &self.fields {
+            match object.get(&field.key) {
+                None | Some(Value::Null) if field.required && !field.nullable => {
+                    errors.push(ConfigFieldError::field(
+                        &field.key,
+                        ConfigErrorCode::Required,
+                        format!("`{}` is required", field.title),
+                    ));
+                },
+                None | Some(Value::Null) => {},
+                Some(value) => validate_field(field, value, &mut errors),
+            }
+        }
+        if !self.additional_properties {
+            for key in object.keys() {
+                if self.field(key).is_none() {
+                    errors.push(ConfigFieldError::field(
+                        key,
+                        ConfigErrorCode::UnknownField,
+                        format!("unknown field `{key}`"),
+                    ));
+                }
+            }
+        }
+        errors
+    }
+
+    /// Rejects `config_json` with an [`Error::InvalidInput`] listing every field error.
+    pub fn check_json(&self, config_json: &str) -> Result<()> {
+        let errors = self.validate_json(config_json);
+        if errors.is_empty() {
+            return Ok(());
+        }
+        let details = errors
+            .iter()
+            .map(|error| match &error.field {
+                Some(field) => format!("{field}: {}", error.message),
+                None => error.message.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        Err(Error::invalid_input(format!(
+            "config rejected by schema: {details}"
+        )))
+    }
+}
+
+fn validate_field(field: &ConfigField, value: &Value, errors: &mut Vec<ConfigFieldError>) {
+    let key = field.key.as_str();
+    let mismatch = |expected: &str| {
+        ConfigFieldError::field(
+            key,
+            ConfigErrorCode::TypeMismatch,
+            format!("`{}` must be {expected}", field.title),
+        )
+    };
+    match &field.control {
+        ConfigFieldControl::Text {
+            min_length,
+            max_length,
+            pattern,
+            ..
+        } => {
+            let Some(text) = value.as_str() else {
+                errors.push(mismatch("a string"));
+                return;
+            };
+            let length = text.chars().count();
+            if let Some(min) = min_length
+                && length < *min as usize
+            {
+                errors.push(ConfigFieldError::field(
+                    key,
+                    ConfigErrorCode::TooShort,
+                    format!("`{}` must be at least {min} characters", field.title),
+                ));
+            }
+            if let Some(max) = max_length
+                && length > *max as usize
+            {
+                errors.push(ConfigFieldError::field(
+                    key,
+                    ConfigErrorCode::TooLong,
+                    format!("`{}` must be at most {max} characters", field.title),
+                ));
+            }
+            if let Some(pattern) = pattern
+                && !pattern.is_match(text)
+            {
+                errors.push(ConfigFieldError::field(
+                    key,
+                    ConfigErrorCode::PatternMismatch,
+                    format!("`{}` must match `{pattern}`", field.title),
+                ));
+            }
+        },
+        ConfigFieldControl::Number {
+            integer,
+            minimum,
+            maximum,
+        } => {
+            let Some(number) = value.as_f64() else {
+                errors.push(mismatch("a number"));
+                return;
+            };
+            if *integer && !(value.is_i64() || value.is_u64()) {
+                errors.push(mismatch("an integer"));
+                return;
+            }
+            if let Some(min) = minimum
+                && number < *min
+            {
+                errors.push(ConfigFieldError::field(
+                    key,
+                    ConfigErrorCode::BelowMinimum,
+                    format!("`{}` must be at least {min}", field.title),
+                ));
+            }
+            if let Some(max) = maximum
+                && number > *max
+            {
+                errors.push(ConfigFieldError::field(
+                    key,
+                    ConfigErrorCode::AboveMaximum,
+                    format!("`{}` must be at most {max}", field.title),
+                ));
+            }
+        },
+        ConfigFieldControl::Toggle => {
+            if !value.is_boolean() {
+                errors.push(mismatch("a boolean"));
+            }
+        },
+        ConfigFieldControl::Select { options } => {
+            if !options.iter().any(|option| &option.value == value) {
+                errors.push(ConfigFieldError::field(
+                    key,
+                    ConfigErrorCode::NotAnOption,
+                    format!("`{}` must be one of the listed options", field.title),
+                ));
+            }
+        },
+        ConfigFieldControl::TextList {
+            min_items,
+            max_items,
+        } => {
+            let Some(items) = value.as_array() else {
+                errors.push(mismatch("a list of strings"));
+                return;
+            };
+            if !items.iter().all(Value::is_string) {
+                errors.push(mismatch("a list of strings"));
+                return;
+            }
+            if let Some(min) = min_items
+                && items.len() < *min as usize
+            {
+                errors.push(ConfigFieldError::field(
+                    key,
+                    ConfigErrorCode::TooShort,
+                    format!("`{}` needs at least {min} entries", field.title),
+                ));
+            }
+            if let Some(max) = max_items
+                && items.len() > *max as usize
+            {
+                errors.push(ConfigFieldError::field(
+                    key,
+                    ConfigErrorCode::TooLong,
+                    format!("`{}` allows at most {max} entries", field.title),
+                ));
+            }
+        },
+        ConfigFieldControl::Json => {},
+    }
+}
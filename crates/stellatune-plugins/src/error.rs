@@ -14,6 +14,12 @@ pub enum Error {
     Conflict { resource: &'static str, id: String },
     #[error("unsupported: {message}")]
     Unsupported { message: String },
+    #[error("denied: {message}")]
+    Denied { message: String },
+    #[error("auth required: {message}")]
+    AuthRequired { message: String },
+    #[error("auth expired: {message}")]
+    AuthExpired { message: String },
     #[error("{operation} failed: {details}")]
     Operation {
         operation: &'static str,
@@ -73,6 +79,29 @@ impl Error {
         }
     }
 
+    pub fn denied(message: impl Into<String>) -> Self {
+        Self::Denied {
+            message: message.into(),
+        }
+    }
+
+    pub fn auth_required(message: impl Into<String>) -> Self {
+        Self::AuthRequired {
+            message: message.into(),
+        }
+    }
+
+    pub fn auth_expired(message: impl Into<String>) -> Self {
+        Self::AuthExpired {
+            message: message.into(),
+        }
+    }
+
+    /// Whether the failure asks the user to log in (again) rather than retry.
+    pub fn is_auth_failure(&self) -> bool {
+        matches!(self, Self::AuthRequired { .. } | Self::AuthExpired { .. })
+    }
+
     pub fn operation(operation: &'static str, details: impl Into<String>) -> Self {
         Self::Operation {
             operation,
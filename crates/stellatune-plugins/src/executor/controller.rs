@@ -4,6 +4,7 @@ use crate::error::Result;
 
 use crate::executor::{
     ActivePluginRecord, WasmPluginController, WasmtimePluginController, WorldKind, classify_world,
+    compile_component,
 };
 use crate::runtime::model::{
     PluginDisableReason, RuntimeCapabilityDescriptor, RuntimePluginDirective, RuntimePluginInfo,
@@ -52,13 +53,20 @@ impl WasmPluginController for WasmtimePluginController {
                 ));
             }
             let component_path: std::path::PathBuf = plugin.root_dir.join(&component_rel_path);
-            self.load_component_cached(&component_path).map_err(|e| {
+            let component = self.load_component_cached(&component_path).map_err(|e| {
                 crate::op_error!(
                     "failed to load component for plugin `{}` component `{}`: {e:#}",
                     plugin.id,
                     component_id
                 )
             })?;
+            self.check_component_links(&world, &component)
+                .map_err(|message| {
+                    crate::error::Error::unsupported(format!(
+                        "component `{component_id}` of plugin `{}` {message}",
+                        plugin.id
+                    ))
+                })?;
         }
 
         let mut routes = self.directives.write();
@@ -70,7 +78,9 @@ impl WasmPluginController for WasmtimePluginController {
         }
         drop(routes);
         if !already_installed {
-            self.sidecar_registry.plugin_activated(plugin.id.as_str());
+            self.hosts
+                .sidecar_registry
+                .plugin_activated(plugin.id.as_str());
         }
 
         let mut plugins = self.plugins.write();
@@ -102,7 +112,8 @@ impl WasmPluginController for WasmtimePluginController {
         drop(routes);
         if was_active {
             // Package-scoped sidecars must follow plugin enabled/disabled lifecycle.
-            self.sidecar_registry
+            self.hosts
+                .sidecar_registry
                 .plugin_deactivated(plugin_id, PACKAGE_SIDECAR_SHUTDOWN_GRACE_MS);
         }
 
@@ -115,6 +126,40 @@ impl WasmPluginController for WasmtimePluginController {
         Ok(())
     }
 
+    fn reload_components(
+        &self,
+        plugin: &RuntimePluginInfo,
+        capabilities: &[RuntimeCapabilityDescriptor],
+    ) -> Result<()> {
+        let mut fresh = BTreeMap::new();
+        for capability in capabilities {
+            if classify_world(&capability.world) == WorldKind::Unknown {
+                return Err(crate::op_error!(
+                    "unsupported world `{}` in plugin `{}` component `{}`",
+                    capability.world,
+                    plugin.id,
+                    capability.component_id
+                ));
+            }
+            let component_path = plugin.root_dir.join(&capability.component_rel_path);
+            if fresh.contains_key(&component_path) {
+                continue;
+            }
+            let component = compile_component(&self.engine, &component_path).map_err(|e| {
+                crate::op_error!(
+                    "failed to load component for plugin `{}` component `{}`: {e:#}",
+                    plugin.id,
+                    capability.component_id
+                )
+            })?;
+            fresh.insert(component_path, component);
+        }
+        self.component_cache.write().extend(fresh);
+        // Installing an active plugin again sends `Rebuild` to every live instance, which
+        // respawns it from the cache entries replaced above.
+        self.install_plugin(plugin, capabilities)
+    }
+
     fn dispatch_directive(&self, plugin_id: &str, directive: RuntimePluginDirective) -> Result<()> {
         let plugin_id = plugin_id.trim();
         if plugin_id.is_empty() {
@@ -153,7 +198,8 @@ impl WasmPluginController for WasmtimePluginController {
         routes.senders.clear();
         drop(routes);
         for plugin_id in active_plugin_ids {
-            self.sidecar_registry
+            self.hosts
+                .sidecar_registry
                 .plugin_deactivated(plugin_id.as_str(), PACKAGE_SIDECAR_SHUTDOWN_GRACE_MS);
         }
 
@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::host::http::{HttpAllowlist, HttpClientHost, HttpHeader, HttpRequest, HttpResponseBody};
+
+/// Per-store `http-client` state: the shared client, the plugin's host allowlist and the
+/// response bodies the guest still holds.
+pub(crate) struct HttpState {
+    client: Arc<dyn HttpClientHost>,
+    allowlist: HttpAllowlist,
+    next_body_rep: u32,
+    bodies: BTreeMap<u32, Box<dyn HttpResponseBody>>,
+}
+
+pub(crate) struct HttpResponseHead {
+    pub(crate) status: u16,
+    pub(crate) headers: Vec<HttpHeader>,
+    pub(crate) body_rep: u32,
+}
+
+impl HttpState {
+    pub(crate) fn new(client: Arc<dyn HttpClientHost>, allowlist: HttpAllowlist) -> Self {
+        Self {
+            client,
+            allowlist,
+            next_body_rep: 1,
+            bodies: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn fetch_json(&self, url: &str) -> Result<String> {
+        self.allowlist.check(url)?;
+        self.client.fetch_json(url, &self.allowlist)
+    }
+
+    pub(crate) fn send(&mut self, request: &HttpRequest) -> Result<HttpResponseHead> {
+        self.allowlist.check(&request.url)?;
+        let response = self.client.send(request, &self.allowlist)?;
+        let body_rep = self.alloc_body_rep();
+        self.bodies.insert(body_rep, response.body);
+        Ok(HttpResponseHead {
+            status: response.status,
+            headers: response.headers,
+            body_rep,
+        })
+    }
+
+    pub(crate) fn read_body(&mut self, body_rep: u32, max_bytes: u32) -> Result<Vec<u8>> {
+        let body = self
+            .bodies
+            .get_mut(&body_rep)
+            .ok_or_else(|| crate::op_error!("http response body `{body_rep}` not found"))?;
+        body.read(max_bytes)
+    }
+
+    pub(crate) fn close_body(&mut self, body_rep: u32) {
+        if let Some(body) = self.bodies.get_mut(&body_rep) {
+            body.close();
+        }
+    }
+
+    pub(crate) fn drop_body(&mut self, body_rep: u32) {
+        if let Some(mut body) = self.bodies.remove(&body_rep) {
+            body.close();
+        }
+    }
+
+    fn alloc_body_rep(&mut self) -> u32 {
+        let rep = self.next_body_rep;
+        self.next_body_rep = self.next_body_rep.saturating_add(1);
+        if self.next_body_rep == 0 {
+            self.next_body_rep = 1;
+        }
+        rep
+    }
+}
@@ -3,28 +3,38 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::mpsc::{Receiver, Sender};
 
+use crate::config_schema::ConfigSchema;
 use crate::error::Result;
 use parking_lot::RwLock;
 use tracing::warn;
-use wasmtime::component::{Component, HasSelf, Linker};
+use wasmtime::component::{Component, HasSelf, InstancePre, Linker};
 use wasmtime::{Cache, Config, Engine, Store};
 use wasmtime_wasi::p2::add_to_linker_sync;
 use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxBuilder};
 
 use stellatune_host_bindings as wasm_host;
 use stellatune_host_bindings::generated::decoder_plugin::DecoderPlugin as DecoderPluginBinding;
+use stellatune_host_bindings::generated::decoder_plugin::DecoderPluginPre as DecoderPluginBindingPre;
 use stellatune_host_bindings::generated::decoder_plugin::exports::stellatune::plugin::lifecycle as decoder_lifecycle;
 use stellatune_host_bindings::generated::dsp_plugin::DspPlugin as DspPluginBinding;
+use stellatune_host_bindings::generated::dsp_plugin::DspPluginPre as DspPluginBindingPre;
 use stellatune_host_bindings::generated::dsp_plugin::exports::stellatune::plugin::lifecycle as dsp_lifecycle;
 use stellatune_host_bindings::generated::lyrics_plugin::LyricsPlugin as LyricsPluginBinding;
+use stellatune_host_bindings::generated::lyrics_plugin::LyricsPluginPre as LyricsPluginBindingPre;
 use stellatune_host_bindings::generated::lyrics_plugin::exports::stellatune::plugin::lifecycle as lyrics_lifecycle;
 use stellatune_host_bindings::generated::output_sink_plugin::OutputSinkPlugin as OutputSinkPluginBinding;
+use stellatune_host_bindings::generated::output_sink_plugin::OutputSinkPluginPre as OutputSinkPluginBindingPre;
 use stellatune_host_bindings::generated::output_sink_plugin::exports::stellatune::plugin::lifecycle as output_sink_lifecycle;
+use stellatune_host_bindings::generated::playback_listener_plugin::PlaybackListenerPlugin as PlaybackListenerPluginBinding;
+use stellatune_host_bindings::generated::playback_listener_plugin::PlaybackListenerPluginPre as PlaybackListenerPluginBindingPre;
+use stellatune_host_bindings::generated::playback_listener_plugin::exports::stellatune::plugin::lifecycle as playback_listener_lifecycle;
 use stellatune_host_bindings::generated::source_plugin::SourcePlugin as SourcePluginBinding;
+use stellatune_host_bindings::generated::source_plugin::SourcePluginPre as SourcePluginBindingPre;
 use stellatune_host_bindings::generated::source_plugin::exports::stellatune::plugin::lifecycle as source_lifecycle;
 
-use crate::host::http::HttpClientHost;
-use crate::host::sidecar::{SidecarHost, default_sidecar_host};
+use crate::host::http::{HttpAllowlist, HttpClientHost};
+use crate::host::sidecar::{SidecarHost, SidecarStateEvent, default_sidecar_host};
+use crate::host::storage::PluginStorage;
 use crate::host::stream::HostStreamService;
 use crate::manifest::AbilityKind;
 use crate::runtime::model::{
@@ -32,9 +42,12 @@ use crate::runtime::model::{
 };
 
 pub mod plugin_cell;
-use plugin_cell::PluginCell;
+use plugin_cell::{PluginCell, Respawn};
+mod http_state;
 pub mod plugin_instance;
 mod sidecar_state;
+mod sidecar_supervisor;
+use http_state::HttpState;
 use sidecar_state::{PackageSidecarRegistry, SidecarState};
 
 pub trait WasmPluginController: Send + Sync {
@@ -44,6 +57,13 @@ pub trait WasmPluginController: Send + Sync {
         capabilities: &[RuntimeCapabilityDescriptor],
     ) -> Result<()>;
     fn uninstall_plugin(&self, plugin_id: &str, reason: PluginDisableReason) -> Result<()>;
+    /// Recompiles the plugin components from disk and rebuilds live instances on them.
+    /// Nothing is replaced unless every component compiles.
+    fn reload_components(
+        &self,
+        plugin: &RuntimePluginInfo,
+        capabilities: &[RuntimeCapabilityDescriptor],
+    ) -> Result<()>;
     fn dispatch_directive(&self, plugin_id: &str, directive: RuntimePluginDirective) -> Result<()>;
     fn shutdown(&self) -> Result<()>;
 }
@@ -53,6 +73,7 @@ use stores::decoder::DecoderStoreData;
 use stores::dsp::DspStoreData;
 use stores::lyrics::LyricsStoreData;
 use stores::output_sink::OutputSinkStoreData;
+use stores::playback_listener::PlaybackListenerStoreData;
 use stores::source::SourceStoreData;
 
 #[derive(Default)]
@@ -67,19 +88,38 @@ struct ActivePluginRecord {
     capabilities: Vec<RuntimeCapabilityDescriptor>,
 }
 
-pub struct WasmtimePluginController {
-    engine: Engine,
+type ComponentCache = RwLock<BTreeMap<PathBuf, Component>>;
+
+/// Host services handed to every new store; cloned into instance respawners so a
+/// rebuild can create fresh stores without borrowing the controller.
+#[derive(Clone)]
+struct StoreHosts {
     http_client: Arc<dyn HttpClientHost>,
     stream_service: Arc<dyn HostStreamService>,
     sidecar_registry: PackageSidecarRegistry,
+    /// Directory holding each plugin's storage; unset until the host provides one.
+    storage_root: Arc<RwLock<Option<PathBuf>>>,
+}
+
+#[derive(Debug, Clone)]
+struct PersistedConfig {
+    schema_version: u32,
+    config_json: String,
+}
+
+pub struct WasmtimePluginController {
+    engine: Engine,
+    hosts: StoreHosts,
     directives: RwLock<DirectiveRegistry>,
     plugins: RwLock<BTreeMap<String, ActivePluginRecord>>,
-    component_cache: RwLock<BTreeMap<PathBuf, Component>>,
+    persisted_configs: RwLock<BTreeMap<(String, String), PersistedConfig>>,
+    component_cache: Arc<ComponentCache>,
     decoder_linker: Linker<DecoderStoreData>,
     source_linker: Linker<SourceStoreData>,
     lyrics_linker: Linker<LyricsStoreData>,
     output_sink_linker: Linker<OutputSinkStoreData>,
     dsp_linker: Linker<DspStoreData>,
+    playback_listener_linker: Linker<PlaybackListenerStoreData>,
 }
 
 impl WasmtimePluginController {
@@ -90,7 +130,9 @@ impl WasmtimePluginController {
         Self::new_with_sidecar_host(http_client, stream_service, default_sidecar_host())
     }
 
-    pub(crate) fn new_with_sidecar_host(
+    /// Like [`Self::new`], but sidecar launches go through `sidecar_host` instead of
+    /// spawning processes.
+    pub fn new_with_sidecar_host(
         http_client: Arc<dyn HttpClientHost>,
         stream_service: Arc<dyn HostStreamService>,
         sidecar_host: Arc<dyn SidecarHost>,
@@ -142,21 +184,33 @@ impl WasmtimePluginController {
             state
         })?;
         add_to_linker_sync(&mut dsp_linker)?;
+
+        let mut playback_listener_linker: Linker<PlaybackListenerStoreData> = Linker::new(&engine);
+        PlaybackListenerPluginBinding::add_to_linker::<_, HasSelf<PlaybackListenerStoreData>>(
+            &mut playback_listener_linker,
+            |state| state,
+        )?;
+        add_to_linker_sync(&mut playback_listener_linker)?;
         let sidecar_registry = PackageSidecarRegistry::new(sidecar_host);
 
         Ok(Self {
             engine,
-            http_client,
-            stream_service,
-            sidecar_registry,
+            hosts: StoreHosts {
+                http_client,
+                stream_service,
+                sidecar_registry,
+                storage_root: Arc::default(),
+            },
             directives: RwLock::new(DirectiveRegistry::default()),
             plugins: RwLock::new(BTreeMap::new()),
-            component_cache: RwLock::new(BTreeMap::new()),
+            persisted_configs: RwLock::new(BTreeMap::new()),
+            component_cache: Arc::new(RwLock::new(BTreeMap::new())),
             decoder_linker,
             source_linker,
             lyrics_linker,
             output_sink_linker,
             dsp_linker,
+            playback_listener_linker,
         })
     }
 
@@ -167,65 +221,68 @@ impl WasmtimePluginController {
         Ok(Arc::new(Self::new(http_client, stream_service)?))
     }
 
-    fn new_decoder_store_data(&self, plugin_id: &str, plugin_root: &Path) -> DecoderStoreData {
-        let (wasi_ctx, wasi_table) = create_store_wasi_state();
-        DecoderStoreData {
-            stream_service: self.stream_service.clone(),
-            next_rep: 1,
-            streams: BTreeMap::new(),
-            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
-            plugin_root: plugin_root.to_path_buf(),
-            wasi_ctx,
-            wasi_table,
-        }
+    /// Sets the directory plugin storage lives in, normally
+    /// `<app data dir>/`[`PLUGIN_STORAGE_DIR_NAME`](crate::host::storage::PLUGIN_STORAGE_DIR_NAME).
+    /// Instances created before this call
+    /// keep their storage denied until they are rebuilt.
+    pub fn set_storage_root(&self, storage_root: PathBuf) {
+        *self.hosts.storage_root.write() = Some(storage_root);
     }
 
-    fn new_source_store_data(&self, plugin_id: &str, plugin_root: &Path) -> SourceStoreData {
-        let (wasi_ctx, wasi_table) = create_store_wasi_state();
-        SourceStoreData {
-            stream_service: self.stream_service.clone(),
-            next_rep: 1,
-            streams: BTreeMap::new(),
-            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
-            plugin_root: plugin_root.to_path_buf(),
-            wasi_ctx,
-            wasi_table,
-        }
+    pub fn storage_root(&self) -> Option<PathBuf> {
+        self.hosts.storage_root.read().clone()
     }
 
-    fn new_lyrics_store_data(&self, plugin_id: &str, plugin_root: &Path) -> LyricsStoreData {
-        let (wasi_ctx, wasi_table) = create_store_wasi_state();
-        LyricsStoreData {
-            http_client: self.http_client.clone(),
-            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
-            plugin_root: plugin_root.to_path_buf(),
-            wasi_ctx,
-            wasi_table,
-        }
+    /// Records the saved config of `plugin_id`/`type_id`, applied to every instance
+    /// created from now on. `config_json` is migrated from `schema_version` to the
+    /// capability's current schema at creation time.
+    pub fn set_persisted_config(
+        &self,
+        plugin_id: &str,
+        type_id: &str,
+        schema_version: u32,
+        config_json: String,
+    ) {
+        self.persisted_configs.write().insert(
+            (plugin_id.trim().to_string(), type_id.trim().to_string()),
+            PersistedConfig {
+                schema_version,
+                config_json,
+            },
+        );
     }
 
-    fn new_output_sink_store_data(
+    /// Saved config for a new instance, migrated and validated against `schema`. Configs
+    /// that no longer fit are logged and skipped so the instance starts from defaults.
+    pub(crate) fn persisted_config(
         &self,
         plugin_id: &str,
-        plugin_root: &Path,
-    ) -> OutputSinkStoreData {
-        let (wasi_ctx, wasi_table) = create_store_wasi_state();
-        OutputSinkStoreData {
-            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
-            plugin_root: plugin_root.to_path_buf(),
-            wasi_ctx,
-            wasi_table,
+        type_id: &str,
+        schema: &ConfigSchema,
+    ) -> Option<String> {
+        let persisted = self
+            .persisted_configs
+            .read()
+            .get(&(plugin_id.trim().to_string(), type_id.trim().to_string()))
+            .cloned()?;
+        let config = match serde_json::from_str(&persisted.config_json) {
+            Ok(config) => schema.migrate(config, persisted.schema_version),
+            Err(error) => {
+                warn!(plugin_id, type_id, %error, "ignoring persisted config that is not JSON");
+                return None;
+            },
+        };
+        let config_json = config.to_string();
+        if let Err(error) = schema.check_json(&config_json) {
+            warn!(plugin_id, type_id, %error, "ignoring persisted config that fails its schema");
+            return None;
         }
+        Some(config_json)
     }
 
-    fn new_dsp_store_data(&self, plugin_id: &str, plugin_root: &Path) -> DspStoreData {
-        let (wasi_ctx, wasi_table) = create_store_wasi_state();
-        DspStoreData {
-            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
-            plugin_root: plugin_root.to_path_buf(),
-            wasi_ctx,
-            wasi_table,
-        }
+    /// State transitions of supervised sidecars across all plugins.
+    pub fn subscribe_sidecar_events(&self) -> Receiver<SidecarStateEvent> {
+        self.hosts.sidecar_registry.subscribe_events()
     }
 
     fn ensure_plugin_active(&self, plugin_id: &str) -> Result<()> {
@@ -289,28 +346,51 @@ impl WasmtimePluginController {
     }
 
     pub(crate) fn load_component_cached(&self, component_path: &Path) -> Result<Component> {
-        let cache_key = component_path.to_path_buf();
+        load_cached_component(&self.engine, &self.component_cache, component_path)
+    }
 
-        {
-            let cache = self.component_cache.read();
-            if let Some(component) = cache.get(&cache_key) {
-                return Ok(component.clone());
-            }
+    /// Pre-links `component` against the host linker of `world` without instantiating it,
+    /// so a component importing interfaces the host does not provide, or missing the
+    /// world's exports, is rejected when its plugin is installed rather than on first use.
+    pub(crate) fn check_component_links(
+        &self,
+        world: &str,
+        component: &Component,
+    ) -> std::result::Result<(), String> {
+        fn link<T: 'static, P>(
+            linker: &Linker<T>,
+            component: &Component,
+            pre: impl FnOnce(InstancePre<T>) -> wasmtime::Result<P>,
+        ) -> std::result::Result<(), String> {
+            let instance_pre = linker
+                .instantiate_pre(component)
+                .map_err(|error| format!("imports what the host does not provide: {error:#}"))?;
+            pre(instance_pre)
+                .map(|_| ())
+                .map_err(|error| format!("lacks exports of its world: {error:#}"))
         }
 
-        let component = Component::from_file(&self.engine, &cache_key).map_err(|error| {
-            crate::op_error!(
-                "failed to load component from `{}`: {error:#}",
-                cache_key.display()
-            )
-        })?;
-
-        let mut cache = self.component_cache.write();
-        if let Some(component) = cache.get(&cache_key) {
-            return Ok(component.clone());
+        match classify_world(world) {
+            WorldKind::Decoder => link(
+                &self.decoder_linker,
+                component,
+                DecoderPluginBindingPre::new,
+            ),
+            WorldKind::Source => link(&self.source_linker, component, SourcePluginBindingPre::new),
+            WorldKind::Lyrics => link(&self.lyrics_linker, component, LyricsPluginBindingPre::new),
+            WorldKind::OutputSink => link(
+                &self.output_sink_linker,
+                component,
+                OutputSinkPluginBindingPre::new,
+            ),
+            WorldKind::Dsp => link(&self.dsp_linker, component, DspPluginBindingPre::new),
+            WorldKind::PlaybackListener => link(
+                &self.playback_listener_linker,
+                component,
+                PlaybackListenerPluginBindingPre::new,
+            ),
+            WorldKind::Unknown => Err(format!("targets unsupported world `{world}`")),
         }
-        cache.insert(cache_key, component.clone());
-        Ok(component)
     }
 
     pub(crate) fn remove_cached_components_for_plugin(
@@ -333,95 +413,434 @@ impl WasmtimePluginController {
         &self,
         plugin_id: &str,
         plugin_root: &Path,
-        component: &Component,
+        component_path: &Path,
         rx: Receiver<RuntimePluginDirective>,
     ) -> Result<PluginCell<Store<LyricsStoreData>, LyricsPluginBinding>> {
-        let mut store = Store::new(
-            &self.engine,
-            self.new_lyrics_store_data(plugin_id, plugin_root),
+        let hosts = self.hosts.clone();
+        let plugin_id = plugin_id.to_string();
+        let plugin_root = plugin_root.to_path_buf();
+        let http_allowlist = self.http_allowlist(&plugin_id);
+        let storage = self.hosts.plugin_storage(&plugin_id)?;
+        let mut respawn = self.respawner(
+            &self.lyrics_linker,
+            component_path,
+            move || {
+                hosts.new_lyrics_store_data(
+                    &plugin_id,
+                    &plugin_root,
+                    http_allowlist.clone(),
+                    storage.clone(),
+                )
+            },
+            instantiate_lyrics,
         );
-        let instance = LyricsPluginBinding::instantiate(&mut store, component, &self.lyrics_linker)
-            .map_err(|error| {
-                crate::op_error!("failed to instantiate lyrics component: {error:#}")
-            })?;
-        call_lyrics_on_enable(&instance, &mut store)?;
-        Ok(PluginCell::new(store, instance, rx))
+        let (store, instance) = respawn()?;
+        Ok(PluginCell::new(store, instance, rx, respawn))
     }
 
     fn instantiate_decoder_component(
         &self,
         plugin_id: &str,
         plugin_root: &Path,
-        component: &Component,
+        component_path: &Path,
         rx: Receiver<RuntimePluginDirective>,
     ) -> Result<PluginCell<Store<DecoderStoreData>, DecoderPluginBinding>> {
-        let mut store = Store::new(
-            &self.engine,
-            self.new_decoder_store_data(plugin_id, plugin_root),
+        let hosts = self.hosts.clone();
+        let plugin_id = plugin_id.to_string();
+        let plugin_root = plugin_root.to_path_buf();
+        let http_allowlist = self.http_allowlist(&plugin_id);
+        let storage = self.hosts.plugin_storage(&plugin_id)?;
+        let mut respawn = self.respawner(
+            &self.decoder_linker,
+            component_path,
+            move || {
+                hosts.new_decoder_store_data(
+                    &plugin_id,
+                    &plugin_root,
+                    http_allowlist.clone(),
+                    storage.clone(),
+                )
+            },
+            instantiate_decoder,
         );
-        let instance =
-            DecoderPluginBinding::instantiate(&mut store, component, &self.decoder_linker)?;
-        call_decoder_on_enable(&instance, &mut store)?;
-        Ok(PluginCell::new(store, instance, rx))
+        let (store, instance) = respawn()?;
+        Ok(PluginCell::new(store, instance, rx, respawn))
     }
 
     fn instantiate_source_component(
         &self,
         plugin_id: &str,
         plugin_root: &Path,
-        component: &Component,
+        component_path: &Path,
         rx: Receiver<RuntimePluginDirective>,
     ) -> Result<PluginCell<Store<SourceStoreData>, SourcePluginBinding>> {
-        let mut store = Store::new(
-            &self.engine,
-            self.new_source_store_data(plugin_id, plugin_root),
+        let hosts = self.hosts.clone();
+        let plugin_id = plugin_id.to_string();
+        let plugin_root = plugin_root.to_path_buf();
+        let http_allowlist = self.http_allowlist(&plugin_id);
+        let storage = self.hosts.plugin_storage(&plugin_id)?;
+        let mut respawn = self.respawner(
+            &self.source_linker,
+            component_path,
+            move || {
+                hosts.new_source_store_data(
+                    &plugin_id,
+                    &plugin_root,
+                    http_allowlist.clone(),
+                    storage.clone(),
+                )
+            },
+            instantiate_source,
         );
-        let instance = SourcePluginBinding::instantiate(&mut store, component, &self.source_linker)
-            .map_err(|error| {
-                crate::op_error!("failed to instantiate source component: {error:#}")
-            })?;
-        call_source_on_enable(&instance, &mut store)?;
-        Ok(PluginCell::new(store, instance, rx))
+        let (store, instance) = respawn()?;
+        Ok(PluginCell::new(store, instance, rx, respawn))
     }
 
     fn instantiate_output_sink_component(
         &self,
         plugin_id: &str,
         plugin_root: &Path,
-        component: &Component,
+        component_path: &Path,
         rx: Receiver<RuntimePluginDirective>,
     ) -> Result<PluginCell<Store<OutputSinkStoreData>, OutputSinkPluginBinding>> {
-        let mut store = Store::new(
-            &self.engine,
-            self.new_output_sink_store_data(plugin_id, plugin_root),
+        let hosts = self.hosts.clone();
+        let plugin_id = plugin_id.to_string();
+        let plugin_root = plugin_root.to_path_buf();
+        let http_allowlist = self.http_allowlist(&plugin_id);
+        let storage = self.hosts.plugin_storage(&plugin_id)?;
+        let mut respawn = self.respawner(
+            &self.output_sink_linker,
+            component_path,
+            move || {
+                hosts.new_output_sink_store_data(
+                    &plugin_id,
+                    &plugin_root,
+                    http_allowlist.clone(),
+                    storage.clone(),
+                )
+            },
+            instantiate_output_sink,
         );
-        let instance =
-            OutputSinkPluginBinding::instantiate(&mut store, component, &self.output_sink_linker)
-                .map_err(|error| {
-                crate::op_error!("failed to instantiate output-sink component: {error:#}")
-            })?;
-        call_output_sink_on_enable(&instance, &mut store)?;
-        Ok(PluginCell::new(store, instance, rx))
+        let (store, instance) = respawn()?;
+        Ok(PluginCell::new(store, instance, rx, respawn))
     }
 
     fn instantiate_dsp_component(
         &self,
         plugin_id: &str,
         plugin_root: &Path,
-        component: &Component,
+        component_path: &Path,
         rx: Receiver<RuntimePluginDirective>,
     ) -> Result<PluginCell<Store<DspStoreData>, DspPluginBinding>> {
-        let mut store = Store::new(
-            &self.engine,
-            self.new_dsp_store_data(plugin_id, plugin_root),
+        let hosts = self.hosts.clone();
+        let plugin_id = plugin_id.to_string();
+        let plugin_root = plugin_root.to_path_buf();
+        let http_allowlist = self.http_allowlist(&plugin_id);
+        let storage = self.hosts.plugin_storage(&plugin_id)?;
+        let mut respawn = self.respawner(
+            &self.dsp_linker,
+            component_path,
+            move || {
+                hosts.new_dsp_store_data(
+                    &plugin_id,
+                    &plugin_root,
+                    http_allowlist.clone(),
+                    storage.clone(),
+                )
+            },
+            instantiate_dsp,
+        );
+        let (store, instance) = respawn()?;
+        Ok(PluginCell::new(store, instance, rx, respawn))
+    }
+
+    fn instantiate_playback_listener_component(
+        &self,
+        plugin_id: &str,
+        plugin_root: &Path,
+        component_path: &Path,
+        rx: Receiver<RuntimePluginDirective>,
+    ) -> Result<PluginCell<Store<PlaybackListenerStoreData>, PlaybackListenerPluginBinding>> {
+        let hosts = self.hosts.clone();
+        let plugin_id = plugin_id.to_string();
+        let plugin_root = plugin_root.to_path_buf();
+        let http_allowlist = self.http_allowlist(&plugin_id);
+        let storage = self.hosts.plugin_storage(&plugin_id)?;
+        let mut respawn = self.respawner(
+            &self.playback_listener_linker,
+            component_path,
+            move || {
+                hosts.new_playback_listener_store_data(
+                    &plugin_id,
+                    &plugin_root,
+                    http_allowlist.clone(),
+                    storage.clone(),
+                )
+            },
+            instantiate_playback_listener,
         );
-        let instance = DspPluginBinding::instantiate(&mut store, component, &self.dsp_linker)
-            .map_err(|error| crate::op_error!("failed to instantiate dsp component: {error:#}"))?;
-        call_dsp_on_enable(&instance, &mut store)?;
-        Ok(PluginCell::new(store, instance, rx))
+        let (store, instance) = respawn()?;
+        Ok(PluginCell::new(store, instance, rx, respawn))
+    }
+
+    fn http_allowlist(&self, plugin_id: &str) -> HttpAllowlist {
+        let plugins = self.plugins.read();
+        HttpAllowlist::new(
+            plugins
+                .get(plugin_id)
+                .and_then(|record| record.info.permissions.http.clone()),
+        )
+    }
+
+    /// Builds the closure that (re)creates an instance of `component_path`. It reads the
+    /// shared component cache on every call, so a rebuild after the cache entry was
+    /// replaced picks up the new component.
+    fn respawner<T: Send + 'static, B: 'static>(
+        &self,
+        linker: &Linker<T>,
+        component_path: &Path,
+        mut new_data: impl FnMut() -> T + Send + 'static,
+        instantiate: fn(&mut Store<T>, &Component, &Linker<T>) -> Result<B>,
+    ) -> Respawn<Store<T>, B> {
+        let engine = self.engine.clone();
+        let cache = Arc::clone(&self.component_cache);
+        let linker = linker.clone();
+        let component_path = component_path.to_path_buf();
+        Box::new(move || {
+            let component = load_cached_component(&engine, &cache, &component_path)?;
+            let mut store = Store::new(&engine, new_data());
+            let instance = instantiate(&mut store, &component, &linker)?;
+            Ok((store, instance))
+        })
     }
 }
 
+impl StoreHosts {
+    fn plugin_storage(&self, plugin_id: &str) -> Result<PluginStorage> {
+        match self.storage_root.read().as_deref() {
+            Some(root) => PluginStorage::for_plugin(root, plugin_id),
+            None => Ok(PluginStorage::unavailable()),
+        }
+    }
+
+    fn new_decoder_store_data(
+        &self,
+        plugin_id: &str,
+        plugin_root: &Path,
+        http_allowlist: HttpAllowlist,
+        storage: PluginStorage,
+    ) -> DecoderStoreData {
+        let (wasi_ctx, wasi_table) = create_store_wasi_state();
+        DecoderStoreData {
+            stream_service: self.stream_service.clone(),
+            next_rep: 1,
+            streams: BTreeMap::new(),
+            http: HttpState::new(self.http_client.clone(), http_allowlist),
+            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
+            storage,
+            plugin_root: plugin_root.to_path_buf(),
+            wasi_ctx,
+            wasi_table,
+        }
+    }
+
+    fn new_source_store_data(
+        &self,
+        plugin_id: &str,
+        plugin_root: &Path,
+        http_allowlist: HttpAllowlist,
+        storage: PluginStorage,
+    ) -> SourceStoreData {
+        let (wasi_ctx, wasi_table) = create_store_wasi_state();
+        SourceStoreData {
+            stream_service: self.stream_service.clone(),
+            next_rep: 1,
+            streams: BTreeMap::new(),
+            http: HttpState::new(self.http_client.clone(), http_allowlist),
+            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
+            storage,
+            plugin_root: plugin_root.to_path_buf(),
+            wasi_ctx,
+            wasi_table,
+        }
+    }
+
+    fn new_lyrics_store_data(
+        &self,
+        plugin_id: &str,
+        plugin_root: &Path,
+        http_allowlist: HttpAllowlist,
+        storage: PluginStorage,
+    ) -> LyricsStoreData {
+        let (wasi_ctx, wasi_table) = create_store_wasi_state();
+        LyricsStoreData {
+            http: HttpState::new(self.http_client.clone(), http_allowlist),
+            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
+            storage,
+            plugin_root: plugin_root.to_path_buf(),
+            wasi_ctx,
+            wasi_table,
+        }
+    }
+
+    fn new_output_sink_store_data(
+        &self,
+        plugin_id: &str,
+        plugin_root: &Path,
+        http_allowlist: HttpAllowlist,
+        storage: PluginStorage,
+    ) -> OutputSinkStoreData {
+        let (wasi_ctx, wasi_table) = create_store_wasi_state();
+        OutputSinkStoreData {
+            http: HttpState::new(self.http_client.clone(), http_allowlist),
+            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
+            storage,
+            plugin_root: plugin_root.to_path_buf(),
+            wasi_ctx,
+            wasi_table,
+        }
+    }
+
+    fn new_dsp_store_data(
+        &self,
+        plugin_id: &str,
+        plugin_root: &Path,
+        http_allowlist: HttpAllowlist,
+        storage: PluginStorage,
+    ) -> DspStoreData {
+        let (wasi_ctx, wasi_table) = create_store_wasi_state();
+        DspStoreData {
+            http: HttpState::new(self.http_client.clone(), http_allowlist),
+            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
+            storage,
+            plugin_root: plugin_root.to_path_buf(),
+            wasi_ctx,
+            wasi_table,
+        }
+    }
+
+    fn new_playback_listener_store_data(
+        &self,
+        plugin_id: &str,
+        plugin_root: &Path,
+        http_allowlist: HttpAllowlist,
+        storage: PluginStorage,
+    ) -> PlaybackListenerStoreData {
+        let (wasi_ctx, wasi_table) = create_store_wasi_state();
+        PlaybackListenerStoreData {
+            http: HttpState::new(self.http_client.clone(), http_allowlist),
+            sidecar: SidecarState::new(plugin_id.to_string(), self.sidecar_registry.clone()),
+            storage,
+            plugin_root: plugin_root.to_path_buf(),
+            wasi_ctx,
+            wasi_table,
+        }
+    }
+}
+
+fn instantiate_lyrics(
+    store: &mut Store<LyricsStoreData>,
+    component: &Component,
+    linker: &Linker<LyricsStoreData>,
+) -> Result<LyricsPluginBinding> {
+    let instance = LyricsPluginBinding::instantiate(&mut *store, component, linker)
+        .map_err(|error| crate::op_error!("failed to instantiate lyrics component: {error:#}"))?;
+    call_lyrics_on_enable(&instance, store)?;
+    Ok(instance)
+}
+
+fn instantiate_decoder(
+    store: &mut Store<DecoderStoreData>,
+    component: &Component,
+    linker: &Linker<DecoderStoreData>,
+) -> Result<DecoderPluginBinding> {
+    let instance = DecoderPluginBinding::instantiate(&mut *store, component, linker)
+        .map_err(|error| crate::op_error!("failed to instantiate decoder component: {error:#}"))?;
+    call_decoder_on_enable(&instance, store)?;
+    Ok(instance)
+}
+
+fn instantiate_source(
+    store: &mut Store<SourceStoreData>,
+    component: &Component,
+    linker: &Linker<SourceStoreData>,
+) -> Result<SourcePluginBinding> {
+    let instance = SourcePluginBinding::instantiate(&mut *store, component, linker)
+        .map_err(|error| crate::op_error!("failed to instantiate source component: {error:#}"))?;
+    call_source_on_enable(&instance, store)?;
+    Ok(instance)
+}
+
+fn instantiate_output_sink(
+    store: &mut Store<OutputSinkStoreData>,
+    component: &Component,
+    linker: &Linker<OutputSinkStoreData>,
+) -> Result<OutputSinkPluginBinding> {
+    let instance =
+        OutputSinkPluginBinding::instantiate(&mut *store, component, linker).map_err(|error| {
+            crate::op_error!("failed to instantiate output-sink component: {error:#}")
+        })?;
+    call_output_sink_on_enable(&instance, store)?;
+    Ok(instance)
+}
+
+fn instantiate_dsp(
+    store: &mut Store<DspStoreData>,
+    component: &Component,
+    linker: &Linker<DspStoreData>,
+) -> Result<DspPluginBinding> {
+    let instance = DspPluginBinding::instantiate(&mut *store, component, linker)
+        .map_err(|error| crate::op_error!("failed to instantiate dsp component: {error:#}"))?;
+    call_dsp_on_enable(&instance, store)?;
+    Ok(instance)
+}
+
+fn instantiate_playback_listener(
+    store: &mut Store<PlaybackListenerStoreData>,
+    component: &Component,
+    linker: &Linker<PlaybackListenerStoreData>,
+) -> Result<PlaybackListenerPluginBinding> {
+    let instance = PlaybackListenerPluginBinding::instantiate(&mut *store, component, linker)
+        .map_err(|error| {
+            crate::op_error!("failed to instantiate playback-listener component: {error:#}")
+        })?;
+    call_playback_listener_on_enable(&instance, store)?;
+    Ok(instance)
+}
+
+fn load_cached_component(
+    engine: &Engine,
+    cache: &ComponentCache,
+    component_path: &Path,
+) -> Result<Component> {
+    let cache_key = component_path.to_path_buf();
+
+    {
+        let cache = cache.read();
+        if let Some(component) = cache.get(&cache_key) {
+            return Ok(component.clone());
+        }
+    }
+
+    let component = compile_component(engine, &cache_key)?;
+
+    let mut cache = cache.write();
+    if let Some(component) = cache.get(&cache_key) {
+        return Ok(component.clone());
+    }
+    cache.insert(cache_key, component.clone());
+    Ok(component)
+}
+
+fn compile_component(engine: &Engine, component_path: &Path) -> Result<Component> {
+    Component::from_file(engine, component_path).map_err(|error| {
+        crate::op_error!(
+            "failed to load component from `{}`: {error:#}",
+            component_path.display()
+        )
+    })
+}
+
 fn map_disable_reason_decoder(reason: PluginDisableReason) -> decoder_lifecycle::DisableReason {
     match reason {
         PluginDisableReason::HostDisable => decoder_lifecycle::DisableReason::HostDisable,
@@ -469,6 +888,17 @@ fn map_disable_reason_dsp(reason: PluginDisableReason) -> dsp_lifecycle::Disable
     }
 }
 
+fn map_disable_reason_playback_listener(
+    reason: PluginDisableReason,
+) -> playback_listener_lifecycle::DisableReason {
+    match reason {
+        PluginDisableReason::HostDisable => playback_listener_lifecycle::DisableReason::HostDisable,
+        PluginDisableReason::Unload => playback_listener_lifecycle::DisableReason::Unload,
+        PluginDisableReason::Shutdown => playback_listener_lifecycle::DisableReason::Shutdown,
+        PluginDisableReason::Reload => playback_listener_lifecycle::DisableReason::Reload,
+    }
+}
+
 pub(crate) fn call_decoder_on_enable(
     plugin: &DecoderPluginBinding,
     store: &mut Store<DecoderStoreData>,
@@ -594,19 +1024,45 @@ pub(crate) fn call_dsp_on_disable(
     Ok(())
 }
 
+pub(crate) fn call_playback_listener_on_enable(
+    plugin: &PlaybackListenerPluginBinding,
+    store: &mut Store<PlaybackListenerStoreData>,
+) -> Result<()> {
+    let on_enable = plugin
+        .stellatune_plugin_lifecycle()
+        .call_on_enable(store)
+        .map_err(|error| crate::op_error!("lifecycle.on-enable call failed: {error:#}"))?;
+    on_enable.map_err(|error| crate::op_error!("lifecycle.on-enable plugin error: {error:?}"))?;
+    Ok(())
+}
+
+pub(crate) fn call_playback_listener_on_disable(
+    plugin: &PlaybackListenerPluginBinding,
+    store: &mut Store<PlaybackListenerStoreData>,
+    reason: playback_listener_lifecycle::DisableReason,
+) -> Result<()> {
+    let on_disable = plugin
+        .stellatune_plugin_lifecycle()
+        .call_on_disable(store, reason)
+        .map_err(|error| crate::op_error!("lifecycle.on-disable call failed: {error:#}"))?;
+    on_disable.map_err(|error| crate::op_error!("lifecycle.on-disable plugin error: {error:?}"))?;
+    Ok(())
+}
+
 mod controller;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum WorldKind {
+pub(crate) enum WorldKind {
     Decoder,
     Source,
     Lyrics,
     OutputSink,
     Dsp,
+    PlaybackListener,
     Unknown,
 }
 
-fn classify_world(world: &str) -> WorldKind {
+pub(crate) fn classify_world(world: &str) -> WorldKind {
     let normalized = normalize_world_name(world);
     match normalized {
         wasm_host::WORLD_DECODER_PLUGIN => WorldKind::Decoder,
@@ -614,6 +1070,7 @@ fn classify_world(world: &str) -> WorldKind {
         wasm_host::WORLD_LYRICS_PLUGIN => WorldKind::Lyrics,
         wasm_host::WORLD_OUTPUT_SINK_PLUGIN => WorldKind::OutputSink,
         wasm_host::WORLD_DSP_PLUGIN => WorldKind::Dsp,
+        wasm_host::WORLD_PLAYBACK_LISTENER_PLUGIN => WorldKind::PlaybackListener,
         _ => WorldKind::Unknown,
     }
 }
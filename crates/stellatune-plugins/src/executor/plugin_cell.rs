@@ -4,6 +4,9 @@ use crate::error::Result;
 
 use crate::runtime::model::{PluginDisableReason, RuntimePluginDirective};
 
+/// Creates a fresh, enabled store and plugin pair from the current cached component.
+pub type Respawn<TStore, TPlugin> = Box<dyn FnMut() -> Result<(TStore, TPlugin)> + Send>;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PluginCellState {
     Active,
@@ -16,17 +19,24 @@ pub struct PluginCell<TStore, TPlugin> {
     rx: Receiver<RuntimePluginDirective>,
     pub store: TStore,
     pub plugin: TPlugin,
+    respawn: Respawn<TStore, TPlugin>,
     state: PluginCellState,
     pending_config: Option<String>,
     pending_destroy_reason: Option<PluginDisableReason>,
 }
 
 impl<TStore, TPlugin> PluginCell<TStore, TPlugin> {
-    pub fn new(store: TStore, plugin: TPlugin, rx: Receiver<RuntimePluginDirective>) -> Self {
+    pub fn new(
+        store: TStore,
+        plugin: TPlugin,
+        rx: Receiver<RuntimePluginDirective>,
+        respawn: Respawn<TStore, TPlugin>,
+    ) -> Self {
         Self {
             rx,
             store,
             plugin,
+            respawn,
             state: PluginCellState::Active,
             pending_config: None,
             pending_destroy_reason: None,
@@ -69,7 +79,7 @@ impl<TStore, TPlugin> PluginCell<TStore, TPlugin> {
     ) -> Result<()>
     where
         FUpdate: FnMut(&mut TStore, &mut TPlugin, &str) -> Result<()>,
-        FRebuild: FnMut(&mut TStore, &mut TPlugin) -> Result<()>,
+        FRebuild: FnMut(&mut TStore, &mut TPlugin, &mut Respawn<TStore, TPlugin>) -> Result<()>,
         FDestroy: FnMut(&mut TStore, &mut TPlugin, PluginDisableReason) -> Result<()>,
     {
         self.poll_directives();
@@ -86,7 +96,7 @@ impl<TStore, TPlugin> PluginCell<TStore, TPlugin> {
         }
 
         if self.state == PluginCellState::RebuildPending {
-            rebuild(&mut self.store, &mut self.plugin)?;
+            rebuild(&mut self.store, &mut self.plugin, &mut self.respawn)?;
             self.state = PluginCellState::Active;
         }
 
@@ -1,8 +1,9 @@
+use crate::config_schema::ConfigSchema;
 use crate::error::Result;
 
-use crate::runtime::model::PluginDisableReason;
+use crate::runtime::model::{PluginDisableReason, RuntimeCapabilityDescriptor};
 
-use crate::executor::plugin_cell::{PluginCell, PluginCellState};
+use crate::executor::plugin_cell::{PluginCell, PluginCellState, Respawn};
 
 pub(crate) fn map_lyrics_plugin_error<T, E: std::fmt::Debug>(
     value: std::result::Result<T, E>,
@@ -26,7 +27,7 @@ pub(crate) fn reconcile_with<TStore, TPlugin, FUpdate, FRebuild, FDestroy>(
 ) -> Result<()>
 where
     FUpdate: FnMut(&mut TStore, &mut TPlugin, &str) -> Result<()>,
-    FRebuild: FnMut(&mut TStore, &mut TPlugin) -> Result<()>,
+    FRebuild: FnMut(&mut TStore, &mut TPlugin, &mut Respawn<TStore, TPlugin>) -> Result<()>,
     FDestroy: FnMut(&mut TStore, &mut TPlugin, PluginDisableReason) -> Result<()>,
 {
     cell.reconcile(update, rebuild, destroy)?;
@@ -38,3 +39,68 @@ where
     }
     Ok(())
 }
+
+/// Replaces a disabled store and plugin with a freshly spawned pair. If spawning fails
+/// (for example the new component traps in `on-enable`), the old pair is re-enabled so
+/// the instance keeps running the code it had.
+pub(crate) fn respawn_or_reenable<TStore, TPlugin>(
+    store: &mut TStore,
+    plugin: &mut TPlugin,
+    respawn: &mut Respawn<TStore, TPlugin>,
+    reenable: impl FnOnce(&TPlugin, &mut TStore) -> Result<()>,
+) -> Result<()> {
+    match respawn() {
+        Ok((fresh_store, fresh_plugin)) => {
+            *store = fresh_store;
+            *plugin = fresh_plugin;
+            Ok(())
+        },
+        Err(error) => {
+            tracing::warn!(
+                target: "stellatune_plugins::runtime",
+                %error,
+                "failed to respawn plugin instance, re-enabling the current one"
+            );
+            reenable(plugin, store)
+        },
+    }
+}
+
+/// Applies the persisted config to a freshly created plugin resource. A config the plugin
+/// rejects is logged and forgotten, leaving the resource on its own defaults.
+pub(crate) fn apply_initial_config(
+    plugin_id: &str,
+    config_json: &mut Option<String>,
+    apply: impl FnOnce(&str) -> Result<()>,
+) {
+    let Some(config) = config_json.as_deref() else {
+        return;
+    };
+    if let Err(error) = apply(config) {
+        tracing::warn!(
+            target: "stellatune_plugins::runtime",
+            plugin_id,
+            %error,
+            "plugin rejected persisted config, keeping its defaults"
+        );
+        *config_json = None;
+    }
+}
+
+/// Parsed config schema for an instance; malformed schemas fall back to permissive so a
+/// schema mistake never blocks the capability itself.
+pub(crate) fn instance_config_schema(
+    plugin_id: &str,
+    capability: &RuntimeCapabilityDescriptor,
+) -> ConfigSchema {
+    ConfigSchema::parse(&capability.config_schema_json).unwrap_or_else(|error| {
+        tracing::warn!(
+            target: "stellatune_plugins::runtime",
+            plugin_id,
+            type_id = %capability.type_id,
+            %error,
+            "ignoring invalid capability config schema"
+        );
+        ConfigSchema::permissive()
+    })
+}
@@ -11,6 +11,7 @@ use stellatune_host_bindings::generated as host_bindings;
 use host_bindings::decoder_plugin::DecoderPlugin as DecoderBinding;
 use host_bindings::decoder_plugin::stellatune::plugin::common as decoder_common;
 
+use crate::config_schema::ConfigSchema;
 use crate::executor::plugin_cell::{PluginCell, PluginCellState};
 use crate::executor::stores::decoder::DecoderStoreData;
 use crate::executor::{
@@ -25,7 +26,10 @@ use crate::runtime::model::{
     RuntimePluginInfo,
 };
 
-use crate::executor::plugin_instance::common::{map_decoder_plugin_error, reconcile_with};
+use crate::executor::plugin_instance::common::{
+    apply_initial_config, instance_config_schema, map_decoder_plugin_error, reconcile_with,
+    respawn_or_reenable,
+};
 
 macro_rules! runtime_decoder_info_from {
     ($info:expr) => {{
@@ -121,8 +125,11 @@ pub trait DecoderPluginApi {
 pub struct WasmtimeDecoderPlugin {
     plugin_id: String,
     component: PluginCell<Store<DecoderStoreData>, DecoderBinding>,
+    config_schema: ConfigSchema,
     next_session_handle: u64,
     sessions: BTreeMap<u64, wasmtime::component::ResourceAny>,
+    /// Last applied config, applied to every session opened afterwards.
+    config_json: Option<String>,
 }
 
 impl WasmtimeDecoderPlugin {
@@ -145,11 +152,14 @@ impl WasmtimeDecoderPlugin {
 
     fn reconcile_runtime(&mut self) -> Result<()> {
         let session_refs = self.sessions.values().cloned().collect::<Vec<_>>();
+        let mut applied_config = None;
         let mut rebuilt = false;
         let mut destroyed = false;
+        let config_schema = &self.config_schema;
         reconcile_with(
             &mut self.component,
             |store, plugin, config_json| {
+                config_schema.check_json(config_json)?;
                 let decoder = plugin.stellatune_plugin_decoder();
                 for session in &session_refs {
                     let plan = map_decoder_plugin_error(
@@ -184,9 +194,10 @@ impl WasmtimeDecoderPlugin {
                         },
                     }
                 }
+                applied_config = Some(config_json.to_string());
                 Ok(())
             },
-            |store, plugin| {
+            |store, plugin, respawn| {
                 let decoder = plugin.stellatune_plugin_decoder();
                 for session in &session_refs {
                     let _ = decoder.session().call_close(&mut *store, *session);
@@ -197,7 +208,7 @@ impl WasmtimeDecoderPlugin {
                     store,
                     map_disable_reason_decoder(PluginDisableReason::Reload),
                 )?;
-                call_decoder_on_enable(plugin, store)?;
+                respawn_or_reenable(store, plugin, respawn, call_decoder_on_enable)?;
                 rebuilt = true;
                 Ok(())
             },
@@ -212,6 +223,9 @@ impl WasmtimeDecoderPlugin {
                 Ok(())
             },
         )?;
+        if let Some(config_json) = applied_config {
+            self.config_json = Some(config_json);
+        }
         if rebuilt || destroyed {
             self.sessions.clear();
         }
@@ -248,6 +262,16 @@ impl DecoderPluginApi for WasmtimeDecoderPlugin {
                 return Err(error.into());
             },
         };
+        apply_initial_config(&self.plugin_id, &mut self.config_json, |config_json| {
+            map_decoder_plugin_error(
+                decoder.session().call_apply_config_update_json(
+                    &mut self.component.store,
+                    session,
+                    config_json,
+                )?,
+                "decoder.session.apply-config-update-json",
+            )
+        });
         let handle = self.alloc_session_handle();
         self.sessions.insert(handle, session);
         Ok(RuntimeDecoderSessionHandle(handle))
@@ -380,8 +404,7 @@ impl WasmtimePluginController {
         self.ensure_plugin_active(plugin_id)?;
 
         let component_path = plugin.root_dir.join(&capability.component_rel_path);
-        let component = self
-            .load_component_cached(&component_path)
+        self.load_component_cached(&component_path)
             .map_err(|error| {
                 crate::op_error!(
                     "failed to load component for plugin `{}` component `{}`: {error:#}",
@@ -392,9 +415,12 @@ impl WasmtimePluginController {
 
         let (tx, rx) = mpsc::channel::<RuntimePluginDirective>();
         let component = match classify_world(&capability.world) {
-            WorldKind::Decoder => {
-                self.instantiate_decoder_component(plugin_id, &plugin.root_dir, &component, rx)?
-            },
+            WorldKind::Decoder => self.instantiate_decoder_component(
+                plugin_id,
+                &plugin.root_dir,
+                &component_path,
+                rx,
+            )?,
             _ => {
                 return Err(crate::op_error!(
                     "capability world `{}` is not a decoder world",
@@ -405,11 +431,15 @@ impl WasmtimePluginController {
 
         self.register_directive_sender(plugin_id, tx)?;
 
+        let config_schema = instance_config_schema(plugin_id, &capability);
+        let config_json = self.persisted_config(plugin_id, &capability.type_id, &config_schema);
         Ok(WasmtimeDecoderPlugin {
             plugin_id: plugin_id.to_string(),
             component,
+            config_schema,
             next_session_handle: 1,
             sessions: BTreeMap::new(),
+            config_json,
         })
     }
 
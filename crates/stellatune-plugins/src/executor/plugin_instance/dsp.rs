@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::sync::mpsc;
 
@@ -11,6 +12,7 @@ use host_bindings::dsp_plugin::exports::stellatune::plugin::dsp as dsp_exports;
 use host_bindings::dsp_plugin::stellatune::plugin::common as dsp_common;
 use host_bindings::dsp_plugin::stellatune::plugin::hot_path as dsp_hot_path;
 
+use crate::config_schema::ConfigSchema;
 use crate::executor::plugin_cell::{PluginCell, PluginCellState};
 use crate::executor::stores::dsp::DspStoreData;
 use crate::executor::{
@@ -24,7 +26,9 @@ use crate::runtime::model::{
     RuntimePluginInfo, RuntimeSampleFormat,
 };
 
-use crate::executor::plugin_instance::common::reconcile_with;
+use crate::executor::plugin_instance::common::{
+    apply_initial_config, instance_config_schema, reconcile_with, respawn_or_reenable,
+};
 
 pub trait DspPluginApi {
     fn create_processor(&mut self, spec: RuntimeAudioSpec) -> Result<RuntimeDspProcessorHandle>;
@@ -64,8 +68,11 @@ pub trait DspPluginApi {
 pub struct WasmtimeDspPlugin {
     plugin_id: String,
     component: PluginCell<Store<DspStoreData>, DspBinding>,
+    config_schema: ConfigSchema,
     next_processor_handle: u64,
     processors: BTreeMap<u64, wasmtime::component::ResourceAny>,
+    processor_specs: BTreeMap<u64, RuntimeAudioSpec>,
+    config_json: Option<String>,
 }
 
 impl WasmtimeDspPlugin {
@@ -91,14 +98,23 @@ impl WasmtimeDspPlugin {
     }
 
     fn reconcile_runtime(&mut self) -> Result<()> {
-        let processor_refs = self.processors.values().cloned().collect::<Vec<_>>();
-        let mut rebuilt = false;
+        let processor_refs = self.processors.clone();
+        let processor_specs = &self.processor_specs;
+        let last_config = self.config_json.clone();
+        let restored = RefCell::new(None::<BTreeMap<u64, wasmtime::component::ResourceAny>>);
+        let mut applied_config = None;
         let mut destroyed = false;
+        let config_schema = &self.config_schema;
         reconcile_with(
             &mut self.component,
             |store, plugin, config_json| {
+                config_schema.check_json(config_json)?;
                 let dsp = plugin.stellatune_plugin_dsp();
-                for processor in &processor_refs {
+                let processors = restored
+                    .borrow()
+                    .clone()
+                    .unwrap_or_else(|| processor_refs.clone());
+                for processor in processors.values() {
                     let plan = dsp
                         .processor()
                         .call_plan_config_update_json(&mut *store, *processor, config_json)?
@@ -130,11 +146,20 @@ impl WasmtimeDspPlugin {
                         },
                     }
                 }
+                applied_config = Some(config_json.to_string());
                 Ok(())
             },
-            |store, plugin| {
+            |store, plugin, respawn| {
                 let dsp = plugin.stellatune_plugin_dsp();
-                for processor in &processor_refs {
+                let mut states = BTreeMap::new();
+                for (handle, processor) in &processor_refs {
+                    let state = dsp
+                        .processor()
+                        .call_export_state_json(&mut *store, *processor)
+                        .ok()
+                        .and_then(|result| result.ok())
+                        .flatten();
+                    states.insert(*handle, state);
                     let _ = dsp.processor().call_close(&mut *store, *processor);
                     let _ = (*processor).resource_drop(&mut *store);
                 }
@@ -143,13 +168,19 @@ impl WasmtimeDspPlugin {
                     store,
                     map_disable_reason_dsp(PluginDisableReason::Reload),
                 )?;
-                call_dsp_on_enable(plugin, store)?;
-                rebuilt = true;
+                respawn_or_reenable(store, plugin, respawn, call_dsp_on_enable)?;
+                *restored.borrow_mut() = Some(restore_processors(
+                    plugin,
+                    store,
+                    processor_specs,
+                    last_config.as_deref(),
+                    states,
+                ));
                 Ok(())
             },
             |store, plugin, reason| {
                 let dsp = plugin.stellatune_plugin_dsp();
-                for processor in &processor_refs {
+                for processor in processor_refs.values() {
                     let _ = dsp.processor().call_close(&mut *store, *processor);
                     let _ = (*processor).resource_drop(&mut *store);
                 }
@@ -158,11 +189,96 @@ impl WasmtimeDspPlugin {
                 Ok(())
             },
         )?;
-        if rebuilt || destroyed {
+        if let Some(config_json) = applied_config {
+            self.config_json = Some(config_json);
+        }
+        if let Some(processors) = restored.into_inner() {
+            self.processor_specs
+                .retain(|handle, _| processors.contains_key(handle));
+            self.processors = processors;
+        }
+        if destroyed {
             self.processors.clear();
+            self.processor_specs.clear();
+        }
+        Ok(())
+    }
+}
+
+/// Recreates processors on a rebuilt instance under their old handles, re-applying the
+/// last config and the state exported before the rebuild. A processor that fails to come
+/// back is dropped, so callers see it as closed and create a new one.
+fn restore_processors(
+    plugin: &DspBinding,
+    store: &mut Store<DspStoreData>,
+    specs: &BTreeMap<u64, RuntimeAudioSpec>,
+    config_json: Option<&str>,
+    states: BTreeMap<u64, Option<String>>,
+) -> BTreeMap<u64, wasmtime::component::ResourceAny> {
+    let dsp = plugin.stellatune_plugin_dsp();
+    let mut restored = BTreeMap::new();
+    for (handle, state_json) in states {
+        let Some(spec) = specs.get(&handle) else {
+            continue;
+        };
+        match restore_processor(dsp, store, *spec, config_json, state_json.as_deref()) {
+            Ok(processor) => {
+                restored.insert(handle, processor);
+            },
+            Err(error) => {
+                tracing::warn!(
+                    target: "stellatune_plugins::runtime",
+                    handle,
+                    %error,
+                    "dropping dsp processor that failed to restore after rebuild"
+                );
+            },
+        }
+    }
+    restored
+}
+
+fn restore_processor(
+    dsp: &dsp_exports::Guest,
+    store: &mut Store<DspStoreData>,
+    spec: RuntimeAudioSpec,
+    config_json: Option<&str>,
+    state_json: Option<&str>,
+) -> Result<wasmtime::component::ResourceAny> {
+    let processor = dsp
+        .call_create(
+            &mut *store,
+            dsp_common::AudioSpec {
+                sample_rate: spec.sample_rate,
+                channels: spec.channels,
+            },
+        )?
+        .map_err(|error| crate::op_error!("dsp.create plugin error: {error:?}"))?;
+    let mut configure = || -> Result<()> {
+        if let Some(config_json) = config_json {
+            dsp.processor()
+                .call_apply_config_update_json(&mut *store, processor, config_json)?
+                .map_err(|error| {
+                    crate::op_error!(
+                        "dsp.processor.apply-config-update-json plugin error: {error:?}"
+                    )
+                })?;
+        }
+        if let Some(state_json) = state_json {
+            dsp.processor()
+                .call_import_state_json(&mut *store, processor, state_json)?
+                .map_err(|error| {
+                    crate::op_error!("dsp.processor.import-state-json plugin error: {error:?}")
+                })?;
         }
         Ok(())
+    };
+    if let Err(error) = configure() {
+        let _ = dsp.processor().call_close(&mut *store, processor);
+        let _ = processor.resource_drop(&mut *store);
+        return Err(error);
     }
+    Ok(processor)
 }
 
 impl DspPluginApi for WasmtimeDspPlugin {
@@ -178,8 +294,18 @@ impl DspPluginApi for WasmtimeDspPlugin {
                 },
             )?
             .map_err(|error| crate::op_error!("dsp.create plugin error: {error:?}"))?;
+        apply_initial_config(&self.plugin_id, &mut self.config_json, |config_json| {
+            dsp.processor()
+                .call_apply_config_update_json(&mut self.component.store, processor, config_json)?
+                .map_err(|error| {
+                    crate::op_error!(
+                        "dsp.processor.apply-config-update-json plugin error: {error:?}"
+                    )
+                })
+        });
         let handle = self.alloc_processor_handle();
         self.processors.insert(handle, processor);
+        self.processor_specs.insert(handle, spec);
         Ok(RuntimeDspProcessorHandle(handle))
     }
 
@@ -295,6 +421,7 @@ impl DspPluginApi for WasmtimeDspPlugin {
         processor: RuntimeDspProcessorHandle,
         config_json: &str,
     ) -> Result<(String, Option<String>)> {
+        self.config_schema.check_json(config_json)?;
         let Some(processor_ref) = self.processors.get(&processor.0).cloned() else {
             return Err(crate::op_error!(
                 "dsp processor `{}` not found",
@@ -317,6 +444,7 @@ impl DspPluginApi for WasmtimeDspPlugin {
         processor: RuntimeDspProcessorHandle,
         config_json: &str,
     ) -> Result<()> {
+        self.config_schema.check_json(config_json)?;
         let Some(processor_ref) = self.processors.get(&processor.0).cloned() else {
             return Err(crate::op_error!(
                 "dsp processor `{}` not found",
@@ -330,6 +458,7 @@ impl DspPluginApi for WasmtimeDspPlugin {
             .map_err(|error| {
                 crate::op_error!("dsp.processor.apply-config-update-json plugin error: {error:?}")
             })?;
+        self.config_json = Some(config_json.to_string());
         Ok(())
     }
 
@@ -374,6 +503,7 @@ impl DspPluginApi for WasmtimeDspPlugin {
     }
 
     fn close_processor(&mut self, processor: RuntimeDspProcessorHandle) -> Result<()> {
+        self.processor_specs.remove(&processor.0);
         let Some(processor_ref) = self.processors.remove(&processor.0) else {
             return Ok(());
         };
@@ -413,8 +543,7 @@ impl WasmtimePluginController {
         self.ensure_plugin_active(plugin_id)?;
 
         let component_path = plugin.root_dir.join(&capability.component_rel_path);
-        let component = self
-            .load_component_cached(&component_path)
+        self.load_component_cached(&component_path)
             .map_err(|error| {
                 crate::op_error!(
                     "failed to load component for plugin `{}` component `{}`: {error:#}",
@@ -426,9 +555,12 @@ impl WasmtimePluginController {
         let (tx, rx) = mpsc::channel::<RuntimePluginDirective>();
         let component: PluginCell<Store<DspStoreData>, DspBinding> =
             match classify_world(&capability.world) {
-                WorldKind::Dsp => {
-                    self.instantiate_dsp_component(plugin_id, &plugin.root_dir, &component, rx)?
-                },
+                WorldKind::Dsp => self.instantiate_dsp_component(
+                    plugin_id,
+                    &plugin.root_dir,
+                    &component_path,
+                    rx,
+                )?,
                 _ => {
                     return Err(crate::op_error!(
                         "capability world `{}` is not a dsp world",
@@ -438,11 +570,16 @@ impl WasmtimePluginController {
             };
         self.register_directive_sender(plugin_id, tx)?;
 
+        let config_schema = instance_config_schema(plugin_id, &capability);
+        let config_json = self.persisted_config(plugin_id, &capability.type_id, &config_schema);
         Ok(WasmtimeDspPlugin {
             plugin_id: plugin_id.to_string(),
             component,
+            config_schema,
             next_processor_handle: 1,
             processors: BTreeMap::new(),
+            processor_specs: BTreeMap::new(),
+            config_json,
         })
     }
 
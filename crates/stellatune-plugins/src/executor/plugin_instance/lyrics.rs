@@ -8,6 +8,7 @@ use stellatune_host_bindings::generated as host_bindings;
 use host_bindings::lyrics_plugin::LyricsPlugin as LyricsBinding;
 use host_bindings::lyrics_plugin::stellatune::plugin::common as lyrics_common;
 
+use crate::config_schema::ConfigSchema;
 use crate::executor::plugin_cell::{PluginCell, PluginCellState};
 use crate::executor::stores::lyrics::LyricsStoreData;
 use crate::executor::{
@@ -20,7 +21,10 @@ use crate::runtime::model::{
     RuntimePluginDirective, RuntimePluginInfo,
 };
 
-use crate::executor::plugin_instance::common::{map_lyrics_plugin_error, reconcile_with};
+use crate::executor::plugin_instance::common::{
+    apply_initial_config, instance_config_schema, map_lyrics_plugin_error, reconcile_with,
+    respawn_or_reenable,
+};
 
 pub trait LyricsPluginApi {
     fn search(&mut self, keyword: &str) -> Result<Vec<RuntimeLyricCandidate>>;
@@ -30,6 +34,9 @@ pub trait LyricsPluginApi {
 pub struct WasmtimeLyricsPlugin {
     plugin_id: String,
     component: PluginCell<Store<LyricsStoreData>, LyricsBinding>,
+    config_schema: ConfigSchema,
+    /// Last applied config, applied to every provider created for a call.
+    config_json: Option<String>,
 }
 
 impl WasmtimeLyricsPlugin {
@@ -38,9 +45,12 @@ impl WasmtimeLyricsPlugin {
     }
 
     fn reconcile_runtime(&mut self) -> Result<()> {
+        let config_schema = &self.config_schema;
+        let mut applied_config = None;
         reconcile_with(
             &mut self.component,
             |store, plugin, config_json| {
+                config_schema.check_json(config_json)?;
                 let lyrics = plugin.stellatune_plugin_lyrics();
                 let provider =
                     map_lyrics_plugin_error(lyrics.call_create(&mut *store)?, "lyrics.create")?;
@@ -77,22 +87,46 @@ impl WasmtimeLyricsPlugin {
                 }
                 let _ = lyrics.provider().call_close(&mut *store, provider);
                 let _ = provider.resource_drop(&mut *store);
+                applied_config = Some(config_json.to_string());
                 Ok(())
             },
-            |store, plugin| {
+            |store, plugin, respawn| {
                 call_lyrics_on_disable(
                     plugin,
                     store,
                     map_disable_reason_lyrics(PluginDisableReason::Reload),
                 )?;
-                call_lyrics_on_enable(plugin, store)?;
+                respawn_or_reenable(store, plugin, respawn, call_lyrics_on_enable)?;
                 Ok(())
             },
             |store, plugin, reason| {
                 call_lyrics_on_disable(plugin, store, map_disable_reason_lyrics(reason))?;
                 Ok(())
             },
-        )
+        )?;
+        if let Some(config_json) = applied_config {
+            self.config_json = Some(config_json);
+        }
+        Ok(())
+    }
+
+    fn create_provider(&mut self) -> Result<wasmtime::component::ResourceAny> {
+        let lyrics = self.component.plugin.stellatune_plugin_lyrics();
+        let provider = map_lyrics_plugin_error(
+            lyrics.call_create(&mut self.component.store)?,
+            "lyrics.create",
+        )?;
+        apply_initial_config(&self.plugin_id, &mut self.config_json, |config_json| {
+            map_lyrics_plugin_error(
+                lyrics.provider().call_apply_config_update_json(
+                    &mut self.component.store,
+                    provider,
+                    config_json,
+                )?,
+                "lyrics.provider.apply-config-update-json",
+            )
+        });
+        Ok(provider)
     }
 }
 
@@ -104,11 +138,8 @@ impl LyricsPluginApi for WasmtimeLyricsPlugin {
         }
 
         self.reconcile_runtime()?;
+        let provider = self.create_provider()?;
         let lyrics = self.component.plugin.stellatune_plugin_lyrics();
-        let provider = map_lyrics_plugin_error(
-            lyrics.call_create(&mut self.component.store)?,
-            "lyrics.create",
-        )?;
         let out = map_lyrics_plugin_error(
             lyrics
                 .provider()
@@ -136,11 +167,8 @@ impl LyricsPluginApi for WasmtimeLyricsPlugin {
         }
 
         self.reconcile_runtime()?;
+        let provider = self.create_provider()?;
         let lyrics = self.component.plugin.stellatune_plugin_lyrics();
-        let provider = map_lyrics_plugin_error(
-            lyrics.call_create(&mut self.component.store)?,
-            "lyrics.create",
-        )?;
         let out = map_lyrics_plugin_error(
             lyrics
                 .provider()
@@ -179,8 +207,7 @@ impl WasmtimePluginController {
         self.ensure_plugin_active(plugin_id)?;
 
         let component_path = plugin.root_dir.join(&capability.component_rel_path);
-        let component = self
-            .load_component_cached(&component_path)
+        self.load_component_cached(&component_path)
             .map_err(|error| {
                 crate::op_error!(
                     "failed to load component for plugin `{}` component `{}`: {error:#}",
@@ -192,7 +219,7 @@ impl WasmtimePluginController {
         let (tx, rx) = mpsc::channel::<RuntimePluginDirective>();
         let component = match classify_world(&capability.world) {
             WorldKind::Lyrics => {
-                self.instantiate_lyrics_component(plugin_id, &plugin.root_dir, &component, rx)?
+                self.instantiate_lyrics_component(plugin_id, &plugin.root_dir, &component_path, rx)?
             },
             _ => {
                 return Err(crate::op_error!(
@@ -204,9 +231,13 @@ impl WasmtimePluginController {
 
         self.register_directive_sender(plugin_id, tx)?;
 
+        let config_schema = instance_config_schema(plugin_id, &capability);
+        let config_json = self.persisted_config(plugin_id, &capability.type_id, &config_schema);
         Ok(WasmtimeLyricsPlugin {
             plugin_id: plugin_id.to_string(),
             component,
+            config_schema,
+            config_json,
         })
     }
 
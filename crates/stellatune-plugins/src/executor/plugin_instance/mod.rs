@@ -3,4 +3,5 @@ pub mod decoder;
 pub mod dsp;
 pub mod lyrics;
 pub mod output_sink;
+pub mod playback_listener;
 pub mod source;
@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::sync::mpsc;
 
 use crate::error::Result;
@@ -10,6 +11,7 @@ use host_bindings::output_sink_plugin::exports::stellatune::plugin::output_sink
 use host_bindings::output_sink_plugin::stellatune::plugin::common as output_sink_common;
 use host_bindings::output_sink_plugin::stellatune::plugin::hot_path as output_sink_hot_path;
 
+use crate::config_schema::ConfigSchema;
 use crate::executor::plugin_cell::{PluginCell, PluginCellState};
 use crate::executor::stores::output_sink::OutputSinkStoreData;
 use crate::executor::{
@@ -23,7 +25,9 @@ use crate::runtime::model::{
     RuntimePluginDirective, RuntimePluginInfo, RuntimeSampleFormat,
 };
 
-use crate::executor::plugin_instance::common::reconcile_with;
+use crate::executor::plugin_instance::common::{
+    apply_initial_config, instance_config_schema, reconcile_with, respawn_or_reenable,
+};
 
 pub trait OutputSinkPluginApi {
     fn list_targets_json(&mut self) -> Result<String>;
@@ -51,7 +55,10 @@ pub trait OutputSinkPluginApi {
 pub struct WasmtimeOutputSinkPlugin {
     plugin_id: String,
     component: PluginCell<Store<OutputSinkStoreData>, OutputSinkBinding>,
+    config_schema: ConfigSchema,
     session: Option<wasmtime::component::ResourceAny>,
+    opened: Option<(String, RuntimeAudioSpec)>,
+    config_json: Option<String>,
 }
 
 impl WasmtimeOutputSinkPlugin {
@@ -74,6 +81,16 @@ impl WasmtimeOutputSinkPlugin {
         let session = output
             .call_create(&mut self.component.store)?
             .map_err(|error| crate::op_error!("output-sink.create plugin error: {error:?}"))?;
+        apply_initial_config(&self.plugin_id, &mut self.config_json, |config_json| {
+            output
+                .session()
+                .call_apply_config_update_json(&mut self.component.store, session, config_json)?
+                .map_err(|error| {
+                    crate::op_error!(
+                        "output-sink.session.apply-config-update-json plugin error: {error:?}"
+                    )
+                })
+        });
         self.session = Some(session);
         self.session
             .ok_or_else(|| crate::op_error!("output-sink session handle missing after create"))
@@ -81,12 +98,18 @@ impl WasmtimeOutputSinkPlugin {
 
     fn reconcile_runtime(&mut self) -> Result<()> {
         let session = self.session;
-        let mut rebuilt = false;
+        let opened = &self.opened;
+        let last_config = self.config_json.clone();
+        let restored = RefCell::new(None::<Option<wasmtime::component::ResourceAny>>);
+        let mut applied_config = None;
         let mut destroyed = false;
+        let config_schema = &self.config_schema;
         reconcile_with(
             &mut self.component,
             |store, plugin, config_json| {
+                config_schema.check_json(config_json)?;
                 let output = plugin.stellatune_plugin_output_sink();
+                let session = restored.borrow().unwrap_or(session);
                 if let Some(session_ref) = session {
                     let plan = output
                         .session()
@@ -116,11 +139,19 @@ impl WasmtimeOutputSinkPlugin {
                         },
                     }
                 }
+                applied_config = Some(config_json.to_string());
                 Ok(())
             },
-            |store, plugin| {
+            |store, plugin, respawn| {
                 let output = plugin.stellatune_plugin_output_sink();
+                let mut state_json = None;
                 if let Some(session_ref) = session {
+                    state_json = output
+                        .session()
+                        .call_export_state_json(&mut *store, session_ref)
+                        .ok()
+                        .and_then(|result| result.ok())
+                        .flatten();
                     let _ = output.session().call_close(&mut *store, session_ref);
                     let _ = session_ref.resource_drop(&mut *store);
                 }
@@ -129,8 +160,26 @@ impl WasmtimeOutputSinkPlugin {
                     store,
                     map_disable_reason_output_sink(PluginDisableReason::Reload),
                 )?;
-                call_output_sink_on_enable(plugin, store)?;
-                rebuilt = true;
+                respawn_or_reenable(store, plugin, respawn, call_output_sink_on_enable)?;
+                let session = match session {
+                    Some(_) => restore_session(
+                        plugin,
+                        store,
+                        last_config.as_deref(),
+                        opened.as_ref(),
+                        state_json.as_deref(),
+                    )
+                    .inspect_err(|error| {
+                        tracing::warn!(
+                            target: "stellatune_plugins::runtime",
+                            %error,
+                            "dropping output-sink session that failed to restore after rebuild"
+                        );
+                    })
+                    .ok(),
+                    None => None,
+                };
+                *restored.borrow_mut() = Some(session);
                 Ok(())
             },
             |store, plugin, reason| {
@@ -144,11 +193,81 @@ impl WasmtimeOutputSinkPlugin {
                 Ok(())
             },
         )?;
-        if rebuilt || destroyed {
+        if let Some(config_json) = applied_config {
+            self.config_json = Some(config_json);
+        }
+        if let Some(session) = restored.into_inner() {
+            self.session = session;
+            if session.is_none() {
+                self.opened = None;
+            }
+        }
+        if destroyed {
             self.session = None;
+            self.opened = None;
+        }
+        Ok(())
+    }
+}
+
+/// Recreates the session on a rebuilt instance: re-applies the last config, reopens the
+/// last target and imports the state exported before the rebuild.
+fn restore_session(
+    plugin: &OutputSinkBinding,
+    store: &mut Store<OutputSinkStoreData>,
+    config_json: Option<&str>,
+    opened: Option<&(String, RuntimeAudioSpec)>,
+    state_json: Option<&str>,
+) -> Result<wasmtime::component::ResourceAny> {
+    let output = plugin.stellatune_plugin_output_sink();
+    let session = output
+        .call_create(&mut *store)?
+        .map_err(|error| crate::op_error!("output-sink.create plugin error: {error:?}"))?;
+    let mut configure = || -> Result<()> {
+        if let Some(config_json) = config_json {
+            output
+                .session()
+                .call_apply_config_update_json(&mut *store, session, config_json)?
+                .map_err(|error| {
+                    crate::op_error!(
+                        "output-sink.session.apply-config-update-json plugin error: {error:?}"
+                    )
+                })?;
+        }
+        if let Some((target_json, spec)) = opened {
+            output
+                .session()
+                .call_open_json(
+                    &mut *store,
+                    session,
+                    target_json,
+                    output_sink_exports::AudioSpec {
+                        sample_rate: spec.sample_rate,
+                        channels: spec.channels,
+                    },
+                )?
+                .map_err(|error| {
+                    crate::op_error!("output-sink.session.open-json plugin error: {error:?}")
+                })?;
+        }
+        if let Some(state_json) = state_json {
+            output
+                .session()
+                .call_import_state_json(&mut *store, session, state_json)?
+                .map_err(|error| {
+                    crate::op_error!(
+                        "output-sink.session.import-state-json plugin error: {error:?}"
+                    )
+                })?;
         }
         Ok(())
+    };
+    if let Err(error) = configure() {
+        let _ = output.session().call_close(&mut *store, session);
+        let _ = session.resource_drop(&mut *store);
+        return Err(error);
     }
+    Ok(session)
 }
 
 impl OutputSinkPluginApi for WasmtimeOutputSinkPlugin {
@@ -261,6 +380,7 @@ impl OutputSinkPluginApi for WasmtimeOutputSinkPlugin {
             .map_err(|error| {
                 crate::op_error!("output-sink.session.open-json plugin error: {error:?}")
             })?;
+        self.opened = Some((target_json.to_string(), spec));
         Ok(())
     }
 
@@ -326,6 +446,7 @@ impl OutputSinkPluginApi for WasmtimeOutputSinkPlugin {
     }
 
     fn plan_config_update_json(&mut self, config_json: &str) -> Result<(String, Option<String>)> {
+        self.config_schema.check_json(config_json)?;
         self.reconcile_runtime()?;
         let session = self.ensure_session()?;
         let output = self.output_sink_api();
@@ -341,6 +462,7 @@ impl OutputSinkPluginApi for WasmtimeOutputSinkPlugin {
     }
 
     fn apply_config_update_json(&mut self, config_json: &str) -> Result<()> {
+        self.config_schema.check_json(config_json)?;
         self.reconcile_runtime()?;
         let session = self.ensure_session()?;
         let output = self.output_sink_api();
@@ -352,6 +474,7 @@ impl OutputSinkPluginApi for WasmtimeOutputSinkPlugin {
                     "output-sink.session.apply-config-update-json plugin error: {error:?}"
                 )
             })?;
+        self.config_json = Some(config_json.to_string());
         Ok(())
     }
 
@@ -381,6 +504,7 @@ impl OutputSinkPluginApi for WasmtimeOutputSinkPlugin {
     }
 
     fn close(&mut self) -> Result<()> {
+        self.opened = None;
         let Some(session) = self.session.take() else {
             return Ok(());
         };
@@ -418,8 +542,7 @@ impl WasmtimePluginController {
         self.ensure_plugin_active(plugin_id)?;
 
         let component_path = plugin.root_dir.join(&capability.component_rel_path);
-        let component = self
-            .load_component_cached(&component_path)
+        self.load_component_cached(&component_path)
             .map_err(|error| {
                 crate::op_error!(
                     "failed to load component for plugin `{}` component `{}`: {error:#}",
@@ -430,9 +553,12 @@ impl WasmtimePluginController {
 
         let (tx, rx) = mpsc::channel::<RuntimePluginDirective>();
         let component = match classify_world(&capability.world) {
-            WorldKind::OutputSink => {
-                self.instantiate_output_sink_component(plugin_id, &plugin.root_dir, &component, rx)?
-            },
+            WorldKind::OutputSink => self.instantiate_output_sink_component(
+                plugin_id,
+                &plugin.root_dir,
+                &component_path,
+                rx,
+            )?,
             _ => {
                 return Err(crate::op_error!(
                     "capability world `{}` is not an output-sink world",
@@ -442,10 +568,15 @@ impl WasmtimePluginController {
         };
         self.register_directive_sender(plugin_id, tx)?;
 
+        let config_schema = instance_config_schema(plugin_id, &capability);
+        let config_json = self.persisted_config(plugin_id, &capability.type_id, &config_schema);
         Ok(WasmtimeOutputSinkPlugin {
             plugin_id: plugin_id.to_string(),
             component,
+            config_schema,
             session: None,
+            opened: None,
+            config_json,
         })
     }
 
@@ -0,0 +1,375 @@
+use std::sync::mpsc;
+
+use crate::error::Result;
+use wasmtime::Store;
+
+use stellatune_host_bindings::generated as host_bindings;
+
+use host_bindings::playback_listener_plugin::PlaybackListenerPlugin as PlaybackListenerBinding;
+use host_bindings::playback_listener_plugin::exports::stellatune::plugin::playback_listener as playback_listener_exports;
+use host_bindings::playback_listener_plugin::stellatune::plugin::common as playback_listener_common;
+
+use crate::config_schema::ConfigSchema;
+use crate::executor::plugin_cell::{PluginCell, PluginCellState};
+use crate::executor::stores::playback_listener::PlaybackListenerStoreData;
+use crate::executor::{
+    WasmPluginController, WasmtimePluginController, WorldKind, call_playback_listener_on_disable,
+    call_playback_listener_on_enable, classify_world, map_disable_reason_playback_listener,
+};
+use crate::manifest::AbilityKind;
+use crate::runtime::model::{
+    PluginDisableReason, RuntimeCapabilityDescriptor, RuntimeMediaMetadata, RuntimeMetadataValue,
+    RuntimePlaybackEvent, RuntimePlaybackSkipReason, RuntimePlaybackTrackInfo,
+    RuntimePluginDirective, RuntimePluginInfo,
+};
+
+use crate::executor::plugin_instance::common::{
+    apply_initial_config, instance_config_schema, reconcile_with, respawn_or_reenable,
+};
+
+pub trait PlaybackListenerPluginApi {
+    fn on_event(&mut self, event: &RuntimePlaybackEvent) -> Result<()>;
+    fn close(&mut self) -> Result<()>;
+}
+
+pub struct WasmtimePlaybackListenerPlugin {
+    plugin_id: String,
+    component: PluginCell<Store<PlaybackListenerStoreData>, PlaybackListenerBinding>,
+    config_schema: ConfigSchema,
+    listener: Option<wasmtime::component::ResourceAny>,
+    config_json: Option<String>,
+}
+
+impl WasmtimePlaybackListenerPlugin {
+    pub fn plugin_id(&self) -> &str {
+        &self.plugin_id
+    }
+
+    fn playback_listener_api(&self) -> playback_listener_exports::Guest {
+        self.component
+            .plugin
+            .stellatune_plugin_playback_listener()
+            .clone()
+    }
+
+    fn ensure_listener(&mut self) -> Result<wasmtime::component::ResourceAny> {
+        if let Some(listener) = self.listener {
+            return Ok(listener);
+        }
+        let api = self.playback_listener_api();
+        let listener = api
+            .call_create(&mut self.component.store)?
+            .map_err(|error| {
+                crate::op_error!("playback-listener.create plugin error: {error:?}")
+            })?;
+        apply_initial_config(&self.plugin_id, &mut self.config_json, |config_json| {
+            api.listener()
+                .call_apply_config_update_json(&mut self.component.store, listener, config_json)?
+                .map_err(|error| {
+                    crate::op_error!(
+                        "playback-listener.listener.apply-config-update-json plugin error: {error:?}"
+                    )
+                })
+        });
+        self.listener = Some(listener);
+        self.listener
+            .ok_or_else(|| crate::op_error!("playback listener handle missing after create"))
+    }
+
+    fn reconcile_runtime(&mut self) -> Result<()> {
+        let listener = self.listener;
+        let mut applied_config = None;
+        let mut rebuilt = false;
+        let mut destroyed = false;
+        let config_schema = &self.config_schema;
+        reconcile_with(
+            &mut self.component,
+            |store, plugin, config_json| {
+                config_schema.check_json(config_json)?;
+                let api = plugin.stellatune_plugin_playback_listener();
+                let Some(listener_ref) = listener else {
+                    applied_config = Some(config_json.to_string());
+                    return Ok(());
+                };
+                let plan = api
+                    .listener()
+                    .call_plan_config_update_json(&mut *store, listener_ref, config_json)?
+                    .map_err(|error| {
+                        crate::op_error!(
+                            "playback-listener.listener.plan-config-update-json plugin error: {error:?}"
+                        )
+                    })?;
+                match plan.mode {
+                    playback_listener_common::ConfigUpdateMode::HotApply => {
+                        api.listener()
+                            .call_apply_config_update_json(&mut *store, listener_ref, config_json)?
+                            .map_err(|error| {
+                                crate::op_error!(
+                                    "playback-listener.listener.apply-config-update-json plugin error: {error:?}"
+                                )
+                            })?;
+                    },
+                    playback_listener_common::ConfigUpdateMode::Recreate => {
+                        return Err(crate::op_error!(
+                            "playback listener requested recreate for config update"
+                        ));
+                    },
+                    playback_listener_common::ConfigUpdateMode::Reject => {
+                        return Err(crate::op_error!(
+                            "playback listener rejected config update: {}",
+                            plan.reason.unwrap_or_else(|| "unknown".to_string())
+                        ));
+                    },
+                }
+                applied_config = Some(config_json.to_string());
+                Ok(())
+            },
+            |store, plugin, respawn| {
+                let api = plugin.stellatune_plugin_playback_listener();
+                if let Some(listener_ref) = listener {
+                    let _ = api.listener().call_close(&mut *store, listener_ref);
+                    let _ = listener_ref.resource_drop(&mut *store);
+                }
+                call_playback_listener_on_disable(
+                    plugin,
+                    store,
+                    map_disable_reason_playback_listener(PluginDisableReason::Reload),
+                )?;
+                respawn_or_reenable(store, plugin, respawn, call_playback_listener_on_enable)?;
+                rebuilt = true;
+                Ok(())
+            },
+            |store, plugin, reason| {
+                let api = plugin.stellatune_plugin_playback_listener();
+                if let Some(listener_ref) = listener {
+                    let _ = api.listener().call_close(&mut *store, listener_ref);
+                    let _ = listener_ref.resource_drop(&mut *store);
+                }
+                call_playback_listener_on_disable(
+                    plugin,
+                    store,
+                    map_disable_reason_playback_listener(reason),
+                )?;
+                destroyed = true;
+                Ok(())
+            },
+        )?;
+        if let Some(config_json) = applied_config {
+            self.config_json = Some(config_json);
+        }
+        if rebuilt || destroyed {
+            self.listener = None;
+        }
+        Ok(())
+    }
+
+    fn map_metadata(meta: &RuntimeMediaMetadata) -> playback_listener_common::MediaMetadata {
+        playback_listener_common::MediaMetadata {
+            tags: playback_listener_common::AudioTags {
+                title: meta.tags.title.clone(),
+                album: meta.tags.album.clone(),
+                artists: meta.tags.artists.clone(),
+                album_artists: meta.tags.album_artists.clone(),
+                genres: meta.tags.genres.clone(),
+                track_number: meta.tags.track_number,
+                track_total: meta.tags.track_total,
+                disc_number: meta.tags.disc_number,
+                disc_total: meta.tags.disc_total,
+                year: meta.tags.year,
+                comment: meta.tags.comment.clone(),
+            },
+            duration_ms: meta.duration_ms,
+            format: playback_listener_common::EncodedAudioFormat {
+                codec: meta.format.codec.clone(),
+                sample_rate: meta.format.sample_rate,
+                channels: meta.format.channels,
+                bitrate_kbps: meta.format.bitrate_kbps,
+                container: meta.format.container.clone(),
+            },
+            extras: meta
+                .extras
+                .iter()
+                .map(|entry| playback_listener_common::MetadataEntry {
+                    key: entry.key.clone(),
+                    value: match &entry.value {
+                        RuntimeMetadataValue::Text(v) => {
+                            playback_listener_common::MetadataValue::Text(v.clone())
+                        },
+                        RuntimeMetadataValue::Boolean(v) => {
+                            playback_listener_common::MetadataValue::Boolean(*v)
+                        },
+                        RuntimeMetadataValue::Uint32(v) => {
+                            playback_listener_common::MetadataValue::Uint32(*v)
+                        },
+                        RuntimeMetadataValue::Uint64(v) => {
+                            playback_listener_common::MetadataValue::Uint64(*v)
+                        },
+                        RuntimeMetadataValue::Int64(v) => {
+                            playback_listener_common::MetadataValue::Int64(*v)
+                        },
+                        RuntimeMetadataValue::Float64(v) => {
+                            playback_listener_common::MetadataValue::Float64(*v)
+                        },
+                        RuntimeMetadataValue::Bytes(v) => {
+                            playback_listener_common::MetadataValue::Bytes(v.clone())
+                        },
+                    },
+                })
+                .collect::<Vec<_>>(),
+        }
+    }
+
+    fn map_track(track: &RuntimePlaybackTrackInfo) -> playback_listener_exports::TrackInfo {
+        playback_listener_exports::TrackInfo {
+            track_token: track.track_token.clone(),
+            source_id: track.source_id.clone(),
+            track_id: track.track_id.clone(),
+            locator: track.locator.clone(),
+            duration_ms: track.duration_ms,
+            metadata: track.metadata.as_ref().map(Self::map_metadata),
+        }
+    }
+
+    fn map_event(event: &RuntimePlaybackEvent) -> playback_listener_exports::PlaybackEvent {
+        match event {
+            RuntimePlaybackEvent::TrackStarted { track } => {
+                playback_listener_exports::PlaybackEvent::TrackStarted(Self::map_track(track))
+            },
+            RuntimePlaybackEvent::Progress { track, position_ms } => {
+                playback_listener_exports::PlaybackEvent::Progress(
+                    playback_listener_exports::TrackProgress {
+                        track: Self::map_track(track),
+                        position_ms: *position_ms,
+                    },
+                )
+            },
+            RuntimePlaybackEvent::Paused { track, position_ms } => {
+                playback_listener_exports::PlaybackEvent::Paused(
+                    playback_listener_exports::TrackProgress {
+                        track: Self::map_track(track),
+                        position_ms: *position_ms,
+                    },
+                )
+            },
+            RuntimePlaybackEvent::Finished { track } => {
+                playback_listener_exports::PlaybackEvent::Finished(Self::map_track(track))
+            },
+            RuntimePlaybackEvent::Skipped {
+                track,
+                position_ms,
+                reason,
+            } => playback_listener_exports::PlaybackEvent::Skipped(
+                playback_listener_exports::TrackSkipped {
+                    track: Self::map_track(track),
+                    position_ms: *position_ms,
+                    reason: match reason {
+                        RuntimePlaybackSkipReason::UserSwitch => {
+                            playback_listener_exports::SkipReason::UserSwitch
+                        },
+                        RuntimePlaybackSkipReason::Stopped => {
+                            playback_listener_exports::SkipReason::Stopped
+                        },
+                    },
+                },
+            ),
+        }
+    }
+}
+
+impl PlaybackListenerPluginApi for WasmtimePlaybackListenerPlugin {
+    fn on_event(&mut self, event: &RuntimePlaybackEvent) -> Result<()> {
+        self.reconcile_runtime()?;
+        let listener = self.ensure_listener()?;
+        let api = self.playback_listener_api();
+        api.listener()
+            .call_on_event(&mut self.component.store, listener, &Self::map_event(event))?
+            .map_err(|error| {
+                crate::op_error!("playback-listener.listener.on-event plugin error: {error:?}")
+            })
+    }
+
+    fn close(&mut self) -> Result<()> {
+        let Some(listener) = self.listener.take() else {
+            return Ok(());
+        };
+        let api = self.playback_listener_api();
+        let _ = api
+            .listener()
+            .call_close(&mut self.component.store, listener);
+        let _ = listener.resource_drop(&mut self.component.store);
+        Ok(())
+    }
+}
+
+impl Drop for WasmtimePlaybackListenerPlugin {
+    fn drop(&mut self) {
+        let _ = self.close();
+        if self.component.state() != PluginCellState::Destroyed {
+            let _ = call_playback_listener_on_disable(
+                &self.component.plugin,
+                &mut self.component.store,
+                map_disable_reason_playback_listener(PluginDisableReason::HostDisable),
+            );
+        }
+    }
+}
+
+impl WasmtimePluginController {
+    pub fn create_playback_listener_plugin(
+        &self,
+        plugin_id: &str,
+        type_id: &str,
+    ) -> Result<WasmtimePlaybackListenerPlugin> {
+        let (plugin, capability) =
+            self.resolve_capability(plugin_id, AbilityKind::PlaybackListener, type_id)?;
+        let plugin_id = plugin.id.trim();
+        self.ensure_plugin_active(plugin_id)?;
+
+        let component_path = plugin.root_dir.join(&capability.component_rel_path);
+        self.load_component_cached(&component_path)
+            .map_err(|error| {
+                crate::op_error!(
+                    "failed to load component for plugin `{}` component `{}`: {error:#}",
+                    plugin_id,
+                    capability.component_id
+                )
+            })?;
+
+        let (tx, rx) = mpsc::channel::<RuntimePluginDirective>();
+        let component = match classify_world(&capability.world) {
+            WorldKind::PlaybackListener => self.instantiate_playback_listener_component(
+                plugin_id,
+                &plugin.root_dir,
+                &component_path,
+                rx,
+            )?,
+            _ => {
+                return Err(crate::op_error!(
+                    "capability world `{}` is not a playback-listener world",
+                    capability.world
+                ));
+            },
+        };
+        self.register_directive_sender(plugin_id, tx)?;
+
+        let config_schema = instance_config_schema(plugin_id, &capability);
+        let config_json = self.persisted_config(plugin_id, &capability.type_id, &config_schema);
+        Ok(WasmtimePlaybackListenerPlugin {
+            plugin_id: plugin_id.to_string(),
+            component,
+            config_schema,
+            listener: None,
+            config_json,
+        })
+    }
+
+    pub fn install_and_create_playback_listener_plugin(
+        &self,
+        plugin: &RuntimePluginInfo,
+        capabilities: &[RuntimeCapabilityDescriptor],
+        type_id: &str,
+    ) -> Result<WasmtimePlaybackListenerPlugin> {
+        WasmPluginController::install_plugin(self, plugin, capabilities)?;
+        self.create_playback_listener_plugin(&plugin.id, type_id)
+    }
+}
@@ -1,7 +1,8 @@
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::sync::mpsc;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use wasmtime::Store;
 
 use stellatune_host_bindings::generated as host_bindings;
@@ -10,6 +11,7 @@ use host_bindings::source_plugin::SourcePlugin as SourceBinding;
 use host_bindings::source_plugin::exports::stellatune::plugin::source as source_exports;
 use host_bindings::source_plugin::stellatune::plugin::common as source_common;
 
+use crate::config_schema::ConfigSchema;
 use crate::executor::plugin_cell::{PluginCell, PluginCellState};
 use crate::executor::stores::source::SourceStoreData;
 use crate::executor::{
@@ -19,12 +21,27 @@ use crate::executor::{
 use crate::host::stream::HostStreamHandle;
 use crate::manifest::AbilityKind;
 use crate::runtime::model::{
-    PluginDisableReason, RuntimeAudioTags, RuntimeCapabilityDescriptor, RuntimeEncodedAudioFormat,
-    RuntimeEncodedChunk, RuntimeMediaMetadata, RuntimeMetadataEntry, RuntimeMetadataValue,
-    RuntimePluginDirective, RuntimePluginInfo, RuntimeSourceStreamHandle,
+    PluginDisableReason, RuntimeAudioTags, RuntimeAuthState, RuntimeAuthStatus, RuntimeBrowseNode,
+    RuntimeBrowseNodeKind, RuntimeBrowsePage, RuntimeCapabilityDescriptor,
+    RuntimeCatalogCapabilities, RuntimeEncodedAudioFormat, RuntimeEncodedChunk,
+    RuntimeLoginChallenge, RuntimeLoginMethod, RuntimeLoginProgress, RuntimeMediaMetadata,
+    RuntimeMetadataEntry, RuntimeMetadataValue, RuntimePageRequest, RuntimePluginDirective,
+    RuntimePluginInfo, RuntimeSearchRequest, RuntimeSourceStreamHandle,
 };
 
-use crate::executor::plugin_instance::common::reconcile_with;
+use crate::executor::plugin_instance::common::{
+    apply_initial_config, instance_config_schema, reconcile_with, respawn_or_reenable,
+};
+
+/// Keeps auth failures typed so callers can prompt for login; everything else stays an
+/// operation error.
+fn catalog_error(operation: &str, error: source_common::PluginError) -> Error {
+    match error {
+        source_common::PluginError::AuthRequired(message) => Error::auth_required(message),
+        source_common::PluginError::AuthExpired(message) => Error::auth_expired(message),
+        other => crate::op_error!("{operation} plugin error: {other:?}"),
+    }
+}
 
 pub enum RuntimeOpenedSourceStreamHandle {
     Passthrough(Box<dyn HostStreamHandle>),
@@ -38,7 +55,19 @@ pub struct RuntimeOpenedSourceStream {
 }
 
 pub trait SourcePluginApi {
+    fn capabilities(&mut self) -> Result<RuntimeCatalogCapabilities>;
+    fn browse(
+        &mut self,
+        parent_id: Option<&str>,
+        page: &RuntimePageRequest,
+    ) -> Result<RuntimeBrowsePage>;
+    fn search(&mut self, request: &RuntimeSearchRequest) -> Result<RuntimeBrowsePage>;
     fn list_items_json(&mut self, request_json: &str) -> Result<String>;
+    fn auth_status(&mut self) -> Result<RuntimeAuthStatus>;
+    fn begin_login(&mut self, method: Option<RuntimeLoginMethod>) -> Result<RuntimeLoginChallenge>;
+    fn poll_login(&mut self, session_id: &str) -> Result<RuntimeLoginProgress>;
+    fn refresh_auth(&mut self) -> Result<RuntimeAuthStatus>;
+    fn logout(&mut self) -> Result<()>;
     fn open_stream_json(&mut self, track_json: &str) -> Result<RuntimeOpenedSourceStream>;
     fn open_uri(&mut self, uri: &str) -> Result<RuntimeOpenedSourceStream>;
     fn metadata(&mut self, stream: RuntimeSourceStreamHandle) -> Result<RuntimeMediaMetadata>;
@@ -57,9 +86,11 @@ pub trait SourcePluginApi {
 pub struct WasmtimeSourcePlugin {
     plugin_id: String,
     component: PluginCell<Store<SourceStoreData>, SourceBinding>,
+    config_schema: ConfigSchema,
     catalog: Option<wasmtime::component::ResourceAny>,
     next_stream_handle: u64,
     streams: BTreeMap<u64, wasmtime::component::ResourceAny>,
+    config_json: Option<String>,
 }
 
 impl WasmtimeSourcePlugin {
@@ -87,12 +118,17 @@ impl WasmtimeSourcePlugin {
     fn reconcile_runtime(&mut self) -> Result<()> {
         let stream_refs = self.streams.values().cloned().collect::<Vec<_>>();
         let catalog = self.catalog;
-        let mut rebuilt = false;
+        let last_config = self.config_json.clone();
+        let restored = RefCell::new(None::<Option<wasmtime::component::ResourceAny>>);
+        let mut applied_config = None;
         let mut destroyed = false;
+        let config_schema = &self.config_schema;
         reconcile_with(
             &mut self.component,
             |store, plugin, config_json| {
+                config_schema.check_json(config_json)?;
                 let source = plugin.stellatune_plugin_source();
+                let catalog = restored.borrow().unwrap_or(catalog);
                 if let Some(catalog_ref) = catalog {
                     let plan = source
                         .catalog()
@@ -126,15 +162,23 @@ impl WasmtimeSourcePlugin {
                         },
                     }
                 }
+                applied_config = Some(config_json.to_string());
                 Ok(())
             },
-            |store, plugin| {
+            |store, plugin, respawn| {
                 let source = plugin.stellatune_plugin_source();
                 for stream in &stream_refs {
                     let _ = source.source_stream().call_close(&mut *store, *stream);
                     let _ = (*stream).resource_drop(&mut *store);
                 }
+                let mut state_json = None;
                 if let Some(catalog_ref) = catalog {
+                    state_json = source
+                        .catalog()
+                        .call_export_state_json(&mut *store, catalog_ref)
+                        .ok()
+                        .and_then(|result| result.ok())
+                        .flatten();
                     let _ = source.catalog().call_close(&mut *store, catalog_ref);
                     let _ = catalog_ref.resource_drop(&mut *store);
                 }
@@ -143,8 +187,25 @@ impl WasmtimeSourcePlugin {
                     store,
                     map_disable_reason_source(PluginDisableReason::Reload),
                 )?;
-                call_source_on_enable(plugin, store)?;
-                rebuilt = true;
+                respawn_or_reenable(store, plugin, respawn, call_source_on_enable)?;
+                let catalog = match catalog {
+                    Some(_) => restore_catalog(
+                        plugin,
+                        store,
+                        last_config.as_deref(),
+                        state_json.as_deref(),
+                    )
+                    .inspect_err(|error| {
+                        tracing::warn!(
+                            target: "stellatune_plugins::runtime",
+                            %error,
+                            "dropping source catalog that failed to restore after rebuild"
+                        );
+                    })
+                    .ok(),
+                    None => None,
+                };
+                *restored.borrow_mut() = Some(catalog);
                 Ok(())
             },
             |store, plugin, reason| {
@@ -162,7 +223,15 @@ impl WasmtimeSourcePlugin {
                 Ok(())
             },
         )?;
-        if rebuilt || destroyed {
+        if let Some(config_json) = applied_config {
+            self.config_json = Some(config_json);
+        }
+        if let Some(catalog) = restored.into_inner() {
+            // Open streams belong to the old instance and cannot be carried over.
+            self.catalog = catalog;
+            self.streams.clear();
+        }
+        if destroyed {
             self.catalog = None;
             self.streams.clear();
         }
@@ -177,6 +246,16 @@ impl WasmtimeSourcePlugin {
         let catalog = source
             .call_create(&mut self.component.store)?
             .map_err(|error| crate::op_error!("source.create plugin error: {error:?}"))?;
+        apply_initial_config(&self.plugin_id, &mut self.config_json, |config_json| {
+            source
+                .catalog()
+                .call_apply_config_update_json(&mut self.component.store, catalog, config_json)?
+                .map_err(|error| {
+                    crate::op_error!(
+                        "source.catalog.apply-config-update-json plugin error: {error:?}"
+                    )
+                })
+        });
         self.catalog = Some(catalog);
         self.catalog
             .ok_or_else(|| crate::op_error!("source catalog handle missing after create"))
@@ -208,26 +287,115 @@ impl WasmtimeSourcePlugin {
             extras: meta
                 .extras
                 .into_iter()
-                .map(|entry| RuntimeMetadataEntry {
-                    key: entry.key,
-                    value: match entry.value {
-                        source_common::MetadataValue::Text(v) => RuntimeMetadataValue::Text(v),
-                        source_common::MetadataValue::Boolean(v) => {
-                            RuntimeMetadataValue::Boolean(v)
-                        },
-                        source_common::MetadataValue::Uint32(v) => RuntimeMetadataValue::Uint32(v),
-                        source_common::MetadataValue::Uint64(v) => RuntimeMetadataValue::Uint64(v),
-                        source_common::MetadataValue::Int64(v) => RuntimeMetadataValue::Int64(v),
-                        source_common::MetadataValue::Float64(v) => {
-                            RuntimeMetadataValue::Float64(v)
-                        },
-                        source_common::MetadataValue::Bytes(v) => RuntimeMetadataValue::Bytes(v),
-                    },
-                })
+                .map(Self::map_runtime_metadata_entry)
                 .collect::<Vec<_>>(),
         }
     }
 
+    fn map_runtime_metadata_entry(entry: source_common::MetadataEntry) -> RuntimeMetadataEntry {
+        RuntimeMetadataEntry {
+            key: entry.key,
+            value: match entry.value {
+                source_common::MetadataValue::Text(v) => RuntimeMetadataValue::Text(v),
+                source_common::MetadataValue::Boolean(v) => RuntimeMetadataValue::Boolean(v),
+                source_common::MetadataValue::Uint32(v) => RuntimeMetadataValue::Uint32(v),
+                source_common::MetadataValue::Uint64(v) => RuntimeMetadataValue::Uint64(v),
+                source_common::MetadataValue::Int64(v) => RuntimeMetadataValue::Int64(v),
+                source_common::MetadataValue::Float64(v) => RuntimeMetadataValue::Float64(v),
+                source_common::MetadataValue::Bytes(v) => RuntimeMetadataValue::Bytes(v),
+            },
+        }
+    }
+
+    fn map_runtime_node_kind(kind: source_exports::BrowseNodeKind) -> RuntimeBrowseNodeKind {
+        match kind {
+            source_exports::BrowseNodeKind::Folder => RuntimeBrowseNodeKind::Folder,
+            source_exports::BrowseNodeKind::Playlist => RuntimeBrowseNodeKind::Playlist,
+            source_exports::BrowseNodeKind::Album => RuntimeBrowseNodeKind::Album,
+            source_exports::BrowseNodeKind::Artist => RuntimeBrowseNodeKind::Artist,
+            source_exports::BrowseNodeKind::Track => RuntimeBrowseNodeKind::Track,
+        }
+    }
+
+    fn map_wit_node_kind(kind: RuntimeBrowseNodeKind) -> source_exports::BrowseNodeKind {
+        match kind {
+            RuntimeBrowseNodeKind::Folder => source_exports::BrowseNodeKind::Folder,
+            RuntimeBrowseNodeKind::Playlist => source_exports::BrowseNodeKind::Playlist,
+            RuntimeBrowseNodeKind::Album => source_exports::BrowseNodeKind::Album,
+            RuntimeBrowseNodeKind::Artist => source_exports::BrowseNodeKind::Artist,
+            RuntimeBrowseNodeKind::Track => source_exports::BrowseNodeKind::Track,
+        }
+    }
+
+    fn map_wit_page_request(page: &RuntimePageRequest) -> source_exports::PageRequest {
+        source_exports::PageRequest {
+            cursor: page.cursor.clone(),
+            limit: page.limit,
+        }
+    }
+
+    fn map_runtime_login_method(method: source_exports::LoginMethod) -> RuntimeLoginMethod {
+        match method {
+            source_exports::LoginMethod::QrCode => RuntimeLoginMethod::QrCode,
+            source_exports::LoginMethod::Url => RuntimeLoginMethod::Url,
+            source_exports::LoginMethod::DeviceCode => RuntimeLoginMethod::DeviceCode,
+        }
+    }
+
+    fn map_wit_login_method(method: RuntimeLoginMethod) -> source_exports::LoginMethod {
+        match method {
+            RuntimeLoginMethod::QrCode => source_exports::LoginMethod::QrCode,
+            RuntimeLoginMethod::Url => source_exports::LoginMethod::Url,
+            RuntimeLoginMethod::DeviceCode => source_exports::LoginMethod::DeviceCode,
+        }
+    }
+
+    fn map_runtime_auth_status(status: source_exports::AuthStatus) -> RuntimeAuthStatus {
+        RuntimeAuthStatus {
+            state: match status.state {
+                source_exports::AuthState::NotRequired => RuntimeAuthState::NotRequired,
+                source_exports::AuthState::LoggedOut => RuntimeAuthState::LoggedOut,
+                source_exports::AuthState::LoggedIn => RuntimeAuthState::LoggedIn,
+                source_exports::AuthState::Expired => RuntimeAuthState::Expired,
+            },
+            account: status.account,
+            expires_at_ms: status.expires_at_ms,
+            login_methods: status
+                .login_methods
+                .into_iter()
+                .map(Self::map_runtime_login_method)
+                .collect(),
+            message: status.message,
+        }
+    }
+
+    fn map_runtime_browse_page(page: source_exports::BrowsePage) -> RuntimeBrowsePage {
+        RuntimeBrowsePage {
+            nodes: page
+                .nodes
+                .into_iter()
+                .map(|node| RuntimeBrowseNode {
+                    kind: Self::map_runtime_node_kind(node.kind),
+                    id: node.id,
+                    title: node.title,
+                    subtitle: node.subtitle,
+                    artists: node.artists,
+                    album: node.album,
+                    duration_ms: node.duration_ms,
+                    child_count: node.child_count,
+                    artwork_url: node.artwork_url,
+                    track_json: node.track_json,
+                    extras: node
+                        .extras
+                        .into_iter()
+                        .map(Self::map_runtime_metadata_entry)
+                        .collect(),
+                })
+                .collect(),
+            next_cursor: page.next_cursor,
+        }
+    }
+
     fn map_opened_stream(
         &mut self,
         opened: source_exports::OpenedStream,
@@ -262,7 +430,108 @@ impl WasmtimeSourcePlugin {
     }
 }
 
+/// Recreates the catalog on a rebuilt instance, re-applying the last config and the
+/// state exported before the rebuild.
+fn restore_catalog(
+    plugin: &SourceBinding,
+    store: &mut Store<SourceStoreData>,
+    config_json: Option<&str>,
+    state_json: Option<&str>,
+) -> Result<wasmtime::component::ResourceAny> {
+    let source = plugin.stellatune_plugin_source();
+    let catalog = source
+        .call_create(&mut *store)?
+        .map_err(|error| crate::op_error!("source.create plugin error: {error:?}"))?;
+    let mut configure = || -> Result<()> {
+        if let Some(config_json) = config_json {
+            source
+                .catalog()
+                .call_apply_config_update_json(&mut *store, catalog, config_json)?
+                .map_err(|error| {
+                    crate::op_error!(
+                        "source.catalog.apply-config-update-json plugin error: {error:?}"
+                    )
+                })?;
+        }
+        if let Some(state_json) = state_json {
+            source
+                .catalog()
+                .call_import_state_json(&mut *store, catalog, state_json)?
+                .map_err(|error| {
+                    crate::op_error!("source.catalog.import-state-json plugin error: {error:?}")
+                })?;
+        }
+        Ok(())
+    };
+    if let Err(error) = configure() {
+        let _ = source.catalog().call_close(&mut *store, catalog);
+        let _ = catalog.resource_drop(&mut *store);
+        return Err(error);
+    }
+    Ok(catalog)
+}
+
 impl SourcePluginApi for WasmtimeSourcePlugin {
+    fn capabilities(&mut self) -> Result<RuntimeCatalogCapabilities> {
+        self.reconcile_runtime()?;
+        let catalog = self.ensure_catalog()?;
+        let source = self.source_api();
+        let capabilities = source
+            .catalog()
+            .call_capabilities(&mut self.component.store, catalog)?;
+        Ok(RuntimeCatalogCapabilities {
+            browse: capabilities.browse,
+            search: capabilities.search,
+            open_uri: capabilities.open_uri,
+            search_kinds: capabilities
+                .search_kinds
+                .into_iter()
+                .map(Self::map_runtime_node_kind)
+                .collect(),
+        })
+    }
+
+    fn browse(
+        &mut self,
+        parent_id: Option<&str>,
+        page: &RuntimePageRequest,
+    ) -> Result<RuntimeBrowsePage> {
+        self.reconcile_runtime()?;
+        let catalog = self.ensure_catalog()?;
+        let source = self.source_api();
+        let page = source
+            .catalog()
+            .call_browse(
+                &mut self.component.store,
+                catalog,
+                parent_id,
+                &Self::map_wit_page_request(page),
+            )?
+            .map_err(|error| catalog_error("source.catalog.browse", error))?;
+        Ok(Self::map_runtime_browse_page(page))
+    }
+
+    fn search(&mut self, request: &RuntimeSearchRequest) -> Result<RuntimeBrowsePage> {
+        self.reconcile_runtime()?;
+        let catalog = self.ensure_catalog()?;
+        let source = self.source_api();
+        let request = source_exports::SearchRequest {
+            query: request.query.clone(),
+            kinds: request
+                .kinds
+                .iter()
+                .copied()
+                .map(Self::map_wit_node_kind)
+                .collect(),
+            page: Self::map_wit_page_request(&request.page),
+        };
+        let page = source
+            .catalog()
+            .call_search(&mut self.component.store, catalog, &request)?
+            .map_err(|error| catalog_error("source.catalog.search", error))?;
+        Ok(Self::map_runtime_browse_page(page))
+    }
+
     fn list_items_json(&mut self, request_json: &str) -> Result<String> {
         self.reconcile_runtime()?;
         let catalog = self.ensure_catalog()?;
@@ -270,9 +539,83 @@ impl SourcePluginApi for WasmtimeSourcePlugin {
         source
             .catalog()
             .call_list_items_json(&mut self.component.store, catalog, request_json)?
-            .map_err(|error| {
-                crate::op_error!("source.catalog.list-items-json plugin error: {error:?}")
-            })
+            .map_err(|error| catalog_error("source.catalog.list-items-json", error))
+    }
+
+    fn auth_status(&mut self) -> Result<RuntimeAuthStatus> {
+        self.reconcile_runtime()?;
+        let catalog = self.ensure_catalog()?;
+        let source = self.source_api();
+        let status = source
+            .catalog()
+            .call_auth_status(&mut self.component.store, catalog)?
+            .map_err(|error| catalog_error("source.catalog.auth-status", error))?;
+        Ok(Self::map_runtime_auth_status(status))
+    }
+
+    fn begin_login(&mut self, method: Option<RuntimeLoginMethod>) -> Result<RuntimeLoginChallenge> {
+        self.reconcile_runtime()?;
+        let catalog = self.ensure_catalog()?;
+        let source = self.source_api();
+        let challenge = source
+            .catalog()
+            .call_begin_login(
+                &mut self.component.store,
+                catalog,
+                method.map(Self::map_wit_login_method),
+            )?
+            .map_err(|error| catalog_error("source.catalog.begin-login", error))?;
+        Ok(RuntimeLoginChallenge {
+            method: Self::map_runtime_login_method(challenge.method),
+            session_id: challenge.session_id,
+            url: challenge.url,
+            qr_payload: challenge.qr_payload,
+            user_code: challenge.user_code,
+            expires_at_ms: challenge.expires_at_ms,
+            poll_interval_ms: challenge.poll_interval_ms,
+        })
+    }
+
+    fn poll_login(&mut self, session_id: &str) -> Result<RuntimeLoginProgress> {
+        self.reconcile_runtime()?;
+        let catalog = self.ensure_catalog()?;
+        let source = self.source_api();
+        let progress = source
+            .catalog()
+            .call_poll_login(&mut self.component.store, catalog, session_id)?
+            .map_err(|error| catalog_error("source.catalog.poll-login", error))?;
+        Ok(match progress {
+            source_exports::LoginProgress::Waiting => RuntimeLoginProgress::Waiting,
+            source_exports::LoginProgress::Confirming => RuntimeLoginProgress::Confirming,
+            source_exports::LoginProgress::Completed(status) => RuntimeLoginProgress::Completed {
+                status: Self::map_runtime_auth_status(status),
+            },
+            source_exports::LoginProgress::Expired => RuntimeLoginProgress::Expired,
+            source_exports::LoginProgress::Failed(message) => {
+                RuntimeLoginProgress::Failed { message }
+            },
+        })
+    }
+
+    fn refresh_auth(&mut self) -> Result<RuntimeAuthStatus> {
+        self.reconcile_runtime()?;
+        let catalog = self.ensure_catalog()?;
+        let source = self.source_api();
+        let status = source
+            .catalog()
+            .call_refresh_auth(&mut self.component.store, catalog)?
+            .map_err(|error| catalog_error("source.catalog.refresh-auth", error))?;
+        Ok(Self::map_runtime_auth_status(status))
+    }
+
+    fn logout(&mut self) -> Result<()> {
+        self.reconcile_runtime()?;
+        let catalog = self.ensure_catalog()?;
+        let source = self.source_api();
+        source
+            .catalog()
+            .call_logout(&mut self.component.store, catalog)?
+            .map_err(|error| catalog_error("source.catalog.logout", error))
     }
 
     fn open_stream_json(&mut self, track_json: &str) -> Result<RuntimeOpenedSourceStream> {
@@ -282,9 +625,7 @@ impl SourcePluginApi for WasmtimeSourcePlugin {
         let opened = source
             .catalog()
             .call_open_stream_json(&mut self.component.store, catalog, track_json)?
-            .map_err(|error| {
-                crate::op_error!("source.catalog.open-stream-json plugin error: {error:?}")
-            })?;
+            .map_err(|error| catalog_error("source.catalog.open-stream-json", error))?;
         self.map_opened_stream(opened)
     }
 
@@ -299,7 +640,7 @@ impl SourcePluginApi for WasmtimeSourcePlugin {
         let opened = source
             .catalog()
             .call_open_uri(&mut self.component.store, catalog, uri)?
-            .map_err(|error| crate::op_error!("source.catalog.open-uri plugin error: {error:?}"))?;
+            .map_err(|error| catalog_error("source.catalog.open-uri", error))?;
         self.map_opened_stream(opened)
     }
 
@@ -312,7 +653,7 @@ impl SourcePluginApi for WasmtimeSourcePlugin {
         let meta = source
             .source_stream()
             .call_metadata(&mut self.component.store, stream_ref)?
-            .map_err(|error| crate::op_error!("source.stream.metadata plugin error: {error:?}"))?;
+            .map_err(|error| catalog_error("source.stream.metadata", error))?;
         Ok(Self::map_runtime_metadata(meta))
     }
 
@@ -329,7 +670,7 @@ impl SourcePluginApi for WasmtimeSourcePlugin {
         let chunk = source
             .source_stream()
             .call_read(&mut self.component.store, stream_ref, max_bytes)?
-            .map_err(|error| crate::op_error!("source.stream.read plugin error: {error:?}"))?;
+            .map_err(|error| catalog_error("source.stream.read", error))?;
         Ok(RuntimeEncodedChunk {
             bytes: chunk.bytes,
             eof: chunk.eof,
@@ -349,6 +690,7 @@ impl SourcePluginApi for WasmtimeSourcePlugin {
     }
 
     fn plan_config_update_json(&mut self, config_json: &str) -> Result<(String, Option<String>)> {
+        self.config_schema.check_json(config_json)?;
         self.reconcile_runtime()?;
         let catalog = self.ensure_catalog()?;
         let source = self.source_api();
@@ -362,6 +704,7 @@ impl SourcePluginApi for WasmtimeSourcePlugin {
     }
 
     fn apply_config_update_json(&mut self, config_json: &str) -> Result<()> {
+        self.config_schema.check_json(config_json)?;
         self.reconcile_runtime()?;
         let catalog = self.ensure_catalog()?;
         let source = self.source_api();
@@ -371,6 +714,7 @@ impl SourcePluginApi for WasmtimeSourcePlugin {
             .map_err(|error| {
                 crate::op_error!("source.catalog.apply-config-update-json plugin error: {error:?}")
             })?;
+        self.config_json = Some(config_json.to_string());
         Ok(())
     }
 
@@ -438,8 +782,7 @@ impl WasmtimePluginController {
         self.ensure_plugin_active(plugin_id)?;
 
         let component_path = plugin.root_dir.join(&capability.component_rel_path);
-        let component = self
-            .load_component_cached(&component_path)
+        self.load_component_cached(&component_path)
             .map_err(|error| {
                 crate::op_error!(
                     "failed to load component for plugin `{}` component `{}`: {error:#}",
@@ -451,7 +794,7 @@ impl WasmtimePluginController {
         let (tx, rx) = mpsc::channel::<RuntimePluginDirective>();
         let component = match classify_world(&capability.world) {
             WorldKind::Source => {
-                self.instantiate_source_component(plugin_id, &plugin.root_dir, &component, rx)?
+                self.instantiate_source_component(plugin_id, &plugin.root_dir, &component_path, rx)?
             },
             _ => {
                 return Err(crate::op_error!(
@@ -462,12 +805,16 @@ impl WasmtimePluginController {
         };
         self.register_directive_sender(plugin_id, tx)?;
 
+        let config_schema = instance_config_schema(plugin_id, &capability);
+        let config_json = self.persisted_config(plugin_id, &capability.type_id, &config_schema);
         Ok(WasmtimeSourcePlugin {
             plugin_id: plugin_id.to_string(),
             component,
+            config_schema,
             catalog: None,
             next_stream_handle: 1,
             streams: BTreeMap::new(),
+            config_json,
         })
     }
 
@@ -1,15 +1,17 @@
 use std::collections::BTreeMap;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
+use std::sync::mpsc::Receiver;
 use std::time::{Duration, Instant};
 
 use parking_lot::{Condvar, Mutex};
 use tracing::{debug, info};
 
 use crate::error::Result;
+use crate::executor::sidecar_supervisor::{SidecarEventHub, SupervisedSidecar};
 use crate::host::sidecar::{
     SidecarChannelHandle, SidecarHost, SidecarLaunchScope, SidecarLaunchSpec, SidecarProcessHandle,
-    SidecarTransportKind, SidecarTransportOption,
+    SidecarProcessStatus, SidecarStateEvent, SidecarTransportKind, SidecarTransportOption,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -43,6 +45,7 @@ struct NamedLock {
 
 struct PackageSidecarRegistryInner {
     host: Arc<dyn SidecarHost>,
+    events: Arc<SidecarEventHub>,
     plugin_refs: Mutex<BTreeMap<String, usize>>,
     processes: Mutex<BTreeMap<SidecarProcessKey, SharedProcessEntry>>,
     locks: Mutex<BTreeMap<SidecarLockKey, Arc<NamedLock>>>,
@@ -58,6 +61,7 @@ impl PackageSidecarRegistry {
         Self {
             inner: Arc::new(PackageSidecarRegistryInner {
                 host,
+                events: Arc::new(SidecarEventHub::default()),
                 plugin_refs: Mutex::new(BTreeMap::new()),
                 processes: Mutex::new(BTreeMap::new()),
                 locks: Mutex::new(BTreeMap::new()),
@@ -65,6 +69,28 @@ impl PackageSidecarRegistry {
         }
     }
 
+    pub(crate) fn subscribe_events(&self) -> Receiver<SidecarStateEvent> {
+        self.inner.events.subscribe()
+    }
+
+    /// Launches through the host, wrapping the process in a supervisor when the spec asks
+    /// for one.
+    fn spawn_process(
+        &self,
+        plugin_id: &str,
+        spec: &SidecarLaunchSpec,
+    ) -> Result<Box<dyn SidecarProcessHandle>> {
+        if spec.supervision.is_none() {
+            return self.inner.host.launch(spec);
+        }
+        Ok(Box::new(SupervisedSidecar::launch(
+            Arc::clone(&self.inner.host),
+            Arc::clone(&self.inner.events),
+            plugin_id,
+            spec,
+        )?))
+    }
+
     pub(crate) fn plugin_activated(&self, plugin_id: &str) {
         let plugin_id = plugin_id.trim();
         if plugin_id.is_empty() {
@@ -176,7 +202,7 @@ impl PackageSidecarRegistry {
             executable = %spec.executable,
             "launching shared sidecar process"
         );
-        let launched = Arc::new(Mutex::new(self.inner.host.launch(spec)?));
+        let launched = Arc::new(Mutex::new(self.spawn_process(plugin_id, spec)?));
 
         let mut processes = self.inner.processes.lock();
         if let Some(entry) = processes.get_mut(&key) {
@@ -232,6 +258,12 @@ impl PackageSidecarRegistry {
         process.wait_exit(timeout_ms)
     }
 
+    fn status(&self, key: &SidecarProcessKey) -> Result<SidecarProcessStatus> {
+        let process = self.get_process(key)?;
+        let mut process = process.lock();
+        process.status()
+    }
+
     fn release_process(&self, key: &SidecarProcessKey, grace_ms: u32) -> Result<()> {
         let keep_alive = self.is_plugin_active(key.plugin_id.as_str());
         let process = {
@@ -415,7 +447,7 @@ impl SidecarState {
                     .acquire_process(self.plugin_id.as_str(), spec)?,
             ),
             SidecarLaunchScope::Instance => SidecarProcessRef::Instance(Arc::new(Mutex::new(
-                self.registry.inner.host.launch(spec)?,
+                self.registry.spawn_process(self.plugin_id.as_str(), spec)?,
             ))),
         };
         let process_rep = self.alloc_process_rep();
@@ -480,6 +512,20 @@ impl SidecarState {
         }
     }
 
+    pub(crate) fn status(&mut self, process_rep: u32) -> Result<SidecarProcessStatus> {
+        let process = self
+            .processes
+            .get(&process_rep)
+            .ok_or_else(|| crate::op_error!("sidecar process handle `{process_rep}` not found"))?;
+        match process {
+            SidecarProcessRef::Shared(key) => self.registry.status(key),
+            SidecarProcessRef::Instance(process) => {
+                let mut process = process.lock();
+                process.status()
+            },
+        }
+    }
+
     pub(crate) fn terminate(&mut self, process_rep: u32, grace_ms: u32) -> Result<()> {
         let process = self
             .processes
@@ -634,8 +680,14 @@ fn launch_signature(spec: &SidecarLaunchSpec) -> String {
         SidecarLaunchScope::Package => "package",
     };
     format!(
-        "scope={};exe={};args={:?};control={:?};data={:?};env={:?}",
-        scope, spec.executable, spec.args, spec.preferred_control, spec.preferred_data, env
+        "scope={};exe={};args={:?};control={:?};data={:?};env={:?};supervision={:?}",
+        scope,
+        spec.executable,
+        spec.args,
+        spec.preferred_control,
+        spec.preferred_data,
+        env,
+        spec.supervision
     )
 }
 
@@ -739,6 +791,7 @@ mod tests {
                 preferred_control: Vec::new(),
                 preferred_data: Vec::new(),
                 env: Vec::new(),
+                supervision: None,
             })
             .expect("launch");
         let channel_rep = state.open_control(process_rep).expect("open control");
@@ -781,6 +834,7 @@ mod tests {
                     preferred_control: Vec::new(),
                     preferred_data: Vec::new(),
                     env: Vec::new(),
+                    supervision: None,
                 })
                 .expect("launch");
             let channel_rep = state
@@ -841,6 +895,7 @@ mod tests {
             preferred_control: Vec::new(),
             preferred_data: Vec::new(),
             env: vec![("TOKEN".to_string(), "abc".to_string())],
+            supervision: None,
         };
         let first_rep = first.launch(&spec).expect("first launch");
         let second_rep = second.launch(&spec).expect("second launch");
@@ -868,6 +923,7 @@ mod tests {
             preferred_control: Vec::new(),
             preferred_data: Vec::new(),
             env: Vec::new(),
+            supervision: None,
         };
         let first_rep = first.launch(&spec).expect("first launch");
         let second_rep = second.launch(&spec).expect("second launch");
@@ -0,0 +1,789 @@
+use std::io::{BufRead, BufReader};
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use parking_lot::{Condvar, Mutex};
+use tracing::{info, warn};
+
+use crate::error::{Error, Result};
+use crate::host::sidecar::{
+    SidecarChannelHandle, SidecarHealthProbe, SidecarHost, SidecarLaunchSpec, SidecarOutputKind,
+    SidecarOutputStream, SidecarProcessHandle, SidecarProcessState, SidecarProcessStatus,
+    SidecarStateEvent, SidecarSupervision, SidecarTransportKind, SidecarTransportOption,
+};
+
+const SUPERVISOR_POLL_MS: u64 = 50;
+const PROBE_READ_CHUNK_BYTES: u32 = 4096;
+/// How long a restarted sidecar has to stay running before its restart attempts, and with
+/// them the backoff, start over.
+const RESTART_STABLE_WINDOW_MS: u64 = 60_000;
+
+/// Fans out sidecar state transitions to host subscribers.
+#[derive(Default)]
+pub(crate) struct SidecarEventHub {
+    subscribers: Mutex<Vec<Sender<SidecarStateEvent>>>,
+}
+
+impl SidecarEventHub {
+    pub(crate) fn subscribe(&self) -> Receiver<SidecarStateEvent> {
+        let (tx, rx) = mpsc::channel::<SidecarStateEvent>();
+        self.subscribers.lock().push(tx);
+        rx
+    }
+
+    fn publish(&self, event: SidecarStateEvent) {
+        self.subscribers
+            .lock()
+            .retain(|sender| sender.send(event.clone()).is_ok());
+    }
+}
+
+struct Supervisor {
+    plugin_id: String,
+    spec: SidecarLaunchSpec,
+    supervision: SidecarSupervision,
+    stable_window: Duration,
+    host: Arc<dyn SidecarHost>,
+    events: Arc<SidecarEventHub>,
+    process: Mutex<Option<Box<dyn SidecarProcessHandle>>>,
+    status: Mutex<SidecarProcessStatus>,
+    status_cv: Condvar,
+    stopping: Mutex<bool>,
+    stop_cv: Condvar,
+}
+
+/// A sidecar process watched by a monitor thread that applies the launch spec's
+/// [`SidecarSupervision`] policy. The plugin keeps one handle across restarts; channels
+/// opened before a restart are dead and have to be reopened.
+pub(crate) struct SupervisedSidecar {
+    supervisor: Arc<Supervisor>,
+    monitor: Option<JoinHandle<()>>,
+}
+
+impl SupervisedSidecar {
+    pub(crate) fn launch(
+        host: Arc<dyn SidecarHost>,
+        events: Arc<SidecarEventHub>,
+        plugin_id: &str,
+        spec: &SidecarLaunchSpec,
+    ) -> Result<Self> {
+        Self::launch_with_stable_window(
+            host,
+            events,
+            plugin_id,
+            spec,
+            Duration::from_millis(RESTART_STABLE_WINDOW_MS),
+        )
+    }
+
+    fn launch_with_stable_window(
+        host: Arc<dyn SidecarHost>,
+        events: Arc<SidecarEventHub>,
+        plugin_id: &str,
+        spec: &SidecarLaunchSpec,
+        stable_window: Duration,
+    ) -> Result<Self> {
+        let supervision = spec.supervision.clone().unwrap_or_default();
+        let mut process = host.launch(spec)?;
+        let supervisor = Arc::new(Supervisor {
+            plugin_id: plugin_id.to_string(),
+            spec: spec.clone(),
+            host,
+            events,
+            process: Mutex::new(None),
+            status: Mutex::new(SidecarProcessStatus::new(SidecarProcessState::Starting)),
+            status_cv: Condvar::new(),
+            stopping: Mutex::new(false),
+            stop_cv: Condvar::new(),
+            supervision,
+            stable_window,
+        });
+        supervisor.forward_output(process.take_output());
+        *supervisor.process.lock() = Some(process);
+        supervisor.update_status(|status| status.state = supervisor.launched_state());
+
+        let monitor = {
+            let supervisor = Arc::clone(&supervisor);
+            thread::Builder::new()
+                .name("stellatune-sidecar-supervisor".to_string())
+                .spawn(move || supervisor.run())
+                .map_err(|error| Error::operation("sidecar.supervise", error.to_string()))?
+        };
+        Ok(Self {
+            supervisor,
+            monitor: Some(monitor),
+        })
+    }
+
+    fn stop_monitor(&mut self) {
+        *self.supervisor.stopping.lock() = true;
+        self.supervisor.stop_cv.notify_all();
+        if let Some(monitor) = self.monitor.take() {
+            let _ = monitor.join();
+        }
+    }
+
+    fn with_process<T>(
+        &self,
+        f: impl FnOnce(&mut dyn SidecarProcessHandle) -> Result<T>,
+    ) -> Result<T> {
+        let mut process = self.supervisor.process.lock();
+        match process.as_mut() {
+            Some(process) => f(process.as_mut()),
+            None => Err(crate::op_error!(
+                "sidecar is {}",
+                self.supervisor.status.lock().state.as_str()
+            )),
+        }
+    }
+}
+
+impl SidecarProcessHandle for SupervisedSidecar {
+    fn open_control(&mut self) -> Result<Box<dyn SidecarChannelHandle>> {
+        self.with_process(|process| process.open_control())
+    }
+
+    fn open_data(
+        &mut self,
+        role: &str,
+        preferred: &[SidecarTransportOption],
+    ) -> Result<Box<dyn SidecarChannelHandle>> {
+        self.with_process(|process| process.open_data(role, preferred))
+    }
+
+    /// Waits until the supervisor gives up or the sidecar is terminated; restarts in
+    /// between are not exits from the plugin's point of view.
+    fn wait_exit(&mut self, timeout_ms: Option<u32>) -> Result<Option<i32>> {
+        let deadline = timeout_ms.map(|ms| Instant::now() + Duration::from_millis(ms as u64));
+        let mut status = self.supervisor.status.lock();
+        loop {
+            if status.state.is_terminal() {
+                return Ok(Some(status.last_exit_code.unwrap_or(-1)));
+            }
+            match deadline {
+                Some(deadline) => {
+                    if self
+                        .supervisor
+                        .status_cv
+                        .wait_until(&mut status, deadline)
+                        .timed_out()
+                        && !status.state.is_terminal()
+                    {
+                        return Ok(None);
+                    }
+                },
+                None => self.supervisor.status_cv.wait(&mut status),
+            }
+        }
+    }
+
+    fn terminate(&mut self, grace_ms: u32) -> Result<()> {
+        self.stop_monitor();
+        let Some(mut process) = self.supervisor.process.lock().take() else {
+            return Ok(());
+        };
+        let result = process.terminate(grace_ms);
+        let exit_code = process.wait_exit(Some(0)).ok().flatten();
+        self.supervisor.update_status(|status| {
+            status.state = SidecarProcessState::Stopped;
+            status.last_exit_code = exit_code.or(status.last_exit_code);
+            status.detail = Some("terminated".to_string());
+        });
+        result
+    }
+
+    fn status(&mut self) -> Result<SidecarProcessStatus> {
+        Ok(self.supervisor.status.lock().clone())
+    }
+}
+
+impl Drop for SupervisedSidecar {
+    fn drop(&mut self) {
+        let _ = self.terminate(0);
+    }
+}
+
+enum ProbeOutcome {
+    Healthy,
+    Failed(String),
+    Unsupported,
+}
+
+impl Supervisor {
+    fn launched_state(&self) -> SidecarProcessState {
+        if self.supervision.health_probe.is_some() {
+            SidecarProcessState::Starting
+        } else {
+            SidecarProcessState::Running
+        }
+    }
+
+    fn run(&self) {
+        let mut probe = self.supervision.health_probe.clone();
+        let mut next_probe = Instant::now();
+        let mut probe_failures = 0_u32;
+        let mut attempts = 0_u32;
+        let mut running_since = None::<Instant>;
+        loop {
+            if self.wait_stop(Duration::from_millis(SUPERVISOR_POLL_MS)) {
+                return;
+            }
+            let exited = match self.process.lock().as_mut() {
+                Some(process) => process.wait_exit(Some(0)),
+                None => return,
+            };
+            let failure = match exited {
+                Ok(Some(code)) => Some((Some(code), format!("exited with code {code}"))),
+                Ok(None) => None,
+                Err(error) => Some((None, format!("lost track of process: {error}"))),
+            };
+            let failure = failure.or_else(|| {
+                let spec = probe.clone()?;
+                if Instant::now() < next_probe {
+                    return None;
+                }
+                next_probe = Instant::now() + Duration::from_millis(spec.interval_ms.max(1) as u64);
+                match self.probe(&spec) {
+                    ProbeOutcome::Healthy => {
+                        probe_failures = 0;
+                        self.mark_healthy();
+                        None
+                    },
+                    ProbeOutcome::Unsupported => {
+                        warn!(
+                            plugin_id = %self.plugin_id,
+                            executable = %self.spec.executable,
+                            "sidecar health probe disabled: control channel is stdio"
+                        );
+                        probe = None;
+                        self.mark_healthy();
+                        None
+                    },
+                    ProbeOutcome::Failed(detail) => {
+                        probe_failures = probe_failures.saturating_add(1);
+                        if probe_failures < spec.failure_threshold.max(1) {
+                            return None;
+                        }
+                        let detail =
+                            format!("health probe failed {probe_failures} times: {detail}");
+                        self.update_status(|status| {
+                            status.state = SidecarProcessState::Unhealthy;
+                            status.detail = Some(detail.clone());
+                        });
+                        self.supervision.restart.as_ref()?;
+                        Some((None, detail))
+                    },
+                }
+            });
+            if let Some((exit_code, detail)) = failure {
+                if !self.restart(exit_code, detail, &mut attempts) {
+                    return;
+                }
+                probe = self.supervision.health_probe.clone();
+                next_probe = Instant::now();
+                probe_failures = 0;
+                running_since = None;
+            } else if attempts > 0 && self.status.lock().state == SidecarProcessState::Running {
+                let since = *running_since.get_or_insert_with(Instant::now);
+                if since.elapsed() >= self.stable_window {
+                    attempts = 0;
+                    running_since = None;
+                }
+            } else {
+                running_since = None;
+            }
+        }
+    }
+
+    /// Replaces a dead or unhealthy process. `attempts` counts restarts since the sidecar
+    /// last ran stably and drives both the backoff and `max_restarts`; `status.restarts`
+    /// keeps the lifetime total. Returns `false` once supervision is over.
+    fn restart(&self, exit_code: Option<i32>, mut detail: String, attempts: &mut u32) -> bool {
+        if let Some(mut process) = self.process.lock().take() {
+            let _ = process.terminate(0);
+        }
+        let Some(policy) = self.supervision.restart.as_ref() else {
+            self.update_status(|status| {
+                status.state = if exit_code == Some(0) {
+                    SidecarProcessState::Stopped
+                } else {
+                    SidecarProcessState::Failed
+                };
+                status.last_exit_code = exit_code.or(status.last_exit_code);
+                status.detail = Some(detail.clone());
+            });
+            return false;
+        };
+        loop {
+            let attempt = *attempts;
+            if attempt >= policy.max_restarts {
+                self.update_status(|status| {
+                    status.state = SidecarProcessState::Failed;
+                    status.last_exit_code = exit_code.or(status.last_exit_code);
+                    status.detail = Some(format!("{detail}; gave up after {attempt} restarts"));
+                });
+                return false;
+            }
+            self.update_status(|status| {
+                status.state = SidecarProcessState::Restarting;
+                status.last_exit_code = exit_code.or(status.last_exit_code);
+                status.detail = Some(detail.clone());
+            });
+            if self.wait_stop(policy.backoff(attempt)) {
+                return false;
+            }
+            *attempts = attempt.saturating_add(1);
+            match self.host.launch(&self.spec) {
+                Ok(mut process) => {
+                    self.forward_output(process.take_output());
+                    *self.process.lock() = Some(process);
+                    self.update_status(|status| {
+                        status.state = self.launched_state();
+                        status.restarts = status.restarts.saturating_add(1);
+                    });
+                    return true;
+                },
+                Err(error) => {
+                    detail = format!("relaunch failed: {error}");
+                    self.update_status(|status| {
+                        status.restarts = status.restarts.saturating_add(1);
+                    });
+                },
+            }
+        }
+    }
+
+    fn probe(&self, spec: &SidecarHealthProbe) -> ProbeOutcome {
+        let channel = match self.process.lock().as_mut() {
+            Some(process) => process.open_control(),
+            None => return ProbeOutcome::Failed("process is not running".to_string()),
+        };
+        let mut channel = match channel {
+            Ok(channel) => channel,
+            Err(error) => return ProbeOutcome::Failed(format!("open control channel: {error}")),
+        };
+        if channel.transport() == SidecarTransportKind::Stdio {
+            return ProbeOutcome::Unsupported;
+        }
+        let outcome = probe_channel(channel.as_mut(), spec);
+        channel.close();
+        match outcome {
+            Ok(()) => ProbeOutcome::Healthy,
+            Err(detail) => ProbeOutcome::Failed(detail),
+        }
+    }
+
+    fn mark_healthy(&self) {
+        let state = self.status.lock().state;
+        if matches!(
+            state,
+            SidecarProcessState::Starting | SidecarProcessState::Unhealthy
+        ) {
+            self.update_status(|status| {
+                status.state = SidecarProcessState::Running;
+                status.detail = None;
+            });
+        }
+    }
+
+    fn update_status(&self, update: impl FnOnce(&mut SidecarProcessStatus)) {
+        let status = {
+            let mut status = self.status.lock();
+            let previous = status.state;
+            update(&mut status);
+            if status.state == previous {
+                return;
+            }
+            status.clone()
+        };
+        self.status_cv.notify_all();
+        info!(
+            plugin_id = %self.plugin_id,
+            executable = %self.spec.executable,
+            state = status.state.as_str(),
+            restarts = status.restarts,
+            exit_code = ?status.last_exit_code,
+            detail = status.detail.as_deref().unwrap_or(""),
+            "sidecar state changed"
+        );
+        self.events.publish(SidecarStateEvent {
+            plugin_id: self.plugin_id.clone(),
+            executable: self.spec.executable.clone(),
+            status,
+        });
+    }
+
+    /// Sleeps for `timeout` unless the owner terminates the sidecar first.
+    fn wait_stop(&self, timeout: Duration) -> bool {
+        let mut stopping = self.stopping.lock();
+        if !*stopping {
+            let _ = self.stop_cv.wait_for(&mut stopping, timeout);
+        }
+        *stopping
+    }
+
+    fn forward_output(&self, streams: Vec<SidecarOutputStream>) {
+        for stream in streams {
+            let plugin_id = self.plugin_id.clone();
+            let kind = stream.kind;
+            let spawned = thread::Builder::new()
+                .name(format!("stellatune-sidecar-{}", kind.as_str()))
+                .spawn(move || {
+                    let reader = BufReader::new(stream.reader);
+                    for line in reader.split(b'\n') {
+                        let Ok(line) = line else {
+                            return;
+                        };
+                        let line = String::from_utf8_lossy(&line);
+                        let line = line.trim_end();
+                        match kind {
+                            SidecarOutputKind::Stdout => {
+                                info!(target: "stellatune_plugins::sidecar", plugin_id = %plugin_id, stream = kind.as_str(), "{line}");
+                            },
+                            SidecarOutputKind::Stderr => {
+                                warn!(target: "stellatune_plugins::sidecar", plugin_id = %plugin_id, stream = kind.as_str(), "{line}");
+                            },
+                        }
+                    }
+                });
+            if let Err(error) = spawned {
+                warn!(plugin_id = %self.plugin_id, %error, "failed to capture sidecar output");
+            }
+        }
+    }
+}
+
+fn probe_channel(
+    channel: &mut dyn SidecarChannelHandle,
+    spec: &SidecarHealthProbe,
+) -> std::result::Result<(), String> {
+    if !spec.request.is_empty() {
+        channel
+            .write(&spec.request)
+            .map_err(|error| format!("write probe: {error}"))?;
+    }
+    let deadline = Instant::now() + Duration::from_millis(spec.timeout_ms.max(1) as u64);
+    let mut reply = Vec::<u8>::new();
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(format!("no reply within {}ms", spec.timeout_ms));
+        }
+        let chunk = channel
+            .read(
+                PROBE_READ_CHUNK_BYTES,
+                Some(remaining.as_millis().max(1) as u32),
+            )
+            .map_err(|error| format!("read probe reply: {error}"))?;
+        if chunk.is_empty() {
+            return Err("control channel closed".to_string());
+        }
+        reply.extend_from_slice(&chunk);
+        if reply.len() >= spec.expect_prefix.len() {
+            return if reply.starts_with(&spec.expect_prefix) {
+                Ok(())
+            } else {
+                Err("unexpected probe reply".to_string())
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use parking_lot::Mutex;
+
+    use crate::error::Result;
+    use crate::executor::sidecar_supervisor::{SidecarEventHub, SupervisedSidecar};
+    use crate::host::sidecar::{
+        SidecarChannelHandle, SidecarHealthProbe, SidecarHost, SidecarLaunchScope,
+        SidecarLaunchSpec, SidecarProcessHandle, SidecarProcessState, SidecarProcessStatus,
+        SidecarRestartPolicy, SidecarSupervision, SidecarTransportKind, SidecarTransportOption,
+    };
+
+    /// Every launched process can be killed through `alive` and answers probes with
+    /// `healthy`.
+    #[derive(Default)]
+    struct ScriptedHost {
+        launches: AtomicU32,
+        alive: Mutex<Vec<Arc<AtomicBool>>>,
+        healthy: Arc<AtomicBool>,
+    }
+
+    impl ScriptedHost {
+        fn kill_current(&self) {
+            if let Some(alive) = self.alive.lock().last() {
+                alive.store(false, Ordering::SeqCst);
+            }
+        }
+    }
+
+    struct ScriptedProcess {
+        alive: Arc<AtomicBool>,
+        healthy: Arc<AtomicBool>,
+    }
+
+    struct ProbeChannel {
+        healthy: Arc<AtomicBool>,
+    }
+
+    impl SidecarChannelHandle for ProbeChannel {
+        fn transport(&self) -> SidecarTransportKind {
+            SidecarTransportKind::LoopbackTcp
+        }
+
+        fn write(&mut self, data: &[u8]) -> Result<u32> {
+            Ok(data.len() as u32)
+        }
+
+        fn read(&mut self, _max_bytes: u32, _timeout_ms: Option<u32>) -> Result<Vec<u8>> {
+            if self.healthy.load(Ordering::SeqCst) {
+                Ok(b"pong".to_vec())
+            } else {
+                Ok(b"busy".to_vec())
+            }
+        }
+    }
+
+    impl SidecarProcessHandle for ScriptedProcess {
+        fn open_control(&mut self) -> Result<Box<dyn SidecarChannelHandle>> {
+            Ok(Box::new(ProbeChannel {
+                healthy: Arc::clone(&self.healthy),
+            }))
+        }
+
+        fn open_data(
+            &mut self,
+            _role: &str,
+            _preferred: &[SidecarTransportOption],
+        ) -> Result<Box<dyn SidecarChannelHandle>> {
+            self.open_control()
+        }
+
+        fn wait_exit(&mut self, _timeout_ms: Option<u32>) -> Result<Option<i32>> {
+            Ok((!self.alive.load(Ordering::SeqCst)).then_some(3))
+        }
+
+        fn terminate(&mut self, _grace_ms: u32) -> Result<()> {
+            self.alive.store(false, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    impl SidecarHost for ScriptedHost {
+        fn launch(&self, _spec: &SidecarLaunchSpec) -> Result<Box<dyn SidecarProcessHandle>> {
+            self.launches.fetch_add(1, Ordering::SeqCst);
+            let alive = Arc::new(AtomicBool::new(true));
+            self.alive.lock().push(Arc::clone(&alive));
+            Ok(Box::new(ScriptedProcess {
+                alive,
+                healthy: Arc::clone(&self.healthy),
+            }))
+        }
+    }
+
+    fn spec(supervision: SidecarSupervision) -> SidecarLaunchSpec {
+        SidecarLaunchSpec {
+            scope: SidecarLaunchScope::Instance,
+            executable: "scripted".to_string(),
+            args: Vec::new(),
+            preferred_control: Vec::new(),
+            preferred_data: Vec::new(),
+            env: Vec::new(),
+            supervision: Some(supervision),
+        }
+    }
+
+    fn restart_policy(max_restarts: u32) -> Option<SidecarRestartPolicy> {
+        Some(SidecarRestartPolicy {
+            max_restarts,
+            initial_backoff_ms: 1,
+            max_backoff_ms: 4,
+        })
+    }
+
+    fn wait_for(
+        process: &mut SupervisedSidecar,
+        done: impl Fn(&SidecarProcessStatus) -> bool,
+    ) -> SidecarProcessStatus {
+        for _ in 0..200 {
+            let status = process.status().expect("status");
+            if done(&status) {
+                return status;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!(
+            "sidecar never reached the expected status: {:?}",
+            process.status().expect("status")
+        );
+    }
+
+    #[test]
+    fn crashed_sidecar_restarts_until_policy_gives_up() {
+        let host = Arc::new(ScriptedHost::default());
+        let events = Arc::new(SidecarEventHub::default());
+        let transitions = events.subscribe();
+        let mut process = SupervisedSidecar::launch(
+            host.clone(),
+            Arc::clone(&events),
+            "dev.test",
+            &spec(SidecarSupervision {
+                restart: restart_policy(1),
+                ..SidecarSupervision::default()
+            }),
+        )
+        .expect("launch");
+        assert_eq!(
+            process.status().expect("status").state,
+            SidecarProcessState::Running
+        );
+
+        host.kill_current();
+        let status = wait_for(&mut process, |status| {
+            status.restarts == 1 && status.state == SidecarProcessState::Running
+        });
+        assert_eq!(status.last_exit_code, Some(3));
+        assert_eq!(host.launches.load(Ordering::SeqCst), 2);
+        assert_eq!(process.wait_exit(Some(0)).expect("wait"), None);
+
+        host.kill_current();
+        wait_for(&mut process, |status| {
+            status.state == SidecarProcessState::Failed
+        });
+        assert_eq!(process.wait_exit(Some(0)).expect("wait"), Some(3));
+        assert!(process.open_control().is_err());
+
+        let states = transitions
+            .try_iter()
+            .map(|event| {
+                assert_eq!(event.plugin_id, "dev.test");
+                event.status.state
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            states,
+            vec![
+                SidecarProcessState::Running,
+                SidecarProcessState::Restarting,
+                SidecarProcessState::Running,
+                SidecarProcessState::Failed,
+            ]
+        );
+    }
+
+    #[test]
+    fn stable_run_resets_restart_attempts() {
+        let host = Arc::new(ScriptedHost::default());
+        let mut process = SupervisedSidecar::launch_with_stable_window(
+            host.clone(),
+            Arc::new(SidecarEventHub::default()),
+            "dev.test",
+            &spec(SidecarSupervision {
+                restart: restart_policy(1),
+                ..SidecarSupervision::default()
+            }),
+            Duration::from_millis(100),
+        )
+        .expect("launch");
+
+        host.kill_current();
+        wait_for(&mut process, |status| {
+            status.restarts == 1 && status.state == SidecarProcessState::Running
+        });
+        std::thread::sleep(Duration::from_millis(300));
+
+        host.kill_current();
+        let status = wait_for(&mut process, |status| {
+            status.restarts == 2 && status.state == SidecarProcessState::Running
+        });
+        assert_eq!(status.last_exit_code, Some(3));
+        assert_eq!(host.launches.load(Ordering::SeqCst), 3);
+
+        host.kill_current();
+        wait_for(&mut process, |status| {
+            status.state == SidecarProcessState::Failed
+        });
+        assert_eq!(process.status().expect("status").restarts, 2);
+    }
+
+    #[test]
+    fn failing_health_probe_marks_unhealthy_and_restarts() {
+        let host = Arc::new(ScriptedHost::default());
+        host.healthy.store(true, Ordering::SeqCst);
+        let events = Arc::new(SidecarEventHub::default());
+        let transitions = events.subscribe();
+        let mut process = SupervisedSidecar::launch(
+            host.clone(),
+            events,
+            "dev.test",
+            &spec(SidecarSupervision {
+                restart: restart_policy(8),
+                health_probe: Some(SidecarHealthProbe {
+                    request: b"ping".to_vec(),
+                    expect_prefix: b"pong".to_vec(),
+                    interval_ms: 5,
+                    timeout_ms: 50,
+                    failure_threshold: 2,
+                }),
+                capture_output: false,
+            }),
+        )
+        .expect("launch");
+        wait_for(&mut process, |status| {
+            status.state == SidecarProcessState::Running
+        });
+
+        host.healthy.store(false, Ordering::SeqCst);
+        let mut seen = Vec::new();
+        while !seen.contains(&SidecarProcessState::Restarting) {
+            let event = transitions
+                .recv_timeout(Duration::from_secs(2))
+                .expect("sidecar transition");
+            seen.push(event.status.state);
+        }
+        assert!(seen.contains(&SidecarProcessState::Unhealthy));
+        host.healthy.store(true, Ordering::SeqCst);
+        let status = wait_for(&mut process, |status| {
+            status.restarts >= 1 && status.state == SidecarProcessState::Running
+        });
+        assert!(status.last_exit_code.is_none());
+        assert!(host.launches.load(Ordering::SeqCst) >= 2);
+
+        process.terminate(0).expect("terminate");
+        assert_eq!(
+            process.status().expect("status").state,
+            SidecarProcessState::Stopped
+        );
+        assert!(
+            host.alive
+                .lock()
+                .iter()
+                .all(|alive| !alive.load(Ordering::SeqCst))
+        );
+    }
+
+    #[test]
+    fn exit_without_restart_policy_fails() {
+        let host = Arc::new(ScriptedHost::default());
+        let mut process = SupervisedSidecar::launch(
+            host.clone(),
+            Arc::new(SidecarEventHub::default()),
+            "dev.test",
+            &spec(SidecarSupervision::default()),
+        )
+        .expect("launch");
+        host.kill_current();
+        let status = wait_for(&mut process, |status| {
+            status.state == SidecarProcessState::Failed
+        });
+        assert_eq!(status.restarts, 0);
+        assert_eq!(host.launches.load(Ordering::SeqCst), 1);
+    }
+}
@@ -7,13 +7,20 @@ use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxView, WasiView};
 
 use stellatune_host_bindings::generated::decoder_plugin::stellatune::plugin::common as decoder_common;
 use stellatune_host_bindings::generated::decoder_plugin::stellatune::plugin::host_stream as decoder_host_stream;
+use stellatune_host_bindings::generated::decoder_plugin::stellatune::plugin::http_client as decoder_http_client;
 use stellatune_host_bindings::generated::decoder_plugin::stellatune::plugin::sidecar as decoder_sidecar;
+use stellatune_host_bindings::generated::decoder_plugin::stellatune::plugin::storage as decoder_storage;
 
+use crate::error::Error;
+use crate::executor::http_state::HttpState;
 use crate::executor::sidecar_state::SidecarState;
+use crate::host::http::{HttpHeader, HttpMethod, HttpRequest};
 use crate::host::sidecar::{
-    SidecarLaunchScope, SidecarLaunchSpec, SidecarTransportKind, SidecarTransportOption,
-    resolve_sidecar_executable,
+    SidecarHealthProbe, SidecarLaunchScope, SidecarLaunchSpec, SidecarProcessState,
+    SidecarProcessStatus, SidecarRestartPolicy, SidecarSupervision, SidecarTransportKind,
+    SidecarTransportOption, resolve_sidecar_executable,
 };
+use crate::host::storage::PluginStorage;
 use crate::host::stream::{
     HostStreamHandle, HostStreamOpenRequest, HostStreamService, StreamHeader, StreamHttpMethod,
     StreamOpenKind, StreamSeekWhence,
@@ -23,7 +30,9 @@ pub(crate) struct DecoderStoreData {
     pub(crate) stream_service: Arc<dyn HostStreamService>,
     pub(crate) next_rep: u32,
     pub(crate) streams: BTreeMap<u32, Box<dyn HostStreamHandle>>,
+    pub(crate) http: HttpState,
     pub(crate) sidecar: SidecarState,
+    pub(crate) storage: PluginStorage,
     pub(crate) plugin_root: PathBuf,
     pub(crate) wasi_ctx: WasiCtx,
     pub(crate) wasi_table: ResourceTable,
@@ -42,6 +51,58 @@ impl DecoderStoreData {
 
 impl decoder_common::Host for DecoderStoreData {}
 
+fn decoder_storage_error(error: Error) -> decoder_storage::PluginError {
+    match error {
+        Error::InvalidInput { message } => decoder_storage::PluginError::InvalidArg(message),
+        Error::Denied { message } => decoder_storage::PluginError::Denied(message),
+        Error::IoAt { .. } | Error::Io(_) => decoder_storage::PluginError::Io(error.to_string()),
+        other => decoder_storage::PluginError::Internal(other.to_string()),
+    }
+}
+
+impl decoder_storage::Host for DecoderStoreData {
+    fn get(
+        &mut self,
+        key: String,
+    ) -> std::result::Result<Option<Vec<u8>>, decoder_storage::PluginError> {
+        self.storage.get(&key).map_err(decoder_storage_error)
+    }
+
+    fn set(
+        &mut self,
+        key: String,
+        value: Vec<u8>,
+    ) -> std::result::Result<(), decoder_storage::PluginError> {
+        self.storage
+            .set(&key, &value)
+            .map_err(decoder_storage_error)
+    }
+
+    fn delete(&mut self, key: String) -> std::result::Result<bool, decoder_storage::PluginError> {
+        self.storage.delete(&key).map_err(decoder_storage_error)
+    }
+
+    fn list_prefix(
+        &mut self,
+        prefix: String,
+    ) -> std::result::Result<Vec<String>, decoder_storage::PluginError> {
+        self.storage
+            .list_prefix(&prefix)
+            .map_err(decoder_storage_error)
+    }
+
+    fn usage(
+        &mut self,
+    ) -> std::result::Result<decoder_storage::StorageUsage, decoder_storage::PluginError> {
+        let usage = self.storage.usage().map_err(decoder_storage_error)?;
+        Ok(decoder_storage::StorageUsage {
+            used_bytes: usage.used_bytes,
+            quota_bytes: usage.quota_bytes,
+            key_count: usage.key_count,
+        })
+    }
+}
+
 impl WasiView for DecoderStoreData {
     fn ctx(&mut self) -> WasiCtxView<'_> {
         WasiCtxView {
@@ -183,6 +244,91 @@ impl decoder_host_stream::Host for DecoderStoreData {
     }
 }
 
+fn decoder_http_error(error: Error) -> decoder_http_client::PluginError {
+    match error {
+        Error::InvalidInput { message } => decoder_http_client::PluginError::InvalidArg(message),
+        Error::Denied { message } => decoder_http_client::PluginError::Denied(message),
+        _ => decoder_http_client::PluginError::Internal(error.to_string()),
+    }
+}
+
+fn decoder_http_request_from(request: decoder_http_client::Request) -> HttpRequest {
+    HttpRequest {
+        method: match request.method {
+            decoder_http_client::Method::Get => HttpMethod::Get,
+            decoder_http_client::Method::Post => HttpMethod::Post,
+            decoder_http_client::Method::Put => HttpMethod::Put,
+            decoder_http_client::Method::Patch => HttpMethod::Patch,
+            decoder_http_client::Method::Delete => HttpMethod::Delete,
+            decoder_http_client::Method::Head => HttpMethod::Head,
+            decoder_http_client::Method::Options => HttpMethod::Options,
+        },
+        url: request.url,
+        headers: request
+            .headers
+            .into_iter()
+            .map(|header| HttpHeader {
+                name: header.name,
+                value: header.value,
+            })
+            .collect(),
+        body: request.body,
+        timeout_ms: request.timeout_ms,
+    }
+}
+
+impl decoder_http_client::Host for DecoderStoreData {
+    fn fetch_json(
+        &mut self,
+        url: String,
+    ) -> std::result::Result<String, decoder_http_client::PluginError> {
+        self.http.fetch_json(&url).map_err(decoder_http_error)
+    }
+
+    fn send(
+        &mut self,
+        request: decoder_http_client::Request,
+    ) -> std::result::Result<decoder_http_client::Response, decoder_http_client::PluginError> {
+        let head = self
+            .http
+            .send(&decoder_http_request_from(request))
+            .map_err(decoder_http_error)?;
+        Ok(decoder_http_client::Response {
+            status: head.status,
+            headers: head
+                .headers
+                .into_iter()
+                .map(|header| decoder_http_client::Header {
+                    name: header.name,
+                    value: header.value,
+                })
+                .collect(),
+            body: Resource::new_own(head.body_rep),
+        })
+    }
+}
+
+impl decoder_http_client::HostResponseBody for DecoderStoreData {
+    fn read(
+        &mut self,
+        self_: Resource<decoder_http_client::ResponseBody>,
+        max_bytes: u32,
+    ) -> std::result::Result<Vec<u8>, decoder_http_client::PluginError> {
+        self.http
+            .read_body(self_.rep(), max_bytes)
+            .map_err(decoder_http_error)
+    }
+
+    fn close(&mut self, self_: Resource<decoder_http_client::ResponseBody>) {
+        self.http.close_body(self_.rep());
+    }
+
+    fn drop(&mut self, rep: Resource<decoder_http_client::ResponseBody>) -> wasmtime::Result<()> {
+        self.http.drop_body(rep.rep());
+        Ok(())
+    }
+}
+
 fn decoder_plugin_error_internal(error: impl std::fmt::Display) -> decoder_sidecar::PluginError {
     decoder_sidecar::PluginError::Internal(error.to_string())
 }
@@ -226,6 +372,40 @@ fn decoder_launch_scope_from(scope: decoder_sidecar::LaunchScope) -> SidecarLaun
     }
 }
 
+fn decoder_supervision_from(supervision: decoder_sidecar::Supervision) -> SidecarSupervision {
+    SidecarSupervision {
+        restart: supervision.restart.map(|policy| SidecarRestartPolicy {
+            max_restarts: policy.max_restarts,
+            initial_backoff_ms: policy.initial_backoff_ms,
+            max_backoff_ms: policy.max_backoff_ms,
+        }),
+        health_probe: supervision.health_probe.map(|probe| SidecarHealthProbe {
+            request: probe.request,
+            expect_prefix: probe.expect_prefix,
+            interval_ms: probe.interval_ms,
+            timeout_ms: probe.timeout_ms,
+            failure_threshold: probe.failure_threshold,
+        }),
+        capture_output: supervision.capture_output,
+    }
+}
+
+fn decoder_process_status_into(status: SidecarProcessStatus) -> decoder_sidecar::ProcessStatus {
+    decoder_sidecar::ProcessStatus {
+        state: match status.state {
+            SidecarProcessState::Starting => decoder_sidecar::ProcessState::Starting,
+            SidecarProcessState::Running => decoder_sidecar::ProcessState::Running,
+            SidecarProcessState::Unhealthy => decoder_sidecar::ProcessState::Unhealthy,
+            SidecarProcessState::Restarting => decoder_sidecar::ProcessState::Restarting,
+            SidecarProcessState::Failed => decoder_sidecar::ProcessState::Failed,
+            SidecarProcessState::Stopped => decoder_sidecar::ProcessState::Stopped,
+        },
+        restarts: status.restarts,
+        last_exit_code: status.last_exit_code,
+        detail: status.detail,
+    }
+}
+
 impl decoder_sidecar::Host for DecoderStoreData {
     fn lock(
         &mut self,
@@ -262,6 +442,7 @@ impl decoder_sidecar::Host for DecoderStoreData {
                     .map(decoder_transport_option_from)
                     .collect::<Vec<_>>(),
                 env: spec.env,
+                supervision: spec.supervision.map(decoder_supervision_from),
             })
             .map_err(decoder_plugin_error_internal)?;
         Ok(Resource::new_own(process_rep))
@@ -321,6 +502,17 @@ impl decoder_sidecar::HostProcess for DecoderStoreData {
             .map_err(decoder_plugin_error_internal)
     }
 
+    fn status(
+        &mut self,
+        self_: Resource<decoder_sidecar::Process>,
+    ) -> std::result::Result<decoder_sidecar::ProcessStatus, decoder_sidecar::PluginError> {
+        let process_rep = self_.rep();
+        self.sidecar
+            .status(process_rep)
+            .map(decoder_process_status_into)
+            .map_err(decoder_plugin_error_internal)
+    }
+
     fn drop(&mut self, rep: Resource<decoder_sidecar::Process>) -> wasmtime::Result<()> {
         self.sidecar.drop_process(rep.rep());
         Ok(())
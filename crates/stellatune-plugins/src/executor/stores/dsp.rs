@@ -5,22 +5,79 @@ use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxView, WasiView};
 
 use stellatune_host_bindings::generated::dsp_plugin::stellatune::plugin::common as dsp_common;
 use stellatune_host_bindings::generated::dsp_plugin::stellatune::plugin::hot_path as dsp_hot_path;
+use stellatune_host_bindings::generated::dsp_plugin::stellatune::plugin::http_client as dsp_http_client;
 use stellatune_host_bindings::generated::dsp_plugin::stellatune::plugin::sidecar as dsp_sidecar;
+use stellatune_host_bindings::generated::dsp_plugin::stellatune::plugin::storage as dsp_storage;
 
+use crate::error::Error;
+use crate::executor::http_state::HttpState;
 use crate::executor::sidecar_state::SidecarState;
+use crate::host::http::{HttpHeader, HttpMethod, HttpRequest};
 use crate::host::sidecar::{
-    SidecarLaunchScope, SidecarLaunchSpec, SidecarTransportKind, SidecarTransportOption,
-    resolve_sidecar_executable,
+    SidecarHealthProbe, SidecarLaunchScope, SidecarLaunchSpec, SidecarProcessState,
+    SidecarProcessStatus, SidecarRestartPolicy, SidecarSupervision, SidecarTransportKind,
+    SidecarTransportOption, resolve_sidecar_executable,
 };
+use crate::host::storage::PluginStorage;
 
 pub(crate) struct DspStoreData {
+    pub(crate) http: HttpState,
     pub(crate) sidecar: SidecarState,
+    pub(crate) storage: PluginStorage,
     pub(crate) plugin_root: PathBuf,
     pub(crate) wasi_ctx: WasiCtx,
     pub(crate) wasi_table: ResourceTable,
 }
 
 impl dsp_common::Host for DspStoreData {}
+
+fn dsp_storage_error(error: Error) -> dsp_storage::PluginError {
+    match error {
+        Error::InvalidInput { message } => dsp_storage::PluginError::InvalidArg(message),
+        Error::Denied { message } => dsp_storage::PluginError::Denied(message),
+        Error::IoAt { .. } | Error::Io(_) => dsp_storage::PluginError::Io(error.to_string()),
+        other => dsp_storage::PluginError::Internal(other.to_string()),
+    }
+}
+
+impl dsp_storage::Host for DspStoreData {
+    fn get(
+        &mut self,
+        key: String,
+    ) -> std::result::Result<Option<Vec<u8>>, dsp_storage::PluginError> {
+        self.storage.get(&key).map_err(dsp_storage_error)
+    }
+
+    fn set(
+        &mut self,
+        key: String,
+        value: Vec<u8>,
+    ) -> std::result::Result<(), dsp_storage::PluginError> {
+        self.storage.set(&key, &value).map_err(dsp_storage_error)
+    }
+
+    fn delete(&mut self, key: String) -> std::result::Result<bool, dsp_storage::PluginError> {
+        self.storage.delete(&key).map_err(dsp_storage_error)
+    }
+
+    fn list_prefix(
+        &mut self,
+        prefix: String,
+    ) -> std::result::Result<Vec<String>, dsp_storage::PluginError> {
+        self.storage.list_prefix(&prefix).map_err(dsp_storage_error)
+    }
+
+    fn usage(
+        &mut self,
+    ) -> std::result::Result<dsp_storage::StorageUsage, dsp_storage::PluginError> {
+        let usage = self.storage.usage().map_err(dsp_storage_error)?;
+        Ok(dsp_storage::StorageUsage {
+            used_bytes: usage.used_bytes,
+            quota_bytes: usage.quota_bytes,
+            key_count: usage.key_count,
+        })
+    }
+}
 impl dsp_hot_path::Host for DspStoreData {}
 
 impl WasiView for DspStoreData {
@@ -32,6 +89,91 @@ impl WasiView for DspStoreData {
     }
 }
 
+fn dsp_http_error(error: Error) -> dsp_http_client::PluginError {
+    match error {
+        Error::InvalidInput { message } => dsp_http_client::PluginError::InvalidArg(message),
+        Error::Denied { message } => dsp_http_client::PluginError::Denied(message),
+        _ => dsp_http_client::PluginError::Internal(error.to_string()),
+    }
+}
+
+fn dsp_http_request_from(request: dsp_http_client::Request) -> HttpRequest {
+    HttpRequest {
+        method: match request.method {
+            dsp_http_client::Method::Get => HttpMethod::Get,
+            dsp_http_client::Method::Post => HttpMethod::Post,
+            dsp_http_client::Method::Put => HttpMethod::Put,
+            dsp_http_client::Method::Patch => HttpMethod::Patch,
+            dsp_http_client::Method::Delete => HttpMethod::Delete,
+            dsp_http_client::Method::Head => HttpMethod::Head,
+            dsp_http_client::Method::Options => HttpMethod::Options,
+        },
+        url: request.url,
+        headers: request
+            .headers
+            .into_iter()
+            .map(|header| HttpHeader {
+                name: header.name,
+                value: header.value,
+            })
+            .collect(),
+        body: request.body,
+        timeout_ms: request.timeout_ms,
+    }
+}
+
+impl dsp_http_client::Host for DspStoreData {
+    fn fetch_json(
+        &mut self,
+        url: String,
+    ) -> std::result::Result<String, dsp_http_client::PluginError> {
+        self.http.fetch_json(&url).map_err(dsp_http_error)
+    }
+
+    fn send(
+        &mut self,
+        request: dsp_http_client::Request,
+    ) -> std::result::Result<dsp_http_client::Response, dsp_http_client::PluginError> {
+        let head = self
+            .http
+            .send(&dsp_http_request_from(request))
+            .map_err(dsp_http_error)?;
+        Ok(dsp_http_client::Response {
+            status: head.status,
+            headers: head
+                .headers
+                .into_iter()
+                .map(|header| dsp_http_client::Header {
+                    name: header.name,
+                    value: header.value,
+                })
+                .collect(),
+            body: Resource::new_own(head.body_rep),
+        })
+    }
+}
+
+impl dsp_http_client::HostResponseBody for DspStoreData {
+    fn read(
+        &mut self,
+        self_: Resource<dsp_http_client::ResponseBody>,
+        max_bytes: u32,
+    ) -> std::result::Result<Vec<u8>, dsp_http_client::PluginError> {
+        self.http
+            .read_body(self_.rep(), max_bytes)
+            .map_err(dsp_http_error)
+    }
+
+    fn close(&mut self, self_: Resource<dsp_http_client::ResponseBody>) {
+        self.http.close_body(self_.rep());
+    }
+
+    fn drop(&mut self, rep: Resource<dsp_http_client::ResponseBody>) -> wasmtime::Result<()> {
+        self.http.drop_body(rep.rep());
+        Ok(())
+    }
+}
+
 fn dsp_plugin_error_internal(error: impl std::fmt::Display) -> dsp_sidecar::PluginError {
     dsp_sidecar::PluginError::Internal(error.to_string())
 }
@@ -67,6 +209,40 @@ fn dsp_launch_scope_from(scope: dsp_sidecar::LaunchScope) -> SidecarLaunchScope
     }
 }
 
+fn dsp_supervision_from(supervision: dsp_sidecar::Supervision) -> SidecarSupervision {
+    SidecarSupervision {
+        restart: supervision.restart.map(|policy| SidecarRestartPolicy {
+            max_restarts: policy.max_restarts,
+            initial_backoff_ms: policy.initial_backoff_ms,
+            max_backoff_ms: policy.max_backoff_ms,
+        }),
+        health_probe: supervision.health_probe.map(|probe| SidecarHealthProbe {
+            request: probe.request,
+            expect_prefix: probe.expect_prefix,
+            interval_ms: probe.interval_ms,
+            timeout_ms: probe.timeout_ms,
+            failure_threshold: probe.failure_threshold,
+        }),
+        capture_output: supervision.capture_output,
+    }
+}
+
+fn dsp_process_status_into(status: SidecarProcessStatus) -> dsp_sidecar::ProcessStatus {
+    dsp_sidecar::ProcessStatus {
+        state: match status.state {
+            SidecarProcessState::Starting => dsp_sidecar::ProcessState::Starting,
+            SidecarProcessState::Running => dsp_sidecar::ProcessState::Running,
+            SidecarProcessState::Unhealthy => dsp_sidecar::ProcessState::Unhealthy,
+            SidecarProcessState::Restarting => dsp_sidecar::ProcessState::Restarting,
+            SidecarProcessState::Failed => dsp_sidecar::ProcessState::Failed,
+            SidecarProcessState::Stopped => dsp_sidecar::ProcessState::Stopped,
+        },
+        restarts: status.restarts,
+        last_exit_code: status.last_exit_code,
+        detail: status.detail,
+    }
+}
+
 impl dsp_sidecar::Host for DspStoreData {
     fn lock(
         &mut self,
@@ -102,6 +278,7 @@ impl dsp_sidecar::Host for DspStoreData {
                     .map(dsp_transport_option_from)
                     .collect::<Vec<_>>(),
                 env: spec.env,
+                supervision: spec.supervision.map(dsp_supervision_from),
             })
             .map_err(dsp_plugin_error_internal)?;
         Ok(Resource::new_own(process_rep))
@@ -161,6 +338,17 @@ impl dsp_sidecar::HostProcess for DspStoreData {
             .map_err(dsp_plugin_error_internal)
     }
 
+    fn status(
+        &mut self,
+        self_: Resource<dsp_sidecar::Process>,
+    ) -> std::result::Result<dsp_sidecar::ProcessStatus, dsp_sidecar::PluginError> {
+        let process_rep = self_.rep();
+        self.sidecar
+            .status(process_rep)
+            .map(dsp_process_status_into)
+            .map_err(dsp_plugin_error_internal)
+    }
+
     fn drop(&mut self, rep: Resource<dsp_sidecar::Process>) -> wasmtime::Result<()> {
         self.sidecar.drop_process(rep.rep());
         Ok(())
@@ -1,5 +1,4 @@
 use std::path::PathBuf;
-use std::sync::Arc;
 
 use wasmtime::component::Resource;
 use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxView, WasiView};
@@ -7,17 +6,23 @@ use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxView, WasiView};
 use stellatune_host_bindings::generated::lyrics_plugin::stellatune::plugin::common as lyrics_common;
 use stellatune_host_bindings::generated::lyrics_plugin::stellatune::plugin::http_client as lyrics_http_client;
 use stellatune_host_bindings::generated::lyrics_plugin::stellatune::plugin::sidecar as lyrics_sidecar;
+use stellatune_host_bindings::generated::lyrics_plugin::stellatune::plugin::storage as lyrics_storage;
 
+use crate::error::Error;
+use crate::executor::http_state::HttpState;
 use crate::executor::sidecar_state::SidecarState;
-use crate::host::http::HttpClientHost;
+use crate::host::http::{HttpHeader, HttpMethod, HttpRequest};
 use crate::host::sidecar::{
-    SidecarLaunchScope, SidecarLaunchSpec, SidecarTransportKind, SidecarTransportOption,
-    resolve_sidecar_executable,
+    SidecarHealthProbe, SidecarLaunchScope, SidecarLaunchSpec, SidecarProcessState,
+    SidecarProcessStatus, SidecarRestartPolicy, SidecarSupervision, SidecarTransportKind,
+    SidecarTransportOption, resolve_sidecar_executable,
 };
+use crate::host::storage::PluginStorage;
 
 pub(crate) struct LyricsStoreData {
-    pub(crate) http_client: Arc<dyn HttpClientHost>,
+    pub(crate) http: HttpState,
     pub(crate) sidecar: SidecarState,
+    pub(crate) storage: PluginStorage,
     pub(crate) plugin_root: PathBuf,
     pub(crate) wasi_ctx: WasiCtx,
     pub(crate) wasi_table: ResourceTable,
@@ -25,6 +30,56 @@ pub(crate) struct LyricsStoreData {
 
 impl lyrics_common::Host for LyricsStoreData {}
 
+fn lyrics_storage_error(error: Error) -> lyrics_storage::PluginError {
+    match error {
+        Error::InvalidInput { message } => lyrics_storage::PluginError::InvalidArg(message),
+        Error::Denied { message } => lyrics_storage::PluginError::Denied(message),
+        Error::IoAt { .. } | Error::Io(_) => lyrics_storage::PluginError::Io(error.to_string()),
+        other => lyrics_storage::PluginError::Internal(other.to_string()),
+    }
+}
+
+impl lyrics_storage::Host for LyricsStoreData {
+    fn get(
+        &mut self,
+        key: String,
+    ) -> std::result::Result<Option<Vec<u8>>, lyrics_storage::PluginError> {
+        self.storage.get(&key).map_err(lyrics_storage_error)
+    }
+
+    fn set(
+        &mut self,
+        key: String,
+        value: Vec<u8>,
+    ) -> std::result::Result<(), lyrics_storage::PluginError> {
+        self.storage.set(&key, &value).map_err(lyrics_storage_error)
+    }
+
+    fn delete(&mut self, key: String) -> std::result::Result<bool, lyrics_storage::PluginError> {
+        self.storage.delete(&key).map_err(lyrics_storage_error)
+    }
+
+    fn list_prefix(
+        &mut self,
+        prefix: String,
+    ) -> std::result::Result<Vec<String>, lyrics_storage::PluginError> {
+        self.storage
+            .list_prefix(&prefix)
+            .map_err(lyrics_storage_error)
+    }
+
+    fn usage(
+        &mut self,
+    ) -> std::result::Result<lyrics_storage::StorageUsage, lyrics_storage::PluginError> {
+        let usage = self.storage.usage().map_err(lyrics_storage_error)?;
+        Ok(lyrics_storage::StorageUsage {
+            used_bytes: usage.used_bytes,
+            quota_bytes: usage.quota_bytes,
+            key_count: usage.key_count,
+        })
+    }
+}
+
 impl WasiView for LyricsStoreData {
     fn ctx(&mut self) -> WasiCtxView<'_> {
         WasiCtxView {
@@ -34,14 +89,88 @@ impl WasiView for LyricsStoreData {
     }
 }
 
+fn lyrics_http_error(error: Error) -> lyrics_http_client::PluginError {
+    match error {
+        Error::InvalidInput { message } => lyrics_http_client::PluginError::InvalidArg(message),
+        Error::Denied { message } => lyrics_http_client::PluginError::Denied(message),
+        _ => lyrics_http_client::PluginError::Internal(error.to_string()),
+    }
+}
+
+fn lyrics_http_request_from(request: lyrics_http_client::Request) -> HttpRequest {
+    HttpRequest {
+        method: match request.method {
+            lyrics_http_client::Method::Get => HttpMethod::Get,
+            lyrics_http_client::Method::Post => HttpMethod::Post,
+            lyrics_http_client::Method::Put => HttpMethod::Put,
+            lyrics_http_client::Method::Patch => HttpMethod::Patch,
+            lyrics_http_client::Method::Delete => HttpMethod::Delete,
+            lyrics_http_client::Method::Head => HttpMethod::Head,
+            lyrics_http_client::Method::Options => HttpMethod::Options,
+        },
+        url: request.url,
+        headers: request
+            .headers
+            .into_iter()
+            .map(|header| HttpHeader {
+                name: header.name,
+                value: header.value,
+            })
+            .collect(),
+        body: request.body,
+        timeout_ms: request.timeout_ms,
+    }
+}
+
 impl lyrics_http_client::Host for LyricsStoreData {
     fn fetch_json(
         &mut self,
         url: String,
     ) -> std::result::Result<String, lyrics_http_client::PluginError> {
-        self.http_client
-            .fetch_json(&url)
-            .map_err(|error| lyrics_http_client::PluginError::Internal(error.to_string()))
+        self.http.fetch_json(&url).map_err(lyrics_http_error)
+    }
+
+    fn send(
+        &mut self,
+        request: lyrics_http_client::Request,
+    ) -> std::result::Result<lyrics_http_client::Response, lyrics_http_client::PluginError> {
+        let head = self
+            .http
+            .send(&lyrics_http_request_from(request))
+            .map_err(lyrics_http_error)?;
+        Ok(lyrics_http_client::Response {
+            status: head.status,
+            headers: head
+                .headers
+                .into_iter()
+                .map(|header| lyrics_http_client::Header {
+                    name: header.name,
+                    value: header.value,
+                })
+                .collect(),
+            body: Resource::new_own(head.body_rep),
+        })
+    }
+}
+
+impl lyrics_http_client::HostResponseBody for LyricsStoreData {
+    fn read(
+        &mut self,
+        self_: Resource<lyrics_http_client::ResponseBody>,
+        max_bytes: u32,
+    ) -> std::result::Result<Vec<u8>, lyrics_http_client::PluginError> {
+        self.http
+            .read_body(self_.rep(), max_bytes)
+            .map_err(lyrics_http_error)
+    }
+
+    fn close(&mut self, self_: Resource<lyrics_http_client::ResponseBody>) {
+        self.http.close_body(self_.rep());
+    }
+
+    fn drop(&mut self, rep: Resource<lyrics_http_client::ResponseBody>) -> wasmtime::Result<()> {
+        self.http.drop_body(rep.rep());
+        Ok(())
     }
 }
 
@@ -82,6 +211,40 @@ fn lyrics_launch_scope_from(scope: lyrics_sidecar::LaunchScope) -> SidecarLaunch
     }
 }
 
+fn lyrics_supervision_from(supervision: lyrics_sidecar::Supervision) -> SidecarSupervision {
+    SidecarSupervision {
+        restart: supervision.restart.map(|policy| SidecarRestartPolicy {
+            max_restarts: policy.max_restarts,
+            initial_backoff_ms: policy.initial_backoff_ms,
+            max_backoff_ms: policy.max_backoff_ms,
+        }),
+        health_probe: supervision.health_probe.map(|probe| SidecarHealthProbe {
+            request: probe.request,
+            expect_prefix: probe.expect_prefix,
+            interval_ms: probe.interval_ms,
+            timeout_ms: probe.timeout_ms,
+            failure_threshold: probe.failure_threshold,
+        }),
+        capture_output: supervision.capture_output,
+    }
+}
+
+fn lyrics_process_status_into(status: SidecarProcessStatus) -> lyrics_sidecar::ProcessStatus {
+    lyrics_sidecar::ProcessStatus {
+        state: match status.state {
+            SidecarProcessState::Starting => lyrics_sidecar::ProcessState::Starting,
+            SidecarProcessState::Running => lyrics_sidecar::ProcessState::Running,
+            SidecarProcessState::Unhealthy => lyrics_sidecar::ProcessState::Unhealthy,
+            SidecarProcessState::Restarting => lyrics_sidecar::ProcessState::Restarting,
+            SidecarProcessState::Failed => lyrics_sidecar::ProcessState::Failed,
+            SidecarProcessState::Stopped => lyrics_sidecar::ProcessState::Stopped,
+        },
+        restarts: status.restarts,
+        last_exit_code: status.last_exit_code,
+        detail: status.detail,
+    }
+}
+
 impl lyrics_sidecar::Host for LyricsStoreData {
     fn lock(
         &mut self,
@@ -117,6 +280,7 @@ impl lyrics_sidecar::Host for LyricsStoreData {
                     .map(lyrics_transport_option_from)
                     .collect::<Vec<_>>(),
                 env: spec.env,
+                supervision: spec.supervision.map(lyrics_supervision_from),
             })
             .map_err(lyrics_plugin_error_internal)?;
         Ok(Resource::new_own(process_rep))
@@ -176,6 +340,17 @@ impl lyrics_sidecar::HostProcess for LyricsStoreData {
             .map_err(lyrics_plugin_error_internal)
     }
 
+    fn status(
+        &mut self,
+        self_: Resource<lyrics_sidecar::Process>,
+    ) -> std::result::Result<lyrics_sidecar::ProcessStatus, lyrics_sidecar::PluginError> {
+        let process_rep = self_.rep();
+        self.sidecar
+            .status(process_rep)
+            .map(lyrics_process_status_into)
+            .map_err(lyrics_plugin_error_internal)
+    }
+
     fn drop(&mut self, rep: Resource<lyrics_sidecar::Process>) -> wasmtime::Result<()> {
         self.sidecar.drop_process(rep.rep());
         Ok(())
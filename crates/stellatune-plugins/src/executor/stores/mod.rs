@@ -2,4 +2,5 @@ pub(crate) mod decoder;
 pub(crate) mod dsp;
 pub(crate) mod lyrics;
 pub(crate) mod output_sink;
+pub(crate) mod playback_listener;
 pub(crate) mod source;
@@ -5,22 +5,89 @@ use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxView, WasiView};
 
 use stellatune_host_bindings::generated::output_sink_plugin::stellatune::plugin::common as output_sink_common;
 use stellatune_host_bindings::generated::output_sink_plugin::stellatune::plugin::hot_path as output_sink_hot_path;
+use stellatune_host_bindings::generated::output_sink_plugin::stellatune::plugin::http_client as output_sink_http_client;
 use stellatune_host_bindings::generated::output_sink_plugin::stellatune::plugin::sidecar as output_sink_sidecar;
+use stellatune_host_bindings::generated::output_sink_plugin::stellatune::plugin::storage as output_sink_storage;
 
+use crate::error::Error;
+use crate::executor::http_state::HttpState;
 use crate::executor::sidecar_state::SidecarState;
+use crate::host::http::{HttpHeader, HttpMethod, HttpRequest};
 use crate::host::sidecar::{
-    SidecarLaunchScope, SidecarLaunchSpec, SidecarTransportKind, SidecarTransportOption,
-    resolve_sidecar_executable,
+    SidecarHealthProbe, SidecarLaunchScope, SidecarLaunchSpec, SidecarProcessState,
+    SidecarProcessStatus, SidecarRestartPolicy, SidecarSupervision, SidecarTransportKind,
+    SidecarTransportOption, resolve_sidecar_executable,
 };
+use crate::host::storage::PluginStorage;
 
 pub(crate) struct OutputSinkStoreData {
+    pub(crate) http: HttpState,
     pub(crate) sidecar: SidecarState,
+    pub(crate) storage: PluginStorage,
     pub(crate) plugin_root: PathBuf,
     pub(crate) wasi_ctx: WasiCtx,
     pub(crate) wasi_table: ResourceTable,
 }
 
 impl output_sink_common::Host for OutputSinkStoreData {}
+
+fn output_sink_storage_error(error: Error) -> output_sink_storage::PluginError {
+    match error {
+        Error::InvalidInput { message } => output_sink_storage::PluginError::InvalidArg(message),
+        Error::Denied { message } => output_sink_storage::PluginError::Denied(message),
+        Error::IoAt { .. } | Error::Io(_) => {
+            output_sink_storage::PluginError::Io(error.to_string())
+        },
+        other => output_sink_storage::PluginError::Internal(other.to_string()),
+    }
+}
+
+impl output_sink_storage::Host for OutputSinkStoreData {
+    fn get(
+        &mut self,
+        key: String,
+    ) -> std::result::Result<Option<Vec<u8>>, output_sink_storage::PluginError> {
+        self.storage.get(&key).map_err(output_sink_storage_error)
+    }
+
+    fn set(
+        &mut self,
+        key: String,
+        value: Vec<u8>,
+    ) -> std::result::Result<(), output_sink_storage::PluginError> {
+        self.storage
+            .set(&key, &value)
+            .map_err(output_sink_storage_error)
+    }
+
+    fn delete(
+        &mut self,
+        key: String,
+    ) -> std::result::Result<bool, output_sink_storage::PluginError> {
+        self.storage.delete(&key).map_err(output_sink_storage_error)
+    }
+
+    fn list_prefix(
+        &mut self,
+        prefix: String,
+    ) -> std::result::Result<Vec<String>, output_sink_storage::PluginError> {
+        self.storage
+            .list_prefix(&prefix)
+            .map_err(output_sink_storage_error)
+    }
+
+    fn usage(
+        &mut self,
+    ) -> std::result::Result<output_sink_storage::StorageUsage, output_sink_storage::PluginError>
+    {
+        let usage = self.storage.usage().map_err(output_sink_storage_error)?;
+        Ok(output_sink_storage::StorageUsage {
+            used_bytes: usage.used_bytes,
+            quota_bytes: usage.quota_bytes,
+            key_count: usage.key_count,
+        })
+    }
+}
 impl output_sink_hot_path::Host for OutputSinkStoreData {}
 
 impl WasiView for OutputSinkStoreData {
@@ -32,6 +99,97 @@ impl WasiView for OutputSinkStoreData {
     }
 }
 
+fn output_sink_http_error(error: Error) -> output_sink_http_client::PluginError {
+    match error {
+        Error::InvalidInput { message } => {
+            output_sink_http_client::PluginError::InvalidArg(message)
+        },
+        Error::Denied { message } => output_sink_http_client::PluginError::Denied(message),
+        _ => output_sink_http_client::PluginError::Internal(error.to_string()),
+    }
+}
+
+fn output_sink_http_request_from(request: output_sink_http_client::Request) -> HttpRequest {
+    HttpRequest {
+        method: match request.method {
+            output_sink_http_client::Method::Get => HttpMethod::Get,
+            output_sink_http_client::Method::Post => HttpMethod::Post,
+            output_sink_http_client::Method::Put => HttpMethod::Put,
+            output_sink_http_client::Method::Patch => HttpMethod::Patch,
+            output_sink_http_client::Method::Delete => HttpMethod::Delete,
+            output_sink_http_client::Method::Head => HttpMethod::Head,
+            output_sink_http_client::Method::Options => HttpMethod::Options,
+        },
+        url: request.url,
+        headers: request
+            .headers
+            .into_iter()
+            .map(|header| HttpHeader {
+                name: header.name,
+                value: header.value,
+            })
+            .collect(),
+        body: request.body,
+        timeout_ms: request.timeout_ms,
+    }
+}
+
+impl output_sink_http_client::Host for OutputSinkStoreData {
+    fn fetch_json(
+        &mut self,
+        url: String,
+    ) -> std::result::Result<String, output_sink_http_client::PluginError> {
+        self.http.fetch_json(&url).map_err(output_sink_http_error)
+    }
+
+    fn send(
+        &mut self,
+        request: output_sink_http_client::Request,
+    ) -> std::result::Result<output_sink_http_client::Response, output_sink_http_client::PluginError>
+    {
+        let head = self
+            .http
+            .send(&output_sink_http_request_from(request))
+            .map_err(output_sink_http_error)?;
+        Ok(output_sink_http_client::Response {
+            status: head.status,
+            headers: head
+                .headers
+                .into_iter()
+                .map(|header| output_sink_http_client::Header {
+                    name: header.name,
+                    value: header.value,
+                })
+                .collect(),
+            body: Resource::new_own(head.body_rep),
+        })
+    }
+}
+
+impl output_sink_http_client::HostResponseBody for OutputSinkStoreData {
+    fn read(
+        &mut self,
+        self_: Resource<output_sink_http_client::ResponseBody>,
+        max_bytes: u32,
+    ) -> std::result::Result<Vec<u8>, output_sink_http_client::PluginError> {
+        self.http
+            .read_body(self_.rep(), max_bytes)
+            .map_err(output_sink_http_error)
+    }
+
+    fn close(&mut self, self_: Resource<output_sink_http_client::ResponseBody>) {
+        self.http.close_body(self_.rep());
+    }
+
+    fn drop(
+        &mut self,
+        rep: Resource<output_sink_http_client::ResponseBody>,
+    ) -> wasmtime::Result<()> {
+        self.http.drop_body(rep.rep());
+        Ok(())
+    }
+}
+
 fn output_sink_plugin_error_internal(
     error: impl std::fmt::Display,
 ) -> output_sink_sidecar::PluginError {
@@ -77,6 +235,44 @@ fn output_sink_launch_scope_from(scope: output_sink_sidecar::LaunchScope) -> Sid
     }
 }
 
+fn output_sink_supervision_from(
+    supervision: output_sink_sidecar::Supervision,
+) -> SidecarSupervision {
+    SidecarSupervision {
+        restart: supervision.restart.map(|policy| SidecarRestartPolicy {
+            max_restarts: policy.max_restarts,
+            initial_backoff_ms: policy.initial_backoff_ms,
+            max_backoff_ms: policy.max_backoff_ms,
+        }),
+        health_probe: supervision.health_probe.map(|probe| SidecarHealthProbe {
+            request: probe.request,
+            expect_prefix: probe.expect_prefix,
+            interval_ms: probe.interval_ms,
+            timeout_ms: probe.timeout_ms,
+            failure_threshold: probe.failure_threshold,
+        }),
+        capture_output: supervision.capture_output,
+    }
+}
+
+fn output_sink_process_status_into(
+    status: SidecarProcessStatus,
+) -> output_sink_sidecar::ProcessStatus {
+    output_sink_sidecar::ProcessStatus {
+        state: match status.state {
+            SidecarProcessState::Starting => output_sink_sidecar::ProcessState::Starting,
+            SidecarProcessState::Running => output_sink_sidecar::ProcessState::Running,
+            SidecarProcessState::Unhealthy => output_sink_sidecar::ProcessState::Unhealthy,
+            SidecarProcessState::Restarting => output_sink_sidecar::ProcessState::Restarting,
+            SidecarProcessState::Failed => output_sink_sidecar::ProcessState::Failed,
+            SidecarProcessState::Stopped => output_sink_sidecar::ProcessState::Stopped,
+        },
+        restarts: status.restarts,
+        last_exit_code: status.last_exit_code,
+        detail: status.detail,
+    }
+}
+
 impl output_sink_sidecar::Host for OutputSinkStoreData {
     fn lock(
         &mut self,
@@ -116,6 +312,7 @@ impl output_sink_sidecar::Host for OutputSinkStoreData {
                     .map(output_sink_transport_option_from)
                     .collect::<Vec<_>>(),
                 env: spec.env,
+                supervision: spec.supervision.map(output_sink_supervision_from),
             })
             .map_err(output_sink_plugin_error_internal)?;
         Ok(Resource::new_own(process_rep))
@@ -177,6 +374,18 @@ impl output_sink_sidecar::HostProcess for OutputSinkStoreData {
             .map_err(output_sink_plugin_error_internal)
     }
 
+    fn status(
+        &mut self,
+        self_: Resource<output_sink_sidecar::Process>,
+    ) -> std::result::Result<output_sink_sidecar::ProcessStatus, output_sink_sidecar::PluginError>
+    {
+        let process_rep = self_.rep();
+        self.sidecar
+            .status(process_rep)
+            .map(output_sink_process_status_into)
+            .map_err(output_sink_plugin_error_internal)
+    }
+
     fn drop(&mut self, rep: Resource<output_sink_sidecar::Process>) -> wasmtime::Result<()> {
         self.sidecar.drop_process(rep.rep());
         Ok(())
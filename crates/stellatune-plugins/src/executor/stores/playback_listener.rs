@@ -0,0 +1,481 @@
+use std::path::PathBuf;
+
+use wasmtime::component::Resource;
+use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxView, WasiView};
+
+use stellatune_host_bindings::generated::playback_listener_plugin::stellatune::plugin::common as playback_listener_common;
+use stellatune_host_bindings::generated::playback_listener_plugin::stellatune::plugin::http_client as playback_listener_http_client;
+use stellatune_host_bindings::generated::playback_listener_plugin::stellatune::plugin::sidecar as playback_listener_sidecar;
+use stellatune_host_bindings::generated::playback_listener_plugin::stellatune::plugin::storage as playback_listener_storage;
+
+use crate::error::Error;
+use crate::executor::http_state::HttpState;
+use crate::executor::sidecar_state::SidecarState;
+use crate::host::http::{HttpHeader, HttpMethod, HttpRequest};
+use crate::host::sidecar::{
+    SidecarHealthProbe, SidecarLaunchScope, SidecarLaunchSpec, SidecarProcessState,
+    SidecarProcessStatus, SidecarRestartPolicy, SidecarSupervision, SidecarTransportKind,
+    SidecarTransportOption, resolve_sidecar_executable,
+};
+use crate::host::storage::PluginStorage;
+
+pub(crate) struct PlaybackListenerStoreData {
+    pub(crate) http: HttpState,
+    pub(crate) sidecar: SidecarState,
+    pub(crate) storage: PluginStorage,
+    pub(crate) plugin_root: PathBuf,
+    pub(crate) wasi_ctx: WasiCtx,
+    pub(crate) wasi_table: ResourceTable,
+}
+
+impl playback_listener_common::Host for PlaybackListenerStoreData {}
+
+fn playback_listener_storage_error(error: Error) -> playback_listener_storage::PluginError {
+    match error {
+        Error::InvalidInput { message } => {
+            playback_listener_storage::PluginError::InvalidArg(message)
+        },
+        Error::Denied { message } => playback_listener_storage::PluginError::Denied(message),
+        Error::IoAt { .. } | Error::Io(_) => {
+            playback_listener_storage::PluginError::Io(error.to_string())
+        },
+        other => playback_listener_storage::PluginError::Internal(other.to_string()),
+    }
+}
+
+impl playback_listener_storage::Host for PlaybackListenerStoreData {
+    fn get(
+        &mut self,
+        key: String,
+    ) -> std::result::Result<Option<Vec<u8>>, playback_listener_storage::PluginError> {
+        self.storage
+            .get(&key)
+            .map_err(playback_listener_storage_error)
+    }
+
+    fn set(
+        &mut self,
+        key: String,
+        value: Vec<u8>,
+    ) -> std::result::Result<(), playback_listener_storage::PluginError> {
+        self.storage
+            .set(&key, &value)
+            .map_err(playback_listener_storage_error)
+    }
+
+    fn delete(
+        &mut self,
+        key: String,
+    ) -> std::result::Result<bool, playback_listener_storage::PluginError> {
+        self.storage
+            .delete(&key)
+            .map_err(playback_listener_storage_error)
+    }
+
+    fn list_prefix(
+        &mut self,
+        prefix: String,
+    ) -> std::result::Result<Vec<String>, playback_listener_storage::PluginError> {
+        self.storage
+            .list_prefix(&prefix)
+            .map_err(playback_listener_storage_error)
+    }
+
+    fn usage(
+        &mut self,
+    ) -> std::result::Result<
+        playback_listener_storage::StorageUsage,
+        playback_listener_storage::PluginError,
+    > {
+        let usage = self
+            .storage
+            .usage()
+            .map_err(playback_listener_storage_error)?;
+        Ok(playback_listener_storage::StorageUsage {
+            used_bytes: usage.used_bytes,
+            quota_bytes: usage.quota_bytes,
+            key_count: usage.key_count,
+        })
+    }
+}
+
+impl WasiView for PlaybackListenerStoreData {
+    fn ctx(&mut self) -> WasiCtxView<'_> {
+        WasiCtxView {
+            ctx: &mut self.wasi_ctx,
+            table: &mut self.wasi_table,
+        }
+    }
+}
+
+fn playback_listener_http_error(error: Error) -> playback_listener_http_client::PluginError {
+    match error {
+        Error::InvalidInput { message } => {
+            playback_listener_http_client::PluginError::InvalidArg(message)
+        },
+        Error::Denied { message } => playback_listener_http_client::PluginError::Denied(message),
+        _ => playback_listener_http_client::PluginError::Internal(error.to_string()),
+    }
+}
+
+fn playback_listener_http_request_from(
+    request: playback_listener_http_client::Request,
+) -> HttpRequest {
+    HttpRequest {
+        method: match request.method {
+            playback_listener_http_client::Method::Get => HttpMethod::Get,
+            playback_listener_http_client::Method::Post => HttpMethod::Post,
+            playback_listener_http_client::Method::Put => HttpMethod::Put,
+            playback_listener_http_client::Method::Patch => HttpMethod::Patch,
+            playback_listener_http_client::Method::Delete => HttpMethod::Delete,
+            playback_listener_http_client::Method::Head => HttpMethod::Head,
+            playback_listener_http_client::Method::Options => HttpMethod::Options,
+        },
+        url: request.url,
+        headers: request
+            .headers
+            .into_iter()
+            .map(|header| HttpHeader {
+                name: header.name,
+                value: header.value,
+            })
+            .collect(),
+        body: request.body,
+        timeout_ms: request.timeout_ms,
+    }
+}
+
+impl playback_listener_http_client::Host for PlaybackListenerStoreData {
+    fn fetch_json(
+        &mut self,
+        url: String,
+    ) -> std::result::Result<String, playback_listener_http_client::PluginError> {
+        self.http
+            .fetch_json(&url)
+            .map_err(playback_listener_http_error)
+    }
+
+    fn send(
+        &mut self,
+        request: playback_listener_http_client::Request,
+    ) -> std::result::Result<
+        playback_listener_http_client::Response,
+        playback_listener_http_client::PluginError,
+    > {
+        let head = self
+            .http
+            .send(&playback_listener_http_request_from(request))
+            .map_err(playback_listener_http_error)?;
+        Ok(playback_listener_http_client::Response {
+            status: head.status,
+            headers: head
+                .headers
+                .into_iter()
+                .map(|header| playback_listener_http_client::Header {
+                    name: header.name,
+                    value: header.value,
+                })
+                .collect(),
+            body: Resource::new_own(head.body_rep),
+        })
+    }
+}
+
+impl playback_listener_http_client::HostResponseBody for PlaybackListenerStoreData {
+    fn read(
+        &mut self,
+        self_: Resource<playback_listener_http_client::ResponseBody>,
+        max_bytes: u32,
+    ) -> std::result::Result<Vec<u8>, playback_listener_http_client::PluginError> {
+        self.http
+            .read_body(self_.rep(), max_bytes)
+            .map_err(playback_listener_http_error)
+    }
+
+    fn close(&mut self, self_: Resource<playback_listener_http_client::ResponseBody>) {
+        self.http.close_body(self_.rep());
+    }
+
+    fn drop(
+        &mut self,
+        rep: Resource<playback_listener_http_client::ResponseBody>,
+    ) -> wasmtime::Result<()> {
+        self.http.drop_body(rep.rep());
+        Ok(())
+    }
+}
+
+fn playback_listener_plugin_error_internal(
+    error: impl std::fmt::Display,
+) -> playback_listener_sidecar::PluginError {
+    playback_listener_sidecar::PluginError::Internal(error.to_string())
+}
+
+fn playback_listener_transport_option_from(
+    option: playback_listener_sidecar::TransportOption,
+) -> SidecarTransportOption {
+    SidecarTransportOption {
+        kind: match option.kind {
+            playback_listener_sidecar::TransportKind::Stdio => SidecarTransportKind::Stdio,
+            playback_listener_sidecar::TransportKind::NamedPipe => SidecarTransportKind::NamedPipe,
+            playback_listener_sidecar::TransportKind::UnixSocket => {
+                SidecarTransportKind::UnixSocket
+            },
+            playback_listener_sidecar::TransportKind::LoopbackTcp => {
+                SidecarTransportKind::LoopbackTcp
+            },
+            playback_listener_sidecar::TransportKind::SharedMemoryRing => {
+                SidecarTransportKind::SharedMemoryRing
+            },
+        },
+        priority: option.priority,
+        max_frame_bytes: option.max_frame_bytes,
+    }
+}
+
+fn playback_listener_transport_kind_into(
+    kind: SidecarTransportKind,
+) -> playback_listener_sidecar::TransportKind {
+    match kind {
+        SidecarTransportKind::Stdio => playback_listener_sidecar::TransportKind::Stdio,
+        SidecarTransportKind::NamedPipe => playback_listener_sidecar::TransportKind::NamedPipe,
+        SidecarTransportKind::UnixSocket => playback_listener_sidecar::TransportKind::UnixSocket,
+        SidecarTransportKind::LoopbackTcp => playback_listener_sidecar::TransportKind::LoopbackTcp,
+        SidecarTransportKind::SharedMemoryRing => {
+            playback_listener_sidecar::TransportKind::SharedMemoryRing
+        },
+    }
+}
+
+fn playback_listener_launch_scope_from(
+    scope: playback_listener_sidecar::LaunchScope,
+) -> SidecarLaunchScope {
+    match scope {
+        playback_listener_sidecar::LaunchScope::Instance => SidecarLaunchScope::Instance,
+        playback_listener_sidecar::LaunchScope::PackageShared => SidecarLaunchScope::Package,
+    }
+}
+
+fn playback_listener_supervision_from(
+    supervision: playback_listener_sidecar::Supervision,
+) -> SidecarSupervision {
+    SidecarSupervision {
+        restart: supervision.restart.map(|policy| SidecarRestartPolicy {
+            max_restarts: policy.max_restarts,
+            initial_backoff_ms: policy.initial_backoff_ms,
+            max_backoff_ms: policy.max_backoff_ms,
+        }),
+        health_probe: supervision.health_probe.map(|probe| SidecarHealthProbe {
+            request: probe.request,
+            expect_prefix: probe.expect_prefix,
+            interval_ms: probe.interval_ms,
+            timeout_ms: probe.timeout_ms,
+            failure_threshold: probe.failure_threshold,
+        }),
+        capture_output: supervision.capture_output,
+    }
+}
+
+fn playback_listener_process_status_into(
+    status: SidecarProcessStatus,
+) -> playback_listener_sidecar::ProcessStatus {
+    playback_listener_sidecar::ProcessStatus {
+        state: match status.state {
+            SidecarProcessState::Starting => playback_listener_sidecar::ProcessState::Starting,
+            SidecarProcessState::Running => playback_listener_sidecar::ProcessState::Running,
+            SidecarProcessState::Unhealthy => playback_listener_sidecar::ProcessState::Unhealthy,
+            SidecarProcessState::Restarting => playback_listener_sidecar::ProcessState::Restarting,
+            SidecarProcessState::Failed => playback_listener_sidecar::ProcessState::Failed,
+            SidecarProcessState::Stopped => playback_listener_sidecar::ProcessState::Stopped,
+        },
+        restarts: status.restarts,
+        last_exit_code: status.last_exit_code,
+        detail: status.detail,
+    }
+}
+
+impl playback_listener_sidecar::Host for PlaybackListenerStoreData {
+    fn lock(
+        &mut self,
+        name: String,
+        timeout_ms: Option<u32>,
+    ) -> std::result::Result<
+        Resource<playback_listener_sidecar::LockGuard>,
+        playback_listener_sidecar::PluginError,
+    > {
+        let lock_rep = self
+            .sidecar
+            .lock(name.trim(), timeout_ms)
+            .map_err(playback_listener_plugin_error_internal)?;
+        Ok(Resource::new_own(lock_rep))
+    }
+
+    fn launch(
+        &mut self,
+        spec: playback_listener_sidecar::LaunchSpec,
+    ) -> std::result::Result<
+        Resource<playback_listener_sidecar::Process>,
+        playback_listener_sidecar::PluginError,
+    > {
+        let process_rep = self
+            .sidecar
+            .launch(&SidecarLaunchSpec {
+                scope: playback_listener_launch_scope_from(spec.scope),
+                executable: resolve_sidecar_executable(&self.plugin_root, &spec.executable)
+                    .map_err(playback_listener_plugin_error_internal)?,
+                args: spec.args,
+                preferred_control: spec
+                    .preferred_control
+                    .into_iter()
+                    .map(playback_listener_transport_option_from)
+                    .collect::<Vec<_>>(),
+                preferred_data: spec
+                    .preferred_data
+                    .into_iter()
+                    .map(playback_listener_transport_option_from)
+                    .collect::<Vec<_>>(),
+                env: spec.env,
+                supervision: spec.supervision.map(playback_listener_supervision_from),
+            })
+            .map_err(playback_listener_plugin_error_internal)?;
+        Ok(Resource::new_own(process_rep))
+    }
+}
+
+impl playback_listener_sidecar::HostProcess for PlaybackListenerStoreData {
+    fn open_control(
+        &mut self,
+        self_: Resource<playback_listener_sidecar::Process>,
+    ) -> std::result::Result<
+        Resource<playback_listener_sidecar::Channel>,
+        playback_listener_sidecar::PluginError,
+    > {
+        let process_rep = self_.rep();
+        let channel_rep = self
+            .sidecar
+            .open_control(process_rep)
+            .map_err(playback_listener_plugin_error_internal)?;
+        Ok(Resource::new_own(channel_rep))
+    }
+
+    fn open_data(
+        &mut self,
+        self_: Resource<playback_listener_sidecar::Process>,
+        role: String,
+        preferred: Vec<playback_listener_sidecar::TransportOption>,
+    ) -> std::result::Result<
+        Resource<playback_listener_sidecar::Channel>,
+        playback_listener_sidecar::PluginError,
+    > {
+        let process_rep = self_.rep();
+        let preferred = preferred
+            .into_iter()
+            .map(playback_listener_transport_option_from)
+            .collect::<Vec<_>>();
+        let channel_rep = self
+            .sidecar
+            .open_data(process_rep, role.trim(), &preferred)
+            .map_err(playback_listener_plugin_error_internal)?;
+        Ok(Resource::new_own(channel_rep))
+    }
+
+    fn wait_exit(
+        &mut self,
+        self_: Resource<playback_listener_sidecar::Process>,
+        timeout_ms: Option<u32>,
+    ) -> std::result::Result<Option<i32>, playback_listener_sidecar::PluginError> {
+        let process_rep = self_.rep();
+        self.sidecar
+            .wait_exit(process_rep, timeout_ms)
+            .map_err(playback_listener_plugin_error_internal)
+    }
+
+    fn terminate(
+        &mut self,
+        self_: Resource<playback_listener_sidecar::Process>,
+        grace_ms: u32,
+    ) -> std::result::Result<(), playback_listener_sidecar::PluginError> {
+        let process_rep = self_.rep();
+        self.sidecar
+            .terminate(process_rep, grace_ms)
+            .map_err(playback_listener_plugin_error_internal)
+    }
+
+    fn status(
+        &mut self,
+        self_: Resource<playback_listener_sidecar::Process>,
+    ) -> std::result::Result<
+        playback_listener_sidecar::ProcessStatus,
+        playback_listener_sidecar::PluginError,
+    > {
+        let process_rep = self_.rep();
+        self.sidecar
+            .status(process_rep)
+            .map(playback_listener_process_status_into)
+            .map_err(playback_listener_plugin_error_internal)
+    }
+
+    fn drop(&mut self, rep: Resource<playback_listener_sidecar::Process>) -> wasmtime::Result<()> {
+        self.sidecar.drop_process(rep.rep());
+        Ok(())
+    }
+}
+
+impl playback_listener_sidecar::HostChannel for PlaybackListenerStoreData {
+    fn transport(
+        &mut self,
+        self_: Resource<playback_listener_sidecar::Channel>,
+    ) -> playback_listener_sidecar::TransportKind {
+        let channel_rep = self_.rep();
+        self.sidecar
+            .channel_transport(channel_rep)
+            .map(playback_listener_transport_kind_into)
+            .unwrap_or(playback_listener_sidecar::TransportKind::Stdio)
+    }
+
+    fn write(
+        &mut self,
+        self_: Resource<playback_listener_sidecar::Channel>,
+        data: Vec<u8>,
+    ) -> std::result::Result<u32, playback_listener_sidecar::PluginError> {
+        let channel_rep = self_.rep();
+        self.sidecar
+            .channel_write(channel_rep, &data)
+            .map_err(playback_listener_plugin_error_internal)
+    }
+
+    fn read(
+        &mut self,
+        self_: Resource<playback_listener_sidecar::Channel>,
+        max_bytes: u32,
+        timeout_ms: Option<u32>,
+    ) -> std::result::Result<Vec<u8>, playback_listener_sidecar::PluginError> {
+        let channel_rep = self_.rep();
+        self.sidecar
+            .channel_read(channel_rep, max_bytes, timeout_ms)
+            .map_err(playback_listener_plugin_error_internal)
+    }
+
+    fn close(&mut self, self_: Resource<playback_listener_sidecar::Channel>) {
+        let _ = self.sidecar.channel_close(self_.rep());
+    }
+
+    fn drop(&mut self, rep: Resource<playback_listener_sidecar::Channel>) -> wasmtime::Result<()> {
+        self.sidecar.drop_channel(rep.rep());
+        Ok(())
+    }
+}
+
+impl playback_listener_sidecar::HostLockGuard for PlaybackListenerStoreData {
+    fn unlock(&mut self, self_: Resource<playback_listener_sidecar::LockGuard>) {
+        let _ = self.sidecar.unlock(self_.rep());
+    }
+
+    fn drop(
+        &mut self,
+        rep: Resource<playback_listener_sidecar::LockGuard>,
+    ) -> wasmtime::Result<()> {
+        self.sidecar.drop_lock(rep.rep());
+        Ok(())
+    }
+}
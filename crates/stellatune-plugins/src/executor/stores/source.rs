@@ -7,13 +7,20 @@ use wasmtime_wasi::{ResourceTable, WasiCtx, WasiCtxView, WasiView};
 
 use stellatune_host_bindings::generated::source_plugin::stellatune::plugin::common as source_common;
 use stellatune_host_bindings::generated::source_plugin::stellatune::plugin::host_stream as source_host_stream;
+use stellatune_host_bindings::generated::source_plugin::stellatune::plugin::http_client as source_http_client;
 use stellatune_host_bindings::generated::source_plugin::stellatune::plugin::sidecar as source_sidecar;
+use stellatune_host_bindings::generated::source_plugin::stellatune::plugin::storage as source_storage;
 
+use crate::error::Error;
+use crate::executor::http_state::HttpState;
 use crate::executor::sidecar_state::SidecarState;
+use crate::host::http::{HttpHeader, HttpMethod, HttpRequest};
 use crate::host::sidecar::{
-    SidecarLaunchScope, SidecarLaunchSpec, SidecarTransportKind, SidecarTransportOption,
-    resolve_sidecar_executable,
+    SidecarHealthProbe, SidecarLaunchScope, SidecarLaunchSpec, SidecarProcessState,
+    SidecarProcessStatus, SidecarRestartPolicy, SidecarSupervision, SidecarTransportKind,
+    SidecarTransportOption, resolve_sidecar_executable,
 };
+use crate::host::storage::PluginStorage;
 use crate::host::stream::{
     HostStreamHandle, HostStreamOpenRequest, HostStreamService, StreamHeader, StreamHttpMethod,
     StreamOpenKind, StreamSeekWhence,
@@ -23,7 +30,9 @@ pub(crate) struct SourceStoreData {
     pub(crate) stream_service: Arc<dyn HostStreamService>,
     pub(crate) next_rep: u32,
     pub(crate) streams: BTreeMap<u32, Box<dyn HostStreamHandle>>,
+    pub(crate) http: HttpState,
     pub(crate) sidecar: SidecarState,
+    pub(crate) storage: PluginStorage,
     pub(crate) plugin_root: PathBuf,
     pub(crate) wasi_ctx: WasiCtx,
     pub(crate) wasi_table: ResourceTable,
@@ -42,6 +51,56 @@ impl SourceStoreData {
 
 impl source_common::Host for SourceStoreData {}
 
+fn source_storage_error(error: Error) -> source_storage::PluginError {
+    match error {
+        Error::InvalidInput { message } => source_storage::PluginError::InvalidArg(message),
+        Error::Denied { message } => source_storage::PluginError::Denied(message),
+        Error::IoAt { .. } | Error::Io(_) => source_storage::PluginError::Io(error.to_string()),
+        other => source_storage::PluginError::Internal(other.to_string()),
+    }
+}
+
+impl source_storage::Host for SourceStoreData {
+    fn get(
+        &mut self,
+        key: String,
+    ) -> std::result::Result<Option<Vec<u8>>, source_storage::PluginError> {
+        self.storage.get(&key).map_err(source_storage_error)
+    }
+
+    fn set(
+        &mut self,
+        key: String,
+        value: Vec<u8>,
+    ) -> std::result::Result<(), source_storage::PluginError> {
+        self.storage.set(&key, &value).map_err(source_storage_error)
+    }
+
+    fn delete(&mut self, key: String) -> std::result::Result<bool, source_storage::PluginError> {
+        self.storage.delete(&key).map_err(source_storage_error)
+    }
+
+    fn list_prefix(
+        &mut self,
+        prefix: String,
+    ) -> std::result::Result<Vec<String>, source_storage::PluginError> {
+        self.storage
+            .list_prefix(&prefix)
+            .map_err(source_storage_error)
+    }
+
+    fn usage(
+        &mut self,
+    ) -> std::result::Result<source_storage::StorageUsage, source_storage::PluginError> {
+        let usage = self.storage.usage().map_err(source_storage_error)?;
+        Ok(source_storage::StorageUsage {
+            used_bytes: usage.used_bytes,
+            quota_bytes: usage.quota_bytes,
+            key_count: usage.key_count,
+        })
+    }
+}
+
 impl WasiView for SourceStoreData {
     fn ctx(&mut self) -> WasiCtxView<'_> {
         WasiCtxView {
@@ -183,6 +242,91 @@ impl source_host_stream::Host for SourceStoreData {
     }
 }
 
+fn source_http_error(error: Error) -> source_http_client::PluginError {
+    match error {
+        Error::InvalidInput { message } => source_http_client::PluginError::InvalidArg(message),
+        Error::Denied { message } => source_http_client::PluginError::Denied(message),
+        _ => source_http_client::PluginError::Internal(error.to_string()),
+    }
+}
+
+fn source_http_request_from(request: source_http_client::Request) -> HttpRequest {
+    HttpRequest {
+        method: match request.method {
+            source_http_client::Method::Get => HttpMethod::Get,
+            source_http_client::Method::Post => HttpMethod::Post,
+            source_http_client::Method::Put => HttpMethod::Put,
+            source_http_client::Method::Patch => HttpMethod::Patch,
+            source_http_client::Method::Delete => HttpMethod::Delete,
+            source_http_client::Method::Head => HttpMethod::Head,
+            source_http_client::Method::Options => HttpMethod::Options,
+        },
+        url: request.url,
+        headers: request
+            .headers
+            .into_iter()
+            .map(|header| HttpHeader {
+                name: header.name,
+                value: header.value,
+            })
+            .collect(),
+        body: request.body,
+        timeout_ms: request.timeout_ms,
+    }
+}
+
+impl source_http_client::Host for SourceStoreData {
+    fn fetch_json(
+        &mut self,
+        url: String,
+    ) -> std::result::Result<String, source_http_client::PluginError> {
+        self.http.fetch_json(&url).map_err(source_http_error)
+    }
+
+    fn send(
+        &mut self,
+        request: source_http_client::Request,
+    ) -> std::result::Result<source_http_client::Response, source_http_client::PluginError> {
+        let head = self
+            .http
+            .send(&source_http_request_from(request))
+            .map_err(source_http_error)?;
+        Ok(source_http_client::Response {
+            status: head.status,
+            headers: head
+                .headers
+                .into_iter()
+                .map(|header| source_http_client::Header {
+                    name: header.name,
+                    value: header.value,
+                })
+                .collect(),
+            body: Resource::new_own(head.body_rep),
+        })
+    }
+}
+
+impl source_http_client::HostResponseBody for SourceStoreData {
+    fn read(
+        &mut self,
+        self_: Resource<source_http_client::ResponseBody>,
+        max_bytes: u32,
+    ) -> std::result::Result<Vec<u8>, source_http_client::PluginError> {
+        self.http
+            .read_body(self_.rep(), max_bytes)
+            .map_err(source_http_error)
+    }
+
+    fn close(&mut self, self_: Resource<source_http_client::ResponseBody>) {
+        self.http.close_body(self_.rep());
+    }
+
+    fn drop(&mut self, rep: Resource<source_http_client::ResponseBody>) -> wasmtime::Result<()> {
+        self.http.drop_body(rep.rep());
+        Ok(())
+    }
+}
+
 fn source_plugin_error_internal(error: impl std::fmt::Display) -> source_sidecar::PluginError {
     source_sidecar::PluginError::Internal(error.to_string())
 }
@@ -220,6 +364,40 @@ fn source_launch_scope_from(scope: source_sidecar::LaunchScope) -> SidecarLaunch
     }
 }
 
+fn source_supervision_from(supervision: source_sidecar::Supervision) -> SidecarSupervision {
+    SidecarSupervision {
+        restart: supervision.restart.map(|policy| SidecarRestartPolicy {
+            max_restarts: policy.max_restarts,
+            initial_backoff_ms: policy.initial_backoff_ms,
+            max_backoff_ms: policy.max_backoff_ms,
+        }),
+        health_probe: supervision.health_probe.map(|probe| SidecarHealthProbe {
+            request: probe.request,
+            expect_prefix: probe.expect_prefix,
+            interval_ms: probe.interval_ms,
+            timeout_ms: probe.timeout_ms,
+            failure_threshold: probe.failure_threshold,
+        }),
+        capture_output: supervision.capture_output,
+    }
+}
+
+fn source_process_status_into(status: SidecarProcessStatus) -> source_sidecar::ProcessStatus {
+    source_sidecar::ProcessStatus {
+        state: match status.state {
+            SidecarProcessState::Starting => source_sidecar::ProcessState::Starting,
+            SidecarProcessState::Running => source_sidecar::ProcessState::Running,
+            SidecarProcessState::Unhealthy => source_sidecar::ProcessState::Unhealthy,
+            SidecarProcessState::Restarting => source_sidecar::ProcessState::Restarting,
+            SidecarProcessState::Failed => source_sidecar::ProcessState::Failed,
+            SidecarProcessState::Stopped => source_sidecar::ProcessState::Stopped,
+        },
+        restarts: status.restarts,
+        last_exit_code: status.last_exit_code,
+        detail: status.detail,
+    }
+}
+
 impl source_sidecar::Host for SourceStoreData {
     fn lock(
         &mut self,
@@ -255,6 +433,7 @@ impl source_sidecar::Host for SourceStoreData {
                     .map(source_transport_option_from)
                     .collect::<Vec<_>>(),
                 env: spec.env,
+                supervision: spec.supervision.map(source_supervision_from),
             })
             .map_err(source_plugin_error_internal)?;
         Ok(Resource::new_own(process_rep))
@@ -314,6 +493,17 @@ impl source_sidecar::HostProcess for SourceStoreData {
             .map_err(source_plugin_error_internal)
     }
 
+    fn status(
+        &mut self,
+        self_: Resource<source_sidecar::Process>,
+    ) -> std::result::Result<source_sidecar::ProcessStatus, source_sidecar::PluginError> {
+        let process_rep = self_.rep();
+        self.sidecar
+            .status(process_rep)
+            .map(source_process_status_into)
+            .map_err(source_plugin_error_internal)
+    }
+
     fn drop(&mut self, rep: Resource<source_sidecar::Process>) -> wasmtime::Result<()> {
         self.sidecar.drop_process(rep.rep());
         Ok(())
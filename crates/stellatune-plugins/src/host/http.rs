@@ -1,5 +1,499 @@
-use crate::error::Result;
+use std::io::Read;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
 
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::{StatusCode, Url, header};
+
+use crate::error::{Error, Result};
+
+/// Redirect hops followed before a request fails, matching reqwest's default policy.
+const HTTP_MAX_REDIRECTS: usize = 10;
+/// Threads running blocking reqwest calls; further requests queue behind them.
+const HTTP_WORKER_COUNT: usize = 4;
+/// Applied when a request sets no `timeout_ms`, so a server that never answers cannot hold
+/// a worker forever.
+const HTTP_DEFAULT_TIMEOUT_MS: u32 = 30_000;
+/// Upper bound on a caller's `timeout_ms`; it covers connecting through reading the body.
+const HTTP_MAX_TIMEOUT_MS: u32 = 300_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+}
+
+impl HttpMethod {
+    fn as_reqwest(self) -> reqwest::Method {
+        match self {
+            Self::Get => reqwest::Method::GET,
+            Self::Post => reqwest::Method::POST,
+            Self::Put => reqwest::Method::PUT,
+            Self::Patch => reqwest::Method::PATCH,
+            Self::Delete => reqwest::Method::DELETE,
+            Self::Head => reqwest::Method::HEAD,
+            Self::Options => reqwest::Method::OPTIONS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    pub url: String,
+    pub headers: Vec<HttpHeader>,
+    pub body: Option<Vec<u8>>,
+    /// Defaults to 30 seconds and is clamped to 5 minutes.
+    pub timeout_ms: Option<u32>,
+}
+
+impl HttpRequest {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            method: HttpMethod::Get,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+            timeout_ms: None,
+        }
+    }
+}
+
+/// Response body read in chunks; an empty read means the body is exhausted.
+pub trait HttpResponseBody: Send {
+    fn read(&mut self, max_bytes: u32) -> Result<Vec<u8>>;
+    fn close(&mut self) {}
+}
+
+/// Status and headers arrive before the body. Non-2xx statuses are returned as-is rather
+/// than mapped to errors.
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<HttpHeader>,
+    pub body: Box<dyn HttpResponseBody>,
+}
+
+/// Performs plugin HTTP requests. Callers check the request URL against `allowlist`;
+/// implementations that follow redirects must check every hop against it too.
 pub trait HttpClientHost: Send + Sync {
-    fn fetch_json(&self, url: &str) -> Result<String>;
+    fn fetch_json(&self, url: &str, allowlist: &HttpAllowlist) -> Result<String>;
+    fn send(&self, request: &HttpRequest, allowlist: &HttpAllowlist) -> Result<HttpResponse>;
+}
+
+/// [`HttpClientHost`] on one shared reqwest blocking client. Redirects are followed here
+/// rather than by reqwest so each hop goes through the plugin's [`HttpAllowlist`].
+///
+/// Blocking reqwest calls must stay off async runtime threads, so requests and body reads
+/// run on a fixed pool of worker threads instead of the caller's.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReqwestHttpClient;
+
+type HttpJob = Box<dyn FnOnce() + Send>;
+
+fn http_workers() -> &'static Mutex<Sender<HttpJob>> {
+    static WORKERS: OnceLock<Mutex<Sender<HttpJob>>> = OnceLock::new();
+    WORKERS.get_or_init(|| {
+        let (job_tx, job_rx) = mpsc::channel::<HttpJob>();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        for index in 0..HTTP_WORKER_COUNT {
+            let job_rx = Arc::clone(&job_rx);
+            let spawned = thread::Builder::new()
+                .name(format!("stellatune-http-client-{index}"))
+                .spawn(move || run_http_worker(&job_rx));
+            if let Err(error) = spawned {
+                tracing::warn!(index, "failed to spawn http client worker: {error}");
+            }
+        }
+        Mutex::new(job_tx)
+    })
+}
+
+fn run_http_worker(jobs: &Mutex<Receiver<HttpJob>>) {
+    loop {
+        let job = match jobs.lock() {
+            Ok(jobs) => jobs.recv(),
+            Err(_) => return,
+        };
+        match job {
+            Ok(job) => job(),
+            Err(_) => return,
+        }
+    }
+}
+
+/// Runs `job` on the http worker pool and waits for its result.
+fn run_on_worker<T: Send + 'static>(
+    operation: &'static str,
+    job: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    let (result_tx, result_rx) = mpsc::sync_channel(1);
+    http_workers()
+        .lock()
+        .map_err(|_| Error::operation(operation, "http worker pool poisoned"))?
+        .send(Box::new(move || {
+            let _ = result_tx.send(job());
+        }))
+        .map_err(|_| Error::operation(operation, "http worker pool stopped"))?;
+    result_rx
+        .recv()
+        .map_err(|_| Error::operation(operation, "http worker did not respond"))?
+}
+
+fn shared_client(operation: &'static str) -> Result<&'static Client> {
+    static CLIENT: OnceLock<std::result::Result<Client, String>> = OnceLock::new();
+    CLIENT
+        .get_or_init(|| {
+            Client::builder()
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .map_err(|error| error.to_string())
+        })
+        .as_ref()
+        .map_err(|message| Error::operation(operation, message.clone()))
+}
+
+impl HttpClientHost for ReqwestHttpClient {
+    fn fetch_json(&self, url: &str, allowlist: &HttpAllowlist) -> Result<String> {
+        const OPERATION: &str = "http_client.fetch_json";
+        if url.trim().is_empty() {
+            return Err(Error::invalid_input("url is empty"));
+        }
+        let request = HttpRequest::get(url);
+        let allowlist = allowlist.clone();
+        run_on_worker(OPERATION, move || {
+            send_following_redirects(OPERATION, &request, &allowlist)?
+                .error_for_status()
+                .map_err(|error| Error::operation(OPERATION, error.to_string()))?
+                .text()
+                .map_err(|error| Error::operation(OPERATION, error.to_string()))
+        })
+    }
+
+    fn send(&self, request: &HttpRequest, allowlist: &HttpAllowlist) -> Result<HttpResponse> {
+        const OPERATION: &str = "http_client.send";
+        if request.url.trim().is_empty() {
+            return Err(Error::invalid_input("url is empty"));
+        }
+        let request = request.clone();
+        let allowlist = allowlist.clone();
+        let response = run_on_worker(OPERATION, move || {
+            send_following_redirects(OPERATION, &request, &allowlist)
+        })?;
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| HttpHeader {
+                name: name.as_str().to_string(),
+                value: String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            })
+            .collect();
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            headers,
+            body: Box::new(ReqwestResponseBody {
+                response: Some(response),
+            }),
+        })
+    }
+}
+
+/// One hop of a request: what to send and where.
+struct Hop {
+    method: reqwest::Method,
+    url: Url,
+    body: Option<Vec<u8>>,
+    /// Cleared once a redirect leaves the original host so credentials do not follow it.
+    sensitive_headers: bool,
+}
+
+fn send_following_redirects(
+    operation: &'static str,
+    request: &HttpRequest,
+    allowlist: &HttpAllowlist,
+) -> Result<Response> {
+    let client = shared_client(operation)?;
+    let url = Url::parse(request.url.trim())
+        .map_err(|error| Error::invalid_input(format!("invalid url `{}`: {error}", request.url)))?;
+    let mut hop = Hop {
+        method: request.method.as_reqwest(),
+        url,
+        body: request.body.clone(),
+        sensitive_headers: true,
+    };
+    for _ in 0..=HTTP_MAX_REDIRECTS {
+        let response = build_hop(client, request, &hop)
+            .send()
+            .map_err(|error| Error::operation(operation, error.to_string()))?;
+        let Some(next) = next_hop(&hop, response.status(), response.headers()) else {
+            return Ok(response);
+        };
+        allowlist.check(next.url.as_str())?;
+        hop = next;
+    }
+    Err(Error::operation(
+        operation,
+        format!("too many redirects (more than {HTTP_MAX_REDIRECTS})"),
+    ))
+}
+
+fn build_hop(client: &Client, request: &HttpRequest, hop: &Hop) -> RequestBuilder {
+    let mut builder = client.request(hop.method.clone(), hop.url.clone());
+    builder = builder.timeout(request_timeout(request.timeout_ms));
+    for HttpHeader { name, value } in &request.headers {
+        if !hop.sensitive_headers && is_sensitive_header(name) {
+            continue;
+        }
+        if hop.body.is_none() && is_body_header(name) {
+            continue;
+        }
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    if let Some(body) = &hop.body {
+        builder = builder.body(body.clone());
+    }
+    builder
+}
+
+fn request_timeout(timeout_ms: Option<u32>) -> Duration {
+    let timeout_ms = timeout_ms
+        .unwrap_or(HTTP_DEFAULT_TIMEOUT_MS)
+        .min(HTTP_MAX_TIMEOUT_MS);
+    Duration::from_millis(timeout_ms as u64)
+}
+
+/// The hop a redirect response points at, or `None` when `status` is not a followable
+/// redirect. 303, and 301/302 after a POST, continue as a bodiless GET like browsers do.
+fn next_hop(hop: &Hop, status: StatusCode, headers: &header::HeaderMap) -> Option<Hop> {
+    let keeps_method = match status {
+        StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND => hop.method != reqwest::Method::POST,
+        StatusCode::SEE_OTHER => hop.method == reqwest::Method::HEAD,
+        StatusCode::TEMPORARY_REDIRECT | StatusCode::PERMANENT_REDIRECT => true,
+        _ => return None,
+    };
+    let location = headers.get(header::LOCATION)?.to_str().ok()?;
+    let url = hop.url.join(location).ok()?;
+    let same_origin = url.host_str() == hop.url.host_str()
+        && url.port_or_known_default() == hop.url.port_or_known_default();
+    Some(Hop {
+        method: if keeps_method {
+            hop.method.clone()
+        } else {
+            reqwest::Method::GET
+        },
+        url,
+        body: if keeps_method { hop.body.clone() } else { None },
+        sensitive_headers: hop.sensitive_headers && same_origin,
+    })
+}
+
+fn is_sensitive_header(name: &str) -> bool {
+    ["authorization", "cookie", "proxy-authorization"]
+        .iter()
+        .any(|sensitive| name.eq_ignore_ascii_case(sensitive))
+}
+
+fn is_body_header(name: &str) -> bool {
+    ["content-type", "content-length", "content-encoding"]
+        .iter()
+        .any(|body| name.eq_ignore_ascii_case(body))
+}
+
+/// Holds the response itself and reads it on demand, so nothing downloads ahead of the
+/// plugin and closing or dropping the body drops the connection.
+struct ReqwestResponseBody {
+    response: Option<Response>,
+}
+
+impl HttpResponseBody for ReqwestResponseBody {
+    fn read(&mut self, max_bytes: u32) -> Result<Vec<u8>> {
+        const OPERATION: &str = "http_client.body.read";
+        let Some(mut response) = self.response.take() else {
+            return Ok(Vec::new());
+        };
+        let limit = max_bytes.max(1) as usize;
+        let (response, chunk) = run_on_worker(OPERATION, move || {
+            let mut chunk = vec![0_u8; limit];
+            let read = response
+                .read(&mut chunk)
+                .map_err(|error| Error::operation(OPERATION, error.to_string()))?;
+            chunk.truncate(read);
+            Ok((response, chunk))
+        })?;
+        if !chunk.is_empty() {
+            self.response = Some(response);
+        }
+        Ok(chunk)
+    }
+
+    fn close(&mut self) {
+        self.response = None;
+    }
+}
+
+/// Hosts a plugin may reach over `http-client`, from the manifest's `permissions.http`.
+///
+/// `None` leaves HTTP unrestricted for manifests that predate permissions. Entries match
+/// a host exactly or, written as `*.example.com`, any of its subdomains.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpAllowlist {
+    hosts: Option<Vec<String>>,
+}
+
+impl HttpAllowlist {
+    pub fn new(hosts: Option<Vec<String>>) -> Self {
+        Self {
+            hosts: hosts.map(|hosts| {
+                hosts
+                    .into_iter()
+                    .map(|host| host.trim().to_ascii_lowercase())
+                    .filter(|host| !host.is_empty())
+                    .collect()
+            }),
+        }
+    }
+
+    pub fn check(&self, url: &str) -> Result<()> {
+        let Some(hosts) = self.hosts.as_ref() else {
+            return Ok(());
+        };
+        let parsed = reqwest::Url::parse(url.trim())
+            .map_err(|error| Error::invalid_input(format!("invalid url `{url}`: {error}")))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(Error::denied(format!(
+                "scheme `{}` is not allowed for plugin http",
+                parsed.scheme()
+            )));
+        }
+        let host = parsed.host_str().unwrap_or_default().to_ascii_lowercase();
+        let allowed = hosts
+            .iter()
+            .any(|pattern| match pattern.strip_prefix("*.") {
+                Some(domain) => host
+                    .strip_suffix(domain)
+                    .is_some_and(|prefix| prefix.ends_with('.')),
+                None => *pattern == host,
+            });
+        if allowed {
+            Ok(())
+        } else {
+            Err(Error::denied(format!(
+                "host `{host}` is not in the plugin http allowlist"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderMap, HeaderValue, LOCATION};
+    use reqwest::{Method, StatusCode, Url};
+
+    use std::time::Duration;
+
+    use super::{
+        HTTP_DEFAULT_TIMEOUT_MS, HTTP_MAX_TIMEOUT_MS, Hop, HttpAllowlist, next_hop, request_timeout,
+    };
+
+    fn hop(method: Method, url: &str) -> Hop {
+        Hop {
+            method,
+            url: Url::parse(url).expect("url"),
+            body: Some(b"payload".to_vec()),
+            sensitive_headers: true,
+        }
+    }
+
+    fn location(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(LOCATION, HeaderValue::from_str(value).expect("header"));
+        headers
+    }
+
+    #[test]
+    fn allowlist_matches_exact_hosts_and_subdomain_wildcards() {
+        let allowlist = HttpAllowlist::new(Some(vec![
+            "api.example.com".to_string(),
+            "*.music.test".to_string(),
+        ]));
+        assert!(allowlist.check("https://api.example.com/v1").is_ok());
+        assert!(allowlist.check("https://API.example.com:8443/").is_ok());
+        assert!(allowlist.check("https://cdn.music.test/a.mp3").is_ok());
+        assert!(allowlist.check("https://music.test/").is_err());
+        assert!(allowlist.check("https://evilmusic.test/").is_err());
+        assert!(allowlist.check("https://example.com/").is_err());
+        assert!(allowlist.check("file:///etc/passwd").is_err());
+        assert!(allowlist.check("not a url").is_err());
+    }
+
+    #[test]
+    fn missing_allowlist_is_unrestricted_and_empty_denies_everything() {
+        assert!(HttpAllowlist::default().check("https://any.test/").is_ok());
+        assert!(
+            HttpAllowlist::new(Some(Vec::new()))
+                .check("https://any.test/")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn request_timeouts_default_and_clamp_to_the_host_maximum() {
+        assert_eq!(
+            request_timeout(None),
+            Duration::from_millis(HTTP_DEFAULT_TIMEOUT_MS as u64)
+        );
+        assert_eq!(request_timeout(Some(1_500)), Duration::from_millis(1_500));
+        assert_eq!(
+            request_timeout(Some(u32::MAX)),
+            Duration::from_millis(HTTP_MAX_TIMEOUT_MS as u64)
+        );
+    }
+
+    #[test]
+    fn redirects_resolve_locations_and_rewrite_methods() {
+        let post = hop(Method::POST, "https://api.example.com/v1/login");
+        let next = next_hop(&post, StatusCode::FOUND, &location("/v1/home")).expect("redirect");
+        assert_eq!(next.url.as_str(), "https://api.example.com/v1/home");
+        assert_eq!(next.method, Method::GET);
+        assert!(next.body.is_none());
+        assert!(next.sensitive_headers);
+
+        let next = next_hop(
+            &post,
+            StatusCode::TEMPORARY_REDIRECT,
+            &location("https://other.test/upload"),
+        )
+        .expect("redirect");
+        assert_eq!(next.method, Method::POST);
+        assert_eq!(next.body.as_deref(), Some(&b"payload"[..]));
+        assert!(!next.sensitive_headers);
+
+        assert!(next_hop(&post, StatusCode::OK, &location("/elsewhere")).is_none());
+        assert!(next_hop(&post, StatusCode::FOUND, &HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn redirect_targets_outside_the_allowlist_are_denied() {
+        let allowlist = HttpAllowlist::new(Some(vec!["api.example.com".to_string()]));
+        let get = hop(Method::GET, "https://api.example.com/track");
+        let next = next_hop(
+            &get,
+            StatusCode::MOVED_PERMANENTLY,
+            &location("http://169.254.169.254/latest/meta-data"),
+        )
+        .expect("redirect");
+        assert!(allowlist.check(next.url.as_str()).is_err());
+    }
 }
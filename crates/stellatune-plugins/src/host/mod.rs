@@ -1,3 +1,4 @@
 pub mod http;
-pub(crate) mod sidecar;
+pub mod sidecar;
+pub mod storage;
 pub mod stream;
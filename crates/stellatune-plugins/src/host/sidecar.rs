@@ -13,6 +13,7 @@ use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use memmap2::{MmapMut, MmapOptions};
 use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
@@ -20,7 +21,7 @@ use std::os::unix::net::UnixStream;
 use crate::error::{Error, Result};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum SidecarTransportKind {
+pub enum SidecarTransportKind {
     Stdio,
     NamedPipe,
     UnixSocket,
@@ -29,36 +30,161 @@ pub(crate) enum SidecarTransportKind {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct SidecarTransportOption {
+pub struct SidecarTransportOption {
     pub kind: SidecarTransportKind,
     pub priority: u8,
     pub max_frame_bytes: Option<u32>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum SidecarLaunchScope {
+pub enum SidecarLaunchScope {
     Instance,
     Package,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct SidecarLaunchSpec {
+pub struct SidecarLaunchSpec {
     pub scope: SidecarLaunchScope,
     pub executable: String,
     pub args: Vec<String>,
     pub preferred_control: Vec<SidecarTransportOption>,
     pub preferred_data: Vec<SidecarTransportOption>,
     pub env: Vec<(String, String)>,
+    pub supervision: Option<SidecarSupervision>,
 }
 
-pub(crate) trait SidecarChannelHandle: Send {
+/// Host-side supervision declared at launch. Without it a sidecar that exits stays dead.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SidecarSupervision {
+    pub restart: Option<SidecarRestartPolicy>,
+    pub health_probe: Option<SidecarHealthProbe>,
+    /// Forwards stderr, and stdout when no stdio transport is requested, into the plugin log.
+    pub capture_output: bool,
+}
+
+/// Restarts an exited or unhealthy sidecar, doubling the delay after every attempt.
+/// `max_restarts` bounds consecutive attempts: once a restarted sidecar has stayed up for a
+/// minute, the attempt count and backoff start over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidecarRestartPolicy {
+    pub max_restarts: u32,
+    pub initial_backoff_ms: u32,
+    pub max_backoff_ms: u32,
+}
+
+impl SidecarRestartPolicy {
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let initial = u64::from(self.initial_backoff_ms);
+        let delay = initial.saturating_mul(1_u64 << attempt.min(16));
+        Duration::from_millis(
+            delay.min(u64::from(self.max_backoff_ms.max(self.initial_backoff_ms))),
+        )
+    }
+}
+
+/// Writes `request` to a freshly opened control channel every `interval_ms` and expects a
+/// reply starting with `expect_prefix` within `timeout_ms`. The control transport must honor
+/// read timeouts, so probes are skipped when it resolves to stdio.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidecarHealthProbe {
+    pub request: Vec<u8>,
+    pub expect_prefix: Vec<u8>,
+    pub interval_ms: u32,
+    pub timeout_ms: u32,
+    pub failure_threshold: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SidecarProcessState {
+    /// Launched, waiting for the first successful health probe.
+    Starting,
+    Running,
+    /// Health probes are failing; a restart follows if the policy allows one.
+    Unhealthy,
+    /// Exited or killed; waiting out the backoff before launching again.
+    Restarting,
+    /// Gave up after exhausting the restart policy.
+    Failed,
+    /// Exited without supervision or terminated by its owner.
+    Stopped,
+}
+
+impl SidecarProcessState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Starting => "starting",
+            Self::Running => "running",
+            Self::Unhealthy => "unhealthy",
+            Self::Restarting => "restarting",
+            Self::Failed => "failed",
+            Self::Stopped => "stopped",
+        }
+    }
+
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Failed | Self::Stopped)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SidecarProcessStatus {
+    pub state: SidecarProcessState,
+    /// Restarts over the sidecar's lifetime, including attempts the policy has since
+    /// forgiven.
+    pub restarts: u32,
+    pub last_exit_code: Option<i32>,
+    pub detail: Option<String>,
+}
+
+impl SidecarProcessStatus {
+    pub fn new(state: SidecarProcessState) -> Self {
+        Self {
+            state,
+            restarts: 0,
+            last_exit_code: None,
+            detail: None,
+        }
+    }
+}
+
+/// A supervised sidecar changed state. Published to the host alongside the plugin-facing
+/// `process.status`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SidecarStateEvent {
+    pub plugin_id: String,
+    pub executable: String,
+    pub status: SidecarProcessStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidecarOutputKind {
+    Stdout,
+    Stderr,
+}
+
+impl SidecarOutputKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stdout => "stdout",
+            Self::Stderr => "stderr",
+        }
+    }
+}
+
+pub struct SidecarOutputStream {
+    pub kind: SidecarOutputKind,
+    pub reader: Box<dyn Read + Send>,
+}
+
+pub trait SidecarChannelHandle: Send {
     fn transport(&self) -> SidecarTransportKind;
     fn write(&mut self, data: &[u8]) -> Result<u32>;
     fn read(&mut self, max_bytes: u32, timeout_ms: Option<u32>) -> Result<Vec<u8>>;
     fn close(&mut self) {}
 }
 
-pub(crate) trait SidecarProcessHandle: Send {
+pub trait SidecarProcessHandle: Send {
     fn open_control(&mut self) -> Result<Box<dyn SidecarChannelHandle>>;
     fn open_data(
         &mut self,
@@ -67,9 +193,24 @@ pub(crate) trait SidecarProcessHandle: Send {
     ) -> Result<Box<dyn SidecarChannelHandle>>;
     fn wait_exit(&mut self, timeout_ms: Option<u32>) -> Result<Option<i32>>;
     fn terminate(&mut self, grace_ms: u32) -> Result<()>;
+
+    fn status(&mut self) -> Result<SidecarProcessStatus> {
+        Ok(match self.wait_exit(Some(0))? {
+            None => SidecarProcessStatus::new(SidecarProcessState::Running),
+            Some(code) => SidecarProcessStatus {
+                last_exit_code: Some(code),
+                ..SidecarProcessStatus::new(SidecarProcessState::Stopped)
+            },
+        })
+    }
+
+    /// Hands over captured output pipes. Each stream is returned at most once.
+    fn take_output(&mut self) -> Vec<SidecarOutputStream> {
+        Vec::new()
+    }
 }
 
-pub(crate) trait SidecarHost: Send + Sync {
+pub trait SidecarHost: Send + Sync {
     fn launch(&self, spec: &SidecarLaunchSpec) -> Result<Box<dyn SidecarProcessHandle>>;
 }
 
@@ -188,9 +329,17 @@ impl SidecarHost for ProcessSidecarHost {
             env.iter()
                 .map(|(key, value)| (key.as_str(), value.as_str())),
         );
+        let capture_output = spec
+            .supervision
+            .as_ref()
+            .is_some_and(|supervision| supervision.capture_output);
         command.stdin(Stdio::piped());
         command.stdout(Stdio::piped());
-        command.stderr(Stdio::null());
+        command.stderr(if capture_output {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        });
 
         let mut child = command
             .spawn()
@@ -199,10 +348,31 @@ impl SidecarHost for ProcessSidecarHost {
             .stdin
             .take()
             .ok_or_else(|| Error::operation("sidecar.launch", "missing stdin pipe"))?;
-        let stdout = child
-            .stdout
-            .take()
-            .ok_or_else(|| Error::operation("sidecar.launch", "missing stdout pipe"))?;
+        let mut stdout = Some(
+            child
+                .stdout
+                .take()
+                .ok_or_else(|| Error::operation("sidecar.launch", "missing stdout pipe"))?,
+        );
+        let mut output = Vec::<SidecarOutputStream>::new();
+        if capture_output {
+            if let Some(stderr) = child.stderr.take() {
+                output.push(SidecarOutputStream {
+                    kind: SidecarOutputKind::Stderr,
+                    reader: Box::new(stderr),
+                });
+            }
+            // Stdout doubles as the stdio channel, so it can only be captured when the
+            // sidecar talks over something else.
+            if !requests_stdio(spec)
+                && let Some(stdout) = stdout.take()
+            {
+                output.push(SidecarOutputStream {
+                    kind: SidecarOutputKind::Stdout,
+                    reader: Box::new(stdout),
+                });
+            }
+        }
 
         Ok(Box::new(ProcessHandle {
             inner: Arc::new(Mutex::new(ChildIo {
@@ -210,6 +380,7 @@ impl SidecarHost for ProcessSidecarHost {
                 stdin: Some(stdin),
                 stdout,
             })),
+            output,
             control_preferred: spec.preferred_control.clone(),
             data_preferred: spec.preferred_data.clone(),
             env_map,
@@ -221,11 +392,12 @@ impl SidecarHost for ProcessSidecarHost {
 struct ChildIo {
     child: Child,
     stdin: Option<ChildStdin>,
-    stdout: ChildStdout,
+    stdout: Option<ChildStdout>,
 }
 
 struct ProcessHandle {
     inner: Arc<Mutex<ChildIo>>,
+    output: Vec<SidecarOutputStream>,
     control_preferred: Vec<SidecarTransportOption>,
     data_preferred: Vec<SidecarTransportOption>,
     env_map: BTreeMap<String, String>,
@@ -300,7 +472,14 @@ impl ProcessHandle {
         kind: SidecarTransportKind,
     ) -> Result<Box<dyn SidecarChannelHandle>> {
         match kind {
-            SidecarTransportKind::Stdio => Ok(Box::new(ChannelHandle::stdio(self.inner.clone()))),
+            SidecarTransportKind::Stdio => {
+                if self.inner.lock().stdout.is_none() {
+                    return Err(Error::unsupported(
+                        "stdio transport is unavailable while sidecar stdout is captured",
+                    ));
+                }
+                Ok(Box::new(ChannelHandle::stdio(self.inner.clone())))
+            },
             SidecarTransportKind::LoopbackTcp => {
                 let endpoint = self.resolve_endpoint(role, kind).ok_or_else(|| {
                     Error::unsupported(format!(
@@ -449,7 +628,7 @@ impl SidecarProcessHandle for ProcessHandle {
                     .child
                     .wait()
                     .map_err(|error| Error::operation("sidecar.wait-exit", error.to_string()))?;
-                Ok(status.code())
+                Ok(Some(exit_code(status)))
             },
             Some(timeout) => {
                 let deadline = Instant::now() + Duration::from_millis(timeout as u64);
@@ -458,7 +637,7 @@ impl SidecarProcessHandle for ProcessHandle {
                         Error::operation("sidecar.wait-exit", error.to_string())
                     })?;
                     if let Some(status) = status {
-                        return Ok(status.code());
+                        return Ok(Some(exit_code(status)));
                     }
                     if Instant::now() >= deadline {
                         return Ok(None);
@@ -512,6 +691,10 @@ impl SidecarProcessHandle for ProcessHandle {
         let _ = inner.child.wait();
         Ok(())
     }
+
+    fn take_output(&mut self) -> Vec<SidecarOutputStream> {
+        mem::take(&mut self.output)
+    }
 }
 
 impl Drop for ProcessHandle {
@@ -588,8 +771,10 @@ impl SidecarChannelHandle for ChannelHandle {
         let size = match &mut self.io {
             ChannelIo::Stdio(inner) => {
                 let mut inner = inner.lock();
-                inner
-                    .stdout
+                let stdout = inner.stdout.as_mut().ok_or_else(|| {
+                    Error::operation("sidecar.channel.read", "sidecar stdout is captured")
+                })?;
+                stdout
                     .read(&mut buffer)
                     .map_err(|error| Error::operation("sidecar.channel.read", error.to_string()))?
             },
@@ -1073,6 +1258,22 @@ fn transport_env_suffixes(kind: SidecarTransportKind) -> &'static [&'static str]
     }
 }
 
+/// Processes killed by a signal have no exit code; report them as `-1` rather than as
+/// still running.
+fn exit_code(status: std::process::ExitStatus) -> i32 {
+    status.code().unwrap_or(-1)
+}
+
+fn requests_stdio(spec: &SidecarLaunchSpec) -> bool {
+    // An empty control preference falls back to stdio.
+    spec.preferred_control.is_empty()
+        || spec
+            .preferred_control
+            .iter()
+            .chain(&spec.preferred_data)
+            .any(|option| option.kind == SidecarTransportKind::Stdio)
+}
+
 fn ordered_kinds(options: &[SidecarTransportOption]) -> Vec<SidecarTransportKind> {
     if options.is_empty() {
         return vec![SidecarTransportKind::Stdio];
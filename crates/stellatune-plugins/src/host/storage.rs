@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::Mutex;
+
+use crate::error::{Error, Result};
+
+/// Directory under the app data dir that holds every plugin's storage.
+pub const PLUGIN_STORAGE_DIR_NAME: &str = "plugin-storage";
+pub const PLUGIN_STORAGE_QUOTA_BYTES: u64 = 16 * 1024 * 1024;
+/// Hex-encoded with the entry suffix, the longest key still fits the 255-byte file
+/// name limit common to Linux, macOS and Windows file systems.
+pub const PLUGIN_STORAGE_MAX_KEY_BYTES: usize = 125;
+pub const PLUGIN_STORAGE_MAX_VALUE_BYTES: usize = 1024 * 1024;
+
+const ENTRY_FILE_SUFFIX: &str = ".kv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginStorageUsage {
+    pub used_bytes: u64,
+    pub quota_bytes: u64,
+    pub key_count: u32,
+}
+
+/// Per-plugin key/value store backed by one file per key.
+///
+/// Keys are hex-encoded into file names so arbitrary UTF-8 keys never escape
+/// the storage directory. Each write goes through its own temp file and a
+/// rename, so concurrent instances of the same plugin only ever observe whole
+/// values. Writers of one directory share a lock that spans the quota check
+/// and the rename.
+#[derive(Debug, Clone)]
+pub struct PluginStorage {
+    /// `None` until the host sets a storage root; every call is then denied.
+    dir: Option<PathBuf>,
+    quota_bytes: u64,
+    write_lock: Arc<Mutex<()>>,
+}
+
+/// Storage directory for `plugin_id` under `storage_root`.
+///
+/// The root lives in the app data dir rather than next to the packages, so
+/// reinstalling or upgrading a plugin, or loading it from a dev directory,
+/// keeps its data; only uninstall removes it.
+pub fn plugin_storage_dir(storage_root: impl AsRef<Path>, plugin_id: &str) -> Result<PathBuf> {
+    let plugin_id = plugin_id.trim();
+    if plugin_id.is_empty() || plugin_id.contains(['/', '\\']) || plugin_id.contains("..") {
+        return Err(Error::invalid_input(format!(
+            "plugin id `{plugin_id}` cannot name a storage directory"
+        )));
+    }
+    Ok(storage_root.as_ref().join(plugin_id))
+}
+
+/// Deletes everything `plugin_id` stored under `storage_root`; used on uninstall.
+pub fn remove_plugin_storage(storage_root: impl AsRef<Path>, plugin_id: &str) -> Result<()> {
+    let dir = plugin_storage_dir(storage_root, plugin_id)?;
+    match std::fs::remove_dir_all(&dir) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+        Err(source) => Err(Error::io_at(dir, source)),
+    }
+}
+
+impl PluginStorage {
+    pub fn new(dir: impl Into<PathBuf>, quota_bytes: u64) -> Self {
+        let dir = dir.into();
+        Self {
+            write_lock: write_lock_for(&dir),
+            dir: Some(dir),
+            quota_bytes,
+        }
+    }
+
+    /// Storage that denies every call, for hosts that have no data directory.
+    pub fn unavailable() -> Self {
+        Self {
+            dir: None,
+            quota_bytes: 0,
+            write_lock: Arc::default(),
+        }
+    }
+
+    /// Storage for `plugin_id` under `storage_root`, with the default quota.
+    pub fn for_plugin(storage_root: &Path, plugin_id: &str) -> Result<Self> {
+        Ok(Self::new(
+            plugin_storage_dir(storage_root, plugin_id)?,
+            PLUGIN_STORAGE_QUOTA_BYTES,
+        ))
+    }
+
+    pub fn dir(&self) -> Result<&Path> {
+        self.dir
+            .as_deref()
+            .ok_or_else(|| Error::denied("plugin storage is unavailable: host has no data dir"))
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.entry_path(key)?;
+        match std::fs::read(&path) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(Error::IoAt { path, source }),
+        }
+    }
+
+    pub fn set(&self, key: &str, value: &[u8]) -> Result<()> {
+        let path = self.entry_path(key)?;
+        if value.len() > PLUGIN_STORAGE_MAX_VALUE_BYTES {
+            return Err(Error::invalid_input(format!(
+                "storage value for `{key}` is {} bytes, limit is {PLUGIN_STORAGE_MAX_VALUE_BYTES}",
+                value.len()
+            )));
+        }
+
+        let _guard = self.write_lock.lock();
+        let usage = self.usage()?;
+        let previous_len = entry_len(&path)?.map_or(0, |len| stored_len(key, len as usize));
+        let next_used = usage
+            .used_bytes
+            .saturating_sub(previous_len)
+            .saturating_add(stored_len(key, value.len()));
+        if next_used > self.quota_bytes {
+            return Err(Error::denied(format!(
+                "storage quota exceeded: {next_used} bytes needed, quota is {}",
+                self.quota_bytes
+            )));
+        }
+
+        let dir = self.dir()?;
+        std::fs::create_dir_all(dir).map_err(|source| Error::io_at(dir, source))?;
+        // The temp file lacks the entry suffix, so listings skip it until the rename.
+        let mut temp =
+            tempfile::NamedTempFile::new_in(dir).map_err(|source| Error::io_at(dir, source))?;
+        temp.write_all(value).map_err(|source| Error::IoAt {
+            path: temp.path().to_path_buf(),
+            source,
+        })?;
+        temp.persist(&path).map_err(|error| Error::IoAt {
+            path: path.clone(),
+            source: error.error,
+        })?;
+        Ok(())
+    }
+
+    pub fn delete(&self, key: &str) -> Result<bool> {
+        let path = self.entry_path(key)?;
+        let _guard = self.write_lock.lock();
+        match std::fs::remove_file(&path) {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(false),
+            Err(source) => Err(Error::IoAt { path, source }),
+        }
+    }
+
+    pub fn list_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut keys = self
+            .entries()?
+            .into_iter()
+            .map(|(key, _)| key)
+            .filter(|key| key.starts_with(prefix))
+            .collect::<Vec<_>>();
+        keys.sort();
+        Ok(keys)
+    }
+
+    pub fn usage(&self) -> Result<PluginStorageUsage> {
+        let entries = self.entries()?;
+        let used_bytes = entries
+            .iter()
+            .map(|(key, len)| stored_len(key, *len as usize))
+            .sum();
+        Ok(PluginStorageUsage {
+            used_bytes,
+            quota_bytes: self.quota_bytes,
+            key_count: entries.len().min(u32::MAX as usize) as u32,
+        })
+    }
+
+    fn entry_path(&self, key: &str) -> Result<PathBuf> {
+        if key.is_empty() {
+            return Err(Error::invalid_input("storage key is empty"));
+        }
+        if key.len() > PLUGIN_STORAGE_MAX_KEY_BYTES {
+            return Err(Error::invalid_input(format!(
+                "storage key is {} bytes, limit is {PLUGIN_STORAGE_MAX_KEY_BYTES}",
+                key.len()
+            )));
+        }
+        Ok(self
+            .dir()?
+            .join(format!("{}{ENTRY_FILE_SUFFIX}", encode_key(key))))
+    }
+
+    fn entries(&self) -> Result<Vec<(String, u64)>> {
+        let dir = self.dir()?;
+        let read_dir = match std::fs::read_dir(dir) {
+            Ok(read_dir) => read_dir,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => return Err(Error::io_at(dir, source)),
+        };
+        let mut out = Vec::new();
+        for entry in read_dir {
+            let entry = entry?;
+            let file_name = entry.file_name();
+            let Some(encoded) = file_name
+                .to_str()
+                .and_then(|name| name.strip_suffix(ENTRY_FILE_SUFFIX))
+            else {
+                continue;
+            };
+            let Some(key) = decode_key(encoded) else {
+                continue;
+            };
+            let metadata = entry.metadata()?;
+            if metadata.is_file() {
+                out.push((key, metadata.len()));
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// One lock per storage directory, shared by every `PluginStorage` that points at it.
+fn write_lock_for(dir: &Path) -> Arc<Mutex<()>> {
+    static LOCKS: OnceLock<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
+    let mut locks = LOCKS.get_or_init(Default::default).lock();
+    Arc::clone(locks.entry(dir.to_path_buf()).or_default())
+}
+
+fn entry_len(path: &Path) -> Result<Option<u64>> {
+    match std::fs::metadata(path) {
+        Ok(metadata) => Ok(Some(metadata.len())),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(Error::IoAt {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+// Keys count against the quota too, so a plugin cannot fill the disk with empty values.
+fn stored_len(key: &str, value_len: usize) -> u64 {
+    (key.len() + value_len) as u64
+}
+
+fn encode_key(key: &str) -> String {
+    key.bytes().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn decode_key(encoded: &str) -> Option<String> {
+    if !encoded.is_ascii() || !encoded.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes = (0..encoded.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&encoded[index..index + 2], 16).ok())
+        .collect::<Option<Vec<_>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::error::Error;
+    use crate::host::storage::{
+        PLUGIN_STORAGE_MAX_KEY_BYTES, PLUGIN_STORAGE_MAX_VALUE_BYTES, PluginStorage,
+        plugin_storage_dir,
+    };
+
+    fn storage(quota_bytes: u64) -> (tempfile::TempDir, PluginStorage) {
+        let temp = tempfile::tempdir().expect("create temp dir");
+        let storage = PluginStorage::new(temp.path().join("dev.example"), quota_bytes);
+        (temp, storage)
+    }
+
+    #[test]
+    fn set_get_delete_roundtrip() {
+        let (_temp, storage) = storage(1024);
+
+        assert_eq!(storage.get("token").expect("get"), None);
+        storage.set("token", b"abc").expect("set");
+        assert_eq!(storage.get("token").expect("get"), Some(b"abc".to_vec()));
+        assert!(storage.delete("token").expect("delete"));
+        assert!(!storage.delete("token").expect("delete again"));
+        assert_eq!(storage.get("token").expect("get"), None);
+    }
+
+    #[test]
+    fn list_prefix_returns_sorted_matching_keys() {
+        let (_temp, storage) = storage(1024);
+        for key in ["cache/b", "auth/token", "cache/a", "../escape"] {
+            storage.set(key, b"1").expect("set");
+        }
+
+        assert_eq!(
+            storage.list_prefix("cache/").expect("list"),
+            vec!["cache/a".to_string(), "cache/b".to_string()]
+        );
+        assert_eq!(storage.list_prefix("").expect("list").len(), 4);
+    }
+
+    #[test]
+    fn set_enforces_quota_but_allows_overwrite_within_quota() {
+        let (_temp, storage) = storage(16);
+        storage.set("k", &[0; 10]).expect("first set fits");
+        storage
+            .set("k", &[1; 15])
+            .expect("overwrite reuses the old allowance");
+
+        let error = storage.set("other", &[0; 4]).expect_err("quota exceeded");
+        assert!(matches!(error, Error::Denied { .. }));
+        let usage = storage.usage().expect("usage");
+        assert_eq!(usage.used_bytes, 16);
+        assert_eq!(usage.key_count, 1);
+    }
+
+    #[test]
+    fn key_at_the_length_limit_roundtrips() {
+        let (_temp, storage) = storage(1024);
+        let key = "k".repeat(PLUGIN_STORAGE_MAX_KEY_BYTES);
+
+        storage.set(&key, b"value").expect("set");
+        assert_eq!(storage.get(&key).expect("get"), Some(b"value".to_vec()));
+        assert_eq!(storage.list_prefix("").expect("list"), vec![key.clone()]);
+        assert!(storage.delete(&key).expect("delete"));
+    }
+
+    #[test]
+    fn set_rejects_invalid_keys_and_oversized_values() {
+        let (_temp, storage) = storage(u64::MAX);
+
+        assert!(matches!(
+            storage.set("", b"x"),
+            Err(Error::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            storage.set(&"k".repeat(PLUGIN_STORAGE_MAX_KEY_BYTES + 1), b"x"),
+            Err(Error::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            storage.set("big", &vec![0; PLUGIN_STORAGE_MAX_VALUE_BYTES + 1]),
+            Err(Error::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    fn concurrent_writers_stay_within_quota() {
+        let (_temp, storage) = storage(64);
+        let storage = Arc::new(storage);
+        let writers = (0..8)
+            .map(|index| {
+                let storage = Arc::clone(&storage);
+                std::thread::spawn(move || storage.set(&format!("k{index}"), &[0; 14]).is_ok())
+            })
+            .collect::<Vec<_>>();
+        let written = writers
+            .into_iter()
+            .map(|writer| writer.join().expect("writer thread"))
+            .filter(|written| *written)
+            .count();
+
+        assert_eq!(written, 4);
+        assert_eq!(storage.usage().expect("usage").used_bytes, 64);
+    }
+
+    #[test]
+    fn storage_dir_rejects_ids_that_escape_the_root() {
+        for plugin_id in ["", "../other", "a/b", "a\\b", ".."] {
+            assert!(matches!(
+                plugin_storage_dir("/data/plugin-storage", plugin_id),
+                Err(Error::InvalidInput { .. })
+            ));
+        }
+        assert!(plugin_storage_dir("/data/plugin-storage", "dev.example.plugin").is_ok());
+    }
+}
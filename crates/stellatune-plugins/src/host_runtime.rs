@@ -12,14 +12,20 @@ use crate::executor::plugin_instance::lyrics::{LyricsPluginApi, WasmtimeLyricsPl
 use crate::executor::plugin_instance::output_sink::{
     OutputSinkPluginApi, WasmtimeOutputSinkPlugin,
 };
+use crate::executor::plugin_instance::playback_listener::{
+    PlaybackListenerPluginApi, WasmtimePlaybackListenerPlugin,
+};
 use crate::executor::plugin_instance::source::{
     RuntimeOpenedSourceStream, SourcePluginApi, WasmtimeSourcePlugin,
 };
 
 use crate::host::stream::{HostStreamHandle, open_local_file_stream};
 use crate::runtime::model::{
-    RuntimeAudioSpec, RuntimeDecoderSessionHandle, RuntimeDspProcessorHandle, RuntimeEncodedChunk,
-    RuntimeMediaMetadata, RuntimeNegotiatedSpec, RuntimeOutputSinkStatus, RuntimePcmF32Chunk,
+    RuntimeAudioSpec, RuntimeAuthStatus, RuntimeBrowsePage, RuntimeCatalogCapabilities,
+    RuntimeCoreModuleSpec, RuntimeDecoderSessionHandle, RuntimeDspProcessorHandle,
+    RuntimeEncodedChunk, RuntimeLoginChallenge, RuntimeLoginMethod, RuntimeLoginProgress,
+    RuntimeLyricCandidate, RuntimeMediaMetadata, RuntimeNegotiatedSpec, RuntimeOutputSinkStatus,
+    RuntimePageRequest, RuntimePcmF32Chunk, RuntimePlaybackEvent, RuntimeSearchRequest,
     RuntimeSourceStreamHandle,
 };
 
@@ -30,6 +36,7 @@ pub enum RuntimeCapabilityKind {
     SourceCatalog,
     LyricsProvider,
     OutputSink,
+    PlaybackListener,
 }
 
 #[derive(Debug, Clone)]
@@ -167,6 +174,51 @@ pub struct RuntimeSourcePlugin {
 }
 
 impl RuntimeSourcePlugin {
+    pub fn capabilities(
+        &mut self,
+    ) -> std::result::Result<RuntimeCatalogCapabilities, WasmPluginError> {
+        self.inner.capabilities()
+    }
+
+    pub fn browse(
+        &mut self,
+        parent_id: Option<&str>,
+        page: &RuntimePageRequest,
+    ) -> std::result::Result<RuntimeBrowsePage, WasmPluginError> {
+        self.inner.browse(parent_id, page)
+    }
+
+    pub fn search(
+        &mut self,
+        request: &RuntimeSearchRequest,
+    ) -> std::result::Result<RuntimeBrowsePage, WasmPluginError> {
+        self.inner.search(request)
+    }
+
+    pub fn capabilities_json(&mut self) -> std::result::Result<String, WasmPluginError> {
+        let out = self.inner.capabilities()?;
+        serde_json::to_string(&out).map_err(WasmPluginError::from)
+    }
+
+    pub fn browse_json(
+        &mut self,
+        parent_id: Option<&str>,
+        page_json: &str,
+    ) -> std::result::Result<String, WasmPluginError> {
+        let page = serde_json::from_str::<RuntimePageRequest>(page_json)?;
+        let out = self.inner.browse(parent_id, &page)?;
+        serde_json::to_string(&out).map_err(WasmPluginError::from)
+    }
+
+    pub fn search_json(
+        &mut self,
+        request_json: &str,
+    ) -> std::result::Result<String, WasmPluginError> {
+        let request = serde_json::from_str::<RuntimeSearchRequest>(request_json)?;
+        let out = self.inner.search(&request)?;
+        serde_json::to_string(&out).map_err(WasmPluginError::from)
+    }
+
     pub fn list_items_json(
         &mut self,
         request_json: &str,
@@ -174,6 +226,32 @@ impl RuntimeSourcePlugin {
         self.inner.list_items_json(request_json)
     }
 
+    pub fn auth_status(&mut self) -> std::result::Result<RuntimeAuthStatus, WasmPluginError> {
+        self.inner.auth_status()
+    }
+
+    pub fn begin_login(
+        &mut self,
+        method: Option<RuntimeLoginMethod>,
+    ) -> std::result::Result<RuntimeLoginChallenge, WasmPluginError> {
+        self.inner.begin_login(method)
+    }
+
+    pub fn poll_login(
+        &mut self,
+        session_id: &str,
+    ) -> std::result::Result<RuntimeLoginProgress, WasmPluginError> {
+        self.inner.poll_login(session_id)
+    }
+
+    pub fn refresh_auth(&mut self) -> std::result::Result<RuntimeAuthStatus, WasmPluginError> {
+        self.inner.refresh_auth()
+    }
+
+    pub fn logout(&mut self) -> std::result::Result<(), WasmPluginError> {
+        self.inner.logout()
+    }
+
     pub fn apply_config_update_json(
         &mut self,
         config_json: &str,
@@ -209,6 +287,13 @@ pub struct RuntimeLyricsPlugin {
 }
 
 impl RuntimeLyricsPlugin {
+    pub fn search(
+        &mut self,
+        keyword: &str,
+    ) -> std::result::Result<Vec<RuntimeLyricCandidate>, WasmPluginError> {
+        self.inner.search(keyword)
+    }
+
     pub fn search_json(&mut self, keyword: &str) -> std::result::Result<String, WasmPluginError> {
         let out = self.inner.search(keyword)?;
         serde_json::to_string(&out).map_err(WasmPluginError::from)
@@ -219,6 +304,27 @@ impl RuntimeLyricsPlugin {
     }
 }
 
+pub struct RuntimePlaybackListenerPlugin {
+    inner: WasmtimePlaybackListenerPlugin,
+}
+
+impl RuntimePlaybackListenerPlugin {
+    pub fn plugin_id(&self) -> &str {
+        self.inner.plugin_id()
+    }
+
+    pub fn on_event(
+        &mut self,
+        event: &RuntimePlaybackEvent,
+    ) -> std::result::Result<(), WasmPluginError> {
+        self.inner.on_event(event)
+    }
+
+    pub fn close(&mut self) -> std::result::Result<(), WasmPluginError> {
+        self.inner.close()
+    }
+}
+
 struct RuntimeDspPluginCell {
     inner: WasmtimeDspPlugin,
     processor: Option<RuntimeDspProcessorHandle>,
@@ -431,6 +537,19 @@ impl RuntimeOutputSinkPlugin {
         })
     }
 
+    pub fn describe_hot_path(
+        &mut self,
+        sample_rate: u32,
+        channels: u16,
+    ) -> std::result::Result<Option<RuntimeCoreModuleSpec>, WasmPluginError> {
+        self.with_cell(|cell| {
+            cell.inner.describe_hot_path(RuntimeAudioSpec {
+                sample_rate,
+                channels,
+            })
+        })
+    }
+
     pub fn open(
         &mut self,
         target_json: &str,
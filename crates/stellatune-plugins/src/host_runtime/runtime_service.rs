@@ -2,17 +2,19 @@ use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, OnceLock};
-use std::thread;
 
 use crate::executor::WasmtimePluginController;
-use crate::host::http::HttpClientHost;
+use crate::host::http::ReqwestHttpClient;
+use crate::host::sidecar::SidecarStateEvent;
+use crate::host::storage::{PLUGIN_STORAGE_DIR_NAME, remove_plugin_storage};
 use crate::host::stream::DefaultHostStreamService;
 use crate::manifest::AbilityKind;
 use crate::runtime::model::{
-    DesiredPluginState, RuntimePluginLifecycleState, RuntimePluginTransitionOutcome,
+    DesiredPluginState, RuntimePluginErrorAction, RuntimePluginErrorCode, RuntimePluginErrorRecord,
+    RuntimePluginErrorSeverity, RuntimePluginLifecycleState, RuntimePluginTransitionOutcome,
     RuntimePluginTransitionTrigger,
 };
-use crate::runtime::service::WasmPluginRuntime;
+use crate::runtime::service::{DevPluginWatcher, WasmPluginRuntime};
 use anyhow::{Result, anyhow};
 
 use super::{
@@ -20,8 +22,8 @@ use super::{
     RUNTIME_DSP_PLUGINS, RUNTIME_OUTPUT_SINK_PLUGIN_SEQ, RUNTIME_OUTPUT_SINK_PLUGINS,
     RuntimeCapabilityDescriptor, RuntimeCapabilityKind, RuntimeDecoderCandidate,
     RuntimeDecoderPlugin, RuntimeDecoderPluginCell, RuntimeDspPlugin, RuntimeDspPluginCell,
-    RuntimeLyricsPlugin, RuntimeOutputSinkPlugin, RuntimeOutputSinkPluginCell, RuntimeSourcePlugin,
-    WasmPluginError,
+    RuntimeLyricsPlugin, RuntimeOutputSinkPlugin, RuntimeOutputSinkPluginCell,
+    RuntimePlaybackListenerPlugin, RuntimeSourcePlugin, WasmPluginError,
 };
 
 #[derive(Debug, Clone)]
@@ -73,7 +75,7 @@ pub struct SharedPluginRuntime {
 impl SharedPluginRuntime {
     pub fn new() -> Result<Self> {
         let controller = WasmtimePluginController::shared(
-            Arc::new(BackendHttpClient),
+            Arc::new(ReqwestHttpClient),
             Arc::new(DefaultHostStreamService),
         )
         .map_err(|error| anyhow!("failed to create wasmtime plugin controller: {error:#}"))?;
@@ -129,6 +131,65 @@ impl SharedPluginRuntime {
         self.active_plugins_snapshot()
     }
 
+    /// Keeps plugin storage under `<data_dir>/plugin-storage`. Called once the app data
+    /// dir is known; until then storage calls are denied.
+    pub fn set_data_dir(&self, data_dir: &Path) {
+        self.runtime
+            .controller()
+            .set_storage_root(data_dir.join(PLUGIN_STORAGE_DIR_NAME));
+    }
+
+    /// Sets the app version plugin manifests' `requires.host` is checked against. Call
+    /// before the first sync; until then `requires.host` is not enforced.
+    pub fn set_host_version(&self, version: &str) -> Result<()> {
+        crate::compat::set_host_version(version)
+            .map_err(|error| anyhow!("failed to set host version: {error}"))
+    }
+
+    /// Records a saved capability config so new instances start with it. See
+    /// [`WasmtimePluginController::set_persisted_config`].
+    pub fn set_persisted_config(
+        &self,
+        plugin_id: &str,
+        type_id: &str,
+        schema_version: u32,
+        config_json: String,
+    ) {
+        self.runtime.controller().set_persisted_config(
+            plugin_id,
+            type_id,
+            schema_version,
+            config_json,
+        );
+    }
+
+    /// Deletes the storage of an uninstalled plugin. A no-op before
+    /// [`Self::set_data_dir`].
+    pub fn remove_plugin_storage(&self, plugin_id: &str) -> Result<()> {
+        let Some(storage_root) = self.runtime.controller().storage_root() else {
+            return Ok(());
+        };
+        remove_plugin_storage(storage_root, plugin_id)
+            .map_err(|error| anyhow!("failed to remove storage of `{plugin_id}`: {error}"))
+    }
+
+    /// Loads an unpacked plugin directory and reloads it whenever its build output or
+    /// manifest changes. See [`WasmPluginRuntime::watch_dev_plugin`].
+    pub fn watch_dev_plugin(&self, root_dir: &Path) -> Result<DevPluginWatcher> {
+        self.runtime.watch_dev_plugin(root_dir).map_err(|error| {
+            anyhow!(
+                "failed to watch dev plugin directory `{}`: {error:#}",
+                root_dir.display()
+            )
+        })
+    }
+
+    pub fn unload_dev_plugin(&self, plugin_id: &str) -> Result<()> {
+        self.runtime
+            .unload_dev_plugin(plugin_id)
+            .map_err(|error| anyhow!("failed to unload dev plugin `{plugin_id}`: {error:#}"))
+    }
+
     pub fn list_decoder_candidates_for_ext(&self, ext: &str) -> Vec<RuntimeDecoderCandidate> {
         let ext = normalize_ext(ext);
         let mut out = Vec::<RuntimeDecoderCandidate>::new();
@@ -261,6 +322,13 @@ impl SharedPluginRuntime {
         self.list_capabilities_of_kind(plugin_id, RuntimeCapabilityKind::OutputSink)
     }
 
+    pub fn list_playback_listener_capabilities(
+        &self,
+        plugin_id: &str,
+    ) -> Vec<RuntimeCapabilityDescriptor> {
+        self.list_capabilities_of_kind(plugin_id, RuntimeCapabilityKind::PlaybackListener)
+    }
+
     pub fn find_capability(
         &self,
         plugin_id: &str,
@@ -286,7 +354,9 @@ impl SharedPluginRuntime {
             .runtime
             .controller()
             .create_decoder_plugin(plugin_id, type_id)
-            .map_err(|error| anyhow!(error.to_string()))?;
+            .map_err(|error| {
+                self.report_create_failure(plugin_id, AbilityKind::Decoder, type_id, &error)
+            })?;
         let id = RUNTIME_DECODER_PLUGIN_SEQ.fetch_add(1, Ordering::Relaxed) + 1;
         RUNTIME_DECODER_PLUGINS.with(|map| {
             let mut map = map.borrow_mut();
@@ -304,7 +374,9 @@ impl SharedPluginRuntime {
             .runtime
             .controller()
             .create_source_plugin(plugin_id, type_id)
-            .map_err(|error| anyhow!(error.to_string()))?;
+            .map_err(|error| {
+                self.report_create_failure(plugin_id, AbilityKind::Source, type_id, &error)
+            })?;
         Ok(RuntimeSourcePlugin { inner: source })
     }
 
@@ -313,7 +385,9 @@ impl SharedPluginRuntime {
             .runtime
             .controller()
             .create_dsp_plugin(plugin_id, type_id)
-            .map_err(|error| anyhow!(error.to_string()))?;
+            .map_err(|error| {
+                self.report_create_failure(plugin_id, AbilityKind::Dsp, type_id, &error)
+            })?;
         let id = RUNTIME_DSP_PLUGIN_SEQ.fetch_add(1, Ordering::Relaxed) + 1;
         RUNTIME_DSP_PLUGINS.with(|map| {
             let mut map = map.borrow_mut();
@@ -338,7 +412,9 @@ impl SharedPluginRuntime {
             .runtime
             .controller()
             .create_output_sink_plugin(plugin_id, type_id)
-            .map_err(|error| anyhow!(error.to_string()))?;
+            .map_err(|error| {
+                self.report_create_failure(plugin_id, AbilityKind::OutputSink, type_id, &error)
+            })?;
         let id = RUNTIME_OUTPUT_SINK_PLUGIN_SEQ.fetch_add(1, Ordering::Relaxed) + 1;
         RUNTIME_OUTPUT_SINK_PLUGINS.with(|map| {
             let mut map = map.borrow_mut();
@@ -356,14 +432,152 @@ impl SharedPluginRuntime {
             .runtime
             .controller()
             .create_lyrics_plugin(plugin_id, type_id)
-            .map_err(|error| anyhow!(error.to_string()))?;
+            .map_err(|error| {
+                self.report_create_failure(plugin_id, AbilityKind::Lyrics, type_id, &error)
+            })?;
         Ok(RuntimeLyricsPlugin { inner: lyrics })
     }
 
+    pub fn create_playback_listener_plugin(
+        &self,
+        plugin_id: &str,
+        type_id: &str,
+    ) -> Result<RuntimePlaybackListenerPlugin> {
+        let listener = self
+            .runtime
+            .controller()
+            .create_playback_listener_plugin(plugin_id, type_id)
+            .map_err(|error| {
+                self.report_create_failure(
+                    plugin_id,
+                    AbilityKind::PlaybackListener,
+                    type_id,
+                    &error,
+                )
+            })?;
+        Ok(RuntimePlaybackListenerPlugin { inner: listener })
+    }
+
+    fn report_create_failure(
+        &self,
+        plugin_id: &str,
+        kind: AbilityKind,
+        type_id: &str,
+        error: &crate::error::Error,
+    ) -> anyhow::Error {
+        let detail = error.to_string();
+        self.runtime.report_plugin_error(
+            RuntimePluginErrorRecord::new(
+                RuntimePluginErrorCode::InstanceCreateFailed,
+                plugin_id,
+                RuntimePluginErrorAction::Operation,
+                detail.clone(),
+            )
+            .with_capability(kind, type_id),
+        );
+        anyhow!(detail)
+    }
+
+    /// Records a failure raised by a live plugin instance (e.g. a capability call).
+    pub fn report_plugin_error(&self, record: RuntimePluginErrorRecord) {
+        self.runtime.report_plugin_error(record);
+    }
+
+    /// Reports `error` as an `auth_required`/`auth_expired` plugin error so the UI can prompt
+    /// for login. Returns `false` (and reports nothing) for other errors.
+    pub fn report_auth_failure(
+        &self,
+        plugin_id: &str,
+        kind: AbilityKind,
+        type_id: &str,
+        error: &WasmPluginError,
+    ) -> bool {
+        let code = match error {
+            WasmPluginError::AuthRequired { .. } => RuntimePluginErrorCode::AuthRequired,
+            WasmPluginError::AuthExpired { .. } => RuntimePluginErrorCode::AuthExpired,
+            _ => return false,
+        };
+        self.runtime.report_plugin_error(
+            RuntimePluginErrorRecord::new(
+                code,
+                plugin_id,
+                RuntimePluginErrorAction::Operation,
+                error.to_string(),
+            )
+            .with_capability(kind, type_id)
+            .with_severity(RuntimePluginErrorSeverity::Warning),
+        );
+        true
+    }
+
+    /// Reports a failed call on a live instance: auth failures as by
+    /// [`Self::report_auth_failure`], anything else as `operation_failed` with `detail`.
+    pub fn report_operation_failure(
+        &self,
+        plugin_id: &str,
+        kind: AbilityKind,
+        type_id: &str,
+        error: &WasmPluginError,
+        detail: impl Into<String>,
+    ) {
+        if !self.report_auth_failure(plugin_id, kind, type_id, error) {
+            self.report_operation_error(plugin_id, kind, type_id, detail);
+        }
+    }
+
+    /// Reports an `operation_failed` error for a failure the host detected itself, such as
+    /// a stalled output sink.
+    pub fn report_operation_error(
+        &self,
+        plugin_id: &str,
+        kind: AbilityKind,
+        type_id: &str,
+        detail: impl Into<String>,
+    ) {
+        self.runtime.report_plugin_error(
+            RuntimePluginErrorRecord::new(
+                RuntimePluginErrorCode::OperationFailed,
+                plugin_id,
+                RuntimePluginErrorAction::Operation,
+                detail.into(),
+            )
+            .with_capability(kind, type_id),
+        );
+    }
+
+    /// Subscribes to structured errors from sync, instance creation and reported operations.
+    pub fn subscribe_plugin_errors(&self) -> std::sync::mpsc::Receiver<RuntimePluginErrorRecord> {
+        self.runtime.subscribe_plugin_errors()
+    }
+
+    /// Subscribes to plugin errors and returns the ones reported before, e.g. while the
+    /// host had nowhere to persist them yet.
+    pub fn subscribe_plugin_errors_with_backlog(
+        &self,
+    ) -> (
+        Vec<RuntimePluginErrorRecord>,
+        std::sync::mpsc::Receiver<RuntimePluginErrorRecord>,
+    ) {
+        self.runtime.subscribe_plugin_errors_with_backlog()
+    }
+
+    pub fn subscribe_sidecar_events(&self) -> std::sync::mpsc::Receiver<SidecarStateEvent> {
+        self.runtime.controller().subscribe_sidecar_events()
+    }
+
+    pub fn recent_plugin_errors(&self, plugin_id: Option<&str>) -> Vec<RuntimePluginErrorRecord> {
+        self.runtime.recent_plugin_errors(plugin_id)
+    }
+
     pub fn active_plugin_ids(&self) -> Vec<String> {
         self.runtime.active_ids()
     }
 
+    /// Changes whenever plugins load, reload or unload; see [`WasmPluginRuntime::revision`].
+    pub fn active_plugins_revision(&self) -> u64 {
+        self.runtime.revision()
+    }
+
     pub fn sync_dir_with_disabled_ids(
         &self,
         dir: impl AsRef<Path>,
@@ -535,7 +749,7 @@ impl SharedPluginRuntime {
         let load_errors = report
             .errors
             .into_iter()
-            .map(|error| anyhow!(error))
+            .map(|error| anyhow!(error.detail))
             .collect::<Vec<_>>();
 
         tracing::info!(
@@ -638,46 +852,6 @@ fn map_ability_kind(kind: AbilityKind) -> RuntimeCapabilityKind {
         AbilityKind::Source => RuntimeCapabilityKind::SourceCatalog,
         AbilityKind::Lyrics => RuntimeCapabilityKind::LyricsProvider,
         AbilityKind::OutputSink => RuntimeCapabilityKind::OutputSink,
-    }
-}
-
-#[derive(Default)]
-struct BackendHttpClient;
-
-impl HttpClientHost for BackendHttpClient {
-    fn fetch_json(&self, url: &str) -> std::result::Result<String, WasmPluginError> {
-        let url = url.trim();
-        if url.is_empty() {
-            return Err(WasmPluginError::invalid_input("url is empty"));
-        }
-        let url = url.to_string();
-        let worker = thread::Builder::new()
-            .name("stellatune-http-client".to_string())
-            .spawn(move || {
-                reqwest::blocking::get(url)
-                    .map_err(|error| {
-                        WasmPluginError::operation("http_client.fetch_json", error.to_string())
-                    })?
-                    .error_for_status()
-                    .map_err(|error| {
-                        WasmPluginError::operation("http_client.fetch_json", error.to_string())
-                    })?
-                    .text()
-                    .map_err(|error| {
-                        WasmPluginError::operation("http_client.fetch_json", error.to_string())
-                    })
-            })
-            .map_err(|error| {
-                WasmPluginError::operation(
-                    "http_client.fetch_json",
-                    format!("spawn blocking worker failed: {error}"),
-                )
-            })?;
-        worker.join().map_err(|_| {
-            WasmPluginError::operation(
-                "http_client.fetch_json",
-                "blocking worker panicked".to_string(),
-            )
-        })?
+        AbilityKind::PlaybackListener => RuntimeCapabilityKind::PlaybackListener,
     }
 }
@@ -1,3 +1,5 @@
+pub mod compat;
+pub mod config_schema;
 pub mod error;
 pub mod executor;
 pub mod host;
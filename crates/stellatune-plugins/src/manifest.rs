@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
+use crate::compat::check_compatibility;
 use crate::error::{ErrorContext, Result};
 use serde::{Deserialize, Serialize};
 use tracing::warn;
@@ -16,6 +17,9 @@ pub enum PluginInstallState {
     Installed,
     PendingUninstall,
     DeleteFailed,
+    /// Installed, but the manifest's host/WIT constraints or component worlds do not
+    /// match this host. The plugin is never activated.
+    Incompatible,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -26,6 +30,20 @@ pub enum AbilityKind {
     Lyrics,
     OutputSink,
     Dsp,
+    PlaybackListener,
+}
+
+impl AbilityKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Decoder => "decoder",
+            Self::Source => "source",
+            Self::Lyrics => "lyrics",
+            Self::OutputSink => "output_sink",
+            Self::Dsp => "dsp",
+            Self::PlaybackListener => "playback_listener",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -64,6 +82,38 @@ pub struct ComponentSpec {
     pub abilities: Vec<AbilitySpec>,
 }
 
+/// Semver requirements a plugin places on the host, e.g. `{ "host": ">=0.3", "wit": "^0.2" }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct PluginRequirements {
+    /// Requirement on the app version set through [`crate::compat::set_host_version`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Requirement on the `stellatune:plugin` WIT package version the host links.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wit: Option<String>,
+}
+
+impl PluginRequirements {
+    pub fn is_empty(&self) -> bool {
+        self.host.is_none() && self.wit.is_none()
+    }
+}
+
+/// Host services a plugin asks to be allowed, e.g. `{ "http": ["api.example.com"] }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct PluginPermissions {
+    /// Hosts reachable through the `http-client` import; `*.example.com` matches
+    /// subdomains. Omitted means unrestricted, an empty list denies all HTTP.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<Vec<String>>,
+}
+
+impl PluginPermissions {
+    pub fn is_empty(&self) -> bool {
+        self.http.is_none()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct WasmPluginManifest {
     pub schema_version: u32,
@@ -71,6 +121,10 @@ pub struct WasmPluginManifest {
     pub name: String,
     pub version: String,
     pub api_version: u32,
+    #[serde(default, skip_serializing_if = "PluginRequirements::is_empty")]
+    pub requires: PluginRequirements,
+    #[serde(default, skip_serializing_if = "PluginPermissions::is_empty")]
+    pub permissions: PluginPermissions,
     pub components: Vec<ComponentSpec>,
 }
 
@@ -97,6 +151,8 @@ pub struct DiscoveredPlugin {
     pub root_dir: PathBuf,
     pub manifest_path: PathBuf,
     pub manifest: WasmPluginManifest,
+    /// Set when [`crate::compat::check_compatibility`] rejects the manifest.
+    pub incompatible_reason: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -145,6 +201,12 @@ pub fn validate_manifest(manifest: &WasmPluginManifest, root_dir: &Path) -> Resu
     if manifest.components.is_empty() {
         return Err(crate::op_error!("manifest.components is empty"));
     }
+    if let Some(requirement) = manifest.requires.host.as_deref() {
+        crate::compat::parse_requirement("requires.host", requirement)?;
+    }
+    if let Some(requirement) = manifest.requires.wit.as_deref() {
+        crate::compat::parse_requirement("requires.wit", requirement)?;
+    }
 
     let mut component_ids = HashSet::<String>::new();
     let mut ability_keys = HashSet::<(AbilityKind, String)>::new();
@@ -351,10 +413,22 @@ pub fn discover_plugins(dir: impl AsRef<Path>) -> Result<Vec<DiscoveredPlugin>>
                 continue;
             },
         };
+        let incompatible_reason = check_compatibility(&manifest)
+            .err()
+            .map(|error| error.to_string());
+        if let Some(reason) = &incompatible_reason {
+            warn!(
+                target: "stellatune_plugins::discover",
+                plugin_id = %manifest.id,
+                manifest_path = %manifest_path.display(),
+                "plugin is incompatible with this host: {reason}"
+            );
+        }
         out.push(DiscoveredPlugin {
             root_dir,
             manifest_path,
             manifest,
+            incompatible_reason,
         });
     }
     Ok(out)
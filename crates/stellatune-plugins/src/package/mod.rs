@@ -5,6 +5,7 @@ use crate::error::{ErrorContext, Result};
 use serde::Serialize;
 use tracing::{info, warn};
 
+use crate::compat::check_compatibility;
 use crate::manifest::{
     INSTALL_RECEIPT_FILE_NAME, PLUGIN_MANIFEST_FILE_NAME, PluginInstallReceipt, PluginInstallState,
     UninstallPendingMarker, WasmPluginManifest, discover_pending_uninstalls, discover_plugins,
@@ -23,6 +24,7 @@ pub struct InstalledPlugin {
     pub install_state: PluginInstallState,
     pub uninstall_retry_count: u32,
     pub uninstall_last_error: Option<String>,
+    pub incompatible_reason: Option<String>,
 }
 
 pub fn install_from_artifact(
@@ -99,6 +101,7 @@ pub fn install_from_artifact(
         .parent()
         .ok_or_else(|| crate::op_error!("invalid manifest path: {}", manifest_path.display()))?;
     validate_manifest(&manifest, package_root)?;
+    check_compatibility(&manifest)?;
 
     let install_root = plugins_dir.join(&manifest.id);
     if install_root.exists() {
@@ -123,6 +126,7 @@ pub fn install_from_artifact(
         install_state: PluginInstallState::Installed,
         uninstall_retry_count: 0,
         uninstall_last_error: None,
+        incompatible_reason: None,
     };
     info!(
         target: "stellatune_plugins::install",
@@ -148,9 +152,14 @@ pub fn list_installed(plugins_dir: impl AsRef<Path>) -> Result<Vec<InstalledPlug
             root_dir: discovered.root_dir.clone(),
             manifest_path: discovered.manifest_path.clone(),
             component_count: discovered.manifest.components.len(),
-            install_state: PluginInstallState::Installed,
+            install_state: if discovered.incompatible_reason.is_some() {
+                PluginInstallState::Incompatible
+            } else {
+                PluginInstallState::Installed
+            },
             uninstall_retry_count: 0,
             uninstall_last_error: None,
+            incompatible_reason: discovered.incompatible_reason.clone(),
         });
     }
 
@@ -176,6 +185,7 @@ pub fn list_installed(plugins_dir: impl AsRef<Path>) -> Result<Vec<InstalledPlug
             install_state: pending.marker.state,
             uninstall_retry_count: pending.marker.retry_count,
             uninstall_last_error: pending.marker.last_error.clone(),
+            incompatible_reason: None,
         });
     }
 
@@ -0,0 +1,65 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use parking_lot::Mutex;
+
+use crate::runtime::model::RuntimePluginErrorRecord;
+
+const RECENT_PLUGIN_ERRORS_CAPACITY: usize = 256;
+
+/// Fans out structured plugin errors and keeps a short in-memory history.
+///
+/// Subscribers that were created before the host had a place to persist errors can
+/// backfill from [`PluginErrorHub::recent`].
+#[derive(Default)]
+pub(crate) struct PluginErrorHub {
+    subscribers: Mutex<Vec<Sender<RuntimePluginErrorRecord>>>,
+    recent: Mutex<VecDeque<RuntimePluginErrorRecord>>,
+}
+
+impl PluginErrorHub {
+    pub(crate) fn subscribe(&self) -> Receiver<RuntimePluginErrorRecord> {
+        let (tx, rx) = mpsc::channel::<RuntimePluginErrorRecord>();
+        self.subscribers.lock().push(tx);
+        rx
+    }
+
+    /// Subscribes and returns the history up to that point, so every record is seen exactly
+    /// once across the two.
+    pub(crate) fn subscribe_with_backlog(
+        &self,
+    ) -> (
+        Vec<RuntimePluginErrorRecord>,
+        Receiver<RuntimePluginErrorRecord>,
+    ) {
+        let (tx, rx) = mpsc::channel::<RuntimePluginErrorRecord>();
+        let mut subscribers = self.subscribers.lock();
+        let backlog = self.recent.lock().iter().cloned().collect();
+        subscribers.push(tx);
+        (backlog, rx)
+    }
+
+    pub(crate) fn report(&self, record: RuntimePluginErrorRecord) {
+        // Holding the subscriber lock across both steps keeps `subscribe_with_backlog`
+        // from seeing a record in the history and again on its channel.
+        let mut subscribers = self.subscribers.lock();
+        {
+            let mut recent = self.recent.lock();
+            if recent.len() >= RECENT_PLUGIN_ERRORS_CAPACITY {
+                recent.pop_front();
+            }
+            recent.push_back(record.clone());
+        }
+        subscribers.retain(|sender| sender.send(record.clone()).is_ok());
+    }
+
+    pub(crate) fn recent(&self, plugin_id: Option<&str>) -> Vec<RuntimePluginErrorRecord> {
+        let plugin_id = plugin_id.map(str::trim).filter(|id| !id.is_empty());
+        self.recent
+            .lock()
+            .iter()
+            .filter(|record| plugin_id.is_none_or(|id| record.plugin_id == id))
+            .cloned()
+            .collect()
+    }
+}
@@ -1,7 +1,10 @@
 mod directives;
+mod errors;
 pub mod model;
 mod registry;
 pub mod service;
 
+pub use registry::install_descriptors_from_manifest;
+
 #[cfg(test)]
 mod tests;
@@ -1,8 +1,9 @@
 use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
 
-use crate::manifest::AbilityKind;
+use crate::manifest::{AbilityKind, PluginPermissions};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuntimePluginInfo {
@@ -12,6 +13,7 @@ pub struct RuntimePluginInfo {
     pub root_dir: PathBuf,
     pub manifest_path: PathBuf,
     pub component_count: usize,
+    pub permissions: PluginPermissions,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +46,112 @@ pub struct RuntimeLyricCandidate {
     pub artist: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimeBrowseNodeKind {
+    Folder,
+    Playlist,
+    Album,
+    Artist,
+    Track,
+}
+
+/// One entry of a source catalog listing. Non-track nodes are browsed by `id`; track
+/// nodes are opened by passing `track_json` to `open_stream_json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeBrowseNode {
+    pub kind: RuntimeBrowseNodeKind,
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    #[serde(default)]
+    pub artists: Vec<String>,
+    pub album: Option<String>,
+    pub duration_ms: Option<u64>,
+    pub child_count: Option<u32>,
+    pub artwork_url: Option<String>,
+    pub track_json: Option<String>,
+    #[serde(default)]
+    pub extras: Vec<RuntimeMetadataEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimePageRequest {
+    #[serde(default)]
+    pub cursor: Option<String>,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeBrowsePage {
+    pub nodes: Vec<RuntimeBrowseNode>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimeSearchRequest {
+    pub query: String,
+    #[serde(default)]
+    pub kinds: Vec<RuntimeBrowseNodeKind>,
+    pub page: RuntimePageRequest,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimeCatalogCapabilities {
+    pub browse: bool,
+    pub search: bool,
+    pub open_uri: bool,
+    pub search_kinds: Vec<RuntimeBrowseNodeKind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimeAuthState {
+    NotRequired,
+    LoggedOut,
+    LoggedIn,
+    Expired,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimeLoginMethod {
+    QrCode,
+    Url,
+    DeviceCode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimeAuthStatus {
+    pub state: RuntimeAuthState,
+    pub account: Option<String>,
+    pub expires_at_ms: Option<u64>,
+    #[serde(default)]
+    pub login_methods: Vec<RuntimeLoginMethod>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimeLoginChallenge {
+    pub method: RuntimeLoginMethod,
+    pub session_id: String,
+    pub url: Option<String>,
+    pub qr_payload: Option<String>,
+    pub user_code: Option<String>,
+    pub expires_at_ms: Option<u64>,
+    pub poll_interval_ms: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum RuntimeLoginProgress {
+    Waiting,
+    Confirming,
+    Completed { status: RuntimeAuthStatus },
+    Expired,
+    Failed { message: String },
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct RuntimeDecoderSessionHandle(pub u64);
 
@@ -109,6 +217,47 @@ pub struct RuntimeMediaMetadata {
     pub extras: Vec<RuntimeMetadataEntry>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuntimePlaybackTrackInfo {
+    pub track_token: String,
+    pub source_id: String,
+    pub track_id: String,
+    pub locator: String,
+    pub duration_ms: Option<u64>,
+    pub metadata: Option<RuntimeMediaMetadata>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimePlaybackSkipReason {
+    UserSwitch,
+    Stopped,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum RuntimePlaybackEvent {
+    TrackStarted {
+        track: RuntimePlaybackTrackInfo,
+    },
+    Progress {
+        track: RuntimePlaybackTrackInfo,
+        position_ms: u64,
+    },
+    Paused {
+        track: RuntimePlaybackTrackInfo,
+        position_ms: u64,
+    },
+    Finished {
+        track: RuntimePlaybackTrackInfo,
+    },
+    Skipped {
+        track: RuntimePlaybackTrackInfo,
+        position_ms: u64,
+        reason: RuntimePlaybackSkipReason,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RuntimeEncodedChunk {
     pub bytes: Vec<u8>,
@@ -254,6 +403,19 @@ pub enum RuntimePluginTransitionOutcome {
     Failed,
 }
 
+/// Outcome of loading or reloading a dev-mode plugin directory after its files changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeDevReloadEvent {
+    pub root_dir: PathBuf,
+    /// Unknown until the manifest has been read successfully once.
+    #[serde(default)]
+    pub plugin_id: Option<String>,
+    #[serde(default)]
+    pub changed_paths: Vec<PathBuf>,
+    pub outcome: RuntimePluginTransitionOutcome,
+    pub detail: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuntimePluginTransition {
     pub plugin_id: String,
@@ -272,6 +434,121 @@ pub enum RuntimePluginDirective {
     UpdateConfig { config_json: String },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimePluginErrorCode {
+    DuplicatePluginId,
+    Incompatible,
+    InstallFailed,
+    UninstallFailed,
+    InstanceCreateFailed,
+    OperationFailed,
+    AuthRequired,
+    AuthExpired,
+}
+
+impl RuntimePluginErrorCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::DuplicatePluginId => "duplicate_plugin_id",
+            Self::Incompatible => "incompatible",
+            Self::InstallFailed => "install_failed",
+            Self::UninstallFailed => "uninstall_failed",
+            Self::InstanceCreateFailed => "instance_create_failed",
+            Self::OperationFailed => "operation_failed",
+            Self::AuthRequired => "auth_required",
+            Self::AuthExpired => "auth_expired",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimePluginErrorAction {
+    Load,
+    Reload,
+    Disable,
+    Unload,
+    Operation,
+}
+
+impl RuntimePluginErrorAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Load => "load",
+            Self::Reload => "reload",
+            Self::Disable => "disable",
+            Self::Unload => "unload",
+            Self::Operation => "operation",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimePluginErrorSeverity {
+    Warning,
+    Error,
+}
+
+impl RuntimePluginErrorSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Structured record of a plugin failure, produced by sync and by capability calls.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimePluginErrorRecord {
+    pub code: RuntimePluginErrorCode,
+    pub plugin_id: String,
+    #[serde(default)]
+    pub capability_kind: Option<AbilityKind>,
+    #[serde(default)]
+    pub type_id: Option<String>,
+    pub action: RuntimePluginErrorAction,
+    pub detail: String,
+    pub severity: RuntimePluginErrorSeverity,
+    pub timestamp_ms: u64,
+}
+
+impl RuntimePluginErrorRecord {
+    pub fn new(
+        code: RuntimePluginErrorCode,
+        plugin_id: impl Into<String>,
+        action: RuntimePluginErrorAction,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self {
+            code,
+            plugin_id: plugin_id.into(),
+            capability_kind: None,
+            type_id: None,
+            action,
+            detail: detail.into(),
+            severity: RuntimePluginErrorSeverity::Error,
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_millis() as u64)
+                .unwrap_or(0),
+        }
+    }
+
+    pub fn with_capability(mut self, kind: AbilityKind, type_id: impl Into<String>) -> Self {
+        self.capability_kind = Some(kind);
+        self.type_id = Some(type_id.into());
+        self
+    }
+
+    pub fn with_severity(mut self, severity: RuntimePluginErrorSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RuntimeSyncReport {
     pub revision: u64,
@@ -279,5 +556,5 @@ pub struct RuntimeSyncReport {
     pub active_plugins: Vec<RuntimePluginInfo>,
     pub plugin_statuses: Vec<RuntimePluginStatus>,
     pub transitions: Vec<RuntimePluginTransition>,
-    pub errors: Vec<String>,
+    pub errors: Vec<RuntimePluginErrorRecord>,
 }
@@ -22,6 +22,12 @@ pub(crate) struct RuntimeRegistry {
     pub(crate) desired_states: BTreeMap<String, DesiredPluginState>,
     pub(crate) last_discovered_plugin_ids: BTreeSet<String>,
     pub(crate) last_errors_by_plugin: BTreeMap<String, String>,
+    /// Incompatibility already reported per plugin, so repeated syncs stay quiet until
+    /// the reason changes.
+    pub(crate) reported_incompatible: BTreeMap<String, String>,
+    /// Dev-mode plugins loaded from unpacked directories, keyed by plugin id. Sync leaves
+    /// them alone.
+    pub(crate) dev_plugin_roots: BTreeMap<String, PathBuf>,
 }
 
 pub(crate) fn build_plugin_statuses(
@@ -64,6 +70,17 @@ pub(crate) fn build_plugin_statuses(
     out
 }
 
+/// Plugin info and capability descriptors for installing a manifest directly into a
+/// [`crate::executor::WasmPluginController`], without going through discovery.
+pub fn install_descriptors_from_manifest(
+    root_dir: PathBuf,
+    manifest_path: PathBuf,
+    manifest: WasmPluginManifest,
+) -> (RuntimePluginInfo, Vec<RuntimeCapabilityDescriptor>) {
+    let plugin = active_plugin_from_manifest(root_dir, manifest_path, manifest);
+    (plugin.info, plugin.capabilities)
+}
+
 pub(crate) fn active_plugin_from_manifest(
     root_dir: PathBuf,
     manifest_path: PathBuf,
@@ -76,6 +93,7 @@ pub(crate) fn active_plugin_from_manifest(
         root_dir,
         manifest_path,
         component_count: manifest.components.len(),
+        permissions: manifest.permissions.clone(),
     };
     let capabilities = manifest
         .components
@@ -220,6 +238,8 @@ mod tests {
             name: "Test Plugin".to_string(),
             version: version.to_string(),
             api_version: 1,
+            requires: Default::default(),
+            permissions: Default::default(),
             components: vec![ComponentSpec {
                 id: "main".to_string(),
                 path: "plugin.wasm".to_string(),
@@ -2,14 +2,16 @@ use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use parking_lot::RwLock;
 
 use crate::executor::WasmPluginController;
 use crate::manifest::discover_plugins;
 use crate::runtime::directives::PluginDirectiveHub;
+use crate::runtime::errors::PluginErrorHub;
 use crate::runtime::model::{
-    DesiredPluginState, PluginDisableReason, RuntimePluginDirective, RuntimePluginLifecycleState,
+    DesiredPluginState, PluginDisableReason, RuntimePluginDirective, RuntimePluginErrorAction,
+    RuntimePluginErrorCode, RuntimePluginErrorRecord, RuntimePluginLifecycleState,
     RuntimePluginTransition, RuntimePluginTransitionOutcome, RuntimePluginTransitionTrigger,
     RuntimeSyncReport,
 };
@@ -17,13 +19,17 @@ use crate::runtime::registry::{
     ActivePlugin, RuntimeRegistry, active_plugin_from_manifest, build_plugin_statuses,
 };
 
+mod dev;
 mod management;
 mod query;
 
+pub use dev::DevPluginWatcher;
+
 pub struct WasmPluginRuntime<C: WasmPluginController> {
     registry: Arc<RwLock<RuntimeRegistry>>,
     controller: Arc<C>,
     directives: Arc<PluginDirectiveHub>,
+    errors: Arc<PluginErrorHub>,
 }
 
 impl<C: WasmPluginController> Clone for WasmPluginRuntime<C> {
@@ -32,6 +38,7 @@ impl<C: WasmPluginController> Clone for WasmPluginRuntime<C> {
             registry: Arc::clone(&self.registry),
             controller: Arc::clone(&self.controller),
             directives: Arc::clone(&self.directives),
+            errors: Arc::clone(&self.errors),
         }
     }
 }
@@ -42,6 +49,7 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
             registry: Arc::new(RwLock::new(RuntimeRegistry::default())),
             controller,
             directives: Arc::new(PluginDirectiveHub::default()),
+            errors: Arc::new(PluginErrorHub::default()),
         }
     }
 
@@ -73,6 +81,37 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
         self.directives.notify_plugin(plugin_id, directive);
     }
 
+    pub fn subscribe_plugin_errors(&self) -> std::sync::mpsc::Receiver<RuntimePluginErrorRecord> {
+        self.errors.subscribe()
+    }
+
+    /// Like [`Self::subscribe_plugin_errors`], plus the recent history that predates the
+    /// subscription.
+    pub fn subscribe_plugin_errors_with_backlog(
+        &self,
+    ) -> (
+        Vec<RuntimePluginErrorRecord>,
+        std::sync::mpsc::Receiver<RuntimePluginErrorRecord>,
+    ) {
+        self.errors.subscribe_with_backlog()
+    }
+
+    pub fn report_plugin_error(&self, record: RuntimePluginErrorRecord) {
+        tracing::warn!(
+            target: "stellatune_plugins::runtime",
+            plugin_id = %record.plugin_id,
+            code = record.code.as_str(),
+            action = record.action.as_str(),
+            error = %record.detail,
+            "plugin error reported"
+        );
+        self.errors.report(record);
+    }
+
+    pub fn recent_plugin_errors(&self, plugin_id: Option<&str>) -> Vec<RuntimePluginErrorRecord> {
+        self.errors.recent(plugin_id)
+    }
+
     pub fn sync_plugins(&self, dir: impl AsRef<Path>) -> Result<RuntimeSyncReport> {
         let dir = dir.as_ref();
         tracing::debug!(
@@ -84,9 +123,22 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
         let discovered_plugins = discovered.len();
 
         let mut discovered_active = BTreeMap::<String, ActivePlugin>::new();
-        let mut errors = Vec::<String>::new();
+        let mut errors = Vec::<RuntimePluginErrorRecord>::new();
         let mut errors_by_plugin = BTreeMap::<String, String>::new();
         for item in discovered {
+            if let Some(reason) = item.incompatible_reason {
+                record_plugin_error(
+                    &mut errors,
+                    &mut errors_by_plugin,
+                    RuntimePluginErrorRecord::new(
+                        RuntimePluginErrorCode::Incompatible,
+                        &item.manifest.id,
+                        RuntimePluginErrorAction::Load,
+                        reason,
+                    ),
+                );
+                continue;
+            }
             let active = active_plugin_from_manifest(
                 item.root_dir.clone(),
                 item.manifest_path.clone(),
@@ -97,21 +149,36 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
                 record_plugin_error(
                     &mut errors,
                     &mut errors_by_plugin,
-                    &plugin_id,
-                    format!("duplicate plugin id discovered during sync: {}", plugin_id),
+                    RuntimePluginErrorRecord::new(
+                        RuntimePluginErrorCode::DuplicatePluginId,
+                        &plugin_id,
+                        RuntimePluginErrorAction::Load,
+                        format!("duplicate plugin id discovered during sync: {}", plugin_id),
+                    ),
                 );
                 continue;
             }
             discovered_active.insert(plugin_id, active);
         }
 
-        let (previous_active, desired_states, previous_discovered_ids, previous_errors_by_plugin) = {
+        let (
+            previous_active,
+            desired_states,
+            previous_discovered_ids,
+            previous_errors_by_plugin,
+            dev_plugin_ids,
+        ) = {
             let state = self.registry.read();
             (
                 state.active_plugins.clone(),
                 state.desired_states.clone(),
                 state.last_discovered_plugin_ids.clone(),
                 state.last_errors_by_plugin.clone(),
+                state
+                    .dev_plugin_roots
+                    .keys()
+                    .cloned()
+                    .collect::<BTreeSet<_>>(),
             )
         };
 
@@ -122,6 +189,10 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
         let mut deactivated = HashSet::<String>::new();
 
         for (plugin_id, discovered_plugin) in &discovered_active {
+            if dev_plugin_ids.contains(plugin_id) {
+                // A dev-mode directory shadows the installed copy until it is unloaded.
+                continue;
+            }
             let previous_lifecycle = lifecycle_state_from_snapshot(
                 plugin_id,
                 &previous_active,
@@ -225,11 +296,27 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
                 .controller
                 .install_plugin(&discovered_plugin.info, &discovered_plugin.capabilities)
             {
+                let action = if trigger == RuntimePluginTransitionTrigger::ReloadChanged {
+                    RuntimePluginErrorAction::Reload
+                } else {
+                    RuntimePluginErrorAction::Load
+                };
+                // Components that do not link against the host are reported like
+                // manifest-level incompatibilities.
+                let code = if matches!(error, Error::Unsupported { .. }) {
+                    RuntimePluginErrorCode::Incompatible
+                } else {
+                    RuntimePluginErrorCode::InstallFailed
+                };
                 record_plugin_error(
                     &mut errors,
                     &mut errors_by_plugin,
-                    plugin_id,
-                    format!("install plugin `{}` failed: {:#}", plugin_id, error),
+                    RuntimePluginErrorRecord::new(
+                        code,
+                        plugin_id,
+                        action,
+                        format!("install plugin `{}` failed: {:#}", plugin_id, error),
+                    ),
                 );
                 transitions.push(RuntimePluginTransition {
                     plugin_id: plugin_id.clone(),
@@ -259,7 +346,10 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
         }
 
         for plugin_id in previous_active.keys() {
-            if !next_active.contains_key(plugin_id) || discovered_active.contains_key(plugin_id) {
+            if !next_active.contains_key(plugin_id)
+                || discovered_active.contains_key(plugin_id)
+                || dev_plugin_ids.contains(plugin_id)
+            {
                 continue;
             }
             let previous_lifecycle = lifecycle_state_from_snapshot(
@@ -306,6 +396,12 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
         state.active_plugins = next_active;
         state.last_discovered_plugin_ids = discovered_ids.clone();
         state.last_errors_by_plugin = errors_by_plugin.clone();
+        let incompatible = errors
+            .iter()
+            .filter(|record| record.code == RuntimePluginErrorCode::Incompatible)
+            .map(|record| (record.plugin_id.clone(), record.detail.clone()))
+            .collect::<BTreeMap<_, _>>();
+        let previously_reported = std::mem::replace(&mut state.reported_incompatible, incompatible);
 
         let mut active_plugins = state
             .active_plugins
@@ -411,6 +507,16 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
             );
         }
 
+        drop(state);
+        for record in &errors {
+            if record.code == RuntimePluginErrorCode::Incompatible
+                && previously_reported.get(&record.plugin_id) == Some(&record.detail)
+            {
+                continue;
+            }
+            self.errors.report(record.clone());
+        }
+
         Ok(RuntimeSyncReport {
             revision,
             discovered_plugins,
@@ -427,7 +533,7 @@ fn try_uninstall_plugin<C: WasmPluginController>(
     plugin_id: &str,
     reason: PluginDisableReason,
     deactivated: &mut HashSet<String>,
-    errors: &mut Vec<String>,
+    errors: &mut Vec<RuntimePluginErrorRecord>,
     errors_by_plugin: &mut BTreeMap<String, String>,
 ) -> bool {
     if deactivated.contains(plugin_id) {
@@ -439,11 +545,22 @@ fn try_uninstall_plugin<C: WasmPluginController>(
             true
         },
         Err(error) => {
+            let action = match reason {
+                PluginDisableReason::HostDisable => RuntimePluginErrorAction::Disable,
+                PluginDisableReason::Reload => RuntimePluginErrorAction::Reload,
+                PluginDisableReason::Unload | PluginDisableReason::Shutdown => {
+                    RuntimePluginErrorAction::Unload
+                },
+            };
             record_plugin_error(
                 errors,
                 errors_by_plugin,
-                plugin_id,
-                format!("uninstall plugin `{}` failed: {:#}", plugin_id, error),
+                RuntimePluginErrorRecord::new(
+                    RuntimePluginErrorCode::UninstallFailed,
+                    plugin_id,
+                    action,
+                    format!("uninstall plugin `{}` failed: {:#}", plugin_id, error),
+                ),
             );
             false
         },
@@ -451,27 +568,28 @@ fn try_uninstall_plugin<C: WasmPluginController>(
 }
 
 fn record_plugin_error(
-    errors: &mut Vec<String>,
+    errors: &mut Vec<RuntimePluginErrorRecord>,
     errors_by_plugin: &mut BTreeMap<String, String>,
-    plugin_id: &str,
-    message: String,
+    record: RuntimePluginErrorRecord,
 ) {
     tracing::warn!(
         target: "stellatune_plugins::runtime",
-        plugin_id = %plugin_id,
-        error = %message,
+        plugin_id = %record.plugin_id,
+        code = record.code.as_str(),
+        action = record.action.as_str(),
+        error = %record.detail,
         "plugin runtime sync error"
     );
-    errors.push(message.clone());
     errors_by_plugin
-        .entry(plugin_id.to_string())
+        .entry(record.plugin_id.clone())
         .and_modify(|existing| {
             if !existing.is_empty() {
                 existing.push_str("; ");
             }
-            existing.push_str(&message);
+            existing.push_str(&record.detail);
         })
-        .or_insert(message);
+        .or_insert_with(|| record.detail.clone());
+    errors.push(record);
 }
 
 fn lifecycle_state_from_snapshot(
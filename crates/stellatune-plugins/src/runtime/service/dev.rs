@@ -0,0 +1,304 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::compat::check_compatibility;
+use crate::error::{Error, Result};
+use crate::executor::WasmPluginController;
+use crate::manifest::{PLUGIN_MANIFEST_FILE_NAME, read_manifest};
+use crate::runtime::model::{
+    PluginDisableReason, RuntimeDevReloadEvent, RuntimePluginDirective, RuntimePluginErrorAction,
+    RuntimePluginErrorCode, RuntimePluginErrorRecord, RuntimePluginInfo,
+    RuntimePluginTransitionOutcome,
+};
+use crate::runtime::registry::{ActivePlugin, active_plugin_from_manifest};
+use crate::runtime::service::WasmPluginRuntime;
+
+const DEV_WATCH_DEBOUNCE_MS: u64 = 300;
+
+impl<C: WasmPluginController> WasmPluginRuntime<C> {
+    /// Loads an unpacked plugin directory without an install receipt. Dev plugins are left
+    /// alone by [`Self::sync_plugins`] and shadow an installed plugin with the same id.
+    pub fn load_dev_plugin(&self, root_dir: impl AsRef<Path>) -> Result<RuntimePluginInfo> {
+        let root_dir = root_dir.as_ref();
+        let active = read_dev_plugin(root_dir)?;
+        self.controller
+            .reload_components(&active.info, &active.capabilities)?;
+        let plugin_id = active.info.id.clone();
+        self.registry
+            .write()
+            .dev_plugin_roots
+            .insert(plugin_id.clone(), root_dir.to_path_buf());
+        self.commit_dev_plugin(active.clone());
+        tracing::info!(
+            target: "stellatune_plugins::runtime",
+            plugin_id = %plugin_id,
+            root_dir = %root_dir.display(),
+            "dev plugin loaded"
+        );
+        Ok(active.info)
+    }
+
+    /// Re-reads a dev plugin's manifest, recompiles its components and rebuilds live
+    /// instances, carrying their exported state over. On failure the error is reported and
+    /// instances keep running the previous build.
+    pub fn reload_dev_plugin(&self, plugin_id: &str) -> Result<RuntimePluginInfo> {
+        let plugin_id = plugin_id.trim();
+        let Some(root_dir) = self
+            .registry
+            .read()
+            .dev_plugin_roots
+            .get(plugin_id)
+            .cloned()
+        else {
+            return Err(Error::not_found("dev plugin", plugin_id));
+        };
+        let reloaded = read_dev_plugin(&root_dir).and_then(|active| {
+            if active.info.id != plugin_id {
+                return Err(crate::op_error!(
+                    "dev plugin id changed from `{plugin_id}` to `{}`; unload it and load the directory again",
+                    active.info.id
+                ));
+            }
+            self.controller
+                .reload_components(&active.info, &active.capabilities)?;
+            Ok(active)
+        });
+        match reloaded {
+            Ok(active) => {
+                self.commit_dev_plugin(active.clone());
+                tracing::info!(
+                    target: "stellatune_plugins::runtime",
+                    plugin_id = %plugin_id,
+                    "dev plugin reloaded"
+                );
+                Ok(active.info)
+            },
+            Err(error) => {
+                self.registry
+                    .write()
+                    .last_errors_by_plugin
+                    .insert(plugin_id.to_string(), error.to_string());
+                self.report_plugin_error(RuntimePluginErrorRecord::new(
+                    RuntimePluginErrorCode::InstallFailed,
+                    plugin_id,
+                    RuntimePluginErrorAction::Reload,
+                    format!("reload dev plugin `{plugin_id}` failed: {error:#}"),
+                ));
+                Err(error)
+            },
+        }
+    }
+
+    /// Unloads a dev plugin. An installed copy with the same id comes back on the next sync.
+    pub fn unload_dev_plugin(&self, plugin_id: &str) -> Result<()> {
+        let plugin_id = plugin_id.trim();
+        if !self
+            .registry
+            .read()
+            .dev_plugin_roots
+            .contains_key(plugin_id)
+        {
+            return Ok(());
+        }
+        self.controller
+            .uninstall_plugin(plugin_id, PluginDisableReason::Unload)?;
+        self.notify_plugin(
+            plugin_id,
+            RuntimePluginDirective::Destroy {
+                reason: PluginDisableReason::Unload,
+            },
+        );
+        let mut state = self.registry.write();
+        state.revision = state.revision.saturating_add(1);
+        state.dev_plugin_roots.remove(plugin_id);
+        state.active_plugins.remove(plugin_id);
+        state.last_errors_by_plugin.remove(plugin_id);
+        Ok(())
+    }
+
+    pub fn dev_plugin_ids(&self) -> Vec<String> {
+        self.registry
+            .read()
+            .dev_plugin_roots
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    fn commit_dev_plugin(&self, active: ActivePlugin) {
+        let plugin_id = active.info.id.clone();
+        {
+            let mut state = self.registry.write();
+            state.revision = state.revision.saturating_add(1);
+            state.active_plugins.insert(plugin_id.clone(), active);
+            state.last_errors_by_plugin.remove(&plugin_id);
+        }
+        // The controller already sent `Rebuild` to its own instances; only external
+        // subscribers still need to hear about it.
+        self.directives
+            .notify_plugin(&plugin_id, RuntimePluginDirective::Rebuild);
+    }
+
+    /// Loads the directory at `root_dir` as a dev plugin, then reloads it whenever a
+    /// `.wasm` file or `plugin.json` below it changes. Every attempt, including the
+    /// initial load, is reported on [`DevPluginWatcher::events`]; a failed initial load is
+    /// retried on the next change.
+    pub fn watch_dev_plugin(&self, root_dir: impl AsRef<Path>) -> Result<DevPluginWatcher>
+    where
+        C: 'static,
+    {
+        let root_dir = root_dir.as_ref().to_path_buf();
+        let (fs_tx, fs_rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(fs_tx)
+            .map_err(|error| Error::operation("watch dev plugin", error.to_string()))?;
+        watcher
+            .watch(&root_dir, RecursiveMode::Recursive)
+            .map_err(|error| Error::operation("watch dev plugin", error.to_string()))?;
+
+        let (events_tx, events_rx) = mpsc::channel();
+        let runtime = self.clone();
+        let thread_root = root_dir.clone();
+        std::thread::Builder::new()
+            .name("stellatune-dev-plugin-watch".to_string())
+            .spawn(move || run_dev_watch(&runtime, &thread_root, &fs_rx, &events_tx))
+            .map_err(|error| Error::io_at(&root_dir, error))?;
+
+        Ok(DevPluginWatcher {
+            _watcher: watcher,
+            root_dir,
+            events: events_rx,
+        })
+    }
+}
+
+/// Watches one dev plugin directory. Dropping it stops watching; the plugin itself stays
+/// loaded until [`WasmPluginRuntime::unload_dev_plugin`].
+pub struct DevPluginWatcher {
+    _watcher: RecommendedWatcher,
+    root_dir: PathBuf,
+    events: Receiver<RuntimeDevReloadEvent>,
+}
+
+impl DevPluginWatcher {
+    pub fn root_dir(&self) -> &Path {
+        &self.root_dir
+    }
+
+    pub fn events(&self) -> &Receiver<RuntimeDevReloadEvent> {
+        &self.events
+    }
+}
+
+fn read_dev_plugin(root_dir: &Path) -> Result<ActivePlugin> {
+    let manifest_path = root_dir.join(PLUGIN_MANIFEST_FILE_NAME);
+    let manifest = read_manifest(&manifest_path)?;
+    check_compatibility(&manifest)?;
+    Ok(active_plugin_from_manifest(
+        root_dir.to_path_buf(),
+        manifest_path,
+        manifest,
+    ))
+}
+
+fn is_dev_reload_trigger(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "wasm")
+        || path
+            .file_name()
+            .is_some_and(|name| name == PLUGIN_MANIFEST_FILE_NAME)
+}
+
+fn collect_changed_paths(event: notify::Result<notify::Event>, changed: &mut BTreeSet<PathBuf>) {
+    let Ok(event) = event else {
+        return;
+    };
+    if matches!(event.kind, EventKind::Access(_)) {
+        return;
+    }
+    changed.extend(
+        event
+            .paths
+            .into_iter()
+            .filter(|path| is_dev_reload_trigger(path)),
+    );
+}
+
+fn run_dev_watch<C: WasmPluginController>(
+    runtime: &WasmPluginRuntime<C>,
+    root_dir: &Path,
+    fs_rx: &Receiver<notify::Result<notify::Event>>,
+    events_tx: &Sender<RuntimeDevReloadEvent>,
+) {
+    let mut plugin_id = None;
+    let _ = events_tx.send(apply_dev_change(
+        runtime,
+        root_dir,
+        &mut plugin_id,
+        Vec::new(),
+    ));
+    loop {
+        let mut changed = BTreeSet::new();
+        // Editors and build tools write in bursts; wait for the directory to settle.
+        match fs_rx.recv() {
+            Ok(event) => collect_changed_paths(event, &mut changed),
+            Err(_) => return,
+        }
+        let deadline = Instant::now() + Duration::from_millis(DEV_WATCH_DEBOUNCE_MS);
+        loop {
+            match fs_rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(event) => collect_changed_paths(event, &mut changed),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+        if changed.is_empty() {
+            continue;
+        }
+        let event = apply_dev_change(
+            runtime,
+            root_dir,
+            &mut plugin_id,
+            changed.into_iter().collect(),
+        );
+        let _ = events_tx.send(event);
+    }
+}
+
+fn apply_dev_change<C: WasmPluginController>(
+    runtime: &WasmPluginRuntime<C>,
+    root_dir: &Path,
+    plugin_id: &mut Option<String>,
+    changed_paths: Vec<PathBuf>,
+) -> RuntimeDevReloadEvent {
+    let result = match plugin_id.as_deref() {
+        Some(id) => runtime.reload_dev_plugin(id),
+        None => runtime.load_dev_plugin(root_dir).inspect_err(|error| {
+            tracing::warn!(
+                target: "stellatune_plugins::runtime",
+                root_dir = %root_dir.display(),
+                %error,
+                "dev plugin load failed"
+            );
+        }),
+    };
+    let (outcome, detail) = match result {
+        Ok(info) => {
+            *plugin_id = Some(info.id);
+            (
+                RuntimePluginTransitionOutcome::Applied,
+                "reloaded".to_string(),
+            )
+        },
+        Err(error) => (RuntimePluginTransitionOutcome::Failed, format!("{error:#}")),
+    };
+    RuntimeDevReloadEvent {
+        root_dir: root_dir.to_path_buf(),
+        plugin_id: plugin_id.clone(),
+        changed_paths,
+        outcome,
+        detail,
+    }
+}
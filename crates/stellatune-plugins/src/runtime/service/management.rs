@@ -44,8 +44,10 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
         state
             .desired_states
             .insert(plugin_id.to_string(), desired_state);
-        if desired_state == DesiredPluginState::Disabled {
-            state.active_plugins.remove(plugin_id);
+        if desired_state == DesiredPluginState::Disabled
+            && state.active_plugins.remove(plugin_id).is_some()
+        {
+            state.revision = state.revision.saturating_add(1);
         }
         tracing::info!(
             target: "stellatune_plugins::runtime",
@@ -114,7 +116,9 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
         let mut state = self.registry.write();
         state.desired_states = normalized;
         for plugin_id in deactivated_ids {
-            state.active_plugins.remove(&plugin_id);
+            if state.active_plugins.remove(&plugin_id).is_some() {
+                state.revision = state.revision.saturating_add(1);
+            }
         }
 
         if deactivation_errors.is_empty() {
@@ -168,6 +172,9 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
 
         let mut state = self.registry.write();
         let removed = state.active_plugins.remove(plugin_id).is_some();
+        if removed {
+            state.revision = state.revision.saturating_add(1);
+        }
         tracing::info!(
             target: "stellatune_plugins::runtime",
             plugin_id = %plugin_id,
@@ -218,7 +225,9 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
 
         let mut state = self.registry.write();
         for plugin_id in &deactivated {
-            state.active_plugins.remove(plugin_id);
+            if state.active_plugins.remove(plugin_id).is_some() {
+                state.revision = state.revision.saturating_add(1);
+            }
         }
 
         if errors.is_empty() {
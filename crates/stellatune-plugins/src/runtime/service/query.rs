@@ -41,6 +41,12 @@ impl<C: WasmPluginController> WasmPluginRuntime<C> {
         out
     }
 
+    /// Counter bumped whenever the set of active plugins changes, so callers can cache
+    /// anything derived from it.
+    pub fn revision(&self) -> u64 {
+        self.registry.read().revision
+    }
+
     pub fn active_ids(&self) -> Vec<String> {
         let state = self.registry.read();
         let mut out = state.active_plugins.keys().cloned().collect::<Vec<_>>();
@@ -12,8 +12,10 @@ use crate::manifest::{
 };
 use crate::runtime::model::DesiredPluginState;
 use crate::runtime::model::{
-    PluginDisableReason, RuntimeCapabilityDescriptor, RuntimePluginInfo,
-    RuntimePluginLifecycleState, RuntimePluginTransitionOutcome, RuntimePluginTransitionTrigger,
+    PluginDisableReason, RuntimeCapabilityDescriptor, RuntimePluginErrorAction,
+    RuntimePluginErrorCode, RuntimePluginErrorRecord, RuntimePluginErrorSeverity,
+    RuntimePluginInfo, RuntimePluginLifecycleState, RuntimePluginTransitionOutcome,
+    RuntimePluginTransitionTrigger,
 };
 use crate::runtime::service::WasmPluginRuntime;
 
@@ -56,6 +58,15 @@ impl WasmPluginController for RecordingLifecycleHost {
         Ok(())
     }
 
+    fn reload_components(
+        &self,
+        plugin: &RuntimePluginInfo,
+        _capabilities: &[RuntimeCapabilityDescriptor],
+    ) -> Result<()> {
+        self.events.lock().push(format!("reload:{}", plugin.id));
+        Ok(())
+    }
+
     fn dispatch_directive(
         &self,
         _plugin_id: &str,
@@ -75,7 +86,7 @@ fn install_test_plugin(plugins_dir: &Path, plugin_id: &str, version: &str) -> Re
         plugin_id,
         version,
         AbilityKind::OutputSink,
-        "audio-plugin",
+        stellatune_host_bindings::WORLD_OUTPUT_SINK_PLUGIN,
         "test.sink",
     )
 }
@@ -100,6 +111,8 @@ fn install_test_plugin_with_ability(
         name: format!("Test {plugin_id}"),
         version: version.to_string(),
         api_version: 1,
+        requires: Default::default(),
+        permissions: Default::default(),
         components: vec![ComponentSpec {
             id: "main".to_string(),
             path: component_rel_path.to_string(),
@@ -154,6 +167,32 @@ fn sync_activates_new_plugin() {
     assert_eq!(host.events(), vec!["install:demo".to_string()]);
 }
 
+#[test]
+fn sync_skips_incompatible_plugin_and_reports_error() {
+    let temp = tempfile::tempdir().expect("create tempdir");
+    let plugins_dir = temp.path().join("plugins");
+    std::fs::create_dir_all(&plugins_dir).expect("create plugins dir");
+    install_test_plugin_with_ability(
+        &plugins_dir,
+        "legacy",
+        "1.0.0",
+        AbilityKind::OutputSink,
+        "stellatune:plugin/output-sink-plugin@9.0.0",
+        "test.sink",
+    )
+    .expect("install incompatible plugin");
+
+    let host = Arc::new(RecordingLifecycleHost::default());
+    let runtime = WasmPluginRuntime::new(host.clone());
+    let report = runtime.sync_plugins(&plugins_dir).expect("sync runtime");
+
+    assert!(report.active_plugins.is_empty());
+    assert!(host.events().is_empty());
+    assert!(report.errors.iter().any(|error| {
+        error.code == RuntimePluginErrorCode::Incompatible && error.plugin_id == "legacy"
+    }));
+}
+
 #[test]
 fn disable_desired_state_deactivates_plugin() {
     let temp = tempfile::tempdir().expect("create tempdir");
@@ -310,3 +349,185 @@ fn failed_reload_keeps_previous_plugin_active_and_records_error() {
             .is_some_and(|value| !value.is_empty())
     );
 }
+
+#[test]
+fn failed_reload_reports_structured_error_to_subscribers() {
+    let temp = tempfile::tempdir().expect("create tempdir");
+    let plugins_dir = temp.path().join("plugins");
+    std::fs::create_dir_all(&plugins_dir).expect("create plugins dir");
+    install_test_plugin(&plugins_dir, "demo", "1.0.0").expect("install test plugin");
+
+    let host = Arc::new(RecordingLifecycleHost::default());
+    let runtime = WasmPluginRuntime::new(host.clone());
+    let errors = runtime.subscribe_plugin_errors();
+    runtime.sync_plugins(&plugins_dir).expect("first sync");
+
+    update_plugin_version(&plugins_dir, "demo", "2.0.0").expect("update version");
+    host.set_fail_uninstall(true);
+    let report = runtime
+        .sync_plugins(&plugins_dir)
+        .expect("reload sync with forced failure");
+
+    assert_eq!(report.errors.len(), 1);
+    let record = &report.errors[0];
+    assert_eq!(record.plugin_id, "demo");
+    assert_eq!(record.code, RuntimePluginErrorCode::UninstallFailed);
+    assert_eq!(record.action, RuntimePluginErrorAction::Reload);
+    assert_eq!(record.severity, RuntimePluginErrorSeverity::Error);
+    assert!(record.timestamp_ms > 0);
+
+    assert_eq!(errors.try_recv().expect("error delivered"), *record);
+    assert_eq!(
+        runtime.recent_plugin_errors(Some("demo")),
+        vec![record.clone()]
+    );
+    assert!(runtime.recent_plugin_errors(Some("other")).is_empty());
+}
+
+#[test]
+fn reported_operation_error_keeps_capability_context() {
+    let host = Arc::new(RecordingLifecycleHost::default());
+    let runtime = WasmPluginRuntime::new(host);
+    let errors = runtime.subscribe_plugin_errors();
+
+    runtime.report_plugin_error(
+        RuntimePluginErrorRecord::new(
+            RuntimePluginErrorCode::OperationFailed,
+            "demo",
+            RuntimePluginErrorAction::Operation,
+            "on-event failed",
+        )
+        .with_capability(AbilityKind::PlaybackListener, "scrobbler")
+        .with_severity(RuntimePluginErrorSeverity::Warning),
+    );
+
+    let record = errors.try_recv().expect("error delivered");
+    assert_eq!(record.capability_kind, Some(AbilityKind::PlaybackListener));
+    assert_eq!(record.type_id.as_deref(), Some("scrobbler"));
+    assert_eq!(record.severity, RuntimePluginErrorSeverity::Warning);
+}
+
+fn active_version(runtime: &WasmPluginRuntime<RecordingLifecycleHost>, plugin_id: &str) -> String {
+    runtime
+        .active_plugins()
+        .into_iter()
+        .find(|plugin| plugin.id == plugin_id)
+        .map(|plugin| plugin.version)
+        .unwrap_or_default()
+}
+
+#[test]
+fn dev_plugin_shadows_installed_copy_across_syncs() {
+    let temp = tempfile::tempdir().expect("create tempdir");
+    let plugins_dir = temp.path().join("plugins");
+    let dev_dir = temp.path().join("dev");
+    install_test_plugin(&plugins_dir, "demo", "1.0.0").expect("install test plugin");
+    install_test_plugin(&dev_dir, "demo", "2.0.0").expect("write dev plugin");
+
+    let host = Arc::new(RecordingLifecycleHost::default());
+    let runtime = WasmPluginRuntime::new(host.clone());
+    runtime.sync_plugins(&plugins_dir).expect("initial sync");
+    runtime
+        .load_dev_plugin(dev_dir.join("demo"))
+        .expect("load dev plugin");
+    assert_eq!(active_version(&runtime, "demo"), "2.0.0");
+    assert_eq!(runtime.dev_plugin_ids(), vec!["demo".to_string()]);
+
+    update_plugin_version(&plugins_dir, "demo", "1.1.0").expect("update installed plugin");
+    runtime
+        .sync_plugins(&plugins_dir)
+        .expect("sync with installed copy");
+    runtime
+        .sync_plugins(temp.path().join("empty"))
+        .expect("sync without installed copy");
+    assert_eq!(active_version(&runtime, "demo"), "2.0.0");
+    assert_eq!(
+        host.events(),
+        vec!["install:demo".to_string(), "reload:demo".to_string()]
+    );
+
+    runtime
+        .unload_dev_plugin("demo")
+        .expect("unload dev plugin");
+    assert!(runtime.active_ids().is_empty());
+    runtime
+        .sync_plugins(&plugins_dir)
+        .expect("sync after unload");
+    assert_eq!(active_version(&runtime, "demo"), "1.1.0");
+    assert_eq!(
+        host.events()[2..],
+        [
+            "uninstall:demo:Unload".to_string(),
+            "install:demo".to_string()
+        ]
+    );
+}
+
+#[test]
+fn failed_dev_reload_keeps_previous_build_and_reports_error() {
+    let temp = tempfile::tempdir().expect("create tempdir");
+    install_test_plugin(temp.path(), "demo", "1.0.0").expect("write dev plugin");
+    let manifest_path = temp.path().join("demo").join(PLUGIN_MANIFEST_FILE_NAME);
+
+    let host = Arc::new(RecordingLifecycleHost::default());
+    let runtime = WasmPluginRuntime::new(host.clone());
+    let errors = runtime.subscribe_plugin_errors();
+    runtime
+        .load_dev_plugin(temp.path().join("demo"))
+        .expect("load dev plugin");
+
+    let valid_manifest = std::fs::read_to_string(&manifest_path).expect("read manifest");
+    std::fs::write(&manifest_path, "{ not json").expect("break manifest");
+    assert!(runtime.reload_dev_plugin("demo").is_err());
+    assert_eq!(active_version(&runtime, "demo"), "1.0.0");
+    let error = errors.try_recv().expect("reload error record");
+    assert_eq!(error.code, RuntimePluginErrorCode::InstallFailed);
+    assert_eq!(error.action, RuntimePluginErrorAction::Reload);
+    assert!(
+        runtime
+            .plugin_statuses()
+            .into_iter()
+            .any(|status| status.plugin_id == "demo" && status.last_error.is_some())
+    );
+
+    std::fs::write(&manifest_path, valid_manifest).expect("restore manifest");
+    update_plugin_version(temp.path(), "demo", "1.1.0").expect("bump version");
+    runtime
+        .reload_dev_plugin("demo")
+        .expect("reload fixed plugin");
+    assert_eq!(active_version(&runtime, "demo"), "1.1.0");
+    assert_eq!(
+        host.events(),
+        vec!["reload:demo".to_string(), "reload:demo".to_string()]
+    );
+}
+
+#[test]
+fn dev_watcher_reports_initial_load_and_manifest_changes() {
+    let temp = tempfile::tempdir().expect("create tempdir");
+    install_test_plugin(temp.path(), "demo", "1.0.0").expect("write dev plugin");
+
+    let host = Arc::new(RecordingLifecycleHost::default());
+    let runtime = WasmPluginRuntime::new(host.clone());
+    let watcher = runtime
+        .watch_dev_plugin(temp.path().join("demo"))
+        .expect("watch dev plugin");
+    let timeout = std::time::Duration::from_secs(10);
+    let loaded = watcher.events().recv_timeout(timeout).expect("load event");
+    assert_eq!(loaded.outcome, RuntimePluginTransitionOutcome::Applied);
+    assert_eq!(loaded.plugin_id.as_deref(), Some("demo"));
+
+    update_plugin_version(temp.path(), "demo", "1.1.0").expect("bump version");
+    let reloaded = watcher
+        .events()
+        .recv_timeout(timeout)
+        .expect("reload event");
+    assert_eq!(reloaded.outcome, RuntimePluginTransitionOutcome::Applied);
+    assert!(
+        reloaded
+            .changed_paths
+            .iter()
+            .all(|path| path.ends_with(PLUGIN_MANIFEST_FILE_NAME))
+    );
+    assert_eq!(active_version(&runtime, "demo"), "1.1.0");
+}
@@ -2,4 +2,5 @@ pub use stellatune_world_decoder as decoder_plugin;
 pub use stellatune_world_dsp as dsp_plugin;
 pub use stellatune_world_lyrics as lyrics_plugin;
 pub use stellatune_world_output_sink as output_sink_plugin;
+pub use stellatune_world_playback_listener as playback_listener_plugin;
 pub use stellatune_world_source as source_plugin;
@@ -2,8 +2,12 @@ pub mod generated;
 
 pub const WIT_DIR: &str = "../../wit/stellatune-plugin";
 
+pub const WIT_PACKAGE: &str = "stellatune:plugin";
+pub const WIT_PACKAGE_VERSION: &str = "0.2.0";
+
 pub const WORLD_DECODER_PLUGIN: &str = "decoder-plugin";
 pub const WORLD_SOURCE_PLUGIN: &str = "source-plugin";
 pub const WORLD_LYRICS_PLUGIN: &str = "lyrics-plugin";
 pub const WORLD_OUTPUT_SINK_PLUGIN: &str = "output-sink-plugin";
 pub const WORLD_DSP_PLUGIN: &str = "dsp-plugin";
+pub const WORLD_PLAYBACK_LISTENER_PLUGIN: &str = "playback-listener-plugin";